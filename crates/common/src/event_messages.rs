@@ -1,11 +1,15 @@
-use accesskit::NodeId;
+use std::{fmt, path::PathBuf};
+
+use accesskit::{Live, NodeId};
 use accesskit_winit::ActionRequestEvent;
 use dioxus_core::Template;
+use freya_engine::prelude::Color;
 use uuid::Uuid;
-use winit::window::CursorIcon;
+use winit::window::{CursorGrabMode, CursorIcon, Window, WindowLevel};
+
+use crate::{HitRegion, NodeSnapshot, PlatformEvent};
 
 /// Custom EventLoop messages
-#[derive(Debug)]
 pub enum EventMessage {
     /// Update the given template
     UpdateTemplate(Template),
@@ -13,18 +17,156 @@ pub enum EventMessage {
     PollVDOM,
     /// Request a rerender
     RequestRerender,
+    /// Apply synchronously every pending change of the VirtualDOM and the layout, and render the result
+    Flush,
+    /// Request a new frame only to tick the tickers, the Window is only repainted if something changed
+    RequestTick,
     /// Remeasure a text elements group
     RemeasureTextGroup(Uuid),
     /// Change the cursor icon
     SetCursorIcon(CursorIcon),
+    /// Change the level of the Window, e.g. to keep it always on top
+    SetWindowLevel(WindowLevel),
+    /// Change the minimum size of the Window, in logical pixels
+    SetWindowMinSize(Option<(f64, f64)>),
+    /// Change the maximum size of the Window, in logical pixels
+    SetWindowMaxSize(Option<(f64, f64)>),
+    /// Change the background color of the Window
+    SetWindowBackground(Color),
+    /// Start writing the presented frames as PNG images into a directory, at the given frames per second
+    /// and rendered with the given supersampling factor
+    StartFrameCapture {
+        directory: PathBuf,
+        frames_per_second: u32,
+        supersampling: u32,
+    },
+    /// Stop writing the presented frames
+    StopFrameCapture,
+    /// A component started (`true`) or stopped (`false`) needing every pointer movement sample instead of coalesced ones
+    SetUnthrottledPointerMoves(bool),
+    /// Change the parts of the Window that receive pointer events
+    SetHitRegion(HitRegion),
+    /// Confine or lock the cursor to the Window while it's focused, or release it with [`CursorGrabMode::None`]
+    SetCursorGrab(CursorGrabMode),
+    /// Show or hide the cursor while it's over the Window
+    SetCursorVisible(bool),
     /// Accessibility action request event
     ActionRequestEvent(ActionRequestEvent),
+    /// Move the mouse cursor to the given logical position in the Window
+    SetCursorPosition((f64, f64)),
     /// Focus the given accessibility NodeID
     FocusAccessibilityNode(NodeId),
+    /// Announce a message with screen readers, without a visible element
+    Announce { message: String, live: Live },
     /// Focus the next accessibility Node
     FocusNextAccessibilityNode,
     /// Focus the previous accessibility Node
     FocusPrevAccessibilityNode,
+    /// Run a callback with access to the underlying winit [`Window`].
+    WithWindow(Box<dyn FnOnce(&Window) + Send + Sync>),
+    /// Render the given Node and its descendants into an image, with the given supersampling factor, and call the callback with it
+    SnapshotNode {
+        node_id: freya_native_core::NodeId,
+        supersampling: u32,
+        callback: Box<dyn FnOnce(Option<NodeSnapshot>) + Send + Sync>,
+    },
+    /// Find the Nodes under the given logical position of the Window, from the topmost to the bottommost one,
+    /// and call the callback with them
+    NodesAtPoint {
+        point: (f64, f64),
+        callback: Box<dyn FnOnce(Vec<freya_native_core::NodeId>) + Send + Sync>,
+    },
+    /// Emit an event to the given Node directly, instead of the Nodes under the cursor
+    DispatchNodeEvent {
+        node_id: freya_native_core::NodeId,
+        event: PlatformEvent,
+    },
+    /// Log the computed layout of the whole tree as JSON
+    DumpLayout,
+    /// Close the Window and exit the app
+    ExitApp,
+}
+
+impl fmt::Debug for EventMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UpdateTemplate(template) => {
+                f.debug_tuple("UpdateTemplate").field(template).finish()
+            }
+            Self::PollVDOM => write!(f, "PollVDOM"),
+            Self::RequestRerender => write!(f, "RequestRerender"),
+            Self::Flush => write!(f, "Flush"),
+            Self::RequestTick => write!(f, "RequestTick"),
+            Self::RemeasureTextGroup(id) => f.debug_tuple("RemeasureTextGroup").field(id).finish(),
+            Self::SetCursorIcon(icon) => f.debug_tuple("SetCursorIcon").field(icon).finish(),
+            Self::SetWindowLevel(level) => f.debug_tuple("SetWindowLevel").field(level).finish(),
+            Self::SetWindowMinSize(size) => f.debug_tuple("SetWindowMinSize").field(size).finish(),
+            Self::SetWindowMaxSize(size) => f.debug_tuple("SetWindowMaxSize").field(size).finish(),
+            Self::SetWindowBackground(color) => {
+                f.debug_tuple("SetWindowBackground").field(color).finish()
+            }
+            Self::StartFrameCapture {
+                directory,
+                frames_per_second,
+                supersampling,
+            } => f
+                .debug_struct("StartFrameCapture")
+                .field("directory", directory)
+                .field("frames_per_second", frames_per_second)
+                .field("supersampling", supersampling)
+                .finish(),
+            Self::StopFrameCapture => write!(f, "StopFrameCapture"),
+            Self::SetUnthrottledPointerMoves(unthrottled) => f
+                .debug_tuple("SetUnthrottledPointerMoves")
+                .field(unthrottled)
+                .finish(),
+            Self::SetHitRegion(hit_region) => {
+                f.debug_tuple("SetHitRegion").field(hit_region).finish()
+            }
+            Self::SetCursorGrab(mode) => f.debug_tuple("SetCursorGrab").field(mode).finish(),
+            Self::SetCursorVisible(visible) => {
+                f.debug_tuple("SetCursorVisible").field(visible).finish()
+            }
+            Self::ActionRequestEvent(event) => {
+                f.debug_tuple("ActionRequestEvent").field(event).finish()
+            }
+            Self::SetCursorPosition(position) => {
+                f.debug_tuple("SetCursorPosition").field(position).finish()
+            }
+            Self::FocusAccessibilityNode(id) => {
+                f.debug_tuple("FocusAccessibilityNode").field(id).finish()
+            }
+            Self::Announce { message, live } => f
+                .debug_struct("Announce")
+                .field("message", message)
+                .field("live", live)
+                .finish(),
+            Self::FocusNextAccessibilityNode => write!(f, "FocusNextAccessibilityNode"),
+            Self::FocusPrevAccessibilityNode => write!(f, "FocusPrevAccessibilityNode"),
+            // The callbacks can't be printed
+            Self::WithWindow(_) => write!(f, "WithWindow(..)"),
+            Self::SnapshotNode {
+                node_id,
+                supersampling,
+                ..
+            } => f
+                .debug_struct("SnapshotNode")
+                .field("node_id", node_id)
+                .field("supersampling", supersampling)
+                .finish_non_exhaustive(),
+            Self::NodesAtPoint { point, .. } => f
+                .debug_struct("NodesAtPoint")
+                .field("point", point)
+                .finish_non_exhaustive(),
+            Self::DispatchNodeEvent { node_id, event } => f
+                .debug_struct("DispatchNodeEvent")
+                .field("node_id", node_id)
+                .field("event", event)
+                .finish(),
+            Self::DumpLayout => write!(f, "DumpLayout"),
+            Self::ExitApp => write!(f, "ExitApp"),
+        }
+    }
 }
 
 impl From<ActionRequestEvent> for EventMessage {
@@ -0,0 +1,24 @@
+use torin::geometry::Area;
+
+/// Parts of the Window that receive the pointer events, the rest let them pass through to whatever is below it,
+/// e.g. for transparent overlays.
+///
+/// The Window stops receiving pointer events while the cursor is outside of the hit region, so it relies
+/// on the raw movements of the mouse to know when to check the region again.
+///
+/// ## Platform-specific
+///
+/// - **Windows / X11:** Supported.
+/// - **macOS / Wayland:** The raw movements of the mouse are not reported while it's over other windows, so once the
+///   pointer events pass through, the Window only receives them again after it gets focused.
+/// - **Android / iOS / Web:** Not supported, the whole Window receives the pointer events.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum HitRegion {
+    /// The whole Window.
+    #[default]
+    Window,
+    /// Only where something is painted, i.e. over the elements with a background, a border, text or an image.
+    Painted,
+    /// Only inside of these areas, in logical pixels.
+    Areas(Vec<Area>),
+}
@@ -1,30 +1,116 @@
 use freya_native_core::NodeId;
 use rustc_hash::FxHashMap;
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::cmp::Ordering as CmpOrdering;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex, MutexGuard,
+};
 
+/// Paint order of a node, made of its layer inside every stacking context it is nested in,
+/// from the outermost to its own.
+///
+/// Layers are compared lexicographically, so all the nodes of a stacking context are kept together
+/// right after its root, no matter how low or high their own layers are.
+#[derive(Default, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Layer(pub Vec<i16>);
+
+impl Layer {
+    /// Get the layer of a node inside the stacking context of this one.
+    pub fn nested(&self, layer: i16) -> Self {
+        let mut path = self.0.clone();
+        path.push(layer);
+        Self(path)
+    }
+}
+
+/// Nodes of every [`Layer`], painted from the lowest layer to the highest one.
+/// The topmost node under the cursor, the last one painted, is the target of the pointer events.
+///
+/// The nodes of the same layer are kept in the order of the document once sorted with [`Layers::sort_nodes`],
+/// so the ones that come later are painted above the rest.
 #[derive(Default, Clone)]
 pub struct Layers {
-    pub layers: Arc<Mutex<FxHashMap<i16, Vec<NodeId>>>>,
+    pub layers: Arc<Mutex<FxHashMap<Layer, Vec<NodeId>>>>,
+    /// How many nodes were inserted at the end of every layer since it was last sorted.
+    inserted: Arc<Mutex<FxHashMap<Layer, usize>>>,
+    unsorted: Arc<AtomicBool>,
 }
 
 impl Layers {
-    pub fn insert_node_in_layer(&self, node_id: NodeId, layer_n: i16) {
+    pub fn insert_node_in_layer(&self, node_id: NodeId, layer_n: Layer) {
         let mut layers = self.layers.lock().unwrap();
-        let layer = layers.entry(layer_n).or_default();
+        let layer = layers.entry(layer_n.clone()).or_default();
         layer.push(node_id);
+        *self.inserted.lock().unwrap().entry(layer_n).or_default() += 1;
+    }
+
+    /// Mark the nodes of every layer as out of the order of the document, e.g. after moving some of them.
+    pub fn mark_unsorted(&self) {
+        self.unsorted.store(true, Ordering::Relaxed);
+    }
+
+    /// Sort the nodes of the layers that changed by their position in the document.
+    ///
+    /// The nodes inserted since the last sort are placed among the rest of their layer by comparing their `path`,
+    /// the index of every node from the root to them among its siblings.
+    /// Every layer is sorted again with the `positions` of all the nodes once they are marked as unsorted.
+    pub fn sort_nodes(
+        &self,
+        positions: impl FnOnce() -> FxHashMap<NodeId, usize>,
+        path: impl Fn(NodeId) -> Vec<usize>,
+    ) {
+        let mut layers = self.layers.lock().unwrap();
+        let inserted = std::mem::take(&mut *self.inserted.lock().unwrap());
+
+        if self.unsorted.swap(false, Ordering::Relaxed) {
+            let positions = positions();
+            for layer in layers.values_mut() {
+                layer.sort_by_key(|node_id| positions.get(node_id).copied().unwrap_or(usize::MAX));
+            }
+            return;
+        }
+
+        for (layer_n, inserted) in inserted {
+            let Some(layer) = layers.get_mut(&layer_n) else {
+                continue;
+            };
+            let mut new_nodes = layer
+                .drain(layer.len() - inserted..)
+                .map(|node_id| (path(node_id), node_id))
+                .collect::<Vec<_>>();
+            new_nodes.sort();
+
+            // Nodes are usually appended, so compare with the last ones of the layer first
+            let mut end = layer.len();
+            for (node_path, node_id) in new_nodes.into_iter().rev() {
+                let index = partition_point_from_end(&layer[..end], |other| {
+                    path(*other).cmp(&node_path) == CmpOrdering::Less
+                });
+                layer.insert(index, node_id);
+                end = index;
+            }
+        }
     }
 
-    pub fn remove_node_from_layer(&self, node_id: NodeId, layer_n: i16) {
+    pub fn remove_node_from_layer(&self, node_id: NodeId, layer_n: &Layer) {
         let mut layers = self.layers.lock().unwrap();
-        let layer = layers.get_mut(&layer_n).unwrap();
-        layer.retain(|id| *id != node_id);
+        let layer = layers.get_mut(layer_n).unwrap();
+        if let Some(index) = layer.iter().position(|id| *id == node_id) {
+            let mut inserted = self.inserted.lock().unwrap();
+            if let Some(inserted) = inserted.get_mut(layer_n) {
+                if index >= layer.len() - *inserted {
+                    *inserted -= 1;
+                }
+            }
+            layer.remove(index);
+        }
 
         if layer.is_empty() {
-            layers.remove(&layer_n);
+            layers.remove(layer_n);
         }
     }
 
-    pub fn layers(&self) -> MutexGuard<FxHashMap<i16, Vec<NodeId>>> {
+    pub fn layers(&self) -> MutexGuard<FxHashMap<Layer, Vec<NodeId>>> {
         self.layers.lock().unwrap()
     }
 
@@ -32,3 +118,16 @@ impl Layers {
         self.layers.lock().unwrap().len()
     }
 }
+
+/// Find the first index of the sorted `nodes` for which `is_before` is false,
+/// checking the last ones first and doubling the distance until one that is before is found.
+fn partition_point_from_end(nodes: &[NodeId], is_before: impl Fn(&NodeId) -> bool) -> usize {
+    let mut start = nodes.len();
+    let mut step = 1;
+    while start > 0 && !is_before(&nodes[start - 1]) {
+        start = start.saturating_sub(step);
+        step *= 2;
+    }
+    let end = (start + step).min(nodes.len());
+    start + nodes[start..end].partition_point(is_before)
+}
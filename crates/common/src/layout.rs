@@ -1,4 +1,5 @@
 use freya_engine::prelude::Paragraph;
+use freya_native_core::NodeId;
 use std::ops::Div;
 use torin::geometry::{Area, Size2D};
 
@@ -7,23 +8,46 @@ use torin::geometry::{Area, Size2D};
 pub struct NodeReferenceLayout {
     pub area: Area,
     pub inner: Size2D,
+    /// Part of the Node that is not clipped by its ancestors nor outside of the Window, empty when none of it is.
+    pub visible_area: Area,
+    /// Id of the Node, `None` until it has been measured.
+    pub node_id: Option<NodeId>,
 }
 
 impl NodeReferenceLayout {
     pub fn div(&mut self, rhs: f32) {
         self.area = self.area.div(rhs);
         self.inner = self.inner.div(rhs);
+        self.visible_area = self.visible_area.div(rhs);
     }
+
+    /// Check if any part of the Node is visible.
+    pub fn is_visible(&self) -> bool {
+        !self.visible_area.is_empty()
+    }
+}
+
+/// Rendered image of a Node and its descendants, used by `UsePlatform::snapshot_node`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodeSnapshot {
+    /// PNG encoded image, in physical pixels.
+    pub image: Vec<u8>,
+    /// Area covered by the image, in logical pixels. It can be bigger than the Node, e.g. to fit its shadows.
+    pub area: Area,
 }
 
 /// Messages emitted from the layout library to the Nodes. Used in `use_editable`.
+///
+/// `Unselectable` is emitted instead of `CursorPosition` when the text can't be selected, see `user_select`.
 #[derive(Debug)]
 pub enum CursorLayoutResponse {
     CursorPosition { position: usize, id: usize },
     TextSelection { from: usize, to: usize, id: usize },
+    Unselectable { id: usize },
 }
 
-pub struct CachedParagraph(pub Paragraph);
+/// Laid out paragraph of a text element, along with the width it was laid out in.
+pub struct CachedParagraph(pub Paragraph, pub f32);
 
 /// # Safety
 /// Skia `Paragraph` are neither Sync or Send, but in order to store them in the Associated
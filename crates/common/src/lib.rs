@@ -1,9 +1,13 @@
 mod event_messages;
+mod hit_region;
 mod layers;
 mod layout;
 mod paragraphs;
+mod platform_event;
 
 pub use event_messages::*;
+pub use hit_region::*;
 pub use layers::*;
 pub use layout::*;
 pub use paragraphs::*;
+pub use platform_event::*;
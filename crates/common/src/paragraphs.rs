@@ -1,5 +1,5 @@
 use freya_native_core::NodeId;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::sync::{Arc, Mutex, MutexGuard};
 use uuid::Uuid;
 
@@ -37,3 +37,21 @@ impl ParagraphElements {
         self.paragraphs.lock().unwrap().len()
     }
 }
+
+/// Nodes whose text only changed in how it's painted (e.g. its color) since the last layout,
+/// so their cached paragraphs can be rebuilt without measuring the layout again.
+#[derive(Default, Clone)]
+pub struct RestyledParagraphs {
+    pub nodes: Arc<Mutex<FxHashSet<NodeId>>>,
+}
+
+impl RestyledParagraphs {
+    pub fn insert_node(&self, node_id: NodeId) {
+        self.nodes.lock().unwrap().insert(node_id);
+    }
+
+    /// Take the restyled nodes since the last call.
+    pub fn take_nodes(&self) -> FxHashSet<NodeId> {
+        std::mem::take(&mut self.nodes.lock().unwrap())
+    }
+}
@@ -1,12 +1,17 @@
 use std::path::PathBuf;
 
-use freya_elements::events::keyboard::{Code, Key, Modifiers};
+use freya_elements::events::{
+    keyboard::{Code, Key, Modifiers},
+    WheelDelta,
+};
 use torin::prelude::*;
 use winit::event::{Force, MouseButton, TouchPhase};
 
-use crate::prelude::EventName;
+use freya_native_core::events::EventName;
 
 /// Events emitted in Freya.
+///
+/// Coordinates are expressed in physical pixels, just like the layout.
 #[derive(Clone, Debug)]
 pub enum PlatformEvent {
     /// A Mouse Event.
@@ -20,6 +25,9 @@ pub enum PlatformEvent {
         name: EventName,
         scroll: CursorPoint,
         cursor: CursorPoint,
+        /// Raw delta as reported by the device.
+        delta: WheelDelta,
+        modifiers: Modifiers,
     },
     /// A Keyboard event.
     Keyboard {
@@ -27,6 +35,7 @@ pub enum PlatformEvent {
         key: Key,
         code: Code,
         modifiers: Modifiers,
+        repeat: bool,
     },
     /// A Touch event.
     Touch {
@@ -64,4 +73,15 @@ impl PlatformEvent {
             Self::File { name, .. } => *name = new_name,
         }
     }
+
+    /// Map the coordinates of the event, if it has any.
+    pub fn map_cursor(&mut self, map: impl Fn(CursorPoint) -> CursorPoint) {
+        match self {
+            Self::Mouse { cursor, .. } => *cursor = map(*cursor),
+            Self::Wheel { cursor, .. } => *cursor = map(*cursor),
+            Self::Keyboard { .. } => {}
+            Self::Touch { location, .. } => *location = map(*location),
+            Self::File { cursor, .. } => *cursor = map(*cursor),
+        }
+    }
 }
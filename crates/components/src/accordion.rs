@@ -2,12 +2,11 @@ use dioxus::prelude::*;
 use freya_elements::elements as dioxus_elements;
 use freya_elements::events::MouseEvent;
 
-use freya_hooks::{
-    use_animation_with_dependencies, use_applied_theme, use_node, use_platform, AccordionTheme,
-    AccordionThemeWith, AnimNum,
-};
+use freya_hooks::{use_applied_theme, use_platform, AccordionTheme, AccordionThemeWith};
 use winit::window::CursorIcon;
 
+use crate::Collapsible;
+
 /// Indicates the current status of the accordion.
 #[derive(Debug, Default, PartialEq, Clone, Copy)]
 pub enum AccordionStatus {
@@ -38,29 +37,16 @@ pub struct AccordionProps {
 pub fn Accordion(props: AccordionProps) -> Element {
     let theme = use_applied_theme!(&props.theme, accordion);
     let mut open = use_signal(|| false);
-    let (node_ref, size) = use_node();
-
-    let animation = use_animation_with_dependencies(&size.area.height(), move |ctx, height| {
-        ctx.with(AnimNum::new(0., height).time(200))
-    });
     let mut status = use_signal(AccordionStatus::default);
     let platform = use_platform();
 
-    let animation_value = animation.get().read().as_f32();
     let AccordionTheme {
         background,
         color,
         border_fill,
     } = theme;
 
-    let onclick = move |_: MouseEvent| {
-        open.toggle();
-        if *open.read() {
-            animation.start();
-        } else {
-            animation.reverse();
-        }
-    };
+    let onclick = move |_: MouseEvent| open.toggle();
 
     use_drop(move || {
         if *status.read() == AccordionStatus::Hovering {
@@ -93,16 +79,9 @@ pub fn Accordion(props: AccordionProps) -> Element {
             onclick,
             border: "1 solid {border_fill}",
             {&props.summary}
-            rect {
-                overflow: "clip",
-                width: "100%",
-                height: "{animation_value}",
-                rect {
-                    reference: node_ref,
-                    height: "auto",
-                    width: "100%",
-                    {&props.children}
-                }
+            Collapsible {
+                open: open(),
+                {&props.children}
             }
         }
     )
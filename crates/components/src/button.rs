@@ -108,10 +108,11 @@ pub fn Button(props: ButtonProps) -> Element {
         ButtonStatus::Hovering => hover_background,
         ButtonStatus::Idle => background,
     };
-    let border = if focus.is_selected() {
+    let border = format!("1 solid {border_fill}");
+    let outline = if focus.is_selected() {
         format!("2 solid {focus_border_fill}")
     } else {
-        format!("1 solid {border_fill}")
+        "none".to_string()
     };
 
     rsx!(
@@ -131,6 +132,8 @@ pub fn Button(props: ButtonProps) -> Element {
             color: "{font_theme.color}",
             shadow: "{shadow}",
             border: "{border}",
+            outline: "{outline}",
+            outline_offset: "1",
             corner_radius: "{corner_radius}",
             background: "{background}",
             text_align: "center",
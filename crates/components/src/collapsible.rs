@@ -0,0 +1,154 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_hooks::{use_frame, use_node, use_platform};
+
+/// Show or hide its children by smoothly animating its height between `0` and the height of the children,
+/// e.g. for the sections of an accordion.
+///
+/// The children stay mounted and keep their layout while collapsed, so their height is measured all the time and
+/// they don't lose their state. They are clipped during the animation, and while collapsed they are neither visible
+/// nor reachable by the mouse. Toggling `open` in the middle of the animation turns it around from where it was.
+///
+/// Once fully open the height is `auto`, so it follows the changes of the children right away.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut open = use_signal(|| false);
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| open.toggle(),
+///             label {
+///                 "Toggle"
+///             }
+///         }
+///         Collapsible {
+///             open: open(),
+///             label {
+///                 "Hello, World!"
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Collapsible(
+    /// Whether the children are shown.
+    open: bool,
+    /// Duration of the animation, in milliseconds. Defaults to `200`.
+    #[props(default = 200)]
+    duration: u64,
+    /// Content that is collapsed.
+    children: Element,
+) -> Element {
+    let (node_ref, size) = use_node();
+    let platform = use_platform();
+    let mut progress = use_signal(|| if open { 1.0 } else { 0.0 });
+    let target = use_memo(use_reactive(&open, |open| if open { 1.0 } else { 0.0 }));
+    let duration = use_memo(use_reactive(&duration, |duration| duration.max(1) as f32));
+
+    // Frames are only requested while the animation runs, so it needs one to start moving
+    use_effect(use_reactive(&open, move |_| platform.request_tick()));
+
+    use_frame(move |elapsed| {
+        let target = *target.peek();
+        let current = *progress.peek();
+        if current == target {
+            return false;
+        }
+
+        let step = elapsed.as_millis() as f32 / *duration.peek();
+        let next = if target > current {
+            (current + step).min(target)
+        } else {
+            (current - step).max(target)
+        };
+        progress.set(next);
+        next != target
+    });
+
+    let progress = progress();
+    let height = if progress >= 1.0 {
+        "auto".to_string()
+    } else {
+        // Ease in and out
+        let eased = progress * progress * (3.0 - 2.0 * progress);
+        (size.area.height() * eased).to_string()
+    };
+
+    rsx!(
+        rect {
+            overflow: "clip",
+            width: "100%",
+            height: "{height}",
+            rect {
+                reference: node_ref,
+                height: "auto",
+                width: "100%",
+                {children}
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+    use tokio::time::sleep;
+
+    #[tokio::test]
+    pub async fn collapsible() {
+        fn collapsible_app() -> Element {
+            let mut open = use_signal(|| false);
+
+            rsx!(
+                rect {
+                    width: "100%",
+                    height: "50",
+                    onclick: move |_| open.toggle(),
+                }
+                Collapsible {
+                    open: open(),
+                    rect {
+                        width: "100%",
+                        height: "100",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(collapsible_app);
+        let collapsible = utils.root().get(1);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Collapsed
+        assert_eq!(collapsible.layout().unwrap().area.height(), 0.0);
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        // Somewhere in the middle
+        sleep(Duration::from_millis(100)).await;
+        utils.wait_for_update().await;
+        let height = collapsible.layout().unwrap().area.height();
+        assert!(height > 0.0 && height < 100.0);
+
+        // Fully open
+        sleep(Duration::from_millis(200)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(collapsible.layout().unwrap().area.height(), 100.0);
+    }
+}
@@ -8,12 +8,18 @@ pub use winit::window::CursorIcon;
 pub struct CursorAreaProps {
     /// Cursor icon that will be used when hovering this area.
     icon: CursorIcon,
+    /// Cursor icon that will be used while this area is pressed, even when the cursor leaves it.
+    /// Defaults to `CursorIcon::Grabbing` for `CursorIcon::Grab`, and to `icon` for the rest.
+    pressed_icon: Option<CursorIcon>,
     /// Inner children for the CursorArea.
     children: Element,
 }
 
 /// Change the cursor icon when it's hovering over this component.
 ///
+/// The icon changes to its `pressed_icon` while the area is pressed, so a draggable element
+/// with `CursorIcon::Grab` shows `CursorIcon::Grabbing` until it's released.
+///
 /// # Example
 ///
 /// ```no_run
@@ -34,22 +40,55 @@ pub struct CursorAreaProps {
 /// ```
 ///
 #[allow(non_snake_case)]
-pub fn CursorArea(CursorAreaProps { children, icon }: CursorAreaProps) -> Element {
+pub fn CursorArea(
+    CursorAreaProps {
+        children,
+        icon,
+        pressed_icon,
+    }: CursorAreaProps,
+) -> Element {
     let platform = use_platform();
     let mut is_hovering = use_signal(|| false);
+    let mut is_pressed = use_signal(|| false);
+
+    let pressed_icon = pressed_icon.unwrap_or(match icon {
+        CursorIcon::Grab => CursorIcon::Grabbing,
+        icon => icon,
+    });
 
     let onmouseover = move |_| {
         *is_hovering.write() = true;
-        platform.set_cursor(icon);
+        if !*is_pressed.read() {
+            platform.set_cursor(icon);
+        }
     };
 
     let onmouseleave = move |_| {
         *is_hovering.write() = false;
-        platform.set_cursor(CursorIcon::default());
+        if !*is_pressed.read() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    };
+
+    let onmousedown = move |_| {
+        *is_pressed.write() = true;
+        platform.set_cursor(pressed_icon);
+    };
+
+    // The area might not be hovered anymore when it's released
+    let onglobalclick = move |_| {
+        if *is_pressed.read() {
+            *is_pressed.write() = false;
+            if *is_hovering.read() {
+                platform.set_cursor(icon);
+            } else {
+                platform.set_cursor(CursorIcon::default());
+            }
+        }
     };
 
     use_drop(move || {
-        if *is_hovering.peek() {
+        if *is_hovering.peek() || *is_pressed.peek() {
             platform.set_cursor(CursorIcon::default());
         }
     });
@@ -58,6 +97,8 @@ pub fn CursorArea(CursorAreaProps { children, icon }: CursorAreaProps) -> Elemen
         rect {
             onmouseover,
             onmouseleave,
+            onmousedown,
+            onglobalclick,
             {children}
         }
     )
@@ -128,4 +169,60 @@ mod test {
         // Cursor after leaving the window
         assert_eq!(utils.cursor_icon(), CursorIcon::default());
     }
+
+    #[tokio::test]
+    pub async fn cursor_area_pressed() {
+        fn cursor_area_app() -> Element {
+            rsx!(
+                CursorArea {
+                    icon: CursorIcon::Grab,
+                    rect {
+                        height: "50%",
+                        width: "100%",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(cursor_area_app);
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (100., 100.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.cursor_icon(), CursorIcon::Grab);
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (100., 100.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        // Cursor while pressing the area
+        assert_eq!(utils.cursor_icon(), CursorIcon::Grabbing);
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (100., 300.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        // Cursor after dragging it out of the area
+        assert_eq!(utils.cursor_icon(), CursorIcon::Grabbing);
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (100., 300.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        // Cursor after releasing it out of the area
+        assert_eq!(utils.cursor_icon(), CursorIcon::default());
+    }
 }
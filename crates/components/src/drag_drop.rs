@@ -1,8 +1,10 @@
+use bytes::Bytes;
 use dioxus::prelude::*;
 use freya_elements::elements as dioxus_elements;
 use freya_elements::events::MouseEvent;
-use freya_hooks::use_node_signal;
-use torin::prelude::CursorPoint;
+use freya_hooks::{use_node_signal, use_platform};
+use freya_node_state::dynamic_bytes;
+use torin::prelude::{Area, CursorPoint};
 
 /// Properties for the [`DragProvider`] component.
 #[derive(Props, Clone, PartialEq)]
@@ -22,7 +24,10 @@ pub fn DragProvider<T: 'static>(DragProviderProps { children }: DragProviderProp
 #[derive(Props, Clone, PartialEq)]
 pub struct DragZoneProps<T: Clone + 'static + PartialEq> {
     /// Element visible when dragging the element. This follows the cursor.
-    drag_element: Element,
+    ///
+    /// Without it, a semi-transparent snapshot of the children follows the cursor instead,
+    /// see [`UsePlatform::snapshot_node`](freya_hooks::UsePlatform::snapshot_node).
+    drag_element: Option<Element>,
     /// Inner children for the DropZone.
     children: Element,
     /// Data that will be handled to the destination [`DropZone`].
@@ -41,7 +46,10 @@ pub fn DragZone<T: 'static + Clone + PartialEq>(
     let mut drags = use_context::<Signal<Option<T>>>();
     let mut dragging = use_signal(|| false);
     let mut pos = use_signal(CursorPoint::default);
+    let mut grab_pos = use_signal(CursorPoint::default);
+    let mut snapshot = use_signal(|| None::<(Bytes, Area)>);
     let (node_reference, size) = use_node_signal();
+    let platform = use_platform();
 
     let onglobalmouseover = move |e: MouseEvent| {
         if *dragging.read() {
@@ -57,36 +65,65 @@ pub fn DragZone<T: 'static + Clone + PartialEq>(
         }
     };
 
+    let has_drag_element = drag_element.is_some();
     let onmousedown = move |e: MouseEvent| {
         let size = size.read();
         let coord = e.get_screen_coordinates();
-        pos.set(
-            (
-                coord.x - size.area.min_x() as f64,
-                coord.y - size.area.min_y() as f64,
-            )
-                .into(),
+        let point = CursorPoint::new(
+            coord.x - size.area.min_x() as f64,
+            coord.y - size.area.min_y() as f64,
         );
+        pos.set(point);
+        grab_pos.set(point);
         dragging.set(true);
         *drags.write() = Some(data.clone());
+
+        if !has_drag_element {
+            if let Some(node_id) = size.node_id {
+                spawn(async move {
+                    let node_snapshot = platform.snapshot_node(node_id).await;
+                    snapshot.set(
+                        node_snapshot
+                            .map(|node_snapshot| (node_snapshot.image.into(), node_snapshot.area)),
+                    );
+                });
+            }
+        }
     };
 
     let onglobalclick = move |_: MouseEvent| {
         if *dragging.read() {
             dragging.set(false);
             pos.set((0.0, 0.0).into());
+            snapshot.set(None);
             *drags.write() = None;
         }
     };
 
     rsx!(
         if *dragging.read() {
-            rect {
-                width: "0",
-                height: "0",
-                offset_x: "{pos.read().x}",
-                offset_y: "{pos.read().y}",
-                {drag_element}
+            if let Some(drag_element) = drag_element {
+                rect {
+                    width: "0",
+                    height: "0",
+                    offset_x: "{pos.read().x}",
+                    offset_y: "{pos.read().y}",
+                    {drag_element}
+                }
+            } else if let Some((image, area)) = &*snapshot.read() {
+                // Keep the snapshot under the cursor at the same point it was grabbed from
+                rect {
+                    width: "0",
+                    height: "0",
+                    offset_x: "{pos.read().x - grab_pos.read().x + (area.min_x() - size.read().area.min_x()) as f64}",
+                    offset_y: "{pos.read().y - grab_pos.read().y + (area.min_y() - size.read().area.min_y()) as f64}",
+                    image {
+                        image_data: dynamic_bytes(image.clone()),
+                        width: "{area.width()}",
+                        height: "{area.height()}",
+                        opacity: "0.5",
+                    }
+                }
             }
         }
         rect {
@@ -217,4 +254,68 @@ mod test {
             Some("Enabled: true")
         );
     }
+
+    #[tokio::test]
+    pub async fn drag_drop_without_drag_element() {
+        fn drop_app() -> Element {
+            let mut state = use_signal::<bool>(|| false);
+
+            rsx!(
+                DragProvider::<bool> {
+                    rect {
+                        height: "50%",
+                        width: "100%",
+                        DragZone {
+                            data: true,
+                            label {
+                                "Move"
+                            }
+                        }
+                    },
+                    DropZone {
+                        ondrop: move |data: bool| {
+                            state.set(data);
+                        },
+                        rect {
+                            height: "50%",
+                            width: "100%",
+                            label {
+                                "Enabled: {state.read()}"
+                            }
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(drop_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (5.0, 300.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 300.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            root.get(1).get(0).get(0).get(0).text(),
+            Some("Enabled: true")
+        );
+    }
 }
@@ -0,0 +1,207 @@
+use std::{cell::RefCell, rc::Rc};
+
+use dioxus::{dioxus_core::CapturedError, prelude::*};
+
+/// Error of the children of an [`ErrorGuard`], shown by its `fallback`.
+#[derive(Clone)]
+pub struct CaughtError {
+    /// `Debug` representation of the thrown error, the panics just have a generic message.
+    pub message: String,
+    /// Whether a component panicked while rendering, instead of throwing an error.
+    pub is_panic: bool,
+    caught: Rc<RefCell<Option<CaughtError>>>,
+    guard: ScopeId,
+}
+
+impl CaughtError {
+    fn new(error: CapturedError, caught: Rc<RefCell<Option<CaughtError>>>, guard: ScopeId) -> Self {
+        let message = format!("{:?}", error.error);
+        // Dioxus doesn't expose the payload of the panics it catches, they are only logged
+        let is_panic = message == "CapturedPanic";
+        let message = if is_panic {
+            "A component panicked while rendering".to_string()
+        } else {
+            message
+        };
+
+        Self {
+            message,
+            is_panic,
+            caught,
+            guard,
+        }
+    }
+
+    /// Mount the children again from scratch, e.g. after the data they failed with was fixed.
+    pub fn retry(&self) {
+        self.caught.borrow_mut().take();
+        self.guard.needs_update();
+    }
+}
+
+impl PartialEq for CaughtError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message && self.is_panic == other.is_panic
+    }
+}
+
+/// Element an [`ErrorGuard`] renders with the error of its children.
+#[derive(Clone)]
+pub struct ErrorFallback(Rc<dyn Fn(CaughtError) -> Element>);
+
+impl<F: Fn(CaughtError) -> Element + 'static> From<F> for ErrorFallback {
+    fn from(fallback: F) -> Self {
+        Self(Rc::new(fallback))
+    }
+}
+
+impl PartialEq for ErrorFallback {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Render a `fallback` instead of its children once they throw an error or panic while rendering,
+/// so the rest of the app keeps working.
+///
+/// The errors are the ones thrown with [`Throw`] and the panics caught by the renders of the components,
+/// panics in event handlers or futures aren't caught. Every error is caught by its closest guard,
+/// and errors of the fallback itself reach the guards above it.
+///
+/// The children are unmounted once they fail, so their state is lost while the state of the components
+/// around the guard is kept. [`CaughtError::retry`] mounts them again from scratch.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         ErrorGuard {
+///             fallback: |error: CaughtError| rsx!(
+///                 label { "Something went wrong: {error.message}" }
+///                 Button {
+///                     onclick: move |_| error.retry(),
+///                     label { "Retry" }
+///                 }
+///             ),
+///             Settings {}
+///         }
+///     )
+/// }
+///
+/// #[component]
+/// fn Settings() -> Element {
+///     let volume = "loud".parse::<u8>().throw()?;
+///
+///     rsx!(label { "Volume: {volume}" })
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn ErrorGuard(
+    /// Renders the error of the children.
+    #[props(into)]
+    fallback: ErrorFallback,
+    /// Content that might fail.
+    children: Element,
+) -> Element {
+    let error_boundary = use_error_boundary();
+    let caught = use_hook(|| Rc::new(RefCell::new(None)));
+    let guard = use_hook(|| current_scope_id().unwrap());
+
+    if let Some(error) = error_boundary.take_error() {
+        caught
+            .borrow_mut()
+            .replace(CaughtError::new(error, caught.clone(), guard));
+    }
+
+    let error = caught.borrow().clone();
+    match error {
+        Some(error) => (fallback.0)(error),
+        None => children,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    async fn click(utils: &mut TestingHandler, x: f64, y: f64) {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: CursorPoint::new(x, y),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+    }
+
+    #[tokio::test]
+    pub async fn error_guard() {
+        #[component]
+        fn Fails(fail_with: Signal<Option<bool>>) -> Element {
+            match fail_with() {
+                Some(true) => panic!("Broken"),
+                Some(false) => Err::<(), _>("Invalid").throw()?,
+                None => {}
+            }
+            rsx!(label { "Working" })
+        }
+
+        fn error_guard_app() -> Element {
+            let mut fail_with = use_signal(|| None);
+            let mut clicks = use_signal(|| 0);
+
+            rsx!(
+                rect {
+                    width: "100",
+                    height: "100",
+                    onclick: move |_| {
+                        clicks += 1;
+                        fail_with.set(Some(clicks() % 2 == 1));
+                    },
+                    label { "{clicks}" }
+                }
+                ErrorGuard {
+                    fallback: move |error: CaughtError| rsx!(
+                        rect {
+                            width: "100",
+                            height: "100",
+                            onclick: move |_| {
+                                fail_with.set(None);
+                                error.retry();
+                            },
+                            label { "{error.message} {error.is_panic}" }
+                        }
+                    ),
+                    Fails { fail_with }
+                }
+            )
+        }
+
+        let mut utils = launch_test(error_guard_app);
+        utils.wait_for_update().await;
+        let root = utils.root();
+        assert_eq!(root.get(1).get(0).text(), Some("Working"));
+
+        // A panic shows the fallback but the rest of the app keeps working
+        click(&mut utils, 50.0, 50.0).await;
+        let root = utils.root();
+        assert_eq!(root.get(0).get(0).get(0).text(), Some("1"));
+        assert_eq!(
+            root.get(1).get(0).get(0).text(),
+            Some("A component panicked while rendering true")
+        );
+
+        // Retrying mounts the children again
+        click(&mut utils, 50.0, 150.0).await;
+        assert_eq!(utils.root().get(1).get(0).text(), Some("Working"));
+
+        // Thrown errors are caught too
+        click(&mut utils, 50.0, 50.0).await;
+        let root = utils.root();
+        assert_eq!(root.get(1).get(0).get(0).text(), Some("\"Invalid\" false"));
+    }
+}
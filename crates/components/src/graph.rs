@@ -3,7 +3,7 @@ use freya_common::EventMessage;
 use freya_elements::elements as dioxus_elements;
 use freya_engine::prelude::*;
 
-use freya_hooks::{use_applied_theme, use_canvas, use_platform, GraphTheme, GraphThemeWith};
+use freya_hooks::{use_applied_theme, use_cached_canvas, use_platform, GraphTheme, GraphThemeWith};
 use freya_node_state::Parse;
 
 /// Data line for the [`Graph`] component.
@@ -43,7 +43,7 @@ pub fn Graph(props: GraphProps) -> Element {
         platform.send(EventMessage::RequestRerender).ok();
     }));
 
-    let canvas = use_canvas(&props, |state| {
+    let canvas = use_cached_canvas(&props, |state| {
         Box::new(move |canvas, font_collection, region| {
             canvas.translate((region.min_x(), region.min_y()));
 
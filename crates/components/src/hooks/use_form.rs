@@ -28,6 +28,9 @@ impl<Id: Clone + Hash + Eq> UseForm<Id> {
             theme: None,
             mode: InputMode::default(),
             value,
+            placeholder: None,
+            onvalidate: None,
+            onkeydown: None,
         }
     }
 
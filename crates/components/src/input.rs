@@ -1,7 +1,9 @@
+use std::{cell::Cell, rc::Rc};
+
 use dioxus::prelude::*;
 use freya_elements::elements as dioxus_elements;
 use freya_elements::events::keyboard::Key;
-use freya_elements::events::{KeyboardData, MouseEvent};
+use freya_elements::events::{KeyboardData, KeyboardEvent, MouseEvent};
 use freya_hooks::use_platform;
 use freya_hooks::{
     use_applied_theme, use_editable, use_focus, EditableConfig, EditableEvent, EditableMode,
@@ -36,6 +38,37 @@ pub enum InputStatus {
     Hovering,
 }
 
+/// Text of an [`Input`] after an edit, which is only applied if it's valid. See [`InputProps::onvalidate`].
+#[derive(Clone)]
+pub struct InputValidator {
+    text: String,
+    valid: Rc<Cell<bool>>,
+}
+
+impl InputValidator {
+    fn new(text: String) -> Self {
+        Self {
+            text,
+            valid: Rc::new(Cell::new(true)),
+        }
+    }
+
+    /// Get the text the Input would have.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Mark the text as valid or not, it's valid by default.
+    pub fn set_valid(&self, valid: bool) {
+        self.valid.set(valid);
+    }
+
+    /// Check if the text is valid.
+    pub fn is_valid(&self) -> bool {
+        self.valid.get()
+    }
+}
+
 /// Properties for the [`Input`] component.
 #[derive(Props, Clone, PartialEq)]
 pub struct InputProps {
@@ -48,6 +81,12 @@ pub struct InputProps {
     /// Display mode for Input. By default, input text is shown as it is provided.
     #[props(default = InputMode::Shown, into)]
     pub mode: InputMode,
+    /// Text to show in a dimmed color while the Input is empty.
+    pub placeholder: Option<String>,
+    /// Handler to validate every edit before `onchange` is called, the invalid ones are reverted.
+    pub onvalidate: Option<EventHandler<InputValidator>>,
+    /// Handler for the keys pressed while the Input is focused, once the text was edited with them.
+    pub onkeydown: Option<EventHandler<KeyboardEvent>>,
 }
 
 /// Small box to edit text.
@@ -68,6 +107,7 @@ pub struct InputProps {
 ///         }
 ///         Input {
 ///             value: value.read().clone(),
+///             placeholder: "Type something...",
 ///             onchange: move |e| {
 ///                  value.set(e)
 ///             }
@@ -82,6 +122,9 @@ pub fn Input(
         value,
         onchange,
         mode,
+        placeholder,
+        onvalidate,
+        onkeydown,
     }: InputProps,
 ) -> Element {
     let platform = use_platform();
@@ -110,8 +153,30 @@ pub fn Input(
 
     let onkeydown = move |e: Event<KeyboardData>| {
         if focus.is_focused() && e.data.key != Key::Enter {
-            editable.process_event(&EditableEvent::KeyDown(e.data));
-            onchange.call(editable.editor().peek().to_string());
+            // Kept to revert the edit if it's not valid
+            let previous = onvalidate.is_some().then(|| {
+                let editor = editable.editor().peek();
+                (editor.to_string(), editor.cursor_pos())
+            });
+
+            editable.process_event(&EditableEvent::KeyDown(e.data.clone()));
+            let text = editable.editor().peek().to_string();
+
+            if let Some((onvalidate, (previous_text, previous_cursor))) = onvalidate.zip(previous) {
+                let validator = InputValidator::new(text.clone());
+                onvalidate.call(validator.clone());
+                if !validator.is_valid() {
+                    let mut editor = editable.editor_mut().write();
+                    editor.set(&previous_text);
+                    editor.set_cursor_pos(previous_cursor);
+                    return;
+                }
+            }
+
+            onchange.call(text);
+            if let Some(onkeydown) = &onkeydown {
+                onkeydown.call(e);
+            }
         }
     };
 
@@ -161,10 +226,18 @@ pub fn Input(
         width,
         margin,
         corner_radius,
+        caret_color,
+        selection_color,
         font_theme: FontTheme { color },
+        placeholder_font_theme: FontTheme {
+            color: placeholder_color,
+        },
         ..
     } = theme;
 
+    // The placeholder is painted over the empty paragraph, so the caret and the selection keep working with the real value
+    let placeholder = placeholder.filter(|_| value.is_empty());
+
     rsx!(
         rect {
             width: "{width}",
@@ -192,13 +265,28 @@ pub fn Input(
                 cursor_id: "0",
                 cursor_index: "{cursor_char}",
                 cursor_mode: "editable",
-                cursor_color: "{color}",
+                cursor_color: "{caret_color}",
                 max_lines: "1",
                 highlights,
+                highlight_color: "{selection_color}",
                 text {
                     "{text}"
                 }
             }
+            if let Some(placeholder) = placeholder.as_ref() {
+                rect {
+                    position: "absolute",
+                    padding: "8 12",
+                    width: "100%",
+                    label {
+                        width: "100%",
+                        color: "{placeholder_color}",
+                        max_lines: "1",
+                        text_overflow: "ellipsis",
+                        "{placeholder}"
+                    }
+                }
+            }
         }
     )
 }
@@ -249,10 +337,60 @@ mod test {
             key: Key::Character("d".to_string()),
             code: Code::KeyD,
             modifiers: Modifiers::default(),
+            repeat: false,
         });
         utils.wait_for_update().await;
 
         // Check that "d" has been written into the input.
         assert_eq!(text.get(0).text(), Some("Hello, World"));
     }
+
+    #[tokio::test]
+    pub async fn input_placeholder() {
+        fn input_placeholder_app() -> Element {
+            let mut value = use_signal(String::new);
+
+            rsx!(Input {
+                value: value.read().clone(),
+                placeholder: "Search",
+                onchange: move |new_value| {
+                    value.set(new_value);
+                }
+            },)
+        }
+
+        let mut utils = launch_test(input_placeholder_app);
+        let root = utils.root();
+        utils.wait_for_update().await;
+
+        // The placeholder is shown over the empty value
+        let text = root.get(0).get(0).get(0);
+        assert_eq!(text.get(0).text(), Some(""));
+        let placeholder = root.get(0).get(1).get(0);
+        assert_eq!(placeholder.get(0).text(), Some("Search"));
+
+        // Focus the input
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (20., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Write "a"
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("a".to_string()),
+            code: Code::KeyA,
+            modifiers: Modifiers::default(),
+            repeat: false,
+        });
+        utils.wait_for_update().await;
+
+        // The placeholder is gone and the value was not affected by it
+        let text = root.get(0).get(0).get(0);
+        assert_eq!(text.get(0).text(), Some("a"));
+        assert!(root.get(0).get(1).is_placeholder());
+    }
 }
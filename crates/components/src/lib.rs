@@ -7,9 +7,11 @@ mod body;
 mod button;
 mod canvas;
 mod checkbox;
+mod collapsible;
 mod cursor_area;
 mod drag_drop;
 mod dropdown;
+mod error_guard;
 mod gesture_area;
 mod graph;
 mod hooks;
@@ -21,13 +23,20 @@ mod loader;
 mod menu;
 mod native_router;
 mod network_image;
+mod number_input;
 mod popup;
+mod portal;
 mod progress_bar;
 mod radio;
+mod radio_group;
+mod safe_area;
+mod scrim;
 mod scroll_views;
 mod sidebar;
 mod slider;
 mod snackbar;
+mod split_pane;
+mod suspense;
 mod switch;
 mod table;
 mod theme;
@@ -40,9 +49,11 @@ pub use body::*;
 pub use button::*;
 pub use canvas::*;
 pub use checkbox::*;
+pub use collapsible::*;
 pub use cursor_area::*;
 pub use drag_drop::*;
 pub use dropdown::*;
+pub use error_guard::*;
 pub use gesture_area::*;
 pub use graph::*;
 pub use hooks::*;
@@ -54,13 +65,20 @@ pub use loader::*;
 pub use menu::*;
 pub use native_router::*;
 pub use network_image::*;
+pub use number_input::*;
 pub use popup::*;
+pub use portal::*;
 pub use progress_bar::*;
 pub use radio::*;
+pub use radio_group::*;
+pub use safe_area::*;
+pub use scrim::*;
 pub use scroll_views::*;
 pub use sidebar::*;
 pub use slider::*;
 pub use snackbar::*;
+pub use split_pane::*;
+pub use suspense::*;
 pub use switch::*;
 pub use table::*;
 pub use theme::*;
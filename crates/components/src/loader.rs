@@ -1,8 +1,6 @@
 use dioxus::prelude::*;
 use freya_elements::elements as dioxus_elements;
-use freya_hooks::{
-    use_animation, use_applied_theme, AnimNum, LoaderTheme, LoaderThemeWith, OnFinish,
-};
+use freya_hooks::{use_applied_theme, LoaderTheme, LoaderThemeWith};
 
 /// Properties for the [`Loader`] component.
 #[derive(Props, Clone, PartialEq)]
@@ -16,18 +14,10 @@ pub struct LoaderProps {
 #[allow(non_snake_case)]
 pub fn Loader(props: LoaderProps) -> Element {
     let theme = use_applied_theme!(&props.theme, loader);
-    let anim = use_animation(|ctx| {
-        ctx.auto_start(true);
-        ctx.on_finish(OnFinish::Restart);
-        ctx.with(AnimNum::new(0.0, 360.0).time(650))
-    });
-
     let LoaderTheme { primary_color } = theme;
 
-    let degrees = anim.get().read().as_f32();
-
     rsx!(svg {
-        rotate: "{degrees}deg",
+        animation: "spin 650ms linear infinite",
         width: "48",
         height: "48",
         svg_content: r#"
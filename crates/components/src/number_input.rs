@@ -0,0 +1,272 @@
+use dioxus::prelude::*;
+use freya_elements::events::{keyboard::Key, KeyboardEvent};
+use freya_hooks::InputThemeWith;
+
+use crate::{Input, InputValidator};
+
+/// Properties for the [`NumberInput`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct NumberInputProps {
+    /// Theme override.
+    pub theme: Option<InputThemeWith>,
+    /// Current value of the NumberInput.
+    pub value: f64,
+    /// Handler for the `onchange` event, called with the number once the text is a valid one.
+    pub onchange: EventHandler<f64>,
+    /// Lowest value accepted.
+    #[props(default = f64::MIN)]
+    pub min: f64,
+    /// Highest value accepted.
+    #[props(default = f64::MAX)]
+    pub max: f64,
+    /// Amount added or subtracted by the `ArrowUp` and `ArrowDown` keys.
+    #[props(default = 1.0)]
+    pub step: f64,
+    /// Text to show in a dimmed color while the NumberInput is empty.
+    pub placeholder: Option<String>,
+}
+
+/// Check if the text of a [`NumberInput`] can be kept while editing, even if it's not a number yet.
+///
+/// The numbers out of bounds are only kept when typing more digits could bring them back,
+/// e.g. `5` with a minimum of `10` is kept as it could become `50`, but `150` with a maximum of `100` is not.
+fn is_editable_number(text: &str, min: f64, max: f64) -> bool {
+    let digits = text.strip_prefix('-').filter(|_| min < 0.0).unwrap_or(text);
+    let is_number_like = digits
+        .splitn(2, '.')
+        .all(|part| part.chars().all(|char| char.is_ascii_digit()));
+    if !is_number_like {
+        return false;
+    }
+
+    match text.parse::<f64>() {
+        Ok(number) if number >= 0.0 => number <= max,
+        Ok(number) => number >= min,
+        // Empty, or just a sign or a decimal point
+        Err(_) => true,
+    }
+}
+
+/// Get the number of decimals of the given step, so the values are rounded to it.
+fn step_decimals(step: f64) -> usize {
+    let step = step.to_string();
+    step.split_once('.')
+        .map(|(_, decimals)| decimals.len())
+        .unwrap_or_default()
+}
+
+/// Format a number with the decimals of the step.
+fn format_number(value: f64, step: f64) -> String {
+    let text = format!("{value:.*}", step_decimals(step));
+    // Avoid showing `-0`
+    if text.parse::<f64>() == Ok(0.0) {
+        text.trim_start_matches('-').to_string()
+    } else {
+        text
+    }
+}
+
+/// [`Input`] that only accepts numbers within `min` and `max`, and steps them with the `ArrowUp` and `ArrowDown` keys.
+///
+/// Incomplete numbers like a lone `-` are kept while editing, but `onchange` is only called once the text is a valid number.
+///
+/// # Styling
+/// Inherits the [`InputTheme`](freya_hooks::InputTheme) theme.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut quantity = use_signal(|| 1.0);
+///
+///     rsx!(
+///         label {
+///             "Quantity: {quantity}"
+///         }
+///         NumberInput {
+///             value: quantity(),
+///             min: 1.0,
+///             max: 99.0,
+///             onchange: move |value| quantity.set(value)
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn NumberInput(
+    NumberInputProps {
+        theme,
+        value,
+        onchange,
+        min,
+        max,
+        step,
+        placeholder,
+    }: NumberInputProps,
+) -> Element {
+    let mut text = use_signal(|| format_number(value, step));
+    // Last value the text was written for, so it's only replaced when the value is changed from outside
+    let mut synced_value = use_signal(|| value);
+
+    if value != *synced_value.peek() {
+        synced_value.set(value);
+        text.set(format_number(value, step));
+    }
+
+    let mut change_value = move |new_value: f64| {
+        synced_value.set(new_value);
+        onchange.call(new_value);
+    };
+
+    let onvalidate = move |validator: InputValidator| {
+        validator.set_valid(is_editable_number(validator.text(), min, max));
+    };
+
+    let onchange_text = move |new_text: String| {
+        if new_text == *text.peek() {
+            return;
+        }
+        if let Ok(new_value) = new_text.parse::<f64>() {
+            if (min..=max).contains(&new_value) {
+                change_value(new_value);
+            }
+        }
+        text.set(new_text);
+    };
+
+    let onkeydown = move |e: KeyboardEvent| {
+        let direction = match e.key {
+            Key::ArrowUp => 1.0,
+            Key::ArrowDown => -1.0,
+            _ => return,
+        };
+        let value = *synced_value.peek();
+        let new_text = format_number((value + step * direction).clamp(min, max), step);
+        // Rounded to the decimals of the step
+        let new_value = new_text.parse().unwrap_or(value);
+        text.set(new_text);
+        change_value(new_value);
+    };
+
+    rsx!(Input {
+        theme,
+        value: text(),
+        placeholder,
+        onvalidate,
+        onchange: onchange_text,
+        onkeydown,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use super::{format_number, is_editable_number};
+
+    #[test]
+    pub fn editable_numbers() {
+        assert!(is_editable_number("", 0.0, 10.0));
+        assert!(is_editable_number("4.5", 0.0, 10.0));
+        assert!(is_editable_number("-", -10.0, 10.0));
+        assert!(is_editable_number("-.", -10.0, 10.0));
+        // These can't become valid by typing more digits
+        assert!(!is_editable_number("11", 0.0, 10.0));
+        assert!(!is_editable_number("-11", -10.0, 10.0));
+        assert!(!is_editable_number("-", 0.0, 10.0));
+        // But these can
+        assert!(is_editable_number("5", 10.0, 100.0));
+        assert!(is_editable_number("-5", -100.0, -10.0));
+        // Not numbers at all
+        assert!(!is_editable_number("1.2.3", 0.0, 10.0));
+        assert!(!is_editable_number("1e3", 0.0, 10.0));
+        assert!(!is_editable_number("--1", -10.0, 10.0));
+    }
+
+    #[test]
+    pub fn formatted_numbers() {
+        assert_eq!(format_number(2.0, 1.0), "2");
+        assert_eq!(format_number(0.1 + 0.2, 0.1), "0.3");
+        assert_eq!(format_number(-0.001, 0.5), "0.0");
+    }
+
+    #[tokio::test]
+    pub async fn number_input() {
+        fn number_input_app() -> Element {
+            let mut value = use_signal(|| 8.0);
+
+            rsx!(
+                NumberInput {
+                    value: value(),
+                    min: -10.0,
+                    max: 10.0,
+                    step: 2.0,
+                    onchange: move |new_value| value.set(new_value)
+                }
+                label { "{value}" }
+            )
+        }
+
+        let mut utils = launch_test(number_input_app);
+        let root = utils.root();
+        let text = root.get(0).get(0).get(0);
+        let label = root.get(1);
+        utils.wait_for_update().await;
+
+        let press = |key: Key, code: Code| PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key,
+            code,
+            modifiers: Modifiers::default(),
+            repeat: false,
+        };
+
+        // Focus the input at the end of the text
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (115., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Letters are rejected
+        utils.push_event(press(Key::Character("a".to_string()), Code::KeyA));
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("8"));
+
+        // Stepping is clamped to the maximum
+        utils.push_event(press(Key::ArrowUp, Code::ArrowUp));
+        utils.wait_for_update().await;
+        utils.push_event(press(Key::ArrowUp, Code::ArrowUp));
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("10"));
+        assert_eq!(label.get(0).text(), Some("10"));
+
+        // A lone sign is kept while editing, without changing the value
+        utils.push_event(press(Key::End, Code::End));
+        utils.wait_for_update().await;
+        for _ in 0..2 {
+            utils.push_event(press(Key::Backspace, Code::Backspace));
+            utils.wait_for_update().await;
+        }
+        utils.push_event(press(Key::Character("-".to_string()), Code::Minus));
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("-"));
+        assert_eq!(label.get(0).text(), Some("10"));
+
+        // Until it's a valid number
+        utils.push_event(press(Key::Character("3".to_string()), Code::Digit3));
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("-3"));
+        assert_eq!(label.get(0).text(), Some("-3"));
+
+        // Numbers out of bounds are rejected
+        utils.push_event(press(Key::Character("3".to_string()), Code::Digit3));
+        utils.wait_for_update().await;
+        assert_eq!(text.get(0).text(), Some("-3"));
+    }
+}
@@ -11,10 +11,10 @@ pub fn PopupBackground(children: Element) -> Element {
         height: "100v",
         width: "100v",
         background: "rgb(0, 0, 0, 150)",
-        position: "absolute",
+        position: "global",
         position_top: "0",
         position_left: "0",
-        layer: "-99",
+        layer: "overlay",
         main_align: "center",
         cross_align: "center",
         {children}
@@ -0,0 +1,128 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+
+/// Render the children above the rest of the app, laid out inside the whole Window instead of
+/// the area of its parent, and without being clipped or scrolled by its ancestors.
+///
+/// The children stay in the scope of the component that renders the Portal, so they keep reading
+/// its state, and their events still bubble up to the ancestors of the Portal.
+/// Useful for modals, tooltips or dropdown menus.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut show_modal = use_signal(|| false);
+///
+///     rsx!(
+///         rect {
+///             width: "100",
+///             height: "100",
+///             overflow: "clip",
+///             Button {
+///                 onclick: move |_| show_modal.toggle(),
+///                 label {
+///                     "Toggle"
+///                 }
+///             }
+///             if show_modal() {
+///                 Portal {
+///                     rect {
+///                         width: "100%",
+///                         height: "100%",
+///                         main_align: "center",
+///                         cross_align: "center",
+///                         background: "rgb(0, 0, 0, 150)",
+///                         onclick: move |_| show_modal.set(false),
+///                         label {
+///                             color: "white",
+///                             "Not clipped by the parent"
+///                         }
+///                     }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+///
+#[allow(non_snake_case)]
+#[component]
+pub fn Portal(
+    /// Inner children for the Portal.
+    children: Element,
+) -> Element {
+    rsx!(
+        rect {
+            position: "global",
+            position_top: "0",
+            position_left: "0",
+            width: "100%",
+            height: "100%",
+            layer: "overlay",
+            {children}
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn portal() {
+        fn portal_app() -> Element {
+            let mut clicks = use_signal(|| 0);
+
+            rsx!(
+                rect {
+                    width: "100",
+                    height: "100",
+                    overflow: "clip",
+                    offset_y: "-50",
+                    Portal {
+                        rect {
+                            width: "50%",
+                            height: "200",
+                            position: "absolute",
+                            position_top: "200",
+                            position_left: "200",
+                            onclick: move |_| clicks += 1,
+                            label {
+                                "{clicks}"
+                            }
+                        }
+                    }
+                }
+                rect {
+                    width: "fill",
+                    height: "fill",
+                    background: "red",
+                }
+            )
+        }
+
+        let mut utils = launch_test(portal_app);
+        utils.wait_for_update().await;
+
+        // Laid out inside the Window, ignoring the area and scroll of the parent
+        let content = utils.root().get(0).get(0).get(0);
+        assert_eq!(
+            content.layout().unwrap().area,
+            Rect::new(Point2D::new(200.0, 200.0), Size2D::new(250.0, 200.0))
+        );
+
+        // Painted above the red rect and not clipped by the parent, so it receives the click
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (300.0, 300.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0).get(0).get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("1"));
+    }
+}
@@ -0,0 +1,364 @@
+use dioxus::dioxus_core::AttributeValue;
+use dioxus::prelude::*;
+use freya_core::types::AccessibilityId;
+use freya_elements::elements as dioxus_elements;
+use freya_elements::events::keyboard::Key;
+use freya_elements::events::KeyboardEvent;
+use freya_hooks::{use_applied_theme, use_focus, use_platform, TileTheme, TileThemeWith, UseFocus};
+use winit::window::CursorIcon;
+
+use crate::{Radio, TileStatus};
+
+/// Shared state of a [`RadioGroup`] and its items.
+pub struct RadioGroupContext<T: 'static> {
+    selected: Signal<T>,
+    items: Signal<Vec<(T, AccessibilityId)>>,
+    onchange: EventHandler<T>,
+}
+
+impl<T> Clone for RadioGroupContext<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for RadioGroupContext<T> {}
+
+/// Properties for the [`RadioGroup`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioGroupProps<T: 'static + Clone + PartialEq> {
+    /// Selected value.
+    pub value: T,
+    /// Handler for the `onchange` event, called with the newly selected value.
+    pub onchange: EventHandler<T>,
+    /// Selectable items, like [`RadioGroupItem`].
+    pub children: Element,
+}
+
+/// Group of mutually exclusive options, use alongside [`RadioGroupItem`] or [`use_radio_group_item`].
+///
+/// Only the selected item can be focused with the `Tab` key, and the arrow keys move the selection
+/// between the items of the group in the order they were mounted.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut selected = use_signal(|| "A");
+///
+///     rsx!(
+///         RadioGroup {
+///             value: selected(),
+///             onchange: move |value| selected.set(value),
+///             for value in ["A", "B", "C"] {
+///                 RadioGroupItem {
+///                     value,
+///                     label { "{value}" }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn RadioGroup<T>(
+    RadioGroupProps {
+        value,
+        onchange,
+        children,
+    }: RadioGroupProps<T>,
+) -> Element
+where
+    T: PartialEq + Clone + 'static,
+{
+    let mut selected = use_signal(|| value.clone());
+    let items = use_signal(Vec::<(T, AccessibilityId)>::new);
+    let mut focused_id = use_context::<Signal<AccessibilityId>>();
+    use_context_provider(|| RadioGroupContext {
+        selected,
+        items,
+        onchange,
+    });
+
+    // Update the provided value if the passed value changes
+    use_effect(use_reactive(&value, move |value| {
+        *selected.write() = value;
+    }));
+
+    let onkeydown = move |e: KeyboardEvent| {
+        let items = items.peek();
+        let Some(index) = items.iter().position(|(_, id)| *id == *focused_id.peek()) else {
+            return;
+        };
+
+        let next_index = match e.key {
+            Key::ArrowDown | Key::ArrowRight => (index + 1) % items.len(),
+            Key::ArrowUp | Key::ArrowLeft => (index + items.len() - 1) % items.len(),
+            _ => return,
+        };
+
+        let (value, id) = items[next_index].clone();
+        focused_id.set(id);
+        onchange.call(value);
+    };
+
+    rsx!(
+        rect {
+            role: "radioGroup",
+            onkeydown,
+            {children}
+        }
+    )
+}
+
+/// Manage an item of a [`RadioGroup`], created with [`use_radio_group_item`].
+pub struct UseRadioGroupItem<T: 'static> {
+    value: T,
+    focus: UseFocus,
+    group: RadioGroupContext<T>,
+}
+
+impl<T: Clone> Clone for UseRadioGroupItem<T> {
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            focus: self.focus,
+            group: self.group,
+        }
+    }
+}
+
+impl<T: PartialEq + Clone> UseRadioGroupItem<T> {
+    /// Create a node focus ID attribute.
+    pub fn attribute(&self) -> AttributeValue {
+        self.focus.attribute()
+    }
+
+    /// Get the value for the `tab_focusable` attribute.
+    /// Only the selected item is focusable with the `Tab` key, or the first one if none is selected.
+    pub fn tab_focusable(&self) -> &'static str {
+        let selected = self.group.selected.read();
+        let items = self.group.items.read();
+        let is_tab_focusable = if items.iter().any(|(value, _)| *value == *selected) {
+            self.value == *selected
+        } else {
+            items.first().map(|(_, id)| *id) == Some(self.focus.id())
+        };
+
+        if is_tab_focusable {
+            "true"
+        } else {
+            "false"
+        }
+    }
+
+    /// Check if this item is selected.
+    pub fn is_selected(&self) -> bool {
+        *self.group.selected.read() == self.value
+    }
+
+    /// Check if this item is focused.
+    pub fn is_focused(&self) -> bool {
+        self.focus.is_focused()
+    }
+
+    /// Focus and select this item.
+    pub fn select(&mut self) {
+        self.focus.focus();
+        if !self.is_selected() {
+            self.group.onchange.call(self.value.clone());
+        }
+    }
+}
+
+/// Register an item with the given `value` in the closest [`RadioGroup`],
+/// useful to build custom items such as the segments of a segmented control.
+///
+/// Attach [`UseRadioGroupItem::attribute`] as `focus_id` and [`UseRadioGroupItem::tab_focusable`]
+/// as `tab_focusable` to the element of the item.
+pub fn use_radio_group_item<T: PartialEq + Clone + 'static>(value: T) -> UseRadioGroupItem<T> {
+    let mut group = use_context::<RadioGroupContext<T>>();
+    let focus = use_focus();
+
+    use_hook(|| {
+        group.items.write().push((value.clone(), focus.id()));
+    });
+
+    // Keep the registered value up to date
+    let is_outdated = group
+        .items
+        .peek()
+        .iter()
+        .any(|(item_value, id)| *id == focus.id() && *item_value != value);
+    if is_outdated {
+        for (item_value, id) in group.items.write().iter_mut() {
+            if *id == focus.id() {
+                *item_value = value.clone();
+            }
+        }
+    }
+
+    use_drop(move || {
+        group.items.write().retain(|(_, id)| *id != focus.id());
+    });
+
+    UseRadioGroupItem {
+        value,
+        focus,
+        group,
+    }
+}
+
+/// Properties for the [`RadioGroupItem`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioGroupItemProps<T: 'static + Clone + PartialEq> {
+    /// Theme override.
+    pub theme: Option<TileThemeWith>,
+    /// Value of this item.
+    pub value: T,
+    /// Inner children for the item.
+    pub children: Element,
+}
+
+/// Item of a [`RadioGroup`], showing a [`Radio`] before its children.
+///
+/// # Styling
+/// Inherits the [`TileTheme`](freya_hooks::TileTheme) theme.
+#[allow(non_snake_case)]
+pub fn RadioGroupItem<T>(
+    RadioGroupItemProps {
+        theme,
+        value,
+        children,
+    }: RadioGroupItemProps<T>,
+) -> Element
+where
+    T: PartialEq + Clone + 'static,
+{
+    let mut item = use_radio_group_item(value);
+    let mut status = use_signal(TileStatus::default);
+    let platform = use_platform();
+    let TileTheme { padding } = use_applied_theme!(&theme, tile);
+
+    let focus_id = item.attribute();
+    let tab_focusable = item.tab_focusable();
+    let is_selected = item.is_selected();
+
+    use_drop(move || {
+        if *status.peek() == TileStatus::Hovering {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let onclick = move |_| item.select();
+
+    let onmouseenter = move |_| {
+        platform.set_cursor(CursorIcon::Pointer);
+        status.set(TileStatus::Hovering);
+    };
+
+    let onmouseleave = move |_| {
+        platform.set_cursor(CursorIcon::default());
+        status.set(TileStatus::default());
+    };
+
+    rsx!(
+        rect {
+            onclick,
+            onmouseenter,
+            onmouseleave,
+            focus_id,
+            tab_focusable,
+            role: "radioButton",
+            aria_checked: "{is_selected}",
+            direction: "horizontal",
+            padding: "{padding}",
+            cross_align: "center",
+            rect {
+                padding: "4",
+                Radio {
+                    selected: is_selected,
+                }
+            }
+            {children}
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn radio_group() {
+        fn radio_group_app() -> Element {
+            let mut selected = use_signal(|| "A");
+
+            rsx!(
+                RadioGroup {
+                    value: selected(),
+                    onchange: move |value| selected.set(value),
+                    for value in ["A", "B", "C"] {
+                        RadioGroupItem {
+                            value,
+                            label { "{value}" }
+                        }
+                    }
+                }
+                Button {
+                    label { "Submit" }
+                }
+                label {
+                    "{selected}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(radio_group_app);
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(2);
+        assert_eq!(label.get(0).text(), Some("A"));
+
+        let press = |key: Key, code: Code| PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key,
+            code,
+            modifiers: Modifiers::default(),
+            repeat: false,
+        };
+
+        // Focus the selected item
+        utils.push_event(press(Key::Tab, Code::Tab));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Move the selection with the arrows
+        utils.push_event(press(Key::ArrowDown, Code::ArrowDown));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("B"));
+
+        utils.push_event(press(Key::ArrowUp, Code::ArrowUp));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.push_event(press(Key::ArrowUp, Code::ArrowUp));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("C"));
+
+        // Tab goes to the button and then back to the selected item, skipping the rest
+        utils.push_event(press(Key::Tab, Code::Tab));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.push_event(press(Key::Tab, Code::Tab));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.push_event(press(Key::ArrowLeft, Code::ArrowLeft));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(label.get(0).text(), Some("B"));
+    }
+}
@@ -0,0 +1,87 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_hooks::use_window_insets;
+
+/// Properties for the [`SafeArea`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct SafeAreaProps {
+    /// Inner children for the SafeArea.
+    pub children: Element,
+}
+
+/// Pad its children with the insets of the Window, so they keep clear of the custom title bar
+/// and the edges of the Window. See [`use_window_insets`](freya_hooks::use_window_insets).
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         SafeArea {
+///             label {
+///                 "Hello, World!"
+///             }
+///         }
+///     )
+/// }
+/// ```
+///
+#[allow(non_snake_case)]
+pub fn SafeArea(SafeAreaProps { children }: SafeAreaProps) -> Element {
+    let insets = use_window_insets();
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            padding: "{insets.read().as_padding()}",
+            {children}
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn safe_area() {
+        fn safe_area_app() -> Element {
+            rsx!(
+                SafeArea {
+                    rect {
+                        width: "fill",
+                        height: "fill",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test_with_config(
+            safe_area_app,
+            TestingConfig {
+                window_insets: WindowInsets::new(30.0, 8.0, 8.0, 8.0),
+                ..TestingConfig::default()
+            },
+        );
+        utils.wait_for_update().await;
+
+        let content = utils.root().get(0).get(0);
+        assert_eq!(content.layout().unwrap().area.min_x(), 8.0);
+        assert_eq!(content.layout().unwrap().area.min_y(), 30.0);
+        assert_eq!(content.layout().unwrap().area.width(), 484.0);
+        assert_eq!(content.layout().unwrap().area.height(), 462.0);
+
+        // The Window got maximized
+        utils.set_window_insets(WindowInsets::new(30.0, 0.0, 0.0, 0.0));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let content = utils.root().get(0).get(0);
+        assert_eq!(content.layout().unwrap().area.min_x(), 0.0);
+        assert_eq!(content.layout().unwrap().area.width(), 500.0);
+        assert_eq!(content.layout().unwrap().area.height(), 470.0);
+    }
+}
@@ -0,0 +1,172 @@
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_elements::events::MouseEvent;
+use freya_hooks::{use_animation, use_applied_theme, AnimNum, ScrimTheme, ScrimThemeWith};
+
+/// Dimmed layer that covers the whole Window, above everything else, with its children centered on top of it.
+/// Useful as the backdrop of modal dialogs.
+///
+/// Clicking the scrim outside of its children calls `ondismiss`, and the clicks never reach the elements behind it.
+/// It fades in when `visible` becomes `true`, and fades out before unmounting its children when it becomes `false`.
+///
+/// # Styling
+/// Inherits the [`ScrimTheme`](freya_hooks::ScrimTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut show_modal = use_signal(|| false);
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| show_modal.set(true),
+///             label {
+///                 "Open"
+///             }
+///         }
+///         Scrim {
+///             visible: show_modal(),
+///             ondismiss: move |_| show_modal.set(false),
+///             rect {
+///                 padding: "20",
+///                 corner_radius: "8",
+///                 background: "white",
+///                 label {
+///                     "Click outside to close me"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Scrim(
+    /// Theme override.
+    theme: Option<ScrimThemeWith>,
+    /// Whether the scrim and its children are shown.
+    visible: bool,
+    /// Handler for the `ondismiss` event, called when the scrim is clicked outside of its children.
+    ondismiss: Option<EventHandler>,
+    /// Content shown above the scrim, e.g. a dialog.
+    children: Element,
+) -> Element {
+    let ScrimTheme {
+        background,
+        backdrop_blur,
+    } = use_applied_theme!(&theme, scrim);
+    let animation = use_animation(|ctx| ctx.with(AnimNum::new(0., 1.).time(150)));
+
+    use_effect(use_reactive(&visible, move |visible| {
+        if visible {
+            animation.start();
+        } else if animation.peek_has_run_yet() {
+            animation.reverse();
+        }
+    }));
+
+    let opacity = animation.get().read().as_f32();
+
+    // Keep it mounted while it fades out
+    if !visible && opacity == 0.0 {
+        return None;
+    }
+
+    let onclick = move |_: MouseEvent| {
+        if let Some(ondismiss) = &ondismiss {
+            ondismiss.call(());
+        }
+    };
+
+    rsx!(
+        rect {
+            height: "100v",
+            width: "100v",
+            position: "global",
+            position_top: "0",
+            position_left: "0",
+            layer: "overlay",
+            main_align: "center",
+            cross_align: "center",
+            opacity: "{opacity}",
+            rect {
+                height: "100%",
+                width: "100%",
+                position: "absolute",
+                position_top: "0",
+                position_left: "0",
+                background: "{background}",
+                backdrop_blur: "{backdrop_blur}",
+                onclick,
+            }
+            {children}
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+    use tokio::time::sleep;
+
+    #[tokio::test]
+    pub async fn scrim() {
+        fn scrim_app() -> Element {
+            let mut visible = use_signal(|| true);
+            let mut clicks = use_signal(|| 0);
+
+            rsx!(
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    onclick: move |_| clicks += 1,
+                    label {
+                        "{clicks}"
+                    }
+                }
+                Scrim {
+                    visible: visible(),
+                    ondismiss: move |_| visible.set(false),
+                    rect {
+                        width: "100",
+                        height: "100",
+                        background: "white",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scrim_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Clicking the content does not dismiss it
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (250.0, 250.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert!(utils.root().get(1).is_element());
+
+        // Clicking outside of the content dismisses it without reaching the elements behind
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (20.0, 20.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("0"));
+
+        // It is unmounted once it has faded out
+        sleep(Duration::from_millis(200)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert!(utils.root().get(1).is_placeholder());
+    }
+}
@@ -1,12 +1,18 @@
 mod scroll_bar;
+mod scroll_metrics;
+mod scroll_positions;
 mod scroll_thumb;
 mod scroll_view;
+mod smooth_scroll;
 mod virtual_scroll_view;
 
-use freya_elements::events::{keyboard::Key, KeyboardEvent};
+use freya_elements::events::{keyboard::Key, KeyboardEvent, WheelEvent};
 pub use scroll_bar::*;
+pub use scroll_metrics::*;
+pub use scroll_positions::*;
 pub use scroll_thumb::*;
 pub use scroll_view::*;
+pub use smooth_scroll::*;
 pub use virtual_scroll_view::*;
 
 // Holding alt while scrolling makes it 5x faster (VSCode behavior).
@@ -42,21 +48,31 @@ pub fn is_scrollbar_visible(
     }
 }
 
+/// Length of the scrollbar thumb, proportional to how much of the content is visible but never shorter than `min_thumb_size`.
+#[doc(hidden)]
+pub fn get_scrollbar_thumb_size(inner_size: f32, viewport_size: f32, min_thumb_size: f32) -> f32 {
+    let viewable_ratio = viewport_size / inner_size;
+    (viewport_size * viewable_ratio)
+        .max(min_thumb_size)
+        .min(viewport_size)
+}
+
 #[doc(hidden)]
 pub fn get_scrollbar_pos_and_size(
     inner_size: f32,
     viewport_size: f32,
     scroll_position: f32,
+    min_thumb_size: f32,
 ) -> (f32, f32) {
-    let scrollbar_height = if viewport_size >= inner_size {
-        inner_size
-    } else {
-        let viewable_ratio_height = viewport_size / inner_size;
-        viewport_size * viewable_ratio_height
-    };
-    let scroll_position = (100.0 / inner_size) * -scroll_position;
-    let scrollbar_position = (scroll_position / 100.0) * viewport_size;
-    (scrollbar_position, scrollbar_height)
+    if viewport_size >= inner_size {
+        return (0.0, inner_size);
+    }
+
+    // The thumb goes through the rest of the track while the content is scrolled from the start to the end
+    let scrollbar_size = get_scrollbar_thumb_size(inner_size, viewport_size, min_thumb_size);
+    let scrolled_ratio = -scroll_position / (inner_size - viewport_size);
+    let scrollbar_position = scrolled_ratio * (viewport_size - scrollbar_size);
+    (scrollbar_position, scrollbar_size)
 }
 
 #[doc(hidden)]
@@ -64,14 +80,19 @@ pub fn get_scroll_position_from_cursor(
     cursor_position: f32,
     inner_size: f32,
     viewport_size: f32,
+    min_thumb_size: f32,
 ) -> i32 {
-    let per = 100.0 / viewport_size * cursor_position;
-
     if viewport_size >= inner_size {
         return 0;
     }
 
-    let new_position = -(inner_size / 100.0 * per);
+    let scrollbar_size = get_scrollbar_thumb_size(inner_size, viewport_size, min_thumb_size);
+    let track_size = viewport_size - scrollbar_size;
+    if track_size <= 0.0 {
+        return 0;
+    }
+
+    let new_position = -(cursor_position / track_size * (inner_size - viewport_size));
 
     if new_position >= 0.0 {
         return 0;
@@ -107,6 +128,29 @@ pub fn get_scroll_position_from_wheel(
     new_position as i32
 }
 
+/// Consume the part of the wheel movement used to scroll from `scroll_position` to `new_scroll_position`.
+/// The event keeps propagating with the rest of the movement to the ancestors, unless `scroll_chaining` is disabled.
+#[doc(hidden)]
+pub fn consume_wheel_movement(
+    e: &WheelEvent,
+    wheel_movement: f32,
+    speed_multiplier: f32,
+    scroll_position: f32,
+    new_scroll_position: f32,
+    scroll_chaining: bool,
+) {
+    let leftover_movement = wheel_movement - (new_scroll_position - scroll_position);
+
+    // Positions are rounded to whole pixels, so less than a pixel left means the whole movement was used
+    if !scroll_chaining || leftover_movement.abs() < 1.0 {
+        e.consume_delta(0.0, e.get_remaining_delta_y());
+        e.stop_propagation();
+    } else {
+        let used_movement = wheel_movement - leftover_movement;
+        e.consume_delta(0.0, (used_movement / speed_multiplier) as f64);
+    }
+}
+
 /// Limit the scroll position to the scroll view bounds to avoid overflows
 #[doc(hidden)]
 pub fn get_corrected_scroll_position(
@@ -154,10 +198,10 @@ pub fn manage_key_event(
             y = get_corrected_scroll_position(inner_height, viewport_height, y - y_line_delta)
         }
         Key::PageUp => {
-            y = get_corrected_scroll_position(inner_height, viewport_height, y + y_line_delta)
+            y = get_corrected_scroll_position(inner_height, viewport_height, y + y_page_delta)
         }
         Key::PageDown => {
-            y = get_corrected_scroll_position(inner_height, viewport_height, y - y_line_delta)
+            y = get_corrected_scroll_position(inner_height, viewport_height, y - y_page_delta)
         }
         Key::ArrowLeft => {
             x = get_corrected_scroll_position(inner_width, viewport_width, x + x_line_delta)
@@ -169,7 +213,7 @@ pub fn manage_key_event(
             y = 0.0;
         }
         Key::End => {
-            y = -inner_height;
+            y = get_corrected_scroll_position(inner_height, viewport_height, -inner_height);
         }
         _ => {}
     };
@@ -18,6 +18,9 @@ pub struct ScrollBarProps {
     #[props(default = "0".to_string(), into)]
     pub offset_y: String,
     pub clicking_scrollbar: bool,
+    /// Place it over the bottom-right edges of its parent instead of next to its siblings.
+    #[props(default = false)]
+    pub overlay: bool,
 }
 
 enum ScrollBarState {
@@ -36,6 +39,7 @@ pub fn ScrollBar(
         offset_y,
         theme,
         children,
+        overlay,
     }: ScrollBarProps,
 ) -> Element {
     let mut status = use_signal(|| ScrollBarState::Idle);
@@ -50,6 +54,8 @@ pub fn ScrollBar(
         ScrollBarState::Idle => "transparent",
     };
 
+    let position = if overlay { "absolute" } else { "stacked" };
+
     rsx!(
         rect {
             overflow: "clip",
@@ -58,6 +64,9 @@ pub fn ScrollBar(
             height: "{height}",
             offset_x: "{offset_x}",
             offset_y: "{offset_y}",
+            position: "{position}",
+            position_right: "0",
+            position_bottom: "0",
             background: "{background}",
             onmouseenter,
             onmouseleave,
@@ -0,0 +1,106 @@
+use dioxus::prelude::*;
+use torin::geometry::Size2D;
+
+/// Sizes and scroll position of a [`ScrollView`](crate::ScrollView) or [`VirtualScrollView`](crate::VirtualScrollView),
+/// see [`use_scroll_metrics`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollMetrics {
+    /// Size of the whole content, including the parts that are scrolled out of view.
+    pub content_size: Size2D,
+    /// Size of the visible part of the content.
+    pub viewport_size: Size2D,
+    /// How far the content is scrolled from its left edge.
+    pub offset_x: f32,
+    /// How far the content is scrolled from its top edge.
+    pub offset_y: f32,
+}
+
+impl ScrollMetrics {
+    /// Furthest the content can be scrolled horizontally, `0` when it doesn't overflow.
+    pub fn max_offset_x(&self) -> f32 {
+        (self.content_size.width - self.viewport_size.width).max(0.0)
+    }
+
+    /// Furthest the content can be scrolled vertically, `0` when it doesn't overflow.
+    pub fn max_offset_y(&self) -> f32 {
+        (self.content_size.height - self.viewport_size.height).max(0.0)
+    }
+
+    /// How much of the horizontal scroll has been done, from `0.0` to `1.0`.
+    pub fn progress_x(&self) -> f32 {
+        progress(self.offset_x, self.max_offset_x())
+    }
+
+    /// How much of the vertical scroll has been done, from `0.0` to `1.0`.
+    pub fn progress_y(&self) -> f32 {
+        progress(self.offset_y, self.max_offset_y())
+    }
+
+    /// Whether the content is scrolled to its right edge, which is always the case when it doesn't overflow.
+    pub fn is_at_end_x(&self) -> bool {
+        self.offset_x >= self.max_offset_x()
+    }
+
+    /// Whether the content is scrolled to its bottom edge, which is always the case when it doesn't overflow.
+    pub fn is_at_end_y(&self) -> bool {
+        self.offset_y >= self.max_offset_y()
+    }
+}
+
+/// Content that doesn't overflow is considered fully scrolled.
+fn progress(offset: f32, max_offset: f32) -> f32 {
+    if max_offset > 0.0 {
+        (offset / max_offset).clamp(0.0, 1.0)
+    } else {
+        1.0
+    }
+}
+
+/// Create a signal that gets updated with the [`ScrollMetrics`] of the scroll view it's passed to
+/// with the `scroll_metrics` prop, whenever its content, its size or its scroll position change.
+///
+/// Useful to build custom scroll indicators or "Jump to latest" buttons.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let scroll_metrics = use_scroll_metrics();
+///     let metrics = scroll_metrics();
+///
+///     rsx!(
+///         label {
+///             "Read {(metrics.progress_y() * 100.0).round()}%"
+///         }
+///         ScrollView {
+///             scroll_metrics,
+///             for i in 0..100 {
+///                 label { key: "{i}", "Message {i}" }
+///             }
+///         }
+///         if !metrics.is_at_end_y() {
+///             label { "There are newer messages below" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_scroll_metrics() -> Signal<ScrollMetrics> {
+    use_signal(ScrollMetrics::default)
+}
+
+/// Keep the signal given to a scroll view with the `scroll_metrics` prop up to date with its current metrics.
+pub(crate) fn use_sync_scroll_metrics(
+    scroll_metrics: Option<Signal<ScrollMetrics>>,
+    metrics: ScrollMetrics,
+) {
+    use_effect(use_reactive(
+        (&scroll_metrics, &metrics),
+        |(scroll_metrics, metrics)| {
+            if let Some(mut scroll_metrics) = scroll_metrics {
+                if *scroll_metrics.peek() != metrics {
+                    scroll_metrics.set(metrics);
+                }
+            }
+        },
+    ));
+}
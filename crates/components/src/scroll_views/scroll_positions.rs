@@ -0,0 +1,87 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use dioxus::prelude::*;
+
+/// Scroll positions saved by the [`ScrollView`](crate::ScrollView)s and [`VirtualScrollView`](crate::VirtualScrollView)s
+/// that have a `restoration_key`. A scroll view mounted with a key that has a saved position starts scrolled to it.
+#[derive(Clone, Default)]
+pub struct ScrollPositions(Rc<RefCell<HashMap<String, (i32, i32)>>>);
+
+impl ScrollPositions {
+    /// Get the saved `(x, y)` scroll position of a key.
+    pub fn get(&self, key: &str) -> Option<(i32, i32)> {
+        self.0.borrow().get(key).copied()
+    }
+
+    /// Save the `(x, y)` scroll position of a key, e.g. to open a route already scrolled to somewhere.
+    pub fn set(&self, key: impl Into<String>, position: (i32, i32)) {
+        self.0.borrow_mut().insert(key.into(), position);
+    }
+
+    /// Forget the scroll position of a key, so its scroll view starts from the beginning next time it's mounted.
+    pub fn remove(&self, key: &str) {
+        self.0.borrow_mut().remove(key);
+    }
+
+    /// Forget all the saved scroll positions.
+    pub fn clear(&self) {
+        self.0.borrow_mut().clear();
+    }
+}
+
+/// Access the scroll positions saved by the scroll views with a `restoration_key`, which are shared by the whole app.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut show_list = use_signal(|| true);
+///     let scroll_positions = use_scroll_positions();
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| show_list.toggle(),
+///             label { "Toggle" }
+///         }
+///         Button {
+///             onclick: move |_| scroll_positions.remove("list"),
+///             label { "Reset" }
+///         }
+///         if show_list() {
+///             ScrollView {
+///                 // Scrolled to where it was before being hidden
+///                 restoration_key: "list",
+///                 for i in 0..100 {
+///                     label { key: "{i}", "Item {i}" }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_scroll_positions() -> ScrollPositions {
+    use_root_context(ScrollPositions::default)
+}
+
+/// Scroll position to start from, and keep the saved position of the `restoration_key` up to date with the current one.
+pub(crate) fn use_scroll_restoration(
+    restoration_key: &Option<String>,
+) -> (Signal<i32>, Signal<i32>) {
+    let scroll_positions = use_scroll_positions();
+    let (restored_x, restored_y) = use_hook(|| {
+        restoration_key
+            .as_deref()
+            .and_then(|key| scroll_positions.get(key))
+            .unwrap_or_default()
+    });
+    let scrolled_x = use_signal(|| restored_x);
+    let scrolled_y = use_signal(|| restored_y);
+
+    use_effect(use_reactive(restoration_key, move |restoration_key| {
+        if let Some(key) = restoration_key {
+            scroll_positions.set(key, (scrolled_x(), scrolled_y()));
+        }
+    }));
+
+    (scrolled_x, scrolled_y)
+}
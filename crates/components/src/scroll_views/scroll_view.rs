@@ -1,14 +1,15 @@
 use dioxus::prelude::*;
 use freya_elements::elements as dioxus_elements;
-use freya_elements::events::{keyboard::Key, KeyboardEvent, MouseEvent, WheelEvent};
+use freya_elements::events::{keyboard::Key, KeyboardEvent, MouseEvent, ScrollEvent, WheelEvent};
 use freya_hooks::{
     use_applied_theme, use_focus, use_node, ScrollBarThemeWith, ScrollViewThemeWith,
 };
 
 use crate::{
-    get_container_size, get_corrected_scroll_position, get_scroll_position_from_cursor,
-    get_scroll_position_from_wheel, get_scrollbar_pos_and_size, is_scrollbar_visible,
-    manage_key_event, Axis, ScrollBar, ScrollThumb, SCROLL_SPEED_MULTIPLIER,
+    consume_wheel_movement, get_container_size, get_corrected_scroll_position,
+    get_scroll_position_from_cursor, get_scroll_position_from_wheel, get_scrollbar_pos_and_size,
+    is_scrollbar_visible, manage_key_event, use_scroll_restoration, use_smooth_scroll,
+    use_sync_scroll_metrics, Axis, ScrollBar, ScrollMetrics, ScrollThumb, SCROLL_SPEED_MULTIPLIER,
 };
 
 /// Properties for the [`ScrollView`] component.
@@ -23,16 +24,44 @@ pub struct ScrollViewProps {
     /// Direction of the ScrollView, `vertical` or `horizontal`.
     #[props(default = "vertical".to_string(), into)]
     pub direction: String,
-    /// Show the scrollbar, visible by default.
+    /// Show the scrollbars, only when the content overflows. Enabled by default.
     #[props(default = true, into)]
     pub show_scrollbar: bool,
+    /// Draw the scrollbars over the edges of the content, so no space is reserved for them.
+    /// Default is `false`, the content is shrunk to make room for the visible scrollbars.
+    #[props(default = false, into)]
+    pub overlay_scrollbars: bool,
     /// Enable scrolling with arrow keys.
     #[props(default = true, into)]
     pub scroll_with_arrows: bool,
+    /// Scroll the ancestor scroll views with the rest of the wheel movement once this one reaches its end.
+    /// Default is `true`.
+    #[props(default = true, into)]
+    pub scroll_chaining: bool,
+    /// Animate the wheel scrolling to where the wheel movements lead instead of jumping there,
+    /// the movements received while it's animating are accumulated. It's always instant when the motion is reduced.
+    /// Default is `false`.
+    #[props(default = false, into)]
+    pub smooth_scroll: bool,
+    /// Save the scroll position under this key, and restore it when a scroll view with the same key gets mounted again,
+    /// e.g. after navigating back to a route. See [`use_scroll_positions`](crate::use_scroll_positions).
+    #[props(into)]
+    pub restoration_key: Option<String>,
+    /// Signal to keep updated with the sizes and scroll position of this scroll view, see [`use_scroll_metrics`](crate::use_scroll_metrics).
+    pub scroll_metrics: Option<Signal<ScrollMetrics>>,
+    /// Handler for the `onscroll` event of the content, called at most once per frame while it gets scrolled.
+    pub onscroll: Option<EventHandler<ScrollEvent>>,
+    /// Length of the fade applied to the edges where the content is clipped, see the `scroll_fade` attribute.
+    /// Default is `0`, no fade.
+    #[props(default = "0".to_string(), into)]
+    pub scroll_fade: String,
 }
 
 /// Scrollable area with bidirectional support and scrollbars.
 ///
+/// When its content overflows it can be focused with the `Tab` key,
+/// and then scrolled with the arrow keys, `PageUp`/`PageDown` and `Home`/`End`.
+///
 /// # Example
 ///
 /// ```no_run
@@ -60,28 +89,44 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
     let mut clicking_scrollbar = use_signal::<Option<(Axis, f64)>>(|| None);
     let mut clicking_shift = use_signal(|| false);
     let mut clicking_alt = use_signal(|| false);
-    let mut scrolled_y = use_signal(|| 0);
-    let mut scrolled_x = use_signal(|| 0);
+    let (mut scrolled_x, mut scrolled_y) = use_scroll_restoration(&props.restoration_key);
+    let mut smooth_scroll = use_smooth_scroll(scrolled_x, scrolled_y);
     let (node_ref, size) = use_node();
     let mut focus = use_focus();
     let theme = use_applied_theme!(&props.theme, scroll_view);
     let scrollbar_theme = use_applied_theme!(&props.scrollbar_theme, scroll_bar);
+    let min_thumb_size = scrollbar_theme.min_thumb_size.parse().unwrap_or_default();
 
     let padding = &theme.padding;
     let user_container_width = &theme.width;
     let user_container_height = &theme.height;
     let user_direction = &props.direction;
     let show_scrollbar = props.show_scrollbar;
+    let overlay_scrollbars = props.overlay_scrollbars;
     let scroll_with_arrows = props.scroll_with_arrows;
+    let scroll_chaining = props.scroll_chaining;
+    let is_smooth_scroll = props.smooth_scroll && !smooth_scroll.is_reduced_motion();
+    let scroll_fade = &props.scroll_fade;
 
     let vertical_scrollbar_is_visible =
         is_scrollbar_visible(show_scrollbar, size.inner.height, size.area.height());
     let horizontal_scrollbar_is_visible =
         is_scrollbar_visible(show_scrollbar, size.inner.width, size.area.width());
 
-    let container_width = get_container_size(vertical_scrollbar_is_visible, &scrollbar_theme.size);
-    let container_height =
-        get_container_size(horizontal_scrollbar_is_visible, &scrollbar_theme.size);
+    // Content that overflows can be focused with the keyboard to scroll it with the keys
+    let is_scrollable =
+        size.inner.height > size.area.height() || size.inner.width > size.area.width();
+    let focus_id = focus.attribute();
+
+    // Overlaid scrollbars don't take any space from the content
+    let container_width = get_container_size(
+        vertical_scrollbar_is_visible && !overlay_scrollbars,
+        &scrollbar_theme.size,
+    );
+    let container_height = get_container_size(
+        horizontal_scrollbar_is_visible && !overlay_scrollbars,
+        &scrollbar_theme.size,
+    );
 
     let corrected_scrolled_y = get_corrected_scroll_position(
         size.inner.height,
@@ -94,22 +139,85 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
         *scrolled_x.read() as f32,
     );
 
-    let (scrollbar_y, scrollbar_height) =
-        get_scrollbar_pos_and_size(size.inner.height, size.area.height(), corrected_scrolled_y);
-    let (scrollbar_x, scrollbar_width) =
-        get_scrollbar_pos_and_size(size.inner.width, size.area.width(), corrected_scrolled_x);
+    // The content is rendered at the fractional position of the smooth scroll while it's animating
+    let (offset_x, offset_y) = smooth_scroll.offset((corrected_scrolled_x, corrected_scrolled_y));
+
+    use_sync_scroll_metrics(
+        props.scroll_metrics,
+        ScrollMetrics {
+            content_size: size.inner,
+            viewport_size: size.area.size,
+            offset_x: corrected_scrolled_x.abs(),
+            offset_y: corrected_scrolled_y.abs(),
+        },
+    );
+
+    let (scrollbar_y, scrollbar_height) = get_scrollbar_pos_and_size(
+        size.inner.height,
+        size.area.height(),
+        offset_y,
+        min_thumb_size,
+    );
+    let (scrollbar_x, scrollbar_width) = get_scrollbar_pos_and_size(
+        size.inner.width,
+        size.area.width(),
+        offset_x,
+        min_thumb_size,
+    );
 
     // Moves the Y axis when the user scrolls in the container
     let onwheel = move |e: WheelEvent| {
+        // A child has asked to not scroll
+        if e.is_default_prevented() {
+            return;
+        }
+
         let speed_multiplier = if *clicking_alt.peek() {
             SCROLL_SPEED_MULTIPLIER
         } else {
             1.0
         };
 
-        let wheel_movement = e.get_delta_y() as f32 * speed_multiplier;
+        let wheel_movement = e.get_remaining_delta_y() as f32 * speed_multiplier;
+
+        if is_smooth_scroll {
+            let current = (corrected_scrolled_x, corrected_scrolled_y);
+            let (destination_x, destination_y) = smooth_scroll.destination(current);
+            let is_horizontal = *clicking_shift.peek();
+            let (inner_size, viewport_size, destination) = if is_horizontal {
+                (size.inner.width, size.area.width(), destination_x)
+            } else {
+                (size.inner.height, size.area.height(), destination_y)
+            };
+
+            // The target isn't rounded, so the fractional movements of touchpads add up
+            let target = get_corrected_scroll_position(
+                inner_size,
+                viewport_size,
+                destination + wheel_movement,
+            );
+
+            consume_wheel_movement(
+                &e,
+                wheel_movement,
+                speed_multiplier,
+                destination,
+                target,
+                scroll_chaining,
+            );
 
-        if *clicking_shift.peek() {
+            // Only scroll when there is still area to scroll
+            if target == destination {
+                return;
+            }
+
+            let target = if is_horizontal {
+                (target, destination_y)
+            } else {
+                (destination_x, target)
+            };
+            smooth_scroll.scroll_to(current, target, size.area.size.to_tuple());
+        } else if *clicking_shift.peek() {
             let scroll_position_x = get_scroll_position_from_wheel(
                 wheel_movement,
                 size.inner.width,
@@ -117,9 +225,17 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
                 corrected_scrolled_x,
             );
 
+            consume_wheel_movement(
+                &e,
+                wheel_movement,
+                speed_multiplier,
+                corrected_scrolled_x,
+                scroll_position_x as f32,
+                scroll_chaining,
+            );
+
             // Only scroll when there is still area to scroll
             if *scrolled_x.peek() != scroll_position_x {
-                e.stop_propagation();
                 *scrolled_x.write() = scroll_position_x;
             } else {
                 return;
@@ -132,9 +248,17 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
                 corrected_scrolled_y,
             );
 
+            consume_wheel_movement(
+                &e,
+                wheel_movement,
+                speed_multiplier,
+                corrected_scrolled_y,
+                scroll_position_y as f32,
+                scroll_chaining,
+            );
+
             // Only scroll when there is still area to scroll
             if *scrolled_y.peek() != scroll_position_y {
-                e.stop_propagation();
                 *scrolled_y.write() = scroll_position_y;
             } else {
                 return;
@@ -148,6 +272,10 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
     let onmouseover = move |e: MouseEvent| {
         let clicking_scrollbar = clicking_scrollbar.peek();
 
+        if clicking_scrollbar.is_some() {
+            smooth_scroll.stop();
+        }
+
         if let Some((Axis::Y, y)) = *clicking_scrollbar {
             let coordinates = e.get_element_coordinates();
             let cursor_y = coordinates.y - y - size.area.min_y() as f64;
@@ -156,6 +284,7 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
                 cursor_y as f32,
                 size.inner.height,
                 size.area.height(),
+                min_thumb_size,
             );
 
             *scrolled_y.write() = scroll_position;
@@ -167,6 +296,7 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
                 cursor_x as f32,
                 size.inner.width,
                 size.area.width(),
+                min_thumb_size,
             );
 
             *scrolled_x.write() = scroll_position;
@@ -214,6 +344,10 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
                     viewport_width,
                 );
 
+                // The keys don't animate the scroll, they take over the running animation
+                if (x, y) != (corrected_scrolled_x, corrected_scrolled_y) {
+                    smooth_scroll.stop();
+                }
                 scrolled_x.set(x as i32);
                 scrolled_y.set(y as i32);
             }
@@ -258,6 +392,12 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
         "0"
     };
 
+    let onscroll = move |e: ScrollEvent| {
+        if let Some(onscroll) = &props.onscroll {
+            onscroll.call(e);
+        }
+    };
+
     let is_scrolling_x = clicking_scrollbar
         .read()
         .as_ref()
@@ -272,6 +412,8 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
     rsx!(
         rect {
             role: "scrollView",
+            focus_id,
+            focusable: "{is_scrollable}",
             overflow: "clip",
             direction: "horizontal",
             width: "{user_container_width}",
@@ -290,10 +432,12 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
                     height: "100%",
                     width: "100%",
                     direction: "{user_direction}",
-                    offset_y: "{corrected_scrolled_y}",
-                    offset_x: "{corrected_scrolled_x}",
+                    offset_y: "{offset_y}",
+                    offset_x: "{offset_x}",
+                    scroll_fade: "{scroll_fade}",
                     reference: node_ref,
                     onwheel: onwheel,
+                    onscroll,
                     {props.children}
                 }
                 ScrollBar {
@@ -301,6 +445,7 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
                     height: "{horizontal_scrollbar_size}",
                     offset_x: "{scrollbar_x}",
                     clicking_scrollbar: is_scrolling_x,
+                    overlay: overlay_scrollbars,
                     theme: props.scrollbar_theme.clone(),
                     ScrollThumb {
                         clicking_scrollbar: is_scrolling_x,
@@ -316,6 +461,7 @@ pub fn ScrollView(props: ScrollViewProps) -> Element {
                 height: "100%",
                 offset_y: "{scrollbar_y}",
                 clicking_scrollbar: is_scrolling_y,
+                overlay: overlay_scrollbars,
                 theme: props.scrollbar_theme.clone(),
                 ScrollThumb {
                     clicking_scrollbar: is_scrolling_y,
@@ -375,6 +521,8 @@ mod test {
             name: EventName::Wheel,
             scroll: (0., -300.).into(),
             cursor: (5., 5.).into(),
+            delta: WheelDelta::Pixels { x: 0., y: -300. },
+            modifiers: Modifiers::default(),
         });
 
         utils.wait_for_update().await;
@@ -387,6 +535,191 @@ mod test {
         assert!(content.get(3).is_visible()); // 4. 600 -> 800, 800 > 300
     }
 
+    #[tokio::test]
+    pub async fn scroll_view_smooth_wheel() {
+        fn scroll_view_smooth_wheel_app() -> Element {
+            rsx!(
+                ScrollView {
+                    smooth_scroll: true,
+                    rect {
+                        height: "1000",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_smooth_wheel_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        let wheel = |utils: &mut TestingHandler| {
+            utils.push_event(PlatformEvent::Wheel {
+                name: EventName::Wheel,
+                scroll: (0., -100.).into(),
+                cursor: (5., 5.).into(),
+                delta: WheelDelta::Pixels { x: 0., y: -100. },
+                modifiers: Modifiers::default(),
+            });
+        };
+
+        // Consecutive wheel movements accumulate
+        wheel(&mut utils);
+        utils.wait_for_update().await;
+        wheel(&mut utils);
+        utils.wait_for_update().await;
+
+        // It's on its way
+        let position = content.get(0).area().unwrap().min_y();
+        assert!(position < 0.0 && position > -200.0);
+
+        for _ in 0..60 {
+            utils.wait_for_update().await;
+        }
+
+        assert_eq!(content.get(0).area().unwrap().min_y(), -200.0);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_wheel_prevent_default() {
+        fn scroll_view_wheel_prevent_default_app() -> Element {
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "200",
+                        width: "200",
+                        onwheel: |e: WheelEvent| e.prevent_default(),
+                    },
+                    rect {
+                        height: "200",
+                        width: "200",
+                    },
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_wheel_prevent_default_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        assert!(content.get(0).is_visible());
+        assert!(!content.get(3).is_visible());
+
+        // The first item prevents the scroll
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 5.).into(),
+            delta: WheelDelta::Pixels { x: 0., y: -300. },
+            modifiers: Modifiers::default(),
+        });
+
+        utils.wait_for_update().await;
+
+        assert!(content.get(0).is_visible());
+        assert!(!content.get(3).is_visible());
+
+        // The second item does not
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 250.).into(),
+            delta: WheelDelta::Pixels { x: 0., y: -300. },
+            modifiers: Modifiers::default(),
+        });
+
+        utils.wait_for_update().await;
+
+        assert!(!content.get(0).is_visible());
+        assert!(content.get(3).is_visible());
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_wheel_zoom() {
+        fn scroll_view_wheel_zoom_app() -> Element {
+            let mut zoom = use_signal(|| 0);
+
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "200",
+                        width: "200",
+                        onwheel: move |e: WheelEvent| {
+                            if e.get_modifiers().ctrl() {
+                                e.prevent_default();
+                                if let WheelDelta::Lines { y, .. } = e.get_raw_delta() {
+                                    zoom += y as i32;
+                                }
+                            }
+                        },
+                        label {
+                            "{zoom}"
+                        }
+                    },
+                    rect {
+                        height: "200",
+                        width: "200",
+                    },
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_wheel_zoom_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        assert_eq!(content.get(0).get(0).get(0).text(), Some("0"));
+
+        // Zoom with Ctrl + wheel
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -60.).into(),
+            cursor: (5., 5.).into(),
+            delta: WheelDelta::Lines { x: 0., y: -2. },
+            modifiers: Modifiers::CONTROL,
+        });
+
+        utils.wait_for_update().await;
+
+        assert_eq!(content.get(0).get(0).get(0).text(), Some("-2"));
+        assert!(content.get(0).is_visible());
+        assert!(!content.get(3).is_visible());
+
+        // Scroll without any modifier
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 5.).into(),
+            delta: WheelDelta::Pixels { x: 0., y: -300. },
+            modifiers: Modifiers::default(),
+        });
+
+        utils.wait_for_update().await;
+
+        assert_eq!(content.get(0).get(0).get(0).text(), Some("-2"));
+        assert!(!content.get(0).is_visible());
+        assert!(content.get(3).is_visible());
+    }
+
     #[tokio::test]
     pub async fn scroll_view_scrollbar() {
         fn scroll_view_scrollbar_app() -> Element {
@@ -462,6 +795,7 @@ mod test {
                 key: Key::ArrowUp,
                 code: Code::ArrowUp,
                 modifiers: Modifiers::default(),
+                repeat: false,
             });
             utils.wait_for_update().await;
         }
@@ -477,6 +811,7 @@ mod test {
             key: Key::End,
             code: Code::End,
             modifiers: Modifiers::default(),
+            repeat: false,
         });
         utils.wait_for_update().await;
 
@@ -485,4 +820,516 @@ mod test {
         assert!(content.get(2).is_visible());
         assert!(content.get(3).is_visible());
     }
+
+    #[tokio::test]
+    pub async fn scroll_view_keyboard() {
+        fn scroll_view_keyboard_app() -> Element {
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "500",
+                        width: "200",
+                    },
+                    rect {
+                        height: "500",
+                        width: "200",
+                    },
+                    rect {
+                        height: "500",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_keyboard_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        let press = |key: Key, code: Code| PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key,
+            code,
+            modifiers: Modifiers::default(),
+            repeat: false,
+        };
+
+        // The keys don't scroll until the ScrollView is focused
+        utils.push_event(press(Key::PageDown, Code::PageDown));
+        utils.wait_for_update().await;
+        assert_eq!(content.get(0).layout().unwrap().area.min_y(), 0.);
+
+        // Focus it with the keyboard
+        utils.push_event(press(Key::Tab, Code::Tab));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Scroll a whole page
+        utils.push_event(press(Key::PageDown, Code::PageDown));
+        utils.wait_for_update().await;
+        assert_eq!(content.get(0).layout().unwrap().area.min_y(), -500.);
+
+        // Jump to the bottom
+        utils.push_event(press(Key::End, Code::End));
+        utils.wait_for_update().await;
+        assert_eq!(content.get(0).layout().unwrap().area.min_y(), -1000.);
+
+        // And back to the top
+        utils.push_event(press(Key::Home, Code::Home));
+        utils.wait_for_update().await;
+        assert_eq!(content.get(0).layout().unwrap().area.min_y(), 0.);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_keyed_reorder() {
+        fn scroll_view_keyed_reorder_app() -> Element {
+            let mut items = use_signal(|| vec!["a", "b"]);
+
+            rsx!(
+                rect {
+                    height: "50",
+                    width: "100%",
+                    onclick: move |_| items.write().reverse(),
+                }
+                for item in items.read().iter() {
+                    ScrollView {
+                        key: "{item}",
+                        theme: theme_with!(ScrollViewTheme {
+                            height: "200".into(),
+                        }),
+                        label {
+                            "{item}"
+                        }
+                        rect {
+                            height: "200",
+                            width: "200",
+                        }
+                        rect {
+                            height: "200",
+                            width: "200",
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_keyed_reorder_app);
+        utils.wait_for_update().await;
+
+        // Scroll the first ScrollView
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 100.).into(),
+            delta: WheelDelta::Pixels { x: 0., y: -300. },
+            modifiers: Modifiers::default(),
+        });
+
+        utils.wait_for_update().await;
+
+        let content_a = utils.root().get(1).get(0).get(0);
+        let content_b = utils.root().get(2).get(0).get(0);
+        assert_eq!(content_a.get(0).get(0).text(), Some("a"));
+        assert!(!content_a.get(1).is_visible());
+        assert_eq!(content_b.get(0).get(0).text(), Some("b"));
+        assert!(content_b.get(1).is_visible());
+
+        // Swap both ScrollViews
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Each ScrollView keeps its own scroll in its new position
+        let content_b = utils.root().get(1).get(0).get(0);
+        let content_a = utils.root().get(2).get(0).get(0);
+        assert_eq!(content_b.get(0).get(0).text(), Some("b"));
+        assert!(content_b.get(1).is_visible());
+        assert_eq!(content_a.get(0).get(0).text(), Some("a"));
+        assert!(!content_a.get(1).is_visible());
+        assert_eq!(utils.root().get(2).area().unwrap().min_y(), 250.);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_chaining() {
+        fn scroll_view_chaining_app(scroll_chaining: bool) -> Element {
+            rsx!(
+                ScrollView {
+                    ScrollView {
+                        scroll_chaining,
+                        theme: theme_with!(ScrollViewTheme {
+                            height: "200".into(),
+                        }),
+                        rect {
+                            height: "300",
+                            width: "200",
+                        }
+                    }
+                    rect {
+                        height: "600",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let apps: [(fn() -> Element, f32); 2] = [
+            (|| scroll_view_chaining_app(true), -200.),
+            (|| scroll_view_chaining_app(false), 0.),
+        ];
+
+        for (app, outer_scroll) in apps {
+            let mut utils = launch_test(app);
+            let root = utils.root();
+            let content = root.get(0).get(0).get(0);
+            utils.wait_for_update().await;
+
+            utils.push_event(PlatformEvent::Wheel {
+                name: EventName::Wheel,
+                scroll: (0., -300.).into(),
+                cursor: (5., 5.).into(),
+                delta: WheelDelta::Pixels { x: 0., y: -300. },
+                modifiers: Modifiers::default(),
+            });
+
+            utils.wait_for_update().await;
+
+            // The inner ScrollView only had 100 pixels to scroll, the rest goes to the outer one
+            let inner_content = content.get(0).get(0).get(0);
+            assert_eq!(
+                inner_content.get(0).layout().unwrap().area.min_y(),
+                outer_scroll - 100.
+            );
+            assert_eq!(content.get(0).layout().unwrap().area.min_y(), outer_scroll);
+        }
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_restoration() {
+        fn scroll_view_restoration_app() -> Element {
+            let mut items = use_signal(|| 4);
+            let mut show = use_signal(|| true);
+
+            rsx!(
+                rect {
+                    height: "50",
+                    width: "100%",
+                    direction: "horizontal",
+                    rect {
+                        height: "50",
+                        width: "50",
+                        onclick: move |_| show.toggle(),
+                    }
+                    rect {
+                        height: "50",
+                        width: "50",
+                        // Refresh the data
+                        onclick: move |_| items += 1,
+                    }
+                }
+                if show() {
+                    ScrollView {
+                        restoration_key: "list",
+                        for i in 0..items() {
+                            rect {
+                                key: "{i}",
+                                height: "200",
+                                width: "200",
+                            }
+                        }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_restoration_app);
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -300.).into(),
+            cursor: (5., 100.).into(),
+            delta: WheelDelta::Pixels { x: 0., y: -300. },
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        let content = utils.root().get(1).get(0).get(0);
+        assert!(!content.get(0).is_visible());
+
+        // Re-rendering the content keeps the scroll
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (75., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let content = utils.root().get(1).get(0).get(0);
+        assert_eq!(content.get(0).layout().unwrap().area.min_y(), -250.);
+
+        // Remounting the ScrollView restores it
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (25., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (25., 25.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let content = utils.root().get(1).get(0).get(0);
+        assert_eq!(content.get(0).layout().unwrap().area.min_y(), -250.);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_min_thumb_size() {
+        fn scroll_view_min_thumb_size_app() -> Element {
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "50000",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_min_thumb_size_app);
+        utils.wait_for_update().await;
+
+        // The thumb would be 5 pixels long otherwise
+        let thumb = utils.root().get(0).get(1).get(0);
+        assert_eq!(thumb.area().unwrap().height(), 24.);
+        assert_eq!(thumb.area().unwrap().min_y(), 0.);
+
+        // Scroll to the end
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -100000.).into(),
+            cursor: (5., 5.).into(),
+            delta: WheelDelta::Pixels { x: 0., y: -100000. },
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        // The thumb reaches the end of the track
+        let thumb = utils.root().get(0).get(1).get(0);
+        assert_eq!(thumb.area().unwrap().max_y(), 500.);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_scrollbar_only_when_needed() {
+        fn scroll_view_scrollbar_app() -> Element {
+            let mut tall = use_signal(|| false);
+            let height = if tall() { 1000 } else { 100 };
+
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "{height}",
+                        width: "100%",
+                        onclick: move |_| tall.toggle(),
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_scrollbar_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The content fits, so there is no scrollbar and no space reserved for it
+        let container = utils.root().get(0).get(0);
+        let scrollbar = utils.root().get(0).get(1);
+        assert_eq!(container.area().unwrap().width(), 500.);
+        assert_eq!(scrollbar.area().unwrap().width(), 0.);
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The content overflows, so the scrollbar shows up next to it
+        assert_eq!(container.area().unwrap().width(), 485.);
+        assert_eq!(scrollbar.area().unwrap().width(), 15.);
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // It fits again
+        assert_eq!(container.area().unwrap().width(), 500.);
+        assert_eq!(scrollbar.area().unwrap().width(), 0.);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_overlay_scrollbars() {
+        fn scroll_view_overlay_scrollbars_app() -> Element {
+            rsx!(
+                ScrollView {
+                    overlay_scrollbars: true,
+                    rect {
+                        height: "1000",
+                        width: "100%",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_overlay_scrollbars_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The scrollbar is drawn over the right edge of the content
+        let container = utils.root().get(0).get(0);
+        let scrollbar = utils.root().get(0).get(1);
+        assert_eq!(container.area().unwrap().width(), 500.);
+        assert_eq!(scrollbar.area().unwrap().width(), 15.);
+        assert_eq!(scrollbar.area().unwrap().min_x(), 485.);
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_metrics() {
+        fn scroll_view_metrics_app() -> Element {
+            let scroll_metrics = use_scroll_metrics();
+            let metrics = scroll_metrics();
+
+            rsx!(
+                ScrollView {
+                    theme: theme_with!(ScrollViewTheme {
+                        height: "400".into(),
+                    }),
+                    scroll_metrics,
+                    rect {
+                        height: "1000",
+                        width: "200",
+                    }
+                }
+                label {
+                    "{metrics.content_size.height} {metrics.viewport_size.height} {metrics.offset_y} {metrics.is_at_end_y()}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_metrics_app);
+        let label = utils.root().get(1);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(label.get(0).text(), Some("1000 400 0 false"));
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -700.).into(),
+            cursor: (5., 5.).into(),
+            delta: WheelDelta::Pixels { x: 0., y: -700. },
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // It can only be scrolled until the end of the content
+        assert_eq!(label.get(0).text(), Some("1000 400 600 true"));
+    }
+
+    #[tokio::test]
+    pub async fn scroll_view_wheel_focused_stepper() {
+        fn scroll_view_wheel_focused_stepper_app() -> Element {
+            let mut value = use_signal(|| 0);
+            let mut focus = use_focus();
+            let focus_id = focus.attribute();
+
+            rsx!(
+                ScrollView {
+                    rect {
+                        height: "200",
+                        width: "200",
+                        focus_id,
+                        focusable: "true",
+                        onclick: move |_| focus.focus(),
+                        // The stepper owns the wheel only while it's focused
+                        onwheel: move |e: WheelEvent| {
+                            if focus.is_focused() {
+                                e.prevent_default();
+                                value += e.get_delta_y().signum() as i32;
+                            }
+                        },
+                        label {
+                            "{value}"
+                        }
+                    },
+                    rect {
+                        height: "200",
+                        width: "200",
+                    },
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                    rect {
+                        height: "200",
+                        width: "200",
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(scroll_view_wheel_focused_stepper_app);
+        let root = utils.root();
+        let content = root.get(0).get(0).get(0);
+        utils.wait_for_update().await;
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5., 5.).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+
+        // The focused stepper changes its value without scrolling its parent
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., 60.).into(),
+            cursor: (5., 5.).into(),
+            delta: WheelDelta::Pixels { x: 0., y: 60. },
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(content.get(0).get(0).get(0).text(), Some("1"));
+        assert!(content.get(0).is_visible());
+        assert!(!content.get(3).is_visible());
+
+        utils.push_event(PlatformEvent::Wheel {
+            name: EventName::Wheel,
+            scroll: (0., -60.).into(),
+            cursor: (5., 5.).into(),
+            delta: WheelDelta::Pixels { x: 0., y: -60. },
+            modifiers: Modifiers::default(),
+        });
+        utils.wait_for_update().await;
+
+        assert_eq!(content.get(0).get(0).get(0).text(), Some("0"));
+        assert!(content.get(0).is_visible());
+    }
 }
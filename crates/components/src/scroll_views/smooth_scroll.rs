@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use dioxus::prelude::*;
+use freya_hooks::{use_animation_clock, use_frame};
+
+/// Time it takes the smooth scroll to get most of the way to where the wheel movements lead.
+#[doc(hidden)]
+pub const SMOOTH_SCROLL_DURATION: Duration = Duration::from_millis(150);
+
+/// Elapsed time used for the first frame of the animation, the previous frame might have been long ago.
+const FIRST_FRAME: Duration = Duration::from_millis(16);
+
+/// Wheel scrolling animated towards its target instead of jumping to it, created with [`use_smooth_scroll`].
+#[derive(Clone, Copy)]
+pub(crate) struct SmoothScroll {
+    /// Fractional position rendered while the animation runs.
+    position: Signal<(f32, f32)>,
+    /// Where the animation is heading, the wheel movements accumulate into it.
+    target: Signal<Option<(f32, f32)>>,
+    reduced_motion: bool,
+}
+
+impl SmoothScroll {
+    /// Check if the scroll is not animated because the motion is reduced.
+    pub fn is_reduced_motion(&self) -> bool {
+        self.reduced_motion
+    }
+
+    /// Get where a new wheel movement continues from, the target of the running animation or the `current` position.
+    pub fn destination(&self, current: (f32, f32)) -> (f32, f32) {
+        self.target.peek().unwrap_or(current)
+    }
+
+    /// Animate from the `current` position, or from where the running animation is, to the `target`.
+    ///
+    /// The animation never lags behind more than `max_lag`, usually the size of the viewport,
+    /// so fast consecutive movements don't leave the content far from where the wheel led it.
+    pub fn scroll_to(&mut self, current: (f32, f32), target: (f32, f32), max_lag: (f32, f32)) {
+        let (x, y) = if self.target.peek().is_some() {
+            *self.position.peek()
+        } else {
+            current
+        };
+        let catch_up = |from: f32, to: f32, max_lag: f32| from.clamp(to - max_lag, to + max_lag);
+        self.position.set((
+            catch_up(x, target.0, max_lag.0),
+            catch_up(y, target.1, max_lag.1),
+        ));
+        self.target.set(Some(target));
+    }
+
+    /// Stop the animation where it is, e.g. because something else is changing the scroll position.
+    pub fn stop(&mut self) {
+        if self.target.peek().is_some() {
+            self.target.set(None);
+        }
+    }
+
+    /// Get the position to render, which is fractional while the animation runs.
+    pub fn offset(&self, current: (f32, f32)) -> (f32, f32) {
+        if self.target.read().is_some() {
+            *self.position.read()
+        } else {
+            current
+        }
+    }
+}
+
+/// Animate the wheel scrolling of a scroll view, the rounded position is kept in `scrolled_x` and `scrolled_y` as it moves.
+///
+/// The position approaches its target exponentially, so it starts fast and slows down as it arrives,
+/// and the movements received while it's running only move the target further.
+pub(crate) fn use_smooth_scroll(
+    mut scrolled_x: Signal<i32>,
+    mut scrolled_y: Signal<i32>,
+) -> SmoothScroll {
+    let animation_clock = use_animation_clock();
+    let mut position = use_signal(|| (0.0, 0.0));
+    let mut target = use_signal(|| None);
+
+    let mut running = false;
+    use_frame(move |elapsed| {
+        let Some((target_x, target_y)) = *target.peek() else {
+            running = false;
+            return false;
+        };
+
+        let elapsed = if running {
+            elapsed
+        } else {
+            elapsed.min(FIRST_FRAME)
+        };
+        running = true;
+
+        let progress =
+            1.0 - (-3.0 * elapsed.as_secs_f32() / SMOOTH_SCROLL_DURATION.as_secs_f32()).exp();
+        let step = |from: f32, to: f32| {
+            // Less than half a pixel away looks the same as being there
+            if (to - from).abs() < 0.5 {
+                to
+            } else {
+                from + (to - from) * progress
+            }
+        };
+        let (x, y) = *position.peek();
+        let (x, y) = (step(x, target_x), step(y, target_y));
+        position.set((x, y));
+
+        if *scrolled_x.peek() != x.round() as i32 {
+            scrolled_x.set(x.round() as i32);
+        }
+        if *scrolled_y.peek() != y.round() as i32 {
+            scrolled_y.set(y.round() as i32);
+        }
+
+        if (x, y) == (target_x, target_y) {
+            target.set(None);
+            running = false;
+            false
+        } else {
+            true
+        }
+    });
+
+    SmoothScroll {
+        position,
+        target,
+        reduced_motion: animation_clock.prefers_reduced_motion(),
+    }
+}
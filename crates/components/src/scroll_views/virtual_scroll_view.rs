@@ -7,11 +7,13 @@ use freya_hooks::{
     use_applied_theme, use_focus, use_node, ScrollBarThemeWith, ScrollViewThemeWith,
 };
 use std::ops::Range;
+use torin::geometry::Size2D;
 
 use crate::{
-    get_container_size, get_corrected_scroll_position, get_scroll_position_from_cursor,
-    get_scroll_position_from_wheel, get_scrollbar_pos_and_size, is_scrollbar_visible,
-    manage_key_event, Axis, ScrollBar, ScrollThumb, SCROLL_SPEED_MULTIPLIER,
+    consume_wheel_movement, get_container_size, get_corrected_scroll_position,
+    get_scroll_position_from_cursor, get_scroll_position_from_wheel, get_scrollbar_pos_and_size,
+    is_scrollbar_visible, manage_key_event, use_scroll_restoration, use_sync_scroll_metrics, Axis,
+    ScrollBar, ScrollMetrics, ScrollThumb, SCROLL_SPEED_MULTIPLIER,
 };
 
 /// Properties for the [`VirtualScrollView`] component.
@@ -36,12 +38,26 @@ pub struct VirtualScrollViewProps<
     /// Direction of the VirtualScrollView, `vertical` or `horizontal`.
     #[props(default = "vertical".to_string(), into)]
     pub direction: String,
-    /// Show the scrollbar, visible by default.
+    /// Show the scrollbars, only when the content overflows. Enabled by default.
     #[props(default = true, into)]
     pub show_scrollbar: bool,
+    /// Draw the scrollbars over the edges of the content, so no space is reserved for them.
+    /// Default is `false`, the content is shrunk to make room for the visible scrollbars.
+    #[props(default = false, into)]
+    pub overlay_scrollbars: bool,
     /// Enable scrolling with arrow keys.
     #[props(default = true, into)]
     pub scroll_with_arrows: bool,
+    /// Scroll the ancestor scroll views with the rest of the wheel movement once this one reaches its end.
+    /// Default is `true`.
+    #[props(default = true, into)]
+    pub scroll_chaining: bool,
+    /// Save the scroll position under this key, and restore it when a scroll view with the same key gets mounted again,
+    /// e.g. after navigating back to a route. See [`use_scroll_positions`](crate::use_scroll_positions).
+    #[props(into)]
+    pub restoration_key: Option<String>,
+    /// Signal to keep updated with the sizes and scroll position of this scroll view, see [`use_scroll_metrics`](crate::use_scroll_metrics).
+    pub scroll_metrics: Option<Signal<ScrollMetrics>>,
     /// Cache elements or not, changing `builder_args` will invalidate the cache if enabled.
     /// Default is `true`.
     #[props(default = true, into)]
@@ -59,7 +75,11 @@ impl<
             && self.item_size == other.item_size
             && self.direction == other.direction
             && self.show_scrollbar == other.show_scrollbar
+            && self.overlay_scrollbars == other.overlay_scrollbars
             && self.scroll_with_arrows == other.scroll_with_arrows
+            && self.scroll_chaining == other.scroll_chaining
+            && self.restoration_key == other.restoration_key
+            && self.scroll_metrics == other.scroll_metrics
             && self.builder_args == other.builder_args
     }
 }
@@ -88,6 +108,9 @@ fn get_render_range(
 ///
 /// Use cases: text editors, chats, etc.
 ///
+/// When its content overflows it can be focused with the `Tab` key,
+/// and then scrolled with the arrow keys, `PageUp`/`PageDown` and `Home`/`End`.
+///
 /// # Example
 ///
 /// ```no_run
@@ -123,21 +146,23 @@ pub fn VirtualScrollView<
     let mut clicking_scrollbar = use_signal::<Option<(Axis, f64)>>(|| None);
     let mut clicking_shift = use_signal(|| false);
     let mut clicking_alt = use_signal(|| false);
-    let mut scrolled_y = use_signal(|| 0);
-    let mut scrolled_x = use_signal(|| 0);
+    let (mut scrolled_x, mut scrolled_y) = use_scroll_restoration(&props.restoration_key);
     let (node_ref, size) = use_node();
     let mut focus = use_focus();
     let theme = use_applied_theme!(&props.theme, scroll_view);
     let scrollbar_theme = use_applied_theme!(&props.scrollbar_theme, scroll_bar);
+    let min_thumb_size = scrollbar_theme.min_thumb_size.parse().unwrap_or_default();
 
     let padding = &theme.padding;
     let user_container_width = &theme.width;
     let user_container_height = &theme.height;
     let user_direction = &props.direction;
     let show_scrollbar = props.show_scrollbar;
+    let overlay_scrollbars = props.overlay_scrollbars;
     let items_length = props.length;
     let items_size = props.item_size;
     let scroll_with_arrows = props.scroll_with_arrows;
+    let scroll_chaining = props.scroll_chaining;
 
     let inner_size = items_size + (items_size * items_length as f32);
 
@@ -146,29 +171,75 @@ pub fn VirtualScrollView<
     let horizontal_scrollbar_is_visible = user_direction != "vertical"
         && is_scrollbar_visible(show_scrollbar, inner_size, size.area.width());
 
-    let container_width = get_container_size(vertical_scrollbar_is_visible, &scrollbar_theme.size);
-    let container_height =
-        get_container_size(horizontal_scrollbar_is_visible, &scrollbar_theme.size);
+    // Content that overflows can be focused with the keyboard to scroll it with the keys
+    let is_scrollable = match user_direction.as_str() {
+        "horizontal" => inner_size > size.area.width(),
+        _ => inner_size > size.area.height(),
+    };
+    let focus_id = focus.attribute();
+
+    // Overlaid scrollbars don't take any space from the content
+    let container_width = get_container_size(
+        vertical_scrollbar_is_visible && !overlay_scrollbars,
+        &scrollbar_theme.size,
+    );
+    let container_height = get_container_size(
+        horizontal_scrollbar_is_visible && !overlay_scrollbars,
+        &scrollbar_theme.size,
+    );
 
     let corrected_scrolled_y =
         get_corrected_scroll_position(inner_size, size.area.height(), *scrolled_y.read() as f32);
     let corrected_scrolled_x =
         get_corrected_scroll_position(inner_size, size.area.width(), *scrolled_x.read() as f32);
 
-    let (scrollbar_y, scrollbar_height) =
-        get_scrollbar_pos_and_size(inner_size, size.area.height(), corrected_scrolled_y);
-    let (scrollbar_x, scrollbar_width) =
-        get_scrollbar_pos_and_size(inner_size, size.area.width(), corrected_scrolled_x);
+    // Only the main axis is scrolled, the items fill the viewport in the other one
+    use_sync_scroll_metrics(
+        props.scroll_metrics,
+        if user_direction == "horizontal" {
+            ScrollMetrics {
+                content_size: Size2D::new(inner_size, size.area.height()),
+                viewport_size: size.area.size,
+                offset_x: corrected_scrolled_x.abs(),
+                offset_y: 0.0,
+            }
+        } else {
+            ScrollMetrics {
+                content_size: Size2D::new(size.area.width(), inner_size),
+                viewport_size: size.area.size,
+                offset_x: 0.0,
+                offset_y: corrected_scrolled_y.abs(),
+            }
+        },
+    );
+
+    let (scrollbar_y, scrollbar_height) = get_scrollbar_pos_and_size(
+        inner_size,
+        size.area.height(),
+        corrected_scrolled_y,
+        min_thumb_size,
+    );
+    let (scrollbar_x, scrollbar_width) = get_scrollbar_pos_and_size(
+        inner_size,
+        size.area.width(),
+        corrected_scrolled_x,
+        min_thumb_size,
+    );
 
     // Moves the Y axis when the user scrolls in the container
     let onwheel = move |e: WheelEvent| {
+        // A child has asked to not scroll
+        if e.is_default_prevented() {
+            return;
+        }
+
         let speed_multiplier = if *clicking_alt.peek() {
             SCROLL_SPEED_MULTIPLIER
         } else {
             1.0
         };
 
-        let wheel_movement = e.get_delta_y() as f32 * speed_multiplier;
+        let wheel_movement = e.get_remaining_delta_y() as f32 * speed_multiplier;
 
         if *clicking_shift.peek() {
             let scroll_position_x = get_scroll_position_from_wheel(
@@ -178,9 +249,17 @@ pub fn VirtualScrollView<
                 corrected_scrolled_x,
             );
 
+            consume_wheel_movement(
+                &e,
+                wheel_movement,
+                speed_multiplier,
+                corrected_scrolled_x,
+                scroll_position_x as f32,
+                scroll_chaining,
+            );
+
             // Only scroll when there is still area to scroll
             if *scrolled_x.peek() != scroll_position_x {
-                e.stop_propagation();
                 *scrolled_x.write() = scroll_position_x;
             } else {
                 return;
@@ -193,9 +272,17 @@ pub fn VirtualScrollView<
                 corrected_scrolled_y,
             );
 
+            consume_wheel_movement(
+                &e,
+                wheel_movement,
+                speed_multiplier,
+                corrected_scrolled_y,
+                scroll_position_y as f32,
+                scroll_chaining,
+            );
+
             // Only scroll when there is still area to scroll
             if *scrolled_y.peek() != scroll_position_y {
-                e.stop_propagation();
                 *scrolled_y.write() = scroll_position_y;
             } else {
                 return;
@@ -213,16 +300,24 @@ pub fn VirtualScrollView<
             let coordinates = e.get_element_coordinates();
             let cursor_y = coordinates.y - y - size.area.min_y() as f64;
 
-            let scroll_position =
-                get_scroll_position_from_cursor(cursor_y as f32, inner_size, size.area.height());
+            let scroll_position = get_scroll_position_from_cursor(
+                cursor_y as f32,
+                inner_size,
+                size.area.height(),
+                min_thumb_size,
+            );
 
             *scrolled_y.write() = scroll_position;
         } else if let Some((Axis::X, x)) = *clicking_scrollbar {
             let coordinates = e.get_element_coordinates();
             let cursor_x = coordinates.x - x - size.area.min_x() as f64;
 
-            let scroll_position =
-                get_scroll_position_from_cursor(cursor_x as f32, inner_size, size.area.width());
+            let scroll_position = get_scroll_position_from_cursor(
+                cursor_x as f32,
+                inner_size,
+                size.area.width(),
+                min_thumb_size,
+            );
 
             *scrolled_x.write() = scroll_position;
         }
@@ -359,6 +454,8 @@ pub fn VirtualScrollView<
     rsx!(
         rect {
             role: "scrollView",
+            focus_id,
+            focusable: "{is_scrollable}",
             overflow: "clip",
             direction: "horizontal",
             width: "{user_container_width}",
@@ -386,6 +483,7 @@ pub fn VirtualScrollView<
                     height: "{horizontal_scrollbar_size}",
                     offset_x: "{scrollbar_x}",
                     clicking_scrollbar: is_scrolling_x,
+                    overlay: overlay_scrollbars,
                     theme: props.scrollbar_theme.clone(),
                     ScrollThumb {
                         clicking_scrollbar: is_scrolling_x,
@@ -401,6 +499,7 @@ pub fn VirtualScrollView<
                 height: "100%",
                 offset_y: "{scrollbar_y}",
                 clicking_scrollbar: is_scrolling_y,
+                overlay: overlay_scrollbars,
                 theme: props.scrollbar_theme.clone(),
                 ScrollThumb {
                     clicking_scrollbar: is_scrolling_y,
@@ -463,6 +562,8 @@ mod test {
             name: EventName::Wheel,
             scroll: (0., -300.).into(),
             cursor: (5., 5.).into(),
+            delta: WheelDelta::Pixels { x: 0., y: -300. },
+            modifiers: Modifiers::default(),
         });
 
         utils.wait_for_update().await;
@@ -566,6 +667,7 @@ mod test {
                 key: Key::ArrowUp,
                 code: Code::ArrowUp,
                 modifiers: Modifiers::default(),
+                repeat: false,
             });
             utils.wait_for_update().await;
         }
@@ -587,6 +689,7 @@ mod test {
             key: Key::End,
             code: Code::End,
             modifiers: Modifiers::default(),
+            repeat: false,
         });
         utils.wait_for_update().await;
         utils.wait_for_update().await;
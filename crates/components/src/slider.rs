@@ -152,7 +152,7 @@ pub fn Slider(
     };
 
     let inner_width = (size.area.width() - 15.0) * (value / 100.0) as f32;
-    let border = if focus.is_selected() {
+    let outline = if focus.is_selected() {
         format!("2 solid {}", theme.border_fill)
     } else {
         "none".to_string()
@@ -172,7 +172,7 @@ pub fn Slider(
             onwheel: onwheel,
             main_align: "center",
             cross_align: "center",
-            border: "{border}",
+            outline: "{outline}",
             corner_radius: "8",
             rect {
                 background: "{theme.background}",
@@ -0,0 +1,437 @@
+use std::time::{Duration, Instant};
+
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+use freya_elements::events::MouseEvent;
+use freya_hooks::{use_applied_theme, use_node, use_platform, SplitPaneThemeWith};
+use winit::window::CursorIcon;
+
+/// Maximum time between two clicks in the divider to reset its position.
+const DOUBLE_CLICK_DURATION: Duration = Duration::from_millis(500);
+
+/// Properties for the [`SplitPane`] component.
+#[derive(Props, Clone, PartialEq)]
+pub struct SplitPaneProps {
+    /// Theme override.
+    pub theme: Option<SplitPaneThemeWith>,
+    /// Direction in which the panes are placed, `horizontal` or `vertical`.
+    #[props(default = "horizontal".to_string(), into)]
+    pub direction: String,
+    /// Initial share of the space given to the first pane, from `0.0` to `1.0`.
+    /// Double clicking the divider resets it to this share.
+    #[props(default = 0.5)]
+    pub initial_ratio: f32,
+    /// Minimum size in logical pixels of the first pane.
+    #[props(default = 0.0)]
+    pub first_min_size: f32,
+    /// Maximum size in logical pixels of the first pane.
+    pub first_max_size: Option<f32>,
+    /// Minimum size in logical pixels of the second pane.
+    #[props(default = 0.0)]
+    pub second_min_size: f32,
+    /// Maximum size in logical pixels of the second pane.
+    pub second_max_size: Option<f32>,
+    /// Handler for the `onresize` event, called with the new share of the first pane.
+    /// Store it somewhere and pass it back as `initial_ratio` to persist it.
+    pub onresize: Option<EventHandler<f32>>,
+    /// Content of the first pane.
+    pub first: Element,
+    /// Content of the second pane.
+    pub second: Element,
+}
+
+/// Describes the current status of the divider of a SplitPane.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum SplitPaneStatus {
+    /// Default state.
+    #[default]
+    Idle,
+    /// Mouse is hovering the divider.
+    Hovering,
+}
+
+/// Get the minimum and maximum sizes of the first pane, respecting the constraints of both panes.
+fn get_first_pane_bounds(available_size: f32, props: &SplitPaneProps) -> (f32, f32) {
+    let min_size = props
+        .first_min_size
+        .max(available_size - props.second_max_size.unwrap_or(f32::MAX));
+    let max_size = props
+        .first_max_size
+        .unwrap_or(f32::MAX)
+        .min(available_size - props.second_min_size);
+
+    (min_size, max_size)
+}
+
+/// Get the size of the first pane for the given share of the available size.
+fn get_first_pane_size(ratio: f32, available_size: f32, (min_size, max_size): (f32, f32)) -> f32 {
+    (ratio * available_size)
+        .min(max_size)
+        .max(min_size)
+        .max(0.0)
+}
+
+/// Two panes separated by a divider that can be dragged to resize them.
+///
+/// # Styling
+/// Inherits a [`SplitPaneTheme`](freya_hooks::SplitPaneTheme) theme.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         SplitPane {
+///             first_min_size: 100.0,
+///             first: rsx!(
+///                 label {
+///                     "Sidebar"
+///                 }
+///             ),
+///             second: rsx!(
+///                 label {
+///                     "Content"
+///                 }
+///             )
+///         }
+///     )
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub fn SplitPane(props: SplitPaneProps) -> Element {
+    let theme = use_applied_theme!(&props.theme, split_pane);
+    let platform = use_platform();
+    let (node_reference, size) = use_node();
+    let mut ratio = use_signal(|| props.initial_ratio);
+    let mut status = use_signal(SplitPaneStatus::default);
+    let mut dragging = use_signal(|| false);
+    let mut last_click = use_signal::<Option<Instant>>(|| None);
+
+    let is_horizontal = props.direction != "vertical";
+    let divider_size = theme.divider_size.parse::<f32>().unwrap_or_default();
+    let cursor_icon = if is_horizontal {
+        CursorIcon::ColResize
+    } else {
+        CursorIcon::RowResize
+    };
+
+    let (container_start, container_size) = if is_horizontal {
+        (size.area.min_x(), size.area.width())
+    } else {
+        (size.area.min_y(), size.area.height())
+    };
+    let available_size = (container_size - divider_size).max(0.0);
+    let bounds = get_first_pane_bounds(available_size, &props);
+
+    // Share of the first pane once the constraints are applied, this is what gets reported
+    let applied_ratio = move |ratio: f32| {
+        if available_size > 0.0 {
+            get_first_pane_size(ratio, available_size, bounds) / available_size
+        } else {
+            ratio
+        }
+    };
+
+    use_drop(move || {
+        if *status.peek() == SplitPaneStatus::Hovering || *dragging.peek() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    });
+
+    let onmouseenter = move |_: MouseEvent| {
+        status.set(SplitPaneStatus::Hovering);
+        platform.set_cursor(cursor_icon);
+    };
+
+    let onmouseleave = move |_: MouseEvent| {
+        status.set(SplitPaneStatus::Idle);
+        // Keep the cursor while dragging, even if the pointer goes out of the divider
+        if !*dragging.peek() {
+            platform.set_cursor(CursorIcon::default());
+        }
+    };
+
+    let onmousedown = {
+        let onresize = props.onresize;
+        let initial_ratio = props.initial_ratio;
+        move |e: MouseEvent| {
+            e.stop_propagation();
+            let now = Instant::now();
+            let is_double_click = last_click
+                .peek()
+                .map(|last_click| now.duration_since(last_click) <= DOUBLE_CLICK_DURATION)
+                .unwrap_or_default();
+
+            if is_double_click {
+                last_click.set(None);
+                ratio.set(initial_ratio);
+                if let Some(onresize) = &onresize {
+                    onresize.call(applied_ratio(initial_ratio));
+                }
+            } else {
+                last_click.set(Some(now));
+                dragging.set(true);
+            }
+        }
+    };
+
+    let onglobalmouseover = {
+        let onresize = props.onresize;
+        move |e: MouseEvent| {
+            if !*dragging.peek() || available_size <= 0.0 {
+                return;
+            }
+
+            let coordinates = e.get_screen_coordinates();
+            let cursor = if is_horizontal {
+                coordinates.x
+            } else {
+                coordinates.y
+            } as f32;
+            let position = cursor - container_start - divider_size / 2.0;
+            let new_ratio = applied_ratio((position / available_size).clamp(0.0, 1.0));
+
+            if *ratio.peek() != new_ratio {
+                ratio.set(new_ratio);
+                if let Some(onresize) = &onresize {
+                    onresize.call(new_ratio);
+                }
+            }
+        }
+    };
+
+    let onglobalclick = move |_: MouseEvent| {
+        if *dragging.peek() {
+            dragging.set(false);
+            if *status.peek() != SplitPaneStatus::Hovering {
+                platform.set_cursor(CursorIcon::default());
+            }
+        }
+    };
+
+    // Until the container is measured its size is not known, so the ratio is applied as a percentage
+    let first_size = if available_size > 0.0 {
+        get_first_pane_size(*ratio.read(), available_size, bounds).to_string()
+    } else {
+        let ratio = *ratio.read();
+        format!("calc({}% - {})", ratio * 100.0, ratio * divider_size)
+    };
+
+    let (first_width, first_height, divider_width, divider_height) = if is_horizontal {
+        (
+            first_size,
+            "fill".to_string(),
+            theme.divider_size.to_string(),
+            "fill".to_string(),
+        )
+    } else {
+        (
+            "fill".to_string(),
+            first_size,
+            "fill".to_string(),
+            theme.divider_size.to_string(),
+        )
+    };
+
+    let divider_background = if *status.read() == SplitPaneStatus::Hovering || *dragging.read() {
+        &theme.hover_divider_background
+    } else {
+        &theme.divider_background
+    };
+
+    rsx!(
+        rect {
+            reference: node_reference,
+            width: "fill",
+            height: "fill",
+            direction: if is_horizontal { "horizontal" } else { "vertical" },
+            onglobalmouseover,
+            onglobalclick,
+            rect {
+                width: "{first_width}",
+                height: "{first_height}",
+                overflow: "clip",
+                {props.first}
+            }
+            rect {
+                width: "{divider_width}",
+                height: "{divider_height}",
+                background: "{divider_background}",
+                onmouseenter,
+                onmouseleave,
+                onmousedown,
+            }
+            rect {
+                width: "fill",
+                height: "fill",
+                overflow: "clip",
+                {props.second}
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    use super::DOUBLE_CLICK_DURATION;
+
+    /// Drag the divider from `from` to `to` in the horizontal axis.
+    async fn drag(utils: &mut TestingHandler, from: f64, to: f64) {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (from, 250.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (to, 250.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.click((to, 250.0));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+    }
+
+    /// Ratio reported by `onresize`, shown in the second pane.
+    fn reported_ratio(utils: &mut TestingHandler) -> Option<String> {
+        utils
+            .root()
+            .get(0)
+            .get(2)
+            .get(0)
+            .get(0)
+            .text()
+            .map(|text| text.to_string())
+    }
+
+    #[tokio::test]
+    pub async fn split_pane() {
+        fn split_pane_app() -> Element {
+            let mut reported = use_signal(|| 0.5);
+            rsx!(SplitPane {
+                first_min_size: 100.0,
+                onresize: move |ratio| reported.set(ratio),
+                first: rsx!(rect {
+                    width: "fill",
+                    height: "fill",
+                }),
+                second: rsx!(label {
+                    "{reported:.3}"
+                })
+            })
+        }
+
+        let mut utils = launch_test(split_pane_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let container = utils.root().get(0);
+        let first = container.get(0);
+        // (500 - 6) / 2
+        assert_eq!(first.layout().unwrap().area.width(), 247.0);
+
+        // Drag the divider to the left, past the minimum size of the first pane
+        drag(&mut utils, 250.0, 50.0).await;
+
+        let container = utils.root().get(0);
+        assert_eq!(container.get(0).layout().unwrap().area.width(), 100.0);
+        assert_eq!(container.get(2).layout().unwrap().area.width(), 394.0);
+        // 100 / 494
+        assert_eq!(reported_ratio(&mut utils).as_deref(), Some("0.202"));
+    }
+
+    #[tokio::test]
+    pub async fn split_pane_constraints() {
+        fn split_pane_app() -> Element {
+            let mut reported = use_signal(|| 0.5);
+            rsx!(SplitPane {
+                first_max_size: 300.0,
+                second_min_size: 250.0,
+                onresize: move |ratio| reported.set(ratio),
+                first: rsx!(rect {
+                    width: "fill",
+                    height: "fill",
+                }),
+                second: rsx!(label {
+                    "{reported:.3}"
+                })
+            })
+        }
+
+        let mut utils = launch_test(split_pane_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // Drag the divider to the right, the second pane stops it before the maximum of the first pane
+        drag(&mut utils, 250.0, 450.0).await;
+
+        let container = utils.root().get(0);
+        // 494 - 250
+        assert_eq!(container.get(0).layout().unwrap().area.width(), 244.0);
+        assert_eq!(container.get(2).layout().unwrap().area.width(), 250.0);
+        // 244 / 494
+        assert_eq!(reported_ratio(&mut utils).as_deref(), Some("0.494"));
+    }
+
+    #[tokio::test]
+    pub async fn split_pane_double_click_reset() {
+        fn split_pane_app() -> Element {
+            let mut reported = use_signal(|| 0.5);
+            rsx!(SplitPane {
+                initial_ratio: 0.25,
+                onresize: move |ratio| reported.set(ratio),
+                first: rsx!(rect {
+                    width: "fill",
+                    height: "fill",
+                }),
+                second: rsx!(label {
+                    "{reported:.3}"
+                })
+            })
+        }
+
+        let mut utils = launch_test(split_pane_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // 494 * 0.25
+        assert_eq!(
+            utils.root().get(0).get(0).layout().unwrap().area.width(),
+            123.5
+        );
+
+        drag(&mut utils, 125.0, 300.0).await;
+        assert_eq!(
+            utils.root().get(0).get(0).layout().unwrap().area.width(),
+            297.0
+        );
+
+        // So the next click is not taken as the second click of the drag
+        tokio::time::sleep(DOUBLE_CLICK_DURATION).await;
+
+        // Double click the divider
+        for _ in 0..2 {
+            utils.push_event(PlatformEvent::Mouse {
+                name: EventName::MouseDown,
+                cursor: (300.0, 250.0).into(),
+                button: Some(MouseButton::Left),
+            });
+            utils.wait_for_update().await;
+        }
+        utils.click((300.0, 250.0));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(
+            utils.root().get(0).get(0).layout().unwrap().area.width(),
+            123.5
+        );
+        assert_eq!(reported_ratio(&mut utils).as_deref(), Some("0.250"));
+    }
+}
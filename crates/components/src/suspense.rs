@@ -0,0 +1,202 @@
+use std::{cell::RefCell, collections::HashSet, future::Future, rc::Rc};
+
+use dioxus::prelude::*;
+use freya_elements::elements as dioxus_elements;
+
+/// Keeps track of the components waiting for a resource inside of a [`Suspense`] boundary.
+#[derive(Clone)]
+pub struct SuspenseContext {
+    suspended: Rc<RefCell<HashSet<ScopeId>>>,
+    boundary: ScopeId,
+}
+
+impl SuspenseContext {
+    /// Check if any component of the boundary is still waiting.
+    pub fn is_suspended(&self) -> bool {
+        !self.suspended.borrow().is_empty()
+    }
+
+    /// Mark the given component as waiting or not, the boundary is rendered again if it changes what it shows.
+    pub fn set_suspended(&self, scope_id: ScopeId, suspended: bool) {
+        let was_suspended = self.is_suspended();
+        {
+            let mut components = self.suspended.borrow_mut();
+            if suspended {
+                components.insert(scope_id);
+            } else {
+                components.remove(&scope_id);
+            }
+        }
+        if was_suspended != self.is_suspended() {
+            needs_update_any(self.boundary);
+        }
+    }
+}
+
+/// Show a `fallback` while the components inside are waiting for their [`use_suspense_resource`] futures.
+///
+/// The children stay mounted but hidden meanwhile, wrapped in a `rect`, so their futures keep running and
+/// they are shown as soon as all of them resolved. Every resource only suspends its closest boundary,
+/// so nested boundaries show their own fallbacks without hiding the content around them.
+///
+/// Resolved futures don't suspend the boundary even if they failed: the component can hand the error off
+/// to the closest `ErrorBoundary` by throwing it, see the example of [`use_suspense_resource`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     rsx!(
+///         Suspense {
+///             fallback: rsx!(Loader {}),
+///             Profile {}
+///         }
+///     )
+/// }
+///
+/// #[component]
+/// fn Profile() -> Element {
+///     let name = use_suspense_resource(|| async {
+///         tokio::time::sleep(Duration::from_secs(1)).await;
+///         "Freya".to_string()
+///     });
+///
+///     rsx!(label { "{name.read().as_deref().unwrap_or_default()}" })
+/// }
+/// ```
+#[allow(non_snake_case)]
+#[component]
+pub fn Suspense(
+    /// Element shown while the children are waiting.
+    fallback: Element,
+    /// Content that might be waiting for some resources.
+    children: Element,
+) -> Element {
+    let suspense = use_context_provider(|| SuspenseContext {
+        suspended: Rc::default(),
+        boundary: current_scope_id().unwrap(),
+    });
+    let is_suspended = suspense.is_suspended();
+
+    rsx!(
+        if is_suspended {
+            {fallback}
+        }
+        rect {
+            hidden: "{is_suspended}",
+            {children}
+        }
+    )
+}
+
+/// Run a future like `use_resource` does, suspending the closest [`Suspense`] boundary until it resolves.
+///
+/// Restarting the resource keeps showing its previous value until the new one is ready,
+/// after clearing it the boundary shows its fallback again. Outside of a boundary it works just like `use_resource`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use freya::prelude::*;
+/// #[component]
+/// fn Profile() -> Element {
+///     let name = use_suspense_resource(|| async { Ok::<_, String>("Freya".to_string()) });
+///
+///     // Let the closest `ErrorBoundary` show the error
+///     let name = name.read();
+///     let name = match &*name {
+///         Some(name) => name.clone().throw()?,
+///         None => return None,
+///     };
+///
+///     rsx!(label { "{name}" })
+/// }
+/// ```
+pub fn use_suspense_resource<T, F>(future: impl FnMut() -> F + 'static) -> Resource<T>
+where
+    T: 'static,
+    F: Future<Output = T> + 'static,
+{
+    let resource = use_resource(future);
+    let suspense = use_hook(try_consume_context::<SuspenseContext>);
+    let scope_id = use_hook(|| current_scope_id().unwrap());
+
+    use_drop({
+        let suspense = suspense.clone();
+        move || {
+            if let Some(suspense) = suspense {
+                suspense.set_suspended(scope_id, false);
+            }
+        }
+    });
+
+    // Reading the value re-renders the component once it resolves
+    let is_pending = resource.read().is_none();
+    if let Some(suspense) = &suspense {
+        suspense.set_suspended(scope_id, is_pending);
+    }
+
+    resource
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+    use tokio::time::sleep;
+
+    #[tokio::test]
+    pub async fn suspense() {
+        #[component]
+        fn Deferred(delay: u64) -> Element {
+            let value = use_suspense_resource(move || async move {
+                sleep(Duration::from_millis(delay)).await;
+                format!("Ready after {delay}")
+            });
+
+            rsx!(label { "{value.read().as_deref().unwrap_or_default()}" })
+        }
+
+        fn suspense_app() -> Element {
+            rsx!(
+                Suspense {
+                    fallback: rsx!(label { "Loading" }),
+                    Deferred { delay: 50 }
+                    Suspense {
+                        fallback: rsx!(label { "Loading inner" }),
+                        Deferred { delay: 150 }
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(suspense_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The fallback is shown while the content is hidden
+        let root = utils.root();
+        assert_eq!(root.get(0).get(0).text(), Some("Loading"));
+        assert!(root.get(1).layout().is_none());
+
+        // The outer content shows up once its resource resolved, the inner boundary is still waiting
+        sleep(Duration::from_millis(100)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        let root = utils.root();
+        assert!(root.get(0).is_placeholder());
+        let content = root.get(1);
+        assert_eq!(content.get(0).get(0).text(), Some("Ready after 50"));
+        assert_eq!(content.get(1).get(0).text(), Some("Loading inner"));
+
+        sleep(Duration::from_millis(100)).await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        let inner = utils.root().get(1).get(2);
+        assert_eq!(inner.get(0).get(0).text(), Some("Ready after 150"));
+    }
+}
@@ -121,7 +121,7 @@ pub fn Switch(props: SwitchProps) -> Element {
             )
         }
     };
-    let border = if focus.is_selected() {
+    let outline = if focus.is_selected() {
         if props.enabled {
             format!("2 solid {}", theme.enabled_focus_border_fill)
         } else {
@@ -147,13 +147,16 @@ pub fn Switch(props: SwitchProps) -> Element {
             padding: "1",
             corner_radius: "50",
             background: "{background}",
-            border: "{border}",
+            outline: "{outline}",
+            outline_offset: "1",
             onmousedown,
             onmouseenter,
             onmouseleave,
             onkeydown,
             onclick,
             focus_id,
+            role: "switch",
+            aria_checked: "{props.enabled}",
             rect {
                 width: "100%",
                 height: "100%",
@@ -214,6 +217,10 @@ mod test {
         // Check if after clicking it is now enabled
         assert_eq!(label.get(0).text(), Some("true"));
 
+        // The focused Switch announces its state
+        let switch = utils.accessibility_node(utils.focus_id()).unwrap();
+        assert_eq!(switch.checked(), Some(AccessibilityChecked::True));
+
         utils.push_event(PlatformEvent::Mouse {
             name: EventName::Click,
             cursor: (5.0, 5.0).into(),
@@ -224,5 +231,8 @@ mod test {
 
         // Check if after clicking again it is now disabled
         assert_eq!(label.get(0).text(), Some("false"));
+
+        let switch = utils.accessibility_node(utils.focus_id()).unwrap();
+        assert_eq!(switch.checked(), Some(AccessibilityChecked::False));
     }
 }
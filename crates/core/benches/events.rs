@@ -0,0 +1,159 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use freya::prelude::*;
+use freya_core::prelude::*;
+use freya_testing::prelude::*;
+use tokio::sync::mpsc::unbounded_channel;
+
+/// Grid of hoverable cells, so every movement hits a different set of nodes.
+fn grid_app() -> Element {
+    let mut hovered = use_signal(|| 0);
+
+    rsx!(
+        for row in 0..50 {
+            rect {
+                key: "{row}",
+                direction: "horizontal",
+                for column in 0..50 {
+                    rect {
+                        key: "{column}",
+                        width: "10",
+                        height: "10",
+                        onmouseenter: move |_| hovered.set(row * 50 + column),
+                        onmouseover: move |_| {},
+                    }
+                }
+            }
+        }
+    )
+}
+
+/// Long list where every item has its own click handler.
+fn per_item_handlers_app() -> Element {
+    let mut clicked = use_signal(|| 0);
+
+    rsx!(for item in 0..2500 {
+        rect {
+            key: "{item}",
+            width: "100%",
+            height: "2",
+            onclick: move |_| clicked.set(item),
+        }
+    })
+}
+
+/// Long list where a single handler in the parent handles the clicks of every item.
+fn delegated_handler_app() -> Element {
+    let mut clicked = use_signal(|| 0);
+
+    rsx!(
+        rect {
+            width: "100%",
+            onclick: move |e: MouseEvent| {
+                if let Some(item) = e.get_target().dataset().get("item") {
+                    clicked.set(item.parse().unwrap());
+                }
+            },
+            for item in 0..2500 {
+                rect {
+                    key: "{item}",
+                    "data-item": "{item}",
+                    width: "100%",
+                    height: "2",
+                }
+            }
+        }
+    )
+}
+
+/// Clicks all over the list.
+fn clicks(samples: usize) -> EventsQueue {
+    (0..samples)
+        .map(|i| PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, i as f64 * 5000.0 / samples as f64).into(),
+            button: Some(MouseButton::Left),
+        })
+        .collect()
+}
+
+/// Cursor crossing the grid diagonally, faster than it could be rendered.
+fn fast_cursor(samples: usize) -> EventsQueue {
+    (0..samples)
+        .map(|i| {
+            let position = i as f64 * 500.0 / samples as f64;
+            PlatformEvent::Mouse {
+                name: EventName::MouseOver,
+                cursor: (position, position).into(),
+                button: None,
+            }
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut utils = launch_test(grid_app);
+    runtime.block_on(utils.wait_for_update());
+
+    let mut g = c.benchmark_group("events");
+
+    for (name, coalesce) in [
+        ("fast cursor (every sample)", false),
+        ("fast cursor (coalesced)", true),
+    ] {
+        g.bench_function(name, |b| {
+            let fdom = utils.sdom().get();
+            let (event_emitter, mut event_receiver) = unbounded_channel();
+            let mut nodes_state = NodesState::default();
+
+            b.iter_batched(
+                || fast_cursor(100),
+                |mut events| {
+                    if coalesce {
+                        coalesce_mouse_movements(&mut events);
+                    }
+                    process_events(&fdom, &mut events, &event_emitter, &mut nodes_state, 1.0);
+                    while event_receiver.try_recv().is_ok() {}
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    for (name, app) in [
+        (
+            "list clicks (per-item handlers)",
+            per_item_handlers_app as fn() -> Element,
+        ),
+        ("list clicks (delegated handler)", delegated_handler_app),
+    ] {
+        let mut utils = launch_test_with_config(
+            app,
+            TestingConfig {
+                size: (500.0, 5000.0).into(),
+                ..TestingConfig::default()
+            },
+        );
+        runtime.block_on(utils.wait_for_update());
+
+        g.bench_function(name, |b| {
+            let fdom = utils.sdom().get();
+            let (event_emitter, mut event_receiver) = unbounded_channel();
+            let mut nodes_state = NodesState::default();
+
+            b.iter_batched(
+                || clicks(100),
+                |mut events| {
+                    process_events(&fdom, &mut events, &event_emitter, &mut nodes_state, 1.0);
+                    while event_receiver.try_recv().is_ok() {}
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    g.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,119 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use dioxus_core::{ScopeId, VirtualDom};
+use freya::prelude::*;
+use freya_core::prelude::*;
+use freya_engine::prelude::{Rect, *};
+use torin::geometry::Area;
+
+/// Characters typed in the first field, one more before every frame.
+static TYPED: AtomicUsize = AtomicUsize::new(0);
+
+/// Form of bordered fields, only the first one changes its text.
+fn form_app() -> Element {
+    let typed = "a".repeat(TYPED.load(Ordering::Relaxed) % 40);
+
+    rsx!(
+        rect {
+            padding: "10",
+            for field in 0..20 {
+                rect {
+                    key: "{field}",
+                    width: "300",
+                    margin: "3 0",
+                    padding: "6",
+                    corner_radius: "6",
+                    border: "1 inner rgb(200, 200, 200)",
+                    background: "white",
+                    if field == 0 {
+                        label { "{typed}" }
+                    } else {
+                        label { "Field {field}" }
+                    }
+                }
+            }
+        }
+    )
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut g = c.benchmark_group("typing");
+
+    let font_mgr = FontMgr::default();
+    let mut font_collection = FontCollection::new();
+    font_collection.set_dynamic_font_manager(font_mgr.clone());
+    let area = Area::new((0.0, 0.0).into(), (500.0, 800.0).into());
+
+    for (name, partial) in [
+        ("typing with full repaints", false),
+        ("typing with dirty regions", true),
+    ] {
+        let mut vdom = VirtualDom::new(form_app);
+        let mut fdom = FreyaDOM::default();
+        fdom.init_dom(&mut vdom, 1.0);
+        process_layout(&fdom, area, &mut font_collection, 1.0, &[]);
+
+        let mut surface = surfaces::raster_n32_premul((500, 800)).unwrap();
+        let mut dirty_regions = DirtyRegions::default();
+
+        g.bench_function(name, |b| {
+            b.iter(|| {
+                TYPED.fetch_add(1, Ordering::Relaxed);
+                vdom.mark_dirty(ScopeId::ROOT);
+
+                // Same steps as the renderer to paint a frame
+                let (_, must_relayout) = fdom.render_mutations(&mut vdom, 1.0);
+                if must_relayout {
+                    process_layout(&fdom, area, &mut font_collection, 1.0, &[]);
+                }
+                fdom.restyle_paragraphs(&font_collection, &[]);
+
+                let dirty_nodes = fdom.take_dirty_nodes();
+                fdom.take_composited_changes();
+
+                // The raster surface keeps the last frame, just like a back buffer of age 1
+                let dirty_region = if partial {
+                    dirty_regions.compute(&fdom, &dirty_nodes, area, 1)
+                } else {
+                    None
+                };
+
+                let canvas = surface.canvas();
+                canvas.save();
+                if let Some(dirty_region) = dirty_region {
+                    canvas.clip_rect(
+                        Rect::new(
+                            dirty_region.min_x(),
+                            dirty_region.min_y(),
+                            dirty_region.max_x(),
+                            dirty_region.max_y(),
+                        ),
+                        ClipOp::Intersect,
+                        false,
+                    );
+                }
+                canvas.clear(Color::WHITE);
+
+                render_layers(
+                    &mut surface,
+                    &fdom,
+                    &mut font_collection,
+                    &font_mgr,
+                    &[],
+                    RenderPass {
+                        dirty_region: dirty_region
+                            .map(|dirty_region| (dirty_region, &dirty_regions)),
+                        ..RenderPass::default()
+                    },
+                );
+                surface.canvas().restore();
+            })
+        });
+    }
+
+    g.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
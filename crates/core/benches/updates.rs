@@ -0,0 +1,80 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use dioxus_core::{ScopeId, VirtualDom};
+use freya::prelude::*;
+use freya_core::prelude::*;
+use freya_engine::prelude::*;
+use torin::geometry::Area;
+
+/// Toggled before every render of the apps.
+static TOGGLED: AtomicBool = AtomicBool::new(false);
+
+fn toggled() -> bool {
+    TOGGLED.load(Ordering::Relaxed)
+}
+
+/// Long list of labels whose color changes on every render.
+fn color_app() -> Element {
+    let color = if toggled() { "red" } else { "blue" };
+
+    rsx!(
+        rect {
+            color: "{color}",
+            for item in 0..500 {
+                label { key: "{item}", "Item {item}" }
+            }
+        }
+    )
+}
+
+/// Long list of labels whose font size changes on every render.
+fn font_size_app() -> Element {
+    let font_size = if toggled() { "16" } else { "18" };
+
+    rsx!(
+        rect {
+            font_size: "{font_size}",
+            for item in 0..500 {
+                label { key: "{item}", "Item {item}" }
+            }
+        }
+    )
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut g = c.benchmark_group("updates");
+
+    let mut font_collection = FontCollection::new();
+    font_collection.set_dynamic_font_manager(FontMgr::default());
+    let area = Area::new((0.0, 0.0).into(), (500.0, 10000.0).into());
+
+    for (name, app) in [
+        ("color-only update", color_app as fn() -> Element),
+        ("font size update", font_size_app),
+    ] {
+        let mut vdom = VirtualDom::new(app);
+        let mut fdom = FreyaDOM::default();
+        fdom.init_dom(&mut vdom, 1.0);
+        process_layout(&fdom, area, &mut font_collection, 1.0, &[]);
+
+        g.bench_function(name, |b| {
+            b.iter(|| {
+                TOGGLED.fetch_xor(true, Ordering::Relaxed);
+                vdom.mark_dirty(ScopeId::ROOT);
+
+                // Same steps as the renderer before painting a frame
+                let (_, must_relayout) = fdom.render_mutations(&mut vdom, 1.0);
+                if must_relayout {
+                    process_layout(&fdom, area, &mut font_collection, 1.0, &[]);
+                }
+                fdom.restyle_paragraphs(&font_collection, &[]);
+            })
+        });
+    }
+
+    g.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -1,9 +1,10 @@
 use crate::{accessibility::*, dom::DioxusNode};
 use accesskit::{
-    Action, DefaultActionVerb, Node, NodeBuilder, NodeClassSet, Rect, Role, Tree, TreeUpdate,
+    Action, DefaultActionVerb, Live, Node, NodeBuilder, NodeClassSet, Rect, Role, Tree, TreeUpdate,
 };
 
 use freya_node_state::AccessibilityNodeState;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::sync::{Arc, Mutex};
 use torin::prelude::LayoutNode;
 
@@ -11,6 +12,12 @@ pub type SharedAccessibilityManager = Arc<Mutex<AccessibilityManager>>;
 
 pub const ACCESSIBILITY_ROOT_ID: AccessibilityId = AccessibilityId(0);
 
+/// Live region of the messages announced with [`Live::Polite`].
+pub const ACCESSIBILITY_POLITE_ANNOUNCER_ID: AccessibilityId = AccessibilityId(u64::MAX - 1);
+
+/// Live region of the messages announced with [`Live::Assertive`].
+pub const ACCESSIBILITY_ASSERTIVE_ANNOUNCER_ID: AccessibilityId = AccessibilityId(u64::MAX);
+
 /// Manages the Accessibility integration.
 pub struct AccessibilityManager {
     /// Accessibility Nodes
@@ -19,6 +26,14 @@ pub struct AccessibilityManager {
     pub node_classes: NodeClassSet,
     /// Current focused Accessibility Node.
     pub focused_id: AccessibilityId,
+    /// Accessibility Nodes that are skipped when navigating with the `Tab` key.
+    pub tab_skipped_ids: FxHashSet<AccessibilityId>,
+    /// Accessibility Nodes placed before the rest when navigating with the `Tab` key, with their positions.
+    pub tab_orders: FxHashMap<AccessibilityId, i32>,
+    /// Last message announced with [`Live::Polite`].
+    pub polite_announcement: Option<String>,
+    /// Last message announced with [`Live::Assertive`].
+    pub assertive_announcement: Option<String>,
 }
 
 impl AccessibilityManager {
@@ -27,6 +42,10 @@ impl AccessibilityManager {
             focused_id,
             node_classes: NodeClassSet::default(),
             nodes: Vec::default(),
+            tab_skipped_ids: FxHashSet::default(),
+            tab_orders: FxHashMap::default(),
+            polite_announcement: None,
+            assertive_announcement: None,
         }
     }
 
@@ -38,6 +57,8 @@ impl AccessibilityManager {
     /// Clear the Accessibility Nodes.
     pub fn clear(&mut self) {
         self.nodes.clear();
+        self.tab_skipped_ids.clear();
+        self.tab_orders.clear();
     }
 
     pub fn push_node(&mut self, id: AccessibilityId, node: Node) {
@@ -68,15 +89,43 @@ impl AccessibilityManager {
         }
 
         // Set name
-        if let Some(name) = &node_accessibility.name {
+        if let Some(name) = node_accessibility
+            .label
+            .as_ref()
+            .or(node_accessibility.name.as_ref())
+        {
             builder.set_name(name.to_owned());
         }
 
+        // Set description
+        if let Some(description) = &node_accessibility.description {
+            builder.set_description(description.to_owned());
+        }
+
         // Set role
         if let Some(role) = node_accessibility.role {
             builder.set_role(role);
         }
 
+        // Set live region
+        if let Some(live) = node_accessibility.live {
+            builder.set_live(live);
+        }
+
+        // Set the state
+        if let Some(checked) = node_accessibility.checked {
+            builder.set_checked(checked);
+        }
+        if let Some(expanded) = node_accessibility.expanded {
+            builder.set_expanded(expanded);
+        }
+        if let Some(selected) = node_accessibility.selected {
+            builder.set_selected(selected);
+        }
+        if node_accessibility.disabled {
+            builder.set_disabled();
+        }
+
         // Set the area
         let area = layout_node.area.to_f64();
         builder.set_bounds(Rect {
@@ -94,6 +143,12 @@ impl AccessibilityManager {
             builder.set_default_action_verb(DefaultActionVerb::Focus);
         }
 
+        if !node_accessibility.tab_focusable {
+            self.tab_skipped_ids.insert(accessibility_id);
+        } else if let Some(tab_order) = node_accessibility.tab_order() {
+            self.tab_orders.insert(accessibility_id, tab_order);
+        }
+
         // Insert the node into the Tree
         let node = builder.build(&mut self.node_classes);
         self.push_node(accessibility_id, node);
@@ -116,6 +171,53 @@ impl AccessibilityManager {
         }
     }
 
+    /// Announce a message with screen readers, without a visible element.
+    ///
+    /// [`Live::Polite`] messages wait for the screen reader to finish speaking,
+    /// while [`Live::Assertive`] messages interrupt it. [`Live::Off`] clears both.
+    pub fn announce(&mut self, message: String, live: Live) -> TreeUpdate {
+        match live {
+            Live::Polite => self.polite_announcement = Some(message),
+            Live::Assertive => self.assertive_announcement = Some(message),
+            Live::Off => {
+                self.polite_announcement = None;
+                self.assertive_announcement = None;
+            }
+        }
+
+        TreeUpdate {
+            nodes: self.build_announcers(),
+            tree: None,
+            focus: self.focused_id,
+        }
+    }
+
+    /// Create the live regions of the announcements.
+    pub fn build_announcers(&mut self) -> Vec<(AccessibilityId, Node)> {
+        [
+            (
+                ACCESSIBILITY_POLITE_ANNOUNCER_ID,
+                Live::Polite,
+                self.polite_announcement.clone(),
+            ),
+            (
+                ACCESSIBILITY_ASSERTIVE_ANNOUNCER_ID,
+                Live::Assertive,
+                self.assertive_announcement.clone(),
+            ),
+        ]
+        .into_iter()
+        .map(|(id, live, message)| {
+            let mut builder = NodeBuilder::new(Role::Status);
+            builder.set_live(live);
+            if let Some(message) = message {
+                builder.set_value(message);
+            }
+            (id, builder.build(&mut self.node_classes))
+        })
+        .collect()
+    }
+
     /// Create the root Accessibility Node.
     pub fn build_root(&mut self, root_name: &str) -> Node {
         let mut builder = NodeBuilder::new(Role::Window);
@@ -124,6 +226,10 @@ impl AccessibilityManager {
             self.nodes
                 .iter()
                 .map(|(id, _)| *id)
+                .chain([
+                    ACCESSIBILITY_POLITE_ANNOUNCER_ID,
+                    ACCESSIBILITY_ASSERTIVE_ANNOUNCER_ID,
+                ])
                 .collect::<Vec<AccessibilityId>>(),
         );
 
@@ -135,6 +241,7 @@ impl AccessibilityManager {
         let root = self.build_root(root_name);
         let mut nodes = vec![(root_id, root)];
         nodes.extend(self.nodes.clone());
+        nodes.extend(self.build_announcers());
         nodes.reverse();
 
         let focus = self
@@ -157,9 +264,18 @@ impl AccessibilityManager {
     }
 
     /// Focus the next/previous Node starting from the currently focused Node.
+    /// Nodes that are not `tab_focusable` are skipped, unless they are the focused one,
+    /// and the ones with a positive `tab_index` go first.
     pub fn set_focus_on_next_node(&mut self, direction: AccessibilityFocusDirection) -> TreeUpdate {
-        let node_index = self
+        let mut nodes = self
             .nodes
+            .iter()
+            .filter(|(id, _)| *id == self.focused_id || !self.tab_skipped_ids.contains(id))
+            .collect::<Vec<_>>();
+        // The sort is stable so the Nodes in the same position keep the order of the document
+        nodes.sort_by_key(|(id, _)| self.tab_orders.get(id).copied().unwrap_or(i32::MAX));
+
+        let node_index = nodes
             .iter()
             .enumerate()
             .find(|(_, node)| node.0 == self.focused_id)
@@ -168,24 +284,24 @@ impl AccessibilityManager {
         let target_node = if direction == AccessibilityFocusDirection::Forward {
             // Find the next Node
             if let Some(node_index) = node_index {
-                if node_index == self.nodes.len() - 1 {
-                    self.nodes.first()
+                if node_index == nodes.len() - 1 {
+                    nodes.first()
                 } else {
-                    self.nodes.get(node_index + 1)
+                    nodes.get(node_index + 1)
                 }
             } else {
-                self.nodes.first()
+                nodes.first()
             }
         } else {
             // Find the previous Node
             if let Some(node_index) = node_index {
                 if node_index == 0 {
-                    self.nodes.last()
+                    nodes.last()
                 } else {
-                    self.nodes.get(node_index - 1)
+                    nodes.get(node_index - 1)
                 }
             } else {
-                self.nodes.last()
+                nodes.last()
             }
         };
 
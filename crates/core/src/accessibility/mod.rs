@@ -7,7 +7,7 @@ use crate::{
 };
 use freya_native_core::{node::NodeType, real_dom::NodeImmutable, tags::TagName, NodeId};
 use freya_node_state::AccessibilityNodeState;
-use torin::torin::Torin;
+use torin::{dom_adapter::LayoutNode, torin::Torin};
 
 /// Direction for the next Accessibility Node to be focused.
 #[derive(PartialEq)]
@@ -45,7 +45,9 @@ impl NodeAccessibility for DioxusNode<'_> {
             .filter_map(|child| {
                 if child.node_type().is_visible_element() {
                     let node_accessibility = &*child.get::<AccessibilityNodeState>().unwrap();
-                    node_accessibility.accessibility_id
+                    node_accessibility
+                        .accessibility_id
+                        .filter(|_| !node_accessibility.hidden)
                 } else {
                     None
                 }
@@ -59,13 +61,22 @@ pub fn process_accessibility(
     rdom: &DioxusDOM,
     accessibility_manager: &mut AccessibilityManager,
 ) {
+    // Nodes skipped in the layout, e.g. by `content_visibility`, are still accessible
+    let skipped_layout_node = LayoutNode::default();
+
     rdom.traverse_depth_first_advanced(|node| {
         if !node.node_type().is_element() {
             return false;
         }
 
-        let layout_node = layout.get(node.id()).unwrap();
+        let layout_node = layout.get(node.id()).unwrap_or(&skipped_layout_node);
         let node_accessibility = &*node.get::<AccessibilityNodeState>().unwrap();
+
+        // Hidden subtrees are left out entirely, including their focusable Nodes
+        if node_accessibility.hidden {
+            return false;
+        }
+
         if let Some(accessibility_id) = node_accessibility.accessibility_id {
             accessibility_manager.add_node(
                 &node,
@@ -0,0 +1,176 @@
+use std::collections::VecDeque;
+
+use freya_native_core::{real_dom::NodeImmutable, tree::TreeRef, NodeId};
+use freya_node_state::{BorderAlignment, BorderStyle, ShadowPosition, Style, Transform};
+use rustc_hash::{FxHashMap, FxHashSet};
+use torin::prelude::*;
+
+use crate::dom::{DioxusNode, FreyaDOM};
+
+/// Maximum fraction of the window that can be dirty before falling back to a full repaint.
+const MAX_DIRTY_RATIO: f32 = 0.5;
+
+/// How many of the last frames have their dirty region remembered.
+const MAX_HISTORY: usize = 3;
+
+/// Keeps track of the regions of the window that changed between frames, so only those need to be repainted.
+///
+/// The dirty region of a frame is the union of the old and new painted areas of every node that
+/// changed its state, its layout, or was added or removed. Whenever it is not safe to repaint
+/// just a region (e.g. rotated nodes, unknown back buffer contents or a region too big to be worth it),
+/// the whole window is repainted instead.
+#[derive(Default)]
+pub struct DirtyRegions {
+    /// Area painted by every node in the last frame.
+    painted_areas: FxHashMap<NodeId, Area>,
+    /// Dirty region of each of the last frames, the most recent first. `None` means the whole window.
+    history: VecDeque<Option<Area>>,
+}
+
+impl DirtyRegions {
+    /// Forget about the previous frames, so the next one gets fully repainted.
+    pub fn invalidate(&mut self) {
+        self.painted_areas.clear();
+        self.history.clear();
+    }
+
+    /// Get the area painted by the given node in the last frame.
+    pub fn painted_area(&self, node_id: &NodeId) -> Option<&Area> {
+        self.painted_areas.get(node_id)
+    }
+
+    /// Compute the region of the window that must be repainted in this frame.
+    ///
+    /// `buffer_age` is the amount of frames since the back buffer was last presented, `0` if unknown.
+    /// Returns `None` if the whole window must be repainted.
+    pub fn compute(
+        &mut self,
+        fdom: &FreyaDOM,
+        dirty_nodes: &FxHashSet<NodeId>,
+        window_area: Area,
+        buffer_age: usize,
+    ) -> Option<Area> {
+        let layout = fdom.layout();
+        let rdom = fdom.rdom();
+
+        // Nodes without layout, e.g text nodes, are painted by their closest ancestor with layout
+        let dirty_nodes = dirty_nodes
+            .iter()
+            .filter_map(|node_id| {
+                let mut node_id = *node_id;
+                while layout.get(node_id).is_none() {
+                    node_id = rdom.tree_ref().parent_id(node_id)?;
+                }
+                Some(node_id)
+            })
+            .collect::<FxHashSet<NodeId>>();
+
+        let mut frame_region = None;
+        // Nothing has been painted yet
+        let mut must_repaint_all = self.history.is_empty();
+        let mut painted_areas = FxHashMap::default();
+
+        for layer in fdom.layers().layers().values() {
+            for node_id in layer {
+                let Some((node, layout_node)) = rdom.get(*node_id).zip(layout.get(*node_id)) else {
+                    continue;
+                };
+
+                // Rotated nodes paint outside of their area
+                if node.get::<Transform>().unwrap().rotate_degs.is_some() {
+                    must_repaint_all = true;
+                }
+
+                // Filters like blurs and shadows spread whatever their descendants paint
+                if !node.get::<Style>().unwrap().filters.0.is_empty() {
+                    must_repaint_all = true;
+                }
+
+                let area = get_painted_area(&node, &layout_node.area);
+                let previous_area = self.painted_areas.remove(node_id);
+
+                if previous_area != Some(area) || dirty_nodes.contains(node_id) {
+                    join_region(&mut frame_region, area);
+                    if let Some(previous_area) = previous_area {
+                        join_region(&mut frame_region, previous_area);
+                    }
+                }
+
+                painted_areas.insert(*node_id, area);
+            }
+        }
+
+        // The remaining nodes were removed
+        for area in self.painted_areas.values() {
+            join_region(&mut frame_region, *area);
+        }
+
+        self.painted_areas = painted_areas;
+
+        let frame_region = if must_repaint_all {
+            None
+        } else {
+            Some(
+                frame_region
+                    .and_then(|region: Area| region.intersection(&window_area))
+                    .unwrap_or_default(),
+            )
+        };
+
+        self.history.push_front(frame_region);
+        self.history.truncate(MAX_HISTORY);
+
+        // The back buffer contents are unknown
+        if buffer_age == 0 || buffer_age > self.history.len() {
+            return None;
+        }
+
+        // The back buffer is missing the changes of the last `buffer_age` frames
+        let mut region: Option<Area> = None;
+        for frame_region in self.history.iter().take(buffer_age) {
+            join_region(&mut region, (*frame_region)?);
+        }
+        let region = region.unwrap_or_default();
+
+        if region.area() > window_area.area() * MAX_DIRTY_RATIO {
+            None
+        } else {
+            Some(region)
+        }
+    }
+}
+
+fn join_region(region: &mut Option<Area>, area: Area) {
+    *region = Some(match region {
+        Some(region) => region.union(&area),
+        None => area,
+    });
+}
+
+/// Get the area where a node paints, including its outer borders and shadows.
+pub fn get_painted_area(node: &DioxusNode, area: &Area) -> Area {
+    let style = node.get::<Style>().unwrap();
+
+    let border_width = match style.border.alignment {
+        BorderAlignment::Inner => 0.,
+        BorderAlignment::Center => style.border.width / 2.,
+        BorderAlignment::Outer => style.border.width,
+    };
+
+    let shadow_width = style
+        .shadows
+        .iter()
+        .filter(|shadow| shadow.position == ShadowPosition::Normal)
+        .map(|shadow| shadow.x.abs().max(shadow.y.abs()) + shadow.spread + shadow.blur * 2.)
+        .fold(0., f32::max);
+
+    let outline_width = if style.outline.style != BorderStyle::None {
+        style.outline.width + style.outline_offset
+    } else {
+        0.
+    };
+
+    let outset = border_width.max(shadow_width).max(outline_width).ceil();
+
+    area.inflate(outset, outset)
+}
@@ -0,0 +1,88 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+struct ClockState {
+    origin: Instant,
+    /// Time the clock advanced until it was last paused, plus the steps.
+    elapsed: Duration,
+    /// When the clock was last resumed, `None` while it's paused.
+    running_since: Option<Instant>,
+    reduced_motion: bool,
+}
+
+/// Time source of every animation of an app: the attribute transitions, the keyframes animations
+/// and the animations of `use_animation`. Pausing it freezes them all while the app stays interactive,
+/// e.g. to debug an animation frame by frame with [`AnimationClock::step`].
+///
+/// It also tells them whether the motion is reduced, for users sensitive to it, see [`AnimationClock::set_reduced_motion`].
+///
+/// The clones share the same time.
+#[derive(Clone)]
+pub struct AnimationClock {
+    state: Arc<Mutex<ClockState>>,
+}
+
+impl Default for AnimationClock {
+    fn default() -> Self {
+        let now = Instant::now();
+        Self {
+            state: Arc::new(Mutex::new(ClockState {
+                origin: now,
+                elapsed: Duration::ZERO,
+                running_since: Some(now),
+                reduced_motion: false,
+            })),
+        }
+    }
+}
+
+impl AnimationClock {
+    /// Get the current time of the animations, which doesn't advance while the clock is paused.
+    pub fn now(&self) -> Instant {
+        let state = self.state.lock().unwrap();
+        let running = state
+            .running_since
+            .map(|running_since| running_since.elapsed())
+            .unwrap_or_default();
+        state.origin + state.elapsed + running
+    }
+
+    /// Stop the time of the animations until [`AnimationClock::resume`] is called.
+    pub fn pause(&self) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(running_since) = state.running_since.take() {
+            state.elapsed += running_since.elapsed();
+        }
+    }
+
+    /// Let the time of the animations advance again, from where it was paused.
+    pub fn resume(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.running_since.is_none() {
+            state.running_since = Some(Instant::now());
+        }
+    }
+
+    /// Advance the time of the animations by the given duration, useful while paused.
+    pub fn step(&self, duration: Duration) {
+        self.state.lock().unwrap().elapsed += duration;
+    }
+
+    /// Check if the clock is paused.
+    pub fn is_paused(&self) -> bool {
+        self.state.lock().unwrap().running_since.is_none()
+    }
+
+    /// Reduce the motion of the app: the transitions and the animations that end are skipped to their end,
+    /// and the infinite animations aren't played at all. The ones already running are not affected.
+    pub fn set_reduced_motion(&self, reduced_motion: bool) {
+        self.state.lock().unwrap().reduced_motion = reduced_motion;
+    }
+
+    /// Check if the motion of the app is reduced.
+    pub fn prefers_reduced_motion(&self) -> bool {
+        self.state.lock().unwrap().reduced_motion
+    }
+}
@@ -0,0 +1,361 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use freya_native_core::{
+    node::OwnedAttributeValue,
+    prelude::{AttributeName, NodeImmutable, NodeType},
+    real_dom::NodeTypeMut,
+    tree::TreeRef,
+    NodeId,
+};
+use freya_node_state::{
+    Animation, AnimationIterations, AnimationProgress, CustomAttributeValues, Keyframes, Parse,
+};
+use rustc_hash::FxHashMap;
+
+use super::{AnimationClock, DioxusDOM};
+
+/// Keyframes available to the `animation` attribute, by name.
+pub type KeyframesRegistry = Arc<Mutex<FxHashMap<String, Keyframes>>>;
+
+type AttributeValue = Option<OwnedAttributeValue<CustomAttributeValues>>;
+
+/// Keyframes animation played on a node.
+struct RunningAnimation {
+    animation: Animation,
+    keyframes: Keyframes,
+    started_at: Instant,
+    paused_at: Option<Instant>,
+    /// Values of the animated attributes as set by the app, restored once the animation stops.
+    base: FxHashMap<AttributeName, AttributeValue>,
+    /// Played by a node removed by the app, which must be removed from the RealDOM once it ends.
+    exiting: bool,
+}
+
+impl RunningAnimation {
+    fn elapsed(&self, now: Instant) -> Duration {
+        self.paused_at
+            .unwrap_or(now)
+            .duration_since(self.started_at)
+    }
+}
+
+/// Keeps track of the nodes playing a keyframes animation because of their `animation` attribute,
+/// or because of their `exit_animation` attribute once they were removed.
+pub struct AnimationsManager {
+    keyframes: KeyframesRegistry,
+    running: FxHashMap<NodeId, RunningAnimation>,
+    exited: Vec<NodeId>,
+    clock: AnimationClock,
+}
+
+impl Default for AnimationsManager {
+    fn default() -> Self {
+        Self::new(AnimationClock::default())
+    }
+}
+
+impl AnimationsManager {
+    pub fn new(clock: AnimationClock) -> Self {
+        let mut keyframes = FxHashMap::default();
+
+        // Built-in keyframes
+        keyframes.insert(
+            "spin".to_string(),
+            Keyframes::new()
+                .keyframe(0.0, [("rotate", "0deg")])
+                .keyframe(1.0, [("rotate", "360deg")]),
+        );
+
+        Self {
+            keyframes: Arc::new(Mutex::new(keyframes)),
+            running: FxHashMap::default(),
+            exited: Vec::new(),
+            clock,
+        }
+    }
+
+    /// Get the [`KeyframesRegistry`] the animations are looked up in.
+    pub fn keyframes(&self) -> &KeyframesRegistry {
+        &self.keyframes
+    }
+
+    /// Check if there is any animation playing, paused animations don't count.
+    pub fn is_running(&self) -> bool {
+        self.running
+            .values()
+            .any(|running| running.paused_at.is_none())
+    }
+
+    /// Start, update or stop the animation of every node of the given subtree according to their `animation` attribute.
+    pub fn sync_subtree(&mut self, rdom: &mut DioxusDOM, node_id: NodeId) {
+        let mut stack = vec![node_id];
+        let mut animated = Vec::new();
+        {
+            let tree = rdom.tree_ref();
+            while let Some(node_id) = stack.pop() {
+                if let Some(node) = rdom.get(node_id) {
+                    if let NodeType::Element(element) = &*node.node_type() {
+                        if element.attributes.contains_key(&AttributeName::Animation) {
+                            animated.push(node_id);
+                        }
+                    }
+                    stack.extend(tree.children_ids(node_id));
+                }
+            }
+        }
+
+        for node_id in animated {
+            self.sync(rdom, node_id);
+        }
+    }
+
+    /// Start, update or stop the animation of the given node according to its `animation` attribute.
+    pub fn sync(&mut self, rdom: &mut DioxusDOM, node_id: NodeId) {
+        let animation = rdom.get(node_id).and_then(|node| {
+            let NodeType::Element(element) = &*node.node_type() else {
+                return None;
+            };
+            element
+                .attributes
+                .get(&AttributeName::Animation)
+                .and_then(|animation| animation.as_text())
+                .and_then(|animation| Animation::parse(animation).ok())
+        });
+        let keyframes = animation
+            .as_ref()
+            .and_then(|animation| self.keyframes.lock().unwrap().get(&animation.name).cloned());
+
+        if let Some(running) = self.running.get_mut(&node_id) {
+            match &animation {
+                // Only pause or resume it, without restarting it
+                Some(animation)
+                    if animation.same_as(&running.animation)
+                        && keyframes.as_ref() == Some(&running.keyframes) =>
+                {
+                    let now = self.clock.now();
+                    match (running.paused_at, animation.paused) {
+                        (None, true) => running.paused_at = Some(now),
+                        (Some(paused_at), false) => {
+                            running.started_at += now.duration_since(paused_at);
+                            running.paused_at = None;
+                        }
+                        _ => {}
+                    }
+                    running.animation = animation.clone();
+                    return;
+                }
+                _ => self.stop(rdom, node_id),
+            }
+        }
+
+        if let (Some(animation), Some(keyframes)) = (animation, keyframes) {
+            self.start(rdom, node_id, animation, keyframes, false);
+        }
+    }
+
+    /// Start the animation of the `exit_animation` attribute of a node that was removed by the app.
+    /// Returns `false` if it has none, in which case it must be removed right away,
+    /// otherwise it's reported by [`AnimationsManager::take_exited`] once it ends.
+    pub fn exit(&mut self, rdom: &mut DioxusDOM, node_id: NodeId) -> bool {
+        // It's removed right away when the motion is reduced
+        if self.clock.prefers_reduced_motion() {
+            return false;
+        }
+
+        let animation = rdom.get(node_id).and_then(|node| {
+            let NodeType::Element(element) = &*node.node_type() else {
+                return None;
+            };
+            element
+                .attributes
+                .get(&AttributeName::ExitAnimation)
+                .and_then(|animation| animation.as_text())
+                .and_then(|animation| Animation::parse(animation).ok())
+        });
+        let Some(mut animation) = animation else {
+            return false;
+        };
+        let Some(keyframes) = self.keyframes.lock().unwrap().get(&animation.name).cloned() else {
+            return false;
+        };
+
+        // It replaces whatever was playing, and it must end as the app can't stop it anymore
+        if self.running.contains_key(&node_id) {
+            self.stop(rdom, node_id);
+        }
+        animation.paused = false;
+        if animation.iterations == AnimationIterations::Infinite {
+            animation.iterations = AnimationIterations::Finite(1.0);
+        }
+
+        self.start(rdom, node_id, animation, keyframes, true);
+        true
+    }
+
+    /// Take the removed nodes whose exit animation ended since the last call, they can now be removed from the RealDOM.
+    pub fn take_exited(&mut self) -> Vec<NodeId> {
+        std::mem::take(&mut self.exited)
+    }
+
+    fn start(
+        &mut self,
+        rdom: &mut DioxusDOM,
+        node_id: NodeId,
+        animation: Animation,
+        keyframes: Keyframes,
+        exiting: bool,
+    ) {
+        // Remember the current values so they can be restored
+        let base = {
+            let Some(node) = rdom.get(node_id) else {
+                return;
+            };
+            let node_type = node.node_type();
+            let NodeType::Element(element) = &*node_type else {
+                return;
+            };
+            keyframes
+                .attributes()
+                .into_iter()
+                .map(|attribute| (attribute, element.attributes.get(&attribute).cloned()))
+                .collect()
+        };
+
+        // Skip straight to the end when the motion is reduced, the infinite animations aren't played at all
+        let reduced_motion = self.clock.prefers_reduced_motion() && !exiting;
+        if reduced_motion && animation.iterations == AnimationIterations::Infinite {
+            return;
+        }
+
+        let now = self.clock.now();
+        self.running.insert(
+            node_id,
+            RunningAnimation {
+                paused_at: animation.paused.then_some(now),
+                animation,
+                keyframes,
+                started_at: now,
+                base,
+                exiting,
+            },
+        );
+        if reduced_motion {
+            self.finish(rdom, node_id);
+        } else {
+            self.apply_node(rdom, node_id, now);
+        }
+    }
+
+    /// Remember the value the app gave to an attribute while it's being animated.
+    /// Returns `true` if the attribute is animated, in which case the value must not be applied yet.
+    pub fn set_base(
+        &mut self,
+        node_id: NodeId,
+        attribute: AttributeName,
+        value: AttributeValue,
+    ) -> bool {
+        let Some(running) = self.running.get_mut(&node_id) else {
+            return false;
+        };
+        let Some(base) = running.base.get_mut(&attribute) else {
+            return false;
+        };
+        *base = value;
+        true
+    }
+
+    /// Write the current values of every playing animation into the RealDOM,
+    /// the finished animations are forgotten after applying their fill mode.
+    pub fn apply(&mut self, rdom: &mut DioxusDOM) {
+        let now = self.clock.now();
+        let node_ids = self.running.keys().copied().collect::<Vec<_>>();
+        for node_id in node_ids {
+            self.apply_node(rdom, node_id, now);
+        }
+    }
+
+    fn apply_node(&mut self, rdom: &mut DioxusDOM, node_id: NodeId, now: Instant) {
+        let Some(running) = self.running.get(&node_id) else {
+            return;
+        };
+        if rdom.get(node_id).is_none() {
+            self.running.remove(&node_id);
+            return;
+        }
+
+        let animation = &running.animation;
+        let progress = match animation.progress(running.elapsed(now)) {
+            AnimationProgress::Delayed if animation.fill_mode.backwards() => {
+                Some(animation.start_progress())
+            }
+            AnimationProgress::Delayed => None,
+            AnimationProgress::Running(progress) => Some(progress),
+            AnimationProgress::Finished(progress) => {
+                if running.exiting {
+                    // Keep the last keyframe until it's removed
+                    self.write(rdom, node_id, Some(progress));
+                    self.running.remove(&node_id);
+                    self.exited.push(node_id);
+                } else if animation.fill_mode.forwards() {
+                    self.write(rdom, node_id, Some(progress));
+                    self.running.remove(&node_id);
+                } else {
+                    self.stop(rdom, node_id);
+                }
+                return;
+            }
+        };
+
+        self.write(rdom, node_id, progress);
+    }
+
+    /// Write the values at the given progress of the keyframes, or the base values if there is none.
+    fn write(&self, rdom: &mut DioxusDOM, node_id: NodeId, progress: Option<f32>) {
+        let Some(running) = self.running.get(&node_id) else {
+            return;
+        };
+        let Some(mut node) = rdom.get_mut(node_id) else {
+            return;
+        };
+        let mut node_type = node.node_type_mut();
+        let NodeTypeMut::Element(element) = &mut node_type else {
+            return;
+        };
+
+        for (attribute, base) in &running.base {
+            let value = progress
+                .and_then(|progress| running.keyframes.sample(attribute, progress))
+                .map(OwnedAttributeValue::Text)
+                .or_else(|| base.clone());
+            match value {
+                Some(value) => {
+                    element.set_attribute(*attribute, value);
+                }
+                None => {
+                    element.remove_attribute(attribute);
+                }
+            }
+        }
+    }
+
+    /// End the animation of the given node right away, applying its fill mode.
+    fn finish(&mut self, rdom: &mut DioxusDOM, node_id: NodeId) {
+        let Some(running) = self.running.get(&node_id) else {
+            return;
+        };
+        match running.animation.progress(Duration::MAX) {
+            AnimationProgress::Finished(progress) if running.animation.fill_mode.forwards() => {
+                self.write(rdom, node_id, Some(progress));
+                self.running.remove(&node_id);
+            }
+            _ => self.stop(rdom, node_id),
+        }
+    }
+
+    /// Stop the animation of the given node, restoring the values of its attributes.
+    fn stop(&mut self, rdom: &mut DioxusDOM, node_id: NodeId) {
+        self.write(rdom, node_id, None);
+        self.running.remove(&node_id);
+    }
+}
@@ -47,14 +47,22 @@ impl DOMAdapter<NodeId> for DioxusDOMAdapter<'_> {
             maximum_height: layout.maximum_height,
             direction: layout.direction,
             padding: layout.padding,
+            padding_percentage: layout.padding_percentage,
+            border: layout.border,
+            box_sizing: layout.box_sizing,
             margin: layout.margin,
             main_alignment: layout.main_alignment,
             cross_alignment: layout.cross_alignment,
             offset_x: layout.offset_x,
             offset_y: layout.offset_y,
+            scrollable: layout.scrollable,
             has_layout_references: layout.node_ref.is_some(),
             position: layout.position,
             content: layout.content,
+            content_visibility: layout.content_visibility,
+            contain_intrinsic_size: layout.contain_intrinsic_size,
+            order: layout.order,
+            flex: layout.flex.clone(),
             contains_text,
         })
     }
@@ -109,9 +117,18 @@ fn is_node_valid(
             // Make sure this Node isn't part of an unconnected Node
             // This walkes up to the ancestor that has a height of 0 and checks if it has the same ID as the root Node
             // If it has the same ID, it means that is not an unconnected ID, otherwise, it is and should be skipped.
+            // Nodes inside a hidden subtree are skipped too.
             let tree = rdom.tree_ref();
             let mut current = *node_id;
             loop {
+                let is_hidden = rdom
+                    .get(current)
+                    .and_then(|node| node.get::<LayoutState>().map(|layout| layout.hidden))
+                    .unwrap_or_default();
+                if is_hidden {
+                    break 'validation false;
+                }
+
                 let height = tree.height(current);
                 if let Some(height) = height {
                     if height == 0 {
@@ -1,23 +1,39 @@
 use std::sync::{Arc, Mutex};
 
 use dioxus_core::VirtualDom;
+use freya_engine::prelude::FontCollection;
 use freya_native_core::{
+    attributes::AttributeName,
+    events::EventName,
+    node_ref::AttributeMask,
     prelude::{DioxusState, State},
-    real_dom::{NodeRef, RealDom},
+    real_dom::{NodeImmutable, NodeRef, RealDom},
+    tags::TagName,
+    tree::TreeRef,
     NodeId, SendAnyMap,
 };
 
-use freya_common::{Layers, ParagraphElements};
+use freya_common::{CachedParagraph, Layers, ParagraphElements, RestyledParagraphs};
 use freya_node_state::{
-    AccessibilityNodeState, CursorSettings, CustomAttributeValues, FontStyleState, LayerState,
-    LayoutState, References, Style, Transform, ViewportState,
+    AccessibilityNodeState, CursorSettings, CustomAttributeValues, CustomElements, FontStyleState,
+    LayerState, LayoutState, References, RenderSettings, Style, Transform, ViewportState,
+    WillChange,
 };
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::sync::MutexGuard;
 use torin::prelude::*;
 use tracing::info;
 use uuid::Uuid;
 
-use super::{mutations_writer::MutationsWriter, paragraph_utils::measure_paragraph};
+use crate::letterbox::Letterbox;
+
+use crate::layout::{create_label, create_paragraph};
+
+use super::{
+    mutations_writer::{unmount_node, MutationsWriter},
+    paragraph_utils::measure_paragraph,
+    AnimationClock, AnimationsManager, KeyframesRegistry, TransitionsManager,
+};
 
 pub type DioxusDOM = RealDom<CustomAttributeValues>;
 pub type DioxusNode<'a> = NodeRef<'a, CustomAttributeValues>;
@@ -97,7 +113,20 @@ pub struct FreyaDOM {
     dioxus_integration_state: DioxusState,
     torin: Arc<Mutex<Torin<NodeId>>>,
     paragraphs: ParagraphElements,
+    restyled_paragraphs: RestyledParagraphs,
     layers: Layers,
+    dirty_nodes: FxHashSet<NodeId>,
+    /// Dirty nodes whose only changes were their rotation or opacity, see [`FreyaDOM::take_composited_changes`].
+    composited_changes: FxHashMap<NodeId, WillChange>,
+    mounted_nodes: FxHashSet<NodeId>,
+    /// Last known offsets of the nodes listening for `scroll`.
+    scroll_offsets: FxHashMap<NodeId, (f32, f32)>,
+    render_settings: RenderSettings,
+    custom_elements: CustomElements,
+    letterbox: Option<Letterbox>,
+    transitions: TransitionsManager,
+    animations: AnimationsManager,
+    animation_clock: AnimationClock,
 }
 
 impl Default for FreyaDOM {
@@ -114,12 +143,24 @@ impl Default for FreyaDOM {
             LayerState::to_type_erased(),
         ]);
         let dioxus_integration_state = DioxusState::create(&mut rdom);
+        let animation_clock = AnimationClock::default();
         Self {
             rdom,
             dioxus_integration_state,
             torin: Arc::new(Mutex::new(Torin::new())),
             paragraphs: ParagraphElements::default(),
+            restyled_paragraphs: RestyledParagraphs::default(),
             layers: Layers::default(),
+            dirty_nodes: FxHashSet::default(),
+            composited_changes: FxHashMap::default(),
+            mounted_nodes: FxHashSet::default(),
+            scroll_offsets: FxHashMap::default(),
+            render_settings: RenderSettings::default(),
+            custom_elements: CustomElements::default(),
+            letterbox: None,
+            transitions: TransitionsManager::new(animation_clock.clone()),
+            animations: AnimationsManager::new(animation_clock.clone()),
+            animation_clock,
         }
     }
 }
@@ -137,6 +178,64 @@ impl FreyaDOM {
         &self.paragraphs
     }
 
+    /// Change the global [`RenderSettings`], must be called before creating the initial DOM.
+    pub fn set_render_settings(&mut self, render_settings: RenderSettings) {
+        self.render_settings = render_settings;
+    }
+
+    /// Change the registered [`CustomElements`], must be called before creating the initial DOM.
+    pub fn set_custom_elements(&mut self, custom_elements: CustomElements) {
+        self.custom_elements = custom_elements;
+    }
+
+    /// Get the [`Letterbox`] that fits the UI into the Window, if it's laid out at a fixed resolution.
+    pub fn letterbox(&self) -> Option<Letterbox> {
+        self.letterbox
+    }
+
+    /// Change the [`Letterbox`] that fits the UI into the Window, the events will be mapped through it.
+    pub fn set_letterbox(&mut self, letterbox: Option<Letterbox>) {
+        self.letterbox = letterbox;
+    }
+
+    /// Take the nodes that changed since the last call.
+    pub fn take_dirty_nodes(&mut self) -> FxHashSet<NodeId> {
+        std::mem::take(&mut self.dirty_nodes)
+    }
+
+    /// Take the dirty nodes whose only changes since the last call were their rotation or opacity, along with which of them changed.
+    /// These can be applied when painting a cached subtree whose root has them in its `will_change`, without rendering it again.
+    pub fn take_composited_changes(&mut self) -> FxHashMap<NodeId, WillChange> {
+        std::mem::take(&mut self.composited_changes)
+    }
+
+    /// Take the nodes listening for `mounted` that were created since the last call.
+    pub fn take_mounted_nodes(&mut self) -> FxHashSet<NodeId> {
+        std::mem::take(&mut self.mounted_nodes)
+    }
+
+    /// Take the nodes listening for `scroll` whose offsets changed since the last call, along with their previous offsets.
+    /// The first offsets of a node are only remembered, as it wasn't scrolled to them.
+    pub fn take_scrolled_nodes(&mut self) -> Vec<(NodeId, (f32, f32))> {
+        let mut scrolled_nodes = Vec::new();
+        let mut scroll_offsets = FxHashMap::default();
+
+        for node in self.rdom.get_listeners(&EventName::Scroll) {
+            let layout = node.get::<LayoutState>().unwrap();
+            let offsets = (layout.offset_x.get(), layout.offset_y.get());
+            if let Some(previous_offsets) = self.scroll_offsets.get(&node.id()) {
+                if *previous_offsets != offsets {
+                    scrolled_nodes.push((node.id(), *previous_offsets));
+                }
+            }
+            scroll_offsets.insert(node.id(), offsets);
+        }
+
+        // Removed nodes and nodes that stopped listening are forgotten
+        self.scroll_offsets = scroll_offsets;
+        scrolled_nodes
+    }
+
     /// Create the initial DOM from the given Mutations
     pub fn init_dom(&mut self, vdom: &mut VirtualDom, scale_factor: f32) {
         // Build the RealDOM
@@ -147,13 +246,19 @@ impl FreyaDOM {
             layout: &mut self.torin.lock().unwrap(),
             layers: &self.layers,
             paragraphs: &self.paragraphs,
+            mounted_nodes: &mut self.mounted_nodes,
+            transitions: &mut self.transitions,
+            animations: &mut self.animations,
         });
 
         let mut ctx = SendAnyMap::new();
         ctx.insert(scale_factor);
+        ctx.insert(self.render_settings);
+        ctx.insert(self.custom_elements.clone());
         ctx.insert(self.torin.clone());
         ctx.insert(self.layers.clone());
         ctx.insert(self.paragraphs.clone());
+        ctx.insert(self.restyled_paragraphs.clone());
 
         self.rdom.update_state(ctx);
     }
@@ -168,21 +273,171 @@ impl FreyaDOM {
             layout: &mut self.torin.lock().unwrap(),
             layers: &self.layers,
             paragraphs: &self.paragraphs,
+            mounted_nodes: &mut self.mounted_nodes,
+            transitions: &mut self.transitions,
+            animations: &mut self.animations,
         });
 
-        // Update the Nodes states
+        self.update_state(scale_factor)
+    }
+
+    /// Compute the state of every node again with a new scale factor, e.g. after the Window moved to a monitor with a different DPI.
+    pub fn update_scale_factor(&mut self, scale_factor: f32) {
+        self.rdom.mark_all_dirty();
+        self.update_state(scale_factor);
+        self.layout().reset();
+    }
+
+    /// Advance the running attribute transitions and keyframes animations,
+    /// see [`TransitionsManager`] and [`AnimationsManager`].
+    pub fn process_animations(&mut self, scale_factor: f32) -> (bool, bool) {
+        // They are still applied while the clock is paused, as it can be stepped
+        if !self.transitions.is_running() && !self.animations.is_running() {
+            return (false, false);
+        }
+
+        self.transitions.apply(&mut self.rdom);
+        self.animations.apply(&mut self.rdom);
+
+        // The nodes removed by the app are only removed from the RealDOM once their exit animation ends
+        for node_id in self.animations.take_exited() {
+            if self.rdom.get(node_id).is_none() {
+                continue;
+            }
+            unmount_node(
+                &self.rdom,
+                &mut self.torin.lock().unwrap(),
+                &self.layers,
+                &self.paragraphs,
+                node_id,
+            );
+            self.rdom.get_mut(node_id).unwrap().remove();
+        }
+
+        self.update_state(scale_factor)
+    }
+
+    /// Check if there are attribute transitions or animations running, in which case new frames must keep being requested.
+    /// Nothing changes while the [`AnimationClock`] is paused, so no frames are needed then.
+    pub fn has_running_animations(&self) -> bool {
+        (self.transitions.is_running() || self.animations.is_running())
+            && !self.animation_clock.is_paused()
+    }
+
+    /// Get the [`AnimationClock`] of the transitions and animations.
+    pub fn animation_clock(&self) -> AnimationClock {
+        self.animation_clock.clone()
+    }
+
+    /// Get the [`KeyframesRegistry`] used by the `animation` attribute.
+    pub fn keyframes(&self) -> KeyframesRegistry {
+        self.animations.keyframes().clone()
+    }
+
+    /// Run the state passes of the changed nodes and tell whether it needs to be repainted and relayouted.
+    fn update_state(&mut self, scale_factor: f32) -> (bool, bool) {
         let mut ctx = SendAnyMap::new();
         ctx.insert(scale_factor);
+        ctx.insert(self.render_settings);
+        ctx.insert(self.custom_elements.clone());
         ctx.insert(self.torin.clone());
         ctx.insert(self.layers.clone());
         ctx.insert(self.paragraphs.clone());
+        ctx.insert(self.restyled_paragraphs.clone());
 
         // Update the Node's states
         let (_, diff) = self.rdom.update_state(ctx);
 
+        // The nodes of the same layer are painted and hit in the order of the document
+        let tree = self.rdom.tree_ref();
+        self.layers.sort_nodes(
+            || {
+                let mut positions = FxHashMap::default();
+                self.rdom.traverse_depth_first(|node| {
+                    positions.insert(node.id(), positions.len());
+                });
+                positions
+            },
+            move |node_id| {
+                let mut path = Vec::new();
+                let mut child_id = node_id;
+                while let Some(parent_id) = tree.parent_id(child_id) {
+                    let index = tree
+                        .children_ids(parent_id)
+                        .iter()
+                        .position(|id| *id == child_id);
+                    path.push(index.unwrap_or_default());
+                    child_id = parent_id;
+                }
+                path.reverse();
+                path
+            },
+        );
+
+        // Hiding or showing a subtree changes the layout of its parent,
+        // and the layout of a hidden subtree is thrown away so it's not rendered anymore
+        for (node_id, mask) in &diff {
+            if let AttributeMask::Some(attributes) = mask.attributes() {
+                if attributes.contains(&AttributeName::Hidden) {
+                    self.reset_subtree_layout(*node_id);
+                }
+                // Reordering a Node or changing how it flexes moves its siblings too,
+                // even the ones that were laid out before it
+                if attributes.iter().any(|attribute| {
+                    matches!(
+                        attribute,
+                        AttributeName::Order
+                            | AttributeName::FlexBasis
+                            | AttributeName::FlexGrow
+                            | AttributeName::FlexShrink
+                    )
+                }) {
+                    self.invalidate_parent_layout(*node_id);
+                }
+            }
+        }
+
         let must_repaint = !diff.is_empty();
         let must_relayout = !self.layout().get_dirty_nodes().is_empty();
 
+        // Nodes that only changed what is applied when compositing them, unless they already had other changes
+        for (node_id, mask) in &diff {
+            let mut changes = WillChange::default();
+            let only_composited = match mask.attributes() {
+                AttributeMask::Some(attributes) if !mask.text() => {
+                    attributes.iter().all(|attribute| match attribute {
+                        AttributeName::Rotate => {
+                            changes.transform = true;
+                            true
+                        }
+                        AttributeName::Opacity => {
+                            changes.opacity = true;
+                            true
+                        }
+                        _ => false,
+                    })
+                }
+                _ => false,
+            };
+            let had_other_changes = self.dirty_nodes.contains(node_id)
+                && !self.composited_changes.contains_key(node_id);
+
+            if only_composited && !had_other_changes {
+                let previous_changes = self.composited_changes.entry(*node_id).or_default();
+                previous_changes.transform |= changes.transform;
+                previous_changes.opacity |= changes.opacity;
+            } else {
+                self.composited_changes.remove(node_id);
+            }
+        }
+
+        // Remember what nodes changed so their regions get repainted
+        self.dirty_nodes.extend(diff.keys());
+        for node_id in self.torin.lock().unwrap().get_dirty_nodes() {
+            self.composited_changes.remove(node_id);
+            self.dirty_nodes.insert(*node_id);
+        }
+
         if !diff.is_empty() {
             info!(
                 "Updated DOM, now with {} nodes",
@@ -193,6 +448,27 @@ impl FreyaDOM {
         (must_repaint, must_relayout)
     }
 
+    /// Mark the parent of a Node as dirty.
+    fn invalidate_parent_layout(&self, node_id: NodeId) {
+        if let Some(parent_id) = self.rdom.tree_ref().parent_id(node_id) {
+            self.torin.lock().unwrap().invalidate(parent_id);
+        }
+    }
+
+    /// Remove the layout of a subtree and mark its parent as dirty.
+    fn reset_subtree_layout(&self, node_id: NodeId) {
+        let mut layout = self.torin.lock().unwrap();
+        let tree = self.rdom.tree_ref();
+        if let Some(parent_id) = tree.parent_id(node_id) {
+            layout.invalidate(parent_id);
+        }
+        let mut pending = vec![node_id];
+        while let Some(node_id) = pending.pop() {
+            layout.raw_remove(node_id);
+            pending.extend(tree.children_ids(node_id));
+        }
+    }
+
     /// Get a reference to the [`DioxusDOM`].
     pub fn rdom(&self) -> &DioxusDOM {
         &self.rdom
@@ -221,6 +497,68 @@ impl FreyaDOM {
         }
     }
 
+    /// Rebuild the cached paragraphs of the texts that only changed in how they are painted (e.g. their color),
+    /// keeping their layout. Texts that will be measured again anyway are skipped.
+    pub fn restyle_paragraphs(&self, font_collection: &FontCollection, default_fonts: &[String]) {
+        let restyled_nodes = self.restyled_paragraphs.take_nodes();
+        if restyled_nodes.is_empty() {
+            return;
+        }
+
+        let mut layout = self.layout();
+        let mut owners = FxHashSet::default();
+
+        // The paragraph of a text belongs to its closest label or paragraph
+        let tree = self.rdom.tree_ref();
+        for node_id in restyled_nodes {
+            let mut node_id = Some(node_id);
+            while let Some(current_id) = node_id {
+                let Some(node) = self.rdom.get(current_id) else {
+                    break;
+                };
+                let tag = node.node_type().tag().cloned();
+                match tag {
+                    Some(TagName::Label | TagName::Paragraph) => {
+                        owners.insert(current_id);
+                        break;
+                    }
+                    Some(TagName::Text) | None => node_id = tree.parent_id(current_id),
+                    Some(_) => break,
+                }
+            }
+        }
+
+        for node_id in owners {
+            if layout.get_dirty_nodes().contains(&node_id) {
+                continue;
+            }
+            let Some((node, layout_node)) = self.rdom.get(node_id).zip(layout.get(node_id)) else {
+                continue;
+            };
+            let Some(width) = layout_node
+                .data
+                .as_ref()
+                .and_then(|data| data.get::<CachedParagraph>())
+                .map(|paragraph| paragraph.1)
+            else {
+                continue;
+            };
+
+            let area_size = Size2D::new(width, layout_node.area.height());
+            let paragraph = if node.node_type().tag() == Some(&TagName::Label) {
+                create_label(&node, &area_size, font_collection, default_fonts)
+            } else {
+                create_paragraph(&node, &area_size, font_collection, false, default_fonts)
+            };
+
+            let mut map = SendAnyMap::new();
+            map.insert(CachedParagraph(paragraph, width));
+            let mut layout_node = layout_node.clone();
+            layout_node.data = Some(Arc::new(map));
+            layout.cache_node(node_id, layout_node);
+        }
+    }
+
     /// Measure all the paragraphs registered under the given TextId
     pub fn measure_paragraphs(&self, text_id: &Uuid, scale_factor: f32) {
         let paragraphs = self.paragraphs.paragraphs();
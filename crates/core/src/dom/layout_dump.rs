@@ -0,0 +1,87 @@
+use freya_native_core::{node::NodeType, real_dom::NodeImmutable, tree::TreeRef, NodeId};
+use freya_node_state::{LayerState, LayoutState, Style};
+use serde_json::{json, Map, Value};
+use torin::prelude::*;
+
+use super::FreyaDOM;
+
+impl FreyaDOM {
+    /// Serialize the computed layout of the whole tree, starting from the root.
+    ///
+    /// Every node contains its id, tag, layer, computed areas and the main layout and style properties.
+    /// Children keep the order of the tree and object keys are sorted, so two dumps can be diffed safely.
+    pub fn dump_layout(&self) -> Value {
+        self.dump_node_layout(self.rdom().root_id())
+    }
+
+    fn dump_node_layout(&self, node_id: NodeId) -> Value {
+        let rdom = self.rdom();
+        let Some(node) = rdom.get(node_id) else {
+            return Value::Null;
+        };
+
+        let mut dump = Map::new();
+        dump.insert("id".to_string(), json!(node_id.index()));
+
+        match &*node.node_type() {
+            NodeType::Text(text) => {
+                dump.insert("tag".to_string(), json!("text"));
+                dump.insert("text".to_string(), json!(text));
+            }
+            NodeType::Element(element) => {
+                dump.insert("tag".to_string(), json!(element.tag.to_string()));
+            }
+            NodeType::Placeholder => {
+                dump.insert("tag".to_string(), json!("placeholder"));
+            }
+        }
+
+        if let Some(layout_node) = self.layout().get(node_id) {
+            dump.insert("area".to_string(), dump_area(&layout_node.area));
+            dump.insert("inner_area".to_string(), dump_area(&layout_node.inner_area));
+        }
+
+        if let Some(layer) = node.get::<LayerState>() {
+            dump.insert("layer".to_string(), json!(layer.layer.0));
+        }
+
+        if let Some(layout) = node.get::<LayoutState>() {
+            dump.insert(
+                "style".to_string(),
+                json!({
+                    "width": layout.width.pretty(),
+                    "height": layout.height.pretty(),
+                    "min_width": layout.minimum_width.pretty(),
+                    "min_height": layout.minimum_height.pretty(),
+                    "max_width": layout.maximum_width.pretty(),
+                    "max_height": layout.maximum_height.pretty(),
+                    "padding": layout.padding.pretty(),
+                    "margin": layout.margin.pretty(),
+                    "direction": layout.direction.pretty(),
+                    "main_alignment": layout.main_alignment.pretty(),
+                    "cross_alignment": layout.cross_alignment.pretty(),
+                    "background": node.get::<Style>().map(|style| style.background.to_string()),
+                }),
+            );
+        }
+
+        let children = rdom
+            .tree_ref()
+            .children_ids(node_id)
+            .into_iter()
+            .map(|child_id| self.dump_node_layout(child_id))
+            .collect::<Vec<Value>>();
+        dump.insert("children".to_string(), Value::Array(children));
+
+        Value::Object(dump)
+    }
+}
+
+fn dump_area(area: &Area) -> Value {
+    json!({
+        "x": area.min_x(),
+        "y": area.min_y(),
+        "width": area.width(),
+        "height": area.height(),
+    })
+}
@@ -1,7 +1,14 @@
+mod animation_clock;
+mod animations;
 pub mod dom_adapter;
 pub mod doms;
+mod layout_dump;
 mod mutations_writer;
 mod paragraph_utils;
+mod transitions;
 
+pub use animation_clock::*;
+pub use animations::*;
 pub use dom_adapter::*;
 pub use doms::*;
+pub use transitions::*;
@@ -1,58 +1,114 @@
-use dioxus_core::{ElementId, WriteMutations};
+use std::str::FromStr;
+
+use dioxus_core::{AttributeValue, ElementId, WriteMutations};
 use freya_common::{Layers, ParagraphElements};
 use freya_native_core::{
-    dioxus::DioxusNativeCoreMutationWriter, prelude::NodeImmutable, tree::TreeRef, NodeId,
+    dioxus::DioxusNativeCoreMutationWriter,
+    node::OwnedAttributeValue,
+    prelude::{AttributeName, NodeImmutable},
+    tree::TreeRef,
+    NodeId,
 };
 use freya_node_state::{CursorSettings, CustomAttributeValues, LayerState};
+use rustc_hash::FxHashSet;
 use torin::torin::Torin;
 
-use crate::prelude::DioxusDOMAdapter;
+use crate::prelude::{AnimationsManager, DioxusDOM, DioxusDOMAdapter, TransitionsManager};
 
 pub struct MutationsWriter<'a> {
     pub native_writer: DioxusNativeCoreMutationWriter<'a, CustomAttributeValues>,
     pub layout: &'a mut Torin<NodeId>,
     pub layers: &'a Layers,
     pub paragraphs: &'a ParagraphElements,
+    pub mounted_nodes: &'a mut FxHashSet<NodeId>,
+    pub transitions: &'a mut TransitionsManager,
+    pub animations: &'a mut AnimationsManager,
+}
+
+/// Remove a node and its descendants from the layout, the layers and the paragraph elements,
+/// must be called before removing it from the RealDOM.
+pub fn unmount_node(
+    rdom: &DioxusDOM,
+    layout: &mut Torin<NodeId>,
+    layers: &Layers,
+    paragraphs: &ParagraphElements,
+    node_id: NodeId,
+) {
+    let mut dom_adapter = DioxusDOMAdapter::new_with_cache(rdom);
+
+    // Remove from layout
+    layout.remove(node_id, &mut dom_adapter, true);
+
+    // Remove from layers and paragraph elements
+    let mut stack = vec![node_id];
+    let tree = rdom.tree_ref();
+    while let Some(node_id) = stack.pop() {
+        if let Some(node) = rdom.get(node_id) {
+            if !node.node_type().is_visible_element() {
+                continue;
+            }
+
+            let traverse_children = node
+                .node_type()
+                .tag()
+                .map(|tag| tag.has_children_with_intrinsic_layout())
+                .unwrap_or_default();
+            if traverse_children {
+                let children = tree.children_ids_advanced(node_id, false);
+                stack.extend(children.iter().copied().rev());
+            }
+
+            // Remove from layers
+            let layer_state = node.get::<LayerState>().unwrap();
+            layers.remove_node_from_layer(node_id, &layer_state.layer);
+
+            // Remove from paragraph elements
+            let cursor_settings = node.get::<CursorSettings>().unwrap();
+            if let Some(cursor_ref) = cursor_settings.cursor_ref.as_ref() {
+                paragraphs.remove_paragraph(node_id, &cursor_ref.text_id);
+            }
+        }
+    }
 }
 
 impl<'a> MutationsWriter<'a> {
     pub fn remove(&mut self, id: ElementId) {
         let node_id = self.native_writer.state.element_to_node_id(id);
-        let mut dom_adapter = DioxusDOMAdapter::new_with_cache(self.native_writer.rdom);
+        unmount_node(
+            self.native_writer.rdom,
+            self.layout,
+            self.layers,
+            self.paragraphs,
+            node_id,
+        );
+    }
+
+    /// Start the `exit_animation` of a node that is about to be removed, so it's kept in the RealDOM until it ends.
+    /// Returns `false` if it has none, in which case it must be removed right away.
+    fn start_exit(&mut self, id: ElementId) -> bool {
+        let node_id = self.native_writer.state.element_to_node_id(id);
+        self.animations.exit(self.native_writer.rdom, node_id)
+    }
 
-        // Remove from layout
-        self.layout.remove(node_id, &mut dom_adapter, true);
+    /// Detach an exiting node from Dioxus, which is free to reuse its ElementIds from now on.
+    fn detach(&mut self, id: ElementId) {
+        let node_id = self.native_writer.state.element_to_node_id(id);
+        self.native_writer
+            .state
+            .detach(self.native_writer.rdom, node_id);
+    }
 
-        // Remove from layers and paragraph elements
-        let mut stack = vec![node_id];
+    /// Invalidate the layout of a node's parent and all its children.
+    /// Keyed children are moved rather than recreated, so their cached layout would point to their old position,
+    /// and so would their paint order.
+    fn invalidate_siblings(&mut self, id: ElementId) {
+        self.layers.mark_unsorted();
+        let node_id = self.native_writer.state.element_to_node_id(id);
         let tree = self.native_writer.rdom.tree_ref();
-        while let Some(node_id) = stack.pop() {
-            if let Some(node) = self.native_writer.rdom.get(node_id) {
-                if !node.node_type().is_visible_element() {
-                    continue;
-                }
-
-                let traverse_children = node
-                    .node_type()
-                    .tag()
-                    .map(|tag| tag.has_children_with_intrinsic_layout())
-                    .unwrap_or_default();
-                if traverse_children {
-                    let children = tree.children_ids_advanced(node_id, false);
-                    stack.extend(children.iter().copied().rev());
-                }
-
-                // Remove from layers
-                let layer_state = node.get::<LayerState>().unwrap();
-                self.layers
-                    .remove_node_from_layer(node_id, layer_state.layer);
-
-                // Remove from paragraph elements
-                let cursor_settings = node.get::<CursorSettings>().unwrap();
-                if let Some(cursor_ref) = cursor_settings.cursor_ref.as_ref() {
-                    self.paragraphs
-                        .remove_paragraph(node_id, &cursor_ref.text_id);
-                }
+        if let Some(parent_id) = tree.parent_id(node_id) {
+            self.layout.invalidate(parent_id);
+            for child_id in tree.children_ids(parent_id) {
+                self.layout.invalidate(child_id);
             }
         }
     }
@@ -85,10 +141,23 @@ impl<'a> WriteMutations for MutationsWriter<'a> {
 
     fn load_template(&mut self, name: &'static str, index: usize, id: dioxus_core::ElementId) {
         self.native_writer.load_template(name, index, id);
+
+        // Start the animations of the static `animation` attributes
+        let node_id = self.native_writer.state.element_to_node_id(id);
+        self.animations
+            .sync_subtree(self.native_writer.rdom, node_id);
     }
 
     fn replace_node_with(&mut self, id: dioxus_core::ElementId, m: usize) {
         if m > 0 {
+            // The new nodes are placed before the exiting one
+            if self.start_exit(id) {
+                self.native_writer.insert_nodes_before(id, m);
+                self.invalidate_siblings(id);
+                self.detach(id);
+                return;
+            }
+
             self.remove(id);
         }
 
@@ -101,10 +170,12 @@ impl<'a> WriteMutations for MutationsWriter<'a> {
 
     fn insert_nodes_after(&mut self, id: dioxus_core::ElementId, m: usize) {
         self.native_writer.insert_nodes_after(id, m);
+        self.invalidate_siblings(id);
     }
 
     fn insert_nodes_before(&mut self, id: dioxus_core::ElementId, m: usize) {
         self.native_writer.insert_nodes_before(id, m);
+        self.invalidate_siblings(id);
     }
 
     fn set_attribute(
@@ -114,6 +185,37 @@ impl<'a> WriteMutations for MutationsWriter<'a> {
         value: &dioxus_core::AttributeValue,
         id: dioxus_core::ElementId,
     ) {
+        // Animated attributes are applied by the AnimationsManager
+        // and transitioned attributes are applied gradually by the TransitionsManager
+        if let Ok(attribute) = AttributeName::from_str(name) {
+            let node_id = self.native_writer.state.element_to_node_id(id);
+
+            if attribute == AttributeName::Animation {
+                self.native_writer.set_attribute(name, ns, value, id);
+                self.animations.sync(self.native_writer.rdom, node_id);
+                return;
+            }
+
+            let base = match value {
+                AttributeValue::None => None,
+                value => Some(OwnedAttributeValue::from(value)),
+            };
+            if self.animations.set_base(node_id, attribute, base) {
+                return;
+            }
+
+            let to = match value {
+                AttributeValue::Text(value) => Some(value.as_str()),
+                _ => None,
+            };
+            if self
+                .transitions
+                .start(self.native_writer.rdom, node_id, attribute, to)
+            {
+                return;
+            }
+        }
+
         self.native_writer.set_attribute(name, ns, value, id);
     }
 
@@ -124,6 +226,11 @@ impl<'a> WriteMutations for MutationsWriter<'a> {
     }
 
     fn create_event_listener(&mut self, name: &'static str, id: dioxus_core::ElementId) {
+        // Listeners are created along with their nodes, so these are the new nodes waiting for their first layout
+        if name == "mounted" {
+            self.mounted_nodes
+                .insert(self.native_writer.state.element_to_node_id(id));
+        }
         self.native_writer.create_event_listener(name, id);
     }
 
@@ -132,6 +239,11 @@ impl<'a> WriteMutations for MutationsWriter<'a> {
     }
 
     fn remove_node(&mut self, id: dioxus_core::ElementId) {
+        if self.start_exit(id) {
+            self.detach(id);
+            return;
+        }
+
         self.remove(id);
         self.native_writer.remove_node(id);
     }
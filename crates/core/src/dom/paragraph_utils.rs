@@ -1,7 +1,7 @@
 use freya_native_core::real_dom::NodeImmutable;
 
 use freya_common::{CachedParagraph, CursorLayoutResponse};
-use freya_node_state::{CursorReference, CursorSettings};
+use freya_node_state::{CursorReference, CursorSettings, UserSelect};
 use std::ops::Mul;
 
 use torin::prelude::*;
@@ -27,20 +27,62 @@ pub fn measure_paragraph(
         if let Some((cursor_ref, id, cursor_position, cursor_selections)) =
             get_cursor_reference(node)
         {
+            let user_select = node.get::<CursorSettings>().unwrap().user_select;
+
+            // Text that can't be selected neither moves the cursor nor starts or extends a selection
+            if user_select == UserSelect::None {
+                cursor_ref.set_cursor_position(None);
+                cursor_ref.set_cursor_selections(None);
+
+                if cursor_position.is_some() {
+                    cursor_ref
+                        .cursor_sender
+                        .send(CursorLayoutResponse::Unselectable { id })
+                        .ok();
+                }
+                return;
+            }
+
+            // Position after the last character, as the whole text is selected at once with `all`
+            let text_end = || {
+                paragraph
+                    .get_glyph_position_at_coordinate((i32::MAX, i32::MAX))
+                    .position as usize
+            };
+
             if let Some(cursor_position) = cursor_position {
-                // Calculate the new cursor position
-                let char_position = paragraph.get_glyph_position_at_coordinate(
-                    cursor_position.mul(scale_factors).to_i32().to_tuple(),
-                );
+                if user_select == UserSelect::All {
+                    let text_end = text_end();
+                    cursor_ref
+                        .cursor_sender
+                        .send(CursorLayoutResponse::CursorPosition {
+                            position: text_end,
+                            id,
+                        })
+                        .ok();
+                    cursor_ref
+                        .cursor_sender
+                        .send(CursorLayoutResponse::TextSelection {
+                            from: 0,
+                            to: text_end,
+                            id,
+                        })
+                        .ok();
+                } else {
+                    // Calculate the new cursor position
+                    let char_position = paragraph.get_glyph_position_at_coordinate(
+                        cursor_position.mul(scale_factors).to_i32().to_tuple(),
+                    );
 
-                // Notify the cursor reference listener
-                cursor_ref
-                    .cursor_sender
-                    .send(CursorLayoutResponse::CursorPosition {
-                        position: char_position.position as usize,
-                        id,
-                    })
-                    .ok();
+                    // Notify the cursor reference listener
+                    cursor_ref
+                        .cursor_sender
+                        .send(CursorLayoutResponse::CursorPosition {
+                            position: char_position.position as usize,
+                            id,
+                        })
+                        .ok();
+                }
             }
 
             if let Some((origin, dist)) = cursor_selections {
@@ -52,13 +94,20 @@ pub fn measure_paragraph(
                 let dist_char = paragraph
                     .get_glyph_position_at_coordinate(dist.mul(scale_factors).to_i32().to_tuple());
 
+                let (from, to) = if user_select == UserSelect::All {
+                    // Keep the direction of the selection
+                    if dist_char.position < origin_char.position {
+                        (text_end(), 0)
+                    } else {
+                        (0, text_end())
+                    }
+                } else {
+                    (origin_char.position as usize, dist_char.position as usize)
+                };
+
                 cursor_ref
                     .cursor_sender
-                    .send(CursorLayoutResponse::TextSelection {
-                        from: origin_char.position as usize,
-                        to: dist_char.position as usize,
-                        id,
-                    })
+                    .send(CursorLayoutResponse::TextSelection { from, to, id })
                     .ok();
             }
         }
@@ -0,0 +1,152 @@
+use std::time::{Duration, Instant};
+
+use freya_native_core::{
+    node::OwnedAttributeValue,
+    prelude::{AttributeName, NodeImmutable, NodeType},
+    real_dom::NodeTypeMut,
+    NodeId,
+};
+use freya_node_state::{interpolate_attribute, Parse, TransitionEasing, Transitions};
+use rustc_hash::FxHashMap;
+
+use super::{AnimationClock, DioxusDOM};
+
+/// Attribute being animated from its previous value to the new one.
+struct RunningTransition {
+    from: String,
+    to: String,
+    started_at: Instant,
+    duration: Duration,
+    easing: TransitionEasing,
+}
+
+impl RunningTransition {
+    fn progress(&self, now: Instant) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        (now.duration_since(self.started_at).as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+}
+
+/// Keeps track of the attributes of every node that are transitioning because of their `transition` attribute.
+#[derive(Default)]
+pub struct TransitionsManager {
+    running: FxHashMap<NodeId, FxHashMap<AttributeName, RunningTransition>>,
+    clock: AnimationClock,
+}
+
+impl TransitionsManager {
+    pub fn new(clock: AnimationClock) -> Self {
+        Self {
+            running: FxHashMap::default(),
+            clock,
+        }
+    }
+
+    /// Check if there is any transition running.
+    pub fn is_running(&self) -> bool {
+        !self.running.is_empty()
+    }
+
+    /// Start a transition if the given node has one for this attribute and both values can be interpolated.
+    /// Returns `true` if the transition was started, in which case the new value must not be applied yet.
+    pub fn start(
+        &mut self,
+        rdom: &DioxusDOM,
+        node_id: NodeId,
+        attribute: AttributeName,
+        to: Option<&str>,
+    ) -> bool {
+        let Some(node) = rdom.get(node_id) else {
+            return false;
+        };
+        let NodeType::Element(element) = &*node.node_type() else {
+            return false;
+        };
+
+        let transition = element
+            .attributes
+            .get(&AttributeName::Transition)
+            .and_then(|transitions| transitions.as_text())
+            .and_then(|transitions| Transitions::parse(transitions).ok())
+            .and_then(|transitions| transitions.get(&attribute).copied());
+
+        // The current value is the one being shown, which might be halfway through another transition
+        let from = element
+            .attributes
+            .get(&attribute)
+            .and_then(|value| value.as_text());
+
+        let (Some(transition), Some(from), Some(to)) = (transition, from, to) else {
+            self.cancel(node_id, &attribute);
+            return false;
+        };
+
+        // The new value is applied right away when the motion is reduced
+        if self.clock.prefers_reduced_motion()
+            || interpolate_attribute(&attribute, from, to, 0.0).is_none()
+        {
+            self.cancel(node_id, &attribute);
+            return false;
+        }
+
+        self.running.entry(node_id).or_default().insert(
+            attribute,
+            RunningTransition {
+                from: from.to_string(),
+                to: to.to_string(),
+                started_at: self.clock.now(),
+                duration: transition.duration,
+                easing: transition.easing,
+            },
+        );
+
+        true
+    }
+
+    /// Stop the transition of the given attribute, if any.
+    pub fn cancel(&mut self, node_id: NodeId, attribute: &AttributeName) {
+        if let Some(transitions) = self.running.get_mut(&node_id) {
+            transitions.remove(attribute);
+            if transitions.is_empty() {
+                self.running.remove(&node_id);
+            }
+        }
+    }
+
+    /// Write the current value of every running transition into the RealDOM,
+    /// the finished transitions are applied with their final value and then forgotten.
+    pub fn apply(&mut self, rdom: &mut DioxusDOM) {
+        let now = self.clock.now();
+
+        self.running.retain(|node_id, transitions| {
+            let Some(mut node) = rdom.get_mut(*node_id) else {
+                return false;
+            };
+            let mut node_type = node.node_type_mut();
+            let NodeTypeMut::Element(element) = &mut node_type else {
+                return false;
+            };
+
+            transitions.retain(|attribute, transition| {
+                let progress = transition.progress(now);
+                let value = if progress >= 1.0 {
+                    transition.to.clone()
+                } else {
+                    interpolate_attribute(
+                        attribute,
+                        &transition.from,
+                        &transition.to,
+                        transition.easing.apply(progress),
+                    )
+                    .unwrap_or_else(|| transition.to.clone())
+                };
+                element.set_attribute(*attribute, OwnedAttributeValue::Text(value));
+                progress < 1.0
+            });
+
+            !transitions.is_empty()
+        });
+    }
+}
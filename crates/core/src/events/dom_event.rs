@@ -1,10 +1,12 @@
 use std::{any::Any, rc::Rc};
 
 use dioxus_core::ElementId;
+use freya_common::Layer;
 use freya_elements::{
     elements::PlatformEventData,
     events::{
-        pointer::PointerType, FileData, KeyboardData, MouseData, PointerData, TouchData, WheelData,
+        pointer::PointerType, CaptureState, Dataset, EventTarget, FileData, KeyboardData,
+        MountedData, MouseData, PointerData, ScrollData, TouchData, WheelData,
     },
 };
 use freya_native_core::NodeId;
@@ -22,7 +24,9 @@ pub struct DomEvent {
     pub element_id: ElementId,
     pub data: DomEventData,
     pub bubbles: bool,
-    pub layer: Option<i16>,
+    pub layer: Option<Layer>,
+    /// Position in the queue of the platform event it comes from.
+    pub source: usize,
 }
 
 impl Eq for DomEvent {}
@@ -40,11 +44,17 @@ impl Ord for DomEvent {
 }
 
 impl DomEvent {
+    /// Create a new [`DomEvent`] from a [`PotentialEvent`].
+    ///
+    /// The platform events and the layout are expressed in physical pixels, but the
+    /// coordinates of the event data are converted here to logical pixels,
+    /// so components never need to deal with the scale factor.
     pub fn new(
         PotentialEvent {
             node_id,
             layer,
             event,
+            source,
         }: PotentialEvent,
         element_id: ElementId,
         node_area: Option<Area>,
@@ -85,28 +95,42 @@ impl DomEvent {
                     data: event_data,
                     bubbles,
                     layer,
+                    source,
                 }
             }
-            PlatformEvent::Wheel { scroll, .. } => Self {
+            PlatformEvent::Wheel {
+                scroll,
+                delta,
+                modifiers,
+                ..
+            } => Self {
                 node_id,
                 element_id,
                 name,
-                data: DomEventData::Wheel(WheelData::new(scroll.x, scroll.y)),
+                data: DomEventData::Wheel(WheelData::new(scroll.x, scroll.y, delta, modifiers)),
                 bubbles,
                 layer,
+                source,
             },
             PlatformEvent::Keyboard {
                 ref key,
                 code,
                 modifiers,
+                repeat,
                 ..
             } => Self {
                 node_id,
                 element_id,
                 name,
-                data: DomEventData::Keyboard(KeyboardData::new(key.clone(), code, modifiers)),
+                data: DomEventData::Keyboard(KeyboardData::new(
+                    key.clone(),
+                    code,
+                    modifiers,
+                    repeat,
+                )),
                 bubbles,
                 layer,
+                source,
             },
             PlatformEvent::Touch {
                 location,
@@ -115,12 +139,15 @@ impl DomEvent {
                 force,
                 ..
             } => {
-                let element_x = location.x - node_area.unwrap_or_default().min_x() as f64;
-                let element_y = location.y - node_area.unwrap_or_default().min_y() as f64;
+                let screen_coordinates = location / scale_factor;
+                let element_x =
+                    (location.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (location.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
 
                 let event_data = if name.is_pointer() {
                     DomEventData::Pointer(PointerData::new(
-                        location,
+                        screen_coordinates,
                         (element_x, element_y).into(),
                         PointerType::Touch {
                             finger_id,
@@ -130,7 +157,7 @@ impl DomEvent {
                     ))
                 } else {
                     DomEventData::Touch(TouchData::new(
-                        location,
+                        screen_coordinates,
                         (element_x, element_y).into(),
                         finger_id,
                         phase,
@@ -145,12 +172,25 @@ impl DomEvent {
                     data: event_data,
                     bubbles,
                     layer,
+                    source,
                 }
             }
             PlatformEvent::File {
-                name, file_path, ..
+                name,
+                file_path,
+                cursor,
             } => {
-                let event_data = DomEventData::File(FileData { file_path });
+                let screen_coordinates = cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                let event_data = DomEventData::File(FileData::new(
+                    file_path,
+                    screen_coordinates,
+                    (element_x, element_y).into(),
+                ));
 
                 Self {
                     node_id,
@@ -159,10 +199,46 @@ impl DomEvent {
                     data: event_data,
                     bubbles,
                     layer,
+                    source,
                 }
             }
         }
     }
+
+    /// Create a new `mounted` [`DomEvent`] with the initial area of the Node, converted to logical pixels.
+    pub fn new_mounted(
+        node_id: NodeId,
+        element_id: ElementId,
+        node_area: Area,
+        scale_factor: f64,
+    ) -> Self {
+        let name = EventName::Mounted;
+
+        Self {
+            node_id,
+            element_id,
+            name,
+            data: DomEventData::Mounted(MountedData::new(node_area / scale_factor as f32)),
+            bubbles: name.does_bubble(),
+            layer: None,
+            source: 0,
+        }
+    }
+
+    /// Create a new `scroll` [`DomEvent`] with the scroll data already converted to logical pixels.
+    pub fn new_scroll(node_id: NodeId, element_id: ElementId, data: ScrollData) -> Self {
+        let name = EventName::Scroll;
+
+        Self {
+            node_id,
+            element_id,
+            name,
+            data: DomEventData::Scroll(data),
+            bubbles: name.does_bubble(),
+            layer: None,
+            source: 0,
+        }
+    }
 }
 
 /// Data of a DOM event.
@@ -174,9 +250,64 @@ pub enum DomEventData {
     Touch(TouchData),
     Pointer(PointerData),
     File(FileData),
+    Mounted(MountedData),
+    Scroll(ScrollData),
 }
 
 impl DomEventData {
+    /// Get the [`CaptureState`] of the events that support a capture phase.
+    pub fn capture_state(&self) -> Option<&CaptureState> {
+        match self {
+            DomEventData::Mouse(m) => Some(&m.capture),
+            DomEventData::Wheel(w) => Some(&w.capture),
+            DomEventData::Pointer(p) => Some(&p.capture),
+            _ => None,
+        }
+    }
+
+    /// Replace the [`CaptureState`] of the events that support a capture phase.
+    pub fn set_capture_state(&mut self, capture: CaptureState) {
+        match self {
+            DomEventData::Mouse(m) => m.capture = capture,
+            DomEventData::Wheel(w) => w.capture = capture,
+            DomEventData::Pointer(p) => p.capture = capture,
+            _ => {}
+        }
+    }
+
+    /// Replace the `data-*` attributes of the element that received the event.
+    pub fn set_dataset(&mut self, dataset: Dataset) {
+        match self {
+            DomEventData::Mouse(m) => m.dataset = dataset,
+            DomEventData::Keyboard(k) => k.dataset = dataset,
+            DomEventData::Wheel(w) => w.dataset = dataset,
+            DomEventData::Touch(t) => t.dataset = dataset,
+            DomEventData::Pointer(p) => p.dataset = dataset,
+            DomEventData::File(fd) => fd.dataset = dataset,
+            DomEventData::Mounted(m) => m.dataset = dataset,
+            DomEventData::Scroll(s) => s.dataset = dataset,
+        }
+    }
+
+    /// Replace the element that originated the pointer events.
+    pub fn set_target(&mut self, target: EventTarget) {
+        match self {
+            DomEventData::Mouse(m) => m.target = target,
+            DomEventData::Wheel(w) => w.target = target,
+            DomEventData::Touch(t) => t.target = target,
+            DomEventData::Pointer(p) => p.target = target,
+            DomEventData::File(fd) => fd.target = target,
+            DomEventData::Keyboard(_) | DomEventData::Mounted(_) | DomEventData::Scroll(_) => {}
+        }
+    }
+
+    /// Check if a capture handler has stopped this event.
+    pub fn is_capture_stopped(&self) -> bool {
+        self.capture_state()
+            .map(|capture| capture.is_stopped())
+            .unwrap_or_default()
+    }
+
     pub fn any(self) -> Rc<dyn Any> {
         match self {
             DomEventData::Mouse(m) => Rc::new(PlatformEventData::new(Box::new(m))),
@@ -185,6 +316,8 @@ impl DomEventData {
             DomEventData::Touch(t) => Rc::new(PlatformEventData::new(Box::new(t))),
             DomEventData::Pointer(p) => Rc::new(PlatformEventData::new(Box::new(p))),
             DomEventData::File(fd) => Rc::new(PlatformEventData::new(Box::new(fd))),
+            DomEventData::Mounted(m) => Rc::new(PlatformEventData::new(Box::new(m))),
+            DomEventData::Scroll(s) => Rc::new(PlatformEventData::new(Box::new(s))),
         }
     }
 }
@@ -1,10 +1,15 @@
+use freya_native_core::node::{ElementNode, NodeType};
 use freya_native_core::real_dom::NodeImmutable;
 use freya_native_core::NodeId;
 use freya_native_core::{prelude::NodeImmutableDioxusExt, tree::TreeRef};
 
+use freya_elements::events::{CaptureState, Dataset, EventTarget, ScrollData};
 use freya_engine::prelude::*;
-use freya_node_state::{Fill, Style, ViewportState};
-use itertools::sorted;
+use freya_node_state::{Fill, LayerState, LayoutState, Style};
+use itertools::{sorted, Itertools};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 
 pub use crate::events::{DomEvent, NodesState, PlatformEvent};
 
@@ -18,6 +23,13 @@ pub fn process_events(
     nodes_state: &mut NodesState,
     scale_factor: f64,
 ) {
+    // 0. Map the events from the Window into the layout coordinates
+    if let Some(letterbox) = dom.letterbox() {
+        for event in events.iter_mut() {
+            event.map_cursor(|cursor| letterbox.to_layout(cursor));
+        }
+    }
+
     // 1. Get global events created from the incoming events
     let global_events = measure_global_events(events);
 
@@ -37,9 +49,21 @@ pub fn process_events(
 
     let colateral_global_events = measure_colateral_global_events(&to_emit_dom_collateral_events);
 
-    // 6. Join both the dom and colateral dom events and sort them
+    // 6. Join both the dom and colateral dom events and sort them by the platform event they come from,
+    // starting with its capture phase, which keeps the order from the root to the target
     to_emit_dom_events.extend(to_emit_dom_collateral_events);
-    to_emit_dom_events.sort_unstable();
+    to_emit_dom_events.sort_by(|left, right| {
+        left.source
+            .cmp(&right.source)
+            .then_with(|| right.name.is_capture().cmp(&left.name.is_capture()))
+            .then_with(|| {
+                if left.name.is_capture() {
+                    Ordering::Equal
+                } else {
+                    left.cmp(right)
+                }
+            })
+    });
 
     // 7. Emit the DOM events
     for event in to_emit_dom_events {
@@ -59,6 +83,192 @@ pub fn process_events(
     events.clear();
 }
 
+/// Coalesce the consecutive mouse movements of the queue into the latest one, as only its position matters.
+/// The enter and leave events are measured against the latest hovered nodes, so none are missed.
+/// Other events split the movements around them, so their order and positions are kept.
+pub fn coalesce_mouse_movements(events: &mut EventsQueue) {
+    let is_mouse_movement = |event: &PlatformEvent| {
+        matches!(
+            event,
+            PlatformEvent::Mouse {
+                name: EventName::MouseOver,
+                ..
+            }
+        )
+    };
+
+    let mut coalesced_events = EventsQueue::new();
+    for event in events.drain(..) {
+        match coalesced_events.last_mut() {
+            Some(last_event) if is_mouse_movement(last_event) && is_mouse_movement(&event) => {
+                *last_event = event;
+            }
+            _ => coalesced_events.push(event),
+        }
+    }
+    *events = coalesced_events;
+}
+
+/// Emit the `mounted` events of the nodes created since the last layout, with their initial area.
+/// Must be called after measuring the layout.
+pub fn process_mounted_events(
+    dom: &FreyaDOM,
+    mounted_nodes: FxHashSet<NodeId>,
+    event_emitter: &EventEmitter,
+    scale_factor: f64,
+) {
+    let layout = dom.layout();
+    let rdom = dom.rdom();
+
+    for node_id in mounted_nodes {
+        // The node might have been removed before being measured
+        let Some(node) = rdom.get(node_id) else {
+            continue;
+        };
+        let (Some(layout_node), Some(element_id)) = (layout.get(node_id), node.mounted_id()) else {
+            continue;
+        };
+
+        let mut event = DomEvent::new_mounted(node_id, element_id, layout_node.area, scale_factor);
+        event.data.set_dataset(get_node_dataset(&node));
+        event_emitter.send(event).unwrap();
+    }
+}
+
+/// Emit the `scroll` events of the nodes whose offsets changed, see [`FreyaDOM::take_scrolled_nodes`].
+/// Must be called after measuring the layout, so the sizes of their content are known.
+pub fn process_scroll_events(
+    dom: &FreyaDOM,
+    scrolled_nodes: Vec<(NodeId, (f32, f32))>,
+    event_emitter: &EventEmitter,
+    scale_factor: f64,
+) {
+    let layout = dom.layout();
+    let rdom = dom.rdom();
+    let scale_factor = scale_factor as f32;
+
+    for (node_id, (previous_offset_x, previous_offset_y)) in scrolled_nodes {
+        let Some(node) = rdom.get(node_id) else {
+            continue;
+        };
+        let (Some(layout_node), Some(element_id)) = (layout.get(node_id), node.mounted_id()) else {
+            continue;
+        };
+        let node_layout = node.get::<LayoutState>().unwrap();
+
+        // The content moves in the opposite direction of the scroll
+        let offset_x = -node_layout.offset_x.get();
+        let offset_y = -node_layout.offset_y.get();
+        let max_offset_x = (layout_node.inner_sizes.width - layout_node.area.width()).max(0.0);
+        let max_offset_y = (layout_node.inner_sizes.height - layout_node.area.height()).max(0.0);
+
+        let data = ScrollData::new(
+            (offset_x / scale_factor, offset_y / scale_factor),
+            (
+                (offset_x + previous_offset_x) / scale_factor,
+                (offset_y + previous_offset_y) / scale_factor,
+            ),
+            (max_offset_x / scale_factor, max_offset_y / scale_factor),
+        );
+        let mut event = DomEvent::new_scroll(node_id, element_id, data);
+        event.data.set_dataset(get_node_dataset(&node));
+        event_emitter.send(event).unwrap();
+    }
+}
+
+/// Emit an event to a node directly, no matter where it is or what is under the cursor.
+///
+/// Only the given event is emitted, without the collateral events (e.g. `mouseenter` for `mouseover`),
+/// the capture phase or the global listeners. Events that bubble go up to the ancestors of the node
+/// like any other event, even if the node itself isn't listening. The coordinates of pointer events
+/// are replaced by the center of the node.
+pub fn process_node_event(
+    dom: &FreyaDOM,
+    node_id: NodeId,
+    mut event: PlatformEvent,
+    event_emitter: &EventEmitter,
+    scale_factor: f64,
+) {
+    let layout = dom.layout();
+    let rdom = dom.rdom();
+
+    let Some(node) = rdom.get(node_id) else {
+        return;
+    };
+    let (Some(layout_node), Some(element_id)) = (layout.get(node_id), node.mounted_id()) else {
+        return;
+    };
+
+    let center = layout_node.visible_area().center().cast::<f64>();
+    event.map_cursor(|_| center);
+
+    let mut event = DomEvent::new(
+        PotentialEvent {
+            node_id,
+            layer: None,
+            event,
+            source: 0,
+        },
+        element_id,
+        Some(layout_node.visible_area()),
+        scale_factor,
+    );
+    event.data.set_dataset(get_node_dataset(&node));
+    event.data.set_target(get_event_target(rdom, node_id));
+    event_emitter.send(event).unwrap();
+}
+
+/// Get the `data-*` attributes of a node, to be passed along its events.
+fn get_node_dataset(node: &DioxusNode) -> Dataset {
+    match &*node.node_type() {
+        NodeType::Element(ElementNode { dataset, .. }) if !dataset.is_empty() => Dataset::new(
+            dataset
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect(),
+        ),
+        _ => Dataset::default(),
+    }
+}
+
+/// Get the target of the events that originated in a node, with the `data-*` attributes of it and its ancestors.
+fn get_event_target(rdom: &DioxusDOM, node_id: NodeId) -> EventTarget {
+    let mut dataset = HashMap::new();
+    let mut element_id = None;
+    let mut next_node = rdom.get(node_id);
+    while let Some(node) = next_node {
+        if let NodeType::Element(ElementNode {
+            dataset: node_dataset,
+            ..
+        }) = &*node.node_type()
+        {
+            element_id = element_id.or(node.mounted_id());
+            for (key, value) in node_dataset {
+                dataset.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+        next_node = node.parent_id().and_then(|parent_id| rdom.get(parent_id));
+    }
+    EventTarget::new(element_id, Dataset::new(dataset))
+}
+
+/// Make the capture and bubble phases of the events that come from the same platform event share the same [`CaptureState`],
+/// so the capture handlers can stop the rest of handlers.
+fn share_capture_states(events: &mut [DomEvent]) {
+    let mut capture_states = FxHashMap::<EventName, CaptureState>::default();
+    for event in events {
+        let capture_event = if event.name.is_capture() {
+            Some(event.name)
+        } else {
+            event.name.get_capture_event()
+        };
+        if let Some(capture_event) = capture_event {
+            let capture_state = capture_states.entry(capture_event).or_default().clone();
+            event.data.set_capture_state(capture_state);
+        }
+    }
+}
+
 /// Measure colateral global events
 pub fn measure_colateral_global_events(events: &[DomEvent]) -> Vec<DomEvent> {
     let mut global_events = Vec::default();
@@ -88,7 +298,10 @@ pub fn measure_global_events(events: &EventsQueue) -> Vec<PlatformEvent> {
     global_events
 }
 
-/// Measure what potential event listeners could be triggered
+/// Measure what potential event listeners could be triggered.
+///
+/// The nodes are visited in the same order they are painted, layer by layer and in the order of the document
+/// inside the same layer, so the last node of every event is the topmost one.
 pub fn measure_potential_event_listeners(events: &EventsQueue, fdom: &FreyaDOM) -> PotentialEvents {
     let mut potential_events = PotentialEvents::default();
 
@@ -101,12 +314,13 @@ pub fn measure_potential_event_listeners(events: &EventsQueue, fdom: &FreyaDOM)
         for node_id in layer_nodes {
             let layout_node = layout.get(*node_id);
             if let Some(layout_node) = layout_node {
-                'events: for event in events.iter() {
+                for (source, event) in events.iter().enumerate() {
                     if let PlatformEvent::Keyboard { name, .. } = event {
                         let event_data = PotentialEvent {
                             node_id: *node_id,
-                            layer: Some(*layer),
+                            layer: Some(layer.clone()),
                             event: event.clone(),
+                            source,
                         };
                         potential_events.entry(*name).or_default().push(event_data);
                     } else {
@@ -118,25 +332,15 @@ pub fn measure_potential_event_listeners(events: &EventsQueue, fdom: &FreyaDOM)
                             _ => None,
                         };
                         if let Some((name, cursor)) = data {
-                            let cursor_is_inside = layout_node.area.contains(cursor.to_f32());
-
-                            // Make sure the cursor is inside the node area
-                            if cursor_is_inside {
-                                let node = rdom.get(*node_id).unwrap();
-                                let node_viewports = node.get::<ViewportState>().unwrap();
-
-                                // Make sure the cursor is inside all the applicable viewports from the element
-                                for viewport_id in &node_viewports.viewports {
-                                    let viewport = layout.get(*viewport_id).unwrap().visible_area();
-                                    if !viewport.contains(cursor.to_f32()) {
-                                        continue 'events;
-                                    }
-                                }
+                            let node = rdom.get(*node_id).unwrap();
 
+                            // Make sure the cursor hits the node
+                            if hits_node(&layout, &node, layout_node, cursor.to_f32()) {
                                 let event_data = PotentialEvent {
                                     node_id: *node_id,
-                                    layer: Some(*layer),
+                                    layer: Some(layer.clone()),
                                     event: event.clone(),
+                                    source,
                                 };
 
                                 potential_events
@@ -180,85 +384,182 @@ fn measure_dom_events(
     scale_factor: f64,
 ) -> Vec<DomEvent> {
     let mut new_events = Vec::new();
-    let rdom = fdom.rdom();
 
-    // Iterate over all the events
+    // Iterate over all the events, the platform events of the same name are measured independently
     for (event_name, event_nodes) in potential_events {
-        let collateral_events = event_name.get_collateral_events();
+        let sources = event_nodes
+            .iter()
+            .map(|event| event.source)
+            .unique()
+            .collect::<Vec<_>>();
+        for source in sources {
+            let event_nodes = event_nodes
+                .iter()
+                .filter(|event| event.source == source)
+                .collect::<Vec<_>>();
+            let mut source_events =
+                measure_source_dom_events(event_name, &event_nodes, fdom, scale_factor);
+            share_capture_states(&mut source_events);
+            new_events.extend(source_events);
+        }
+    }
 
-        let mut valid_events: Vec<PotentialEvent> = Vec::new();
+    new_events
+}
+
+/// Measure what DOM events could be emitted for the potential events of a single platform event
+fn measure_source_dom_events(
+    event_name: EventName,
+    event_nodes: &[&PotentialEvent],
+    fdom: &FreyaDOM,
+    scale_factor: f64,
+) -> Vec<DomEvent> {
+    let mut new_events = Vec::new();
+    let rdom = fdom.rdom();
+
+    let collateral_events = event_name.get_collateral_events();
+
+    let mut valid_events: Vec<PotentialEvent> = Vec::new();
+
+    // Iterate over the collateral events (including the source), their capture phase is measured along with them
+    'event: for collateral_event in collateral_events {
+        if collateral_event.is_capture() {
+            continue;
+        }
+        let capture_event = collateral_event.get_capture_event();
 
-        // Iterate over the collateral events (including the source)
-        'event: for collateral_event in collateral_events {
-            let mut child_node: Option<NodeId> = None;
+        let mut child_node: Option<NodeId> = None;
+        // First node of the path the event bubbles up
+        let mut bubble_start: Option<&PotentialEvent> = None;
 
-            // Iterate over the event nodes
-            for PotentialEvent {
+        // Iterate over the event nodes
+        for potential_event in event_nodes.iter().copied().rev() {
+            let PotentialEvent {
                 node_id,
                 event,
                 layer,
-            } in event_nodes.iter().rev()
-            {
-                let Some(node) = rdom.get(*node_id) else {
-                    continue;
-                };
-
-                if rdom.is_node_listening(node_id, &collateral_event) {
-                    let valid_node = if let Some(child_node) = child_node {
-                        is_node_parent_of(rdom, child_node, *node_id)
-                    } else {
-                        true
-                    };
+                source,
+            } = potential_event;
+            let Some(node) = rdom.get(*node_id) else {
+                continue;
+            };
+
+            let is_listening = rdom.is_node_listening(node_id, &collateral_event);
+            let is_capturing = capture_event
+                .is_some_and(|capture_event| rdom.is_node_listening(node_id, &capture_event));
+
+            let valid_node = match child_node {
+                Some(child_node) if is_listening || is_capturing => {
+                    is_node_parent_of(rdom, child_node, *node_id)
+                }
+                _ => true,
+            };
 
-                    if valid_node {
-                        let mut valid_event = event.clone();
-                        valid_event.set_name(collateral_event);
-                        valid_events.push(PotentialEvent {
-                            node_id: *node_id,
-                            event: valid_event,
-                            layer: *layer,
-                        });
+            if valid_node {
+                if bubble_start.is_none() && (is_listening || is_capturing) {
+                    bubble_start = Some(potential_event);
+                }
 
-                        // Stack events that do not bubble up
-                        if event.get_name().does_bubble() {
-                            continue 'event;
-                        }
+                if is_listening {
+                    let mut valid_event = event.clone();
+                    valid_event.set_name(collateral_event);
+                    valid_events.push(PotentialEvent {
+                        node_id: *node_id,
+                        event: valid_event,
+                        layer: layer.clone(),
+                        source: *source,
+                    });
+
+                    // Stack events that do not bubble up
+                    if event.get_name().does_bubble() {
+                        valid_events.extend(measure_capture_events(
+                            rdom,
+                            capture_event,
+                            bubble_start,
+                        ));
+                        continue 'event;
                     }
                 }
+            }
 
-                let Style { background, .. } = &*node.get::<Style>().unwrap();
+            let Style { background, .. } = &*node.get::<Style>().unwrap();
 
-                if background != &Fill::Color(Color::TRANSPARENT)
-                    && !event.get_name().does_go_through_solid()
-                {
-                    // If the background isn't transparent,
-                    // we must make sure that next nodes are parent of it
-                    // This only matters for events that bubble up (e.g. cursor movement events)
-                    child_node = Some(*node_id);
-                }
+            if background != &Fill::Color(Color::TRANSPARENT)
+                && !event.get_name().does_go_through_solid()
+            {
+                // If the background isn't transparent,
+                // we must make sure that next nodes are parent of it
+                // This only matters for events that bubble up (e.g. cursor movement events)
+                child_node = Some(*node_id);
             }
         }
 
-        for potential_event in valid_events {
-            let layout = fdom.layout();
-            let layout_node = layout.get(potential_event.node_id);
-            if let Some(layout_node) = layout_node {
-                let node_ref = fdom.rdom().get(potential_event.node_id).unwrap();
-                let element_id = node_ref.mounted_id().unwrap();
-                let event = DomEvent::new(
-                    potential_event,
-                    element_id,
-                    Some(layout_node.visible_area()),
-                    scale_factor,
-                );
-                new_events.push(event);
-            }
+        valid_events.extend(measure_capture_events(rdom, capture_event, bubble_start));
+    }
+
+    // The topmost node is the target, even if it's not listening
+    let target = match event_nodes.last() {
+        Some(target) if !valid_events.is_empty() => get_event_target(rdom, target.node_id),
+        _ => EventTarget::default(),
+    };
+
+    for potential_event in valid_events {
+        let layout = fdom.layout();
+        let layout_node = layout.get(potential_event.node_id);
+        if let Some(layout_node) = layout_node {
+            let node_ref = fdom.rdom().get(potential_event.node_id).unwrap();
+            let element_id = node_ref.mounted_id().unwrap();
+            let mut event = DomEvent::new(
+                potential_event,
+                element_id,
+                Some(layout_node.visible_area()),
+                scale_factor,
+            );
+            event.data.set_dataset(get_node_dataset(&node_ref));
+            event.data.set_target(target.clone());
+            new_events.push(event);
         }
     }
 
     new_events
 }
 
+/// Get the capture events for the nodes listening to them in the path an event bubbles up, from the root to the node where it starts.
+fn measure_capture_events(
+    rdom: &DioxusDOM,
+    capture_event: Option<EventName>,
+    bubble_start: Option<&PotentialEvent>,
+) -> Vec<PotentialEvent> {
+    let (Some(capture_event), Some(bubble_start)) = (capture_event, bubble_start) else {
+        return Vec::new();
+    };
+
+    let mut bubble_path = Vec::new();
+    let mut next_node = rdom.get(bubble_start.node_id);
+    while let Some(node) = next_node {
+        bubble_path.push(node.id());
+        next_node = node.parent_id().and_then(|parent_id| rdom.get(parent_id));
+    }
+
+    bubble_path
+        .into_iter()
+        .rev()
+        .filter(|node_id| rdom.is_node_listening(node_id, &capture_event))
+        .map(|node_id| {
+            let mut event = bubble_start.event.clone();
+            event.set_name(capture_event);
+            PotentialEvent {
+                node_id,
+                event,
+                layer: rdom
+                    .get(node_id)
+                    .map(|node| node.get::<LayerState>().unwrap().layer.clone()),
+                source: bubble_start.source,
+            }
+        })
+        .collect()
+}
+
 /// Emit global events
 fn emit_global_events_listeners(
     global_events: Vec<PlatformEvent>,
@@ -273,16 +574,18 @@ fn emit_global_events_listeners(
 
         for listener in listeners {
             let element_id = listener.mounted_id().unwrap();
-            let event = DomEvent::new(
+            let mut event = DomEvent::new(
                 PotentialEvent {
                     node_id: listener.id(),
                     layer: None,
                     event: global_event.clone(),
+                    source: 0,
                 },
                 element_id,
                 None,
                 scale_factor,
             );
+            event.data.set_dataset(get_node_dataset(&listener));
             event_emitter.send(event).unwrap();
         }
     }
@@ -0,0 +1,324 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use freya_elements::events::{
+    keyboard::{Code, Key, Modifiers},
+    WheelDelta,
+};
+use serde_json::{json, Value};
+use torin::prelude::*;
+use winit::event::{Force, MouseButton, TouchPhase};
+
+use crate::prelude::{EventName, PlatformEvent};
+
+/// [`PlatformEvent`] captured by an [`EventsRecorder`], along with the time since the recording started.
+#[derive(Clone, Debug)]
+pub struct RecordedEvent {
+    pub time: Duration,
+    pub event: PlatformEvent,
+}
+
+/// Records the [`PlatformEvent`]s received by an app, so they can be replayed later.
+pub struct EventsRecorder {
+    started_at: Instant,
+    events: Vec<RecordedEvent>,
+}
+
+impl Default for EventsRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventsRecorder {
+    /// Start a new recording.
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Capture an event at the current time.
+    pub fn record(&mut self, event: &PlatformEvent) {
+        self.events.push(RecordedEvent {
+            time: self.started_at.elapsed(),
+            event: event.clone(),
+        });
+    }
+
+    /// Stop recording and get the captured events.
+    pub fn finish(self) -> EventsRecording {
+        EventsRecording {
+            events: self.events,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum EventsRecordingError {
+    /// The recording file couldn't be read or written.
+    Io(std::io::Error),
+    /// The recording is not valid JSON.
+    Json(serde_json::Error),
+    /// The event at the given index of the recording is not valid.
+    InvalidEvent(usize),
+}
+
+impl fmt::Display for EventsRecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "Failed to access the recording: {err}"),
+            Self::Json(err) => write!(f, "Invalid recording: {err}"),
+            Self::InvalidEvent(index) => write!(f, "Invalid event at index {index}"),
+        }
+    }
+}
+
+impl std::error::Error for EventsRecordingError {}
+
+/// Sequence of [`RecordedEvent`]s, sorted by time.
+///
+/// It can be saved as JSON to attach it to a bug report, and replayed with the headless runner of `freya-testing`.
+#[derive(Clone, Debug, Default)]
+pub struct EventsRecording {
+    pub events: Vec<RecordedEvent>,
+}
+
+impl EventsRecording {
+    /// Time of the last event.
+    pub fn duration(&self) -> Duration {
+        self.events
+            .last()
+            .map(|recorded| recorded.time)
+            .unwrap_or_default()
+    }
+
+    /// Serialize the recording.
+    pub fn to_json(&self) -> Value {
+        Value::Array(
+            self.events
+                .iter()
+                .map(|recorded| {
+                    let mut value = event_to_json(&recorded.event);
+                    value["time"] = json!(recorded.time.as_nanos() as u64);
+                    value
+                })
+                .collect(),
+        )
+    }
+
+    /// Deserialize a recording produced by [`to_json`](Self::to_json).
+    pub fn from_json(value: &Value) -> Result<Self, EventsRecordingError> {
+        let events = value
+            .as_array()
+            .ok_or(EventsRecordingError::InvalidEvent(0))?
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let time = value["time"].as_u64().map(Duration::from_nanos);
+                let event = event_from_json(value);
+                time.zip(event)
+                    .map(|(time, event)| RecordedEvent { time, event })
+                    .ok_or(EventsRecordingError::InvalidEvent(index))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { events })
+    }
+
+    /// Write the recording as JSON into the given file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), EventsRecordingError> {
+        let json =
+            serde_json::to_string_pretty(&self.to_json()).map_err(EventsRecordingError::Json)?;
+        std::fs::write(path, json).map_err(EventsRecordingError::Io)
+    }
+
+    /// Read a recording previously written with [`save`](Self::save).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, EventsRecordingError> {
+        let json = std::fs::read_to_string(path).map_err(EventsRecordingError::Io)?;
+        let value = serde_json::from_str(&json).map_err(EventsRecordingError::Json)?;
+        Self::from_json(&value)
+    }
+}
+
+fn event_to_json(event: &PlatformEvent) -> Value {
+    let name: &str = event.get_name().into();
+    match event {
+        PlatformEvent::Mouse { cursor, button, .. } => json!({
+            "type": "mouse",
+            "name": name,
+            "cursor": point_to_json(cursor),
+            "button": button.map(mouse_button_to_json),
+        }),
+        PlatformEvent::Wheel {
+            scroll,
+            cursor,
+            delta,
+            modifiers,
+            ..
+        } => json!({
+            "type": "wheel",
+            "name": name,
+            "scroll": point_to_json(scroll),
+            "cursor": point_to_json(cursor),
+            "delta": match delta {
+                WheelDelta::Lines { x, y } => json!({ "lines": [x, y] }),
+                WheelDelta::Pixels { x, y } => json!({ "pixels": [x, y] }),
+            },
+            "modifiers": modifiers.bits(),
+        }),
+        PlatformEvent::Keyboard {
+            key,
+            code,
+            modifiers,
+            repeat,
+            ..
+        } => json!({
+            "type": "keyboard",
+            "name": name,
+            "key": key.to_string(),
+            "code": code.to_string(),
+            "modifiers": modifiers.bits(),
+            "repeat": repeat,
+        }),
+        PlatformEvent::Touch {
+            location,
+            finger_id,
+            phase,
+            force,
+            ..
+        } => json!({
+            "type": "touch",
+            "name": name,
+            "location": point_to_json(location),
+            "finger_id": finger_id,
+            "phase": match phase {
+                TouchPhase::Started => "started",
+                TouchPhase::Moved => "moved",
+                TouchPhase::Ended => "ended",
+                TouchPhase::Cancelled => "cancelled",
+            },
+            "force": force.map(|force| match force {
+                Force::Calibrated {
+                    force,
+                    max_possible_force,
+                    altitude_angle,
+                } => json!({
+                    "force": force,
+                    "max_possible_force": max_possible_force,
+                    "altitude_angle": altitude_angle,
+                }),
+                Force::Normalized(force) => json!(force),
+            }),
+        }),
+        PlatformEvent::File {
+            cursor, file_path, ..
+        } => json!({
+            "type": "file",
+            "name": name,
+            "cursor": point_to_json(cursor),
+            "file_path": file_path,
+        }),
+    }
+}
+
+fn event_from_json(value: &Value) -> Option<PlatformEvent> {
+    let name = EventName::from_str(value["name"].as_str()?).ok()?;
+    Some(match value["type"].as_str()? {
+        "mouse" => PlatformEvent::Mouse {
+            name,
+            cursor: point_from_json(&value["cursor"])?,
+            button: match &value["button"] {
+                Value::Null => None,
+                button => Some(mouse_button_from_json(button)?),
+            },
+        },
+        "wheel" => PlatformEvent::Wheel {
+            name,
+            scroll: point_from_json(&value["scroll"])?,
+            cursor: point_from_json(&value["cursor"])?,
+            delta: if let Some(lines) = point_from_json(&value["delta"]["lines"]) {
+                WheelDelta::Lines {
+                    x: lines.x as f32,
+                    y: lines.y as f32,
+                }
+            } else {
+                let pixels = point_from_json(&value["delta"]["pixels"])?;
+                WheelDelta::Pixels {
+                    x: pixels.x,
+                    y: pixels.y,
+                }
+            },
+            modifiers: Modifiers::from_bits_truncate(value["modifiers"].as_u64()? as u32),
+        },
+        "keyboard" => PlatformEvent::Keyboard {
+            name,
+            key: Key::from_str(value["key"].as_str()?).ok()?,
+            code: Code::from_str(value["code"].as_str()?).ok()?,
+            modifiers: Modifiers::from_bits_truncate(value["modifiers"].as_u64()? as u32),
+            repeat: value["repeat"].as_bool()?,
+        },
+        "touch" => PlatformEvent::Touch {
+            name,
+            location: point_from_json(&value["location"])?,
+            finger_id: value["finger_id"].as_u64()?,
+            phase: match value["phase"].as_str()? {
+                "started" => TouchPhase::Started,
+                "moved" => TouchPhase::Moved,
+                "ended" => TouchPhase::Ended,
+                "cancelled" => TouchPhase::Cancelled,
+                _ => return None,
+            },
+            force: match &value["force"] {
+                Value::Null => None,
+                Value::Number(force) => Some(Force::Normalized(force.as_f64()?)),
+                force => Some(Force::Calibrated {
+                    force: force["force"].as_f64()?,
+                    max_possible_force: force["max_possible_force"].as_f64()?,
+                    altitude_angle: force["altitude_angle"].as_f64(),
+                }),
+            },
+        },
+        "file" => PlatformEvent::File {
+            name,
+            cursor: point_from_json(&value["cursor"])?,
+            file_path: value["file_path"].as_str().map(PathBuf::from),
+        },
+        _ => return None,
+    })
+}
+
+fn point_to_json(point: &CursorPoint) -> Value {
+    json!([point.x, point.y])
+}
+
+fn point_from_json(value: &Value) -> Option<CursorPoint> {
+    Some(CursorPoint::new(value[0].as_f64()?, value[1].as_f64()?))
+}
+
+fn mouse_button_to_json(button: MouseButton) -> Value {
+    match button {
+        MouseButton::Left => json!("left"),
+        MouseButton::Right => json!("right"),
+        MouseButton::Middle => json!("middle"),
+        MouseButton::Back => json!("back"),
+        MouseButton::Forward => json!("forward"),
+        MouseButton::Other(button) => json!(button),
+    }
+}
+
+fn mouse_button_from_json(value: &Value) -> Option<MouseButton> {
+    if let Some(button) = value.as_u64() {
+        return u16::try_from(button).ok().map(MouseButton::Other);
+    }
+    Some(match value.as_str()? {
+        "left" => MouseButton::Left,
+        "right" => MouseButton::Right,
+        "middle" => MouseButton::Middle,
+        "back" => MouseButton::Back,
+        "forward" => MouseButton::Forward,
+        _ => return None,
+    })
+}
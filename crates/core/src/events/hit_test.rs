@@ -0,0 +1,152 @@
+use freya_engine::prelude::Color;
+use freya_native_core::{node::NodeType, real_dom::NodeImmutable, tags::TagName, NodeId};
+use freya_node_state::{BorderStyle, Fill, References, Style, ViewportState};
+use itertools::sorted;
+use torin::prelude::*;
+
+use crate::dom::{DioxusNode, FreyaDOM};
+
+/// Move a point of the layout into the space a Node is painted in, undoing the rotations it inherits.
+fn unrotate_point(layout: &Torin<NodeId>, rotations: &[(NodeId, f32)], point: Point2D) -> Point2D {
+    rotations
+        .iter()
+        .fold(point, |point, (rotated_node_id, rotate_degs)| {
+            let Some(rotated_node) = layout.get(*rotated_node_id) else {
+                return point;
+            };
+            let center = rotated_node.visible_area().center();
+            let (sin, cos) = (-rotate_degs.to_radians()).sin_cos();
+            let offset = point - center;
+            Point2D::new(
+                center.x + offset.x * cos - offset.y * sin,
+                center.y + offset.x * sin + offset.y * cos,
+            )
+        })
+}
+
+/// Check if a point of the layout hits a Node, respecting its `pointer_events`, `hit_padding`, rotations
+/// and custom element, and the clipping of its ancestors.
+pub(crate) fn hits_node(
+    layout: &Torin<NodeId>,
+    node: &DioxusNode,
+    layout_node: &LayoutNode,
+    point: Point2D,
+) -> bool {
+    let node_viewports = node.get::<ViewportState>().unwrap();
+
+    // Elements with `pointer_events: "none"` let the events through
+    if node_viewports.pointer_events.is_none() {
+        return false;
+    }
+
+    // The clipping is applied in the same space as the rotated Node is painted
+    let point = unrotate_point(layout, &node_viewports.rotations, point);
+
+    let hit_padding = node.get::<Style>().unwrap().hit_padding;
+    if !layout_node.area.before_gaps(&hit_padding).contains(point) {
+        return false;
+    }
+
+    // Custom elements might only be hit in some parts of their area
+    if let Some(custom_element) = &node.get::<References>().unwrap().custom_element {
+        if !custom_element
+            .element
+            .hit_test(layout_node.visible_area(), point)
+        {
+            return false;
+        }
+    }
+
+    // Make sure the point is inside all the applicable viewports from the element
+    let inside_viewports = node_viewports.viewports.iter().all(|viewport_id| {
+        layout
+            .get(*viewport_id)
+            .map(|viewport| viewport.visible_area().contains(point))
+            .unwrap_or_default()
+    });
+
+    // And inside all the shapes it's clipped to
+    inside_viewports
+        && node_viewports
+            .clip_paths
+            .iter()
+            .all(|(clip_node_id, clip_path)| {
+                layout
+                    .get(*clip_node_id)
+                    .map(|clip_node| clip_path.contains(clip_node.visible_area(), point.to_tuple()))
+                    .unwrap_or_default()
+            })
+}
+
+/// Map a point of the Window into the layout coordinates.
+fn layout_point(fdom: &FreyaDOM, point: CursorPoint) -> Point2D {
+    let point = fdom
+        .letterbox()
+        .map(|letterbox| letterbox.to_layout(point))
+        .unwrap_or(point);
+    point.to_f32()
+}
+
+/// Check if a point of the Window, in physical pixels, is inside of a Node for the pointer events.
+///
+/// It doesn't matter whether other Nodes are above it, see [`nodes_at_point`] for that.
+pub fn is_point_inside(fdom: &FreyaDOM, node_id: NodeId, point: CursorPoint) -> bool {
+    let layout = fdom.layout();
+    let (Some(node), Some(layout_node)) = (fdom.rdom().get(node_id), layout.get(node_id)) else {
+        return false;
+    };
+    hits_node(&layout, &node, layout_node, layout_point(fdom, point))
+}
+
+/// Get the Nodes under a point of the Window, in physical pixels, from the topmost to the bottommost one.
+///
+/// The first one is the target the pointer events at that point are emitted to.
+pub fn nodes_at_point(fdom: &FreyaDOM, point: CursorPoint) -> Vec<NodeId> {
+    let layout = fdom.layout();
+    let rdom = fdom.rdom();
+    let point = layout_point(fdom, point);
+
+    let mut nodes = Vec::new();
+    for (_, layer_nodes) in sorted(fdom.layers().layers().iter()) {
+        for node_id in layer_nodes {
+            let (Some(node), Some(layout_node)) = (rdom.get(*node_id), layout.get(*node_id)) else {
+                continue;
+            };
+            if hits_node(&layout, &node, layout_node, point) {
+                nodes.push(*node_id);
+            }
+        }
+    }
+
+    // The last painted Node is the one on top
+    nodes.reverse();
+    nodes
+}
+
+/// Check if a Node paints something over its area, like a background, a border, text or an image.
+fn paints_content(node: &DioxusNode) -> bool {
+    let NodeType::Element(element) = &*node.node_type() else {
+        return false;
+    };
+    match element.tag {
+        TagName::Label | TagName::Paragraph | TagName::Image | TagName::Svg => true,
+        TagName::Rect => {
+            let style = node.get::<Style>().unwrap();
+            style.background != Fill::Color(Color::TRANSPARENT)
+                || (style.border.style != BorderStyle::None && style.border.width > 0.0)
+                || style.shader.is_some()
+        }
+        _ => false,
+    }
+}
+
+/// Check if a point of the Window, in physical pixels, is over a Node that paints something,
+/// so it isn't a fully transparent part of the Window.
+pub fn is_point_painted(fdom: &FreyaDOM, point: CursorPoint) -> bool {
+    let rdom = fdom.rdom();
+    nodes_at_point(fdom, point).into_iter().any(|node_id| {
+        rdom.get(node_id)
+            .map(|node| paints_content(&node))
+            .unwrap_or_default()
+    })
+}
@@ -1,13 +1,17 @@
 pub mod dom_event;
 pub mod event_name;
 pub mod events_measurer;
+pub mod events_recording;
+pub mod hit_test;
 pub mod nodes_state;
-pub mod platform_event;
 pub mod potential_event;
 
+pub use freya_common::PlatformEvent;
+
 pub use dom_event::*;
 pub use event_name::*;
 pub use events_measurer::*;
+pub use events_recording::*;
+pub use hit_test::*;
 pub use nodes_state::*;
-pub use platform_event::*;
 pub use potential_event::*;
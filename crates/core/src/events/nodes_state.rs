@@ -1,7 +1,8 @@
 #![allow(clippy::type_complexity)]
 
+use freya_common::Layer;
 use freya_native_core::NodeId;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     events::{DomEvent, PlatformEvent},
@@ -10,13 +11,14 @@ use crate::{
 
 #[derive(Clone)]
 struct NodeMetadata {
-    layer: Option<i16>,
+    layer: Option<Layer>,
 }
 
 /// [`NodesState`] stores the nodes states given incoming events.
 #[derive(Default)]
 pub struct NodesState {
     hovered_nodes: FxHashMap<NodeId, NodeMetadata>,
+    pressed_nodes: FxHashSet<NodeId>,
 }
 
 impl NodesState {
@@ -36,19 +38,20 @@ impl NodesState {
                 has_node_been_hovered_recently(events_to_emit, node_id);
 
             if no_recent_mouse_movement_on_me {
-                if let Some(PlatformEvent::Mouse { cursor, button, .. }) =
+                if let Some((source, PlatformEvent::Mouse { cursor, button, .. })) =
                     recent_mouse_movement_event
                 {
                     let events = potential_events.entry(EventName::MouseLeave).or_default();
                     // Emit a MouseLeave event as the cursor was moved outside the Node bounds
                     events.push(PotentialEvent {
                         node_id: *node_id,
-                        layer: metadata.layer,
+                        layer: metadata.layer.clone(),
                         event: PlatformEvent::Mouse {
                             name: EventName::MouseLeave,
                             cursor,
                             button,
                         },
+                        source,
                     });
 
                     // Remove the node from the list of hovered nodes as now, the cursor has left
@@ -69,8 +72,12 @@ impl NodesState {
 
                 // Mark the Node as hovered if it wasn't already
                 if !is_hovered {
-                    self.hovered_nodes
-                        .insert(event.node_id, NodeMetadata { layer: event.layer });
+                    self.hovered_nodes.insert(
+                        event.node_id,
+                        NodeMetadata {
+                            layer: event.layer.clone(),
+                        },
+                    );
                 }
 
                 if event.name.is_enter() {
@@ -88,8 +95,24 @@ impl NodesState {
         // e.g `mouseover` will mark the node as hovered.
         for event in events_to_emit {
             if event.name.was_cursor_moved() && !self.hovered_nodes.contains_key(&event.node_id) {
-                self.hovered_nodes
-                    .insert(event.node_id, NodeMetadata { layer: event.layer });
+                self.hovered_nodes.insert(
+                    event.node_id,
+                    NodeMetadata {
+                        layer: event.layer.clone(),
+                    },
+                );
+            }
+        }
+
+        // Releasing the pointer anywhere means that no Node is pressed anymore
+        if events.iter().any(|event| event.get_name().is_release()) {
+            self.pressed_nodes.clear();
+        }
+
+        // `mousedown` and `touchstart` mark the node as pressed
+        for event in events_to_emit {
+            if matches!(event.name, EventName::MouseDown | EventName::TouchStart) {
+                self.pressed_nodes.insert(event.node_id);
             }
         }
 
@@ -100,13 +123,26 @@ impl NodesState {
 
         (potential_events, new_events_to_emit)
     }
+
+    /// Nodes that are currently under the cursor.
+    /// Only those listening for mouse or pointer events are tracked.
+    pub fn hovered_nodes(&self) -> impl Iterator<Item = &NodeId> {
+        self.hovered_nodes.keys()
+    }
+
+    /// Nodes that have been pressed and not released yet.
+    /// Only those listening for mouse or pointer events are tracked.
+    pub fn pressed_nodes(&self) -> impl Iterator<Item = &NodeId> {
+        self.pressed_nodes.iter()
+    }
 }
 
-fn any_recent_mouse_movement(events: &[PlatformEvent]) -> Option<PlatformEvent> {
+fn any_recent_mouse_movement(events: &[PlatformEvent]) -> Option<(usize, PlatformEvent)> {
     events
         .iter()
-        .find(|event| event.get_name().was_cursor_moved())
-        .cloned()
+        .enumerate()
+        .find(|(_, event)| event.get_name().was_cursor_moved())
+        .map(|(source, event)| (source, event.clone()))
 }
 
 fn has_node_been_hovered_recently(events_to_emit: &[DomEvent], node_id: &NodeId) -> bool {
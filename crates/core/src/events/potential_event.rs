@@ -1,3 +1,4 @@
+use freya_common::Layer;
 use freya_native_core::NodeId;
 
 use crate::prelude::PlatformEvent;
@@ -7,5 +8,7 @@ use crate::prelude::PlatformEvent;
 pub struct PotentialEvent {
     pub(crate) node_id: NodeId,
     pub(crate) event: PlatformEvent,
-    pub(crate) layer: Option<i16>,
+    pub(crate) layer: Option<Layer>,
+    /// Position in the queue of the platform event it comes from.
+    pub(crate) source: usize,
 }
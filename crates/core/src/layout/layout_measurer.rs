@@ -13,7 +13,12 @@ pub fn process_layout(
     {
         let rdom = fdom.rdom();
         let mut dom_adapter = DioxusDOMAdapter::new_with_cache(rdom);
-        let skia_measurer = SkiaMeasurer::new(rdom, font_collection, default_fonts, scale_factor);
+        let mut skia_measurer = Some(SkiaMeasurer::new(
+            rdom,
+            font_collection,
+            default_fonts,
+            scale_factor,
+        ));
 
         // Finds the best Node from where to start measuring
         fdom.layout().find_best_root(&mut dom_adapter);
@@ -21,8 +26,12 @@ pub fn process_layout(
         let root_id = fdom.rdom().root_id();
 
         // Measure the layout
-        fdom.layout()
-            .measure(root_id, area, &mut Some(skia_measurer), &mut dom_adapter);
+        let mut layout = fdom.layout();
+        layout.measure(root_id, area, &mut skia_measurer, &mut dom_adapter);
+
+        if let Some(skia_measurer) = skia_measurer {
+            skia_measurer.notify_pending_references(&layout, area);
+        }
     }
 
     fdom.measure_all_paragraphs(scale_factor);
@@ -1,5 +1,9 @@
 pub mod layout_measurer;
 pub mod skia_measurer;
+pub mod text_layout;
+pub mod text_measurer;
 
 pub use layout_measurer::*;
 pub use skia_measurer::*;
+pub use text_layout::*;
+pub use text_measurer::*;
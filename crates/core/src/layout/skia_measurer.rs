@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{cell::RefCell, sync::Arc};
 
 use freya_common::{CachedParagraph, NodeReferenceLayout};
 use freya_native_core::{
@@ -7,10 +7,12 @@ use freya_native_core::{
     tags::TagName,
     NodeId,
 };
-use freya_node_state::{FontStyleState, LayoutState, TextOverflow};
+use freya_node_state::{
+    FontStyleState, LayoutState, References, TextHinting, TextOverflow, ViewportState, WhiteSpace,
+};
 
 use freya_engine::prelude::*;
-use torin::prelude::{LayoutMeasurer, LayoutNode, Node, Size2D};
+use torin::prelude::{Area, LayoutMeasurer, LayoutNode, Node, Size2D, Torin};
 
 use crate::dom::*;
 
@@ -20,6 +22,8 @@ pub struct SkiaMeasurer<'a> {
     pub rdom: &'a DioxusDOM,
     pub default_fonts: &'a [String],
     pub scale_factor: f32,
+    /// Layouts of the referenced Nodes, sent once the whole layout has been measured.
+    pending_references: RefCell<Vec<NodeReferenceLayout>>,
 }
 
 impl<'a> SkiaMeasurer<'a> {
@@ -34,6 +38,36 @@ impl<'a> SkiaMeasurer<'a> {
             rdom,
             default_fonts,
             scale_factor,
+            pending_references: RefCell::default(),
+        }
+    }
+
+    /// Send the layouts of the referenced Nodes measured in the last layout, along with the area of them
+    /// that ended up visible inside of their clipping ancestors and the `root_area`.
+    pub fn notify_pending_references(self, layout: &Torin<NodeId>, root_area: Area) {
+        for mut node_layout in self.pending_references.into_inner() {
+            let Some(node_id) = node_layout.node_id else {
+                continue;
+            };
+            let node = self.rdom.get(node_id).unwrap();
+
+            let visible_area = node
+                .get::<ViewportState>()
+                .unwrap()
+                .viewports
+                .iter()
+                .filter_map(|viewport_id| layout.get(*viewport_id))
+                .try_fold(node_layout.visible_area, |visible_area, viewport| {
+                    visible_area.intersection(&viewport.visible_area())
+                })
+                .and_then(|visible_area| visible_area.intersection(&root_area));
+            node_layout.visible_area = visible_area.unwrap_or_default();
+
+            let layout_state = node.get::<LayoutState>().unwrap();
+            if let Some(reference) = &layout_state.node_ref {
+                node_layout.div(self.scale_factor);
+                reference.0.send(node_layout).ok();
+            }
         }
     }
 }
@@ -52,9 +86,10 @@ impl<'a> LayoutMeasurer<NodeId> for SkiaMeasurer<'a> {
             NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Label => {
                 let label =
                     create_label(&node, area_size, self.font_collection, self.default_fonts);
-                let res = Size2D::new(label.longest_line(), label.height());
+                let font_style = node.get::<FontStyleState>().unwrap();
+                let res = Size2D::new(label.longest_line(), measured_height(&label, &font_style));
                 let mut map = SendAnyMap::new();
-                map.insert(CachedParagraph(label));
+                map.insert(CachedParagraph(label, area_size.width));
                 Some((res, Arc::new(map)))
             }
             NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Paragraph => {
@@ -65,36 +100,59 @@ impl<'a> LayoutMeasurer<NodeId> for SkiaMeasurer<'a> {
                     false,
                     self.default_fonts,
                 );
-                let res = Size2D::new(paragraph.longest_line(), paragraph.height());
+                let font_style = node.get::<FontStyleState>().unwrap();
+                let res = Size2D::new(
+                    paragraph.longest_line(),
+                    measured_height(&paragraph, &font_style),
+                );
                 let mut map = SendAnyMap::new();
-                map.insert(CachedParagraph(paragraph));
+                map.insert(CachedParagraph(paragraph, area_size.width));
                 Some((res, Arc::new(map)))
             }
+            NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Rect => {
+                let references = node.get::<References>().unwrap();
+                let custom_element = references.custom_element.as_ref()?;
+                let res = custom_element.element.measure(area_size)?;
+                Some((res, Arc::new(SendAnyMap::new())))
+            }
             _ => None,
         }
     }
 
-    fn should_measure_inner_children(&mut self, node_id: NodeId) -> bool {
+    fn should_measure_inner_children(&mut self, node_id: NodeId, layout_node: &LayoutNode) -> bool {
         let node = self.rdom.get(node_id).unwrap();
         let node_type: &NodeType<_> = &node.node_type();
 
-        node_type
-            .tag()
-            .map(|tag| tag.has_children_with_intrinsic_layout())
-            .unwrap_or_default()
+        match node_type.tag() {
+            // The children of the custom elements that measured themselves were never laid out
+            Some(TagName::Rect) => {
+                layout_node.data.is_none()
+                    || node.get::<References>().unwrap().custom_element.is_none()
+            }
+            Some(tag) => tag.has_children_with_intrinsic_layout(),
+            None => false,
+        }
+    }
+
+    fn baseline(&self, _node_id: NodeId, layout_node: &LayoutNode) -> Option<f32> {
+        let paragraph = &layout_node.data.as_ref()?.get::<CachedParagraph>()?.0;
+        Some(paragraph.alphabetic_baseline())
     }
 
     fn notify_layout_references(&self, node_id: NodeId, layout_node: &LayoutNode) {
         let node = self.rdom.get(node_id).unwrap();
         let size_state = &*node.get::<LayoutState>().unwrap();
 
-        if let Some(reference) = &size_state.node_ref {
-            let mut node_layout = NodeReferenceLayout {
-                area: layout_node.area,
-                inner: layout_node.inner_sizes,
-            };
-            node_layout.div(self.scale_factor);
-            reference.0.send(node_layout).ok();
+        // The clipping ancestors might not be measured yet, so the visible area is resolved later
+        if size_state.node_ref.is_some() {
+            self.pending_references
+                .borrow_mut()
+                .push(NodeReferenceLayout {
+                    area: layout_node.area,
+                    inner: layout_node.inner_sizes,
+                    visible_area: layout_node.visible_area(),
+                    node_id: Some(node_id),
+                });
         }
     }
 }
@@ -106,28 +164,72 @@ pub fn create_label(
     default_font_family: &[String],
 ) -> Paragraph {
     let font_style = &*node.get::<FontStyleState>().unwrap();
+    let children = node.children();
+    let node_types = children
+        .iter()
+        .map(|child| child.node_type())
+        .collect::<Vec<_>>();
+    let texts = node_types.iter().filter_map(|node_type| {
+        if let NodeType::Text(text) = &**node_type {
+            Some(text.as_str())
+        } else {
+            None
+        }
+    });
 
+    build_label(
+        texts,
+        font_style,
+        area_size.width,
+        font_collection,
+        default_font_family,
+    )
+}
+
+/// Compose the SkParagraph of a label with the given texts.
+pub fn build_label(
+    texts: impl Iterator<Item = impl AsRef<str>>,
+    font_style: &FontStyleState,
+    width: f32,
+    font_collection: &FontCollection,
+    default_font_family: &[String],
+) -> Paragraph {
     let mut paragraph_style = ParagraphStyle::default();
     paragraph_style.set_text_align(font_style.text_align);
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
+    if font_style.text_hinting == TextHinting::None {
+        paragraph_style.turn_hinting_off();
+    }
     let text_style = font_style.text_style(default_font_family);
     paragraph_style.set_text_style(&text_style);
 
-    if let Some(ellipsis) = font_style.text_overflow.get_ellipsis() {
+    let ellipsis = font_style.text_overflow.get_ellipsis();
+    if let Some(ellipsis) = ellipsis {
         paragraph_style.set_ellipsis(ellipsis);
+        if font_style.white_space == WhiteSpace::NoWrap {
+            paragraph_style.set_max_lines(1);
+        }
     }
 
     let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
 
-    for child in node.children() {
-        if let NodeType::Text(text) = &*child.node_type() {
-            paragraph_builder.add_text(text);
-        }
+    let mut after_space = true;
+    for text in texts {
+        paragraph_builder.add_text(
+            font_style
+                .white_space
+                .collapse(text.as_ref(), &mut after_space),
+        );
     }
 
     let mut paragraph = paragraph_builder.build();
-    paragraph.layout(area_size.width + 1.0);
+    layout_paragraph(
+        &mut paragraph,
+        width,
+        font_style.white_space,
+        ellipsis.is_some(),
+    );
     paragraph
 }
 
@@ -145,9 +247,16 @@ pub fn create_paragraph(
     paragraph_style.set_text_align(font_style.text_align);
     paragraph_style.set_max_lines(font_style.max_lines);
     paragraph_style.set_replace_tab_characters(true);
+    if font_style.text_hinting == TextHinting::None {
+        paragraph_style.turn_hinting_off();
+    }
 
-    if font_style.text_overflow == TextOverflow::Ellipsis {
+    let ellipsized = font_style.text_overflow == TextOverflow::Ellipsis;
+    if ellipsized {
         paragraph_style.set_ellipsis("…");
+        if font_style.white_space == WhiteSpace::NoWrap {
+            paragraph_style.set_max_lines(1);
+        }
     }
 
     let mut paragraph_builder = ParagraphBuilder::new(&paragraph_style, font_collection);
@@ -155,6 +264,7 @@ pub fn create_paragraph(
     let text_style = font_style.text_style(default_font_family);
     paragraph_builder.push_style(&text_style);
 
+    let mut after_space = true;
     for text_span in node.children() {
         match &*text_span.node_type() {
             NodeType::Element(ElementNode { tag, .. }) if tag == &TagName::Text => {
@@ -166,7 +276,8 @@ pub fn create_paragraph(
                 paragraph_builder.push_style(&text_style);
 
                 if let NodeType::Text(text) = text_node_type {
-                    paragraph_builder.add_text(text);
+                    paragraph_builder
+                        .add_text(font_style.white_space.collapse(text, &mut after_space));
                 }
             }
             _ => {}
@@ -179,6 +290,40 @@ pub fn create_paragraph(
     }
 
     let mut paragraph = paragraph_builder.build();
-    paragraph.layout(area_size.width + 1.0);
+    layout_paragraph(
+        &mut paragraph,
+        area_size.width,
+        font_style.white_space,
+        ellipsized,
+    );
     paragraph
 }
+
+/// Lay out the paragraph in the given width. Texts that don't wrap get as wide as their longest line,
+/// unless they are a single line cut with an ellipsis.
+fn layout_paragraph(
+    paragraph: &mut Paragraph,
+    width: f32,
+    white_space: WhiteSpace,
+    ellipsized: bool,
+) {
+    if white_space.wraps() || (white_space == WhiteSpace::NoWrap && ellipsized) {
+        paragraph.layout(width + 1.0);
+    } else {
+        paragraph.layout(f32::MAX);
+        paragraph.layout(width.max(paragraph.max_intrinsic_width()) + 1.0);
+    }
+}
+
+/// Get the height the paragraph takes in the layout, enough for its `min_lines` even if it has less lines.
+/// They can't be more than its `max_lines`.
+fn measured_height(paragraph: &Paragraph, font_style: &FontStyleState) -> f32 {
+    let min_lines = font_style
+        .min_lines
+        .min(font_style.max_lines.unwrap_or(usize::MAX));
+    let lines = paragraph.line_number().max(1);
+    if min_lines <= lines {
+        return paragraph.height();
+    }
+    paragraph.height() / lines as f32 * min_lines as f32
+}
@@ -0,0 +1,139 @@
+use std::{ops::Range, sync::Arc};
+
+use freya_common::CachedParagraph;
+use freya_engine::prelude::*;
+use torin::prelude::{Area, Point2D, Size2D};
+
+use crate::layout::TextMeasurement;
+
+/// A text laid out by [`TextMeasurer::layout`](crate::layout::TextMeasurer::layout), to map between
+/// positions in the text and text offsets, e.g. to place the caret of an editor.
+///
+/// Positions and rects are in logical pixels relative to the top-left corner of the text.
+/// Offsets are in chars, just like the cursor positions of the editable hooks.
+#[derive(Clone)]
+pub struct TextLayout {
+    pub(crate) text: Arc<str>,
+    pub(crate) paragraph: Arc<CachedParagraph>,
+    pub(crate) scale_factor: f32,
+}
+
+impl TextLayout {
+    /// Get the size of the laid out text.
+    pub fn measurement(&self) -> TextMeasurement {
+        let paragraph = &self.paragraph.0;
+        TextMeasurement {
+            height: paragraph.height() / self.scale_factor,
+            longest_line: paragraph.longest_line() / self.scale_factor,
+            lines: paragraph.line_number(),
+        }
+    }
+
+    /// Get the offset nearest to the given position.
+    pub fn offset_at(&self, x: f32, y: f32) -> usize {
+        let position = self
+            .paragraph
+            .0
+            .get_glyph_position_at_coordinate((x * self.scale_factor, y * self.scale_factor));
+        self.char_offset(position.position.max(0) as usize)
+    }
+
+    /// Get the rect where the caret goes when placed at the given offset, it has no width.
+    pub fn caret_rect(&self, offset: usize) -> Area {
+        let chars = self.text.chars().count();
+        let offset = offset.min(chars);
+
+        // Placed at the left edge of the char in this offset
+        if offset < chars {
+            if let Some(rect) = self.range_rects(offset..offset + 1).first() {
+                return Area::new(rect.origin, Size2D::new(0., rect.height()));
+            }
+        }
+
+        // Or at the right edge of the previous char, or the start of the next line after line breaks
+        if offset > 0 {
+            if let Some(rect) = self.range_rects(offset - 1..offset).first() {
+                return if self.text.chars().nth(offset - 1) == Some('\n') {
+                    Area::new(
+                        Point2D::new(0., rect.max_y()),
+                        Size2D::new(0., rect.height()),
+                    )
+                } else {
+                    Area::new(
+                        Point2D::new(rect.max_x(), rect.min_y()),
+                        Size2D::new(0., rect.height()),
+                    )
+                };
+            }
+        }
+
+        Area::new(
+            Point2D::default(),
+            Size2D::new(0., self.paragraph.0.height() / self.scale_factor),
+        )
+    }
+
+    /// Get the rects that enclose the text in the given range of offsets, e.g. to paint a selection.
+    pub fn range_rects(&self, range: Range<usize>) -> Vec<Area> {
+        let range = self.utf16_offset(range.start)..self.utf16_offset(range.end);
+        self.paragraph
+            .0
+            .get_rects_for_range(range, RectHeightStyle::Tight, RectWidthStyle::Tight)
+            .into_iter()
+            .map(|text_box| {
+                let rect = text_box.rect;
+                Area::new(
+                    Point2D::new(rect.left, rect.top),
+                    Size2D::new(rect.right - rect.left, rect.bottom - rect.top),
+                ) / self.scale_factor
+            })
+            .collect()
+    }
+
+    /// Get the offsets between the glyph clusters of the text, including the start and the end of the text.
+    ///
+    /// The caret should only be placed at these offsets, as a cluster (e.g. an emoji with modifiers)
+    /// is painted as a single glyph even if it's made of several chars.
+    pub fn cluster_boundaries(&self) -> Vec<usize> {
+        let mut boundaries = vec![0];
+        let mut offset = 0;
+        let mut byte_index = 0;
+
+        while byte_index < self.text.len() {
+            // Clusters are found by their index in the UTF-8 text
+            let mut end = self
+                .paragraph
+                .0
+                .get_glyph_cluster_at(byte_index)
+                .map(|cluster| cluster.text_range.end)
+                .filter(|end| *end > byte_index)
+                .unwrap_or(byte_index + 1)
+                .min(self.text.len());
+            while !self.text.is_char_boundary(end) {
+                end += 1;
+            }
+
+            offset += self.text[byte_index..end].chars().count();
+            boundaries.push(offset);
+            byte_index = end;
+        }
+
+        boundaries
+    }
+
+    /// Skia indexes the text of paragraphs in UTF-16 code units.
+    fn utf16_offset(&self, offset: usize) -> usize {
+        self.text.chars().take(offset).map(char::len_utf16).sum()
+    }
+
+    fn char_offset(&self, utf16_offset: usize) -> usize {
+        let mut utf16_count = 0;
+        self.text
+            .chars()
+            .take_while(|char| {
+                utf16_count += char.len_utf16();
+                utf16_count <= utf16_offset
+            })
+            .count()
+    }
+}
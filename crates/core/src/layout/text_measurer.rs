@@ -0,0 +1,171 @@
+use std::{
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+use freya_common::CachedParagraph;
+use freya_engine::prelude::*;
+use freya_node_state::FontStyleState;
+use rustc_hash::{FxHashMap, FxHasher};
+
+use crate::layout::{build_label, TextLayout};
+
+/// Maximum amount of measurements to keep cached.
+const MAX_CACHED_MEASUREMENTS: usize = 1024;
+
+/// Maximum amount of laid out texts to keep cached.
+const MAX_CACHED_LAYOUTS: usize = 64;
+
+/// Size of a wrapped text.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextMeasurement {
+    /// Height of all the lines together.
+    pub height: f32,
+    /// Width of the longest line.
+    pub longest_line: f32,
+    /// Amount of lines the text was wrapped into.
+    pub lines: usize,
+}
+
+impl TextMeasurement {
+    fn div(&mut self, rhs: f32) {
+        self.height /= rhs;
+        self.longest_line /= rhs;
+    }
+}
+
+/// Measures texts with the same SkParagraph code used to lay out labels,
+/// so their wrapped size can be known without laying out the whole tree.
+///
+/// Measurements and laid out texts are cached by text, width, style and scale factor.
+#[derive(Clone)]
+pub struct TextMeasurer {
+    font_collection: FontCollection,
+    default_fonts: Arc<Vec<String>>,
+    cache: Arc<Mutex<FxHashMap<u64, TextMeasurement>>>,
+    layouts: Arc<Mutex<FxHashMap<u64, Arc<CachedParagraph>>>>,
+}
+
+impl TextMeasurer {
+    pub fn new(font_collection: FontCollection, default_fonts: Vec<String>) -> Self {
+        Self {
+            font_collection,
+            default_fonts: Arc::new(default_fonts),
+            cache: Arc::default(),
+            layouts: Arc::default(),
+        }
+    }
+
+    /// Measure a text wrapped in the given width, as a label with the given font style would be.
+    ///
+    /// `width` and the font size of `font_style` are in logical pixels, as is the returned measurement.
+    pub fn measure(
+        &self,
+        text: &str,
+        font_style: &FontStyleState,
+        width: f32,
+        scale_factor: f32,
+    ) -> TextMeasurement {
+        let key = measurement_key(text, font_style, width, scale_factor);
+
+        if let Some(measurement) = self.cache.lock().unwrap().get(&key) {
+            return *measurement;
+        }
+
+        let paragraph = self.build_paragraph(text, font_style, width, scale_factor);
+
+        let mut measurement = TextMeasurement {
+            height: paragraph.height(),
+            longest_line: paragraph.longest_line(),
+            lines: paragraph.line_number(),
+        };
+        measurement.div(scale_factor);
+
+        let mut cache = self.cache.lock().unwrap();
+        if cache.len() >= MAX_CACHED_MEASUREMENTS {
+            cache.clear();
+        }
+        cache.insert(key, measurement);
+
+        measurement
+    }
+
+    /// Lay out a text wrapped in the given width, as a label with the given font style would be,
+    /// to map between positions and offsets of the text. See [`TextLayout`].
+    ///
+    /// `width` and the font size of `font_style` are in logical pixels.
+    pub fn layout(
+        &self,
+        text: &str,
+        font_style: &FontStyleState,
+        width: f32,
+        scale_factor: f32,
+    ) -> TextLayout {
+        let key = measurement_key(text, font_style, width, scale_factor);
+
+        let cached_paragraph = self.layouts.lock().unwrap().get(&key).cloned();
+        let paragraph = cached_paragraph.unwrap_or_else(|| {
+            let paragraph = Arc::new(CachedParagraph(
+                self.build_paragraph(text, font_style, width, scale_factor),
+                width * scale_factor,
+            ));
+
+            let mut layouts = self.layouts.lock().unwrap();
+            if layouts.len() >= MAX_CACHED_LAYOUTS {
+                layouts.clear();
+            }
+            layouts.insert(key, paragraph.clone());
+
+            paragraph
+        });
+
+        TextLayout {
+            text: text.into(),
+            paragraph,
+            scale_factor,
+        }
+    }
+
+    fn build_paragraph(
+        &self,
+        text: &str,
+        font_style: &FontStyleState,
+        width: f32,
+        scale_factor: f32,
+    ) -> Paragraph {
+        let font_style = FontStyleState {
+            font_size: font_style.font_size * scale_factor,
+            ..font_style.clone()
+        };
+        build_label(
+            [text].into_iter(),
+            &font_style,
+            width * scale_factor,
+            &self.font_collection,
+            &self.default_fonts,
+        )
+    }
+}
+
+/// Hash everything that affects how a text gets wrapped.
+fn measurement_key(text: &str, font_style: &FontStyleState, width: f32, scale_factor: f32) -> u64 {
+    let mut hasher = FxHasher::default();
+    text.hash(&mut hasher);
+    width.to_bits().hash(&mut hasher);
+    scale_factor.to_bits().hash(&mut hasher);
+    font_style.font_family.hash(&mut hasher);
+    font_style.font_size.to_bits().hash(&mut hasher);
+    (font_style.font_slant as i32).hash(&mut hasher);
+    (*font_style.font_weight).hash(&mut hasher);
+    (*font_style.font_width).hash(&mut hasher);
+    font_style.line_height.to_bits().hash(&mut hasher);
+    font_style.word_spacing.to_bits().hash(&mut hasher);
+    font_style.letter_spacing.to_bits().hash(&mut hasher);
+    (font_style.text_align as i32).hash(&mut hasher);
+    font_style.max_lines.hash(&mut hasher);
+    font_style.min_lines.hash(&mut hasher);
+    font_style.text_overflow.hash(&mut hasher);
+    font_style.white_space.hash(&mut hasher);
+    font_style.text_hinting.hash(&mut hasher);
+    hasher.finish()
+}
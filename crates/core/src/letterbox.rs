@@ -0,0 +1,61 @@
+use torin::prelude::{Area, CursorPoint, Point2D, Size2D};
+
+/// Fits a UI laid out at a fixed logical resolution into a Window of any size.
+///
+/// The UI is scaled uniformly until it fits the Window and gets centered in it,
+/// the remaining space at the sides (the letterbox bars) is left with the background color of the Window.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Letterbox {
+    /// Size in physical pixels at which the UI is laid out.
+    pub layout_size: Size2D,
+    /// Uniform scale applied to the UI to fit it in the Window.
+    pub scale: f32,
+    /// Position in the Window of the top-left corner of the UI, in physical pixels.
+    pub offset: Point2D,
+}
+
+impl Letterbox {
+    /// Fit the given `resolution` (in logical pixels) into a Window of `window_size` (in physical pixels).
+    pub fn new(resolution: Size2D, scale_factor: f32, window_size: Size2D) -> Self {
+        let layout_size = resolution * scale_factor;
+        let scale = (window_size.width / layout_size.width)
+            .min(window_size.height / layout_size.height)
+            .max(0.0);
+        let offset = Point2D::new(
+            (window_size.width - layout_size.width * scale) / 2.0,
+            (window_size.height - layout_size.height * scale) / 2.0,
+        );
+
+        Self {
+            layout_size,
+            scale,
+            offset,
+        }
+    }
+
+    /// Area in which the UI is laid out.
+    pub fn layout_area(&self) -> Area {
+        Area::from_size(self.layout_size)
+    }
+
+    /// Area of the Window in which the UI is painted.
+    pub fn window_area(&self) -> Area {
+        Area::new(self.offset, self.layout_size * self.scale)
+    }
+
+    /// Map an area of the layout into the Window.
+    pub fn to_window(&self, area: Area) -> Area {
+        Area::new(
+            self.offset + area.origin.to_vector() * self.scale,
+            area.size * self.scale,
+        )
+    }
+
+    /// Map a point of the Window into the layout coordinates.
+    pub fn to_layout(&self, point: CursorPoint) -> CursorPoint {
+        CursorPoint::new(
+            (point.x - self.offset.x as f64) / self.scale as f64,
+            (point.y - self.offset.y as f64) / self.scale as f64,
+        )
+    }
+}
@@ -1,7 +1,9 @@
 pub mod accessibility;
+pub mod dirty_regions;
 pub mod dom;
 pub mod events;
 pub mod layout;
+pub mod letterbox;
 pub mod navigation_mode;
 pub mod node;
 pub mod plugins;
@@ -10,15 +12,20 @@ pub mod types;
 
 pub mod prelude {
     pub use crate::accessibility::*;
+    pub use crate::dirty_regions::*;
     pub use crate::dom::*;
     pub use crate::events::*;
     pub use crate::layout::*;
+    pub use crate::letterbox::*;
     pub use crate::navigation_mode::*;
     pub use crate::node::*;
     pub use crate::plugins::*;
     pub use crate::render::*;
 
+    pub use crate::types::AccessibilityChecked;
     pub use crate::types::AccessibilityId;
+    pub use crate::types::AccessibilityLive;
+    pub use crate::types::AccessibilityNode;
     pub use crate::types::EventEmitter;
     pub use crate::types::EventReceiver;
     pub use crate::types::EventsQueue;
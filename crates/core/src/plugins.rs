@@ -1,3 +1,4 @@
+use dioxus_core::Template;
 use freya_engine::prelude::{Canvas, FontCollection};
 use freya_native_core::NodeId;
 use torin::torin::Torin;
@@ -16,6 +17,11 @@ impl PluginsManager {
         self.plugins.push(Box::new(plugin))
     }
 
+    /// Check if there are no plugins.
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
     pub fn send(&mut self, event: PluginEvent) {
         for plugin in &mut self.plugins {
             plugin.on_event(&event)
@@ -51,6 +57,9 @@ pub enum PluginEvent<'a> {
     StartedUpdatingDOM,
 
     FinishedUpdatingDOM,
+
+    /// A template was hot reloaded, only the components using it will be rerendered.
+    TemplateReplaced(&'a Template),
 }
 
 /// Skeleton for Freya plugins.
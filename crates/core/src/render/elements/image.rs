@@ -1,6 +1,6 @@
 use freya_native_core::real_dom::NodeImmutable;
 
-use freya_core::dom::DioxusNode;
+use crate::dom::DioxusNode;
 use freya_engine::prelude::*;
 use freya_node_state::{References, Style};
 use torin::geometry::Area;
@@ -14,7 +14,7 @@ pub fn render_image(area: &Area, node_ref: &DioxusNode, canvas: &Canvas) {
         let pic = Image::from_encoded(Data::new_copy(bytes));
         if let Some(pic) = pic {
             let mut paint = Paint::default();
-            paint.set_anti_alias(true);
+            paint.set_anti_alias(node_style.antialias);
             canvas.draw_image_nine(
                 pic,
                 IRect::new(0, 0, 0, 0),
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use crate::dom::DioxusNode;
+use freya_common::CachedParagraph;
+use freya_engine::prelude::*;
+use freya_native_core::{prelude::SendAnyMap, real_dom::NodeImmutable};
+use freya_node_state::{Fill, FontStyleState, Style, TextCentering, TextFill};
+use torin::geometry::Area;
+
+/// Render a `label` element
+pub fn render_label(
+    area: &Area,
+    data: &Option<Arc<SendAnyMap>>,
+    dioxus_node: &DioxusNode,
+    canvas: &Canvas,
+) {
+    let paragraph = &data.as_ref().unwrap().get::<CachedParagraph>().unwrap().0;
+    let font_style = &*dioxus_node.get::<FontStyleState>().unwrap();
+    let node_style = &*dioxus_node.get::<Style>().unwrap();
+
+    let (x, y) = text_origin(area, font_style);
+    let y = y + text_centering_offset(paragraph, font_style);
+
+    paint_paragraph(paragraph, (x, y), area, node_style, canvas);
+}
+
+/// Get how much a single line text must be moved down so the part chosen
+/// with `text_centering` is centered in its line box, instead of the whole box.
+fn text_centering_offset(paragraph: &Paragraph, font_style: &FontStyleState) -> f32 {
+    if font_style.text_centering == TextCentering::Box || paragraph.line_number() != 1 {
+        return 0.0;
+    }
+
+    let (_, metrics) = paragraph.get_font_at(0).metrics();
+    let height = match font_style.text_centering {
+        TextCentering::CapHeight => metrics.cap_height,
+        TextCentering::XHeight => metrics.x_height,
+        TextCentering::Box => unreachable!(),
+    };
+
+    // The part goes from the baseline up to its height
+    let part_center = paragraph.alphabetic_baseline() - height / 2.0;
+    let offset = paragraph.height() / 2.0 - part_center;
+
+    if font_style.text_subpixel {
+        offset
+    } else {
+        offset.round()
+    }
+}
+
+/// Paint the text of an element, with its glyphs filled with the `text_fill` of the element if it has one.
+pub fn paint_paragraph(
+    paragraph: &Paragraph,
+    origin: (f32, f32),
+    area: &Area,
+    node_style: &Style,
+    canvas: &Canvas,
+) {
+    let Some(text_fill) = &node_style.text_fill else {
+        paragraph.paint(canvas, origin);
+        return;
+    };
+
+    // Paint the text in its own layer, and then replace its colors with the fill, keeping its alpha
+    canvas.save_layer(&SaveLayerRec::default());
+    paragraph.paint(canvas, origin);
+
+    let rect = Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+    let mut paint = Paint::default();
+    paint.set_anti_alias(node_style.antialias);
+    paint.set_blend_mode(BlendMode::SrcIn);
+
+    match text_fill {
+        TextFill::Fill(Fill::Color(color)) => {
+            paint.set_color(*color);
+            canvas.draw_rect(rect, &paint);
+        }
+        TextFill::Fill(Fill::LinearGradient(gradient)) => {
+            paint.set_shader(gradient.into_shader(*area));
+            canvas.draw_rect(rect, &paint);
+        }
+        TextFill::Image(bytes) => {
+            if let Some(image) = Image::from_encoded(Data::new_copy(bytes.as_slice())) {
+                canvas.draw_image_rect(image, None, rect, &paint);
+            }
+        }
+    }
+
+    canvas.restore();
+}
+
+/// Get where the text of an element must be painted,
+/// snapped to the pixel grid if the element disables `text_subpixel`.
+pub fn text_origin(area: &Area, font_style: &FontStyleState) -> (f32, f32) {
+    if font_style.text_subpixel {
+        (area.min_x(), area.min_y())
+    } else {
+        (area.min_x().round(), area.min_y().round())
+    }
+}
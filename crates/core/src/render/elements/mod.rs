@@ -2,10 +2,12 @@ mod image;
 mod label;
 mod paragraph;
 mod rect;
+mod shader;
 mod svg;
 
 pub use image::*;
 pub use label::*;
 pub use paragraph::*;
 pub use rect::*;
+pub use shader::*;
 pub use svg::*;
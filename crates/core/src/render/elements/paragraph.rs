@@ -1,13 +1,15 @@
 use std::sync::Arc;
 
+use crate::{dom::DioxusNode, layout::create_paragraph};
 use freya_common::CachedParagraph;
-use freya_core::{dom::DioxusNode, layout::create_paragraph};
 use freya_native_core::{real_dom::NodeImmutable, SendAnyMap};
 
 use freya_engine::prelude::*;
-use freya_node_state::CursorSettings;
+use freya_node_state::{CursorSettings, FontStyleState, Style};
 use torin::geometry::Area;
 
+use super::{paint_paragraph, text_origin};
+
 /// Render a `paragraph` element
 pub fn render_paragraph(
     area: &Area,
@@ -17,17 +19,19 @@ pub fn render_paragraph(
     font_collection: &mut FontCollection,
     default_fonts: &[String],
 ) {
-    let (x, y) = area.origin.to_tuple();
+    let (x, y) = text_origin(area, &dioxus_node.get::<FontStyleState>().unwrap());
     let node_cursor_settings = &*dioxus_node.get::<CursorSettings>().unwrap();
+    let node_style = &*dioxus_node.get::<Style>().unwrap();
 
     let paint = |paragraph: &Paragraph| {
-        // Draw the highlights if specified
+        // Draw the matches and then the selection highlights if specified
+        draw_match_highlights(area, paragraph, canvas, node_cursor_settings);
         draw_cursor_highlights(area, paragraph, canvas, dioxus_node);
 
         // Draw a cursor if specified
         draw_cursor(area, paragraph, canvas, dioxus_node);
 
-        paragraph.paint(canvas, (x, y));
+        paint_paragraph(paragraph, (x, y), area, node_style, canvas);
     };
 
     if node_cursor_settings.position.is_some() {
@@ -56,38 +60,60 @@ fn draw_cursor_highlights(
     let highlights = node_cursor_settings.highlights.as_ref()?;
     let highlight_color = node_cursor_settings.highlight_color;
 
-    for (from, to) in highlights.iter() {
-        let (from, to) = {
-            if from < to {
-                (from, to)
-            } else {
-                (to, from)
-            }
-        };
-        let cursor_rects = paragraph.get_rects_for_range(
-            *from..*to,
-            RectHeightStyle::Tight,
-            RectWidthStyle::Tight,
-        );
-        for cursor_rect in cursor_rects {
-            let x = area.min_x() + cursor_rect.rect.left;
-            let y = area.min_y() + cursor_rect.rect.top;
+    for highlight in highlights.iter() {
+        draw_highlight(area, paragraph, canvas, *highlight, highlight_color);
+    }
 
-            let x2 = x + (cursor_rect.rect.right - cursor_rect.rect.left);
-            let y2 = y + (cursor_rect.rect.bottom - cursor_rect.rect.top);
+    Some(())
+}
 
-            let mut paint = Paint::default();
-            paint.set_anti_alias(true);
-            paint.set_style(PaintStyle::Fill);
-            paint.set_color(highlight_color);
+fn draw_match_highlights(
+    area: &Area,
+    paragraph: &Paragraph,
+    canvas: &Canvas,
+    node_cursor_settings: &CursorSettings,
+) -> Option<()> {
+    let match_highlights = node_cursor_settings.match_highlights.as_ref()?;
 
-            canvas.draw_rect(Rect::new(x, y, x2, y2), &paint);
-        }
+    for (i, highlight) in match_highlights.iter().enumerate() {
+        let color = if node_cursor_settings.current_match == Some(i) {
+            node_cursor_settings.current_match_color
+        } else {
+            node_cursor_settings.match_highlight_color
+        };
+        draw_highlight(area, paragraph, canvas, *highlight, color);
     }
 
     Some(())
 }
 
+/// Fill the area behind the glyphs of the given range of text.
+fn draw_highlight(
+    area: &Area,
+    paragraph: &Paragraph,
+    canvas: &Canvas,
+    (from, to): (usize, usize),
+    color: Color,
+) {
+    let (from, to) = if from < to { (from, to) } else { (to, from) };
+    let cursor_rects =
+        paragraph.get_rects_for_range(from..to, RectHeightStyle::Tight, RectWidthStyle::Tight);
+    for cursor_rect in cursor_rects {
+        let x = area.min_x() + cursor_rect.rect.left;
+        let y = area.min_y() + cursor_rect.rect.top;
+
+        let x2 = x + (cursor_rect.rect.right - cursor_rect.rect.left);
+        let y2 = y + (cursor_rect.rect.bottom - cursor_rect.rect.top);
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Fill);
+        paint.set_color(color);
+
+        canvas.draw_rect(Rect::new(x, y, x2, y2), &paint);
+    }
+}
+
 fn draw_cursor(
     area: &Area,
     paragraph: &Paragraph,
@@ -0,0 +1,439 @@
+use freya_native_core::real_dom::NodeImmutable;
+
+use crate::dom::DioxusNode;
+use freya_engine::prelude::*;
+use freya_node_state::{
+    BackgroundClip, BorderAlignment, BorderStyle, Fill, References, ShaderPosition, ShadowPosition,
+    Style,
+};
+use torin::prelude::{Area, Gaps};
+
+use super::render_shader;
+
+/// Render a `rect` element, the shadows follow its `clip_path` if it has one.
+pub fn render_rect(
+    area: &Area,
+    padding: &Gaps,
+    node_ref: &DioxusNode,
+    canvas: &Canvas,
+    font_collection: &mut FontCollection,
+    clip_path: Option<&Path>,
+) {
+    let node_style = &*node_ref.get::<Style>().unwrap();
+
+    let mut paint = Paint::default();
+    let mut path = Path::new();
+    let area = area.to_f32();
+
+    paint.set_anti_alias(node_style.antialias);
+    paint.set_style(PaintStyle::Fill);
+
+    let area = area.to_f32();
+
+    let radius = node_style.corner_radius;
+    let rounded_rect = RRect::new_rect_radii(
+        Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+        &[
+            (radius.top_left, radius.top_left).into(),
+            (radius.top_right, radius.top_right).into(),
+            (radius.bottom_right, radius.bottom_right).into(),
+            (radius.bottom_left, radius.bottom_left).into(),
+        ],
+    );
+
+    if node_style.corner_radius.smoothing > 0.0 {
+        path.add_path(
+            &node_style.corner_radius.smoothed_path(rounded_rect),
+            (area.min_x(), area.min_y()),
+            None,
+        );
+    } else {
+        path.add_rrect(rounded_rect, None);
+    }
+
+    // Backdrop blur
+    if node_style.backdrop_blur > 0.0 {
+        let bounds = Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+        let sigma = node_style.backdrop_blur / 2.0;
+        if let Some(blur) = image_filters::blur((sigma, sigma), TileMode::Clamp, None, bounds) {
+            // Only blur what is inside the shape of the element
+            canvas.save();
+            canvas.clip_path(&path, ClipOp::Intersect, node_style.antialias);
+            canvas.save_layer(&SaveLayerRec::default().bounds(&bounds).backdrop(&blur));
+            canvas.restore();
+            canvas.restore();
+        }
+    }
+
+    // Background
+    let background_insets = background_insets(node_style, padding);
+    let background_area = Area::new(
+        (
+            area.min_x() + background_insets.left(),
+            area.min_y() + background_insets.top(),
+        )
+            .into(),
+        (
+            (area.width() - background_insets.horizontal()).max(0.0),
+            (area.height() - background_insets.vertical()).max(0.0),
+        )
+            .into(),
+    );
+
+    match &node_style.background {
+        Fill::Color(color) => {
+            paint.set_color(*color);
+        }
+        Fill::LinearGradient(gradient) => {
+            paint.set_shader(gradient.into_shader(background_area));
+        }
+    }
+
+    if background_area == area {
+        canvas.draw_path(&path, &paint);
+    } else {
+        // The corners of the inner boxes are rounded by what remains of the radius after the insets
+        let background_rect = RRect::new_rect_radii(
+            Rect::new(
+                background_area.min_x(),
+                background_area.min_y(),
+                background_area.max_x(),
+                background_area.max_y(),
+            ),
+            &[
+                (
+                    (radius.top_left - background_insets.left()).max(0.0),
+                    (radius.top_left - background_insets.top()).max(0.0),
+                )
+                    .into(),
+                (
+                    (radius.top_right - background_insets.right()).max(0.0),
+                    (radius.top_right - background_insets.top()).max(0.0),
+                )
+                    .into(),
+                (
+                    (radius.bottom_right - background_insets.right()).max(0.0),
+                    (radius.bottom_right - background_insets.bottom()).max(0.0),
+                )
+                    .into(),
+                (
+                    (radius.bottom_left - background_insets.left()).max(0.0),
+                    (radius.bottom_left - background_insets.bottom()).max(0.0),
+                )
+                    .into(),
+            ],
+        );
+
+        let mut background_path = Path::new();
+        if node_style.corner_radius.smoothing > 0.0 {
+            background_path.add_path(
+                &node_style.corner_radius.smoothed_path(background_rect),
+                (background_area.min_x(), background_area.min_y()),
+                None,
+            );
+        } else {
+            background_path.add_rrect(background_rect, None);
+        }
+        canvas.draw_path(&background_path, &paint);
+    }
+
+    if let Some(shader) = &node_style.shader {
+        if node_style.shader_position == ShaderPosition::Background {
+            render_shader(&area, node_style, shader, canvas);
+        }
+    }
+
+    // Shadows
+    if let Some(clip_path) = clip_path {
+        // The outer ones are painted before clipping the element, see `render_clip_path_shadows`
+        render_clip_path_shadows(canvas, &area, node_style, clip_path, ShadowPosition::Inset);
+    }
+    for shadow in node_style.shadows.iter() {
+        if shadow.fill != Fill::Color(Color::TRANSPARENT) && clip_path.is_none() {
+            let mut shadow_paint = paint.clone();
+            let mut shadow_path = Path::new();
+
+            match &shadow.fill {
+                Fill::Color(color) => {
+                    shadow_paint.set_color(*color);
+                }
+                Fill::LinearGradient(gradient) => {
+                    shadow_paint.set_shader(gradient.into_shader(area));
+                }
+            }
+
+            // Shadows can be either outset or inset
+            // If they are outset, we fill a copy of the path outset by spread_radius, and blur it.
+            // Otherwise, we draw a stroke with the inner portion being spread_radius width, and the outer portion being blur_radius width.
+            let outset: Point = match shadow.position {
+                ShadowPosition::Normal => {
+                    shadow_paint.set_style(PaintStyle::Fill);
+                    (shadow.spread, shadow.spread).into()
+                }
+                ShadowPosition::Inset => {
+                    shadow_paint.set_style(PaintStyle::Stroke);
+                    shadow_paint.set_stroke_width(shadow.blur / 2.0 + shadow.spread);
+                    (-shadow.spread / 2.0, -shadow.spread / 2.0).into()
+                }
+            };
+
+            // Apply gassuan blur to the copied path.
+            if shadow.blur > 0.0 {
+                shadow_paint.set_mask_filter(MaskFilter::blur(
+                    BlurStyle::Normal,
+                    shadow.blur / 2.0,
+                    false,
+                ));
+            }
+
+            // Add either the RRect or smoothed path based on whether smoothing is used.
+            if node_style.corner_radius.smoothing > 0.0 {
+                shadow_path.add_path(
+                    &node_style
+                        .corner_radius
+                        .smoothed_path(rounded_rect.with_outset(outset)),
+                    Point::new(area.min_x(), area.min_y()) - outset,
+                    None,
+                );
+            } else {
+                shadow_path.add_rrect(rounded_rect.with_outset(outset), None);
+            }
+
+            // Offset our path by the shadow's x and y coordinates.
+            shadow_path.offset((shadow.x, shadow.y));
+
+            // Exclude the original path bounds from the shadow using a clip, then draw the shadow.
+            canvas.save();
+            canvas.clip_path(
+                &path,
+                match shadow.position {
+                    ShadowPosition::Normal => ClipOp::Difference,
+                    ShadowPosition::Inset => ClipOp::Intersect,
+                },
+                node_style.antialias,
+            );
+            canvas.draw_path(&shadow_path, &shadow_paint);
+            canvas.restore();
+        }
+    }
+
+    // Borders
+    if node_style.border.width > 0.0 && node_style.border.style != BorderStyle::None {
+        // Create a new paint and path
+        let mut border_paint = paint.clone();
+        let mut border_path = Path::new();
+
+        // Setup paint params
+        border_paint.set_anti_alias(node_style.antialias);
+        border_paint.set_style(PaintStyle::Stroke);
+        match &node_style.border.fill {
+            Fill::Color(color) => {
+                border_paint.set_color(*color);
+            }
+            Fill::LinearGradient(gradient) => {
+                border_paint.set_shader(gradient.into_shader(area));
+            }
+        }
+        border_paint.set_stroke_width(node_style.border.width);
+
+        // Skia draws strokes centered on the edge of the path. This means that half of the stroke is inside the path, and half outside.
+        // For Inner and Outer borders, we need to grow or shrink the stroke path by half the border width.
+        let outset = Point::new(node_style.border.width / 2.0, node_style.border.width / 2.0)
+            * match node_style.border.alignment {
+                BorderAlignment::Center => 0.0,
+                BorderAlignment::Inner => -1.0,
+                BorderAlignment::Outer => 1.0,
+            };
+
+        // Add either the RRect or smoothed path based on whether smoothing is used.
+        if node_style.corner_radius.smoothing > 0.0 {
+            border_path.add_path(
+                &node_style
+                    .corner_radius
+                    .smoothed_path(rounded_rect.with_outset(outset)),
+                Point::new(area.min_x(), area.min_y()) - outset,
+                None,
+            );
+        } else {
+            border_path.add_rrect(rounded_rect.with_outset(outset), None);
+        }
+
+        canvas.draw_path(&border_path, &border_paint);
+    }
+
+    // Outline
+    if node_style.outline.width > 0.0 && node_style.outline.style != BorderStyle::None {
+        let mut outline_paint = paint.clone();
+        let mut outline_path = Path::new();
+
+        outline_paint.set_shader(None);
+        outline_paint.set_style(PaintStyle::Stroke);
+        match &node_style.outline.fill {
+            Fill::Color(color) => {
+                outline_paint.set_color(*color);
+            }
+            Fill::LinearGradient(gradient) => {
+                outline_paint.set_shader(gradient.into_shader(area));
+            }
+        }
+        outline_paint.set_stroke_width(node_style.outline.width);
+
+        // The stroke is centered on the path, so it's grown by half the width to be drawn fully outside
+        let outset = Point::new(
+            node_style.outline_offset + node_style.outline.width / 2.0,
+            node_style.outline_offset + node_style.outline.width / 2.0,
+        );
+
+        if node_style.corner_radius.smoothing > 0.0 {
+            outline_path.add_path(
+                &node_style
+                    .corner_radius
+                    .smoothed_path(rounded_rect.with_outset(outset)),
+                Point::new(area.min_x(), area.min_y()) - outset,
+                None,
+            );
+        } else {
+            outline_path.add_rrect(rounded_rect.with_outset(outset), None);
+        }
+
+        canvas.draw_path(&outline_path, &outline_paint);
+    }
+
+    let references = node_ref.get::<References>().unwrap();
+
+    if let Some(canvas_ref) = &references.canvas_ref {
+        if let Some(cache) = &canvas_ref.cache {
+            // Record the output of the canvas once and replay it until it changes or gets resized
+            let picture = cache.get(&area).or_else(|| {
+                let mut recorder = PictureRecorder::new();
+                let recording_canvas = recorder.begin_recording(
+                    Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                    None,
+                );
+                (canvas_ref.runner)(recording_canvas, font_collection, area);
+                let picture = recorder.finish_recording_as_picture(None)?;
+                cache.set(area, picture.clone());
+                Some(picture)
+            });
+
+            if let Some(picture) = picture {
+                canvas.draw_picture(&picture, None, None);
+            }
+        } else {
+            (canvas_ref.runner)(canvas, font_collection, area);
+        }
+    }
+
+    if let Some(gpu_canvas_ref) = &references.gpu_canvas_ref {
+        // Surfaces that aren't backed by the GPU have no context to draw with
+        if let Some(mut direct_context) = canvas.direct_context() {
+            canvas.save();
+            canvas.clip_rect(
+                Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                ClipOp::Intersect,
+                node_style.antialias,
+            );
+
+            // Everything below the element must reach the GPU before the content drawn on top of it
+            direct_context.flush_and_submit();
+            (gpu_canvas_ref.runner)(canvas, &mut direct_context, area);
+            // The state of the GPU might have been changed behind the back of Skia
+            direct_context.reset(None);
+
+            canvas.restore();
+        }
+    }
+
+    if let Some(custom_element) = &references.custom_element {
+        custom_element.element.paint(canvas, font_collection, area);
+    }
+}
+
+/// Render the shadows of an element with the given position along the shape of its `clip_path`.
+///
+/// The outer shadows must be painted before clipping the element to its shape, or they would be clipped too.
+pub fn render_clip_path_shadows(
+    canvas: &Canvas,
+    area: &Area,
+    node_style: &Style,
+    clip_path: &Path,
+    position: ShadowPosition,
+) {
+    for shadow in node_style.shadows.iter() {
+        if shadow.position != position || shadow.fill == Fill::Color(Color::TRANSPARENT) {
+            continue;
+        }
+
+        let mut shadow_paint = Paint::default();
+        shadow_paint.set_anti_alias(node_style.antialias);
+        match &shadow.fill {
+            Fill::Color(color) => {
+                shadow_paint.set_color(*color);
+            }
+            Fill::LinearGradient(gradient) => {
+                shadow_paint.set_shader(gradient.into_shader(*area));
+            }
+        }
+
+        // Unlike the rects, paths can't be outset, so they are grown by stroking them.
+        // A stroke is centered on the path, so the inset shadows are as wide as the ones of the rects.
+        match shadow.position {
+            ShadowPosition::Normal if shadow.spread > 0.0 => {
+                shadow_paint.set_style(PaintStyle::StrokeAndFill);
+                shadow_paint.set_stroke_width(shadow.spread * 2.0);
+            }
+            ShadowPosition::Normal => {
+                shadow_paint.set_style(PaintStyle::Fill);
+            }
+            ShadowPosition::Inset => {
+                shadow_paint.set_style(PaintStyle::Stroke);
+                shadow_paint.set_stroke_width(shadow.spread * 2.0 + shadow.blur / 2.0);
+            }
+        }
+
+        if shadow.blur > 0.0 {
+            shadow_paint.set_mask_filter(MaskFilter::blur(
+                BlurStyle::Normal,
+                shadow.blur / 2.0,
+                false,
+            ));
+        }
+
+        let mut shadow_path = Path::new();
+        shadow_path.add_path(clip_path, (shadow.x, shadow.y), None);
+
+        canvas.save();
+        canvas.clip_path(
+            clip_path,
+            match shadow.position {
+                ShadowPosition::Normal => ClipOp::Difference,
+                ShadowPosition::Inset => ClipOp::Intersect,
+            },
+            node_style.antialias,
+        );
+        canvas.draw_path(&shadow_path, &shadow_paint);
+        canvas.restore();
+    }
+}
+
+/// Get how much the background of an element is inset from its area by the `background_clip` attribute.
+fn background_insets(node_style: &Style, padding: &Gaps) -> Gaps {
+    match node_style.background_clip {
+        BackgroundClip::BorderBox => Gaps::default(),
+        BackgroundClip::PaddingBox => {
+            if node_style.border.style == BorderStyle::None {
+                return Gaps::default();
+            }
+            let inner_width = match node_style.border.alignment {
+                BorderAlignment::Inner => node_style.border.width,
+                BorderAlignment::Center => node_style.border.width / 2.0,
+                BorderAlignment::Outer => 0.0,
+            };
+            let mut insets = Gaps::default();
+            insets.fill_all(inner_width);
+            insets
+        }
+        // Freya lays out the padding from the edges of the elements, regardless of their borders
+        BackgroundClip::ContentBox => *padding,
+    }
+}
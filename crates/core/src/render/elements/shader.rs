@@ -0,0 +1,49 @@
+use freya_engine::prelude::*;
+use freya_node_state::{ShaderReference, Style};
+use torin::prelude::Area;
+use tracing::warn;
+
+/// Paint the area of an element with its shader
+pub fn render_shader(area: &Area, node_style: &Style, shader: &ShaderReference, canvas: &Canvas) {
+    // Make the shader coordinates relative to the element
+    let local_matrix = Matrix::translate((area.min_x(), area.min_y()));
+
+    let Some(shader) =
+        shader
+            .effect
+            .0
+            .make_shader(Data::new_copy(&shader.uniforms), &[], Some(&local_matrix))
+    else {
+        warn!("Failed to make the shader, its uniforms don't match the declared ones.");
+        return;
+    };
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(node_style.antialias);
+    paint.set_style(PaintStyle::Fill);
+    paint.set_shader(shader);
+
+    let radius = node_style.corner_radius;
+    let rounded_rect = RRect::new_rect_radii(
+        Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+        &[
+            (radius.top_left, radius.top_left).into(),
+            (radius.top_right, radius.top_right).into(),
+            (radius.bottom_right, radius.bottom_right).into(),
+            (radius.bottom_left, radius.bottom_left).into(),
+        ],
+    );
+
+    let mut path = Path::new();
+    if radius.smoothing > 0.0 {
+        path.add_path(
+            &radius.smoothed_path(rounded_rect),
+            (area.min_x(), area.min_y()),
+            None,
+        );
+    } else {
+        path.add_rrect(rounded_rect, None);
+    }
+
+    canvas.draw_path(&path, &paint);
+}
@@ -1,6 +1,6 @@
 use freya_native_core::real_dom::NodeImmutable;
 
-use freya_core::dom::DioxusNode;
+use crate::dom::DioxusNode;
 use freya_engine::prelude::*;
 use freya_node_state::Style;
 use torin::geometry::Area;
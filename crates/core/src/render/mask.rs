@@ -0,0 +1,321 @@
+use crate::dom::FreyaDOM;
+use freya_engine::prelude::*;
+use freya_native_core::{real_dom::NodeImmutable, tree::TreeRef, NodeId};
+use freya_node_state::{Fill, Filter, LayoutState, Mask, Style, Transform};
+use rustc_hash::{FxHashMap, FxHashSet};
+use torin::prelude::{LayoutNode, Torin};
+
+use super::subtree_cache::get_subtree_nodes;
+
+/// Subtrees of the elements with a mask, a scroll fade or a filter. These are rendered together into a layer,
+/// which is then filtered and masked with the alpha of the element mask and its scroll fade.
+#[derive(Default)]
+pub struct MaskedSubtrees {
+    /// Nodes of every masked subtree, by their root.
+    /// Nested masked subtrees only appear by their root, as they are rendered on their own.
+    subtrees: FxHashMap<NodeId, Vec<NodeId>>,
+    /// Descendants of all the masked or filtered subtrees.
+    masked_nodes: FxHashSet<NodeId>,
+}
+
+impl MaskedSubtrees {
+    /// Find all the masked subtrees.
+    pub fn new(fdom: &FreyaDOM) -> Self {
+        let rdom = fdom.rdom();
+        let layout = fdom.layout();
+
+        let mut subtrees = FxHashMap::default();
+        let mut masked_nodes = FxHashSet::default();
+
+        for node_id in fdom.layers().layers().values().flatten() {
+            let node = rdom.get(*node_id).unwrap();
+            if !is_layered(&node.get::<Style>().unwrap()) {
+                continue;
+            }
+
+            let mut descendants = FxHashSet::default();
+            let mut pending = node.child_ids();
+            while let Some(node_id) = pending.pop() {
+                let node = rdom.get(node_id).unwrap();
+                if !is_layered(&node.get::<Style>().unwrap()) {
+                    pending.extend(node.child_ids());
+                }
+                descendants.insert(node_id);
+            }
+
+            let nodes = get_subtree_nodes(fdom, &layout, *node_id, &descendants);
+            masked_nodes.extend(descendants);
+            subtrees.insert(*node_id, nodes);
+        }
+
+        Self {
+            subtrees,
+            masked_nodes,
+        }
+    }
+
+    /// Check if the given node is rendered along with the masked or filtered subtree of one of its ancestors.
+    pub fn is_masked(&self, node_id: &NodeId) -> bool {
+        self.masked_nodes.contains(node_id)
+    }
+
+    /// Render the masked or filtered subtree of the given node, using `render_node` to render each one of its nodes.
+    ///
+    /// Returns `false` if the node has no mask nor filter, so it must be rendered as usual.
+    pub fn render(
+        &self,
+        canvas: &Canvas,
+        node_id: &NodeId,
+        fdom: &FreyaDOM,
+        layout: &Torin<NodeId>,
+        render_node: &mut impl FnMut(&NodeId),
+    ) -> bool {
+        let Some(nodes) = self.subtrees.get(node_id) else {
+            return false;
+        };
+        let Some((node, layout_node)) = fdom.rdom().get(*node_id).zip(layout.get(*node_id)) else {
+            return false;
+        };
+        let node_style = node.get::<Style>().unwrap();
+        if !is_layered(&node_style) {
+            return false;
+        }
+
+        let area = layout_node.visible_area();
+        let rect = Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+        let matrix = get_inherited_matrix(fdom, layout, *node_id);
+
+        canvas.save();
+
+        let is_masked = node_style.mask.is_some() || node_style.scroll_fade > 0.0;
+
+        // Hide anything painted outside of the element, filters like shadows can paint outside of it though
+        if is_masked {
+            let mut clip_path = Path::new();
+            clip_path.add_rect(rect, None);
+            clip_path.transform(&matrix);
+            canvas.clip_path(&clip_path, ClipOp::Intersect, node_style.antialias);
+
+            canvas.save_layer_alpha_f(None, 1.0);
+        }
+
+        // The content is filtered before being masked
+        let image_filter = get_image_filter(&node_style.filters.0);
+        if let Some(image_filter) = &image_filter {
+            let mut paint = Paint::default();
+            paint.set_image_filter(image_filter.clone());
+            canvas.save_layer(&SaveLayerRec::default().paint(&paint));
+        }
+
+        for id in nodes {
+            if id != node_id && self.render(canvas, id, fdom, layout, render_node) {
+                continue;
+            }
+            render_node(id);
+        }
+
+        if image_filter.is_some() {
+            canvas.restore();
+        }
+
+        if !is_masked {
+            canvas.restore();
+            return true;
+        }
+
+        // Only keep the content where the mask is opaque
+        canvas.save();
+        canvas.concat(&matrix);
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_blend_mode(BlendMode::DstIn);
+
+        match &node_style.mask {
+            Some(Mask::Fill(Fill::Color(color))) => {
+                let mut paint = paint.clone();
+                paint.set_color(*color);
+                canvas.draw_rect(rect, &paint);
+            }
+            Some(Mask::Fill(Fill::LinearGradient(gradient))) => {
+                let mut paint = paint.clone();
+                paint.set_shader(gradient.into_shader(area));
+                canvas.draw_rect(rect, &paint);
+            }
+            Some(Mask::Image(bytes)) => {
+                if let Some(image) = Image::from_encoded(Data::new_copy(bytes.as_slice())) {
+                    canvas.draw_image_rect(image, None, rect, &paint);
+                }
+            }
+            None => {}
+        }
+
+        // Each fade multiplies the alpha of the content again, so both axes can be faded at once
+        if node_style.scroll_fade > 0.0 {
+            let layout_state = node.get::<LayoutState>().unwrap();
+            let offsets = (layout_state.offset_x.get(), layout_state.offset_y.get());
+            for shader in get_scroll_fade_shaders(layout_node, offsets, node_style.scroll_fade) {
+                let mut paint = paint.clone();
+                paint.set_shader(shader);
+                canvas.draw_rect(rect, &paint);
+            }
+        }
+
+        canvas.restore();
+        canvas.restore();
+        canvas.restore();
+
+        true
+    }
+}
+
+/// Check if the subtree of an element must be rendered into its own layer.
+fn is_layered(style: &Style) -> bool {
+    style.mask.is_some() || style.scroll_fade > 0.0 || !style.filters.0.is_empty()
+}
+
+/// Alpha gradients that fade the edges of an element where its scrolled content is clipped.
+///
+/// Each edge fades as much as the content is clipped by it, up to `fade`, so the fades disappear
+/// once the content is scrolled to that edge.
+fn get_scroll_fade_shaders(
+    layout_node: &LayoutNode,
+    (offset_x, offset_y): (f32, f32),
+    fade: f32,
+) -> Vec<Shader> {
+    let area = layout_node.visible_area();
+    let inner_area = layout_node.inner_area;
+    let content_x = inner_area.min_x() + offset_x;
+    let content_y = inner_area.min_y() + offset_y;
+
+    let horizontal = get_edge_fade_shader(
+        (area.min_x(), area.max_x()),
+        (
+            area.min_x() - content_x,
+            content_x + layout_node.inner_sizes.width - area.max_x(),
+        ),
+        fade,
+        |x| Point::new(x, area.min_y()),
+    );
+    let vertical = get_edge_fade_shader(
+        (area.min_y(), area.max_y()),
+        (
+            area.min_y() - content_y,
+            content_y + layout_node.inner_sizes.height - area.max_y(),
+        ),
+        fade,
+        |y| Point::new(area.min_x(), y),
+    );
+
+    horizontal.into_iter().chain(vertical).collect()
+}
+
+/// Alpha gradient along one axis, from `start` to `end`, for the given content clipped by each edge.
+fn get_edge_fade_shader(
+    (start, end): (f32, f32),
+    (clipped_start, clipped_end): (f32, f32),
+    fade: f32,
+    point: impl Fn(f32) -> Point,
+) -> Option<Shader> {
+    let length = end - start;
+    let fade = fade.min(length / 2.0);
+    if fade <= 0.0 || (clipped_start <= 0.0 && clipped_end <= 0.0) {
+        return None;
+    }
+
+    let edge_color = |clipped: f32| {
+        let alpha = 1.0 - (clipped / fade).clamp(0.0, 1.0);
+        Color::from_argb((alpha * 255.0).round() as u8, 255, 255, 255)
+    };
+    let colors = [
+        edge_color(clipped_start),
+        Color::WHITE,
+        Color::WHITE,
+        edge_color(clipped_end),
+    ];
+    let positions = [0.0, fade / length, 1.0 - fade / length, 1.0];
+
+    Shader::linear_gradient(
+        (point(start), point(end)),
+        GradientShaderColors::Colors(&colors),
+        Some(&positions[..]),
+        TileMode::Clamp,
+        None,
+        None,
+    )
+}
+
+/// Chain the filters into a single image filter, each one is applied to the output of the previous one.
+fn get_image_filter(filters: &[Filter]) -> Option<ImageFilter> {
+    filters
+        .iter()
+        .fold(None, |input: Option<ImageFilter>, filter| {
+            let crop_rect = image_filters::CropRect::NO_CROP_RECT;
+            let image_filter = if let Some(matrix) = filter.color_matrix() {
+                image_filters::color_filter(
+                    color_filters::matrix_row_major(&matrix),
+                    input.clone(),
+                    crop_rect,
+                )
+            } else {
+                match filter {
+                    Filter::Blur(blur) => {
+                        let sigma = blur / 2.0;
+                        image_filters::blur(
+                            (sigma, sigma),
+                            TileMode::Decal,
+                            input.clone(),
+                            crop_rect,
+                        )
+                    }
+                    Filter::DropShadow { x, y, blur, color } => {
+                        let sigma = blur / 2.0;
+                        image_filters::drop_shadow(
+                            (*x, *y),
+                            (sigma, sigma),
+                            *color,
+                            input.clone(),
+                            crop_rect,
+                        )
+                    }
+                    _ => None,
+                }
+            };
+            // Skip the filters that couldn't be created
+            image_filter.or(input)
+        })
+}
+
+/// Get the rotations applied to a node, including those of its ancestors.
+fn get_inherited_matrix(fdom: &FreyaDOM, layout: &Torin<NodeId>, node_id: NodeId) -> Matrix {
+    let rdom = fdom.rdom();
+
+    let mut ancestors = vec![node_id];
+    while let Some(parent_id) = rdom.tree_ref().parent_id(*ancestors.last().unwrap()) {
+        ancestors.push(parent_id);
+    }
+
+    let mut matrix = Matrix::new_identity();
+    for node_id in ancestors.iter().rev() {
+        let node = rdom.get(*node_id).unwrap();
+        let Some(rotate_degs) = node.get::<Transform>().unwrap().rotate_degs else {
+            continue;
+        };
+        let Some(layout_node) = layout.get(*node_id) else {
+            continue;
+        };
+
+        let area = layout_node.visible_area();
+        let mut rotation = Matrix::new_identity();
+        rotation.set_rotate(
+            rotate_degs,
+            Some(Point {
+                x: area.min_x() + area.width() / 2.0,
+                y: area.min_y() + area.height() / 2.0,
+            }),
+        );
+        matrix.pre_concat(&rotation);
+    }
+
+    matrix
+}
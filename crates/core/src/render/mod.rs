@@ -0,0 +1,12 @@
+mod elements;
+mod mask;
+mod process_render;
+mod render_target;
+mod renderer;
+mod subtree_cache;
+mod wireframe;
+
+pub use process_render::*;
+pub use render_target::*;
+pub use subtree_cache::*;
+pub use wireframe::*;
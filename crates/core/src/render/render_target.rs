@@ -0,0 +1,258 @@
+use freya_common::Layer;
+use freya_engine::prelude::*;
+use freya_native_core::{real_dom::NodeImmutable, tree::TreeRef, NodeId};
+use freya_node_state::{LayerState, Style, WillChange};
+use rustc_hash::FxHashSet;
+use torin::{geometry::Area, prelude::Torin};
+
+use super::{
+    mask::MaskedSubtrees,
+    process_render,
+    renderer::{render_foreground_shader, render_skia},
+    subtree_cache::SubtreeCache,
+};
+use crate::{dirty_regions::DirtyRegions, dom::FreyaDOM};
+
+/// Where the layers of the app get rendered: the surface of a Window, or an offscreen surface
+/// used to export it. All of them are rendered with the same traversal, see [`render_layers`].
+pub trait RenderTarget {
+    /// Get the canvas to render into, in physical pixels.
+    fn canvas(&mut self) -> &Canvas;
+}
+
+impl RenderTarget for Surface {
+    fn canvas(&mut self) -> &Canvas {
+        Surface::canvas(self)
+    }
+}
+
+/// What to render of the app into a [`RenderTarget`].
+#[derive(Default)]
+pub struct RenderPass<'a> {
+    /// Only render the nodes that paint over this area, their painted areas are tracked by the [`DirtyRegions`].
+    pub dirty_region: Option<(Area, &'a DirtyRegions)>,
+    /// Paint the cached subtrees from their images, that are rendered again if they changed.
+    pub subtree_cache: Option<&'a mut SubtreeCache>,
+    /// Node to highlight with a wireframe, e.g. the one hovered in the devtools.
+    pub hovered_node: Option<NodeId>,
+    /// Only render this node and its descendants instead of the whole app.
+    pub root: Option<NodeId>,
+}
+
+/// Render the layers of the app from the bottom to the top into the given target,
+/// with the masked subtrees and the foreground shaders of the nodes.
+pub fn render_layers(
+    target: &mut impl RenderTarget,
+    fdom: &FreyaDOM,
+    font_collection: &mut FontCollection,
+    font_mgr: &FontMgr,
+    default_fonts: &[String],
+    pass: RenderPass,
+) {
+    let RenderPass {
+        dirty_region,
+        mut subtree_cache,
+        hovered_node,
+        root,
+    } = pass;
+
+    let canvas = target.canvas();
+    let mut masked_subtrees = MaskedSubtrees::new(fdom);
+    // A subtree masked by one of its ancestors is rendered without the masks, as the ancestor isn't rendered
+    if root.is_some_and(|root_id| masked_subtrees.is_masked(&root_id)) {
+        masked_subtrees = MaskedSubtrees::default();
+    }
+    let subtree = root.map(|root_id| {
+        let rdom = fdom.rdom();
+        let mut nodes = FxHashSet::default();
+        let mut pending = vec![root_id];
+        while let Some(node_id) = pending.pop() {
+            if let Some(node) = rdom.get(node_id) {
+                pending.extend(node.child_ids());
+            }
+            nodes.insert(node_id);
+        }
+        nodes
+    });
+
+    let mut matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
+    let mut opacities: Vec<(f32, Vec<NodeId>)> = Vec::default();
+    // Nodes whose shader is painted over their descendants, from the outermost to the innermost
+    let mut foreground_shaders: Vec<(NodeId, Layer)> = Vec::new();
+
+    process_render(
+        fdom,
+        font_collection,
+        |fdom, node_id, _, font_collection, layout| {
+            // The traversal left the subtree or the layer of the pending shaders, so nothing else goes below them
+            if !foreground_shaders.is_empty() {
+                let rdom = fdom.rdom();
+                let tree = rdom.tree_ref();
+                let node_layer = rdom
+                    .get(*node_id)
+                    .map(|node| node.get::<LayerState>().unwrap().layer.clone());
+                render_foreground_shaders(
+                    canvas,
+                    fdom,
+                    layout,
+                    &matrices,
+                    &opacities,
+                    &mut foreground_shaders,
+                    |shader_id, shader_layer| {
+                        node_layer.as_ref() != Some(shader_layer)
+                            || !is_descendant(&tree, *node_id, shader_id)
+                    },
+                );
+            }
+
+            if let Some(subtree) = &subtree {
+                if !subtree.contains(node_id) {
+                    return;
+                }
+            }
+
+            // These are painted along with their cached or masked subtree root
+            let is_cached = subtree_cache
+                .as_ref()
+                .is_some_and(|subtree_cache| subtree_cache.is_cached(node_id));
+            if is_cached || masked_subtrees.is_masked(node_id) {
+                return;
+            }
+
+            // Skip the nodes outside of the dirty region
+            if let Some((dirty_region, dirty_regions)) = dirty_region {
+                if let Some(painted_area) = dirty_regions.painted_area(node_id) {
+                    if !dirty_region.intersects(painted_area) {
+                        return;
+                    }
+                }
+            }
+
+            let mut render_node = |node_id: &NodeId| {
+                let render_wireframe = hovered_node == Some(*node_id);
+
+                if let Some(subtree_cache) = subtree_cache.as_deref_mut() {
+                    let is_cached =
+                        subtree_cache.render(canvas, node_id, |canvas, nodes, composited| {
+                            let mut matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
+                            let mut opacities: Vec<(f32, Vec<NodeId>)> = Vec::default();
+
+                            for (i, node_id) in nodes.iter().enumerate() {
+                                let Some((dioxus_node, layout_node)) =
+                                    fdom.rdom().get(*node_id).zip(layout.get(*node_id))
+                                else {
+                                    continue;
+                                };
+                                // Only the effects of the root are composited
+                                let composited = if i == 0 {
+                                    composited
+                                } else {
+                                    WillChange::default()
+                                };
+                                render_skia(
+                                    canvas,
+                                    layout_node,
+                                    &dioxus_node,
+                                    font_collection,
+                                    font_mgr,
+                                    false,
+                                    &mut matrices,
+                                    &mut opacities,
+                                    default_fonts,
+                                    layout,
+                                    composited,
+                                );
+                            }
+                        });
+
+                    if is_cached {
+                        return;
+                    }
+                }
+
+                let Some((dioxus_node, layout_node)) =
+                    fdom.rdom().get(*node_id).zip(layout.get(*node_id))
+                else {
+                    return;
+                };
+
+                if dioxus_node.get::<Style>().unwrap().shader.is_some() {
+                    let layer = dioxus_node.get::<LayerState>().unwrap().layer.clone();
+                    foreground_shaders.push((*node_id, layer));
+                }
+
+                render_skia(
+                    canvas,
+                    layout_node,
+                    &dioxus_node,
+                    font_collection,
+                    font_mgr,
+                    render_wireframe,
+                    &mut matrices,
+                    &mut opacities,
+                    default_fonts,
+                    layout,
+                    WillChange::default(),
+                );
+            };
+
+            if !masked_subtrees.render(canvas, node_id, fdom, layout, &mut render_node) {
+                render_node(node_id);
+            }
+        },
+    );
+
+    let layout = fdom.layout();
+    render_foreground_shaders(
+        canvas,
+        fdom,
+        &layout,
+        &matrices,
+        &opacities,
+        &mut foreground_shaders,
+        |_, _| true,
+    );
+}
+
+/// Paint the pending foreground shaders from the innermost one, until `is_finished` tells one still has nodes to go below it.
+fn render_foreground_shaders(
+    canvas: &Canvas,
+    fdom: &FreyaDOM,
+    layout: &Torin<NodeId>,
+    matrices: &[(Matrix, Vec<NodeId>)],
+    opacities: &[(f32, Vec<NodeId>)],
+    foreground_shaders: &mut Vec<(NodeId, Layer)>,
+    mut is_finished: impl FnMut(NodeId, &Layer) -> bool,
+) {
+    while let Some((node_id, layer)) = foreground_shaders.last() {
+        if !is_finished(*node_id, layer) {
+            break;
+        }
+        let node_id = *node_id;
+        foreground_shaders.pop();
+
+        if let Some((dioxus_node, layout_node)) = fdom.rdom().get(node_id).zip(layout.get(node_id))
+        {
+            render_foreground_shader(
+                canvas,
+                layout_node,
+                &dioxus_node,
+                matrices,
+                opacities,
+                layout,
+            );
+        }
+    }
+}
+
+/// Check if a node is inside of the subtree of another one.
+fn is_descendant(tree: &impl TreeRef, node_id: NodeId, ancestor_id: NodeId) -> bool {
+    let mut current_id = Some(node_id);
+    while let Some(id) = current_id {
+        if id == ancestor_id {
+            return true;
+        }
+        current_id = tree.parent_id(id);
+    }
+    false
+}
@@ -0,0 +1,257 @@
+use freya_native_core::prelude::ElementNode;
+use freya_native_core::real_dom::NodeImmutable;
+use freya_native_core::NodeId;
+use freya_native_core::{node::NodeType, tags::TagName};
+
+use crate::dom::DioxusNode;
+use freya_engine::prelude::*;
+use freya_node_state::{
+    ClipPath, ShaderPosition, ShadowPosition, Style, Transform, ViewportState, WillChange,
+};
+use torin::{
+    geometry::Area,
+    prelude::{LayoutNode, Torin},
+};
+
+use super::elements::{
+    render_clip_path_shadows, render_image, render_label, render_paragraph, render_rect,
+    render_shader, render_svg,
+};
+
+fn clip_viewport(canvas: &Canvas, viewport: &Area, antialias: bool) {
+    canvas.clip_rect(
+        Rect::new(
+            viewport.min_x(),
+            viewport.min_y(),
+            viewport.max_x(),
+            viewport.max_y(),
+        ),
+        ClipOp::Intersect,
+        antialias,
+    );
+}
+
+/// Clip to the given `clip_path` shapes, usually the ones of a node and its ancestors
+fn clip_paths(
+    canvas: &Canvas,
+    clip_paths: &[(NodeId, ClipPath)],
+    layout: &Torin<NodeId>,
+    antialias: bool,
+) {
+    for (node_id, clip_path) in clip_paths {
+        let area = layout.get(*node_id).unwrap().visible_area();
+        if let Some(path) = clip_path.to_path(area) {
+            canvas.clip_path(&path, ClipOp::Intersect, antialias);
+        }
+    }
+}
+
+/// Get the area where a node is rendered, with its edges rounded to the physical pixel grid when it's snapped.
+fn rendered_area(layout_node: &LayoutNode, node_style: &Style) -> Area {
+    let area = layout_node.visible_area();
+    if node_style.pixel_snapping {
+        area.round()
+    } else {
+        area
+    }
+}
+
+/// Render a node into the Skia canvas, leaving out the `composited` effects as they are applied when painting its cached image.
+#[allow(clippy::too_many_arguments)]
+pub fn render_skia(
+    canvas: &Canvas,
+    layout_node: &LayoutNode,
+    dioxus_node: &DioxusNode,
+    font_collection: &mut FontCollection,
+    font_manager: &FontMgr,
+    render_wireframe: bool,
+    matrices: &mut Vec<(Matrix, Vec<NodeId>)>,
+    opacities: &mut Vec<(f32, Vec<NodeId>)>,
+    default_fonts: &[String],
+    layout: &Torin<NodeId>,
+    composited: WillChange,
+) {
+    let data = &layout_node.data;
+    let node_type = &*dioxus_node.node_type();
+    if let NodeType::Element(ElementNode { tag, .. }) = node_type {
+        canvas.save();
+
+        let node_transform = &*dioxus_node.get::<Transform>().unwrap();
+        let node_style = &*dioxus_node.get::<Style>().unwrap();
+        let area = rendered_area(layout_node, node_style);
+
+        // Pass rotate effect to children
+        if let Some(rotate_degs) = node_transform.rotate_degs.filter(|_| !composited.transform) {
+            let mut matrix = Matrix::new_identity();
+            matrix.set_rotate(
+                rotate_degs,
+                Some(Point {
+                    x: area.min_x() + area.width() / 2.0,
+                    y: area.min_y() + area.height() / 2.0,
+                }),
+            );
+
+            matrices.push((matrix, vec![dioxus_node.id()]));
+        }
+
+        // Pass opacity effect to children
+        if let Some(opacity) = node_style.opacity.filter(|_| !composited.opacity) {
+            opacities.push((opacity, vec![dioxus_node.id()]));
+        }
+
+        // Apply inherited matrices
+        for (matrix, nodes) in matrices.iter_mut() {
+            if nodes.contains(&dioxus_node.id()) {
+                canvas.concat(matrix);
+
+                nodes.extend(dioxus_node.child_ids());
+            }
+        }
+
+        // Apply inherited opacity effects
+        for (opacity, nodes) in opacities.iter_mut() {
+            if nodes.contains(&dioxus_node.id()) {
+                canvas.save_layer_alpha_f(
+                    Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                    *opacity,
+                );
+
+                nodes.extend(dioxus_node.child_ids());
+            }
+        }
+
+        // Clip all elements with their corresponding viewports
+        let node_viewports = dioxus_node.get::<ViewportState>().unwrap();
+        // Only clip the element iself when it's paragraph because
+        // it will render the inner text spans on it's own, so if these spans overflow the paragraph,
+        // It is the paragraph job to make sure they are clipped
+        if !node_viewports.viewports.is_empty() && *tag == TagName::Paragraph {
+            clip_viewport(canvas, &layout_node.visible_area(), node_style.antialias);
+        }
+
+        for viewport_id in &node_viewports.viewports {
+            let viewport = layout.get(*viewport_id).unwrap().visible_area();
+            clip_viewport(canvas, &viewport, node_style.antialias);
+        }
+
+        // The outer shadows of a rect follow its own clip path, so they are painted before clipping to it
+        let (ancestors_clip_paths, own_clip_path) = match node_viewports.clip_paths.split_last() {
+            Some(((node_id, clip_path), ancestors_clip_paths))
+                if *tag == TagName::Rect && *node_id == dioxus_node.id() =>
+            {
+                (
+                    ancestors_clip_paths,
+                    clip_path.to_path(layout_node.visible_area()),
+                )
+            }
+            _ => (node_viewports.clip_paths.as_slice(), None),
+        };
+
+        clip_paths(canvas, ancestors_clip_paths, layout, node_style.antialias);
+
+        if let Some(own_clip_path) = &own_clip_path {
+            render_clip_path_shadows(
+                canvas,
+                &area,
+                node_style,
+                own_clip_path,
+                ShadowPosition::Normal,
+            );
+            canvas.clip_path(own_clip_path, ClipOp::Intersect, node_style.antialias);
+        }
+
+        match tag {
+            TagName::Rect => {
+                render_rect(
+                    &area,
+                    &layout_node.padding,
+                    dioxus_node,
+                    canvas,
+                    font_collection,
+                    own_clip_path.as_ref(),
+                );
+            }
+            TagName::Label => {
+                render_label(&area, data, dioxus_node, canvas);
+            }
+            TagName::Paragraph => {
+                render_paragraph(
+                    &area,
+                    data,
+                    dioxus_node,
+                    canvas,
+                    font_collection,
+                    default_fonts,
+                );
+            }
+            TagName::Svg => {
+                render_svg(&area, dioxus_node, canvas, font_manager);
+            }
+            TagName::Image => {
+                render_image(&area, dioxus_node, canvas);
+            }
+            _ => {}
+        }
+
+        if render_wireframe {
+            super::wireframe::render_wireframe(canvas, &area);
+        }
+
+        canvas.restore();
+    }
+}
+
+/// Paint the shader of a node over itself and its descendants of the same layer, once they have been rendered.
+pub fn render_foreground_shader(
+    canvas: &Canvas,
+    layout_node: &LayoutNode,
+    dioxus_node: &DioxusNode,
+    matrices: &[(Matrix, Vec<NodeId>)],
+    opacities: &[(f32, Vec<NodeId>)],
+    layout: &Torin<NodeId>,
+) {
+    let node_style = &*dioxus_node.get::<Style>().unwrap();
+    let Some(shader) = &node_style.shader else {
+        return;
+    };
+    if node_style.shader_position != ShaderPosition::Foreground {
+        return;
+    }
+
+    let area = rendered_area(layout_node, node_style);
+
+    canvas.save();
+
+    // Apply the same inherited effects the node was rendered with
+    for (matrix, nodes) in matrices {
+        if nodes.contains(&dioxus_node.id()) {
+            canvas.concat(matrix);
+        }
+    }
+
+    for (opacity, nodes) in opacities {
+        if nodes.contains(&dioxus_node.id()) {
+            canvas.save_layer_alpha_f(
+                Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                *opacity,
+            );
+        }
+    }
+
+    let node_viewports = dioxus_node.get::<ViewportState>().unwrap();
+    for viewport_id in &node_viewports.viewports {
+        let viewport = layout.get(*viewport_id).unwrap().visible_area();
+        clip_viewport(canvas, &viewport, node_style.antialias);
+    }
+
+    clip_paths(
+        canvas,
+        &node_viewports.clip_paths,
+        layout,
+        node_style.antialias,
+    );
+
+    render_shader(&area, node_style, shader, canvas);
+
+    canvas.restore();
+}
@@ -0,0 +1,335 @@
+use crate::dom::FreyaDOM;
+use crate::prelude::get_painted_area;
+use freya_engine::prelude::*;
+use freya_native_core::{real_dom::NodeImmutable, tree::TreeRef, NodeId};
+use freya_node_state::{LayerState, References, Style, Transform, ViewportState, WillChange};
+use itertools::sorted;
+use rustc_hash::{FxHashMap, FxHashSet};
+use torin::{geometry::Area, prelude::Torin};
+
+/// Maximum amount of memory that all the cached images can use together.
+const MAX_CACHE_BYTES: usize = 128 * 1024 * 1024;
+
+/// Rendered output of a cached subtree.
+struct CachedSubtree {
+    /// Area covered by the image, in physical pixels.
+    area: Area,
+    /// Visible area of the subtree root, the image content is clipped by it.
+    clip: Area,
+    image: Option<Image>,
+}
+
+/// Subtree that can be painted from the cache in the current frame.
+struct FrameSubtree {
+    /// The root and all its descendants, in the order they must be rendered.
+    nodes: Vec<NodeId>,
+    area: Area,
+    clip: Area,
+    /// Effects of the root that are left out of the image and applied when painting it.
+    composited: WillChange,
+    composited_rotation: Option<Matrix>,
+    composited_opacity: Option<f32>,
+}
+
+/// Caches the rendered output of the subtrees marked with `cache: "true"` in offscreen images,
+/// so they can be painted as a whole instead of rendering every node again in each frame.
+///
+/// The subtrees with a `will_change` hint are cached too, but the hinted effects of their root (its rotation or opacity)
+/// are applied when painting the image, so changing them doesn't render the subtree again.
+///
+/// Every cached image takes `width * height * 4` bytes of memory, and it is rendered again whenever the subtree
+/// changes, moves or gets resized. Subtrees whose output depends on things outside of them
+/// (inherited rotations or opacities, descendants painting outside the root or in other layers, canvas elements)
+/// are never cached and just get rendered normally.
+#[derive(Default)]
+pub struct SubtreeCache {
+    entries: FxHashMap<NodeId, CachedSubtree>,
+    /// Cached subtrees of the current frame, by their root.
+    frame_subtrees: FxHashMap<NodeId, FrameSubtree>,
+    /// Descendants of the cached subtrees of the current frame.
+    cached_nodes: FxHashSet<NodeId>,
+}
+
+impl SubtreeCache {
+    /// Throw away all the cached images.
+    pub fn invalidate(&mut self) {
+        self.entries.clear();
+        self.frame_subtrees.clear();
+        self.cached_nodes.clear();
+    }
+
+    /// Find the subtrees that can be painted from the cache in this frame
+    /// and throw away the images of those that changed.
+    ///
+    /// The `composited_changes` are the dirty nodes whose only changes were their rotation or opacity,
+    /// see [`FreyaDOM::take_composited_changes`].
+    pub fn prepare(
+        &mut self,
+        fdom: &FreyaDOM,
+        dirty_nodes: &FxHashSet<NodeId>,
+        composited_changes: &FxHashMap<NodeId, WillChange>,
+        window_area: Area,
+    ) {
+        self.frame_subtrees.clear();
+        self.cached_nodes.clear();
+
+        let rdom = fdom.rdom();
+        let layout = fdom.layout();
+
+        // From the shallowest to the deepest, so nested subtrees are found after their ancestors
+        let roots = sorted(fdom.layers().layers().iter())
+            .flat_map(|(_, layer)| layer)
+            .filter(|node_id| {
+                rdom.get(**node_id)
+                    .map(|node| {
+                        let style = node.get::<Style>().unwrap();
+                        style.cache || style.will_change.is_any()
+                    })
+                    .unwrap_or_default()
+            })
+            .copied()
+            .collect::<Vec<NodeId>>();
+
+        if roots.is_empty() {
+            self.entries.clear();
+            return;
+        }
+
+        // Changes in any node of a subtree invalidate its image, except the hinted effects of the root
+        for dirty_id in dirty_nodes {
+            let is_composited = composited_changes.get(dirty_id).is_some_and(|changes| {
+                let will_change = rdom
+                    .get(*dirty_id)
+                    .unwrap()
+                    .get::<Style>()
+                    .unwrap()
+                    .will_change;
+                (!changes.transform || will_change.transform)
+                    && (!changes.opacity || will_change.opacity)
+            });
+
+            let mut node_id = Some(*dirty_id);
+            while let Some(id) = node_id {
+                if roots.contains(&id) && !(id == *dirty_id && is_composited) {
+                    self.entries.remove(&id);
+                }
+                node_id = rdom.tree_ref().parent_id(id);
+            }
+        }
+
+        let mut used_bytes = 0;
+
+        'roots: for root_id in &roots {
+            let root = rdom.get(*root_id).unwrap();
+            let Some(root_layout) = layout.get(*root_id) else {
+                continue;
+            };
+
+            let root_style = root.get::<Style>().unwrap();
+            let composited = root_style.will_change;
+            let rotate_degs = root.get::<Transform>().unwrap().rotate_degs;
+
+            if (rotate_degs.is_some() && !composited.transform)
+                || root_style.shader.is_some()
+                || root_style.mask.is_some()
+                || root_style.scroll_fade > 0.0
+                || !root.get::<ViewportState>().unwrap().clip_paths.is_empty()
+            {
+                continue;
+            }
+
+            // Inherited effects can't be applied to the image, and nested subtrees are part of their ancestor's image
+            let mut parent_id = rdom.tree_ref().parent_id(*root_id);
+            while let Some(id) = parent_id {
+                let parent = rdom.get(id).unwrap();
+                if parent.get::<Transform>().unwrap().rotate_degs.is_some()
+                    || parent.get::<Style>().unwrap().opacity.is_some()
+                    || self.frame_subtrees.contains_key(&id)
+                {
+                    continue 'roots;
+                }
+                parent_id = rdom.tree_ref().parent_id(id);
+            }
+
+            let area = get_painted_area(&root, &root_layout.area).round_out();
+            let bytes = (area.width() * area.height()) as usize * 4;
+            if area.is_empty()
+                || area.width() > window_area.width()
+                || area.height() > window_area.height()
+                || used_bytes + bytes > MAX_CACHE_BYTES
+            {
+                continue;
+            }
+
+            let children_layer = root.get::<LayerState>().unwrap().layer_for_children;
+            let mut descendants = FxHashSet::default();
+            let mut pending = root.child_ids();
+            while let Some(node_id) = pending.pop() {
+                let node = rdom.get(node_id).unwrap();
+
+                if node.get::<Transform>().unwrap().rotate_degs.is_some()
+                    || node.get::<References>().unwrap().canvas_ref.is_some()
+                    || node.get::<References>().unwrap().gpu_canvas_ref.is_some()
+                    || node.get::<Style>().unwrap().shader.is_some()
+                    || node.get::<Style>().unwrap().mask.is_some()
+                    || node.get::<Style>().unwrap().scroll_fade > 0.0
+                    || !node.get::<ViewportState>().unwrap().clip_paths.is_empty()
+                    || node.get::<LayerState>().unwrap().layer_for_children != children_layer
+                {
+                    continue 'roots;
+                }
+
+                if let Some(layout_node) = layout.get(node_id) {
+                    if !area.contains_rect(&get_painted_area(&node, &layout_node.area)) {
+                        continue 'roots;
+                    }
+                }
+
+                pending.extend(node.child_ids());
+                descendants.insert(node_id);
+            }
+
+            let mut clip = root_layout.visible_area();
+            for viewport_id in &root.get::<ViewportState>().unwrap().viewports {
+                let viewport = layout.get(*viewport_id).unwrap().visible_area();
+                clip = clip.intersection(&viewport).unwrap_or_default();
+            }
+
+            let nodes = get_subtree_nodes(fdom, &layout, *root_id, &descendants);
+
+            // Same rotation the root would be rendered with
+            let composited_rotation = rotate_degs.filter(|_| composited.transform).map(|degs| {
+                let mut root_area = root_layout.visible_area();
+                if root_style.pixel_snapping {
+                    root_area = root_area.round();
+                }
+                let center = root_area.center();
+                let mut matrix = Matrix::new_identity();
+                matrix.set_rotate(degs, Some(Point::new(center.x, center.y)));
+                matrix
+            });
+            let composited_opacity = root_style.opacity.filter(|_| composited.opacity);
+
+            used_bytes += bytes;
+            self.cached_nodes.extend(descendants);
+            self.frame_subtrees.insert(
+                *root_id,
+                FrameSubtree {
+                    nodes,
+                    area,
+                    clip,
+                    composited,
+                    composited_rotation,
+                    composited_opacity,
+                },
+            );
+        }
+
+        // Forget about the subtrees that are gone or can't be cached anymore
+        let frame_subtrees = &self.frame_subtrees;
+        self.entries.retain(|root_id, entry| {
+            frame_subtrees
+                .get(root_id)
+                .map(|subtree| subtree.area == entry.area && subtree.clip == entry.clip)
+                .unwrap_or_default()
+        });
+    }
+
+    /// Check if the given node is painted by the cached image of one of its ancestors.
+    pub fn is_cached(&self, node_id: &NodeId) -> bool {
+        self.cached_nodes.contains(node_id)
+    }
+
+    /// Paint the cached subtree of the given node, rendering it with `render_nodes` first if there is no image of it yet.
+    /// The effects of the root given to `render_nodes` must be left out, as they are applied when painting the image.
+    ///
+    /// Returns `false` if the node is not the root of a cached subtree, so it must be rendered as usual.
+    pub fn render(
+        &mut self,
+        canvas: &Canvas,
+        node_id: &NodeId,
+        mut render_nodes: impl FnMut(&Canvas, &[NodeId], WillChange),
+    ) -> bool {
+        let Some(subtree) = self.frame_subtrees.get(node_id) else {
+            return false;
+        };
+
+        let entry = self.entries.entry(*node_id).or_insert(CachedSubtree {
+            area: subtree.area,
+            clip: subtree.clip,
+            image: None,
+        });
+
+        if entry.image.is_none() {
+            let image_info = ImageInfo::new_n32_premul(
+                (subtree.area.width() as i32, subtree.area.height() as i32),
+                None,
+            );
+
+            if let Some(mut surface) = canvas.new_surface(&image_info, None) {
+                let surface_canvas = surface.canvas();
+                surface_canvas.clear(Color::TRANSPARENT);
+                surface_canvas.translate((-subtree.area.min_x(), -subtree.area.min_y()));
+                render_nodes(surface_canvas, &subtree.nodes, subtree.composited);
+                entry.image = Some(surface.image_snapshot());
+            }
+        }
+
+        match &entry.image {
+            Some(image) => {
+                canvas.save();
+                if let Some(rotation) = &subtree.composited_rotation {
+                    canvas.concat(rotation);
+                }
+                let paint = subtree.composited_opacity.map(|opacity| {
+                    let mut paint = Paint::default();
+                    paint.set_alpha_f(opacity);
+                    paint
+                });
+                canvas.draw_image(
+                    image,
+                    (subtree.area.min_x(), subtree.area.min_y()),
+                    paint.as_ref(),
+                );
+                canvas.restore();
+            }
+            // The offscreen surface couldn't be created
+            None => render_nodes(canvas, &subtree.nodes, WillChange::default()),
+        }
+
+        true
+    }
+}
+
+/// Get the root and the given descendants of a subtree in the same order the whole tree is rendered,
+/// skipping those that are completely out of their viewports.
+pub(crate) fn get_subtree_nodes(
+    fdom: &FreyaDOM,
+    layout: &Torin<NodeId>,
+    root_id: NodeId,
+    descendants: &FxHashSet<NodeId>,
+) -> Vec<NodeId> {
+    let rdom = fdom.rdom();
+
+    let mut nodes = vec![root_id];
+    for (_, layer) in sorted(fdom.layers().layers().iter()) {
+        'nodes: for node_id in layer {
+            if !descendants.contains(node_id) {
+                continue;
+            }
+            let Some(layout_node) = layout.get(*node_id) else {
+                continue;
+            };
+            let node = rdom.get(*node_id).unwrap();
+            for viewport_id in &node.get::<ViewportState>().unwrap().viewports {
+                let viewport = layout.get(*viewport_id).unwrap().visible_area();
+                if !viewport.intersects(&layout_node.area) {
+                    continue 'nodes;
+                }
+            }
+            nodes.push(*node_id);
+        }
+    }
+
+    nodes
+}
@@ -0,0 +1,148 @@
+use crate::prelude::FreyaDOM;
+use freya_engine::prelude::*;
+use freya_native_core::{tree::TreeRef, NodeId};
+use itertools::sorted;
+use torin::{geometry::Area, prelude::Gaps};
+
+/// Colors of the layout overlay, picked by the depth of the nodes.
+const LAYOUT_OVERLAY_COLORS: [Color; 6] = [
+    Color::RED,
+    Color::GREEN,
+    Color::BLUE,
+    Color::MAGENTA,
+    Color::CYAN,
+    Color::YELLOW,
+];
+
+/// Render a wireframe around the given node
+pub fn render_wireframe(canvas: &Canvas, area: &Area) {
+    let mut paint = Paint::default();
+
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Fill);
+    paint.set_color(Color::MAGENTA);
+
+    let x = area.min_x();
+    let y = area.min_y();
+
+    let x2 = x + area.width();
+    let y2 = if area.height() < 0.0 {
+        y
+    } else {
+        y + area.height()
+    };
+
+    canvas.draw_line((x, y), (x2, y), &paint);
+    canvas.draw_line((x2, y), (x2, y2), &paint);
+    canvas.draw_line((x2, y2), (x, y2), &paint);
+    canvas.draw_line((x, y2), (x, y), &paint);
+}
+
+/// Outline the layout bounds of every node, from the bottom layer to the top one.
+pub fn render_layout_overlay(canvas: &Canvas, fdom: &FreyaDOM) {
+    let layout = fdom.layout();
+    let layers = fdom.layers();
+    let tree = fdom.rdom().tree_ref();
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Stroke);
+    paint.set_stroke_width(1.0);
+
+    for (_, layer) in sorted(layers.layers().iter()) {
+        for node_id in layer {
+            let (Some(layout_node), Some(depth)) = (layout.get(*node_id), tree.height(*node_id))
+            else {
+                continue;
+            };
+            let area = layout_node.visible_area();
+
+            let color = LAYOUT_OVERLAY_COLORS[depth as usize % LAYOUT_OVERLAY_COLORS.len()];
+            paint.set_color(color.with_a(180));
+
+            canvas.draw_rect(
+                Rect::new(
+                    area.min_x() + 0.5,
+                    area.min_y() + 0.5,
+                    area.max_x() - 0.5,
+                    area.max_y() - 0.5,
+                ),
+                &paint,
+            );
+        }
+    }
+}
+
+/// Render a label with the computed size, padding and margin of the given node, in logical pixels.
+/// It's anchored to the top left corner of the node and kept inside the window.
+pub fn render_node_metrics(
+    canvas: &Canvas,
+    fdom: &FreyaDOM,
+    node_id: NodeId,
+    font_collection: &FontCollection,
+    default_fonts: &[String],
+    window_area: Area,
+    scale_factor: f32,
+) {
+    let layout = fdom.layout();
+    let Some(layout_node) = layout.get(node_id) else {
+        return;
+    };
+    let area = layout_node.visible_area();
+
+    let text = format!(
+        "{} × {}\npadding {}\nmargin {}",
+        format_pixels(area.width() / scale_factor),
+        format_pixels(area.height() / scale_factor),
+        format_gaps(&layout_node.padding, scale_factor),
+        format_gaps(&layout_node.margin, scale_factor),
+    );
+
+    let mut text_style = TextStyle::new();
+    text_style.set_color(Color::WHITE);
+    text_style.set_font_size(12.0 * scale_factor);
+    text_style.set_font_families(default_fonts);
+
+    let mut paragraph_builder =
+        ParagraphBuilder::new(&ParagraphStyle::default(), font_collection.clone());
+    paragraph_builder.push_style(&text_style);
+    paragraph_builder.add_text(text);
+    let mut paragraph = paragraph_builder.build();
+    paragraph.layout(f32::MAX);
+
+    let padding = 4.0 * scale_factor;
+    let width = paragraph.max_intrinsic_width() + padding * 2.0;
+    let height = paragraph.height() + padding * 2.0;
+
+    // Above the node, or below it when there is no room left, or inside of it as last resort
+    let mut y = area.min_y() - height;
+    if y < window_area.min_y() {
+        y = area.max_y();
+    }
+    if y + height > window_area.max_y() {
+        y = area.min_y().max(window_area.min_y());
+    }
+    let x = area
+        .min_x()
+        .min(window_area.max_x() - width)
+        .max(window_area.min_x());
+
+    let mut paint = Paint::default();
+    paint.set_anti_alias(true);
+    paint.set_style(PaintStyle::Fill);
+    paint.set_color(Color::from_argb(220, 35, 35, 35));
+    canvas.draw_rect(Rect::new(x, y, x + width, y + height), &paint);
+
+    paragraph.paint(canvas, (x + padding, y + padding));
+}
+
+fn format_pixels(value: f32) -> String {
+    format!("{}", (value * 10.0).round() / 10.0)
+}
+
+/// Top, right, bottom and left gaps, like in the `padding` and `margin` attributes.
+fn format_gaps(gaps: &Gaps, scale_factor: f32) -> String {
+    [gaps.top(), gaps.right(), gaps.bottom(), gaps.left()]
+        .map(|gap| format_pixels(gap / scale_factor))
+        .join(" ")
+}
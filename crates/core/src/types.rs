@@ -2,7 +2,10 @@ use crate::{
     events::{DomEvent, PlatformEvent},
     prelude::{EventName, PotentialEvent},
 };
-pub use accesskit::NodeId as AccessibilityId;
+pub use accesskit::{
+    Checked as AccessibilityChecked, Live as AccessibilityLive, Node as AccessibilityNode,
+    NodeId as AccessibilityId,
+};
 use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
@@ -0,0 +1,220 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+use tokio::time::sleep;
+
+#[tokio::test]
+pub async fn keyframes_animation() {
+    fn animation_app() -> Element {
+        let mut paused = use_signal(|| false);
+        use_keyframes("grow", || {
+            Keyframes::new()
+                .keyframe(0.0, [("width", "100")])
+                .keyframe(1.0, [("width", "200")])
+        });
+
+        let state = if paused() { "paused" } else { "running" };
+
+        rsx!(rect {
+            animation: "grow 100ms {state}",
+            width: "50",
+            height: "50",
+            onclick: move |_| paused.toggle(),
+        })
+    }
+
+    let mut utils = launch_test(animation_app);
+    utils.wait_for_update().await;
+
+    let rect = utils.root().get(0);
+    let width = rect.layout().unwrap().area.width();
+    assert!((100.0..200.0).contains(&width));
+
+    // Pausing keeps its progress
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    let paused_width = rect.layout().unwrap().area.width();
+    sleep(Duration::from_millis(150)).await;
+    utils.wait_for_update().await;
+    assert_eq!(rect.layout().unwrap().area.width(), paused_width);
+
+    // Once resumed it ends and restores the width of the element
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    sleep(Duration::from_millis(150)).await;
+    utils.wait_for_update().await;
+    assert_eq!(rect.layout().unwrap().area.width(), 50.0);
+}
+
+#[tokio::test]
+pub async fn keyframes_animation_fill_forwards() {
+    fn animation_app() -> Element {
+        use_keyframes("grow", || {
+            Keyframes::new()
+                .keyframe(0.0, [("width", "100")])
+                .keyframe(1.0, [("width", "200")])
+        });
+
+        rsx!(rect {
+            animation: "grow 50ms forwards",
+            width: "50",
+            height: "50",
+        })
+    }
+
+    let mut utils = launch_test(animation_app);
+    utils.wait_for_update().await;
+    sleep(Duration::from_millis(100)).await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).layout().unwrap().area.width(), 200.0);
+}
+
+#[tokio::test]
+pub async fn exit_animation() {
+    fn exit_animation_app() -> Element {
+        let mut items = use_signal(|| vec![1, 2]);
+        use_keyframes("shrink", || {
+            Keyframes::new()
+                .keyframe(0.0, [("width", "100")])
+                .keyframe(1.0, [("width", "0")])
+        });
+
+        rsx!(
+            rect {
+                for item in items() {
+                    rect {
+                        key: "{item}",
+                        exit_animation: "shrink 100ms",
+                        width: "100",
+                        height: "50",
+                        onclick: move |_| items.retain(|i| *i != item),
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(exit_animation_app);
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).children_ids().len(), 2);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // It's kept while it shrinks
+    let list = utils.root().get(0);
+    assert_eq!(list.children_ids().len(), 2);
+    let width = list.get(0).layout().unwrap().area.width();
+    assert!((0.0..100.0).contains(&width));
+
+    // And it doesn't receive events anymore
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).children_ids().len(), 2);
+
+    // Until it ends and it's removed
+    sleep(Duration::from_millis(150)).await;
+    utils.wait_for_update().await;
+    let list = utils.root().get(0);
+    assert_eq!(list.children_ids().len(), 1);
+    assert_eq!(list.get(0).layout().unwrap().area.width(), 100.0);
+}
+
+#[tokio::test]
+pub async fn paused_animation_clock() {
+    fn animation_app() -> Element {
+        use_keyframes("grow", || {
+            Keyframes::new()
+                .keyframe(0.0, [("width", "100")])
+                .keyframe(1.0, [("width", "200")])
+        });
+
+        rsx!(rect {
+            animation: "grow 1s forwards",
+            width: "50",
+            height: "50",
+        })
+    }
+
+    let mut utils = launch_test(animation_app);
+    utils.wait_for_update().await;
+
+    let clock = utils.sdom().get().animation_clock();
+    clock.pause();
+    utils.wait_for_update().await;
+
+    // Time doesn't advance for the animations while the clock is paused
+    let rect = utils.root().get(0);
+    let paused_width = rect.layout().unwrap().area.width();
+    sleep(Duration::from_millis(150)).await;
+    utils.wait_for_update().await;
+    assert_eq!(rect.layout().unwrap().area.width(), paused_width);
+
+    // Stepping it advances them
+    clock.step(Duration::from_millis(500));
+    utils.wait_for_update().await;
+    let stepped_width = rect.layout().unwrap().area.width();
+    assert!((paused_width + 45.0..paused_width + 55.0).contains(&stepped_width));
+
+    clock.resume();
+    sleep(Duration::from_millis(600)).await;
+    utils.wait_for_update().await;
+    assert_eq!(rect.layout().unwrap().area.width(), 200.0);
+}
+
+#[tokio::test]
+pub async fn reduced_motion_animations() {
+    fn animation_app() -> Element {
+        use_keyframes("grow", || {
+            Keyframes::new()
+                .keyframe(0.0, [("width", "100")])
+                .keyframe(1.0, [("width", "200")])
+        });
+
+        rsx!(
+            rect {
+                animation: "grow 1s forwards",
+                width: "50",
+                height: "50",
+            }
+            rect {
+                animation: "grow 1s infinite",
+                width: "50",
+                height: "50",
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        animation_app,
+        TestingConfig {
+            reduced_motion: true,
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    // Finite animations skip to their end, and infinite ones aren't played
+    let root = utils.root();
+    assert_eq!(root.get(0).layout().unwrap().area.width(), 200.0);
+    assert_eq!(root.get(1).layout().unwrap().area.width(), 50.0);
+    assert!(!utils.sdom().get().has_running_animations());
+}
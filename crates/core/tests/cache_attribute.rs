@@ -0,0 +1,44 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn cache_attribute() {
+    fn app() -> Element {
+        let mut cache = use_signal(|| true);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                cache: "{cache}",
+                onclick: move |_| cache.set(false),
+                label {
+                    "Cached"
+                }
+            }
+            rect {
+                label {
+                    "Not cached"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+
+    assert!(root.get(0).style().cache);
+    assert!(!root.get(1).style().cache);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    assert!(!root.get(0).style().cache);
+}
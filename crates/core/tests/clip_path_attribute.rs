@@ -0,0 +1,67 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn clip_path_limits_the_clickable_area() {
+    fn clip_path_app() -> Element {
+        let mut clicked = use_signal(|| false);
+        let background = if clicked() { "red" } else { "blue" };
+
+        rsx!(rect {
+            width: "100",
+            height: "100",
+            clip_path: "circle()",
+            background: "{background}",
+            onclick: move |_| clicked.toggle(),
+        })
+    }
+
+    let mut utils = launch_test(clip_path_app);
+    let rect = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    // Inside of the rect but outside of the circle
+    utils.click((5.0, 5.0));
+    utils.wait_for_update().await;
+    assert_eq!(rect.style().background, Fill::Color(Color::BLUE));
+
+    // Inside of the circle
+    utils.click((50.0, 50.0));
+    utils.wait_for_update().await;
+    assert_eq!(rect.style().background, Fill::Color(Color::RED));
+}
+
+#[tokio::test]
+pub async fn clip_path_is_inherited_by_children() {
+    fn clip_path_app() -> Element {
+        let mut clicked = use_signal(|| false);
+        let background = if clicked() { "red" } else { "blue" };
+
+        rsx!(rect {
+            width: "100",
+            height: "100",
+            clip_path: "polygon(0 0, 100% 0, 0 100%)",
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "{background}",
+                onclick: move |_| clicked.toggle(),
+            }
+        })
+    }
+
+    let mut utils = launch_test(clip_path_app);
+    let rect = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    // Below the diagonal of the triangle
+    utils.click((90.0, 90.0));
+    utils.wait_for_update().await;
+    assert_eq!(rect.style().background, Fill::Color(Color::BLUE));
+
+    // Above the diagonal of the triangle
+    utils.click((10.0, 10.0));
+    utils.wait_for_update().await;
+    assert_eq!(rect.style().background, Fill::Color(Color::RED));
+}
@@ -0,0 +1,134 @@
+use freya::prelude::*;
+use freya_core::prelude::is_point_inside;
+use freya_engine::prelude::{Canvas, FontCollection};
+use freya_testing::prelude::*;
+
+/// Circle of a fixed diameter, only hit inside of the circle.
+struct Circle(f32);
+
+impl CustomElement for Circle {
+    fn measure(&self, _available_size: &Size2D) -> Option<Size2D> {
+        Some(Size2D::new(self.0, self.0))
+    }
+
+    fn paint(&self, _canvas: &Canvas, _font_collection: &mut FontCollection, _area: Area) {}
+
+    fn hit_test(&self, area: Area, point: Point2D) -> bool {
+        (point - area.center()).length() <= area.width() / 2.0
+    }
+}
+
+#[tokio::test]
+pub async fn custom_elements_measure_and_hit_test() {
+    fn custom_elements_app() -> Element {
+        rsx!(
+            rect {
+                custom_element: "circle",
+                rect {
+                    width: "300",
+                    height: "300",
+                }
+            }
+            rect {
+                custom_element: "circle",
+                width: "100",
+                height: "20",
+            }
+            rect {
+                custom_element: "unknown",
+                width: "100",
+                height: "20",
+            }
+        )
+    }
+
+    let mut custom_elements = CustomElements::default();
+    custom_elements.register("circle", Circle(50.0));
+
+    let mut utils = launch_test_with_config(
+        custom_elements_app,
+        TestingConfig {
+            custom_elements,
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let (circle, sized_circle, unknown) = (root.get(0), root.get(1), root.get(2));
+
+    // The measured size is used instead of the children
+    assert_eq!(
+        circle.layout().unwrap().area,
+        Area::new((0.0, 0.0).into(), (50.0, 50.0).into())
+    );
+    assert!(circle.get(0).layout().is_none());
+
+    // Explicit sizes win over the measured one
+    assert_eq!(
+        sized_circle.layout().unwrap().area.size,
+        Size2D::new(100.0, 20.0)
+    );
+
+    let fdom = utils.sdom().get();
+    assert!(is_point_inside(&fdom, circle.id(), (25.0, 25.0).into()));
+    assert!(!is_point_inside(&fdom, circle.id(), (2.0, 2.0).into()));
+
+    // Unknown names are laid out and hit like any other rect
+    assert!(is_point_inside(&fdom, unknown.id(), (2.0, 72.0).into()));
+}
+
+#[tokio::test]
+pub async fn custom_elements_children_stay_skipped_when_relayouted() {
+    fn custom_elements_app() -> Element {
+        let mut height = use_signal(|| 100);
+
+        rsx!(
+            rect {
+                rect {
+                    custom_element: "circle",
+                    rect {
+                        width: "300",
+                        height: "300",
+                    }
+                }
+                rect {
+                    width: "100",
+                    height: "{height}",
+                    onclick: move |_| height += 100,
+                }
+            }
+        )
+    }
+
+    let mut custom_elements = CustomElements::default();
+    custom_elements.register("circle", Circle(50.0));
+
+    let mut utils = launch_test_with_config(
+        custom_elements_app,
+        TestingConfig {
+            custom_elements,
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    let parent = utils.root().get(0);
+    let (circle, sibling) = (parent.get(0), parent.get(1));
+    assert!(circle.get(0).layout().is_none());
+
+    // Resizing the sibling relayouts their parent, reusing the cached layout of the custom element
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (10.0, 60.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(sibling.layout().unwrap().area.height(), 200.0);
+    assert_eq!(
+        circle.layout().unwrap().area,
+        Area::new((0.0, 0.0).into(), (50.0, 50.0).into())
+    );
+    assert!(circle.get(0).layout().is_none());
+}
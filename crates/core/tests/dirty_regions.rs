@@ -0,0 +1,98 @@
+use freya::prelude::*;
+use freya_core::prelude::DirtyRegions;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn dirty_region_of_changed_node() {
+    fn app() -> Element {
+        let mut background = use_signal(|| "red");
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                background: "blue",
+            }
+            rect {
+                width: "50",
+                height: "50",
+                background: "{background}",
+                onclick: move |_| background.set("green"),
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let window_area = Area::from_size((500., 500.).into());
+    let mut dirty_regions = DirtyRegions::default();
+
+    // Everything is new in the first frame
+    let dirty_nodes = utils.sdom().get_mut().take_dirty_nodes();
+    let region = dirty_regions.compute(&utils.sdom().get(), &dirty_nodes, window_area, 1);
+    assert_eq!(region, None);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (25.0, 125.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // Only the second rect changed
+    let dirty_nodes = utils.sdom().get_mut().take_dirty_nodes();
+    let region = dirty_regions.compute(&utils.sdom().get(), &dirty_nodes, window_area, 1);
+    assert_eq!(
+        region,
+        Some(Area::new((0., 100.).into(), (50., 50.).into()))
+    );
+
+    // The contents of the back buffer are unknown
+    let region = dirty_regions.compute(&utils.sdom().get(), &dirty_nodes, window_area, 0);
+    assert_eq!(region, None);
+}
+
+#[tokio::test]
+pub async fn dirty_region_includes_outline() {
+    fn app() -> Element {
+        let mut outline = use_signal(|| "none");
+
+        rsx!(rect {
+            margin: "20",
+            width: "50",
+            height: "50",
+            background: "red",
+            outline: "{outline}",
+            outline_offset: "2",
+            onclick: move |_| outline.set("3 solid blue"),
+        })
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let window_area = Area::from_size((500., 500.).into());
+    let mut dirty_regions = DirtyRegions::default();
+
+    let dirty_nodes = utils.sdom().get_mut().take_dirty_nodes();
+    dirty_regions.compute(&utils.sdom().get(), &dirty_nodes, window_area, 1);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (25.0, 25.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // The outline is painted outside of the element, without changing its layout
+    let area = utils.root().get(0).area().unwrap();
+    assert_eq!(area, Area::new((20., 20.).into(), (50., 50.).into()));
+
+    let dirty_nodes = utils.sdom().get_mut().take_dirty_nodes();
+    let region = dirty_regions.compute(&utils.sdom().get(), &dirty_nodes, window_area, 1);
+    assert_eq!(
+        region,
+        Some(Area::new((15., 15.).into(), (60., 60.).into()))
+    );
+}
@@ -0,0 +1,58 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn dispatch_event_to_node() {
+    fn dispatch_app() -> Element {
+        let platform = use_platform();
+        let (reference, layout) = use_node_signal();
+        let mut clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| {
+                    if let Some(node_id) = layout.read().node_id {
+                        platform.dispatch_event(
+                            node_id,
+                            PlatformEvent::Mouse {
+                                name: EventName::Click,
+                                cursor: (0.0, 0.0).into(),
+                                button: Some(MouseButton::Left),
+                            },
+                        );
+                    }
+                },
+            }
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |e: MouseEvent| {
+                    // The coordinates are in the center of the node
+                    assert_eq!(e.get_element_coordinates(), (25.0, 25.0).into());
+                    clicks += 1;
+                },
+                rect {
+                    reference,
+                    width: "50",
+                    height: "50",
+                }
+            }
+            label {
+                "{clicks}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(dispatch_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The event bubbles from the target to its parent, even though the cursor isn't over them
+    utils.click((50.0, 50.0));
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(2).get(0).text(), Some("1"));
+}
@@ -184,3 +184,239 @@ pub async fn event_cant_pass_through_wall() {
 
     assert_eq!(label.get(0).text(), Some(""));
 }
+
+#[tokio::test]
+pub async fn capture_and_bubble_phases() {
+    fn app() -> Element {
+        let mut order = use_signal(Vec::<&str>::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick_capture: move |_: MouseEvent| order.write().push("capture 1"),
+                onclick: move |_: MouseEvent| order.write().push("bubble 1"),
+                rect {
+                    height: "200",
+                    width: "200",
+                    onclick_capture: move |_: MouseEvent| order.write().push("capture 2"),
+                    onclick: move |_: MouseEvent| order.write().push("bubble 2"),
+                    rect {
+                        height: "100",
+                        width: "100",
+                        background: "red",
+                        onclick_capture: move |_: MouseEvent| order.write().push("capture 3"),
+                        onclick: move |_: MouseEvent| order.write().push("bubble 3"),
+                    }
+                }
+                label {
+                    "{order.read().join(\", \")}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(1);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some("capture 1, capture 2, capture 3, bubble 3, bubble 2, bubble 1")
+    );
+}
+
+#[tokio::test]
+pub async fn stopped_capture_phase() {
+    fn app() -> Element {
+        let mut order = use_signal(Vec::<&str>::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick_capture: move |_: MouseEvent| order.write().push("capture 1"),
+                onclick: move |_: MouseEvent| order.write().push("bubble 1"),
+                rect {
+                    height: "200",
+                    width: "200",
+                    onclick_capture: move |e: MouseEvent| {
+                        order.write().push("capture 2");
+                        e.stop_capture();
+                    },
+                    rect {
+                        height: "100",
+                        width: "100",
+                        background: "red",
+                        onclick_capture: move |_: MouseEvent| order.write().push("capture 3"),
+                        onclick: move |_: MouseEvent| order.write().push("bubble 3"),
+                    }
+                }
+                label {
+                    "{order.read().join(\", \")}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(1);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("capture 1, capture 2"));
+}
+
+#[tokio::test]
+pub async fn stopped_capture_phase_of_batched_events() {
+    fn app() -> Element {
+        let mut clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                // Only the clicks on the left half are stopped
+                onclick_capture: move |e: MouseEvent| {
+                    if e.get_screen_coordinates().x < 250.0 {
+                        e.stop_capture();
+                    }
+                },
+                onclick: move |_: MouseEvent| clicks += 1,
+                label {
+                    "{clicks}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(0);
+
+    // Both clicks are processed in the same frame, but stopping one doesn't stop the other
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(450.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("1"));
+}
+
+#[tokio::test]
+pub async fn batched_events_run_both_phases_in_order() {
+    fn app() -> Element {
+        let mut phases = use_signal(Vec::<String>::new);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick_capture: move |e: MouseEvent| {
+                    phases.write().push(format!("capture {}", e.get_screen_coordinates().x))
+                },
+                onclick: move |e: MouseEvent| {
+                    phases.write().push(format!("bubble {}", e.get_screen_coordinates().x))
+                },
+                label {
+                    "{phases.read().join(\", \")}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(0);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(450.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some("capture 50, bubble 50, capture 450, bubble 450")
+    );
+}
+
+#[tokio::test]
+pub async fn capture_events_skip_nodes_under_opaque_ones() {
+    fn app() -> Element {
+        let mut captured = use_signal(|| false);
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                rect {
+                    height: "100",
+                    width: "100",
+                    onclick_capture: move |_: MouseEvent| captured.set(true),
+                }
+                rect {
+                    position: "absolute",
+                    position_top: "0",
+                    position_left: "0",
+                    height: "100",
+                    width: "100",
+                    background: "black",
+                }
+                label {
+                    "{captured}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let root = utils.root().get(0);
+    let label = root.get(2);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    // The sibling is under the overlay, so it's not in the path of the click
+    assert_eq!(label.get(0).text(), Some("false"));
+}
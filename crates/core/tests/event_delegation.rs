@@ -0,0 +1,59 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn delegated_handler_receives_the_target() {
+    fn delegation_app() -> Element {
+        let mut clicked = use_signal(|| "none".to_string());
+
+        rsx!(
+            rect {
+                "data-list": "fruits",
+                onclick: move |e: MouseEvent| {
+                    let target = e.get_target().dataset();
+                    clicked.set(format!(
+                        "{} {}",
+                        target.get("list").unwrap_or_default(),
+                        target.get("item").unwrap_or_default()
+                    ));
+                },
+                for item in ["apple", "orange"] {
+                    rect {
+                        "data-item": item,
+                        width: "100",
+                        height: "50",
+                        // Not listening, but the target anyway
+                        rect {
+                            width: "50",
+                            height: "50",
+                        }
+                    }
+                }
+            }
+            label { "{clicked}" }
+        )
+    }
+
+    let mut utils = launch_test(delegation_app);
+    let label = utils.root().get(1);
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (25.0, 75.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // The attributes of the closest element to the target and its ancestors
+    assert_eq!(label.get(0).text(), Some("fruits orange"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (75.0, 25.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("fruits apple"));
+}
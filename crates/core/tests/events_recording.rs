@@ -0,0 +1,78 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn counter_app() -> Element {
+    let mut count = use_signal(|| 0);
+
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            onclick: move |_| count += 1,
+            label {
+                "{count}"
+            }
+        }
+    )
+}
+
+#[tokio::test]
+pub async fn replay_recorded_events() {
+    let mut utils = launch_test(counter_app);
+    utils.wait_for_update().await;
+
+    utils.start_recording();
+    for _ in 0..3 {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+    }
+    let recording = utils.stop_recording().unwrap();
+
+    assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("3"));
+    assert_eq!(recording.events.len(), 3);
+
+    // Replay the serialized recording in a new app
+    let recording = EventsRecording::from_json(&recording.to_json()).unwrap();
+
+    let mut utils = launch_test(counter_app);
+    utils.wait_for_update().await;
+
+    utils.replay(&recording, f64::INFINITY).await;
+
+    assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("3"));
+}
+
+#[test]
+pub fn serialize_recorded_events() {
+    let mut recorder = EventsRecorder::new();
+    recorder.record(&PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("a".to_string()),
+        code: Code::KeyA,
+        modifiers: Modifiers::SHIFT,
+        repeat: false,
+    });
+    recorder.record(&PlatformEvent::Wheel {
+        name: EventName::Wheel,
+        scroll: (0.0, -10.0).into(),
+        cursor: (20.0, 30.0).into(),
+        delta: WheelDelta::Lines { x: 0.0, y: -1.0 },
+        modifiers: Modifiers::empty(),
+    });
+    let recording = recorder.finish();
+
+    let json = recording.to_json();
+    let deserialized = EventsRecording::from_json(&json).unwrap();
+
+    assert_eq!(deserialized.to_json(), json);
+    assert_eq!(deserialized.duration(), recording.duration());
+
+    assert!(matches!(
+        EventsRecording::from_json(&serde_json::json!([{ "type": "mouse" }])),
+        Err(EventsRecordingError::InvalidEvent(0))
+    ));
+}
@@ -72,3 +72,85 @@ pub async fn filedrop_events() {
     );
     assert_eq!(root.get(0).style().background, Fill::Color(Color::BLUE));
 }
+
+#[tokio::test]
+pub async fn filehover_events() {
+    fn filehover_events_app() -> Element {
+        let mut hovered = use_signal(Vec::<String>::new);
+        let mut dropped = use_signal(Vec::<String>::new);
+
+        let hovered_msg = hovered.read().join(", ");
+        let dropped_msg = dropped.read().join(", ");
+
+        rsx!(
+            rect {
+                height: "50%",
+                width: "100%",
+                onglobalfilehovercancelled: move |_| hovered.write().clear(),
+            }
+            rect {
+                height: "50%",
+                width: "100%",
+                onfilehover: move |e| {
+                    hovered.write().push(e.file_path.as_ref().unwrap().to_string_lossy().to_string())
+                },
+                onfiledrop: move |e| {
+                    hovered.write().clear();
+                    let position = e.get_element_coordinates();
+                    let path = e.file_path.as_ref().unwrap().to_string_lossy().to_string();
+                    dropped.write().push(format!("{path} {} {}", position.x, position.y));
+                },
+                label {
+                    "{hovered_msg}"
+                }
+                label {
+                    "{dropped_msg}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(filehover_events_app);
+    utils.wait_for_update().await;
+
+    // Hovering over the first rect does not reach the second one
+    utils.push_event(PlatformEvent::File {
+        name: EventName::FileHover,
+        cursor: (5., 5.).into(),
+        file_path: Some(PathBuf::from("a.rs")),
+    });
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(1);
+    assert_eq!(root.get(0).get(0).text(), Some(""));
+
+    // Hover two files and then cancel
+    for file_path in ["a.rs", "b.rs"] {
+        utils.push_event(PlatformEvent::File {
+            name: EventName::FileHover,
+            cursor: (5., 300.).into(),
+            file_path: Some(PathBuf::from(file_path)),
+        });
+    }
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).text(), Some("a.rs, b.rs"));
+
+    utils.push_event(PlatformEvent::File {
+        name: EventName::GlobalFileHoverCancelled,
+        cursor: (5., 300.).into(),
+        file_path: None,
+    });
+    utils.wait_for_update().await;
+    assert_eq!(root.get(0).get(0).text(), Some(""));
+
+    // Drop two files at once
+    for file_path in ["a.rs", "b.rs"] {
+        utils.push_event(PlatformEvent::File {
+            name: EventName::FileDrop,
+            cursor: (5., 300.).into(),
+            file_path: Some(PathBuf::from(file_path)),
+        });
+    }
+    utils.wait_for_update().await;
+    assert_eq!(root.get(1).get(0).text(), Some("a.rs 5 50, b.rs 5 50"));
+}
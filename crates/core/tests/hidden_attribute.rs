@@ -0,0 +1,63 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn hidden_subtrees_keep_their_state() {
+    #[component]
+    fn Counter() -> Element {
+        let mut count = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| count += 1,
+                label { "{count}" }
+            }
+        )
+    }
+
+    fn app() -> Element {
+        let mut hidden = use_signal(|| false);
+
+        rsx!(
+            rect {
+                hidden: "{hidden}",
+                Counter {}
+            }
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| hidden.toggle(),
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let tab = utils.root().get(0);
+    let counter = tab.get(0);
+    let toggle = utils.root().get(1);
+
+    utils.click((50.0, 50.0));
+    utils.wait_for_update().await;
+    assert_eq!(counter.get(0).get(0).text(), Some("1"));
+    assert_eq!(toggle.layout().unwrap().area.min_y(), 100.0);
+
+    // Hide the counter
+    utils.click((50.0, 150.0));
+    utils.wait_for_update().await;
+
+    assert!(tab.layout().is_none());
+    assert!(counter.layout().is_none());
+    assert_eq!(toggle.layout().unwrap().area.min_y(), 0.0);
+
+    // Show it again
+    utils.click((50.0, 50.0));
+    utils.wait_for_update().await;
+
+    assert_eq!(counter.layout().unwrap().area.min_y(), 0.0);
+    assert_eq!(toggle.layout().unwrap().area.min_y(), 100.0);
+    assert_eq!(counter.get(0).get(0).text(), Some("1"));
+}
@@ -0,0 +1,83 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn hit_padding_extends_the_clickable_area() {
+    fn hit_padding_app() -> Element {
+        let mut clicked = use_signal(|| false);
+        let background = if clicked() { "red" } else { "blue" };
+
+        rsx!(rect {
+            margin: "50",
+            width: "10",
+            height: "10",
+            hit_padding: "20",
+            background: "{background}",
+            onclick: move |_| clicked.toggle(),
+        })
+    }
+
+    let mut utils = launch_test(hit_padding_app);
+    let rect = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    // The layout isn't affected
+    assert_eq!(
+        rect.layout().unwrap().visible_area(),
+        Rect::new(Point2D::new(50.0, 50.0), Size2D::new(10.0, 10.0))
+    );
+
+    // Too far away
+    utils.click((15.0, 15.0));
+    utils.wait_for_update().await;
+    assert_eq!(rect.style().background, Fill::Color(Color::BLUE));
+
+    // Outside of the rect but inside the hit padding
+    utils.click((35.0, 35.0));
+    utils.wait_for_update().await;
+    assert_eq!(rect.style().background, Fill::Color(Color::RED));
+}
+
+#[tokio::test]
+pub async fn overlapping_hit_paddings_resolve_to_the_topmost() {
+    fn hit_padding_app() -> Element {
+        let mut clicked = use_signal(|| "none");
+        let (first, second) = match clicked() {
+            "first" => ("red", "blue"),
+            "second" => ("blue", "red"),
+            _ => ("blue", "blue"),
+        };
+
+        rsx!(
+            rect {
+                direction: "horizontal",
+                rect {
+                    width: "10",
+                    height: "10",
+                    hit_padding: "10",
+                    background: "{first}",
+                    onclick: move |_| clicked.set("first"),
+                }
+                rect {
+                    width: "10",
+                    height: "10",
+                    hit_padding: "10",
+                    background: "{second}",
+                    onclick: move |_| clicked.set("second"),
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(hit_padding_app);
+    let first = utils.root().get(0).get(0);
+    let second = utils.root().get(0).get(1);
+    utils.wait_for_update().await;
+
+    // Inside of the first rect and the hit padding of the second one, which is above
+    utils.click((5.0, 5.0));
+    utils.wait_for_update().await;
+    assert_eq!(first.style().background, Fill::Color(Color::BLUE));
+    assert_eq!(second.style().background, Fill::Color(Color::RED));
+}
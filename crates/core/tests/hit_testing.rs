@@ -0,0 +1,167 @@
+use freya::prelude::*;
+use freya_core::prelude::{is_point_inside, is_point_painted, nodes_at_point};
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn nodes_at_point_are_ordered_from_the_top() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                position: "absolute",
+                width: "100",
+                height: "100",
+            }
+            rect {
+                position: "absolute",
+                position_top: "50",
+                position_left: "50",
+                width: "100",
+                height: "100",
+            }
+            rect {
+                position: "absolute",
+                position_top: "50",
+                position_left: "50",
+                width: "100",
+                height: "100",
+                pointer_events: "none",
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    let root = utils.root();
+    let (first, second, third) = (root.get(0).id(), root.get(1).id(), root.get(2).id());
+
+    let fdom = utils.sdom().get();
+    let nodes = nodes_at_point(&fdom, (75.0, 75.0).into());
+    let first_position = nodes.iter().position(|node_id| *node_id == first);
+    let second_position = nodes.iter().position(|node_id| *node_id == second);
+    assert_eq!(second_position, Some(0));
+    assert!(first_position > second_position);
+
+    // Elements without pointer events are never hit
+    assert!(!nodes.contains(&third));
+    assert!(!is_point_inside(&fdom, third, (75.0, 75.0).into()));
+
+    // Covered Nodes are still inside the point
+    assert!(is_point_inside(&fdom, first, (75.0, 75.0).into()));
+    assert!(!is_point_inside(&fdom, first, (125.0, 125.0).into()));
+}
+
+#[tokio::test]
+pub async fn nodes_at_point_respect_the_clipping() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                overflow: "clip",
+                rect {
+                    width: "200",
+                    height: "200",
+                }
+            }
+            rect {
+                width: "100",
+                height: "100",
+                clip_path: "circle()",
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    let root = utils.root();
+    let (overflowing, circle) = (root.get(0).get(0).id(), root.get(1).id());
+
+    let fdom = utils.sdom().get();
+    assert!(is_point_inside(&fdom, overflowing, (50.0, 50.0).into()));
+    assert!(!is_point_inside(&fdom, overflowing, (150.0, 50.0).into()));
+
+    assert!(is_point_inside(&fdom, circle, (50.0, 150.0).into()));
+    assert!(!is_point_inside(&fdom, circle, (5.0, 105.0).into()));
+}
+
+#[tokio::test]
+pub async fn nodes_at_point_respect_the_rotations() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "200",
+                height: "200",
+                main_align: "center",
+                cross_align: "center",
+                rect {
+                    rotate: "90deg",
+                    width: "100",
+                    height: "20",
+                    rect {
+                        width: "50",
+                        height: "20",
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    let rotated = utils.root().get(0).get(0);
+    let (rotated, child) = (rotated.id(), rotated.get(0).id());
+
+    // Painted vertically, from (90, 50) to (110, 150)
+    let fdom = utils.sdom().get();
+    assert!(is_point_inside(&fdom, rotated, (100.0, 60.0).into()));
+    assert!(!is_point_inside(&fdom, rotated, (60.0, 100.0).into()));
+
+    // The children are rotated along with it, the left half ends up on the top
+    assert!(is_point_inside(&fdom, child, (100.0, 60.0).into()));
+    assert!(!is_point_inside(&fdom, child, (100.0, 140.0).into()));
+}
+
+#[tokio::test]
+pub async fn only_painted_points_are_hit() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                rect {
+                    width: "100",
+                    height: "100",
+                    background: "red",
+                }
+                rect {
+                    width: "100",
+                    height: "100",
+                    border: "2 solid blue",
+                }
+                label {
+                    "Hello"
+                }
+                rect {
+                    width: "100",
+                    height: "100",
+                    background: "red",
+                    pointer_events: "none",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let fdom = utils.sdom().get();
+    // Background and border
+    assert!(is_point_painted(&fdom, (50.0, 50.0).into()));
+    assert!(is_point_painted(&fdom, (50.0, 150.0).into()));
+    // Text
+    assert!(is_point_painted(&fdom, (5.0, 205.0).into()));
+    // Elements without pointer events let them through
+    assert!(!is_point_painted(&fdom, (50.0, 280.0).into()));
+    // Nothing is painted, not even by the transparent container
+    assert!(!is_point_painted(&fdom, (300.0, 50.0).into()));
+}
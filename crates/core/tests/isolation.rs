@@ -0,0 +1,94 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn isolated_layers_stay_in_their_subtree() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                isolation: "isolate",
+                rect {
+                    test_id: "rect",
+                    layer: "-99",
+                    label { "isolated" }
+                }
+            }
+            rect {
+                test_id: "rect",
+                label { "sibling" }
+            }
+            rect {
+                rect {
+                    test_id: "rect",
+                    layer: "-99",
+                    label { "not isolated" }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    // From the bottom to the top
+    let texts = utils
+        .find_all_by_test_id("rect")
+        .into_iter()
+        .map(|node| node.get(0).get(0).text().map(str::to_string))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        texts,
+        vec![
+            Some("isolated".to_string()),
+            Some("sibling".to_string()),
+            Some("not isolated".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+pub async fn isolated_layers_dont_receive_events_above_siblings() {
+    fn app() -> Element {
+        let mut state = use_signal(String::new);
+
+        rsx!(
+            rect {
+                isolation: "isolate",
+                rect {
+                    height: "100",
+                    width: "100",
+                    layer: "-99",
+                    onclick: move |_: MouseEvent| {
+                        state.set("isolated".to_string());
+                    },
+                }
+            }
+            rect {
+                position: "absolute",
+                height: "100",
+                width: "100",
+                onclick: move |_: MouseEvent| {
+                    state.set("sibling".to_string());
+                },
+                label {
+                    "{state}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+
+    let label = utils.root().get(1).get(0);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: CursorPoint::new(50.0, 50.0),
+        button: Some(MouseButton::Left),
+    });
+
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("sibling"));
+}
@@ -0,0 +1,67 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn keyboard_repeat_and_modifiers() {
+    fn keyboard_app() -> Element {
+        let mut pressed = use_signal(|| "none".to_string());
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onkeydown: move |e: KeyboardEvent| {
+                    pressed.set(format!(
+                        "repeat={} control={} alt={} shift={} meta={}",
+                        e.is_repeat(),
+                        e.is_control_pressed(),
+                        e.is_alt_pressed(),
+                        e.is_shift_pressed(),
+                        e.is_meta_pressed()
+                    ))
+                },
+                label {
+                    "{pressed}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(keyboard_app);
+
+    let root = utils.root();
+    let label = root.get(0).get(0);
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("none"));
+
+    // Hold a key with Control and Shift
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("a".to_string()),
+        code: Code::KeyA,
+        modifiers: Modifiers::CONTROL | Modifiers::SHIFT,
+        repeat: true,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some("repeat=true control=true alt=false shift=true meta=false")
+    );
+
+    // A new press with Alt and Meta
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Character("b".to_string()),
+        code: Code::KeyB,
+        modifiers: Modifiers::ALT | Modifiers::META,
+        repeat: false,
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        label.get(0).text(),
+        Some("repeat=false control=false alt=true shift=false meta=true")
+    );
+}
@@ -0,0 +1,34 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn dump_layout() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "100",
+                height: "50",
+                padding: "10",
+                background: "red",
+                label { "Hello" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let dump = utils.sdom().get().dump_layout();
+    let rect = &dump["children"][0];
+
+    assert_eq!(rect["tag"], "rect");
+    assert_eq!(rect["area"]["width"], 100.0);
+    assert_eq!(rect["area"]["height"], 50.0);
+    assert_eq!(rect["inner_area"]["x"], 10.0);
+    assert_eq!(rect["style"]["width"], "100");
+    assert_eq!(rect["children"][0]["tag"], "label");
+    assert_eq!(rect["children"][0]["children"][0]["text"], "Hello");
+
+    // Dumps of the same tree are equal
+    assert_eq!(utils.dump_layout(), utils.dump_layout());
+}
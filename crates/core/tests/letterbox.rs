@@ -0,0 +1,54 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn fixed_resolution() {
+    fn fixed_resolution_app() -> Element {
+        let mut clicks = use_signal(|| 0);
+
+        rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                onclick: move |_| clicks += 1,
+                label {
+                    "{clicks}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        fixed_resolution_app,
+        TestingConfig {
+            size: Size2D::new(1000.0, 1000.0),
+            fixed_resolution: Some(Size2D::new(500.0, 250.0)),
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    // The app is laid out at the fixed resolution
+    let root = utils.root().get(0);
+    assert_eq!(root.layout().unwrap().area.width(), 500.0);
+    assert_eq!(root.layout().unwrap().area.height(), 250.0);
+
+    // Scaled by 2 and centered vertically, so the top bar goes from 0 to 250
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (100.0, 100.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("0"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (100.0, 300.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("1"));
+}
@@ -0,0 +1,42 @@
+use freya::prelude::*;
+use freya_engine::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn mask_attribute() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                mask: "rgb(0, 0, 0, 128)",
+            }
+            rect {
+                mask: "linear-gradient(white 0%, transparent 100%)",
+            }
+            rect {
+                mask_data: static_bytes(&[1, 2, 3]),
+            }
+            rect {
+                mask: "not a mask",
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+
+    assert_eq!(
+        root.get(0).style().mask,
+        Some(Mask::Fill(Fill::Color(Color::from_argb(128, 0, 0, 0))))
+    );
+    assert!(matches!(
+        root.get(1).style().mask,
+        Some(Mask::Fill(Fill::LinearGradient(_)))
+    ));
+    assert_eq!(
+        root.get(2).style().mask,
+        Some(Mask::Image(AttributesBytes::Static(&[1, 2, 3])))
+    );
+    assert_eq!(root.get(3).style().mask, None);
+}
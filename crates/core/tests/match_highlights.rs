@@ -0,0 +1,43 @@
+use freya::prelude::*;
+use freya_engine::prelude::Color;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn match_highlights() {
+    fn match_highlights_app() -> Element {
+        let mut current_match = use_signal(|| 0);
+
+        rsx!(
+            paragraph {
+                width: "100%",
+                height: "100%",
+                match_highlights: text_highlights(vec![(0, 5), (24, 29)]),
+                current_match: "{current_match}",
+                current_match_color: "red",
+                onclick: move |_| current_match += 1,
+                text {
+                    "Freya is a GUI library, Freya is fast"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(match_highlights_app);
+    utils.wait_for_update().await;
+
+    let paragraph = utils.root().get(0);
+    let cursor = &paragraph.state().cursor;
+    assert_eq!(cursor.match_highlights, Some(vec![(0, 5), (24, 29)]));
+    assert_eq!(cursor.current_match, Some(0));
+    assert_eq!(cursor.current_match_color, Color::RED);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    let paragraph = utils.root().get(0);
+    assert_eq!(paragraph.state().cursor.current_match, Some(1));
+}
@@ -0,0 +1,55 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn min_lines() {
+    fn min_lines_app() -> Element {
+        rsx!(
+            label {
+                font_size: "20",
+                "A"
+            }
+            label {
+                font_size: "20",
+                min_lines: "3",
+                "A"
+            }
+            label {
+                font_size: "20",
+                min_lines: "3",
+                max_lines: "2",
+                "A"
+            }
+            label {
+                font_size: "20",
+                min_lines: "2",
+                "A\nB\nC"
+            }
+        )
+    }
+
+    let mut utils = launch_test(min_lines_app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    let line_height = root.get(0).layout().unwrap().area.height();
+    assert!(line_height > 0.0);
+
+    // A single character reserves the height of three lines
+    assert_eq!(
+        root.get(1).layout().unwrap().area.height(),
+        line_height * 3.0
+    );
+
+    // It can't reserve more lines than its maximum
+    assert_eq!(
+        root.get(2).layout().unwrap().area.height(),
+        line_height * 2.0
+    );
+
+    // Texts with more lines are as tall as usual
+    assert_eq!(
+        root.get(3).layout().unwrap().area.height(),
+        line_height * 3.0
+    );
+}
@@ -0,0 +1,60 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn mounted_events() {
+    fn mounted_events_app() -> Element {
+        let mut items = use_signal(|| 1);
+        let mut mounted = use_signal(Vec::<(usize, Area)>::new);
+
+        let last_mounted = mounted
+            .read()
+            .last()
+            .map(|(i, area)| format!("{i} {} {} {}", area.min_y(), area.width(), area.height()))
+            .unwrap_or_default();
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                onclick: move |_| items += 1,
+                {(0..items()).map(|i| rsx!(
+                    rect {
+                        key: "{i}",
+                        width: "100",
+                        height: "50",
+                        onmounted: move |e| mounted.write().push((i, e.get_area())),
+                    }
+                ))}
+                label {
+                    "{mounted.read().len()} {last_mounted}"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(mounted_events_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    let label = utils.root().get(0).get(1);
+    assert_eq!(label.get(0).text(), Some("1 0 0 100 50"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Only the new node was mounted
+    let label = utils.root().get(0).get(2);
+    assert_eq!(label.get(0).text(), Some("2 1 50 100 50"));
+
+    utils.wait_for_update().await;
+
+    // It's not emitted again in the next layouts
+    let label = utils.root().get(0).get(2);
+    assert_eq!(label.get(0).text(), Some("2 1 50 100 50"));
+}
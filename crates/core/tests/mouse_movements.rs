@@ -0,0 +1,94 @@
+use freya::prelude::*;
+use freya_core::prelude::{coalesce_mouse_movements, EventsQueue};
+use freya_testing::prelude::*;
+
+fn mouse_over(x: f64) -> PlatformEvent {
+    PlatformEvent::Mouse {
+        name: EventName::MouseOver,
+        cursor: (x, 5.0).into(),
+        button: None,
+    }
+}
+
+fn cursors(events: &EventsQueue) -> Vec<(EventName, f64)> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            PlatformEvent::Mouse { name, cursor, .. } => Some((*name, cursor.x)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+pub fn coalesce_consecutive_mouse_movements() {
+    let click = PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (20.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    };
+    let mut events = EventsQueue::from_iter([
+        mouse_over(0.0),
+        mouse_over(10.0),
+        mouse_over(20.0),
+        click,
+        mouse_over(30.0),
+        mouse_over(40.0),
+    ]);
+
+    coalesce_mouse_movements(&mut events);
+
+    assert_eq!(
+        cursors(&events),
+        vec![
+            (EventName::MouseOver, 20.0),
+            (EventName::Click, 20.0),
+            (EventName::MouseOver, 40.0)
+        ]
+    );
+}
+
+#[tokio::test]
+pub async fn coalesced_mouse_movements_enter_and_leave() {
+    fn cells_app() -> Element {
+        let mut log = use_signal(Vec::<String>::new);
+
+        rsx!(
+            rect {
+                direction: "horizontal",
+                for cell in ["a", "b", "c"] {
+                    rect {
+                        key: "{cell}",
+                        width: "10",
+                        height: "10",
+                        onmouseenter: move |_| log.write().push(format!("enter {cell}")),
+                        onmouseleave: move |_| log.write().push(format!("leave {cell}")),
+                    }
+                }
+            }
+            label {
+                "{log.read().join(\", \")}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(cells_app);
+    utils.wait_for_update().await;
+
+    utils.push_event(mouse_over(5.0));
+    utils.wait_for_update().await;
+
+    // Crossing the middle cell faster than a frame only hovers the last one
+    let mut events = EventsQueue::from_iter([mouse_over(15.0), mouse_over(25.0)]);
+    coalesce_mouse_movements(&mut events);
+    for event in events {
+        utils.push_event(event);
+    }
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.root().get(1).get(0).text(),
+        Some("enter a, leave a, enter c")
+    );
+}
@@ -0,0 +1,163 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn later_siblings_receive_the_clicks() {
+    fn app() -> Element {
+        let mut clicked = use_signal(|| "none");
+
+        rsx!(
+            label { "{clicked}" }
+            rect {
+                position: "absolute",
+                position_top: "0",
+                position_left: "0",
+                width: "100",
+                height: "100",
+                onclick: move |_| clicked.set("first"),
+            }
+            rect {
+                position: "absolute",
+                position_top: "50",
+                position_left: "50",
+                width: "100",
+                height: "100",
+                onclick: move |_| clicked.set("second"),
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let label = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    utils.click((75.0, 75.0));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("second"));
+
+    utils.click((25.0, 25.0));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("first"));
+}
+
+#[tokio::test]
+pub async fn lower_layers_receive_the_clicks() {
+    fn app() -> Element {
+        let mut clicked = use_signal(|| "none");
+
+        rsx!(
+            label { "{clicked}" }
+            rect {
+                layer: "-1",
+                position: "absolute",
+                position_top: "0",
+                position_left: "0",
+                width: "100",
+                height: "100",
+                onclick: move |_| clicked.set("first"),
+            }
+            rect {
+                position: "absolute",
+                position_top: "50",
+                position_left: "50",
+                width: "100",
+                height: "100",
+                onclick: move |_| clicked.set("second"),
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let label = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    utils.click((75.0, 75.0));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("first"));
+
+    utils.click((125.0, 125.0));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("second"));
+}
+
+#[tokio::test]
+pub async fn inserted_siblings_keep_the_order_of_the_document() {
+    fn app() -> Element {
+        let mut clicked = use_signal(|| "none");
+        let mut show_first = use_signal(|| false);
+
+        rsx!(
+            label {
+                onclick: move |_| show_first.set(true),
+                "{clicked}"
+            }
+            if show_first() {
+                rect {
+                    position: "absolute",
+                    position_top: "50",
+                    position_left: "0",
+                    width: "100",
+                    height: "100",
+                    onclick: move |_| clicked.set("first"),
+                }
+            }
+            rect {
+                position: "absolute",
+                position_top: "50",
+                position_left: "0",
+                width: "100",
+                height: "100",
+                onclick: move |_| clicked.set("second"),
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let label = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    utils.click((5.0, 5.0));
+    utils.wait_for_update().await;
+
+    // The first rect was inserted after the second one, but it still comes before it in the document
+    utils.click((50.0, 100.0));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("second"));
+}
+
+#[tokio::test]
+pub async fn clicks_pass_through_the_topmost_element_without_pointer_events() {
+    fn app() -> Element {
+        let mut clicked = use_signal(|| "none");
+
+        rsx!(
+            label { "{clicked}" }
+            rect {
+                position: "absolute",
+                position_top: "0",
+                position_left: "0",
+                width: "100",
+                height: "100",
+                onclick: move |_| clicked.set("first"),
+            }
+            rect {
+                layer: "-1",
+                position: "absolute",
+                position_top: "0",
+                position_left: "0",
+                width: "100",
+                height: "100",
+                pointer_events: "none",
+                onclick: move |_| clicked.set("second"),
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let label = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    utils.click((50.0, 50.0));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("first"));
+}
@@ -0,0 +1,61 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn color_changes_dont_relayout() {
+    fn color_app() -> Element {
+        let mut toggled = use_signal(|| false);
+        let (background, color) = if toggled() {
+            ("red", "blue")
+        } else {
+            ("blue", "red")
+        };
+
+        rsx!(
+            rect {
+                background: "{background}",
+                color: "{color}",
+                onclick: move |_| toggled.toggle(),
+                label { "Hello, World!" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(color_app);
+    let label = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    let area = label.layout().unwrap().area;
+
+    utils.click((5.0, 5.0));
+    let (must_repaint, must_relayout) = utils.wait_for_update().await;
+
+    assert!(must_repaint);
+    assert!(!must_relayout);
+    assert_eq!(label.layout().unwrap().area, area);
+}
+
+#[tokio::test]
+pub async fn font_size_changes_relayout() {
+    fn font_size_app() -> Element {
+        let mut toggled = use_signal(|| false);
+        let font_size = if toggled() { "30" } else { "16" };
+
+        rsx!(
+            rect {
+                font_size: "{font_size}",
+                onclick: move |_| toggled.toggle(),
+                label { "Hello, World!" }
+            }
+        )
+    }
+
+    let mut utils = launch_test(font_size_app);
+    utils.wait_for_update().await;
+
+    utils.click((5.0, 5.0));
+    let (must_repaint, must_relayout) = utils.wait_for_update().await;
+
+    assert!(must_repaint);
+    assert!(must_relayout);
+}
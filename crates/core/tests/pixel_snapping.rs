@@ -0,0 +1,41 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn pixel_snapping() {
+    fn pixel_snapping_app() -> Element {
+        rsx!(
+            rect {
+                rect {
+                    pixel_snapping: "false",
+                    label {
+                        "Hello, World!"
+                    }
+                }
+                label {
+                    "Hello, World!"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        pixel_snapping_app,
+        TestingConfig {
+            render_settings: RenderSettings {
+                pixel_snapping: true,
+                ..RenderSettings::default()
+            },
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+
+    // The global setting is used by default
+    assert!(root.get(1).state().style.pixel_snapping);
+
+    // But elements can disable it for their descendants, e.g. for smooth animations
+    assert!(!root.get(0).get(0).state().style.pixel_snapping);
+}
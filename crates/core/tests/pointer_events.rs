@@ -189,3 +189,81 @@ pub async fn pointer_events_from_touch() {
         Some(format!("{:?}", vec!["enter", "over", "down", "up"]).as_str())
     );
 }
+
+#[tokio::test]
+pub async fn pointer_events_with_scale_factor() {
+    fn pointer_events_app() -> Element {
+        let mut coordinates = use_signal(|| None);
+
+        let onpointerdown = move |e: PointerEvent| {
+            coordinates.set(Some((
+                e.get_screen_coordinates(),
+                e.get_element_coordinates(),
+            )))
+        };
+
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                padding: "10",
+                rect {
+                    height: "100",
+                    width: "100",
+                    onpointerdown,
+                    label { "{coordinates:?}" }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        pointer_events_app,
+        TestingConfig {
+            scale_factor: 2.0,
+            ..TestingConfig::default()
+        },
+    );
+
+    let root = utils.root().get(0);
+    let rect = root.get(0);
+    let label = rect.get(0);
+
+    utils.wait_for_update().await;
+
+    // The layout is measured in physical pixels
+    assert_eq!(rect.layout().unwrap().area.min_x(), 20.0);
+    assert_eq!(rect.layout().unwrap().area.width(), 200.0);
+
+    // Platform events come in physical pixels
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::MouseDown,
+        cursor: CursorPoint::new(120.0, 60.0),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // But the event data is in logical pixels
+    let screen_coordinates = CursorPoint::new(60.0, 30.0);
+    let element_coordinates = CursorPoint::new(50.0, 20.0);
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", Some((screen_coordinates, element_coordinates))).as_str())
+    );
+
+    utils.push_event(PlatformEvent::Touch {
+        name: EventName::TouchStart,
+        location: CursorPoint::new(40.0, 40.0),
+        finger_id: 1,
+        phase: TouchPhase::Started,
+        force: None,
+    });
+    utils.wait_for_update().await;
+
+    let screen_coordinates = CursorPoint::new(20.0, 20.0);
+    let element_coordinates = CursorPoint::new(10.0, 10.0);
+    assert_eq!(
+        label.get(0).text(),
+        Some(format!("{:?}", Some((screen_coordinates, element_coordinates))).as_str())
+    );
+}
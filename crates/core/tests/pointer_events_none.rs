@@ -0,0 +1,85 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn overlays_without_pointer_events_dont_block_clicks() {
+    fn app() -> Element {
+        let mut clicked = use_signal(|| "none");
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| clicked.set("button"),
+                label { "{clicked}" }
+            }
+            rect {
+                position: "absolute",
+                position_top: "0",
+                position_left: "0",
+                width: "100",
+                height: "100",
+                background: "rgb(0, 0, 0, 0.5)",
+                pointer_events: "none",
+                onclick: move |_| clicked.set("overlay"),
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let label = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    utils.click((50.0, 50.0));
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("button"));
+}
+
+#[tokio::test]
+pub async fn children_can_enable_pointer_events_again() {
+    fn app() -> Element {
+        let mut clicked = use_signal(|| "none");
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| clicked.set("button"),
+                label { "{clicked}" }
+            }
+            rect {
+                position: "absolute",
+                position_top: "0",
+                position_left: "0",
+                width: "100",
+                height: "100",
+                pointer_events: "none",
+                rect {
+                    width: "50",
+                    height: "50",
+                    background: "red",
+                    rect {
+                        width: "25",
+                        height: "25",
+                        pointer_events: "auto",
+                        onclick: move |_| clicked.set("child"),
+                    }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    let label = utils.root().get(0).get(0);
+    utils.wait_for_update().await;
+
+    // The red rect inherits `none`, so it doesn't block the button
+    utils.click((40.0, 40.0));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("button"));
+
+    utils.click((10.0, 10.0));
+    utils.wait_for_update().await;
+    assert_eq!(label.get(0).text(), Some("child"));
+}
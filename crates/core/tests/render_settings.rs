@@ -0,0 +1,51 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn render_settings() {
+    fn render_settings_app() -> Element {
+        rsx!(
+            rect {
+                rect {
+                    antialias: "false",
+                    text_hinting: "slight",
+                    text_subpixel: "true",
+                    label {
+                        "Hello, World!"
+                    }
+                }
+                label {
+                    "Hello, World!"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        render_settings_app,
+        TestingConfig {
+            render_settings: RenderSettings {
+                antialias: true,
+                text_hinting: TextHinting::None,
+                text_subpixel: false,
+                ..RenderSettings::default()
+            },
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+
+    // The global settings are used by default
+    let label = root.get(1).state().clone();
+    assert!(label.style.antialias);
+    assert_eq!(label.font_style.text_hinting, TextHinting::None);
+    assert!(!label.font_style.text_subpixel);
+
+    // But elements can override them for their descendants
+    let label = root.get(0).get(0).state().clone();
+    assert!(!label.style.antialias);
+    assert_eq!(label.font_style.text_hinting, TextHinting::Slight);
+    assert!(label.font_style.text_subpixel);
+}
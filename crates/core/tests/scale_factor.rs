@@ -0,0 +1,44 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn change_scale_factor() {
+    fn scale_factor_app() -> Element {
+        rsx!(
+            rect {
+                height: "100%",
+                width: "100%",
+                padding: "10",
+                rect {
+                    height: "100",
+                    width: "100",
+                    label { "Hello" }
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(scale_factor_app);
+
+    let root = utils.root().get(0);
+    let rect = root.get(0);
+
+    utils.wait_for_update().await;
+
+    assert_eq!(rect.layout().unwrap().area.min_x(), 10.0);
+    assert_eq!(rect.layout().unwrap().area.width(), 100.0);
+
+    // Move the Window to a 2x monitor
+    utils.set_scale_factor(2.0);
+    utils.wait_for_update().await;
+
+    assert_eq!(rect.layout().unwrap().area.min_x(), 20.0);
+    assert_eq!(rect.layout().unwrap().area.width(), 200.0);
+
+    // And back to the 1x monitor
+    utils.set_scale_factor(1.0);
+    utils.wait_for_update().await;
+
+    assert_eq!(rect.layout().unwrap().area.min_x(), 10.0);
+    assert_eq!(rect.layout().unwrap().area.width(), 100.0);
+}
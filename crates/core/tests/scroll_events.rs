@@ -0,0 +1,76 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn scroll_events() {
+    fn scroll_events_app() -> Element {
+        let mut offset = use_signal(|| 0);
+        let mut scrolls = use_signal(Vec::<ScrollData>::new);
+
+        let last_scroll = scrolls
+            .read()
+            .last()
+            .map(|e| {
+                format!(
+                    "{} {} {} {}",
+                    e.get_offset_y(),
+                    e.get_delta_y(),
+                    e.is_at_start_y(),
+                    e.is_at_end_y()
+                )
+            })
+            .unwrap_or_default();
+
+        rsx!(
+            rect {
+                height: "200",
+                width: "100%",
+                overflow: "clip",
+                offset_y: "{offset}",
+                onclick: move |_| offset -= 150,
+                onscroll: move |e: ScrollEvent| scrolls.write().push(e.data().as_ref().clone()),
+                rect {
+                    height: "500",
+                    width: "100%",
+                }
+            }
+            label {
+                "{scrolls.read().len()} {last_scroll}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(scroll_events_app);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Not emitted for the initial offsets
+    let label = utils.root().get(1);
+    assert_eq!(label.get(0).text(), Some("0 "));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(label.get(0).text(), Some("1 150 150 false false"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The content can be scrolled 300 pixels at most
+    assert_eq!(label.get(0).text(), Some("2 300 150 false true"));
+
+    utils.wait_for_update().await;
+
+    // It's not emitted again while the offsets stay the same
+    assert_eq!(label.get(0).text(), Some("2 300 150 false true"));
+}
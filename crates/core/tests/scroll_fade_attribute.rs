@@ -0,0 +1,28 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn scroll_fade_attribute() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                scroll_fade: "24",
+            }
+            rect {
+                scroll_fade: "-10",
+            }
+            rect {
+                scroll_fade: "not a length",
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+
+    assert_eq!(root.get(0).style().scroll_fade, 24.0);
+    assert_eq!(root.get(1).style().scroll_fade, 0.0);
+    assert_eq!(root.get(2).style().scroll_fade, 0.0);
+}
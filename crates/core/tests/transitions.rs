@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+use tokio::time::sleep;
+
+#[tokio::test]
+pub async fn transition_width() {
+    fn transition_app() -> Element {
+        let mut expanded = use_signal(|| false);
+
+        rsx!(rect {
+            transition: "width 100ms",
+            width: if expanded() { "200" } else { "100" },
+            height: "50",
+            onclick: move |_| expanded.toggle(),
+        })
+    }
+
+    let mut utils = launch_test(transition_app);
+    utils.wait_for_update().await;
+
+    let rect = utils.root().get(0);
+    assert_eq!(rect.layout().unwrap().area.width(), 100.0);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // Halfway through the transition
+    sleep(Duration::from_millis(50)).await;
+    utils.wait_for_update().await;
+    let width = rect.layout().unwrap().area.width();
+    assert!(width > 100.0 && width < 200.0);
+
+    // Finished
+    sleep(Duration::from_millis(100)).await;
+    utils.wait_for_update().await;
+    assert_eq!(rect.layout().unwrap().area.width(), 200.0);
+}
+
+#[tokio::test]
+pub async fn transition_skips_non_interpolatable_values() {
+    fn transition_app() -> Element {
+        let mut expanded = use_signal(|| false);
+
+        rsx!(rect {
+            transition: "width 100ms",
+            width: if expanded() { "fill" } else { "100" },
+            height: "50",
+            onclick: move |_| expanded.toggle(),
+        })
+    }
+
+    let mut utils = launch_test(transition_app);
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).layout().unwrap().area.width(), 500.0);
+}
+
+#[tokio::test]
+pub async fn transition_reduced_motion() {
+    fn transition_app() -> Element {
+        let mut expanded = use_signal(|| false);
+
+        rsx!(rect {
+            transition: "width 1s",
+            width: if expanded() { "200" } else { "100" },
+            height: "50",
+            onclick: move |_| expanded.toggle(),
+        })
+    }
+
+    let mut utils = launch_test_with_config(
+        transition_app,
+        TestingConfig {
+            reduced_motion: true,
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The new value is applied right away
+    let rect = utils.root().get(0);
+    assert_eq!(rect.layout().unwrap().area.width(), 200.0);
+}
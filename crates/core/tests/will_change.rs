@@ -0,0 +1,97 @@
+use freya::prelude::*;
+use freya_node_state::WillChange;
+use freya_testing::prelude::*;
+
+#[tokio::test]
+pub async fn will_change_attribute() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                will_change: "transform, opacity",
+            }
+            rect {
+                will_change: "opacity",
+            }
+            rect {}
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let root = utils.root();
+    assert_eq!(
+        root.get(0).style().will_change,
+        WillChange {
+            transform: true,
+            opacity: true
+        }
+    );
+    assert_eq!(
+        root.get(1).style().will_change,
+        WillChange {
+            transform: false,
+            opacity: true
+        }
+    );
+    assert!(!root.get(2).style().will_change.is_any());
+}
+
+#[tokio::test]
+pub async fn composited_changes() {
+    fn app() -> Element {
+        let mut degrees = use_signal(|| 0);
+        let mut background = use_signal(|| "red");
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                will_change: "transform",
+                rotate: "{degrees}deg",
+                onclick: move |_| degrees += 45,
+                label { "Spinning" }
+            }
+            rect {
+                width: "100",
+                height: "100",
+                will_change: "transform",
+                rotate: "{degrees}deg",
+                background: "{background}",
+                onclick: move |_| {
+                    degrees += 45;
+                    background.set("blue");
+                },
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+    utils.sdom().get_mut().take_dirty_nodes();
+    assert!(utils.sdom().get_mut().take_composited_changes().is_empty());
+
+    let root = utils.root();
+    let first = root.get(0).id();
+    let second = root.get(1).id();
+
+    // Only the rotation of the rects changed
+    utils.click((50.0, 50.0));
+    utils.wait_for_update().await;
+    let changes = utils.sdom().get_mut().take_composited_changes();
+    assert_eq!(
+        changes.get(&first),
+        Some(&WillChange {
+            transform: true,
+            opacity: false
+        })
+    );
+    assert!(changes.contains_key(&second));
+
+    // The background of the second one changed too, so it must be rendered again
+    utils.click((50.0, 150.0));
+    utils.wait_for_update().await;
+    let changes = utils.sdom().get_mut().take_composited_changes();
+    assert!(changes.contains_key(&first));
+    assert!(!changes.contains_key(&second));
+}
@@ -0,0 +1,47 @@
+//! ### Data attributes
+//!
+//! Any attribute whose name starts with `data-` is stored as metadata of the element, like the web `data-*` attributes.
+//! They are not used for the layout nor the styling, so changing them does not cause any relayout or repaint.
+//!
+//! The events carry the data attributes of the element that received them, see `get_dataset`:
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! fn app() -> Element {
+//!     rsx!(
+//!         for item in ["Apples", "Oranges"] {
+//!             rect {
+//!                 "data-item": item,
+//!                 onclick: |e: MouseEvent| println!("Clicked {:?}", e.get_dataset().get("item")),
+//!                 label {
+//!                     "{item}"
+//!                 }
+//!             }
+//!         }
+//!     )
+//! }
+//! ```
+//!
+//! Pointer events also carry the element that originated them, even if it isn't listening, along with the data attributes
+//! of it and its ancestors. This way a single handler in a common ancestor can handle the events of many elements, see `get_target`:
+//!
+//! ```rust, no_run
+//! # use freya::prelude::*;
+//! fn app() -> Element {
+//!     rsx!(
+//!         rect {
+//!             onclick: |e: MouseEvent| println!("Clicked {:?}", e.get_target().dataset().get("item")),
+//!             for item in ["Apples", "Oranges"] {
+//!                 rect {
+//!                     "data-item": item,
+//!                     label {
+//!                         "{item}"
+//!                     }
+//!                 }
+//!             }
+//!         }
+//!     )
+//! }
+//! ```
+//!
+//! They are also useful to find elements in tests, see `TestNode::get_by_data` in `freya-testing`.
@@ -11,6 +11,7 @@
 //! - `line_height`
 //! - `align`
 //! - `max_lines`
+//! - `min_lines`
 //! - `letter_spacing`
 //! - `word_spacing`
 //! - `decoration`
@@ -1,3 +1,4 @@
 pub mod color_syntax;
+pub mod data_attributes;
 pub mod inheritance;
 pub mod size_unit;
@@ -175,6 +175,8 @@ builder_constructors! {
     rect {
         #[doc = include_str!("_docs/attributes/padding.md")]
         padding: String,
+        #[doc = include_str!("_docs/attributes/box_sizing.md")]
+        box_sizing: String,
         #[doc = include_str!("_docs/attributes/width_height.md")]
         height: String,
         width: String,
@@ -186,9 +188,14 @@ builder_constructors! {
         max_width: String,
         #[doc = include_str!("_docs/attributes/background.md")]
         background: String,
+        #[doc = include_str!("_docs/attributes/background_clip.md")]
+        background_clip: String,
         #[doc = include_str!("_docs/attributes/border.md")]
         border: String,
         border_align: String,
+        #[doc = include_str!("_docs/attributes/outline.md")]
+        outline: String,
+        outline_offset: String,
         #[doc = include_str!("_docs/attributes/direction.md")]
         direction: String,
         #[doc = include_str!("_docs/attributes/shadow.md")]
@@ -213,10 +220,14 @@ builder_constructors! {
         cross_align: String,
         #[doc = include_str!("_docs/attributes/text_align.md")]
         text_align: String,
+        #[doc = include_str!("_docs/attributes/text_centering.md")]
+        text_centering: String,
         #[doc = include_str!("_docs/attributes/rotate.md")]
         rotate: String,
         #[doc = include_str!("_docs/attributes/overflow.md")]
         overflow: String,
+        #[doc = include_str!("_docs/attributes/clip_path.md")]
+        clip_path: String,
         #[doc = include_str!("_docs/attributes/margin.md")]
         margin: String,
         #[doc = include_str!("_docs/attributes/position.md")]
@@ -227,16 +238,82 @@ builder_constructors! {
         position_left: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/transition.md")]
+        transition: String,
+        #[doc = include_str!("_docs/attributes/filter.md")]
+        filter: String,
+        #[doc = include_str!("_docs/attributes/animation.md")]
+        animation: String,
+        #[doc = include_str!("_docs/attributes/exit_animation.md")]
+        exit_animation: String,
+        #[doc = include_str!("_docs/attributes/backdrop_blur.md")]
+        backdrop_blur: String,
         #[doc = include_str!("_docs/attributes/content.md")]
         content: String,
+        #[doc = include_str!("_docs/attributes/content_visibility.md")]
+        content_visibility: String,
+        contain_intrinsic_size: String,
+        #[doc = include_str!("_docs/attributes/cache.md")]
+        cache: String,
+        #[doc = include_str!("_docs/attributes/will_change.md")]
+        will_change: String,
+        #[doc = include_str!("_docs/attributes/antialias.md")]
+        antialias: String,
+        #[doc = include_str!("_docs/attributes/text_hinting.md")]
+        text_hinting: String,
+        text_subpixel: String,
+        #[doc = include_str!("_docs/attributes/shader.md")]
+        shader: String,
+        shader_position: String,
+        #[doc = include_str!("_docs/attributes/mask.md")]
+        mask: String,
+        mask_data: String,
+        #[doc = include_str!("_docs/attributes/scroll_fade.md")]
+        scroll_fade: String,
 
         name: String,
         focusable: String,
+        tab_focusable: String,
+        #[doc = include_str!("_docs/attributes/tab_index.md")]
+        tab_index: String,
+        #[doc = include_str!("_docs/attributes/test_id.md")]
+        test_id: String,
+        #[doc = include_str!("_docs/attributes/hit_padding.md")]
+        hit_padding: String,
+        #[doc = include_str!("_docs/attributes/pointer_events.md")]
+        pointer_events: String,
+        #[doc = include_str!("_docs/attributes/user_select.md")]
+        user_select: String,
+        #[doc = include_str!("_docs/attributes/pixel_snapping.md")]
+        pixel_snapping: String,
+        #[doc = include_str!("_docs/attributes/role_aria.md")]
         role: String,
+        aria_label: String,
+        aria_description: String,
+        aria_checked: String,
+        aria_expanded: String,
+        aria_selected: String,
+        aria_disabled: String,
+        aria_live: String,
+        aria_hidden: String,
         focus_id: AccessibilityId,
         alt: String,
         canvas_reference: String,
+        gpu_canvas_reference: String,
+        #[doc = include_str!("_docs/attributes/custom_element.md")]
+        custom_element: String,
+        #[doc = include_str!("_docs/attributes/layer.md")]
         layer: String,
+        #[doc = include_str!("_docs/attributes/isolation.md")]
+        isolation: String,
+        #[doc = include_str!("_docs/attributes/hidden.md")]
+        hidden: String,
+        #[doc = include_str!("_docs/attributes/order.md")]
+        order: String,
+        #[doc = include_str!("_docs/attributes/flex_basis_flex_grow_flex_shrink.md")]
+        flex_basis: String,
+        flex_grow: String,
+        flex_shrink: String,
         offset_y: String,
         offset_x: String,
         reference: Reference,
@@ -261,6 +338,9 @@ builder_constructors! {
         color: String,
         #[doc = include_str!("_docs/attributes/text_shadow.md")]
         text_shadow: String,
+        #[doc = include_str!("_docs/attributes/text_fill.md")]
+        text_fill: String,
+        text_fill_data: String,
         #[doc = include_str!("_docs/attributes/width_height.md")]
         height: String,
         width: String,
@@ -276,8 +356,12 @@ builder_constructors! {
         font_width: String,
         #[doc = include_str!("_docs/attributes/text_align.md")]
         text_align: String,
+        #[doc = include_str!("_docs/attributes/text_centering.md")]
+        text_centering: String,
         #[doc = include_str!("_docs/attributes/max_lines.md")]
         max_lines: String,
+        #[doc = include_str!("_docs/attributes/min_lines.md")]
+        min_lines: String,
         #[doc = include_str!("_docs/attributes/rotate.md")]
         rotate: String,
         #[doc = include_str!("_docs/attributes/line_height.md")]
@@ -294,14 +378,57 @@ builder_constructors! {
         decoration_color: String,
         #[doc = include_str!("_docs/attributes/text_overflow.md")]
         text_overflow: String,
+        #[doc = include_str!("_docs/attributes/white_space.md")]
+        white_space: String,
+        #[doc = include_str!("_docs/attributes/text_hinting.md")]
+        text_hinting: String,
+        text_subpixel: String,
         focusable: String,
+        tab_focusable: String,
+        #[doc = include_str!("_docs/attributes/tab_index.md")]
+        tab_index: String,
+        test_id: String,
         #[doc = include_str!("_docs/attributes/margin.md")]
         margin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
-
+        #[doc = include_str!("_docs/attributes/transition.md")]
+        transition: String,
+        #[doc = include_str!("_docs/attributes/filter.md")]
+        filter: String,
+        #[doc = include_str!("_docs/attributes/animation.md")]
+        animation: String,
+        #[doc = include_str!("_docs/attributes/exit_animation.md")]
+        exit_animation: String,
+
+        #[doc = include_str!("_docs/attributes/layer.md")]
         layer: String,
+        #[doc = include_str!("_docs/attributes/isolation.md")]
+        isolation: String,
+        #[doc = include_str!("_docs/attributes/hidden.md")]
+        hidden: String,
+        #[doc = include_str!("_docs/attributes/order.md")]
+        order: String,
+        #[doc = include_str!("_docs/attributes/flex_basis_flex_grow_flex_shrink.md")]
+        flex_basis: String,
+        flex_grow: String,
+        flex_shrink: String,
+        #[doc = include_str!("_docs/attributes/hit_padding.md")]
+        hit_padding: String,
+        #[doc = include_str!("_docs/attributes/pointer_events.md")]
+        pointer_events: String,
+        #[doc = include_str!("_docs/attributes/pixel_snapping.md")]
+        pixel_snapping: String,
+        #[doc = include_str!("_docs/attributes/role_aria.md")]
         role: String,
+        aria_label: String,
+        aria_description: String,
+        aria_checked: String,
+        aria_expanded: String,
+        aria_selected: String,
+        aria_disabled: String,
+        aria_live: String,
+        aria_hidden: String,
         alt: String,
         focus_id: AccessibilityId,
         name: String,
@@ -339,6 +466,9 @@ builder_constructors! {
         max_width: String,
         #[doc = include_str!("_docs/attributes/text_align.md")]
         text_align: String,
+        #[doc = include_str!("_docs/attributes/text_fill.md")]
+        text_fill: String,
+        text_fill_data: String,
         direction: String,
         #[doc = include_str!("_docs/attributes/rotate.md")]
         rotate: String,
@@ -364,26 +494,80 @@ builder_constructors! {
         decoration_style: String,
         #[doc = include_str!("_docs/attributes/decoration_color.md")]
         text_overflow: String,
+        #[doc = include_str!("_docs/attributes/white_space.md")]
+        white_space: String,
+        #[doc = include_str!("_docs/attributes/text_hinting.md")]
+        text_hinting: String,
+        text_subpixel: String,
         #[doc = include_str!("_docs/attributes/overflow.md")]
         overflow: String,
         focusable: String,
+        tab_focusable: String,
+        #[doc = include_str!("_docs/attributes/tab_index.md")]
+        tab_index: String,
+        test_id: String,
         #[doc = include_str!("_docs/attributes/margin.md")]
         margin: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
-
+        #[doc = include_str!("_docs/attributes/transition.md")]
+        transition: String,
+        #[doc = include_str!("_docs/attributes/filter.md")]
+        filter: String,
+        #[doc = include_str!("_docs/attributes/animation.md")]
+        animation: String,
+        #[doc = include_str!("_docs/attributes/exit_animation.md")]
+        exit_animation: String,
+
+        #[doc = include_str!("_docs/attributes/layer.md")]
         layer: String,
+        #[doc = include_str!("_docs/attributes/isolation.md")]
+        isolation: String,
+        #[doc = include_str!("_docs/attributes/hidden.md")]
+        hidden: String,
+        #[doc = include_str!("_docs/attributes/order.md")]
+        order: String,
+        #[doc = include_str!("_docs/attributes/flex_basis_flex_grow_flex_shrink.md")]
+        flex_basis: String,
+        flex_grow: String,
+        flex_shrink: String,
         cursor_index: String,
         max_lines: String,
+        #[doc = include_str!("_docs/attributes/min_lines.md")]
+        min_lines: String,
+        #[doc = include_str!("_docs/attributes/cursor_color_highlight_color.md")]
         cursor_color: String,
         cursor_mode: String,
         cursor_id: String,
         alt: String,
         name: String,
+        #[doc = include_str!("_docs/attributes/hit_padding.md")]
+        hit_padding: String,
+        #[doc = include_str!("_docs/attributes/pointer_events.md")]
+        pointer_events: String,
+        #[doc = include_str!("_docs/attributes/user_select.md")]
+        user_select: String,
+        #[doc = include_str!("_docs/attributes/pixel_snapping.md")]
+        pixel_snapping: String,
+        #[doc = include_str!("_docs/attributes/role_aria.md")]
         role: String,
+        aria_label: String,
+        aria_description: String,
+        aria_checked: String,
+        aria_expanded: String,
+        aria_selected: String,
+        aria_disabled: String,
+        aria_live: String,
+        aria_hidden: String,
         focus_id: AccessibilityId,
         highlights: String,
+        #[doc = include_str!("_docs/attributes/cursor_color_highlight_color.md")]
         highlight_color: String,
+        #[doc = include_str!("_docs/attributes/match_highlights.md")]
+        match_highlights: String,
+        match_highlight_color: String,
+        current_match: String,
+        current_match_color: String,
     };
     /// `text` element is simply a text span used for the `paragraph` element.
     text {
@@ -444,14 +628,43 @@ builder_constructors! {
         rotate: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/transition.md")]
+        transition: String,
+        #[doc = include_str!("_docs/attributes/filter.md")]
+        filter: String,
+        #[doc = include_str!("_docs/attributes/animation.md")]
+        animation: String,
+        #[doc = include_str!("_docs/attributes/exit_animation.md")]
+        exit_animation: String,
+        #[doc = include_str!("_docs/attributes/antialias.md")]
+        antialias: String,
 
         image_data: String,
         image_reference: String,
+        #[doc = include_str!("_docs/attributes/hit_padding.md")]
+        hit_padding: String,
+        #[doc = include_str!("_docs/attributes/pointer_events.md")]
+        pointer_events: String,
+        #[doc = include_str!("_docs/attributes/pixel_snapping.md")]
+        pixel_snapping: String,
+        #[doc = include_str!("_docs/attributes/role_aria.md")]
         role: String,
+        aria_label: String,
+        aria_description: String,
+        aria_checked: String,
+        aria_expanded: String,
+        aria_selected: String,
+        aria_disabled: String,
+        aria_live: String,
+        aria_hidden: String,
         focus_id: AccessibilityId,
         alt: String,
         name: String,
         focusable: String,
+        tab_focusable: String,
+        #[doc = include_str!("_docs/attributes/tab_index.md")]
+        tab_index: String,
+        test_id: String,
     };
     /// `svg` element let's you display SVG code.
     ///
@@ -483,14 +696,41 @@ builder_constructors! {
         rotate: String,
         #[doc = include_str!("_docs/attributes/opacity.md")]
         opacity: String,
+        #[doc = include_str!("_docs/attributes/transition.md")]
+        transition: String,
+        #[doc = include_str!("_docs/attributes/filter.md")]
+        filter: String,
+        #[doc = include_str!("_docs/attributes/animation.md")]
+        animation: String,
+        #[doc = include_str!("_docs/attributes/exit_animation.md")]
+        exit_animation: String,
 
         svg_data: String,
         svg_content: String,
+        #[doc = include_str!("_docs/attributes/hit_padding.md")]
+        hit_padding: String,
+        #[doc = include_str!("_docs/attributes/pointer_events.md")]
+        pointer_events: String,
+        #[doc = include_str!("_docs/attributes/pixel_snapping.md")]
+        pixel_snapping: String,
+        #[doc = include_str!("_docs/attributes/role_aria.md")]
         role: String,
+        aria_label: String,
+        aria_description: String,
+        aria_checked: String,
+        aria_expanded: String,
+        aria_selected: String,
+        aria_disabled: String,
+        aria_live: String,
+        aria_hidden: String,
         focus_id: AccessibilityId,
         alt: String,
         name: String,
         focusable: String,
+        tab_focusable: String,
+        #[doc = include_str!("_docs/attributes/tab_index.md")]
+        tab_index: String,
+        test_id: String,
     };
 }
 
@@ -590,6 +830,14 @@ pub mod events {
         onmouseleave
         #[doc = include_str!("_docs/events/mouseenter.md")]
         onmouseenter
+        #[doc = include_str!("_docs/events/capture.md")]
+        onclick_capture
+        #[doc = include_str!("_docs/events/capture.md")]
+        onmiddleclick_capture
+        #[doc = include_str!("_docs/events/capture.md")]
+        onrightclick_capture
+        #[doc = include_str!("_docs/events/capture.md")]
+        onmousedown_capture
     ];
 
     impl_event! [
@@ -597,6 +845,8 @@ pub mod events {
 
         #[doc = include_str!("_docs/events/wheel.md")]
         onwheel
+        #[doc = include_str!("_docs/events/capture.md")]
+        onwheel_capture
     ];
 
     impl_event! [
@@ -636,6 +886,10 @@ pub mod events {
         onpointerenter
         #[doc = include_str!("_docs/events/pointerleave.md")]
         onpointerleave
+        #[doc = include_str!("_docs/events/capture.md")]
+        onpointerdown_capture
+        #[doc = include_str!("_docs/events/capture.md")]
+        onpointerup_capture
     ];
 
     impl_event! [
@@ -643,9 +897,25 @@ pub mod events {
 
         #[doc = include_str!("_docs/events/onfiledrop.md")]
         onfiledrop
+        #[doc = include_str!("_docs/events/onfilehover.md")]
+        onfilehover
         #[doc = include_str!("_docs/events/onglobalfilehover.md")]
         onglobalfilehover
         #[doc = include_str!("_docs/events/onglobalfilehovercancelled.md")]
         onglobalfilehovercancelled
     ];
+
+    impl_event! [
+        MountedData;
+
+        #[doc = include_str!("_docs/events/mounted.md")]
+        onmounted
+    ];
+
+    impl_event! [
+        ScrollData;
+
+        #[doc = include_str!("_docs/events/scroll.md")]
+        onscroll
+    ];
 }
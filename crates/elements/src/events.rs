@@ -1,15 +1,25 @@
+pub mod capture;
+pub mod dataset;
 pub mod file;
 pub mod keyboard;
+pub mod mounted;
 pub mod mouse;
 pub mod pointer;
+pub mod scroll;
+pub mod target;
 pub mod touch;
 pub mod wheel;
 
+pub use capture::*;
+pub use dataset::*;
 use dioxus_core::Event;
 pub use file::*;
 pub use keyboard::*;
+pub use mounted::*;
 pub use mouse::*;
 pub use pointer::*;
+pub use scroll::*;
+pub use target::*;
 pub use touch::*;
 pub use wheel::*;
 
@@ -18,3 +28,5 @@ pub type MouseEvent = Event<MouseData>;
 pub type WheelEvent = Event<WheelData>;
 pub type TouchEvent = Event<TouchData>;
 pub type PointerEvent = Event<PointerData>;
+pub type MountedEvent = Event<MountedData>;
+pub type ScrollEvent = Event<ScrollData>;
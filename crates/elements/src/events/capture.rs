@@ -0,0 +1,20 @@
+use std::{cell::Cell, rc::Rc};
+
+/// Shared state of an event across its capture and bubble phases.
+///
+/// The same state is shared by all the `*_capture` handlers and the bubbling handlers of an event,
+/// so stopping it from one of the capture handlers stops the remaining handlers of both phases.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CaptureState(Rc<Cell<bool>>);
+
+impl CaptureState {
+    /// Stop the event from reaching the next handlers.
+    pub fn stop(&self) {
+        self.0.set(true);
+    }
+
+    /// Check if the event was stopped.
+    pub fn is_stopped(&self) -> bool {
+        self.0.get()
+    }
+}
@@ -0,0 +1,40 @@
+use std::{collections::HashMap, rc::Rc};
+
+/// `data-*` attributes of the element that received an event, keyed by their name without the `data-` prefix.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         rect {
+///             "data-section": "sidebar",
+///             onclick: |e: MouseEvent| println!("Clicked {:?}", e.get_dataset().get("section")),
+///         }
+///     )
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dataset(Rc<HashMap<String, String>>);
+
+impl Dataset {
+    pub fn new(dataset: HashMap<String, String>) -> Self {
+        Self(Rc::new(dataset))
+    }
+
+    /// Get the value of a `data-*` attribute, e.g. `get("test-id")` for `data-test-id`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Iterate over all the `data-*` attributes.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Check if the element has no `data-*` attributes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -1,11 +1,58 @@
 use std::path::PathBuf;
 
-use crate::definitions::PlatformEventData;
+use torin::geometry::CursorPoint;
 
-/// Data of a Keyboard event.
+use crate::{
+    definitions::PlatformEventData,
+    events::{Dataset, EventTarget},
+};
+
+/// Data of a File event.
+///
+/// Every file of a drop or hover gets its own event.
 #[derive(Debug, Clone, PartialEq)]
 pub struct FileData {
     pub file_path: Option<PathBuf>,
+    pub screen_coordinates: CursorPoint,
+    pub element_coordinates: CursorPoint,
+    pub dataset: Dataset,
+    pub target: EventTarget,
+}
+
+impl FileData {
+    pub fn new(
+        file_path: Option<PathBuf>,
+        screen_coordinates: CursorPoint,
+        element_coordinates: CursorPoint,
+    ) -> Self {
+        Self {
+            file_path,
+            screen_coordinates,
+            element_coordinates,
+            dataset: Dataset::default(),
+            target: EventTarget::default(),
+        }
+    }
+
+    /// Get the coordinates of the pointer relative to the window bounds.
+    pub fn get_screen_coordinates(&self) -> CursorPoint {
+        self.screen_coordinates
+    }
+
+    /// Get the coordinates of the pointer relative to the element bounds.
+    pub fn get_element_coordinates(&self) -> CursorPoint {
+        self.element_coordinates
+    }
+
+    /// Get the `data-*` attributes of the element that received the event.
+    pub fn get_dataset(&self) -> &Dataset {
+        &self.dataset
+    }
+
+    /// Get the element that originated the event, see [`EventTarget`].
+    pub fn get_target(&self) -> &EventTarget {
+        &self.target
+    }
 }
 
 impl From<&PlatformEventData> for FileData {
@@ -1,4 +1,4 @@
-use crate::definitions::PlatformEventData;
+use crate::{definitions::PlatformEventData, events::Dataset};
 pub use keyboard_types::{Code, Key, Modifiers};
 use winit::keyboard::{self, NamedKey};
 
@@ -283,20 +283,36 @@ pub fn map_winit_physical_key(key: &keyboard::PhysicalKey) -> Code {
             keyboard::KeyCode::BracketRight => Code::BracketRight,
             keyboard::KeyCode::ShiftLeft => Code::ShiftLeft,
             keyboard::KeyCode::Meta => Code::MetaLeft,
-            keyboard::KeyCode::MediaSelect => Code::Unidentified,
-            keyboard::KeyCode::MediaStop => Code::Unidentified,
-            keyboard::KeyCode::Minus => Code::Unidentified,
-            keyboard::KeyCode::Period => Code::Unidentified,
-            keyboard::KeyCode::Power => Code::Unidentified,
+            keyboard::KeyCode::SuperLeft => Code::MetaLeft,
+            keyboard::KeyCode::SuperRight => Code::MetaRight,
+            keyboard::KeyCode::MediaSelect => Code::MediaSelect,
+            keyboard::KeyCode::MediaStop => Code::MediaStop,
+            keyboard::KeyCode::MediaPlayPause => Code::MediaPlayPause,
+            keyboard::KeyCode::MediaTrackNext => Code::MediaTrackNext,
+            keyboard::KeyCode::MediaTrackPrevious => Code::MediaTrackPrevious,
+            keyboard::KeyCode::Minus => Code::Minus,
+            keyboard::KeyCode::Period => Code::Period,
+            keyboard::KeyCode::Quote => Code::Quote,
+            keyboard::KeyCode::Backquote => Code::Backquote,
+            keyboard::KeyCode::IntlBackslash => Code::IntlBackslash,
+            keyboard::KeyCode::IntlRo => Code::IntlRo,
+            keyboard::KeyCode::CapsLock => Code::CapsLock,
+            keyboard::KeyCode::ScrollLock => Code::ScrollLock,
+            keyboard::KeyCode::PrintScreen => Code::PrintScreen,
+            keyboard::KeyCode::ContextMenu => Code::ContextMenu,
+            keyboard::KeyCode::Power => Code::Power,
+            keyboard::KeyCode::AltLeft => Code::AltLeft,
             keyboard::KeyCode::AltRight => Code::AltRight,
             keyboard::KeyCode::ControlLeft => Code::ControlLeft,
             keyboard::KeyCode::ControlRight => Code::ControlRight,
             keyboard::KeyCode::ShiftRight => Code::ShiftRight,
             keyboard::KeyCode::Semicolon => Code::Semicolon,
-            keyboard::KeyCode::Slash => Code::Unidentified,
-            keyboard::KeyCode::Sleep => Code::Unidentified,
+            keyboard::KeyCode::Slash => Code::Slash,
+            keyboard::KeyCode::Sleep => Code::Sleep,
             keyboard::KeyCode::Tab => Code::Tab,
             keyboard::KeyCode::AudioVolumeUp => Code::AudioVolumeUp,
+            keyboard::KeyCode::AudioVolumeDown => Code::AudioVolumeDown,
+            keyboard::KeyCode::AudioVolumeMute => Code::AudioVolumeMute,
             keyboard::KeyCode::IntlYen => Code::IntlYen,
             keyboard::KeyCode::Copy => Code::Copy,
             keyboard::KeyCode::Paste => Code::Paste,
@@ -311,17 +327,26 @@ pub fn map_winit_physical_key(key: &keyboard::PhysicalKey) -> Code {
 /// Data of a Keyboard event.
 #[derive(Debug, Clone, PartialEq)]
 pub struct KeyboardData {
+    /// Logical key, affected by the keyboard layout and the modifiers.
     pub key: Key,
+    /// Physical key code, independent of the keyboard layout.
     pub code: Code,
+    /// Modifiers (ctrl, alt, shift, meta) being held when the event happened.
     pub modifiers: Modifiers,
+    /// Whether this event was caused by a key being held down.
+    pub repeat: bool,
+    /// `data-*` attributes of the element that received the event.
+    pub dataset: Dataset,
 }
 
 impl KeyboardData {
-    pub fn new(key: Key, code: Code, modifiers: Modifiers) -> Self {
+    pub fn new(key: Key, code: Code, modifiers: Modifiers, repeat: bool) -> Self {
         Self {
             key,
             code,
             modifiers,
+            repeat,
+            dataset: Dataset::default(),
         }
     }
 }
@@ -335,6 +360,36 @@ impl KeyboardData {
             None
         }
     }
+
+    /// Check if this event was caused by a key being held down, instead of a new press.
+    pub fn is_repeat(&self) -> bool {
+        self.repeat
+    }
+
+    /// Check if the Control key was being held.
+    pub fn is_control_pressed(&self) -> bool {
+        self.modifiers.contains(Modifiers::CONTROL)
+    }
+
+    /// Check if the Alt key was being held.
+    pub fn is_alt_pressed(&self) -> bool {
+        self.modifiers.contains(Modifiers::ALT)
+    }
+
+    /// Check if the Shift key was being held.
+    pub fn is_shift_pressed(&self) -> bool {
+        self.modifiers.contains(Modifiers::SHIFT)
+    }
+
+    /// Check if the Meta key (e.g. Windows key or Command key) was being held.
+    pub fn is_meta_pressed(&self) -> bool {
+        self.modifiers.contains(Modifiers::META)
+    }
+
+    /// Get the `data-*` attributes of the element that received the event.
+    pub fn get_dataset(&self) -> &Dataset {
+        &self.dataset
+    }
 }
 
 impl From<&PlatformEventData> for KeyboardData {
@@ -0,0 +1,37 @@
+use torin::geometry::Area;
+
+use crate::{definitions::PlatformEventData, events::Dataset};
+
+/// Data of a Mounted event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MountedData {
+    /// Area of the element in the first layout after it was mounted, in logical pixels.
+    pub area: Area,
+    /// `data-*` attributes of the element that received the event.
+    pub dataset: Dataset,
+}
+
+impl MountedData {
+    pub fn new(area: Area) -> Self {
+        Self {
+            area,
+            dataset: Dataset::default(),
+        }
+    }
+
+    /// Get the area of the element in the first layout after it was mounted.
+    pub fn get_area(&self) -> Area {
+        self.area
+    }
+
+    /// Get the `data-*` attributes of the element that received the event.
+    pub fn get_dataset(&self) -> &Dataset {
+        &self.dataset
+    }
+}
+
+impl From<&PlatformEventData> for MountedData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<MountedData>().cloned().unwrap()
+    }
+}
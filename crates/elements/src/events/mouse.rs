@@ -1,7 +1,10 @@
 use torin::geometry::CursorPoint;
 pub use winit::event::MouseButton;
 
-use crate::definitions::PlatformEventData;
+use crate::{
+    definitions::PlatformEventData,
+    events::{CaptureState, Dataset, EventTarget},
+};
 
 /// Data of a Mouse event.
 #[derive(Debug, Clone, PartialEq)]
@@ -9,6 +12,9 @@ pub struct MouseData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub trigger_button: Option<MouseButton>,
+    pub capture: CaptureState,
+    pub dataset: Dataset,
+    pub target: EventTarget,
 }
 
 impl MouseData {
@@ -21,6 +27,9 @@ impl MouseData {
             screen_coordinates,
             element_coordinates,
             trigger_button,
+            capture: CaptureState::default(),
+            dataset: Dataset::default(),
+            target: EventTarget::default(),
         }
     }
 }
@@ -40,6 +49,22 @@ impl MouseData {
     pub fn get_trigger_button(&self) -> Option<MouseButton> {
         self.trigger_button
     }
+
+    /// Stop the capture phase of this event, the remaining `*_capture` handlers and the bubbling handlers will not be called.
+    /// Use `stop_propagation` instead to stop the bubble phase.
+    pub fn stop_capture(&self) {
+        self.capture.stop();
+    }
+
+    /// Get the `data-*` attributes of the element that received the event.
+    pub fn get_dataset(&self) -> &Dataset {
+        &self.dataset
+    }
+
+    /// Get the element that originated the event, see [`EventTarget`].
+    pub fn get_target(&self) -> &EventTarget {
+        &self.target
+    }
 }
 
 impl From<&PlatformEventData> for MouseData {
@@ -2,7 +2,10 @@ use torin::geometry::CursorPoint;
 pub use winit::event::MouseButton;
 use winit::event::{Force, TouchPhase};
 
-use crate::definitions::PlatformEventData;
+use crate::{
+    definitions::PlatformEventData,
+    events::{CaptureState, Dataset, EventTarget},
+};
 
 /// The type of device that triggered a Pointer event.
 #[derive(Debug, Clone, PartialEq, Copy)]
@@ -23,6 +26,9 @@ pub struct PointerData {
     pub screen_coordinates: CursorPoint,
     pub element_coordinates: CursorPoint,
     pub point_type: PointerType,
+    pub capture: CaptureState,
+    pub dataset: Dataset,
+    pub target: EventTarget,
 }
 
 impl PointerData {
@@ -35,6 +41,9 @@ impl PointerData {
             screen_coordinates,
             element_coordinates,
             point_type,
+            capture: CaptureState::default(),
+            dataset: Dataset::default(),
+            target: EventTarget::default(),
         }
     }
 }
@@ -54,6 +63,22 @@ impl PointerData {
     pub fn get_pointer_type(&self) -> PointerType {
         self.point_type
     }
+
+    /// Stop the capture phase of this event, the remaining `*_capture` handlers and the bubbling handlers will not be called.
+    /// Use `stop_propagation` instead to stop the bubble phase.
+    pub fn stop_capture(&self) {
+        self.capture.stop();
+    }
+
+    /// Get the `data-*` attributes of the element that received the event.
+    pub fn get_dataset(&self) -> &Dataset {
+        &self.dataset
+    }
+
+    /// Get the element that originated the event, see [`EventTarget`].
+    pub fn get_target(&self) -> &EventTarget {
+        &self.target
+    }
 }
 
 impl From<&PlatformEventData> for PointerData {
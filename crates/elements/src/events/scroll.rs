@@ -0,0 +1,91 @@
+use crate::{definitions::PlatformEventData, events::Dataset};
+
+/// Data of a Scroll event, in logical pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrollData {
+    offset_x: f32,
+    offset_y: f32,
+    delta_x: f32,
+    delta_y: f32,
+    max_offset_x: f32,
+    max_offset_y: f32,
+    /// `data-*` attributes of the element that received the event.
+    pub dataset: Dataset,
+}
+
+impl ScrollData {
+    pub fn new(offset: (f32, f32), delta: (f32, f32), max_offset: (f32, f32)) -> Self {
+        Self {
+            offset_x: offset.0,
+            offset_y: offset.1,
+            delta_x: delta.0,
+            delta_y: delta.1,
+            max_offset_x: max_offset.0,
+            max_offset_y: max_offset.1,
+            dataset: Dataset::default(),
+        }
+    }
+}
+
+impl ScrollData {
+    /// Get how far the content is scrolled from its left edge.
+    pub fn get_offset_x(&self) -> f32 {
+        self.offset_x
+    }
+
+    /// Get how far the content is scrolled from its top edge.
+    pub fn get_offset_y(&self) -> f32 {
+        self.offset_y
+    }
+
+    /// Get how much the content was scrolled horizontally since the previous event, positive towards the right.
+    pub fn get_delta_x(&self) -> f32 {
+        self.delta_x
+    }
+
+    /// Get how much the content was scrolled vertically since the previous event, positive towards the bottom.
+    pub fn get_delta_y(&self) -> f32 {
+        self.delta_y
+    }
+
+    /// Get the furthest the content can be scrolled horizontally, `0` when it doesn't overflow.
+    pub fn get_max_offset_x(&self) -> f32 {
+        self.max_offset_x
+    }
+
+    /// Get the furthest the content can be scrolled vertically, `0` when it doesn't overflow.
+    pub fn get_max_offset_y(&self) -> f32 {
+        self.max_offset_y
+    }
+
+    /// Check if the content is scrolled to its left edge.
+    pub fn is_at_start_x(&self) -> bool {
+        self.offset_x <= 0.0
+    }
+
+    /// Check if the content is scrolled to its top edge.
+    pub fn is_at_start_y(&self) -> bool {
+        self.offset_y <= 0.0
+    }
+
+    /// Check if the content is scrolled to its right edge.
+    pub fn is_at_end_x(&self) -> bool {
+        self.offset_x >= self.max_offset_x
+    }
+
+    /// Check if the content is scrolled to its bottom edge, e.g. to load more items.
+    pub fn is_at_end_y(&self) -> bool {
+        self.offset_y >= self.max_offset_y
+    }
+
+    /// Get the `data-*` attributes of the element that received the event.
+    pub fn get_dataset(&self) -> &Dataset {
+        &self.dataset
+    }
+}
+
+impl From<&PlatformEventData> for ScrollData {
+    fn from(val: &PlatformEventData) -> Self {
+        val.downcast::<ScrollData>().cloned().unwrap()
+    }
+}
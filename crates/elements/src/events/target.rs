@@ -0,0 +1,55 @@
+use dioxus_core::ElementId;
+
+use crate::events::Dataset;
+
+/// Element that originated a pointer event: the topmost one under the pointer, even if it isn't listening to the event.
+///
+/// A single handler in a common ancestor can use it to handle the events of all its descendants,
+/// instead of attaching a handler to every one of them, which is much lighter for big lists:
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     rsx!(
+///         rect {
+///             onclick: |e: MouseEvent| {
+///                 if let Some(item) = e.get_target().dataset().get("item") {
+///                     println!("Clicked item {item}");
+///                 }
+///             },
+///             for item in 0..1000 {
+///                 rect {
+///                     key: "{item}",
+///                     "data-item": "{item}",
+///                     label { "Item {item}" }
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventTarget {
+    element_id: Option<ElementId>,
+    dataset: Dataset,
+}
+
+impl EventTarget {
+    pub fn new(element_id: Option<ElementId>, dataset: Dataset) -> Self {
+        Self {
+            element_id,
+            dataset,
+        }
+    }
+
+    /// Get the id of the target element.
+    pub fn element_id(&self) -> Option<ElementId> {
+        self.element_id
+    }
+
+    /// Get the `data-*` attributes of the target element and its ancestors.
+    /// When some of them have the same attribute, the value of the closest to the target is kept.
+    pub fn dataset(&self) -> &Dataset {
+        &self.dataset
+    }
+}
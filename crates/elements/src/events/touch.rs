@@ -1,7 +1,10 @@
 use torin::geometry::CursorPoint;
 pub use winit::event::{Force, TouchPhase};
 
-use crate::definitions::PlatformEventData;
+use crate::{
+    definitions::PlatformEventData,
+    events::{Dataset, EventTarget},
+};
 
 /// Data of a Touch event.
 #[derive(Debug, Clone, PartialEq)]
@@ -11,6 +14,8 @@ pub struct TouchData {
     pub finger_id: u64,
     pub phase: TouchPhase,
     pub force: Option<Force>,
+    pub dataset: Dataset,
+    pub target: EventTarget,
 }
 
 impl TouchData {
@@ -27,6 +32,8 @@ impl TouchData {
             finger_id,
             phase,
             force,
+            dataset: Dataset::default(),
+            target: EventTarget::default(),
         }
     }
 
@@ -54,6 +61,16 @@ impl TouchData {
     pub fn get_touch_force(&self) -> Option<Force> {
         self.force
     }
+
+    /// Get the `data-*` attributes of the element that received the event.
+    pub fn get_dataset(&self) -> &Dataset {
+        &self.dataset
+    }
+
+    /// Get the element that originated the event, see [`EventTarget`].
+    pub fn get_target(&self) -> &EventTarget {
+        &self.target
+    }
 }
 
 impl From<&PlatformEventData> for TouchData {
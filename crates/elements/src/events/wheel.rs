@@ -1,16 +1,59 @@
-use crate::definitions::PlatformEventData;
+use std::{cell::Cell, rc::Rc};
+
+use crate::{
+    definitions::PlatformEventData,
+    events::{keyboard::Modifiers, CaptureState, Dataset, EventTarget},
+};
+
+/// Raw scroll delta of a Wheel event, as reported by the device.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WheelDelta {
+    /// Amount of lines, usually reported by mouse wheels.
+    Lines { x: f32, y: f32 },
+    /// Amount of logical pixels, usually reported by trackpads.
+    Pixels { x: f64, y: f64 },
+}
+
+impl Default for WheelDelta {
+    fn default() -> Self {
+        Self::Pixels { x: 0., y: 0. }
+    }
+}
+
+/// Axis of a Wheel event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WheelAxis {
+    Horizontal,
+    Vertical,
+}
 
 /// Data of a Wheel event.
 #[derive(Debug, Clone, PartialEq)]
 pub struct WheelData {
-    #[allow(dead_code)]
     delta_x: f64,
     delta_y: f64,
+    delta: WheelDelta,
+    modifiers: Modifiers,
+    default_prevented: Rc<Cell<bool>>,
+    consumed_delta: Rc<Cell<(f64, f64)>>,
+    pub capture: CaptureState,
+    pub dataset: Dataset,
+    pub target: EventTarget,
 }
 
 impl WheelData {
-    pub fn new(delta_x: f64, delta_y: f64) -> Self {
-        Self { delta_x, delta_y }
+    pub fn new(delta_x: f64, delta_y: f64, delta: WheelDelta, modifiers: Modifiers) -> Self {
+        Self {
+            delta_x,
+            delta_y,
+            delta,
+            modifiers,
+            default_prevented: Rc::default(),
+            consumed_delta: Rc::default(),
+            capture: CaptureState::default(),
+            dataset: Dataset::default(),
+            target: EventTarget::default(),
+        }
     }
 }
 
@@ -24,6 +67,69 @@ impl WheelData {
     pub fn get_delta_y(&self) -> f64 {
         self.delta_y
     }
+
+    /// Get the X delta that has not been consumed yet by the handlers of the descendants.
+    pub fn get_remaining_delta_x(&self) -> f64 {
+        self.delta_x - self.consumed_delta.get().0
+    }
+
+    /// Get the Y delta that has not been consumed yet by the handlers of the descendants.
+    pub fn get_remaining_delta_y(&self) -> f64 {
+        self.delta_y - self.consumed_delta.get().1
+    }
+
+    /// Mark part of the delta as consumed, e.g. by a `ScrollView` that reached its end while scrolling,
+    /// so its ancestors only scroll with the rest of the delta.
+    pub fn consume_delta(&self, x: f64, y: f64) {
+        let (consumed_x, consumed_y) = self.consumed_delta.get();
+        self.consumed_delta.set((consumed_x + x, consumed_y + y));
+    }
+
+    /// Get the raw delta as reported by the device, lines for mouse wheels and pixels for trackpads.
+    pub fn get_raw_delta(&self) -> WheelDelta {
+        self.delta
+    }
+
+    /// Get the main axis of this event, the one with the biggest delta.
+    pub fn get_axis(&self) -> WheelAxis {
+        if self.delta_x.abs() > self.delta_y.abs() {
+            WheelAxis::Horizontal
+        } else {
+            WheelAxis::Vertical
+        }
+    }
+
+    /// Get the modifier keys pressed when this event was emitted.
+    pub fn get_modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Prevent the built-in behavior of this event, e.g. `ScrollView` and `VirtualScrollView` will not scroll.
+    /// The event will still bubble up to the ancestors, use `stop_propagation` to stop it.
+    pub fn prevent_default(&self) {
+        self.default_prevented.set(true);
+    }
+
+    /// Check if any of the handlers called [`WheelData::prevent_default`].
+    pub fn is_default_prevented(&self) -> bool {
+        self.default_prevented.get()
+    }
+
+    /// Stop the capture phase of this event, the remaining `*_capture` handlers and the bubbling handlers will not be called.
+    /// Use `stop_propagation` instead to stop the bubble phase.
+    pub fn stop_capture(&self) {
+        self.capture.stop();
+    }
+
+    /// Get the `data-*` attributes of the element that received the event.
+    pub fn get_dataset(&self) -> &Dataset {
+        &self.dataset
+    }
+
+    /// Get the element that originated the event, see [`EventTarget`].
+    pub fn get_target(&self) -> &EventTarget {
+        &self.target
+    }
 }
 
 impl From<&PlatformEventData> for WheelData {
@@ -168,6 +168,18 @@ impl Matrix {
         unimplemented!("This is mocked")
     }
 
+    pub fn translate(_d: impl Into<Point>) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn scale((_sx, _sy): (f32, f32)) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_concat(&mut self, _other: &Matrix) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn set_rotate(&mut self, _degrees: f32, _pivot: impl Into<Option<Point>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -285,6 +297,13 @@ impl Weight {
     pub const EXTRA_BLACK: Self = Self(1000);
 }
 
+impl Deref for Weight {
+    type Target = i32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum Slant {
     Upright = 0,
@@ -309,6 +328,13 @@ impl Width {
     pub const ULTRA_EXPANDED: Self = Self(9);
 }
 
+impl Deref for Width {
+    type Target = i32;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 bitflags! {
     #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct TextDecoration: u32 {
@@ -567,7 +593,10 @@ impl TextStyle {
 
 pub struct Typeface;
 
-pub struct FontMetrics;
+pub struct FontMetrics {
+    pub cap_height: f32,
+    pub x_height: f32,
+}
 
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum TextBaseline {
@@ -593,6 +622,14 @@ impl Paint {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_blend_mode(&mut self, _mode: BlendMode) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_alpha_f(&mut self, _alpha: f32) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn set_shader(&mut self, _shader: impl Into<Option<Shader>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -601,6 +638,10 @@ impl Paint {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_image_filter(&mut self, _image_filter: impl Into<Option<ImageFilter>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn set_mask_filter(&mut self, _mask_filter: impl Into<Option<MaskFilter>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -954,6 +995,10 @@ pub struct PlaceholderStyle;
 pub struct Canvas;
 
 impl Canvas {
+    pub fn direct_context(&self) -> Option<DirectContext> {
+        unimplemented!("This is mocked")
+    }
+
     pub fn save(&self) {
         unimplemented!("This is mocked")
     }
@@ -1021,6 +1066,53 @@ impl Canvas {
     pub fn save_layer_alpha_f(&self, bounds: impl Into<Option<Rect>>, alpha: f32) -> usize {
         unimplemented!("This is mocked")
     }
+
+    pub fn save_layer(&self, _layer_rec: &SaveLayerRec) -> usize {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn draw_image(
+        &self,
+        _image: impl AsRef<Image>,
+        _left_top: impl Into<Point>,
+        _paint: Option<&Paint>,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn draw_image_rect(
+        &self,
+        _image: impl AsRef<Image>,
+        _src: Option<(&Rect, SrcRectConstraint)>,
+        _dst: impl AsRef<Rect>,
+        _paint: &Paint,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn draw_image_rect_with_sampling_options(
+        &self,
+        _image: impl AsRef<Image>,
+        _src: Option<(&Rect, SrcRectConstraint)>,
+        _dst: impl AsRef<Rect>,
+        _sampling: impl Into<SamplingOptions>,
+        _paint: &Paint,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn new_surface(&self, _info: &ImageInfo, _props: Option<&SurfaceProps>) -> Option<Surface> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn draw_picture(
+        &self,
+        _picture: impl AsRef<Picture>,
+        _matrix: Option<&Matrix>,
+        _paint: Option<&Paint>,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(i32)]
@@ -1061,7 +1153,10 @@ pub enum RectWidthStyle {
 
 pub struct LineMetrics;
 
-pub struct GlyphClusterInfo;
+pub struct GlyphClusterInfo {
+    pub bounds: Rect,
+    pub text_range: Range<usize>,
+}
 
 pub struct TextBox {
     pub rect: Rect,
@@ -1069,6 +1164,12 @@ pub struct TextBox {
 
 pub struct Font;
 
+impl Font {
+    pub fn metrics(&self) -> (f32, FontMetrics) {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct FontInfo;
 
 pub struct PositionWithAffinity {
@@ -1081,8 +1182,29 @@ impl RuntimeEffect {
     pub fn uniforms(&self) -> &[Uniform] {
         unimplemented!("This is mocked")
     }
+
+    pub fn make_for_shader<'a>(
+        _sksl: impl AsRef<str>,
+        _options: impl Into<Option<&'a RuntimeEffectOptions>>,
+    ) -> Result<RuntimeEffect, String> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn make_shader<'a>(
+        &self,
+        _uniforms: impl Into<Data>,
+        _children: &[ChildPtr],
+        _local_matrix: impl Into<Option<&'a Matrix>>,
+    ) -> Option<Shader> {
+        unimplemented!("This is mocked")
+    }
 }
 
+#[doc(hidden)]
+pub struct RuntimeEffectOptions;
+
+pub struct ChildPtr;
+
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum Uniform {
     Float = 0,
@@ -1130,12 +1252,187 @@ impl Rect {
     }
 }
 
+impl AsRef<Rect> for Rect {
+    fn as_ref(&self) -> &Rect {
+        self
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(i32)]
+pub enum BlendMode {
+    Clear = 0,
+    Src = 1,
+    Dst = 2,
+    SrcOver = 3,
+    DstOver = 4,
+    SrcIn = 5,
+    DstIn = 6,
+    SrcOut = 7,
+    DstOut = 8,
+}
+
+#[repr(i32)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SrcRectConstraint {
+    Strict = 0,
+    Fast = 1,
+}
+
+#[derive(Clone)]
 pub struct Image;
 
 impl Image {
     pub fn from_encoded(_data: Data) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn make_raster_image<'a>(
+        &self,
+        _context: impl Into<Option<&'a mut DirectContext>>,
+        _caching_hint: impl Into<Option<CachingHint>>,
+    ) -> Option<Image> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn encode<'a>(
+        &self,
+        _context: impl Into<Option<&'a mut DirectContext>>,
+        _format: EncodedImageFormat,
+        _quality: impl Into<Option<u32>>,
+    ) -> Option<Data> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn dimensions(&self) -> ISize {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn read_pixels<P>(
+        &self,
+        _dst_info: &ImageInfo,
+        _pixels: &mut [P],
+        _dst_row_bytes: usize,
+        _src: impl Into<IPoint>,
+        _caching_hint: CachingHint,
+    ) -> bool {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub mod images {
+    use super::{Data, Image, ImageInfo};
+
+    pub fn raster_from_data(
+        _info: &ImageInfo,
+        _pixels: impl Into<Data>,
+        _row_bytes: usize,
+    ) -> Option<Image> {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CachingHint {
+    Allow,
+    Disallow,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EncodedImageFormat {
+    BMP,
+    GIF,
+    ICO,
+    JPEG,
+    PNG,
+    WBMP,
+    WEBP,
+    PKM,
+    KTX,
+    ASTC,
+    DNG,
+    HEIF,
+    AVIF,
+    JPEGXL,
+}
+
+impl AsRef<Image> for Image {
+    fn as_ref(&self) -> &Image {
+        self
+    }
+}
+
+#[derive(Clone)]
+pub struct Picture;
+
+impl AsRef<Picture> for Picture {
+    fn as_ref(&self) -> &Picture {
+        self
+    }
+}
+
+pub struct PictureRecorder;
+
+impl PictureRecorder {
+    pub fn new() -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn begin_recording(
+        &mut self,
+        _bounds: impl AsRef<Rect>,
+        _bbh_factory: Option<()>,
+    ) -> &Canvas {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn finish_recording_as_picture(&mut self, _cull_rect: Option<&Rect>) -> Option<Picture> {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub struct ImageInfo;
+
+impl ImageInfo {
+    pub fn new(
+        _dimensions: impl Into<ISize>,
+        _ct: ColorType,
+        _at: AlphaType,
+        _cs: impl Into<Option<ColorSpace>>,
+    ) -> ImageInfo {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn new_n32_premul(
+        _dimensions: impl Into<ISize>,
+        _cs: impl Into<Option<ColorSpace>>,
+    ) -> ImageInfo {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct ISize {
+    pub width: i32,
+    pub height: i32,
+}
+
+impl From<(i32, i32)> for ISize {
+    fn from(_source: (i32, i32)) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct IPoint {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl From<(i32, i32)> for IPoint {
+    fn from(_source: (i32, i32)) -> Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct Data;
@@ -1148,6 +1445,10 @@ impl Data {
     pub unsafe fn new_bytes(_bytes: &[u8]) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -1180,6 +1481,15 @@ impl FilterMode {
     pub const Last: FilterMode = FilterMode::Linear;
 }
 
+#[derive(Copy, Clone, Default, Debug)]
+pub struct SamplingOptions;
+
+impl From<FilterMode> for SamplingOptions {
+    fn from(_filter: FilterMode) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct Path;
 
 impl Path {
@@ -1239,6 +1549,47 @@ impl Path {
     pub fn offset(&mut self, _d: impl Into<Point>) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn add_rect(
+        &mut self,
+        _rect: impl AsRef<Rect>,
+        _dir_start: Option<(PathDirection, usize)>,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn transform(&mut self, _matrix: &Matrix) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn add_circle(
+        &mut self,
+        _p: impl Into<Point>,
+        _radius: f32,
+        _dir: impl Into<Option<PathDirection>>,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn add_oval(
+        &mut self,
+        _oval: impl AsRef<Rect>,
+        _dir_start: Option<(PathDirection, usize)>,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn add_poly(&mut self, _pts: &[Point], _close: bool) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn contains(&self, _p: impl Into<Point>) -> bool {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn from_svg(_svg: impl AsRef<str>) -> Option<Path> {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(i32)]
@@ -1303,6 +1654,91 @@ pub enum PathDirection {
     CCW = 1,
 }
 
+#[derive(Default)]
+pub struct SaveLayerRec<'a> {
+    _bounds: Option<&'a Rect>,
+    _backdrop: Option<&'a ImageFilter>,
+}
+
+impl<'a> SaveLayerRec<'a> {
+    pub fn bounds(self, _bounds: &'a Rect) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn backdrop(self, _backdrop: &'a ImageFilter) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn paint(self, _paint: &'a Paint) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Clone)]
+pub struct ImageFilter;
+
+pub struct CropRect;
+
+impl CropRect {
+    pub const NO_CROP_RECT: CropRect = CropRect;
+}
+
+impl From<Rect> for CropRect {
+    fn from(_r: Rect) -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub mod image_filters {
+    pub use super::CropRect;
+    use super::{Color, ColorFilter, ImageFilter, Point, TileMode};
+
+    pub fn blur(
+        _sigma: (f32, f32),
+        _tile_mode: impl Into<Option<TileMode>>,
+        _input: impl Into<Option<ImageFilter>>,
+        _crop_rect: impl Into<CropRect>,
+    ) -> Option<ImageFilter> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn drop_shadow(
+        _offset: impl Into<Point>,
+        _sigma: (f32, f32),
+        _color: impl Into<Color>,
+        _input: impl Into<Option<ImageFilter>>,
+        _crop_rect: impl Into<CropRect>,
+    ) -> Option<ImageFilter> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn color_filter(
+        _cf: impl Into<ColorFilter>,
+        _input: impl Into<Option<ImageFilter>>,
+        _crop_rect: impl Into<CropRect>,
+    ) -> Option<ImageFilter> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn compose(
+        _outer: impl Into<ImageFilter>,
+        _inner: impl Into<ImageFilter>,
+    ) -> Option<ImageFilter> {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Clone)]
+pub struct ColorFilter;
+
+pub mod color_filters {
+    use super::ColorFilter;
+
+    pub fn matrix_row_major(_array: &[f32; 20]) -> ColorFilter {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub struct MaskFilter;
 
 impl MaskFilter {
@@ -1327,6 +1763,14 @@ pub enum BlurStyle {
     Inner = 3,
 }
 
+pub mod surfaces {
+    use super::{ISize, Surface};
+
+    pub fn raster_n32_premul(_size: impl Into<ISize>) -> Option<Surface> {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub mod svg {
     use super::{Canvas, FontMgr, Size};
 
@@ -1374,6 +1818,10 @@ impl Surface {
         unimplemented!("This is mocked")
     }
 
+    pub fn image_snapshot(&mut self) -> Image {
+        unimplemented!("This is mocked")
+    }
+
     pub fn from_backend_render_target(
         _context: &mut RecordingContext,
         _backend_render_target: &BackendRenderTarget,
@@ -1394,8 +1842,41 @@ pub enum ColorType {
     RGBA8888 = 4,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(i32)]
+pub enum AlphaType {
+    Unknown = 0,
+    Opaque = 1,
+    Premul = 2,
+    Unpremul = 3,
+}
+
 pub struct SurfaceProps;
 
+impl SurfaceProps {
+    pub fn new_with_text_properties(
+        _flags: SurfacePropsFlags,
+        _pixel_geometry: PixelGeometry,
+        _text_contrast: f32,
+        _text_gamma: f32,
+    ) -> SurfaceProps {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub struct SurfacePropsFlags;
+
+impl SurfacePropsFlags {
+    pub fn empty() -> Self {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PixelGeometry {
+    Unknown,
+}
+
 use std::ops::{Deref, DerefMut};
 
 pub struct RecordingContext;
@@ -1448,6 +1929,10 @@ impl DirectContext {
     pub fn abandon(&self) {
         unimplemented!("This is mocked")
     }
+
+    pub fn reset(&mut self, _backend_state: Option<u32>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 use std::ffi::c_void;
@@ -1,4 +1,6 @@
 pub use skia_safe::{
+    canvas::SaveLayerRec,
+    color_filters,
     font_style::{Slant, Weight, Width},
     gpu::{
         backend_render_targets,
@@ -10,10 +12,12 @@ pub use skia_safe::{
     graphics::{
         set_resource_cache_single_allocation_byte_limit, set_resource_cache_total_bytes_limit,
     },
+    image::CachingHint,
+    image_filters, images,
     path::ArcSize,
     rrect::Corner,
-    runtime_effect::Uniform,
-    svg,
+    runtime_effect::{ChildPtr, Uniform},
+    surfaces, svg,
     textlayout::{
         paragraph::GlyphClusterInfo, Decoration, FontCollection, FontFeature, LineMetrics,
         Paragraph, ParagraphBuilder, ParagraphStyle, PlaceholderStyle, PositionWithAffinity,
@@ -21,7 +25,9 @@ pub use skia_safe::{
         TextDecoration, TextDecorationStyle, TextDirection, TextHeightBehavior, TextIndex,
         TextRange, TextShadow, TextStyle, TypefaceFontProvider,
     },
-    BlurStyle, Canvas, ClipOp, Color, ColorSpace, ColorType, Data, FilterMode, FontArguments,
-    FontMgr, FontStyle, IRect, Image, MaskFilter, Matrix, Paint, PaintStyle, Path, PathDirection,
-    Point, RRect, Rect, RuntimeEffect, Shader, Surface, TileMode, Typeface, HSV, RGB,
+    AlphaType, BlendMode, BlurStyle, Canvas, ClipOp, Color, ColorFilter, ColorSpace, ColorType,
+    Data, EncodedImageFormat, FilterMode, FontArguments, FontMgr, FontStyle, IPoint, IRect, ISize,
+    Image, ImageFilter, ImageInfo, MaskFilter, Matrix, Paint, PaintStyle, Path, PathDirection,
+    Picture, PictureRecorder, PixelGeometry, Point, RRect, Rect, RuntimeEffect, SamplingOptions,
+    Shader, Surface, SurfaceProps, SurfacePropsFlags, TileMode, Typeface, HSV, RGB,
 };
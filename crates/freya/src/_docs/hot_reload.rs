@@ -4,6 +4,12 @@
 //!
 //! ## Setup
 //!
+//! Enable the `hot-reload` feature of Freya:
+//!
+//! ```toml
+//! freya = { version = "...", features = ["hot-reload"] }
+//! ```
+//!
 //! Before launching your app, you need to initialize the hot-reload context:
 //!
 //! ```rust, no_run
@@ -21,4 +27,12 @@
 //! # }
 //! ```
 //!
-//! That is it!
+//! That is it! Run your app in debug mode and edit any `rsx!` of your project, the new templates are sent to the running app and applied on the next render.
+//!
+//! Only the templates are replaced, so the components are not recreated and their state (signals, hooks, etc) is preserved.
+//! Changes to Rust code outside of the `rsx!` templates, like new dynamic values or hooks, still require recompiling.
+//!
+//! ## Observing reloads
+//!
+//! Every replaced template is also emitted to the plugins as [`PluginEvent::TemplateReplaced`](freya_core::plugins::PluginEvent::TemplateReplaced),
+//! which is useful to build dev tooling that logs or measures the reloads. See the `hotreload` example.
@@ -182,7 +182,9 @@ pub fn launch_with_props(app: AppComponent, title: &'static str, (width, height)
 pub fn launch_cfg<T: 'static + Clone + Send>(app: AppComponent, config: LaunchConfig<T>) {
     use freya_core::prelude::{FreyaDOM, SafeDOM};
 
-    let fdom = FreyaDOM::default();
+    let mut fdom = FreyaDOM::default();
+    fdom.set_render_settings(config.render_settings);
+    fdom.set_custom_elements(config.custom_elements.clone());
     let sdom = SafeDOM::new(fdom);
 
     #[cfg(feature = "log")]
@@ -107,10 +107,14 @@ pub mod prelude {
     pub use crate::launch::*;
     pub use crate::plugins::*;
     pub use freya_components::*;
+    pub use freya_core::prelude::{EventName, PlatformEvent};
     pub use freya_elements::elements as dioxus_elements;
     pub use freya_elements::events::*;
     pub use freya_hooks::*;
-    pub use freya_node_state::{dynamic_bytes, static_bytes, CustomAttributeValues};
+    pub use freya_node_state::{
+        dynamic_bytes, static_bytes, text_highlights, CustomAttributeValues, CustomElement,
+        CustomElements, FontStyleState, Keyframes, RenderSettings, TextHinting,
+    };
     pub use freya_renderer::*;
     pub use torin::prelude::*;
 }
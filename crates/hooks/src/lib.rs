@@ -10,16 +10,32 @@ mod theming;
 mod use_accessibility;
 mod use_activable_route;
 mod use_animation;
+mod use_animation_clock;
 mod use_asset_cacher;
 mod use_canvas;
+mod use_close_requested;
 mod use_editable;
 mod use_focus;
+mod use_frame;
+mod use_gpu_canvas;
+mod use_keyboard_state;
+mod use_keyframes;
+mod use_layout_effect;
+mod use_media_query;
 mod use_node;
 mod use_platform;
+mod use_pointer_nodes;
+mod use_shader;
+mod use_store;
+mod use_text_measurer;
 mod use_theme;
+mod use_unthrottled_pointer_moves;
+mod use_window_insets;
 
 #[cfg(feature = "use_camera")]
 mod use_camera;
+#[cfg(feature = "use_file_dialog")]
+mod use_file_dialog;
 
 pub use editor_history::*;
 pub use rope_editor::*;
@@ -30,13 +46,29 @@ pub use theming::*;
 pub use use_accessibility::*;
 pub use use_activable_route::*;
 pub use use_animation::*;
+pub use use_animation_clock::*;
 pub use use_asset_cacher::*;
 pub use use_canvas::*;
+pub use use_close_requested::*;
 pub use use_editable::*;
 pub use use_focus::*;
+pub use use_frame::*;
+pub use use_gpu_canvas::*;
+pub use use_keyboard_state::*;
+pub use use_keyframes::*;
+pub use use_layout_effect::*;
+pub use use_media_query::*;
 pub use use_node::*;
 pub use use_platform::*;
+pub use use_pointer_nodes::*;
+pub use use_shader::*;
+pub use use_store::*;
+pub use use_text_measurer::*;
 pub use use_theme::*;
+pub use use_unthrottled_pointer_moves::*;
+pub use use_window_insets::*;
 
 #[cfg(feature = "use_camera")]
 pub use use_camera::*;
+#[cfg(feature = "use_file_dialog")]
+pub use use_file_dialog::*;
@@ -20,6 +20,11 @@ impl UniformsBuilder {
         self.uniforms.insert(name.to_string(), value);
     }
 
+    /// Check if a uniform has a value.
+    pub fn contains(&self, name: &str) -> bool {
+        self.uniforms.contains_key(name)
+    }
+
     /// Build the uniform bytes.
     pub fn build(&self, shader: &RuntimeEffect) -> Vec<u8> {
         let mut values = Vec::new();
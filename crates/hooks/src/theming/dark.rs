@@ -35,10 +35,15 @@ pub const DARK_THEME: Theme = Theme {
         font_theme: FontTheme {
             color: cow_borrowed!("white"),
         },
+        placeholder_font_theme: FontTheme {
+            color: cow_borrowed!("rgb(150, 150, 150)"),
+        },
         border_fill: cow_borrowed!("rgb(80, 80, 80)"),
         width: LIGHT_THEME.input.width,
         margin: LIGHT_THEME.input.margin,
         corner_radius: LIGHT_THEME.input.corner_radius,
+        caret_color: cow_borrowed!("white"),
+        selection_color: cow_borrowed!("rgb(38, 79, 120)"),
     },
     switch: SwitchTheme {
         background: cow_borrowed!("rgb(60, 60, 60)"),
@@ -54,6 +59,7 @@ pub const DARK_THEME: Theme = Theme {
         hover_thumb_background: cow_borrowed!("rgb(120, 120, 120)"),
         active_thumb_background: cow_borrowed!("rgb(140, 140, 140)"),
         size: LIGHT_THEME.scroll_bar.size,
+        min_thumb_size: LIGHT_THEME.scroll_bar.min_thumb_size,
     },
     scroll_view: ScrollViewTheme {
         height: LIGHT_THEME.scroll_view.height,
@@ -180,4 +186,13 @@ pub const DARK_THEME: Theme = Theme {
         width: LIGHT_THEME.popup.width,
         height: LIGHT_THEME.popup.height,
     },
+    split_pane: SplitPaneTheme {
+        divider_background: cow_borrowed!("rgb(50, 50, 50)"),
+        hover_divider_background: cow_borrowed!("rgb(100, 100, 100)"),
+        divider_size: LIGHT_THEME.split_pane.divider_size,
+    },
+    scrim: ScrimTheme {
+        background: cow_borrowed!("rgb(0, 0, 0, 200)"),
+        backdrop_blur: LIGHT_THEME.scrim.backdrop_blur,
+    },
 };
@@ -35,10 +35,15 @@ pub const LIGHT_THEME: Theme = Theme {
         font_theme: FontTheme {
             color: cow_borrowed!("rgb(10, 10, 10)"),
         },
+        placeholder_font_theme: FontTheme {
+            color: cow_borrowed!("rgb(100, 100, 100)"),
+        },
         border_fill: cow_borrowed!("rgb(210, 210, 210)"),
         width: cow_borrowed!("150"),
         margin: cow_borrowed!("4"),
         corner_radius: cow_borrowed!("10"),
+        caret_color: cow_borrowed!("rgb(10, 10, 10)"),
+        selection_color: cow_borrowed!("rgb(173, 214, 255)"),
     },
     switch: SwitchTheme {
         background: cow_borrowed!("rgb(121, 116, 126)"),
@@ -54,6 +59,7 @@ pub const LIGHT_THEME: Theme = Theme {
         hover_thumb_background: cow_borrowed!("rgb(115, 115, 115)"),
         active_thumb_background: cow_borrowed!("rgb(95, 95, 95)"),
         size: cow_borrowed!("15"),
+        min_thumb_size: cow_borrowed!("24"),
     },
     scroll_view: ScrollViewTheme {
         height: cow_borrowed!("fill"),
@@ -180,4 +186,13 @@ pub const LIGHT_THEME: Theme = Theme {
         width: cow_borrowed!("350"),
         height: cow_borrowed!("200"),
     },
+    split_pane: SplitPaneTheme {
+        divider_background: cow_borrowed!("rgb(210, 210, 210)"),
+        hover_divider_background: cow_borrowed!("rgb(150, 150, 150)"),
+        divider_size: cow_borrowed!("6"),
+    },
+    scrim: ScrimTheme {
+        background: cow_borrowed!("rgb(0, 0, 0, 150)"),
+        backdrop_blur: cow_borrowed!("0"),
+    },
 };
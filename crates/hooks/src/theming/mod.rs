@@ -276,8 +276,13 @@ define_theme! {
         width: str,
         margin: str,
         corner_radius: str,
+        /// Color of the caret, the `cursor_color` of the text.
+        caret_color: str,
+        /// Color behind the selected text, the `highlight_color` of the text.
+        selection_color: str,
         %[subthemes]
         font_theme: FontTheme,
+        placeholder_font_theme: FontTheme,
     }
 }
 
@@ -311,6 +316,8 @@ define_theme! {
         hover_thumb_background: str,
         active_thumb_background: str,
         size: str,
+        /// Minimum length of the thumb, so it can still be grabbed when the content is very long.
+        min_thumb_size: str,
     }
 }
 
@@ -539,6 +546,25 @@ define_theme! {
     }
 }
 
+define_theme! {
+    %[component]
+    pub SplitPane {
+        %[cows]
+        divider_background: str,
+        hover_divider_background: str,
+        divider_size: str,
+    }
+}
+
+define_theme! {
+    %[component]
+    pub Scrim {
+        %[cows]
+        background: str,
+        backdrop_blur: str,
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Theme {
     pub name: &'static str,
@@ -570,6 +596,8 @@ pub struct Theme {
     pub menu_container: MenuContainerTheme,
     pub snackbar: SnackBarTheme,
     pub popup: PopupTheme,
+    pub split_pane: SplitPaneTheme,
+    pub scrim: ScrimTheme,
 }
 
 impl Default for Theme {
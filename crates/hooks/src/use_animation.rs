@@ -1,9 +1,10 @@
 use std::time::Duration;
 
-use dioxus_core::prelude::{spawn, use_hook, Task};
+use dioxus_core::prelude::{spawn, try_consume_context, use_hook, Task};
 use dioxus_hooks::{use_memo, use_reactive, use_signal, Dependency};
 use dioxus_signals::{Memo, ReadOnlySignal, Readable, Signal, Writable};
 use easer::functions::*;
+use freya_core::prelude::AnimationClock;
 use freya_engine::prelude::Color;
 use freya_node_state::Parse;
 use tokio::time::Instant;
@@ -455,6 +456,8 @@ impl<Animated: PartialEq + Clone + 'static> UseAnimator<Animated> {
     }
 
     /// Run the animation with a given [`AnimDirection`]
+    ///
+    /// It jumps to its end right away when the motion is reduced, see [`AnimationClock::set_reduced_motion`].
     pub fn run(&self, mut direction: AnimDirection) {
         let ctx = &self.value_and_ctx.peek().1;
         let platform = self.platform;
@@ -464,6 +467,7 @@ impl<Animated: PartialEq + Clone + 'static> UseAnimator<Animated> {
         let mut has_run_yet = self.has_run_yet;
         let on_finish = ctx.on_finish;
         let mut task = self.task;
+        let clock = try_consume_context::<AnimationClock>().unwrap_or_default();
 
         // Cancel previous animations
         if let Some(task) = task.write().take() {
@@ -473,13 +477,26 @@ impl<Animated: PartialEq + Clone + 'static> UseAnimator<Animated> {
         if !self.peek_has_run_yet() {
             *has_run_yet.write() = true;
         }
+
+        // Jump straight to the end when the motion is reduced
+        if clock.prefers_reduced_motion() {
+            for value in values.iter_mut() {
+                value.write().prepare(direction);
+                let time = value.peek().time().as_millis() as i32;
+                value.write().advance(time, direction);
+            }
+            is_running.set(false);
+            return;
+        }
+
         is_running.set(true);
 
         let animation_task = spawn(async move {
-            platform.request_animation_frame();
+            platform.request_tick();
 
             let mut index = 0;
-            let mut prev_frame = Instant::now();
+            let mut prev_frame = clock.now();
+            let mut prev_real_frame = Instant::now();
 
             // Prepare the animations with the the proper direction
             for value in values.iter_mut() {
@@ -489,9 +506,18 @@ impl<Animated: PartialEq + Clone + 'static> UseAnimator<Animated> {
             loop {
                 // Wait for the event loop to tick
                 ticker.tick().await;
-                platform.request_animation_frame();
 
-                index += prev_frame.elapsed().as_millis() as i32;
+                let mut elapsed = clock.now().duration_since(prev_frame);
+
+                // Don't count the time the Window was occluded, so the animation resumes where it was paused
+                if let Some(resumed_at) = platform.info().resumed_at {
+                    let resumed_at = Instant::from_std(resumed_at);
+                    if resumed_at > prev_real_frame {
+                        elapsed = elapsed.min(resumed_at.elapsed());
+                    }
+                }
+
+                index += elapsed.as_millis() as i32;
 
                 let is_finished = values
                     .iter()
@@ -522,7 +548,11 @@ impl<Animated: PartialEq + Clone + 'static> UseAnimator<Animated> {
                     value.write().advance(index, direction);
                 }
 
-                prev_frame = Instant::now();
+                prev_frame = clock.now();
+                prev_real_frame = Instant::now();
+
+                // Keep ticking until the animation is finished
+                platform.request_tick();
             }
 
             is_running.set(false);
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{consume_context, use_hook};
+use freya_core::prelude::AnimationClock;
+
+use crate::{use_platform, UsePlatform};
+
+/// Pauses, resumes and steps every animation of the app, created with [`use_animation_clock`].
+#[derive(Clone)]
+pub struct UseAnimationClock {
+    clock: AnimationClock,
+    platform: UsePlatform,
+}
+
+impl UseAnimationClock {
+    /// Freeze every animation where it is, the app stays interactive.
+    pub fn pause(&self) {
+        self.clock.pause();
+    }
+
+    /// Let every animation continue from where it was paused.
+    pub fn resume(&self) {
+        self.clock.resume();
+        self.platform.request_tick();
+    }
+
+    /// Advance every animation by the given duration, e.g. to go through a paused animation frame by frame.
+    pub fn step(&self, duration: Duration) {
+        self.clock.step(duration);
+        self.platform.request_tick();
+    }
+
+    /// Check if the animations are paused.
+    /// This is not reactive, keep a signal along with it to show whether they are paused.
+    pub fn is_paused(&self) -> bool {
+        self.clock.is_paused()
+    }
+
+    /// Override whether the motion is reduced, which is detected from the platform at launch where available.
+    /// The transitions and animations that start afterwards skip straight to their end, and the infinite ones aren't played.
+    pub fn set_reduced_motion(&self, reduced_motion: bool) {
+        self.clock.set_reduced_motion(reduced_motion);
+    }
+
+    /// Check if the motion is reduced, e.g. to not move things around that the app animates on its own.
+    pub fn prefers_reduced_motion(&self) -> bool {
+        self.clock.prefers_reduced_motion()
+    }
+
+    /// Get the underlying [`AnimationClock`].
+    pub fn clock(&self) -> &AnimationClock {
+        &self.clock
+    }
+}
+
+/// Control the time of every animation of the app: the `transition` and `animation` attributes and [`use_animation`](crate::use_animation).
+///
+/// Pausing them is useful to debug an animation glitch, and reducing the motion respects the users sensitive to it,
+/// see [`UseAnimationClock::set_reduced_motion`].
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use std::time::Duration;
+/// fn app() -> Element {
+///     let animation_clock = use_animation_clock();
+///     let mut paused = use_signal(|| false);
+///
+///     let toggle = {
+///         let animation_clock = animation_clock.clone();
+///         move |_| {
+///             if paused() {
+///                 animation_clock.resume();
+///             } else {
+///                 animation_clock.pause();
+///             }
+///             paused.toggle();
+///         }
+///     };
+///
+///     rsx!(
+///         Button {
+///             onclick: toggle,
+///             label { if paused() { "Resume" } else { "Pause" } }
+///         }
+///         Button {
+///             // Go one frame forward at 60 FPS
+///             onclick: move |_| animation_clock.step(Duration::from_millis(16)),
+///             label { "Step" }
+///         }
+///         rect {
+///             animation: "spin 1s infinite linear",
+///             width: "50",
+///             height: "50",
+///             background: "red",
+///         }
+///     )
+/// }
+/// ```
+pub fn use_animation_clock() -> UseAnimationClock {
+    let platform = use_platform();
+    use_hook(|| UseAnimationClock {
+        clock: consume_context::<AnimationClock>(),
+        platform,
+    })
+}
@@ -47,14 +47,68 @@ enum AssetUsers {
     ClearTask(Task),
 }
 
+impl AssetUsers {
+    fn is_unused(&self) -> bool {
+        match self {
+            Self::Scopes(scopes) => scopes.is_empty(),
+            Self::ClearTask(_) => true,
+        }
+    }
+}
+
 struct AssetState {
     users: AssetUsers,
     asset_bytes: Signal<Bytes>,
+    last_used: u64,
+}
+
+/// Default limit of bytes that the [`AssetCacher`] keeps around, 128MB.
+pub const DEFAULT_ASSET_CACHER_MAX_SIZE: usize = 128 * 1024 * 1024;
+
+/// Statistics of an [`AssetCacher`], useful to tune its maximum size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AssetCacherStats {
+    /// Amount of times a requested asset was found in the cache.
+    pub hits: usize,
+    /// Amount of times a requested asset was not found in the cache.
+    pub misses: usize,
+    /// Amount of bytes used by the cached assets.
+    pub memory_used: usize,
+    /// Amount of cached assets.
+    pub assets: usize,
+}
+
+#[derive(Clone, Copy)]
+struct AssetCacherState {
+    max_size: Option<usize>,
+    clock: u64,
+    hits: usize,
+    misses: usize,
+}
+
+impl Default for AssetCacherState {
+    fn default() -> Self {
+        Self {
+            max_size: Some(DEFAULT_ASSET_CACHER_MAX_SIZE),
+            clock: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl AssetCacherState {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
 }
 
+/// Cache of assets (e.g. images or fonts) shared by all the components, see [`use_asset_cacher`].
 #[derive(Clone, Copy, Default)]
 pub struct AssetCacher {
     registry: Signal<HashMap<AssetConfiguration, AssetState>>,
+    state: Signal<AssetCacherState>,
 }
 
 impl AssetCacher {
@@ -74,7 +128,8 @@ impl AssetCacher {
         }
 
         // Insert the asset into the cache
-        let asset_bytes = Signal::new(asset_bytes);
+        let last_used = self.state.write().tick();
+        let asset_bytes = Signal::new_in_scope(asset_bytes, ScopeId(0));
         self.registry.write().insert(
             asset_config.clone(),
             AssetState {
@@ -84,12 +139,52 @@ impl AssetCacher {
                 } else {
                     HashSet::default()
                 }),
+                last_used,
             },
         );
 
+        self.evict();
+
         asset_bytes
     }
 
+    /// Remove the least recently used assets that are not being used until
+    /// the cache fits in its maximum size.
+    fn evict(&mut self) {
+        let Some(max_size) = self.state.peek().max_size else {
+            return;
+        };
+
+        let mut registry = self.registry.write();
+        let mut memory_used = registry
+            .values()
+            .map(|asset_state| asset_state.asset_bytes.peek().len())
+            .sum::<usize>();
+
+        if memory_used <= max_size {
+            return;
+        }
+
+        let mut unused = registry
+            .iter()
+            .filter(|(_, asset_state)| asset_state.users.is_unused())
+            .map(|(asset_config, asset_state)| (asset_state.last_used, asset_config.clone()))
+            .collect::<Vec<_>>();
+        unused.sort_by_key(|(last_used, _)| *last_used);
+
+        for (_, asset_config) in unused {
+            if memory_used <= max_size {
+                break;
+            }
+            if let Some(mut asset_state) = registry.remove(&asset_config) {
+                if let AssetUsers::ClearTask(task) = asset_state.users {
+                    task.cancel();
+                }
+                memory_used -= asset_state.asset_bytes.take().len();
+            }
+        }
+    }
+
     /// Stop using an asset. It will get removed after the specified duration if it's not used until then.
     pub fn unuse_asset(&mut self, asset_config: AssetConfiguration) {
         let mut registry = self.registry;
@@ -146,12 +241,14 @@ impl AssetCacher {
     /// Start using an Asset. Your scope will get subscribed, to stop using an asset use [`Self::unuse_asset`]
     pub fn use_asset(&mut self, config: &AssetConfiguration) -> Option<Signal<Bytes>> {
         let mut registry = self.registry.write();
+        let mut state = self.state.write();
         if let Some(asset_state) = registry.get_mut(config) {
+            state.hits += 1;
+            asset_state.last_used = state.tick();
             match &mut asset_state.users {
                 AssetUsers::ClearTask(task) => {
                     // Cancel clear-tasks
                     task.cancel();
-                    asset_state.asset_bytes.take();
 
                     // Start using this asset
                     asset_state.users =
@@ -162,6 +259,8 @@ impl AssetCacher {
                     scopes.insert(current_scope_id().unwrap());
                 }
             }
+        } else {
+            state.misses += 1;
         }
 
         registry.get(config).map(|s| s.asset_bytes)
@@ -176,14 +275,47 @@ impl AssetCacher {
     pub fn clear(&mut self) {
         self.registry.try_write().unwrap().clear();
     }
+
+    /// Limit the amount of bytes kept in the cache, `None` means no limit.
+    /// Once the limit is exceeded the least recently used assets that are not being used get removed,
+    /// even if their [`AssetAge`] has not expired yet.
+    /// The default is [`DEFAULT_ASSET_CACHER_MAX_SIZE`].
+    pub fn set_max_size(&mut self, max_size: Option<usize>) {
+        self.state.write().max_size = max_size;
+        self.evict();
+    }
+
+    /// Get the [`AssetCacherStats`] of the cache.
+    pub fn stats(&self) -> AssetCacherStats {
+        let registry = self.registry.read();
+        let state = self.state.read();
+        AssetCacherStats {
+            hits: state.hits,
+            misses: state.misses,
+            memory_used: registry
+                .values()
+                .map(|asset_state| asset_state.asset_bytes.peek().len())
+                .sum(),
+            assets: registry.len(),
+        }
+    }
 }
 
 /// Global caching system for assets.
 ///
+/// The same cache is shared by all the components, so assets like images or fonts
+/// only need to be loaded once, no matter how many components use them.
+///
 /// This is a "low level" hook, so you probably won't need it.
 pub fn use_asset_cacher() -> AssetCacher {
     match try_consume_context() {
         Some(asset_cacher) => asset_cacher,
-        None => provide_root_context(AssetCacher::default()),
+        None => {
+            // Create the signals in the root scope so they outlive the component that called this first
+            let asset_cacher = Runtime::current()
+                .unwrap()
+                .on_scope(ScopeId(0), AssetCacher::default);
+            provide_root_context(asset_cacher)
+        }
     }
 }
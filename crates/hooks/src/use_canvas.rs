@@ -1,14 +1,16 @@
 use std::sync::Arc;
 
 use dioxus_core::AttributeValue;
-use dioxus_hooks::{use_memo, use_reactive, Dependency};
-use dioxus_signals::{Memo, Readable};
-use freya_node_state::{CanvasReference, CanvasRunner, CustomAttributeValues};
+use dioxus_hooks::{use_memo, use_reactive, use_signal, Dependency};
+use dioxus_signals::{Memo, Readable, Signal};
+use freya_node_state::{CanvasCache, CanvasReference, CanvasRunner, CustomAttributeValues};
 
 /// Holds a rendering hook callback that allows to render to the Canvas.
-#[derive(PartialEq, Clone)]
+#[derive(PartialEq, Clone, Copy)]
 pub struct UseCanvas {
     runner: Memo<UseCanvasRunner>,
+    cache: Option<Memo<CanvasCache>>,
+    generation: Signal<usize>,
 }
 
 #[derive(Clone)]
@@ -24,12 +26,24 @@ impl UseCanvas {
     pub fn attribute(&self) -> AttributeValue {
         AttributeValue::any_value(CustomAttributeValues::Canvas(CanvasReference {
             runner: self.runner.read().0.clone(),
+            cache: self.cache.map(|cache| cache.read().clone()),
         }))
     }
+
+    /// Throw away the recorded output of a cached Canvas so it gets rendered again in the next frame,
+    /// useful for content that changes over time without changing the dependencies, e.g. animations.
+    /// This does nothing for a Canvas created with [`use_canvas`], as it's rendered again in every frame anyway.
+    pub fn invalidate(&mut self) {
+        if self.cache.is_some() {
+            self.generation += 1;
+        }
+    }
 }
 
 /// Register a rendering hook to gain access to the Canvas.
 ///
+/// The Canvas is rendered again in every frame, see [`use_cached_canvas`] for expensive drawings.
+///
 /// ## Usage
 /// ```rust,no_run
 /// # use freya::prelude::*;
@@ -51,12 +65,71 @@ pub fn use_canvas<D: Dependency>(
     dependencies: D,
     renderer_cb: impl Fn(D::Out) -> Box<CanvasRunner> + 'static,
 ) -> UseCanvas
+where
+    D::Out: 'static,
+{
+    use_canvas_with_cache(dependencies, renderer_cb, false)
+}
+
+/// Register a rendering hook to gain access to the Canvas, whose output is recorded and replayed
+/// in the next frames until the `dependencies` change, the Canvas gets resized or moved,
+/// or [`UseCanvas::invalidate`] is called.
+///
+/// Everything must be drawn inside the area of the Canvas, as the recording is clipped to it.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let values = use_signal(|| vec![10.0, 35.0, 20.0]);
+///
+///     // Only drawn again when the values change
+///     let canvas = use_cached_canvas(&*values.read(), |values| {
+///         Box::new(move |canvas, font_collection, area| {
+///             // Draw an expensive chart with the values using the canvas !
+///         })
+///     });
+///
+///     rsx!(
+///         Canvas {
+///             canvas
+///         }
+///     )
+/// }
+/// ```
+pub fn use_cached_canvas<D: Dependency>(
+    dependencies: D,
+    renderer_cb: impl Fn(D::Out) -> Box<CanvasRunner> + 'static,
+) -> UseCanvas
+where
+    D::Out: 'static,
+{
+    use_canvas_with_cache(dependencies, renderer_cb, true)
+}
+
+fn use_canvas_with_cache<D: Dependency>(
+    dependencies: D,
+    renderer_cb: impl Fn(D::Out) -> Box<CanvasRunner> + 'static,
+    cached: bool,
+) -> UseCanvas
 where
     D::Out: 'static,
 {
     let runner = use_memo(use_reactive(dependencies, move |dependencies| {
         UseCanvasRunner(Arc::new(renderer_cb(dependencies)))
     }));
+    let generation = use_signal(|| 0);
 
-    UseCanvas { runner }
+    // A new runner or an invalidation start with an empty cache
+    let cache = use_memo(move || {
+        runner.read();
+        generation.read();
+        CanvasCache::default()
+    });
+
+    UseCanvas {
+        runner,
+        cache: cached.then_some(cache),
+        generation,
+    }
 }
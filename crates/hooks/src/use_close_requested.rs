@@ -0,0 +1,179 @@
+use std::{
+    cell::Cell,
+    sync::{Arc, Mutex},
+};
+
+use dioxus_core::prelude::{consume_context, spawn, use_hook};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::use_platform;
+
+/// Passed to the [`use_close_requested`] handlers, to keep the Window open.
+#[derive(Default)]
+pub struct CloseRequest {
+    prevented: Cell<bool>,
+}
+
+impl CloseRequest {
+    /// Keep the Window open. Use `UsePlatform::exit` to close it later, e.g. once the changes have been saved.
+    pub fn prevent_close(&self) {
+        self.prevented.set(true);
+    }
+
+    /// Check if any handler called [`CloseRequest::prevent_close`].
+    pub fn is_close_prevented(&self) -> bool {
+        self.prevented.get()
+    }
+}
+
+#[derive(Default)]
+struct CloseRequestState {
+    pending_handlers: usize,
+    prevented: bool,
+}
+
+/// Notifies the [`use_close_requested`] handlers when the user asks to close the Window.
+#[derive(Clone)]
+pub struct CloseRequestNotifier {
+    sender: Arc<broadcast::Sender<()>>,
+    state: Arc<Mutex<CloseRequestState>>,
+}
+
+impl Default for CloseRequestNotifier {
+    fn default() -> Self {
+        Self {
+            sender: Arc::new(broadcast::channel(5).0),
+            state: Arc::default(),
+        }
+    }
+}
+
+impl CloseRequestNotifier {
+    /// Notify the handlers that the user asked to close the Window.
+    ///
+    /// Returns `false` if there are no handlers, in which case the Window must be closed right away.
+    /// Otherwise, the handlers close it once all of them ran and none prevented it.
+    pub fn notify(&self) -> bool {
+        let handlers = self.sender.receiver_count();
+        if handlers == 0 {
+            return false;
+        }
+
+        *self.state.lock().unwrap() = CloseRequestState {
+            pending_handlers: handlers,
+            prevented: false,
+        };
+        self.sender.send(()).ok();
+        true
+    }
+
+    /// Register the response of a handler.
+    /// Returns `true` if it was the last one and none of them prevented closing the Window.
+    fn respond(&self, prevented: bool) -> bool {
+        let mut state = self.state.lock().unwrap();
+        state.prevented |= prevented;
+        state.pending_handlers = state.pending_handlers.saturating_sub(1);
+        state.pending_handlers == 0 && !state.prevented
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<()> {
+        self.sender.subscribe()
+    }
+}
+
+/// Run a handler when the user asks to close the Window, e.g. with the close button of the title bar.
+///
+/// The Window is still closed after all the handlers ran, unless any of them calls [`CloseRequest::prevent_close`],
+/// which is useful to ask the user to save their changes first. Without any handler the Window is closed right away.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let platform = use_platform();
+///     let mut show_prompt = use_signal(|| false);
+///
+///     use_close_requested(move |request| {
+///         request.prevent_close();
+///         show_prompt.set(true);
+///     });
+///
+///     rsx!(
+///         if show_prompt() {
+///             label {
+///                 "You have unsaved changes"
+///             }
+///             Button {
+///                 onclick: move |_| platform.exit(),
+///                 label {
+///                     "Discard and close"
+///                 }
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_close_requested(mut handler: impl FnMut(&CloseRequest) + 'static) {
+    let platform = use_platform();
+    use_hook(move || {
+        let notifier = consume_context::<CloseRequestNotifier>();
+        let mut requests = notifier.subscribe();
+
+        spawn(async move {
+            loop {
+                match requests.recv().await {
+                    Ok(()) => {}
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                }
+
+                let request = CloseRequest::default();
+                handler(&request);
+
+                if notifier.respond(request.is_close_prevented()) {
+                    platform.exit();
+                }
+            }
+        });
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn close_requested() {
+        fn use_close_requested_app() -> Element {
+            let mut requests = use_signal(|| 0);
+
+            use_close_requested(move |request| {
+                requests += 1;
+                // Only close on the second attempt
+                if requests() == 1 {
+                    request.prevent_close();
+                }
+            });
+
+            rsx!(label { "{requests}" })
+        }
+
+        let mut utils = launch_test(use_close_requested_app);
+        utils.wait_for_update().await;
+
+        utils.request_close();
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(0).get(0).text(), Some("1"));
+        assert!(!utils.is_closed());
+
+        utils.request_close();
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        assert_eq!(utils.root().get(0).get(0).text(), Some("2"));
+        assert!(utils.is_closed());
+    }
+}
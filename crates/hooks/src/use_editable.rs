@@ -170,7 +170,7 @@ pub fn use_editable(initializer: impl Fn() -> EditableConfig, mode: EditableMode
             clipboard,
             EditorHistory::new(),
         ));
-        let selecting_text_with_mouse = Signal::new(None);
+        let mut selecting_text_with_mouse = Signal::new(None);
         let (cursor_sender, mut cursor_receiver) = unbounded_channel::<CursorLayoutResponse>();
         let cursor_reference = CursorReference {
             text_id,
@@ -227,6 +227,10 @@ pub fn use_editable(initializer: impl Fn() -> EditableConfig, mode: EditableMode
                             editor.write().highlight_text(from, to, id);
                             cursor_reference.set_cursor_selections(None);
                         }
+                        // The text under the cursor can't be selected, so there is nothing to drag
+                        CursorLayoutResponse::Unselectable { .. } => {
+                            selecting_text_with_mouse.set(None);
+                        }
                     }
                 }
             }
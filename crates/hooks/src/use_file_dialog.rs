@@ -0,0 +1,186 @@
+use std::path::PathBuf;
+
+use dioxus_core::prelude::spawn;
+use dioxus_hooks::use_signal;
+use dioxus_signals::{ReadOnlySignal, Readable, Signal, Writable};
+use rfd::{AsyncFileDialog, FileHandle};
+
+/// Configuration for a native file dialog.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct FileDialogOptions {
+    title: Option<String>,
+    directory: Option<PathBuf>,
+    file_name: Option<String>,
+    filters: Vec<(String, Vec<String>)>,
+}
+
+impl FileDialogOptions {
+    /// Specify the title of the dialog.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Specify the directory the dialog starts in.
+    pub fn with_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Specify the default file name, only used by the save dialog.
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Only show the files with one of the given extensions (without the dot), e.g. `("Images", &["png", "jpg"])`.
+    /// Can be called multiple times to let the user choose between filters.
+    pub fn with_filter(mut self, name: impl Into<String>, extensions: &[&str]) -> Self {
+        self.filters.push((
+            name.into(),
+            extensions.iter().map(|ext| ext.to_string()).collect(),
+        ));
+        self
+    }
+
+    fn build(self) -> AsyncFileDialog {
+        let mut dialog = AsyncFileDialog::new();
+        if let Some(title) = self.title {
+            dialog = dialog.set_title(title);
+        }
+        if let Some(directory) = self.directory {
+            dialog = dialog.set_directory(directory);
+        }
+        if let Some(file_name) = self.file_name {
+            dialog = dialog.set_file_name(file_name);
+        }
+        for (name, extensions) in self.filters {
+            dialog = dialog.add_filter(name, &extensions);
+        }
+        dialog
+    }
+}
+
+/// Kind of native file dialog to open.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum FileDialogKind {
+    PickFile,
+    PickFiles,
+    PickFolder,
+    PickFolders,
+    SaveFile,
+}
+
+/// Manage native file dialogs, created with [`use_file_dialog`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseFileDialog {
+    paths: Signal<Vec<PathBuf>>,
+    is_open: Signal<bool>,
+}
+
+impl UseFileDialog {
+    /// Let the user pick a file.
+    pub fn pick_file(&mut self, options: FileDialogOptions) {
+        self.open(FileDialogKind::PickFile, options);
+    }
+
+    /// Let the user pick one or more files.
+    pub fn pick_files(&mut self, options: FileDialogOptions) {
+        self.open(FileDialogKind::PickFiles, options);
+    }
+
+    /// Let the user pick a directory.
+    pub fn pick_folder(&mut self, options: FileDialogOptions) {
+        self.open(FileDialogKind::PickFolder, options);
+    }
+
+    /// Let the user pick one or more directories.
+    pub fn pick_folders(&mut self, options: FileDialogOptions) {
+        self.open(FileDialogKind::PickFolders, options);
+    }
+
+    /// Let the user choose where to save a file.
+    pub fn save_file(&mut self, options: FileDialogOptions) {
+        self.open(FileDialogKind::SaveFile, options);
+    }
+
+    /// Get the paths selected in the last dialog, empty if it was cancelled.
+    pub fn paths(&self) -> ReadOnlySignal<Vec<PathBuf>> {
+        self.paths.into()
+    }
+
+    /// Check if there is a dialog open.
+    pub fn is_open(&self) -> bool {
+        *self.is_open.read()
+    }
+
+    fn open(&mut self, kind: FileDialogKind, options: FileDialogOptions) {
+        // Only one dialog at a time
+        if *self.is_open.peek() {
+            return;
+        }
+        self.is_open.set(true);
+
+        let mut paths = self.paths;
+        let mut is_open = self.is_open;
+        spawn(async move {
+            let dialog = options.build();
+            let handles = match kind {
+                FileDialogKind::PickFile => dialog.pick_file().await.map(|file| vec![file]),
+                FileDialogKind::PickFiles => dialog.pick_files().await,
+                FileDialogKind::PickFolder => dialog.pick_folder().await.map(|file| vec![file]),
+                FileDialogKind::PickFolders => dialog.pick_folders().await,
+                FileDialogKind::SaveFile => dialog.save_file().await.map(|file| vec![file]),
+            };
+
+            paths.set(
+                handles
+                    .unwrap_or_default()
+                    .iter()
+                    .map(FileHandle::path)
+                    .map(PathBuf::from)
+                    .collect(),
+            );
+            is_open.set(false);
+        });
+    }
+}
+
+/// Open native dialogs to pick files or directories, or to choose where to save a file.
+///
+/// The dialogs are shown by the OS outside of the UI thread, so the app keeps rendering while they are open.
+/// The VirtualDOM gets woken up once the user closes the dialog, and the selected paths are written to [`UseFileDialog::paths`].
+///
+/// Requires the `use_file_dialog` feature.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut file_dialog = use_file_dialog();
+///
+///     rsx!(
+///         Button {
+///             onclick: move |_| {
+///                 file_dialog.pick_files(
+///                     FileDialogOptions::default().with_filter("Images", &["png", "jpg"]),
+///                 )
+///             },
+///             label {
+///                 "Open"
+///             }
+///         }
+///         for path in file_dialog.paths().read().iter() {
+///             label {
+///                 "{path.display()}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_file_dialog() -> UseFileDialog {
+    let paths = use_signal(Vec::new);
+    let is_open = use_signal(|| false);
+
+    UseFileDialog { paths, is_open }
+}
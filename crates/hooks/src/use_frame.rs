@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use dioxus_core::prelude::{spawn, use_hook};
+use tokio::time::Instant;
+
+use crate::use_platform;
+
+/// Run a callback in every frame with the time elapsed since the previous one, e.g. to advance the state of a game or a physics simulation.
+///
+/// The callback returns whether it needs another frame. While it returns `true` new frames keep being requested,
+/// at the pace the Window presents them (usually its refresh rate), but the Window is only repainted if the state changed.
+/// Once it returns `false` the app can go idle again, and the callback only runs in the frames produced for other reasons,
+/// e.g. a state change from an event handler, which is also how to start it again.
+///
+/// The callback is captured when the component is mounted, so use signals for the values that change over time.
+///
+/// ### Timing
+/// The elapsed time is measured when the frame starts, before it gets rendered, so it's as accurate as the scheduling
+/// of the frames. Frames can be skipped when the app can't keep up, then the elapsed time covers all of them.
+/// It also includes the time the app spent idle since the previous call, but not the time the Window was minimized or hidden,
+/// during which the frames, and therefore the callback, are paused.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut x = use_signal(|| 0.0);
+///
+///     use_frame(move |elapsed| {
+///         // Move at 100 pixels per second until reaching the end
+///         x.with_mut(|x| *x = (*x + 100.0 * elapsed.as_secs_f32()).min(300.0));
+///         x() < 300.0
+///     });
+///
+///     rsx!(
+///         rect {
+///             offset_x: "{x}",
+///             width: "50",
+///             height: "50",
+///             background: "red",
+///         }
+///     )
+/// }
+/// ```
+pub fn use_frame(mut callback: impl FnMut(Duration) -> bool + 'static) {
+    let platform = use_platform();
+
+    use_hook(move || {
+        let mut ticker = platform.new_ticker();
+
+        spawn(async move {
+            platform.request_tick();
+
+            let mut prev_frame = Instant::now();
+
+            loop {
+                ticker.tick().await;
+
+                // Don't count the time the Window was occluded
+                if let Some(resumed_at) = platform.info().resumed_at {
+                    prev_frame = prev_frame.max(Instant::from_std(resumed_at));
+                }
+
+                let now = Instant::now();
+                let needs_frame = callback(now - prev_frame);
+                prev_frame = now;
+
+                if needs_frame {
+                    platform.request_tick();
+                }
+            }
+        });
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn frame() {
+        fn use_frame_app() -> Element {
+            let mut frames = use_signal(|| 0);
+
+            use_frame(move |_| {
+                if frames() < 3 {
+                    frames += 1;
+                }
+                frames() < 3
+            });
+
+            rsx!(label { "{frames}" })
+        }
+
+        let mut utils = launch_test(use_frame_app);
+        for _ in 0..5 {
+            utils.wait_for_update().await;
+        }
+
+        // It stopped requesting frames after the third one
+        let label = utils.root().get(0);
+        assert_eq!(label.get(0).text(), Some("3"));
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use dioxus_core::AttributeValue;
+use dioxus_hooks::{use_memo, use_reactive, Dependency};
+use dioxus_signals::{Memo, Readable};
+use freya_node_state::{CustomAttributeValues, GpuCanvasReference, GpuCanvasRunner};
+
+/// Holds a rendering callback that draws directly with the GPU, see [`use_gpu_canvas`].
+#[derive(PartialEq, Clone, Copy)]
+pub struct UseGpuCanvas {
+    runner: Memo<UseGpuCanvasRunner>,
+}
+
+#[derive(Clone)]
+pub struct UseGpuCanvasRunner(pub Arc<Box<GpuCanvasRunner>>);
+
+impl PartialEq for UseGpuCanvasRunner {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl UseGpuCanvas {
+    /// Value for the `gpu_canvas_reference` attribute of the element to draw in.
+    pub fn attribute(&self) -> AttributeValue {
+        AttributeValue::any_value(CustomAttributeValues::GpuCanvas(GpuCanvasReference {
+            runner: self.runner.read().0.clone(),
+        }))
+    }
+}
+
+/// Register a rendering callback that draws directly with the GPU in the area of an element,
+/// e.g. to show the output of a game engine or a video decoder without copying it through the CPU.
+///
+/// The callback runs in every frame the element is rendered, right after its background
+/// and before its children, so it keeps its place in the order of the elements. It gets:
+///
+/// - The canvas of the Window, which can wrap the GPU content in Skia images or draw with them.
+/// - The GPU context of the Window, to create GPU resources, e.g. `Surface`s or textures.
+/// - The area of the element, in physical pixels from the top-left corner of the Window.
+///
+/// Nothing is drawn when the element is rendered outside of the Window, e.g. into node snapshots.
+///
+/// ### Clipping
+/// What is drawn with the canvas is clipped to the area of the element and to the `overflow: "clip"`
+/// of its ancestors. Raw GPU commands skip Skia entirely, so they must clip themselves, e.g. with a scissor
+/// test to the area, flipping it vertically when the backend has its origin in the bottom-left corner.
+///
+/// ### Resizing
+/// There is no separate notification, the area comes in every call instead, so resources that depend on the size
+/// can be recreated whenever it's different from the previous one.
+///
+/// ### GPU state
+/// Skia flushes its work before the callback and forgets its assumptions about the state of the GPU after it,
+/// so raw GPU commands can change any state without restoring it.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let gpu_canvas = use_gpu_canvas((), |_| {
+///         Box::new(|canvas, direct_context, area| {
+///             // Draw using the GPU context !
+///         })
+///     });
+///
+///     rsx!(
+///         rect {
+///             width: "100%",
+///             height: "100%",
+///             gpu_canvas_reference: gpu_canvas.attribute()
+///         }
+///     )
+/// }
+/// ```
+pub fn use_gpu_canvas<D: Dependency>(
+    dependencies: D,
+    renderer_cb: impl Fn(D::Out) -> Box<GpuCanvasRunner> + 'static,
+) -> UseGpuCanvas
+where
+    D::Out: 'static,
+{
+    let runner = use_memo(use_reactive(dependencies, move |dependencies| {
+        UseGpuCanvasRunner(Arc::new(renderer_cb(dependencies)))
+    }));
+
+    UseGpuCanvas { runner }
+}
@@ -0,0 +1,175 @@
+use dioxus_core::prelude::{consume_context, spawn, use_hook};
+use dioxus_signals::{ReadOnlySignal, Signal, Writable};
+use freya_elements::events::keyboard::{Code, Key, Modifiers};
+use rustc_hash::FxHashMap;
+use tokio::sync::watch;
+
+/// Keys and modifiers being held in the Window, see [`use_keyboard_state`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyboardState {
+    /// Keys being held, by their physical key.
+    pub pressed_keys: FxHashMap<Code, Key>,
+    /// Modifiers being held.
+    pub modifiers: Modifiers,
+}
+
+impl KeyboardState {
+    /// Check if a key is being held, by its logical key.
+    pub fn is_key_pressed(&self, key: &Key) -> bool {
+        self.pressed_keys
+            .values()
+            .any(|pressed_key| pressed_key == key)
+    }
+
+    /// Check if a key is being held, by its physical key.
+    pub fn is_code_pressed(&self, code: Code) -> bool {
+        self.pressed_keys.contains_key(&code)
+    }
+}
+
+/// Keeps the [`KeyboardState`] of the Window up to date for the [`use_keyboard_state`] hooks.
+#[derive(Clone)]
+pub struct KeyboardStateNotifier(watch::Sender<KeyboardState>);
+
+impl Default for KeyboardStateNotifier {
+    fn default() -> Self {
+        Self(watch::channel(KeyboardState::default()).0)
+    }
+}
+
+impl KeyboardStateNotifier {
+    /// Track a key that got pressed.
+    /// Keys without a physical key, e.g. the text committed by an IME, are never released so they aren't tracked.
+    pub fn press(&self, code: Code, key: Key, modifiers: Modifiers) {
+        self.0.send_if_modified(|state| {
+            let changed = state.modifiers != modifiers;
+            state.modifiers = modifiers;
+            if code == Code::Unidentified {
+                return changed;
+            }
+            state.pressed_keys.insert(code, key.clone()) != Some(key) || changed
+        });
+    }
+
+    /// Track a key that got released.
+    pub fn release(&self, code: Code, modifiers: Modifiers) {
+        self.0.send_if_modified(|state| {
+            let changed = state.modifiers != modifiers;
+            state.modifiers = modifiers;
+            state.pressed_keys.remove(&code).is_some() || changed
+        });
+    }
+
+    /// Update the modifiers being held.
+    pub fn set_modifiers(&self, modifiers: Modifiers) {
+        self.0.send_if_modified(|state| {
+            let changed = state.modifiers != modifiers;
+            state.modifiers = modifiers;
+            changed
+        });
+    }
+
+    /// Release every key and modifier, e.g. when the Window loses the focus.
+    /// Returns the keys that were being held.
+    pub fn release_all(&self) -> Vec<(Code, Key)> {
+        let mut released_keys = Vec::new();
+        self.0.send_if_modified(|state| {
+            let changed = !state.pressed_keys.is_empty() || !state.modifiers.is_empty();
+            released_keys.extend(state.pressed_keys.drain());
+            state.modifiers = Modifiers::empty();
+            changed
+        });
+        released_keys
+    }
+
+    fn subscribe(&self) -> watch::Receiver<KeyboardState> {
+        self.0.subscribe()
+    }
+}
+
+/// Subscribe to the keys and modifiers being held in the Window, e.g. to show alternative actions while `Alt` is held,
+/// without having to track the keyboard events.
+///
+/// It's updated with every key that gets pressed or released, and cleared when the Window loses the focus,
+/// as the keys released meanwhile can't be noticed. Each Window has its own state, only with its own keys.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let keyboard = use_keyboard_state();
+///     let is_alt_held = use_memo(move || keyboard.read().modifiers.alt());
+///
+///     rsx!(
+///         label {
+///             if is_alt_held() { "Delete permanently" } else { "Move to trash" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_keyboard_state() -> ReadOnlySignal<KeyboardState> {
+    use_hook(|| {
+        let mut rx = consume_context::<KeyboardStateNotifier>().subscribe();
+        let mut signal = Signal::new(rx.borrow().clone());
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                signal.set(rx.borrow_and_update().clone());
+            }
+        });
+
+        signal.into()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn keyboard_state() {
+        fn use_keyboard_state_app() -> Element {
+            let keyboard = use_keyboard_state();
+            let keyboard = keyboard.read();
+
+            rsx!(
+                label {
+                    "{keyboard.is_code_pressed(Code::KeyA)} {keyboard.modifiers.shift()}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_keyboard_state_app);
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0);
+        assert_eq!(label.get(0).text(), Some("false false"));
+
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyDown,
+            key: Key::Character("A".to_string()),
+            code: Code::KeyA,
+            modifiers: Modifiers::SHIFT,
+            repeat: false,
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0);
+        assert_eq!(label.get(0).text(), Some("true true"));
+
+        utils.push_event(PlatformEvent::Keyboard {
+            name: EventName::KeyUp,
+            key: Key::Character("A".to_string()),
+            code: Code::KeyA,
+            modifiers: Modifiers::empty(),
+            repeat: false,
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0);
+        assert_eq!(label.get(0).text(), Some("false false"));
+    }
+}
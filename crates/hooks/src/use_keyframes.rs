@@ -0,0 +1,37 @@
+use dioxus_core::prelude::{consume_context, use_hook};
+use freya_core::prelude::KeyframesRegistry;
+use freya_node_state::Keyframes;
+
+/// Register the given [`Keyframes`] under `name`, so elements can play them with the `animation` and `exit_animation` attributes.
+///
+/// The keyframes are registered for the whole app when the component is first rendered,
+/// so it must be called in a component above the animated elements, e.g. the root component.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     use_keyframes("pulse", || {
+///         Keyframes::new()
+///             .keyframe(0.0, [("opacity", "1")])
+///             .keyframe(1.0, [("opacity", "0.3")])
+///     });
+///
+///     rsx!(
+///         rect {
+///             animation: "pulse 800ms infinite alternate ease-in-out",
+///             width: "100",
+///             height: "100",
+///             background: "red",
+///         }
+///     )
+/// }
+/// ```
+pub fn use_keyframes(name: &str, keyframes: impl FnOnce() -> Keyframes) {
+    use_hook(|| {
+        consume_context::<KeyframesRegistry>()
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), keyframes());
+    });
+}
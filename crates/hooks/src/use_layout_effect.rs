@@ -0,0 +1,165 @@
+use std::sync::Arc;
+
+use dioxus_core::{
+    prelude::{consume_context, spawn},
+    use_hook, AttributeValue,
+};
+use dioxus_hooks::{use_effect, use_reactive, Dependency};
+use dioxus_signals::{Signal, Writable};
+use freya_common::NodeReferenceLayout;
+use freya_node_state::{CustomAttributeValues, NodeReference};
+use tokio::sync::{
+    broadcast::{self, error::RecvError},
+    watch::channel,
+};
+
+use crate::use_platform;
+
+/// Notifies the [`use_layout_effect`] hooks that the layout of a frame has been measured.
+#[derive(Clone)]
+pub struct LayoutNotifier(Arc<broadcast::Sender<()>>);
+
+impl Default for LayoutNotifier {
+    fn default() -> Self {
+        Self(Arc::new(broadcast::channel(5).0))
+    }
+}
+
+impl LayoutNotifier {
+    /// Notify that the layout of the current frame has been measured.
+    pub fn notify(&self) {
+        self.0.send(()).ok();
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<()> {
+        self.0.subscribe()
+    }
+}
+
+/// Run a callback with the measured layout of a Node, once the layout of the frame
+/// in which the component was mounted or `dependencies` changed has been measured.
+///
+/// The callback only runs once per mount or change of `dependencies`, so it can safely change
+/// things that affect the layout of the Node without running again in an infinite loop.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut text = use_signal(|| "Hello, World!".to_string());
+///     let mut fits = use_signal(|| true);
+///
+///     // Check again every time the text changes
+///     let reference = use_layout_effect(&text(), move |layout, _| {
+///         fits.set(layout.area.width() <= 200.);
+///     });
+///
+///     rsx!(
+///         rect {
+///             reference,
+///             label {
+///                 color: if fits() { "black" } else { "red" },
+///                 onclick: move |_| text.write().push_str(" Hello!"),
+///                 "{text}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_layout_effect<D: Dependency>(
+    dependencies: D,
+    mut callback: impl FnMut(NodeReferenceLayout, D::Out) + 'static,
+) -> AttributeValue
+where
+    D::Out: 'static,
+{
+    let platform = use_platform();
+    let (tx, mut pending) = use_hook(|| {
+        let (tx, rx) = channel::<NodeReferenceLayout>(NodeReferenceLayout::default());
+        let mut layouts = consume_context::<LayoutNotifier>().subscribe();
+        let mut pending = Signal::new(None::<D::Out>);
+
+        spawn(async move {
+            loop {
+                if let Err(RecvError::Closed) = layouts.recv().await {
+                    break;
+                }
+
+                // The receiver is never marked as seen, so this only tells if the Node was ever measured
+                if !rx.has_changed().unwrap_or_default() {
+                    continue;
+                }
+
+                let Some(dependencies) = pending.write().take() else {
+                    continue;
+                };
+
+                let layout = rx.borrow().clone();
+                callback(layout, dependencies);
+            }
+        });
+
+        (Arc::new(tx), pending)
+    });
+
+    use_effect(use_reactive(dependencies, move |dependencies| {
+        pending.set(Some(dependencies));
+        // Make sure there is a new frame even if nothing else changed
+        platform.request_tick();
+    }));
+
+    AttributeValue::any_value(CustomAttributeValues::Reference(NodeReference(tx)))
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn layout_effect() {
+        fn layout_effect_app() -> Element {
+            let mut width = use_signal(|| 50);
+            let mut measured = use_signal(|| 0.);
+            let mut runs = use_signal(|| 0);
+
+            let reference = use_layout_effect(&width(), move |layout, _| {
+                measured.set(layout.area.width());
+                runs += 1;
+            });
+
+            rsx!(
+                rect {
+                    reference,
+                    width: "{width}",
+                    height: "50",
+                    onclick: move |_| width += 50,
+                }
+                label {
+                    "{measured} {runs}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(layout_effect_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(1);
+        assert_eq!(label.get(0).text(), Some("50 1"));
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        // The label got laid out again, but the effect only ran once more
+        let label = utils.root().get(1);
+        assert_eq!(label.get(0).text(), Some("100 2"));
+    }
+}
@@ -0,0 +1,167 @@
+use dioxus_core::prelude::{consume_context, spawn, use_hook};
+use dioxus_hooks::{use_memo, use_reactive};
+use dioxus_signals::{Memo, ReadOnlySignal, Readable, Signal, Writable};
+use tokio::sync::watch;
+use torin::geometry::Size2D;
+
+/// Notifies the [`use_window_size`] and [`use_media_query`] hooks when the Window gets resized.
+#[derive(Clone)]
+pub struct WindowSizeNotifier(watch::Sender<Size2D>);
+
+impl Default for WindowSizeNotifier {
+    fn default() -> Self {
+        Self::new(Size2D::default())
+    }
+}
+
+impl WindowSizeNotifier {
+    pub fn new(size: Size2D) -> Self {
+        Self(watch::channel(size).0)
+    }
+
+    /// Update the logical size of the Window.
+    /// Subscribers are only notified if it actually changed.
+    pub fn set(&self, size: Size2D) {
+        self.0.send_if_modified(|current| {
+            if *current != size {
+                *current = size;
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    fn subscribe(&self) -> watch::Receiver<Size2D> {
+        self.0.subscribe()
+    }
+}
+
+/// Conditions on the size of the Window, in logical pixels, checked by [`use_media_query`].
+/// The bounds are inclusive, and the conditions that aren't specified always match.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MediaQuery {
+    pub min_width: Option<f32>,
+    pub max_width: Option<f32>,
+    pub min_height: Option<f32>,
+    pub max_height: Option<f32>,
+}
+
+impl MediaQuery {
+    /// Query that matches any size.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = Some(min_width);
+        self
+    }
+
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    pub fn min_height(mut self, min_height: f32) -> Self {
+        self.min_height = Some(min_height);
+        self
+    }
+
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Check if the given size of the Window matches all the conditions.
+    pub fn matches(&self, size: Size2D) -> bool {
+        self.min_width.is_none_or(|min| size.width >= min)
+            && self.max_width.is_none_or(|max| size.width <= max)
+            && self.min_height.is_none_or(|min| size.height >= min)
+            && self.max_height.is_none_or(|max| size.height <= max)
+    }
+}
+
+/// Subscribe to the logical size of the Window, which is the fixed resolution if the app is laid out at one.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let size = use_window_size();
+///
+///     rsx!(
+///         label {
+///             "{size.read().width} x {size.read().height}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_window_size() -> ReadOnlySignal<Size2D> {
+    use_hook(|| {
+        let mut rx = consume_context::<WindowSizeNotifier>().subscribe();
+        let mut signal = Signal::new(*rx.borrow());
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                signal.set(*rx.borrow_and_update());
+            }
+        });
+
+        signal.into()
+    })
+}
+
+/// Check if the size of the Window matches the given [`MediaQuery`], it updates when the Window gets resized
+/// across its bounds or when the query changes. Useful to switch between the variants of a responsive layout.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let is_wide = use_media_query(MediaQuery::new().min_width(800.0));
+///
+///     rsx!(
+///         rect {
+///             direction: if is_wide() { "horizontal" } else { "vertical" },
+///             label { "Sidebar" }
+///             label { "Content" }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_media_query(query: MediaQuery) -> Memo<bool> {
+    let size = use_window_size();
+
+    use_memo(use_reactive(&query, move |query| {
+        query.matches(*size.read())
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn media_query() {
+        fn use_media_query_app() -> Element {
+            let is_wide = use_media_query(MediaQuery::new().min_width(800.0));
+
+            rsx!(label { "{is_wide}" })
+        }
+
+        let mut utils = launch_test(use_media_query_app);
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0);
+        assert_eq!(label.get(0).text(), Some("false"));
+
+        utils.resize((900.0, 500.0).into());
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0);
+        assert_eq!(label.get(0).text(), Some("true"));
+    }
+}
@@ -29,7 +29,34 @@ pub fn use_node() -> (AttributeValue, NodeReferenceLayout) {
     )
 }
 
-/// Get a signal to read the latest layout from a Node.
+/// Get a signal to read the latest layout from a Node, including the part of it that is visible
+/// inside of its clipping ancestors (e.g. a `ScrollView`) and the Window.
+///
+/// The signal only notifies its subscribers when the layout actually changes, and reading it doesn't cause
+/// a new layout by itself, so it can drive animations or conditional rendering without feedback loops.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let (reference, layout) = use_node_signal();
+///     let is_visible = layout.read().is_visible();
+///
+///     rsx!(
+///         ScrollView {
+///             rect {
+///                 height: "1000",
+///             }
+///             rect {
+///                 reference,
+///                 background: if is_visible { "green" } else { "red" },
+///                 width: "100",
+///                 height: "100",
+///             }
+///         }
+///     )
+/// }
+/// ```
 pub fn use_node_signal() -> (AttributeValue, ReadOnlySignal<NodeReferenceLayout>) {
     let (tx, signal) = use_hook(|| {
         let (tx, mut rx) = channel::<NodeReferenceLayout>(NodeReferenceLayout::default());
@@ -54,7 +81,7 @@ pub fn use_node_signal() -> (AttributeValue, ReadOnlySignal<NodeReferenceLayout>
 
 #[cfg(test)]
 mod test {
-    use crate::use_node;
+    use crate::{use_node, use_node_signal};
     use freya::prelude::*;
     use freya_testing::prelude::*;
 
@@ -99,4 +126,51 @@ mod test {
             Ok(300.0 * 0.5)
         );
     }
+
+    #[tokio::test]
+    pub async fn track_visibility() {
+        fn use_node_signal_app() -> Element {
+            let (reference, layout) = use_node_signal();
+            let mut offset_y = use_signal(|| 0);
+            let layout = layout.read();
+
+            rsx!(
+                rect {
+                    height: "100",
+                    overflow: "clip",
+                    onclick: move |_| offset_y -= 150,
+                    rect {
+                        offset_y: "{offset_y}",
+                        rect {
+                            reference,
+                            width: "100",
+                            height: "50",
+                        }
+                    }
+                }
+                label {
+                    "{layout.is_visible()} {layout.visible_area.height()}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_node_signal_app);
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(1);
+        assert_eq!(label.get(0).text(), Some("true 50"));
+
+        // Scroll the Node out of the clipping rect
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (5.0, 5.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(1);
+        assert_eq!(label.get(0).text(), Some("false 0"));
+    }
 }
@@ -1,11 +1,23 @@
-use std::sync::{Arc, Mutex};
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
 
 use dioxus_core::prelude::{consume_context, try_consume_context, use_hook};
 use dioxus_signals::{Readable, Signal};
-use freya_common::EventMessage;
-use tokio::sync::{broadcast, mpsc::UnboundedSender};
-use torin::geometry::Size2D;
-use winit::{dpi::PhysicalSize, event_loop::EventLoopProxy, window::CursorIcon};
+use freya_common::{EventMessage, HitRegion, NodeSnapshot, PlatformEvent};
+use freya_core::types::AccessibilityLive;
+use freya_engine::prelude::Color;
+use freya_native_core::NodeId;
+use freya_node_state::Parse;
+use tokio::sync::{broadcast, mpsc::UnboundedSender, oneshot};
+use torin::geometry::{CursorPoint, Size2D};
+use winit::{
+    dpi::PhysicalSize,
+    event_loop::EventLoopProxy,
+    window::{CursorGrabMode, CursorIcon, Window, WindowLevel},
+};
 
 #[derive(Clone, Copy, PartialEq)]
 pub struct UsePlatform {
@@ -49,10 +61,246 @@ impl UsePlatform {
         self.send(EventMessage::SetCursorIcon(cursor_icon)).ok();
     }
 
+    /// Change the level of the Window.
+    ///
+    /// On Linux this is only a hint to the window manager or compositor, which might ignore it.
+    pub fn set_window_level(&self, level: WindowLevel) {
+        self.send(EventMessage::SetWindowLevel(level)).ok();
+    }
+
+    /// Keep the Window above all the other windows, or bring it back to the normal level.
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        self.set_window_level(if always_on_top {
+            WindowLevel::AlwaysOnTop
+        } else {
+            WindowLevel::Normal
+        });
+    }
+
+    /// Move the mouse cursor to the given position, in logical pixels relative to the Window.
+    ///
+    /// Warping the cursor is not supported everywhere, in which case nothing happens:
+    /// - It is not possible on iOS, Android and the Web.
+    /// - On Wayland it only works while the cursor is locked into the Window.
+    /// - On macOS no movement event is emitted for the new position, and the cursor might
+    ///   not respond for a short while right after warping it.
+    ///
+    /// [`PlatformInformation::cursor_position`] is updated right away in all the cases where it succeeds.
+    pub fn set_cursor_position(&self, position: (f64, f64)) {
+        self.send(EventMessage::SetCursorPosition(position)).ok();
+    }
+
+    /// Change the minimum `(width, height)` of the Window in logical pixels, `None` to remove it.
+    ///
+    /// The layout never gets smaller than it, even if the platform makes the Window smaller anyway.
+    pub fn set_window_min_size(&self, min_size: Option<(f64, f64)>) {
+        self.send(EventMessage::SetWindowMinSize(min_size)).ok();
+    }
+
+    /// Change the maximum `(width, height)` of the Window in logical pixels, `None` to remove it.
+    pub fn set_window_max_size(&self, max_size: Option<(f64, f64)>) {
+        self.send(EventMessage::SetWindowMaxSize(max_size)).ok();
+    }
+
+    /// Start writing every frame of the Window into `directory` as numbered PNG images (`frame_00000.png`, ...),
+    /// at a fixed rate of `frames_per_second`. The app keeps running as usual meanwhile.
+    ///
+    /// There is one image for every interval since the capture started, even if nothing was rendered during it,
+    /// so the images can be turned into a video with the same rate, e.g. `ffmpeg -framerate 30 -i frame_%05d.png demo.mp4`.
+    /// Any previous capture is stopped.
+    pub fn start_frame_capture(&self, directory: impl Into<PathBuf>, frames_per_second: u32) {
+        self.start_frame_capture_supersampled(directory, frames_per_second, 1);
+    }
+
+    /// Like [`start_frame_capture`](Self::start_frame_capture), but every frame is rendered again offscreen
+    /// at `supersampling` (up to 4) times the resolution in each direction and downsampled, for crisper images.
+    ///
+    /// The edges of shapes, strokes and the glyphs of text get their coverage from many samples instead of being
+    /// approximated, so thin lines and curves are smoother and adjacent shapes don't leave faint seams between them.
+    /// The images have the same size as the Window and don't include the letterboxing or the devtools overlays.
+    ///
+    /// It's costly: rendering takes about `supersampling²` times the work and the memory of a frame,
+    /// so the app might not keep up with the rate while capturing, which is fine as the timing of the images is kept.
+    pub fn start_frame_capture_supersampled(
+        &self,
+        directory: impl Into<PathBuf>,
+        frames_per_second: u32,
+        supersampling: u32,
+    ) {
+        self.send(EventMessage::StartFrameCapture {
+            directory: directory.into(),
+            frames_per_second,
+            supersampling,
+        })
+        .ok();
+    }
+
+    /// Stop the capture started with [`start_frame_capture`](Self::start_frame_capture).
+    pub fn stop_frame_capture(&self) {
+        self.send(EventMessage::StopFrameCapture).ok();
+    }
+
+    /// Change the background color of the Window, e.g. `"rgb(30, 30, 30)"`. Invalid colors are ignored.
+    ///
+    /// Unless the Window is transparent, the alpha channel of the color is ignored.
+    pub fn set_window_background(&self, background: &str) {
+        if let Ok(background) = Color::parse(background) {
+            self.send(EventMessage::SetWindowBackground(background))
+                .ok();
+        }
+    }
+
+    /// Change the parts of the Window that receive pointer events, outside of them the events pass through
+    /// to whatever is below it. See [`HitRegion`] for its platform support.
+    pub fn set_hit_region(&self, hit_region: HitRegion) {
+        self.send(EventMessage::SetHitRegion(hit_region)).ok();
+    }
+
+    /// Keep the cursor inside of the Window, e.g. for games.
+    ///
+    /// - [`CursorGrabMode::Confined`] lets the cursor move but not leave the Window.
+    /// - [`CursorGrabMode::Locked`] keeps the cursor in place, the movements are still reported as `onmousemove` events
+    ///   attempting to move it.
+    /// - [`CursorGrabMode::None`] releases it.
+    ///
+    /// Platforms only support some of the modes (macOS can only lock it, Windows and X11 can only confine it),
+    /// so the other one is used when the requested one isn't supported. The cursor is released while the Window
+    /// is unfocused, so the user is never trapped in it, and grabbed again when it gets focused back.
+    ///
+    /// Not supported on Android, iOS and Web.
+    pub fn set_cursor_grab(&self, mode: CursorGrabMode) {
+        self.send(EventMessage::SetCursorGrab(mode)).ok();
+    }
+
+    /// Show or hide the cursor while it's over the Window, e.g. to hide it while it's locked with [`set_cursor_grab`](Self::set_cursor_grab).
+    pub fn set_cursor_visible(&self, visible: bool) {
+        self.send(EventMessage::SetCursorVisible(visible)).ok();
+    }
+
+    /// Close the Window and exit the app, without running the `use_close_requested` handlers.
+    pub fn exit(&self) {
+        self.send(EventMessage::ExitApp).ok();
+    }
+
+    /// Announce a message with screen readers, e.g. `"3 items added"`, without needing a visible element.
+    ///
+    /// [`AccessibilityLive::Polite`] messages are spoken once the screen reader finishes what it's saying,
+    /// [`AccessibilityLive::Assertive`] ones interrupt it, so keep them for urgent messages like errors.
+    pub fn announce(&self, message: impl Into<String>, live: AccessibilityLive) {
+        self.send(EventMessage::Announce {
+            message: message.into(),
+            live,
+        })
+        .ok();
+    }
+
+    /// Log the computed layout of the whole tree as JSON with `tracing`, useful for debugging and bug reports.
+    pub fn dump_layout(&self) {
+        self.send(EventMessage::DumpLayout).ok();
+    }
+
     pub fn request_animation_frame(&self) {
         self.send(EventMessage::RequestRerender).ok();
     }
 
+    /// Apply every pending state change, relayout and render right away, instead of waiting for the next frame.
+    ///
+    /// The updates caused by the layout itself (e.g. `onsizechange` handlers adjusting the state) are applied too
+    /// before rendering, so the rendered frame doesn't show the intermediate layout, useful for measure-then-adjust flows.
+    pub fn flush(&self) {
+        self.send(EventMessage::Flush).ok();
+    }
+
+    /// Request a new frame to tick the tickers, e.g. to advance an animation.
+    ///
+    /// Unlike [`request_animation_frame`](Self::request_animation_frame), the Window is not repainted
+    /// unless something changed, so the app goes back to idle once nothing requests ticks anymore.
+    pub fn request_tick(&self) {
+        self.send(EventMessage::RequestTick).ok();
+    }
+
+    /// Run a callback with access to the underlying winit [`Window`].
+    ///
+    /// The callback is executed in the event loop, so it will not run immediately.
+    /// Use it for platform integrations, not to drive the rendering of the Window.
+    /// In the testing renderer there is no Window, so the callback never runs.
+    pub fn with_window(&self, cb: impl FnOnce(&Window) + Send + Sync + 'static) {
+        self.send(EventMessage::WithWindow(Box::new(cb))).ok();
+    }
+
+    /// Render the given Node and its descendants into an image, e.g. to show a preview of an element while dragging it.
+    ///
+    /// The Node keeps the transforms, opacity and clipping it has on the screen, except for the rotations and opacities
+    /// inherited from its ancestors. Get the id of a Node with [`use_node_signal`](crate::use_node_signal).
+    /// Returns `None` if the Node doesn't exist or paints nothing, and always in the testing renderer.
+    pub async fn snapshot_node(&self, node_id: NodeId) -> Option<NodeSnapshot> {
+        self.snapshot_node_supersampled(node_id, 1).await
+    }
+
+    /// Like [`snapshot_node`](Self::snapshot_node), but rendered at `supersampling` (up to 4) times the resolution
+    /// in each direction and downsampled to the same size, e.g. to export crisp images of an element.
+    /// See [`start_frame_capture_supersampled`](Self::start_frame_capture_supersampled) for how it looks and what it costs.
+    pub async fn snapshot_node_supersampled(
+        &self,
+        node_id: NodeId,
+        supersampling: u32,
+    ) -> Option<NodeSnapshot> {
+        let (sender, receiver) = oneshot::channel();
+        self.send(EventMessage::SnapshotNode {
+            node_id,
+            supersampling,
+            callback: Box::new(move |snapshot| {
+                sender.send(snapshot).ok();
+            }),
+        })
+        .ok()?;
+        receiver.await.ok().flatten()
+    }
+
+    /// Get the Nodes under a logical position of the Window, from the topmost to the bottommost one,
+    /// e.g. to find the drop zone under a dragged element or the items inside a lasso selection.
+    ///
+    /// They are found just like the targets of the pointer events, so the Nodes with `pointer_events: "none"`
+    /// are left out and the clipping, `hit_padding` and rotations of the Nodes are respected.
+    /// The first one is the Node a click at that position would be emitted to.
+    pub async fn nodes_at_point(&self, point: (f64, f64)) -> Vec<NodeId> {
+        let (sender, receiver) = oneshot::channel();
+        let sent = self.send(EventMessage::NodesAtPoint {
+            point,
+            callback: Box::new(move |nodes| {
+                sender.send(nodes).ok();
+            }),
+        });
+        if sent.is_err() {
+            return Vec::new();
+        }
+        receiver.await.unwrap_or_default()
+    }
+
+    /// Check if a logical position of the Window is inside of the given Node, even if other Nodes are above it.
+    /// See [`nodes_at_point`](Self::nodes_at_point) for how the Nodes are hit.
+    pub async fn is_point_inside(&self, node_id: NodeId, point: (f64, f64)) -> bool {
+        self.nodes_at_point(point).await.contains(&node_id)
+    }
+
+    /// Emit an event to the given Node directly, e.g. for a command palette to "click" a button.
+    /// Get the id of a Node with [`use_node_signal`](crate::use_node_signal).
+    ///
+    /// It runs through the same handlers as the events from the platform, but it doesn't matter
+    /// where the Node is or what is under the cursor:
+    ///
+    /// - Only the given event is emitted, without the collateral events (e.g. `mouseenter` for `mouseover`),
+    ///   the capture phase or the global listeners (e.g. `onglobalclick`).
+    /// - Events that bubble (e.g. `click`) go up to the ancestors of the Node like usual, even if the Node itself
+    ///   isn't listening, unless a handler stops the propagation. Events that don't bubble only reach the Node.
+    /// - The coordinates of pointer events are replaced by the center of the Node, and `pointer_events: "none"` is ignored.
+    ///
+    /// Nothing happens if the Node doesn't exist or hasn't been laid out yet.
+    pub fn dispatch_event(&self, node_id: NodeId, event: PlatformEvent) {
+        self.send(EventMessage::DispatchNodeEvent { node_id, event })
+            .ok();
+    }
+
     pub fn new_ticker(&self) -> Ticker {
         Ticker {
             inner: self.ticker.peek().resubscribe(),
@@ -85,17 +333,36 @@ impl Ticker {
 /// Information about the platform.
 #[derive(Clone)]
 pub struct PlatformInformation {
+    /// Size of the Window in logical pixels.
     pub window_size: Size2D,
+    /// Scale factor of the Window, multiply logical pixels by this to get physical pixels.
+    pub scale_factor: f64,
+    /// Whether the Window is minimized or fully occluded by other windows.
+    /// Nothing is rendered and the tickers are paused meanwhile.
+    pub is_occluded: bool,
+    /// Last time the Window became visible again after being occluded.
+    pub resumed_at: Option<Instant>,
+    /// Last known position of the mouse or touch pointer, in logical pixels relative to the Window.
+    /// `None` when the pointer is outside of the Window or it hasn't entered it yet.
+    pub cursor_position: Option<CursorPoint>,
 }
 
 impl PlatformInformation {
-    pub fn from_winit(physical_size: PhysicalSize<u32>) -> Self {
-        Self {
-            window_size: Size2D::new(physical_size.width as f32, physical_size.height as f32),
-        }
+    pub fn from_winit(physical_size: PhysicalSize<u32>, scale_factor: f64) -> Self {
+        let logical_size = physical_size.to_logical::<f32>(scale_factor);
+        Self::new(
+            Size2D::new(logical_size.width, logical_size.height),
+            scale_factor,
+        )
     }
 
-    pub fn new(window_size: Size2D) -> Self {
-        Self { window_size }
+    pub fn new(window_size: Size2D, scale_factor: f64) -> Self {
+        Self {
+            window_size,
+            scale_factor,
+            is_occluded: false,
+            resumed_at: None,
+            cursor_position: None,
+        }
     }
 }
@@ -0,0 +1,195 @@
+use dioxus_core::prelude::{consume_context, spawn, use_hook};
+use dioxus_signals::{ReadOnlySignal, Signal, Writable};
+use freya_core::prelude::NodesState;
+use freya_native_core::NodeId;
+use rustc_hash::FxHashSet;
+use tokio::sync::watch;
+
+/// Nodes that are currently hovered or pressed by the pointer.
+///
+/// Only the Nodes listening for mouse or pointer events (e.g. `onmouseenter`, `onclick`) are tracked.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PointerNodes {
+    hovered: FxHashSet<NodeId>,
+    pressed: FxHashSet<NodeId>,
+}
+
+impl PointerNodes {
+    /// Get the hovered Nodes.
+    pub fn hovered(&self) -> &FxHashSet<NodeId> {
+        &self.hovered
+    }
+
+    /// Get the pressed Nodes.
+    pub fn pressed(&self) -> &FxHashSet<NodeId> {
+        &self.pressed
+    }
+
+    /// Check if the given Node is hovered.
+    pub fn is_hovered(&self, node_id: &NodeId) -> bool {
+        self.hovered.contains(node_id)
+    }
+
+    /// Check if the given Node is pressed.
+    pub fn is_pressed(&self, node_id: &NodeId) -> bool {
+        self.pressed.contains(node_id)
+    }
+}
+
+/// Notifies the [`use_pointer_nodes`] hooks when the hovered or pressed Nodes change.
+#[derive(Clone)]
+pub struct PointerNodesNotifier(watch::Sender<PointerNodes>);
+
+impl Default for PointerNodesNotifier {
+    fn default() -> Self {
+        Self(watch::channel(PointerNodes::default()).0)
+    }
+}
+
+impl PointerNodesNotifier {
+    /// Update the hovered and pressed Nodes after processing the events.
+    /// Subscribers are only notified if they actually changed.
+    pub fn notify(&self, nodes_state: &NodesState) {
+        let pointer_nodes = PointerNodes {
+            hovered: nodes_state.hovered_nodes().copied().collect(),
+            pressed: nodes_state.pressed_nodes().copied().collect(),
+        };
+
+        self.0.send_if_modified(|current| {
+            if *current != pointer_nodes {
+                *current = pointer_nodes;
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    fn subscribe(&self) -> watch::Receiver<PointerNodes> {
+        self.0.subscribe()
+    }
+}
+
+/// Subscribe to the Nodes that are currently hovered or pressed by the pointer.
+///
+/// The signal only changes when a Node starts or stops being hovered or pressed, not on every movement of the cursor.
+/// Still, every component using this hook will re-render when that happens, so derive what you need inside a memo
+/// to only re-render when it changes. The Node of an element can be found with [`crate::use_node_signal`].
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let (reference, layout) = use_node_signal();
+///     let pointer_nodes = use_pointer_nodes();
+///     let is_hovered = use_memo(move || {
+///         layout
+///             .read()
+///             .node_id
+///             .map(|node_id| pointer_nodes.read().is_hovered(&node_id))
+///             .unwrap_or_default()
+///     });
+///
+///     rsx!(
+///         rect {
+///             reference,
+///             width: "100",
+///             height: "100",
+///             background: if is_hovered() { "blue" } else { "red" },
+///             // Only Nodes listening for mouse or pointer events are tracked
+///             onmouseenter: |_| {},
+///         }
+///     )
+/// }
+/// ```
+pub fn use_pointer_nodes() -> ReadOnlySignal<PointerNodes> {
+    use_hook(|| {
+        let mut rx = consume_context::<PointerNodesNotifier>().subscribe();
+        let mut signal = Signal::new(rx.borrow().clone());
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                signal.set(rx.borrow_and_update().clone());
+            }
+        });
+
+        signal.into()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn track_pointer_nodes() {
+        fn use_pointer_nodes_app() -> Element {
+            let (reference, layout) = use_node_signal();
+            let pointer_nodes = use_pointer_nodes();
+            let state = use_memo(move || {
+                let Some(node_id) = layout.read().node_id else {
+                    return (false, false);
+                };
+                let pointer_nodes = pointer_nodes.read();
+                (
+                    pointer_nodes.is_hovered(&node_id),
+                    pointer_nodes.is_pressed(&node_id),
+                )
+            });
+            let (is_hovered, is_pressed) = state();
+
+            rsx!(
+                rect {
+                    reference,
+                    width: "100",
+                    height: "100",
+                    onmouseenter: |_| {},
+                    onmousedown: |_| {},
+                    label {
+                        "{is_hovered} {is_pressed}"
+                    }
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_pointer_nodes_app);
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("false false"));
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: (50.0, 50.0).into(),
+            button: None,
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("true false"));
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: (50.0, 50.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("true true"));
+
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: (50.0, 50.0).into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0).get(0);
+        assert_eq!(label.get(0).text(), Some("true false"));
+    }
+}
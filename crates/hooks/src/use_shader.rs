@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use dioxus_core::{prelude::use_hook, AttributeValue};
+use dioxus_signals::{Readable, Signal, Writable};
+use freya_engine::prelude::RuntimeEffect;
+use freya_node_state::{CustomAttributeValues, ShaderEffect, ShaderReference};
+
+use crate::{UniformValue, UniformsBuilder};
+
+/// Holds a compiled SkSL shader and the uniforms it gets painted with.
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseShader {
+    effect: Signal<Result<Arc<ShaderEffect>, String>>,
+    uniforms: Signal<UniformsBuilder>,
+}
+
+impl UseShader {
+    /// Set the value of a uniform. The elements using this shader will be painted again with the new value.
+    pub fn set_uniform(&mut self, name: &str, value: UniformValue) {
+        self.uniforms.write().set(name, value);
+    }
+
+    /// Get the error message of the shader compilation, if it failed.
+    pub fn error(&self) -> Option<String> {
+        self.effect.read().as_ref().err().cloned()
+    }
+
+    /// Get the value for the `shader` attribute.
+    ///
+    /// Nothing will be painted if the shader failed to compile or not all of its uniforms have a value.
+    pub fn attribute(&self) -> AttributeValue {
+        let effect = self.effect.read();
+        let Ok(effect) = &*effect else {
+            return AttributeValue::None;
+        };
+
+        let uniforms = self.uniforms.read();
+        let is_complete = effect
+            .0
+            .uniforms()
+            .iter()
+            .all(|uniform| uniforms.contains(uniform.name()));
+
+        if !is_complete {
+            return AttributeValue::None;
+        }
+
+        AttributeValue::any_value(CustomAttributeValues::Shader(ShaderReference {
+            effect: effect.clone(),
+            uniforms: uniforms.build(&effect.0).into(),
+        }))
+    }
+}
+
+/// Compile a SkSL shader to paint elements with it, using the `shader` attribute.
+///
+/// The shader is only compiled once, so later changes of `sksl` are ignored.
+/// If the compilation fails, the error can be retrieved with [`UseShader::error`] and nothing will be painted.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// # use std::time::Instant;
+/// const SHADER: &str = "
+///     uniform float u_time;
+///
+///     vec4 main(vec2 coords) {
+///         return vec4(abs(sin(u_time)), coords.x / 300.0, coords.y / 300.0, 1.0);
+///     }
+/// ";
+///
+/// fn app() -> Element {
+///     let platform = use_platform();
+///     let mut shader = use_shader(SHADER);
+///
+///     use_hook(move || {
+///         spawn(async move {
+///             let mut ticker = platform.new_ticker();
+///             let instant = Instant::now();
+///             loop {
+///                 shader.set_uniform("u_time", UniformValue::Float(instant.elapsed().as_secs_f32()));
+///                 ticker.tick().await;
+///             }
+///         });
+///     });
+///
+///     rsx!(
+///         rect {
+///             width: "300",
+///             height: "300",
+///             shader: shader.attribute(),
+///         }
+///     )
+/// }
+/// ```
+pub fn use_shader(sksl: impl Into<String>) -> UseShader {
+    use_hook(|| {
+        let effect = RuntimeEffect::make_for_shader(sksl.into(), None)
+            .map(|effect| Arc::new(ShaderEffect(effect)));
+
+        UseShader {
+            effect: Signal::new(effect),
+            uniforms: Signal::new(UniformsBuilder::default()),
+        }
+    })
+}
@@ -0,0 +1,63 @@
+use dioxus_hooks::{use_context, use_context_provider, use_memo};
+use dioxus_signals::{Memo, Readable, SyncSignal};
+
+/// A reactive state shared across the whole tree.
+///
+/// Components that read from it get re-rendered when it changes. Writes wake the VirtualDOM
+/// through the same waker the event loop already uses, so no extra runtime is involved.
+///
+/// It uses a thread-safe storage, so it can also be moved into async tasks or other threads and updated from there.
+pub type Store<T> = SyncSignal<T>;
+
+/// Provide a [`Store`] to this component and all its descendants. Usually called in the root component.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// #[derive(Default)]
+/// struct AppState {
+///     count: i32,
+///     name: String,
+/// }
+///
+/// fn app() -> Element {
+///     use_init_store(AppState::default);
+///
+///     rsx!(Counter {})
+/// }
+///
+/// #[component]
+/// fn Counter() -> Element {
+///     let mut store = use_store::<AppState>();
+///     // Only re-run when `count` changes
+///     let count = use_store_selector(|state: &AppState| state.count);
+///
+///     rsx!(
+///         label {
+///             onclick: move |_| store.write().count += 1,
+///             "{count}"
+///         }
+///     )
+/// }
+/// ```
+pub fn use_init_store<T: Send + Sync + 'static>(init: impl FnOnce() -> T) -> Store<T> {
+    use_context_provider(|| Store::new_maybe_sync(init()))
+}
+
+/// Get the [`Store`] of type `T` provided by an ancestor with [`use_init_store`].
+///
+/// Reading it subscribes the component to every change of the store, use [`use_store_selector`] to subscribe to just a part of it.
+pub fn use_store<T: Send + Sync + 'static>() -> Store<T> {
+    use_context::<Store<T>>()
+}
+
+/// Subscribe to a part of the [`Store`] of type `T`.
+///
+/// The component will only be re-rendered when the selected value changes, instead of on every change of the store.
+pub fn use_store_selector<T: Send + Sync + 'static, U: PartialEq + 'static>(
+    selector: impl Fn(&T) -> U + 'static,
+) -> Memo<U> {
+    let store = use_store::<T>();
+    use_memo(move || selector(&store.read()))
+}
@@ -0,0 +1,131 @@
+use dioxus_core::prelude::{consume_context, use_hook};
+use freya_core::prelude::{TextLayout, TextMeasurement, TextMeasurer};
+use freya_node_state::FontStyleState;
+
+use crate::{use_platform, UsePlatform};
+
+/// Measures texts without waiting for the layout, created with [`use_text_measurer`].
+#[derive(Clone)]
+pub struct UseTextMeasurer {
+    measurer: TextMeasurer,
+    platform: UsePlatform,
+}
+
+impl UseTextMeasurer {
+    /// Measure how a label with the given text and font style would be wrapped in `width` logical pixels.
+    pub fn measure(&self, text: &str, font_style: &FontStyleState, width: f32) -> TextMeasurement {
+        let scale_factor = self.platform.info().scale_factor as f32;
+        self.measurer.measure(text, font_style, width, scale_factor)
+    }
+
+    /// Lay out how a label with the given text and font style would be wrapped in `width` logical pixels,
+    /// to find the offset nearest to a position, the rect of the caret at an offset or the glyph cluster boundaries.
+    pub fn layout(&self, text: &str, font_style: &FontStyleState, width: f32) -> TextLayout {
+        let scale_factor = self.platform.info().scale_factor as f32;
+        self.measurer.layout(text, font_style, width, scale_factor)
+    }
+}
+
+/// Measure the wrapped height and the amount of lines of texts, using the same paragraph code as the labels,
+/// so auto-sizing elements (e.g. chat bubbles or growing text areas) get the right size in the first frame.
+///
+/// Measurements are cached by the text, width and font style, so measuring the same text again is cheap.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let measurer = use_text_measurer();
+///     let text = "Hello, World! This is a long message that will be wrapped.";
+///     let font_style = FontStyleState {
+///         font_size: 14.0,
+///         ..Default::default()
+///     };
+///     let measurement = measurer.measure(text, &font_style, 150.0);
+///
+///     rsx!(
+///         rect {
+///             width: "170",
+///             height: "{measurement.height + 20.0}",
+///             padding: "10",
+///             background: "rgb(230, 230, 230)",
+///             corner_radius: "8",
+///             label {
+///                 font_size: "14",
+///                 "{text}"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_text_measurer() -> UseTextMeasurer {
+    let platform = use_platform();
+    use_hook(|| UseTextMeasurer {
+        measurer: consume_context::<TextMeasurer>(),
+        platform,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn measure_wrapped_text() {
+        const TEXT: &str =
+            "Hello, World! This is a long text that gets wrapped into several lines.";
+
+        fn use_text_measurer_app() -> Element {
+            let measurer = use_text_measurer();
+            let measurement = measurer.measure(TEXT, &FontStyleState::default(), 100.0);
+
+            rsx!(
+                label {
+                    width: "100",
+                    "{TEXT}"
+                }
+                label {
+                    "{measurement.height} {measurement.lines}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_text_measurer_app);
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0);
+        let height = label.layout().unwrap().area.height();
+        let text = utils.root().get(1).get(0);
+        let (measured_height, lines) = text.text().unwrap().split_once(' ').unwrap();
+
+        assert_eq!(measured_height.parse::<f32>().unwrap(), height);
+        assert!(lines.parse::<usize>().unwrap() > 1);
+    }
+
+    #[tokio::test]
+    pub async fn text_layout_hit_test() {
+        fn use_text_measurer_app() -> Element {
+            let measurer = use_text_measurer();
+            let layout = measurer.layout("Hello", &FontStyleState::default(), 200.0);
+
+            let boundaries = layout.cluster_boundaries();
+            let caret = layout.caret_rect(3);
+            let offset = layout.offset_at(caret.min_x() + 0.5, caret.center().y);
+            let end_caret = layout.caret_rect(5);
+            let is_after = end_caret.min_x() > caret.min_x() && caret.height() > 0.;
+
+            rsx!(
+                label {
+                    "{boundaries:?} {offset} {is_after}"
+                }
+            )
+        }
+
+        let mut utils = launch_test(use_text_measurer_app);
+        utils.wait_for_update().await;
+
+        let text = utils.root().get(0).get(0);
+        assert_eq!(text.text(), Some("[0, 1, 2, 3, 4, 5] 3 true"));
+    }
+}
@@ -0,0 +1,44 @@
+use dioxus_core::prelude::{use_drop, use_hook};
+use freya_common::EventMessage;
+
+use crate::use_platform;
+
+/// Receive every pointer movement sample while the component is alive, useful for drawing tools.
+///
+/// By default the pointer movements the Window reports between two frames are coalesced into the latest one,
+/// so `onmouseover` handlers might skip some positions. This opts out of it for the whole app until the component is dropped.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let mut points = use_signal(Vec::new);
+///     use_unthrottled_pointer_moves();
+///
+///     rsx!(
+///         rect {
+///             width: "100%",
+///             height: "100%",
+///             onmouseover: move |e: MouseEvent| points.write().push(e.get_element_coordinates()),
+///             label {
+///                 "{points.read().len()} points"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_unthrottled_pointer_moves() {
+    let platform = use_platform();
+
+    use_hook(|| {
+        platform
+            .send(EventMessage::SetUnthrottledPointerMoves(true))
+            .ok();
+    });
+
+    use_drop(move || {
+        platform
+            .send(EventMessage::SetUnthrottledPointerMoves(false))
+            .ok();
+    });
+}
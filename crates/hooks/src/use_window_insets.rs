@@ -0,0 +1,135 @@
+use dioxus_core::prelude::{consume_context, spawn, use_hook};
+use dioxus_signals::{ReadOnlySignal, Signal, Writable};
+use tokio::sync::watch;
+
+/// Space in logical pixels at each edge of the Window that the content should keep clear,
+/// e.g. because a custom title bar or the rounded corners of the Window are painted there.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct WindowInsets {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+}
+
+impl WindowInsets {
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+        }
+    }
+
+    /// Use the same inset in all the edges.
+    pub fn all(inset: f32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+
+    /// Get the insets as a value for the `padding` attribute.
+    pub fn as_padding(&self) -> String {
+        format!("{} {} {} {}", self.top, self.right, self.bottom, self.left)
+    }
+}
+
+/// Notifies the [`use_window_insets`] hooks when the insets of the Window change.
+#[derive(Clone)]
+pub struct WindowInsetsNotifier(watch::Sender<WindowInsets>);
+
+impl Default for WindowInsetsNotifier {
+    fn default() -> Self {
+        Self::new(WindowInsets::default())
+    }
+}
+
+impl WindowInsetsNotifier {
+    pub fn new(insets: WindowInsets) -> Self {
+        Self(watch::channel(insets).0)
+    }
+
+    /// Update the insets of the Window.
+    /// Subscribers are only notified if they actually changed.
+    pub fn set(&self, insets: WindowInsets) {
+        self.0.send_if_modified(|current| {
+            if *current != insets {
+                *current = insets;
+                true
+            } else {
+                false
+            }
+        });
+    }
+
+    fn subscribe(&self) -> watch::Receiver<WindowInsets> {
+        self.0.subscribe()
+    }
+}
+
+/// Subscribe to the [`WindowInsets`] of the Window, so the content can be padded to keep clear of
+/// the custom title bar and the edges of the Window.
+///
+/// The insets are configured with `LaunchConfigBuilder::with_title_bar_height` and `LaunchConfigBuilder::with_window_insets`,
+/// and they change when the Window gets maximized or enters fullscreen, as its corners are not rounded anymore.
+/// Use the `SafeArea` component to apply them automatically.
+///
+/// ## Usage
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> Element {
+///     let insets = use_window_insets();
+///
+///     rsx!(
+///         rect {
+///             width: "fill",
+///             height: "fill",
+///             padding: "{insets.read().as_padding()}",
+///             label {
+///                 "Hello, World!"
+///             }
+///         }
+///     )
+/// }
+/// ```
+pub fn use_window_insets() -> ReadOnlySignal<WindowInsets> {
+    use_hook(|| {
+        let mut rx = consume_context::<WindowInsetsNotifier>().subscribe();
+        let mut signal = Signal::new(*rx.borrow());
+
+        spawn(async move {
+            while rx.changed().await.is_ok() {
+                signal.set(*rx.borrow_and_update());
+            }
+        });
+
+        signal.into()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use freya::prelude::*;
+    use freya_testing::prelude::*;
+
+    #[tokio::test]
+    pub async fn window_insets() {
+        fn use_window_insets_app() -> Element {
+            let insets = use_window_insets();
+
+            rsx!(label { "{insets.read().as_padding()}" })
+        }
+
+        let mut utils = launch_test(use_window_insets_app);
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0);
+        assert_eq!(label.get(0).text(), Some("0 0 0 0"));
+
+        utils.set_window_insets(WindowInsets::new(30.0, 8.0, 8.0, 8.0));
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+
+        let label = utils.root().get(0);
+        assert_eq!(label.get(0).text(), Some("30 8 8 8"));
+    }
+}
@@ -116,3 +116,46 @@ async fn asset_cacher() {
 
     assert_eq!(utils.root().get(0).get(0).text(), Some("size 0"));
 }
+
+#[tokio::test]
+async fn asset_cacher_eviction() {
+    fn asset_cacher_app() -> Element {
+        let mut cacher = use_asset_cacher();
+
+        use_hook(move || {
+            cacher.set_max_size(Some(8));
+
+            for id in ["a", "b", "c"] {
+                let asset_config = AssetConfiguration {
+                    age: AssetAge::Unspecified,
+                    id: id.to_string(),
+                };
+                cacher.cache(asset_config, vec![1, 2, 3, 4].into(), false);
+            }
+
+            // The least recently used asset got evicted
+            cacher.use_asset(&AssetConfiguration {
+                age: AssetAge::Unspecified,
+                id: "a".to_string(),
+            });
+            cacher.use_asset(&AssetConfiguration {
+                age: AssetAge::Unspecified,
+                id: "c".to_string(),
+            });
+        });
+
+        let stats = cacher.stats();
+
+        rsx!(
+            label {
+                "{stats.assets} {stats.memory_used} {stats.hits} {stats.misses}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(asset_cacher_app);
+
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("2 8 1 1"));
+}
@@ -85,6 +85,7 @@ pub async fn multiple_lines_single_editor() {
         key: Key::Character("!".to_string()),
         code: Code::Unidentified,
         modifiers: Modifiers::empty(),
+        repeat: false,
     });
 
     utils.wait_for_update().await;
@@ -121,6 +122,7 @@ pub async fn multiple_lines_single_editor() {
         code: Code::ArrowDown,
         key: Key::ArrowDown,
         modifiers: Modifiers::default(),
+        repeat: false,
     });
     utils.wait_for_update().await;
     let cursor = root.get(1).get(0);
@@ -132,6 +134,7 @@ pub async fn multiple_lines_single_editor() {
         code: Code::ArrowRight,
         key: Key::ArrowRight,
         modifiers: Modifiers::default(),
+        repeat: false,
     });
     utils.wait_for_update().await;
     let cursor = root.get(1).get(0);
@@ -143,6 +146,7 @@ pub async fn multiple_lines_single_editor() {
         code: Code::ArrowUp,
         key: Key::ArrowUp,
         modifiers: Modifiers::default(),
+        repeat: false,
     });
     utils.wait_for_update().await;
     let cursor = root.get(1).get(0);
@@ -154,6 +158,7 @@ pub async fn multiple_lines_single_editor() {
         code: Code::ArrowLeft,
         key: Key::ArrowLeft,
         modifiers: Modifiers::default(),
+        repeat: false,
     });
     utils.wait_for_update().await;
     let cursor = root.get(1).get(0);
@@ -165,12 +170,14 @@ pub async fn multiple_lines_single_editor() {
         code: Code::ArrowDown,
         key: Key::ArrowDown,
         modifiers: Modifiers::default(),
+        repeat: false,
     });
     utils.push_event(PlatformEvent::Keyboard {
         name: EventName::KeyDown,
         code: Code::ArrowDown,
         key: Key::ArrowDown,
         modifiers: Modifiers::default(),
+        repeat: false,
     });
     utils.wait_for_update().await;
     let cursor = root.get(1).get(0);
@@ -183,12 +190,14 @@ pub async fn multiple_lines_single_editor() {
         code: Code::ArrowUp,
         key: Key::ArrowUp,
         modifiers: Modifiers::default(),
+        repeat: false,
     });
     utils.push_event(PlatformEvent::Keyboard {
         name: EventName::KeyDown,
         code: Code::ArrowUp,
         key: Key::ArrowUp,
         modifiers: Modifiers::default(),
+        repeat: false,
     });
     utils.wait_for_update().await;
     let cursor = root.get(1).get(0);
@@ -283,6 +292,7 @@ pub async fn single_line_mulitple_editors() {
         key: Key::Character("!".to_string()),
         code: Code::Unidentified,
         modifiers: Modifiers::empty(),
+        repeat: false,
     });
 
     utils.wait_for_update().await;
@@ -534,3 +544,132 @@ pub async fn highlights_single_line_mulitple_editors() {
 
     assert_eq!(highlights_2, Some(vec![(start, end)]));
 }
+
+#[tokio::test]
+pub async fn unselectable_paragraphs() {
+    fn use_editable_app() -> Element {
+        let mut editable = use_editable(
+            || EditableConfig::new("Hello Rustaceans\n".repeat(3)),
+            EditableMode::SingleLineMultipleEditors,
+        );
+        let cursor_attr = editable.cursor_attr();
+        let editor = editable.editor().read();
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                background: "white",
+                cursor_reference: cursor_attr,
+                direction: "vertical",
+                {editor.lines().enumerate().map(move |(i, line)| {
+                    let highlights = editable.highlights_attr(i);
+
+                    let onmouseover = move |e: MouseEvent| {
+                        editable.process_event(&EditableEvent::MouseOver(e.data, i));
+                    };
+
+                    let onmousedown = move |e: MouseEvent| {
+                        editable.process_event(&EditableEvent::MouseDown(e.data, i));
+                    };
+
+                    let onclick = move |_: MouseEvent| {
+                        editable.process_event(&EditableEvent::Click);
+                    };
+
+                    rsx!(
+                        paragraph {
+                            width: "100%",
+                            height: "30",
+                            max_lines: "1",
+                            user_select: if i == 1 { "none" } else { "text" },
+                            cursor_id: "{i}",
+                            cursor_mode: "editable",
+                            onmouseover,
+                            onmousedown,
+                            onclick,
+                            highlights,
+                            text {
+                                color: "black",
+                                "{line}"
+                            }
+                        }
+                    )
+                })},
+                label {
+                    color: "black",
+                    "{editor.cursor_row()}:{editor.cursor_col()}"
+                }
+            }
+        )
+    }
+
+    async fn drag_over(utils: &mut TestingHandler, cursor: (f64, f64)) {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseOver,
+            cursor: cursor.into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+    }
+
+    async fn drag(utils: &mut TestingHandler, from: (f64, f64), to: (f64, f64)) {
+        utils.push_event(PlatformEvent::Mouse {
+            name: EventName::MouseDown,
+            cursor: from.into(),
+            button: Some(MouseButton::Left),
+        });
+        utils.wait_for_update().await;
+        drag_over(utils, from).await;
+        drag_over(utils, to).await;
+    }
+
+    let mut utils = launch_test(use_editable_app);
+    let root = utils.root().get(0);
+    utils.wait_for_update().await;
+
+    // Pressing the unselectable paragraph neither moves the cursor nor starts a selection
+    drag(&mut utils, (35.0, 35.0), (80.0, 65.0)).await;
+    for i in 0..3 {
+        assert_eq!(
+            root.child(i).unwrap().state().cursor.highlights,
+            Some(vec![])
+        );
+    }
+    assert_eq!(root.get(3).get(0).text(), Some("0:0"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (80.0, 65.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // Dragging over it doesn't extend the selection that started in the first paragraph
+    drag(&mut utils, (35.0, 3.0), (80.0, 3.0)).await;
+    let highlights = root.child(0).unwrap().state().cursor.highlights.clone();
+    assert_ne!(highlights, Some(vec![]));
+
+    drag_over(&mut utils, (80.0, 35.0)).await;
+    assert_eq!(root.child(0).unwrap().state().cursor.highlights, highlights);
+    assert_eq!(
+        root.child(1).unwrap().state().cursor.highlights,
+        Some(vec![])
+    );
+    assert_eq!(
+        root.get(3).get(0).text().map(|text| text.starts_with("0:")),
+        Some(true)
+    );
+
+    // But it still reaches the selectable paragraphs after it
+    drag_over(&mut utils, (80.0, 65.0)).await;
+    assert_ne!(
+        root.child(2).unwrap().state().cursor.highlights,
+        Some(vec![])
+    );
+    assert_eq!(
+        root.get(3).get(0).text().map(|text| text.starts_with("2:")),
+        Some(true)
+    );
+}
@@ -155,6 +155,7 @@ pub async fn block_focus() {
         key: Key::Tab,
         code: Code::Tab,
         modifiers: Modifiers::default(),
+        repeat: false,
     });
 
     // Second rect is now focused
@@ -169,6 +170,7 @@ pub async fn block_focus() {
         key: Key::Tab,
         code: Code::Tab,
         modifiers: Modifiers::default(),
+        repeat: false,
     });
 
     // Second rect is still focused
@@ -177,3 +179,141 @@ pub async fn block_focus() {
     assert_eq!(root.get(0).get(0).get(0).text(), Some("false"));
     assert_eq!(root.get(1).get(0).get(0).text(), Some("true"));
 }
+
+#[tokio::test]
+pub async fn tab_index_order() {
+    #[component]
+    fn Child(tab_index: Option<&'static str>, focusable: Option<&'static str>) -> Element {
+        let mut focus_manager = use_focus();
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "20%",
+                focus_id: focus_manager.attribute(),
+                tab_index,
+                focusable,
+                onclick: move |_| focus_manager.focus(),
+                label {
+                    "{focus_manager.is_focused()}"
+                }
+            }
+        )
+    }
+
+    fn tab_index_app() -> Element {
+        rsx!(
+            Child { tab_index: "2" }
+            Child {}
+            Child { tab_index: "1" }
+            Child { tab_index: "-1" }
+            Child { focusable: "false" }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        tab_index_app,
+        TestingConfig {
+            size: (100.0, 100.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    let focused = |utils: &mut TestingHandler| {
+        (0..5)
+            .position(|i| utils.root().get(i).get(0).get(0).text() == Some("true"))
+            .unwrap()
+    };
+
+    let tab = PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Tab,
+        code: Code::Tab,
+        modifiers: Modifiers::default(),
+        repeat: false,
+    };
+
+    // The explicit indices go first, then the rest in document order, and the skipped ones are left out
+    let mut order = Vec::new();
+    for _ in 0..4 {
+        utils.push_event(tab.clone());
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        order.push(focused(&mut utils));
+    }
+    assert_eq!(order, vec![2, 0, 1, 2]);
+
+    // A negative index can still be focused programmatically
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 70.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    assert_eq!(focused(&mut utils), 3);
+
+    utils.push_event(tab);
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+    assert_eq!(focused(&mut utils), 2);
+}
+
+#[tokio::test]
+pub async fn aria_hidden_subtrees() {
+    #[component]
+    fn Child() -> Element {
+        let focus_manager = use_focus();
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "50%",
+                focus_id: focus_manager.attribute(),
+                label {
+                    "{focus_manager.id().0}"
+                }
+            }
+        )
+    }
+
+    fn aria_hidden_app() -> Element {
+        rsx!(
+            Child {}
+            rect {
+                width: "100%",
+                height: "50%",
+                aria_hidden: "true",
+                Child {}
+            }
+        )
+    }
+
+    let mut utils = launch_test(aria_hidden_app);
+    utils.wait_for_update().await;
+
+    let id = |node: TestNode| AccessibilityId(node.get(0).get(0).text().unwrap().parse().unwrap());
+    let root = utils.root();
+    let visible_id = id(root.get(0));
+    let hidden_id = id(root.get(1).get(0));
+
+    // The hidden subtree is still rendered, but left out of the accessibility tree
+    assert!(root.get(1).get(0).layout().is_some());
+    assert!(utils.accessibility_node(visible_id).is_some());
+    assert!(utils.accessibility_node(hidden_id).is_none());
+
+    // So its focusable Nodes can't be reached with the Tab key
+    let tab = PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Tab,
+        code: Code::Tab,
+        modifiers: Modifiers::default(),
+        repeat: false,
+    };
+    for _ in 0..2 {
+        utils.push_event(tab.clone());
+        utils.wait_for_update().await;
+        utils.wait_for_update().await;
+        assert_eq!(utils.focus_id(), visible_id);
+    }
+}
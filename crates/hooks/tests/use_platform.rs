@@ -1,5 +1,4 @@
-use dioxus::prelude::*;
-use freya_hooks::use_platform;
+use freya::prelude::*;
 use freya_testing::prelude::*;
 
 #[tokio::test]
@@ -24,3 +23,159 @@ async fn window_size() {
 
     assert_eq!(utils.root().get(0).get(0).text(), Some("333.0x190.0"));
 }
+
+#[tokio::test]
+async fn window_size_with_scale_factor() {
+    fn use_animation_app() -> Element {
+        let platform = use_platform();
+
+        let PlatformInformation {
+            window_size,
+            scale_factor,
+            ..
+        } = platform.info();
+
+        rsx!(label { "{window_size:?} {scale_factor}" })
+    }
+
+    let mut utils = launch_test_with_config(
+        use_animation_app,
+        TestingConfig {
+            size: (333.0, 190.0).into(),
+            scale_factor: 2.0,
+            ..TestingConfig::default()
+        },
+    );
+
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).text(), Some("333.0x190.0 2"));
+}
+
+#[tokio::test]
+async fn announce() {
+    fn announce_app() -> Element {
+        let platform = use_platform();
+
+        rsx!(rect {
+            width: "100%",
+            height: "100%",
+            onclick: move |_| platform.announce("3 items added", AccessibilityLive::Polite),
+        })
+    }
+
+    let mut utils = launch_test(announce_app);
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.announcement(AccessibilityLive::Polite), None);
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    assert_eq!(
+        utils.announcement(AccessibilityLive::Polite),
+        Some("3 items added".to_string())
+    );
+    assert_eq!(utils.announcement(AccessibilityLive::Assertive), None);
+}
+
+#[tokio::test]
+async fn cursor_position() {
+    fn cursor_position_app() -> Element {
+        let platform = use_platform();
+        let mut position = use_signal(|| None);
+
+        rsx!(rect {
+            width: "100%",
+            height: "100%",
+            onclick: move |_| position.set(platform.info().cursor_position),
+            label { "{position:?}" }
+        })
+    }
+
+    let mut utils = launch_test_with_config(
+        cursor_position_app,
+        TestingConfig {
+            scale_factor: 2.0,
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("None"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (20.0, 40.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // In logical pixels
+    assert_eq!(
+        utils.root().get(0).get(0).get(0).text(),
+        Some("Some((10.0, 20.0))")
+    );
+}
+
+#[tokio::test]
+async fn nodes_at_point() {
+    fn nodes_at_point_app() -> Element {
+        let platform = use_platform();
+        let (reference, layout) = use_node_signal();
+        let mut hits = use_signal(|| None);
+
+        let onclick = move |_| {
+            spawn(async move {
+                let node_id = layout.read().node_id.unwrap();
+                let topmost = platform.nodes_at_point((75.0, 75.0)).await.first() == Some(&node_id);
+                let inside = platform.is_point_inside(node_id, (25.0, 25.0)).await;
+                hits.set(Some((topmost, inside)));
+            });
+        };
+
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick,
+                label { "{hits:?}" }
+            }
+            rect {
+                reference,
+                position: "absolute",
+                position_top: "50",
+                position_left: "50",
+                width: "100",
+                height: "100",
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        nodes_at_point_app,
+        TestingConfig {
+            scale_factor: 2.0,
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (10.0, 300.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // In logical pixels
+    assert_eq!(
+        utils.root().get(0).get(0).get(0).text(),
+        Some("Some((true, false))")
+    );
+}
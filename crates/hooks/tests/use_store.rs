@@ -0,0 +1,64 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[derive(Default)]
+struct AppState {
+    count: i32,
+    name: String,
+}
+
+#[tokio::test]
+async fn store_updates() {
+    fn store_app() -> Element {
+        use_init_store(AppState::default);
+
+        rsx!(
+            Counter {}
+            Name {}
+        )
+    }
+
+    #[component]
+    fn Counter() -> Element {
+        let mut store = use_store::<AppState>();
+
+        rsx!(
+            rect {
+                width: "100",
+                height: "100",
+                onclick: move |_| store.write().count += 1,
+                label { "{store.read().count}" }
+            }
+        )
+    }
+
+    #[component]
+    fn Name() -> Element {
+        let renders = use_signal(|| 0);
+        let name = use_store_selector(|state: &AppState| state.name.clone());
+
+        *renders.write_unchecked() += 1;
+
+        rsx!(label { "{name} {renders.peek()}" })
+    }
+
+    let mut utils = launch_test(store_app);
+    utils.wait_for_update().await;
+
+    let counter = utils.root().get(0).get(0);
+    let name = utils.root().get(1);
+
+    assert_eq!(counter.get(0).text(), Some("0"));
+    assert_eq!(name.get(0).text(), Some(" 1"));
+
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (5.0, 5.0).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    // The counter changed but `Name` was not re-rendered as the selected value is the same
+    assert_eq!(counter.get(0).text(), Some("1"));
+    assert_eq!(name.get(0).text(), Some(" 1"));
+}
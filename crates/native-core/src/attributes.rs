@@ -10,8 +10,12 @@ pub enum AttributeName {
     MaxHeight,
     Padding,
     Background,
+    BackgroundClip,
     Border,
     BorderAlign,
+    BoxSizing,
+    Outline,
+    OutlineOffset,
     Direction,
     Shadow,
     CornerRadius,
@@ -27,6 +31,7 @@ pub enum AttributeName {
     TextAlign,
     TextShadow,
     MaxLines,
+    MinLines,
     LineHeight,
     LetterSpacing,
     WordSpacing,
@@ -34,8 +39,13 @@ pub enum AttributeName {
     DecorationColor,
     DecorationStyle,
     TextOverflow,
+    WhiteSpace,
+    TextCentering,
+    TextHinting,
+    TextSubpixel,
     Rotate,
     Overflow,
+    ClipPath,
     Margin,
     Position,
     PositionTop,
@@ -43,14 +53,54 @@ pub enum AttributeName {
     PositionBottom,
     PositionLeft,
     Opacity,
+    Transition,
+    Animation,
+    ExitAnimation,
+    BackdropBlur,
+    Cache,
+    WillChange,
+    Antialias,
+    PixelSnapping,
+    Shader,
+    ShaderPosition,
+    Mask,
+    MaskData,
+    ScrollFade,
+    TextFill,
+    TextFillData,
+    Filter,
     Content,
+    ContentVisibility,
+    ContainIntrinsicSize,
     Name,
     Focusable,
+    TabFocusable,
+    TabIndex,
+    TestId,
     Role,
+    AriaLabel,
+    AriaDescription,
+    AriaChecked,
+    AriaExpanded,
+    AriaSelected,
+    AriaDisabled,
+    AriaLive,
+    AriaHidden,
     FocusId,
     Alt,
     CanvasReference,
+    GpuCanvasReference,
+    CustomElement,
     Layer,
+    Isolation,
+    Hidden,
+    Order,
+    FlexBasis,
+    FlexGrow,
+    FlexShrink,
+    HitPadding,
+    PointerEvents,
+    UserSelect,
     OffsetY,
     OffsetX,
     Reference,
@@ -61,6 +111,10 @@ pub enum AttributeName {
     CursorId,
     Highlights,
     HighlightColor,
+    MatchHighlights,
+    MatchHighlightColor,
+    CurrentMatch,
+    CurrentMatchColor,
     ImageReference,
     ImageData,
     SvgData,
@@ -80,8 +134,12 @@ impl FromStr for AttributeName {
             "max_height" => Ok(AttributeName::MaxHeight),
             "padding" => Ok(AttributeName::Padding),
             "background" => Ok(AttributeName::Background),
+            "background_clip" => Ok(AttributeName::BackgroundClip),
             "border" => Ok(AttributeName::Border),
             "border_align" => Ok(AttributeName::BorderAlign),
+            "box_sizing" => Ok(AttributeName::BoxSizing),
+            "outline" => Ok(AttributeName::Outline),
+            "outline_offset" => Ok(AttributeName::OutlineOffset),
             "direction" => Ok(AttributeName::Direction),
             "shadow" => Ok(AttributeName::Shadow),
             "corner_radius" => Ok(AttributeName::CornerRadius),
@@ -97,6 +155,7 @@ impl FromStr for AttributeName {
             "text_align" => Ok(AttributeName::TextAlign),
             "text_shadow" => Ok(AttributeName::TextShadow),
             "max_lines" => Ok(AttributeName::MaxLines),
+            "min_lines" => Ok(AttributeName::MinLines),
             "line_height" => Ok(AttributeName::LineHeight),
             "letter_spacing" => Ok(AttributeName::LetterSpacing),
             "word_spacing" => Ok(AttributeName::WordSpacing),
@@ -104,8 +163,13 @@ impl FromStr for AttributeName {
             "decoration_color" => Ok(AttributeName::DecorationColor),
             "decoration_style" => Ok(AttributeName::DecorationStyle),
             "text_overflow" => Ok(AttributeName::TextOverflow),
+            "white_space" => Ok(AttributeName::WhiteSpace),
+            "text_centering" => Ok(AttributeName::TextCentering),
+            "text_hinting" => Ok(AttributeName::TextHinting),
+            "text_subpixel" => Ok(AttributeName::TextSubpixel),
             "rotate" => Ok(AttributeName::Rotate),
             "overflow" => Ok(AttributeName::Overflow),
+            "clip_path" => Ok(AttributeName::ClipPath),
             "margin" => Ok(AttributeName::Margin),
             "position" => Ok(AttributeName::Position),
             "position_top" => Ok(AttributeName::PositionTop),
@@ -113,14 +177,54 @@ impl FromStr for AttributeName {
             "position_bottom" => Ok(AttributeName::PositionBottom),
             "position_left" => Ok(AttributeName::PositionLeft),
             "opacity" => Ok(AttributeName::Opacity),
+            "transition" => Ok(AttributeName::Transition),
+            "animation" => Ok(AttributeName::Animation),
+            "exit_animation" => Ok(AttributeName::ExitAnimation),
+            "backdrop_blur" => Ok(AttributeName::BackdropBlur),
+            "cache" => Ok(AttributeName::Cache),
+            "will_change" => Ok(AttributeName::WillChange),
+            "antialias" => Ok(AttributeName::Antialias),
+            "pixel_snapping" => Ok(AttributeName::PixelSnapping),
+            "shader" => Ok(AttributeName::Shader),
+            "shader_position" => Ok(AttributeName::ShaderPosition),
+            "mask" => Ok(AttributeName::Mask),
+            "mask_data" => Ok(AttributeName::MaskData),
+            "scroll_fade" => Ok(AttributeName::ScrollFade),
+            "text_fill" => Ok(AttributeName::TextFill),
+            "text_fill_data" => Ok(AttributeName::TextFillData),
+            "filter" => Ok(AttributeName::Filter),
             "content" => Ok(AttributeName::Content),
+            "content_visibility" => Ok(AttributeName::ContentVisibility),
+            "contain_intrinsic_size" => Ok(AttributeName::ContainIntrinsicSize),
             "name" => Ok(AttributeName::Name),
             "focusable" => Ok(AttributeName::Focusable),
+            "tab_focusable" => Ok(AttributeName::TabFocusable),
+            "tab_index" => Ok(AttributeName::TabIndex),
+            "test_id" => Ok(AttributeName::TestId),
             "role" => Ok(AttributeName::Role),
+            "aria_label" => Ok(AttributeName::AriaLabel),
+            "aria_description" => Ok(AttributeName::AriaDescription),
+            "aria_checked" => Ok(AttributeName::AriaChecked),
+            "aria_expanded" => Ok(AttributeName::AriaExpanded),
+            "aria_selected" => Ok(AttributeName::AriaSelected),
+            "aria_disabled" => Ok(AttributeName::AriaDisabled),
+            "aria_live" => Ok(AttributeName::AriaLive),
+            "aria_hidden" => Ok(AttributeName::AriaHidden),
             "focus_id" => Ok(AttributeName::FocusId),
             "alt" => Ok(AttributeName::Alt),
             "canvas_reference" => Ok(AttributeName::CanvasReference),
+            "gpu_canvas_reference" => Ok(AttributeName::GpuCanvasReference),
+            "custom_element" => Ok(AttributeName::CustomElement),
             "layer" => Ok(AttributeName::Layer),
+            "isolation" => Ok(AttributeName::Isolation),
+            "hidden" => Ok(AttributeName::Hidden),
+            "order" => Ok(AttributeName::Order),
+            "flex_basis" => Ok(AttributeName::FlexBasis),
+            "flex_grow" => Ok(AttributeName::FlexGrow),
+            "flex_shrink" => Ok(AttributeName::FlexShrink),
+            "hit_padding" => Ok(AttributeName::HitPadding),
+            "pointer_events" => Ok(AttributeName::PointerEvents),
+            "user_select" => Ok(AttributeName::UserSelect),
             "offset_y" => Ok(AttributeName::OffsetY),
             "offset_x" => Ok(AttributeName::OffsetX),
             "reference" => Ok(AttributeName::Reference),
@@ -131,6 +235,10 @@ impl FromStr for AttributeName {
             "cursor_id" => Ok(AttributeName::CursorId),
             "highlights" => Ok(AttributeName::Highlights),
             "highlight_color" => Ok(AttributeName::HighlightColor),
+            "match_highlights" => Ok(AttributeName::MatchHighlights),
+            "match_highlight_color" => Ok(AttributeName::MatchHighlightColor),
+            "current_match" => Ok(AttributeName::CurrentMatch),
+            "current_match_color" => Ok(AttributeName::CurrentMatchColor),
             "image_reference" => Ok(AttributeName::ImageReference),
             "image_data" => Ok(AttributeName::ImageData),
             "svg_data" => Ok(AttributeName::SvgData),
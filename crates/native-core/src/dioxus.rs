@@ -2,13 +2,13 @@
 
 use std::str::FromStr;
 
-use crate::{tags::TagName, tree::TreeMut};
+use crate::tags::TagName;
 use dioxus_core::{AttributeValue, ElementId, TemplateNode, WriteMutations};
 use rustc_hash::{FxHashMap, FxHashSet};
 use shipyard::Component;
 
 use crate::{
-    node::{ElementNode, FromAnyValue, NodeType, OwnedAttributeValue},
+    node::{ElementNode, FromAnyValue, NodeType, OwnedAttributeValue, DATA_ATTRIBUTE_PREFIX},
     prelude::*,
     real_dom::NodeTypeMut,
     NodeId,
@@ -47,6 +47,41 @@ impl DioxusState {
         self.node_id_mapping.get(element_id.0).copied().flatten()
     }
 
+    /// Get the nodes of the roots of a registered template.
+    pub fn template_roots(&self, name: &str) -> Option<&[NodeId]> {
+        self.templates.get(name).map(|roots| roots.as_slice())
+    }
+
+    /// Forget the ElementIds of a node and its descendants, so Dioxus can reuse them while the nodes stay in the RealDom.
+    /// Their event listeners are removed too, as they don't belong to any element anymore.
+    pub fn detach<V: FromAnyValue + Send + Sync>(
+        &mut self,
+        rdom: &mut RealDom<V>,
+        node_id: NodeId,
+    ) {
+        let mut stack = vec![node_id];
+        while let Some(node_id) = stack.pop() {
+            let Some(mut node) = rdom.get_mut(node_id) else {
+                continue;
+            };
+            stack.extend(node.child_ids());
+
+            if let Some(element_id) = node.get::<ElementIdComponent>().map(|id| id.0) {
+                if self.try_element_to_node_id(element_id) == Some(node_id) {
+                    self.node_id_mapping[element_id.0] = None;
+                }
+            }
+
+            let listeners = match &*node.node_type() {
+                NodeType::Element(ElementNode { listeners, .. }) => listeners.clone(),
+                _ => FxHashSet::default(),
+            };
+            for listener in &listeners {
+                node.remove_event_listener(listener);
+            }
+        }
+    }
+
     /// Create a mutation writer for the RealDom
     pub fn create_mutation_writer<'a, V: FromAnyValue + Send + Sync>(
         &'a mut self,
@@ -99,9 +134,17 @@ impl<V: FromAnyValue + Send + Sync> WriteMutations for DioxusNativeCoreMutationW
             let id = create_template_node(self.rdom, root);
             template_root_ids.push(id);
         }
-        self.state
+        let previous_root_ids = self
+            .state
             .templates
             .insert(template.name.to_string(), template_root_ids);
+
+        // Hot reloaded templates are registered again under the same name, so free the outdated ones
+        for root_id in previous_root_ids.into_iter().flatten() {
+            if let Some(mut root) = self.rdom.get_mut(root_id) {
+                root.remove();
+            }
+        }
     }
 
     fn append_children(&mut self, id: ElementId, m: usize) {
@@ -190,7 +233,8 @@ impl<V: FromAnyValue + Send + Sync> WriteMutations for DioxusNativeCoreMutationW
         let new_nodes = self.state.stack.split_off(self.state.stack.len() - m);
         let old_node_id = self.state.element_to_node_id(id);
         for new in new_nodes {
-            self.rdom.tree_mut().insert_before(old_node_id, new);
+            let mut node = self.rdom.get_mut(new).unwrap();
+            node.insert_before(old_node_id);
         }
     }
 
@@ -205,6 +249,17 @@ impl<V: FromAnyValue + Send + Sync> WriteMutations for DioxusNativeCoreMutationW
         let mut node = self.rdom.get_mut(node_id).unwrap();
         let mut node_type_mut = node.node_type_mut();
         if let NodeTypeMut::Element(element) = &mut node_type_mut {
+            if let Some(key) = name.strip_prefix(DATA_ATTRIBUTE_PREFIX) {
+                match value {
+                    AttributeValue::Text(value) => element.set_data(key, value),
+                    AttributeValue::Float(value) => element.set_data(key, value.to_string()),
+                    AttributeValue::Int(value) => element.set_data(key, value.to_string()),
+                    AttributeValue::Bool(value) => element.set_data(key, value.to_string()),
+                    _ => element.remove_data(key),
+                };
+                return;
+            }
+
             let attribute = AttributeName::from_str(name).expect("Unexpected");
             if let AttributeValue::None = &value {
                 element.remove_attribute(&attribute);
@@ -257,19 +312,25 @@ fn create_template_node<V: FromAnyValue + Send + Sync>(
             children,
             ..
         } => {
-            let node = NodeType::Element(ElementNode {
-                tag: TagName::from_str(tag).expect("Unexpected."),
-                attributes: attrs
-                    .iter()
-                    .filter_map(|attr| match attr {
-                        dioxus_core::TemplateAttribute::Static { name, value, .. } => Some((
+            let mut attributes = FxHashMap::default();
+            let mut dataset = FxHashMap::default();
+            for attr in *attrs {
+                if let dioxus_core::TemplateAttribute::Static { name, value, .. } = attr {
+                    if let Some(key) = name.strip_prefix(DATA_ATTRIBUTE_PREFIX) {
+                        dataset.insert(key.to_string(), value.to_string());
+                    } else {
+                        attributes.insert(
                             AttributeName::from_str(name).expect("Unexpected."),
                             OwnedAttributeValue::Text(value.to_string()),
-                        )),
-                        dioxus_core::TemplateAttribute::Dynamic { .. } => None,
-                    })
-                    .collect(),
+                        );
+                    }
+                }
+            }
+            let node = NodeType::Element(ElementNode {
+                tag: TagName::from_str(tag).expect("Unexpected."),
+                attributes,
                 listeners: FxHashSet::default(),
+                dataset,
             });
             let node_id = rdom.create_node(node).id();
             for child in *children {
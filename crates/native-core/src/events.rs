@@ -37,6 +37,19 @@ pub enum EventName {
     GlobalFileHoverCancelled,
 
     FileDrop,
+    FileHover,
+
+    Mounted,
+
+    Scroll,
+
+    ClickCapture,
+    MiddleClickCapture,
+    RightClickCapture,
+    MouseDownCapture,
+    WheelCapture,
+    PointerDownCapture,
+    PointerUpCapture,
 }
 
 impl FromStr for EventName {
@@ -68,8 +81,18 @@ impl FromStr for EventName {
             "globalmousedown" => Ok(EventName::GlobalMouseDown),
             "globalmouseover" => Ok(EventName::GlobalMouseOver),
             "filedrop" => Ok(EventName::FileDrop),
+            "filehover" => Ok(EventName::FileHover),
             "globalfilehover" => Ok(EventName::GlobalFileHover),
             "globalfilehovercancelled" => Ok(EventName::GlobalFileHoverCancelled),
+            "mounted" => Ok(EventName::Mounted),
+            "scroll" => Ok(EventName::Scroll),
+            "click_capture" => Ok(EventName::ClickCapture),
+            "middleclick_capture" => Ok(EventName::MiddleClickCapture),
+            "rightclick_capture" => Ok(EventName::RightClickCapture),
+            "mousedown_capture" => Ok(EventName::MouseDownCapture),
+            "wheel_capture" => Ok(EventName::WheelCapture),
+            "pointerdown_capture" => Ok(EventName::PointerDownCapture),
+            "pointerup_capture" => Ok(EventName::PointerUpCapture),
             _ => Err(()),
         }
     }
@@ -102,8 +125,18 @@ impl From<EventName> for &str {
             EventName::GlobalMouseDown => "globalmousedown",
             EventName::GlobalMouseOver => "globalmouseover",
             EventName::FileDrop => "filedrop",
+            EventName::FileHover => "filehover",
             EventName::GlobalFileHover => "globalfilehover",
             EventName::GlobalFileHoverCancelled => "globalfilehovercancelled",
+            EventName::Mounted => "mounted",
+            EventName::Scroll => "scroll",
+            EventName::ClickCapture => "click_capture",
+            EventName::MiddleClickCapture => "middleclick_capture",
+            EventName::RightClickCapture => "rightclick_capture",
+            EventName::MouseDownCapture => "mousedown_capture",
+            EventName::WheelCapture => "wheel_capture",
+            EventName::PointerDownCapture => "pointerdown_capture",
+            EventName::PointerUpCapture => "pointerup_capture",
         }
     }
 }
@@ -140,17 +173,46 @@ impl EventName {
             Self::PointerUp => Some(Self::GlobalPointerUp),
             Self::MouseDown => Some(Self::GlobalMouseDown),
             Self::MouseOver => Some(Self::GlobalMouseOver),
-            Self::GlobalFileHover => Some(Self::GlobalFileHover),
+            Self::FileHover | Self::GlobalFileHover => Some(Self::GlobalFileHover),
             Self::GlobalFileHoverCancelled => Some(Self::GlobalFileHoverCancelled),
             _ => None,
         }
     }
 
+    /// Get the equivalent event for the capture phase
+    pub fn get_capture_event(&self) -> Option<Self> {
+        match self {
+            Self::Click => Some(Self::ClickCapture),
+            Self::MiddleClick => Some(Self::MiddleClickCapture),
+            Self::RightClick => Some(Self::RightClickCapture),
+            Self::MouseDown => Some(Self::MouseDownCapture),
+            Self::Wheel => Some(Self::WheelCapture),
+            Self::PointerDown => Some(Self::PointerDownCapture),
+            Self::PointerUp => Some(Self::PointerUpCapture),
+            _ => None,
+        }
+    }
+
+    /// Check if it's one of the capture phase variants
+    pub fn is_capture(&self) -> bool {
+        matches!(
+            &self,
+            Self::ClickCapture
+                | Self::MiddleClickCapture
+                | Self::RightClickCapture
+                | Self::MouseDownCapture
+                | Self::WheelCapture
+                | Self::PointerDownCapture
+                | Self::PointerUpCapture
+        )
+    }
+
     /// Some events might cause other events, like for example:
     /// A `mouseover` might also trigger a `mouseenter`
     /// A `mousedown` or a `touchdown` might also trigger a `pointerdown`
+    /// A `click` might also trigger a `click_capture` and a `pointerup_capture`
     pub fn get_collateral_events(&self) -> SmallVec<[Self; 4]> {
-        let mut events = SmallVec::new();
+        let mut events = SmallVec::<[Self; 4]>::new();
 
         events.push(*self);
 
@@ -167,6 +229,12 @@ impl EventName {
             _ => {}
         }
 
+        let capture_events = events
+            .iter()
+            .filter_map(|event| event.get_capture_event())
+            .collect::<SmallVec<[Self; 2]>>();
+        events.extend(capture_events);
+
         events
     }
 
@@ -185,6 +253,8 @@ impl EventName {
                 | Self::PointerDown
                 | Self::PointerUp
                 | Self::GlobalPointerUp
+                | Self::PointerDownCapture
+                | Self::PointerUpCapture
         )
     }
 
@@ -199,18 +269,24 @@ impl EventName {
     // Bubble all events except:
     // - Keyboard events
     // - Mouse movements events
+    // - Mounted events, these are only emitted to the Node that was mounted
+    // - Scroll events, these are only emitted to the Node that was scrolled
+    // - Capture events, these are emitted to every listening Node from the root to the target
     pub fn does_bubble(&self) -> bool {
-        !matches!(
-            self,
-            Self::KeyDown
-                | Self::KeyUp
-                | Self::MouseLeave
-                | Self::PointerLeave
-                | Self::MouseEnter
-                | Self::PointerEnter
-                | Self::MouseOver
-                | Self::PointerOver
-        )
+        !self.is_capture()
+            && !matches!(
+                self,
+                Self::KeyDown
+                    | Self::KeyUp
+                    | Self::MouseLeave
+                    | Self::PointerLeave
+                    | Self::MouseEnter
+                    | Self::PointerEnter
+                    | Self::MouseOver
+                    | Self::PointerOver
+                    | Self::Mounted
+                    | Self::Scroll
+            )
     }
 
     // Only let events that do not move the mouse, go through solid nodes
@@ -218,6 +294,19 @@ impl EventName {
         matches!(self, Self::KeyDown | Self::KeyUp)
     }
 
+    /// Check if the event means that the pointer (e.g. mouse button) was released
+    pub fn is_release(&self) -> bool {
+        matches!(
+            self,
+            Self::Click
+                | Self::MiddleClick
+                | Self::RightClick
+                | Self::PointerUp
+                | Self::TouchEnd
+                | Self::TouchCancel
+        )
+    }
+
     // Check if this event can change the hover state of a Node.
     pub fn can_change_hover_state(&self) -> bool {
         matches!(
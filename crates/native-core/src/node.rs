@@ -6,6 +6,9 @@ use std::{any::Any, fmt::Debug};
 
 use crate::{events::EventName, prelude::AttributeName, tags::TagName};
 
+/// Prefix of the attributes stored as metadata in [`ElementNode::dataset`], e.g. `data-test-id`.
+pub const DATA_ATTRIBUTE_PREFIX: &str = "data-";
+
 /// A element node in the RealDom
 #[derive(Debug, Clone)]
 pub struct ElementNode<V: FromAnyValue = ()> {
@@ -15,6 +18,9 @@ pub struct ElementNode<V: FromAnyValue = ()> {
     pub attributes: FxHashMap<AttributeName, OwnedAttributeValue<V>>,
     /// The events the element is listening for
     pub listeners: FxHashSet<EventName>,
+    /// The `data-*` attributes of the element, keyed by their name without the `data-` prefix.
+    /// They are only metadata, so changing them does not mark the node as dirty.
+    pub dataset: FxHashMap<String, String>,
 }
 
 /// A type of node with data specific to the node type.
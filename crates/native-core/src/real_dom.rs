@@ -178,6 +178,7 @@ impl<V: FromAnyValue + Send + Sync> RealDom<V> {
             tag: TagName::Root,
             attributes: FxHashMap::default(),
             listeners: FxHashSet::default(),
+            dataset: FxHashMap::default(),
         });
         let root_id = world.add_entity(root_node);
         {
@@ -238,6 +239,22 @@ impl<V: FromAnyValue + Send + Sync> RealDom<V> {
         NodeMut::new(id, self)
     }
 
+    /// Mark every state of every node as dirty, so they are all computed again on the next [RealDom::update_state].
+    /// This is useful when something the states depend on but which is not part of the dom changes.
+    pub fn mark_all_dirty(&mut self) {
+        let mut node_ids = Vec::new();
+        self.traverse_depth_first(|node| node_ids.push(node.id()));
+        for node_id in node_ids {
+            self.dirty_nodes
+                .passes_updated
+                .entry(node_id)
+                .or_default()
+                .extend(self.dirty_nodes.passes.iter().map(|x| x.this_type_id));
+            self.dirty_nodes
+                .mark_dirty(node_id, NodeMaskBuilder::ALL.build());
+        }
+    }
+
     pub fn is_node_listening(&self, node_id: &NodeId, event: &EventName) -> bool {
         self.nodes_listening
             .get(event)
@@ -567,9 +584,18 @@ impl<'a, V: FromAnyValue + Send + Sync> NodeMut<'a, V> {
         self.dom.world.add_component(self.id, value);
     }
 
+    /// Mark as changed the current parent of a node that is about to be moved somewhere else
+    fn mark_moved(&mut self, id: NodeId) {
+        let parent_id = { self.dom.tree_ref().parent_id(id) };
+        if let Some(parent_id) = parent_id {
+            self.dom.dirty_nodes.mark_child_changed(parent_id);
+        }
+    }
+
     /// Add the given node to the end of this nodes children
     #[inline]
     pub fn add_child(&mut self, child: NodeId) {
+        self.mark_moved(child);
         self.dom.dirty_nodes.mark_child_changed(self.id);
         self.dom.dirty_nodes.mark_parent_added_or_removed(child);
         self.dom.tree_mut().add_child(self.id, child);
@@ -579,6 +605,7 @@ impl<'a, V: FromAnyValue + Send + Sync> NodeMut<'a, V> {
     #[inline]
     pub fn insert_after(&mut self, old: NodeId) {
         let id = self.id();
+        self.mark_moved(id);
         let parent_id = { self.dom.tree_ref().parent_id(old) };
         if let Some(parent_id) = parent_id {
             self.dom.dirty_nodes.mark_child_changed(parent_id);
@@ -591,6 +618,7 @@ impl<'a, V: FromAnyValue + Send + Sync> NodeMut<'a, V> {
     #[inline]
     pub fn insert_before(&mut self, old: NodeId) {
         let id = self.id();
+        self.mark_moved(id);
         let parent_id = { self.dom.tree_ref().parent_id(old) };
         if let Some(parent_id) = parent_id {
             self.dom.dirty_nodes.mark_child_changed(parent_id);
@@ -852,6 +880,16 @@ impl<V: FromAnyValue + Send + Sync> ElementNodeMut<'_, V> {
         );
         self.element_mut().attributes.get_mut(name)
     }
+
+    /// Set a `data-*` attribute of the element, `key` does not include the `data-` prefix
+    pub fn set_data(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.element_mut().dataset.insert(key.into(), value.into())
+    }
+
+    /// Remove a `data-*` attribute of the element
+    pub fn remove_data(&mut self, key: &str) -> Option<String> {
+        self.element_mut().dataset.remove(key)
+    }
 }
 
 // Create a workload from all of the passes. This orders the passes so that each pass will only run at most once.
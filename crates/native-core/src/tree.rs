@@ -191,6 +191,7 @@ impl<'a> TreeMut for TreeMutView<'a> {
     }
 
     fn add_child(&mut self, parent: NodeId, new: NodeId) {
+        detach(self, new);
         {
             let mut node_state = &mut self.1;
             (&mut node_state).get(new).unwrap().parent = Some(parent);
@@ -221,6 +222,7 @@ impl<'a> TreeMut for TreeMutView<'a> {
     }
 
     fn insert_before(&mut self, old_id: NodeId, new_id: NodeId) {
+        detach(self, new_id);
         let parent_id = {
             let old_node = self.1.get(old_id).unwrap();
             old_node.parent.expect("tried to insert before root")
@@ -240,6 +242,7 @@ impl<'a> TreeMut for TreeMutView<'a> {
     }
 
     fn insert_after(&mut self, old_id: NodeId, new_id: NodeId) {
+        detach(self, new_id);
         let mut node_state = &mut self.1;
         let old_node = node_state.get(old_id).unwrap();
         let parent_id = old_node.parent.expect("tried to insert before root");
@@ -317,6 +320,15 @@ impl<'a> TreeMut for TreeMutView<'a> {
     }
 }
 
+/// Unlink a node from its current parent, so it can be moved to another position of the tree without duplicating it.
+fn detach(tree: &mut TreeMutView<'_>, id: NodeId) {
+    let mut node_data_mut = &mut tree.1;
+    if let Some(parent_id) = (&mut node_data_mut).get(id).unwrap().parent.take() {
+        let parent = (&mut node_data_mut).get(parent_id).unwrap();
+        parent.children.retain(|&child| child != id);
+    }
+}
+
 fn child_height(parent: &Node, tree: &impl TreeRef) -> u16 {
     match &parent.child_subtree {
         Some(shadow_tree) => {
@@ -600,3 +612,42 @@ fn deletion() {
     assert_eq!(tree.height(parent), Some(0));
     assert_eq!(tree.children_ids(parent), &[]);
 }
+
+#[test]
+fn moving() {
+    use shipyard::World;
+    #[allow(dead_code)]
+    #[derive(Component)]
+    struct Num(i32);
+
+    let mut world = World::new();
+    let parent = world.add_entity(Num(0));
+    let first = world.add_entity(Num(1));
+    let second = world.add_entity(Num(2));
+    let third = world.add_entity(Num(3));
+
+    let mut tree = world.borrow::<TreeMutView>().unwrap();
+
+    tree.create_node(parent);
+    tree.create_node(first);
+    tree.create_node(second);
+    tree.create_node(third);
+
+    tree.add_child(parent, first);
+    tree.add_child(parent, second);
+    tree.add_child(parent, third);
+
+    tree.insert_before(first, third);
+
+    assert_eq!(tree.children_ids(parent), &[third, first, second]);
+
+    tree.insert_after(second, first);
+
+    assert_eq!(tree.children_ids(parent), &[third, second, first]);
+
+    tree.add_child(parent, third);
+
+    assert_eq!(tree.children_ids(parent), &[second, first, third]);
+    assert_eq!(tree.parent_id(first).unwrap(), parent);
+    assert_eq!(tree.height(first), Some(1));
+}
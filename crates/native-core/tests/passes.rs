@@ -9,6 +9,7 @@ fn create_blank_element() -> NodeType {
         tag: TagName::Rect,
         attributes: FxHashMap::default(),
         listeners: FxHashSet::default(),
+        dataset: FxHashMap::default(),
     })
 }
 
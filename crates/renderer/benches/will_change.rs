@@ -0,0 +1,110 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use dioxus_core::{ScopeId, VirtualDom};
+use freya::prelude::*;
+use freya_core::prelude::*;
+use freya_engine::prelude::*;
+use freya_renderer::{render_layers, RenderPass, SubtreeCache};
+use torin::geometry::Area;
+
+/// Rotation of the spinning subtree, advanced before every frame.
+static DEGREES: AtomicU32 = AtomicU32::new(0);
+
+/// Subtree of labels and decorated rects that spins on every frame.
+fn spinning(will_change: &str) -> Element {
+    let degrees = DEGREES.load(Ordering::Relaxed);
+
+    rsx!(
+        rect {
+            width: "400",
+            height: "400",
+            padding: "20",
+            will_change: "{will_change}",
+            rotate: "{degrees}deg",
+            background: "rgb(240, 240, 240)",
+            corner_radius: "16",
+            for item in 0..15 {
+                rect {
+                    key: "{item}",
+                    direction: "horizontal",
+                    cross_align: "center",
+                    rect {
+                        width: "16",
+                        height: "16",
+                        corner_radius: "8",
+                        background: "rgb(103, 80, 164)",
+                        border: "2 inner white",
+                    }
+                    label { "Item {item}" }
+                }
+            }
+        }
+    )
+}
+
+fn hinted_app() -> Element {
+    spinning("transform")
+}
+
+fn unhinted_app() -> Element {
+    spinning("auto")
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut g = c.benchmark_group("will_change");
+
+    let font_mgr = FontMgr::default();
+    let mut font_collection = FontCollection::new();
+    font_collection.set_dynamic_font_manager(font_mgr.clone());
+    let area = Area::new((0.0, 0.0).into(), (500.0, 500.0).into());
+
+    for (name, app) in [
+        ("rotation with will_change", hinted_app as fn() -> Element),
+        ("rotation without will_change", unhinted_app),
+    ] {
+        let mut vdom = VirtualDom::new(app);
+        let mut fdom = FreyaDOM::default();
+        fdom.init_dom(&mut vdom, 1.0);
+        process_layout(&fdom, area, &mut font_collection, 1.0, &[]);
+
+        let mut surface = surfaces::raster_n32_premul((500, 500)).unwrap();
+        let mut subtree_cache = SubtreeCache::default();
+
+        g.bench_function(name, |b| {
+            b.iter(|| {
+                DEGREES.fetch_add(1, Ordering::Relaxed);
+                vdom.mark_dirty(ScopeId::ROOT);
+
+                // Same steps as the renderer to paint a frame
+                let (_, must_relayout) = fdom.render_mutations(&mut vdom, 1.0);
+                if must_relayout {
+                    process_layout(&fdom, area, &mut font_collection, 1.0, &[]);
+                }
+                fdom.restyle_paragraphs(&font_collection, &[]);
+
+                let dirty_nodes = fdom.take_dirty_nodes();
+                let composited_changes = fdom.take_composited_changes();
+                subtree_cache.prepare(&fdom, &dirty_nodes, &composited_changes, area);
+
+                surface.canvas().clear(Color::WHITE);
+                render_layers(
+                    &mut surface,
+                    &fdom,
+                    &mut font_collection,
+                    &font_mgr,
+                    &[],
+                    RenderPass {
+                        subtree_cache: Some(&mut subtree_cache),
+                        ..RenderPass::default()
+                    },
+                );
+            })
+        });
+    }
+
+    g.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
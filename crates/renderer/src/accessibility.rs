@@ -1,3 +1,4 @@
+use accesskit::Live;
 use accesskit_winit::Adapter;
 use freya_common::EventMessage;
 use freya_core::{
@@ -61,6 +62,16 @@ impl AccessKitManager {
         }
     }
 
+    /// Announce a message with screen readers
+    pub fn announce(&self, message: String, live: Live) {
+        let tree = self
+            .accessibility_manager
+            .lock()
+            .unwrap()
+            .announce(message, live);
+        self.accessibility_adapter.update_if_active(|| tree);
+    }
+
     fn update_ime_position(&self, accessibility_id: AccessibilityId, window: &Window) {
         let accessibility_manager = self.accessibility_manager.lock().unwrap();
         let node = accessibility_manager.nodes.iter().find_map(|(id, n)| {
@@ -1,30 +1,46 @@
 use dioxus_core::{Template, VirtualDom};
-use freya_common::EventMessage;
+use freya_common::{EventMessage, HitRegion, NodeSnapshot};
 use freya_core::prelude::*;
 use freya_engine::prelude::*;
-use freya_hooks::PlatformInformation;
+use freya_hooks::{
+    CloseRequestNotifier, KeyboardStateNotifier, LayoutNotifier, PlatformInformation,
+    PointerNodesNotifier, WindowInsetsNotifier, WindowSizeNotifier,
+};
 use freya_native_core::NodeId;
 use futures_task::Waker;
 use futures_util::FutureExt;
 use pin_utils::pin_mut;
+use rustc_hash::FxHashSet;
+use std::collections::HashSet;
+use std::ops::Div;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use tokio::sync::broadcast;
 use tokio::{
     select,
     sync::{mpsc, watch, Notify},
 };
-use torin::geometry::{Area, Size2D};
-use tracing::info;
+use torin::geometry::{Area, CursorPoint, Size2D};
+use tracing::{info, warn};
 use uuid::Uuid;
-use winit::dpi::PhysicalSize;
+use winit::dpi::{LogicalPosition, PhysicalSize};
 use winit::event_loop::{EventLoop, EventLoopProxy};
 
 use crate::{
-    accessibility::AccessKitManager, event_loop::run_event_loop, renderer::render_skia,
+    accessibility::AccessKitManager, event_loop::run_event_loop, frame_capture::FrameCapture,
+    frame_pacer::FramePacer, reduced_motion::platform_prefers_reduced_motion,
     winit_waker::winit_waker,
 };
 use crate::{EmbeddedFonts, HoveredNode, WindowEnv};
 
+/// Maximum amount of VirtualDOM updates and relayouts done by [`App::flush`] until the app is stable,
+/// in case the layout keeps changing the state that affects the layout.
+const MAX_FLUSH_ITERATIONS: usize = 16;
+
+/// Maximum supersampling factor of the offscreen renders, bigger surfaces might not fit in a GPU texture.
+const MAX_SUPERSAMPLING: u32 = 4;
+
 /// Manages the Application lifecycle
 pub struct App<State: 'static + Clone> {
     pub(crate) sdom: SafeDOM,
@@ -42,12 +58,30 @@ pub struct App<State: 'static + Clone> {
     pub(crate) accessibility: AccessKitManager,
     pub(crate) font_collection: FontCollection,
     pub(crate) font_mgr: FontMgr,
+    pub(crate) text_measurer: TextMeasurer,
     pub(crate) ticker_sender: broadcast::Sender<()>,
+    pub(crate) layout_notifier: LayoutNotifier,
+    pub(crate) pointer_nodes_notifier: PointerNodesNotifier,
+    pub(crate) window_insets_notifier: WindowInsetsNotifier,
+    pub(crate) window_size_notifier: WindowSizeNotifier,
+    pub(crate) keyboard_state_notifier: KeyboardStateNotifier,
+    pub(crate) close_request_notifier: CloseRequestNotifier,
     pub(crate) plugins: PluginsManager,
     pub(crate) navigator_state: NavigatorState,
     pub(crate) measure_layout_on_next_render: bool,
+    pub(crate) pending_repaint: bool,
+    pub(crate) tick_requested: bool,
+    pub(crate) window_occluded: bool,
+    pub(crate) window_minimized: bool,
     pub(crate) platform_information: Arc<Mutex<PlatformInformation>>,
     pub(crate) default_fonts: Vec<String>,
+    pub(crate) dirty_regions: DirtyRegions,
+    pub(crate) subtree_cache: SubtreeCache,
+    pub(crate) unthrottled_pointer_moves: usize,
+    pub(crate) hit_region: HitRegion,
+    pub(crate) cursor_hittest: bool,
+    pub(crate) frame_capture: Option<FrameCapture>,
+    pub(crate) frame_pacer: FramePacer,
 }
 
 impl<State: 'static + Clone> App<State> {
@@ -71,7 +105,12 @@ impl<State: 'static + Clone> App<State> {
 
         let mut provider = TypefaceFontProvider::new();
 
+        // The same font might be embedded more than once
+        let mut registered_fonts = HashSet::new();
         for (font_name, font_data) in fonts_config {
+            if !registered_fonts.insert(font_name) {
+                continue;
+            }
             let ft_type = def_mgr.new_from_data(font_data, None).unwrap();
             provider.register_typeface(ft_type, Some(font_name));
         }
@@ -85,9 +124,29 @@ impl<State: 'static + Clone> App<State> {
 
         plugins.send(PluginEvent::WindowCreated(&window_env.window));
 
-        let platform_information = Arc::new(Mutex::new(PlatformInformation::from_winit(
+        let mut platform_information = PlatformInformation::from_winit(
             window_env.window.inner_size(),
-        )));
+            window_env.window.scale_factor(),
+        );
+        // The app is laid out at the fixed resolution, no matter the size of the Window
+        if let Some(fixed_resolution) = window_env.window_config.fixed_resolution {
+            platform_information.window_size = fixed_resolution;
+        }
+        let window_size_notifier = WindowSizeNotifier::new(platform_information.window_size);
+        let platform_information = Arc::new(Mutex::new(platform_information));
+        let window_insets_notifier = WindowInsetsNotifier::new(window_env.window_insets());
+        let text_measurer = TextMeasurer::new(font_collection.clone(), default_fonts.clone());
+        let frame_pacer =
+            FramePacer::new(window_env.window_config.min_frame_time, &window_env.window);
+        let hit_region = window_env.window_config.hit_region.clone();
+
+        let reduced_motion = window_env
+            .window_config
+            .reduced_motion
+            .unwrap_or_else(platform_prefers_reduced_motion);
+        sdom.get()
+            .animation_clock()
+            .set_reduced_motion(reduced_motion);
 
         Self {
             sdom,
@@ -105,12 +164,30 @@ impl<State: 'static + Clone> App<State> {
             focus_receiver,
             font_collection,
             font_mgr,
+            text_measurer,
             ticker_sender: broadcast::channel(5).0,
+            layout_notifier: LayoutNotifier::default(),
+            pointer_nodes_notifier: PointerNodesNotifier::default(),
+            window_insets_notifier,
+            window_size_notifier,
+            keyboard_state_notifier: KeyboardStateNotifier::default(),
+            close_request_notifier: CloseRequestNotifier::default(),
             plugins,
             navigator_state: NavigatorState::new(NavigationMode::NotKeyboard),
             measure_layout_on_next_render: false,
+            pending_repaint: true,
+            tick_requested: false,
+            window_occluded: false,
+            window_minimized: false,
             platform_information,
             default_fonts,
+            dirty_regions: DirtyRegions::default(),
+            frame_capture: None,
+            subtree_cache: SubtreeCache::default(),
+            unthrottled_pointer_moves: 0,
+            hit_region,
+            cursor_hittest: true,
+            frame_pacer,
         }
     }
 
@@ -125,10 +202,28 @@ impl<State: 'static + Clone> App<State> {
             .insert_any_root_context(Box::new(self.focus_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
+        self.vdom
+            .insert_any_root_context(Box::new(self.layout_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.pointer_nodes_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.window_insets_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.window_size_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.keyboard_state_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.close_request_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.text_measurer.clone()));
         self.vdom
             .insert_any_root_context(Box::new(self.navigator_state.clone()));
         self.vdom
             .insert_any_root_context(Box::new(self.platform_information.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.sdom.get().keyframes()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.sdom.get().animation_clock()));
     }
 
     /// Make the first build of the VirtualDOM and sync it with the RealDOM.
@@ -163,46 +258,66 @@ impl<State: 'static + Clone> App<State> {
 
     /// Poll the VirtualDOM for any new change
     pub fn poll_vdom(&mut self) {
+        let (must_repaint, must_relayout) = self.apply_pending_vdom_work();
+
+        if must_relayout || must_repaint {
+            self.request_repaint();
+        } else if self.sdom.get().has_running_animations() {
+            // Transitions and animations only change the DOM once they are processed in the next frame
+            self.request_tick();
+        }
+    }
+
+    /// Run the pending work of the VirtualDOM and apply its changes to the DOM, without requesting a new frame.
+    fn apply_pending_vdom_work(&mut self) -> (bool, bool) {
         let waker = &self.vdom_waker.clone();
         let mut cx = std::task::Context::from_waker(waker);
 
-        loop {
-            {
-                let fut = async {
-                    select! {
-                        ev = self.event_receiver.recv() => {
-                            if let Some(ev) = ev {
-                                let data = ev.data.any();
-                                self.vdom.handle_event(ev.name.into(), data, ev.element_id, ev.bubbles);
-
-                                self.vdom.process_events();
-                            }
-                        },
-                        _ = self.vdom.wait_for_work() => {},
-                    }
-                };
-                pin_mut!(fut);
+        let (mut must_repaint, mut must_relayout) = (false, false);
+        while poll_vdom_work(
+            &mut cx,
+            &mut self.vdom,
+            &mut self.event_receiver,
+            handle_dom_event,
+        ) {
+            let (repaint, relayout) = self.apply_vdom_changes();
+            must_repaint |= repaint;
+            must_relayout |= relayout;
+        }
 
-                match fut.poll_unpin(&mut cx) {
-                    std::task::Poll::Ready(_) => {}
-                    std::task::Poll::Pending => break,
-                }
-            }
+        if must_relayout {
+            self.measure_layout_on_next_render = true;
+        }
 
-            let (must_repaint, must_relayout) = self.apply_vdom_changes();
+        (must_repaint, must_relayout)
+    }
 
-            if must_relayout {
-                self.measure_layout_on_next_render = true;
-            }
+    /// Advance the running attribute transitions and animations of the DOM.
+    pub fn process_animations(&mut self) {
+        let scale_factor = self.window_env.window.scale_factor() as f32;
+        let (must_repaint, must_relayout) = self.sdom.get_mut().process_animations(scale_factor);
 
-            if must_relayout || must_repaint {
-                self.window_env.window.request_redraw();
-            }
+        if must_relayout {
+            self.measure_layout_on_next_render = true;
+        }
+
+        if must_relayout || must_repaint {
+            self.pending_repaint = true;
         }
     }
 
+    /// Check if there are attribute transitions or animations running, which need a new frame for each of their steps.
+    pub fn has_running_animations(&self) -> bool {
+        self.sdom.get().has_running_animations()
+    }
+
+    /// Run the handlers of a DOM event
     /// Process the events queue
     pub fn process_events(&mut self) {
+        if self.are_pointer_moves_throttled() {
+            coalesce_mouse_movements(&mut self.events);
+        }
+
         let scale_factor = self.window_env.window.scale_factor();
         process_events(
             &self.sdom.get(),
@@ -210,9 +325,56 @@ impl<State: 'static + Clone> App<State> {
             &self.event_emitter,
             &mut self.nodes_state,
             scale_factor,
+        );
+
+        self.pointer_nodes_notifier.notify(&self.nodes_state);
+    }
+
+    /// Emit an event to the given Node directly, see [`process_node_event`].
+    pub fn dispatch_node_event(&mut self, node_id: NodeId, event: PlatformEvent) {
+        let scale_factor = self.window_env.window.scale_factor();
+        process_node_event(
+            &self.sdom.get(),
+            node_id,
+            event,
+            &self.event_emitter,
+            scale_factor,
+        );
+    }
+
+    /// Get the Nodes under a logical position of the Window, from the topmost to the bottommost one
+    pub fn nodes_at_point(&self, (x, y): (f64, f64)) -> Vec<NodeId> {
+        let scale_factor = self.window_env.window.scale_factor();
+        nodes_at_point(
+            &self.sdom.get(),
+            CursorPoint::new(x * scale_factor, y * scale_factor),
         )
     }
 
+    /// Emit the `mounted` events of the new nodes, now that their layout is known
+    pub fn process_mounted_events(&mut self) {
+        let scale_factor = self.window_env.window.scale_factor();
+        let mounted_nodes = self.sdom.get_mut().take_mounted_nodes();
+        process_mounted_events(
+            &self.sdom.get(),
+            mounted_nodes,
+            &self.event_emitter,
+            scale_factor,
+        );
+    }
+
+    /// Emit the `scroll` events of the nodes that got scrolled, now that the size of their content is known
+    pub fn process_scroll_events(&mut self) {
+        let scale_factor = self.window_env.window.scale_factor();
+        let scrolled_nodes = self.sdom.get_mut().take_scrolled_nodes();
+        process_scroll_events(
+            &self.sdom.get(),
+            scrolled_nodes,
+            &self.event_emitter,
+            scale_factor,
+        );
+    }
+
     /// Create the Accessibility tree
     /// This will iterater the DOM ordered by layers (top to bottom)
     /// and add every element with an accessibility ID to the Accessibility Tree
@@ -228,26 +390,189 @@ impl<State: 'static + Clone> App<State> {
         );
     }
 
+    /// Get the computed layout of the whole tree as pretty-printed JSON, useful for bug reports.
+    pub fn dump_layout(&self) -> String {
+        format!("{:#}", self.sdom.get().dump_layout())
+    }
+
+    /// Render a Node and its descendants into an offscreen image, e.g. to use it as a drag preview.
+    ///
+    /// The subtree is rendered like in the Window, with its own transforms, opacity and clipping,
+    /// but without the rotations and opacities inherited from its ancestors.
+    /// The clipping of ancestors (e.g. a `ScrollView`) still applies, so the parts that are hidden on the screen are transparent.
+    /// See [`render_offscreen`](Self::render_offscreen) for the `supersampling` factor.
+    pub fn snapshot_node(&mut self, node_id: NodeId, supersampling: u32) -> Option<NodeSnapshot> {
+        let scale_factor = self.window_env.window.scale_factor() as f32;
+        let area = {
+            let fdom = self.sdom.get();
+            let node = fdom.rdom().get(node_id)?;
+            let layout = fdom.layout();
+            get_painted_area(&node, &layout.get(node_id)?.area).round_out()
+        };
+        if area.is_empty() {
+            return None;
+        }
+
+        let image = self.render_offscreen(node_id, area, Color::TRANSPARENT, supersampling)?;
+        let data = image.encode(None, EncodedImageFormat::PNG, None)?;
+
+        Some(NodeSnapshot {
+            image: data.as_bytes().to_vec(),
+            area: area.div(scale_factor),
+        })
+    }
+
+    /// Render a Node and its descendants into a raster image of the given area, in physical pixels.
+    ///
+    /// With a `supersampling` factor above 1 (up to 4) the subtree is rendered into a surface that is that many times
+    /// bigger in each direction, which then gets halved with linear filtering until it has the size of the area,
+    /// so every pixel averages all the samples that cover it.
+    fn render_offscreen(
+        &mut self,
+        node_id: NodeId,
+        area: Area,
+        background: Color,
+        supersampling: u32,
+    ) -> Option<Image> {
+        let supersampling = supersampling.clamp(1, MAX_SUPERSAMPLING);
+        let fdom = self.sdom.get();
+        fdom.rdom().get(node_id)?;
+
+        let target_size = (area.width() as i32, area.height() as i32);
+        let mut size = (
+            target_size.0 * supersampling as i32,
+            target_size.1 * supersampling as i32,
+        );
+        let image_info = ImageInfo::new_n32_premul(size, None);
+        let mut surface = self.window_env.canvas().new_surface(&image_info, None)?;
+        let canvas = surface.canvas();
+        canvas.clear(background);
+        canvas.scale((supersampling as f32, supersampling as f32));
+        canvas.translate((-area.min_x(), -area.min_y()));
+
+        render_layers(
+            &mut surface,
+            &fdom,
+            &mut self.font_collection,
+            &self.font_mgr,
+            &self.default_fonts,
+            RenderPass {
+                root: Some(node_id),
+                ..RenderPass::default()
+            },
+        );
+
+        // Linear filtering only blends the closest 2x2 samples, so it's downsampled by halves to not skip any
+        while size != target_size {
+            size = if size.0 >= target_size.0 * 2 && size.1 >= target_size.1 * 2 {
+                (size.0 / 2, size.1 / 2)
+            } else {
+                target_size
+            };
+            let image_info = ImageInfo::new_n32_premul(size, None);
+            let mut downsampled = self.window_env.canvas().new_surface(&image_info, None)?;
+            let canvas = downsampled.canvas();
+            canvas.clear(Color::TRANSPARENT);
+            canvas.draw_image_rect_with_sampling_options(
+                surface.image_snapshot(),
+                None,
+                Rect::new(0.0, 0.0, size.0 as f32, size.1 as f32),
+                FilterMode::Linear,
+                &Paint::default(),
+            );
+            surface = downsampled;
+        }
+
+        // Read the pixels back from the GPU to encode them
+        surface
+            .image_snapshot()
+            .make_raster_image(&mut self.window_env.gr_context, None)
+    }
+
+    /// Get the frame that was just rendered into the Window, or render the whole app again offscreen if it's supersampled.
+    fn read_frame(&mut self, supersampling: u32) -> Option<Image> {
+        if supersampling > 1 {
+            let (root_id, area) = {
+                let fdom = self.sdom.get();
+                let root_id = fdom.rdom().root_id();
+                let area = fdom.layout().get(root_id)?.area.round_out();
+                (root_id, area)
+            };
+            let background = self.window_env.window_config.background;
+            self.render_offscreen(root_id, area, background, supersampling)
+        } else {
+            self.window_env
+                .surface
+                .image_snapshot()
+                .make_raster_image(&mut self.window_env.gr_context, None)
+        }
+    }
+
     /// Send an event
     pub fn send_event(&mut self, event: PlatformEvent) {
         self.events.push(event);
         self.process_events();
     }
 
+    /// Queue an event without processing it yet, see [`flush_events`](Self::flush_events).
+    pub fn queue_event(&mut self, event: PlatformEvent) {
+        self.events.push(event);
+    }
+
+    /// Process the queued events, if any.
+    pub fn flush_events(&mut self) {
+        if !self.events.is_empty() {
+            self.process_events();
+        }
+    }
+
+    /// Process the events queued since the last frame, like the coalesced pointer movements,
+    /// and apply their changes to the DOM so they are rendered in the frame that is starting.
+    pub fn flush_frame_events(&mut self) {
+        if self.events.is_empty() {
+            return;
+        }
+
+        self.process_events();
+        let (must_repaint, _) = self.apply_pending_vdom_work();
+        if must_repaint {
+            self.pending_repaint = true;
+        }
+    }
+
+    /// Check if the pointer movements are coalesced, which is the case unless a component asked for every sample.
+    pub fn are_pointer_moves_throttled(&self) -> bool {
+        self.unthrottled_pointer_moves == 0
+    }
+
+    /// Count a component that needs every pointer movement sample, or one that no longer does.
+    pub fn set_unthrottled_pointer_moves(&mut self, unthrottled: bool) {
+        if unthrottled {
+            self.unthrottled_pointer_moves += 1;
+        } else {
+            self.unthrottled_pointer_moves = self.unthrottled_pointer_moves.saturating_sub(1);
+        }
+    }
+
     /// Replace a VirtualDOM Template
     pub fn vdom_replace_template(&mut self, template: Template) {
         self.vdom.replace_template(template);
+        self.plugins.send(PluginEvent::TemplateReplaced(&template));
     }
 
     /// Render the App into the Window Canvas
     pub fn render(&mut self, hovered_node: &HoveredNode) {
+        self.sdom
+            .get()
+            .restyle_paragraphs(&self.font_collection, &self.default_fonts);
+
         self.plugins.send(PluginEvent::BeforeRender {
             canvas: self.window_env.canvas(),
             font_collection: &self.font_collection,
             freya_dom: &self.sdom.get(),
         });
 
-        self.start_render(hovered_node);
+        let damage = self.start_render(hovered_node);
 
         self.accessibility
             .render_accessibility(self.window_env.window.title().as_str());
@@ -258,15 +583,176 @@ impl<State: 'static + Clone> App<State> {
             freya_dom: &self.sdom.get(),
         });
 
-        self.finish_render();
+        if let Some(mut frame_capture) = self.frame_capture.take() {
+            let supersampling = frame_capture.supersampling();
+            frame_capture.capture(|| self.read_frame(supersampling));
+            self.frame_capture = Some(frame_capture);
+        }
+
+        self.finish_render(damage);
+    }
+
+    /// Synchronously apply every pending change until the app is stable.
+    ///
+    /// The queued events are processed, the VirtualDOM is polled and the layout measured again
+    /// as long as there are changes, so the layout effects (e.g. `onsizechange` or `use_node`) that change the state
+    /// get applied too. It stops after a bounded amount of iterations in case the app never settles.
+    /// The app is rendered at the end if `render` is `true` and the Window is visible.
+    ///
+    /// Returns whether the app settled.
+    pub fn flush(&mut self, hovered_node: &HoveredNode, render: bool) -> bool {
+        let mut settled = false;
+
+        for _ in 0..MAX_FLUSH_ITERATIONS {
+            self.flush_events();
+            self.poll_vdom();
+
+            if !self.measure_layout_on_next_render {
+                settled = true;
+                break;
+            }
+
+            self.process_layout();
+            self.measure_layout_on_next_render = false;
+            self.notify_layout_effects();
+        }
+
+        if !settled {
+            warn!("The app didn't settle after {MAX_FLUSH_ITERATIONS} relayouts, it might be stuck in a loop.");
+        }
+
+        // Flushing must render synchronously, so it isn't paced, but the next frames are paced from it
+        if render && !self.is_occluded() {
+            self.frame_pacer.start_frame(Instant::now());
+            self.render(hovered_node);
+            self.pending_repaint = false;
+        }
+
+        settled
+    }
+
+    /// Start writing the rendered frames into `directory`, see [`FrameCapture`].
+    pub fn start_frame_capture(
+        &mut self,
+        directory: PathBuf,
+        frames_per_second: u32,
+        supersampling: u32,
+    ) {
+        self.stop_frame_capture();
+        match FrameCapture::start(directory, frames_per_second, supersampling) {
+            Ok(frame_capture) => {
+                self.frame_capture = Some(frame_capture);
+                self.request_repaint();
+            }
+            Err(err) => warn!("Failed to start capturing frames: {err}"),
+        }
+    }
+
+    /// Stop writing the rendered frames, waiting for the pending ones to be written.
+    pub fn stop_frame_capture(&mut self) {
+        if let Some(frame_capture) = self.frame_capture.take() {
+            frame_capture.stop();
+        }
     }
 
     /// Resize the Window
     pub fn resize(&mut self, size: PhysicalSize<u32>) {
         self.measure_layout_on_next_render = true;
-        self.sdom.get().layout().reset();
+        self.pending_repaint = true;
+        self.sdom.get().layout().invalidate_all();
+        self.dirty_regions.invalidate();
+        self.subtree_cache.invalidate();
+        self.frame_pacer.update_monitor(&self.window_env.window);
         self.window_env.resize(size);
-        *self.platform_information.lock().unwrap() = PlatformInformation::from_winit(size);
+        {
+            let mut platform_information = self.platform_information.lock().unwrap();
+            let PlatformInformation {
+                window_size,
+                scale_factor,
+                ..
+            } = PlatformInformation::from_winit(size, self.window_env.window.scale_factor());
+            platform_information.window_size = self
+                .window_env
+                .window_config
+                .fixed_resolution
+                .unwrap_or(window_size);
+            platform_information.scale_factor = scale_factor;
+            self.window_size_notifier
+                .set(platform_information.window_size);
+        }
+        self.set_minimized(self.window_env.window.is_minimized().unwrap_or_default());
+        self.window_insets_notifier
+            .set(self.window_env.window_insets());
+    }
+
+    /// The Window moved to a monitor with a different scale factor.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        // Sizes, paddings, fonts and so on are all scaled by the states
+        self.sdom.get_mut().update_scale_factor(scale_factor as f32);
+        self.resize(self.window_env.window.inner_size());
+    }
+
+    /// Check if the Window is minimized or fully occluded, in which case nothing is rendered.
+    pub fn is_occluded(&self) -> bool {
+        self.window_occluded || self.window_minimized
+    }
+
+    /// Request a new frame, unless the Window is occluded.
+    pub fn request_redraw(&self) {
+        if !self.is_occluded() {
+            self.window_env.window.request_redraw();
+        }
+    }
+
+    /// Request a new frame that repaints the Window.
+    pub fn request_repaint(&mut self) {
+        self.pending_repaint = true;
+        self.request_redraw();
+    }
+
+    /// Request a new frame to tick the tickers, which only repaints the Window if something changed meanwhile.
+    pub fn request_tick(&mut self) {
+        self.tick_requested = true;
+        self.request_redraw();
+    }
+
+    /// Check if the requested frame must be painted.
+    ///
+    /// Frames requested only to tick the tickers are skipped if nothing changed,
+    /// but those requested by the platform (e.g. to restore the content of the Window) are always painted.
+    pub fn must_repaint(&self) -> bool {
+        self.pending_repaint || !self.tick_requested
+    }
+
+    /// Pause or resume the rendering as the Window gets occluded by other windows or visible again.
+    pub fn set_occluded(&mut self, occluded: bool) {
+        self.window_occluded = occluded;
+        self.update_visibility();
+    }
+
+    /// Pause or resume the rendering as the Window gets minimized or restored.
+    pub fn set_minimized(&mut self, minimized: bool) {
+        self.window_minimized = minimized;
+        self.update_visibility();
+    }
+
+    fn update_visibility(&mut self) {
+        let is_occluded = self.is_occluded();
+        let mut platform_information = self.platform_information.lock().unwrap();
+        if platform_information.is_occluded == is_occluded {
+            return;
+        }
+
+        platform_information.is_occluded = is_occluded;
+
+        // Resume with a full repaint, the state may have changed while the Window was occluded
+        if !is_occluded {
+            platform_information.resumed_at = Some(Instant::now());
+            drop(platform_information);
+            self.dirty_regions.invalidate();
+            self.pending_repaint = true;
+            self.window_env.window.request_redraw();
+        }
     }
 
     /// Measure the a text group given it's ID.
@@ -280,6 +766,11 @@ impl<State: 'static + Clone> App<State> {
             .focus_next_node(direction, &self.focus_sender, &self.window_env.window)
     }
 
+    /// Notify the components waiting for the layout of the current frame to be measured.
+    pub fn notify_layout_effects(&self) {
+        self.layout_notifier.notify();
+    }
+
     /// Notify components subscribed to event loop ticks.
     pub fn event_loop_tick(&self) {
         self.ticker_sender.send(()).ok();
@@ -290,24 +781,166 @@ impl<State: 'static + Clone> App<State> {
         self.navigator_state.set(mode);
     }
 
+    /// Get the [`Letterbox`] that fits the app into the Window, if it's laid out at a fixed resolution.
+    fn letterbox(&self) -> Option<Letterbox> {
+        let fixed_resolution = self.window_env.window_config.fixed_resolution?;
+        let window_size = self.window_env.window.inner_size();
+        Some(Letterbox::new(
+            fixed_resolution,
+            self.window_env.window.scale_factor() as f32,
+            Size2D::new(window_size.width as f32, window_size.height as f32),
+        ))
+    }
+
+    /// Get the area in which the app is laid out and painted, before mapping it into the Window.
+    fn layout_area(&self) -> Area {
+        if let Some(letterbox) = self.letterbox() {
+            letterbox.layout_area()
+        } else {
+            // The platform might make the Window smaller than its minimum size anyway, e.g. when tiling it
+            let window_size = self.window_env.window.inner_size();
+            let min_size = self.window_env.min_size();
+            Area::from_size(Size2D::from((
+                (window_size.width as f64).max(min_size.width) as f32,
+                (window_size.height as f64).max(min_size.height) as f32,
+            )))
+        }
+    }
+
+    /// Keep track of the last known position of the pointer, in physical pixels.
+    pub fn update_cursor_position(&mut self, position: Option<CursorPoint>) {
+        let scale_factor = self.window_env.window.scale_factor();
+        self.platform_information.lock().unwrap().cursor_position =
+            position.map(|position| position / scale_factor);
+        self.update_cursor_hittest();
+    }
+
+    /// Change the parts of the Window that receive pointer events, see [`HitRegion`].
+    pub fn set_hit_region(&mut self, hit_region: HitRegion) {
+        self.hit_region = hit_region;
+        self.update_cursor_hittest();
+    }
+
+    /// Check if the Window needs the raw movements of the mouse, to know when the pointer might be back over its hit region.
+    pub fn needs_raw_pointer_movements(&self) -> bool {
+        self.hit_region != HitRegion::Window
+    }
+
+    /// Check if a point of the Window, in physical pixels, is inside of its hit region.
+    fn is_inside_hit_region(&self, point: CursorPoint) -> bool {
+        match &self.hit_region {
+            HitRegion::Window => true,
+            HitRegion::Painted => is_point_painted(&self.sdom.get(), point),
+            HitRegion::Areas(areas) => {
+                let point = (point / self.window_env.window.scale_factor()).to_f32();
+                areas.iter().any(|area| area.contains(point))
+            }
+        }
+    }
+
+    /// Let the pointer events pass through the Window while the pointer is outside of the hit region.
+    /// Must be called again when the painted content changes under the pointer.
+    pub fn update_cursor_hittest(&mut self) {
+        let cursor_position = self.platform_information.lock().unwrap().cursor_position;
+        let hittest = match cursor_position {
+            Some(cursor_position) => {
+                self.is_inside_hit_region(cursor_position * self.window_env.window.scale_factor())
+            }
+            // The pointer is outside of the Window or its events pass through, only its next movement tells where it is
+            None => self.hit_region == HitRegion::Window || self.cursor_hittest,
+        };
+        self.set_cursor_hittest(hittest);
+    }
+
+    /// Enable or disable the pointer hit testing of the whole Window, if it changed.
+    pub fn set_cursor_hittest(&mut self, hittest: bool) {
+        if self.cursor_hittest == hittest {
+            return;
+        }
+
+        match self.window_env.window.set_cursor_hittest(hittest) {
+            Ok(()) => {
+                self.cursor_hittest = hittest;
+                // The position of the pointer is unknown while its events pass through
+                if !hittest {
+                    self.platform_information.lock().unwrap().cursor_position = None;
+                }
+            }
+            Err(err) => {
+                warn!("Could not change the cursor hittest of the Window, it will receive all the pointer events: {err}");
+                self.hit_region = HitRegion::Window;
+            }
+        }
+    }
+
+    /// Move the mouse cursor to the given position of the Window, in logical pixels.
+    pub fn set_cursor_position(&mut self, (x, y): (f64, f64)) {
+        match self
+            .window_env
+            .window
+            .set_cursor_position(LogicalPosition::new(x, y))
+        {
+            Ok(()) => {
+                // Not every platform emits a movement event after warping the cursor
+                self.platform_information.lock().unwrap().cursor_position =
+                    Some(CursorPoint::new(x, y));
+            }
+            Err(err) => warn!("Failed to move the cursor: {err}"),
+        }
+    }
+
+    /// Change the minimum size of the Window, in logical pixels.
+    pub fn set_window_min_size(&mut self, min_size: Option<(f64, f64)>) {
+        self.window_env.set_min_size(min_size);
+
+        // The layout might get bigger than the Window
+        self.measure_layout_on_next_render = true;
+        self.sdom.get().layout().invalidate_all();
+        self.dirty_regions.invalidate();
+        self.subtree_cache.invalidate();
+        self.request_repaint();
+    }
+
+    /// Change the maximum size of the Window, in logical pixels.
+    pub fn set_window_max_size(&mut self, max_size: Option<(f64, f64)>) {
+        self.window_env.set_max_size(max_size);
+    }
+
+    /// Show or hide the outlines of the layout bounds of every node.
+    pub fn toggle_layout_overlay(&mut self) {
+        let window_config = &mut self.window_env.window_config;
+        window_config.layout_overlay = !window_config.layout_overlay;
+
+        self.dirty_regions.invalidate();
+        self.request_repaint();
+    }
+
+    /// Change the background color of the Window.
+    pub fn set_window_background(&mut self, background: Color) {
+        self.window_env.set_background(background);
+
+        // The background is visible wherever the app doesn't paint
+        self.dirty_regions.invalidate();
+        self.request_repaint();
+    }
+
     /// Measure the layout
     pub fn process_layout(&mut self) {
         self.accessibility.clear_accessibility();
 
+        let letterbox = self.letterbox();
+        self.sdom.get_mut().set_letterbox(letterbox);
+
         {
             let fdom = self.sdom.get();
 
             self.plugins
                 .send(PluginEvent::StartedLayout(&fdom.layout()));
 
-            let window_size = self.window_env.window.inner_size();
             let scale_factor = self.window_env.window.scale_factor() as f32;
             process_layout(
                 &fdom,
-                Area::from_size(Size2D::from((
-                    window_size.width as f32,
-                    window_size.height as f32,
-                ))),
+                self.layout_area(),
                 &mut self.font_collection,
                 scale_factor,
                 &self.default_fonts,
@@ -322,6 +955,8 @@ impl<State: 'static + Clone> App<State> {
         }
 
         self.process_accessibility();
+        self.process_mounted_events();
+        self.process_scroll_events();
 
         let fdom = self.sdom.get();
         info!(
@@ -331,50 +966,144 @@ impl<State: 'static + Clone> App<State> {
         );
     }
 
-    /// Start rendering the RealDOM to Window
-    pub fn start_render(&mut self, hovered_node: &HoveredNode) {
+    /// Get the region of the Window that must be repainted, `None` if it's the whole Window.
+    fn get_dirty_region(
+        &mut self,
+        hovered_node: &HoveredNode,
+        dirty_nodes: &FxHashSet<NodeId>,
+        window_area: Area,
+    ) -> Option<Area> {
+        // Devtools, plugins and the layout overlay might paint anywhere
+        if hovered_node.is_some()
+            || !self.plugins.is_empty()
+            || self.window_env.window_config.layout_overlay
+        {
+            self.dirty_regions.invalidate();
+            return None;
+        }
+
+        self.dirty_regions.compute(
+            &self.sdom.get(),
+            dirty_nodes,
+            window_area,
+            self.window_env.buffer_age(),
+        )
+    }
+
+    /// Start rendering the RealDOM to Window.
+    ///
+    /// Returns the region of the Window that was repainted, `None` if it's the whole Window.
+    pub fn start_render(&mut self, hovered_node: &HoveredNode) -> Option<Area> {
+        let dirty_nodes = self.sdom.get_mut().take_dirty_nodes();
+        let composited_changes = self.sdom.get_mut().take_composited_changes();
+
+        let letterbox = self.letterbox();
+        let window_area = self.layout_area();
+
+        let dirty_region = self.get_dirty_region(hovered_node, &dirty_nodes, window_area);
+
+        // Devtools need to highlight any node, even those inside cached subtrees
+        if hovered_node.is_some() {
+            self.subtree_cache.invalidate();
+        } else {
+            self.subtree_cache.prepare(
+                &self.sdom.get(),
+                &dirty_nodes,
+                &composited_changes,
+                window_area,
+            );
+        }
+
+        // Scale and center the app in the Window, the letterbox bars keep the background of the Window.
+        // They only need to be painted with full repaints, as dirty regions are always inside the app.
+        if let Some(letterbox) = letterbox {
+            if dirty_region.is_none() {
+                self.window_env.clear();
+            }
+            let canvas = self.window_env.canvas();
+            canvas.save();
+            canvas.translate((letterbox.offset.x, letterbox.offset.y));
+            canvas.scale((letterbox.scale, letterbox.scale));
+            canvas.clip_rect(
+                Rect::new(0.0, 0.0, window_area.width(), window_area.height()),
+                ClipOp::Intersect,
+                false,
+            );
+        }
+
+        // Only repaint the dirty region, the rest of the back buffer is still valid
+        if let Some(dirty_region) = dirty_region {
+            let canvas = self.window_env.canvas();
+            canvas.save();
+            canvas.clip_rect(
+                Rect::new(
+                    dirty_region.min_x(),
+                    dirty_region.min_y(),
+                    dirty_region.max_x(),
+                    dirty_region.max_y(),
+                ),
+                ClipOp::Intersect,
+                false,
+            );
+        }
+
         self.window_env.clear();
 
-        let canvas = self.window_env.canvas();
+        let layout_overlay = self.window_env.window_config.layout_overlay;
+        let scale_factor = self.window_env.window.scale_factor() as f32;
         let fdom = self.sdom.get();
+        let hovered_node = hovered_node.as_ref().and_then(|node| *node.lock().unwrap());
 
-        let mut matrices: Vec<(Matrix, Vec<NodeId>)> = Vec::default();
-        let mut opacities: Vec<(f32, Vec<NodeId>)> = Vec::default();
-
-        process_render(
+        render_layers(
+            &mut self.window_env,
             &fdom,
             &mut self.font_collection,
-            |fdom, node_id, area, font_collection, layout| {
-                let render_wireframe = if let Some(hovered_node) = &hovered_node {
-                    hovered_node
-                        .lock()
-                        .unwrap()
-                        .map(|id| id == *node_id)
-                        .unwrap_or_default()
-                } else {
-                    false
-                };
-                if let Some(dioxus_node) = fdom.rdom().get(*node_id) {
-                    render_skia(
-                        canvas,
-                        area,
-                        &dioxus_node,
-                        font_collection,
-                        &self.font_mgr,
-                        render_wireframe,
-                        &mut matrices,
-                        &mut opacities,
-                        &self.default_fonts,
-                        layout,
-                    );
-                }
+            &self.font_mgr,
+            &self.default_fonts,
+            RenderPass {
+                dirty_region: dirty_region.map(|dirty_region| (dirty_region, &self.dirty_regions)),
+                subtree_cache: Some(&mut self.subtree_cache),
+                hovered_node,
+                root: None,
             },
         );
+
+        let canvas = self.window_env.canvas();
+
+        if layout_overlay {
+            render_layout_overlay(canvas, &fdom);
+        }
+
+        // The label goes over everything else so it's never hidden by the app
+        if let Some(hovered_node) = hovered_node {
+            render_node_metrics(
+                canvas,
+                &fdom,
+                hovered_node,
+                &self.font_collection,
+                &self.default_fonts,
+                window_area,
+                scale_factor,
+            );
+        }
+
+        if dirty_region.is_some() {
+            canvas.restore();
+        }
+
+        if letterbox.is_some() {
+            canvas.restore();
+        }
+
+        dirty_region.map(|dirty_region| match letterbox {
+            Some(letterbox) => letterbox.to_window(dirty_region),
+            None => dirty_region,
+        })
     }
 
-    /// Finish all rendering in the Window
-    pub fn finish_render(&mut self) {
-        self.window_env.finish_render();
+    /// Finish all rendering in the Window, only presenting the `damage` region of it if it's known.
+    pub fn finish_render(&mut self, damage: Option<Area>) {
+        self.window_env.finish_render(damage);
     }
 
     /// Run the application.
@@ -387,3 +1116,103 @@ impl<State: 'static + Clone> App<State> {
         run_event_loop(self, event_loop, proxy, hovered_node)
     }
 }
+
+/// Wait until the VirtualDOM has some work to do or receives an event, and handle all the already queued events too,
+/// so the state updates of all of them get rendered in a single pass afterwards.
+///
+/// Returns `false` if there is nothing to do yet.
+fn poll_vdom_work<T>(
+    cx: &mut std::task::Context,
+    vdom: &mut VirtualDom,
+    event_receiver: &mut mpsc::UnboundedReceiver<T>,
+    mut handle_event: impl FnMut(&mut VirtualDom, T),
+) -> bool {
+    {
+        let fut = async {
+            select! {
+                ev = event_receiver.recv() => {
+                    if let Some(ev) = ev {
+                        handle_event(vdom, ev);
+                    }
+                },
+                _ = vdom.wait_for_work() => {},
+            }
+        };
+        pin_mut!(fut);
+
+        if fut.poll_unpin(cx).is_pending() {
+            return false;
+        }
+    }
+
+    while let Ok(ev) = event_receiver.try_recv() {
+        handle_event(vdom, ev);
+    }
+
+    true
+}
+
+fn handle_dom_event(vdom: &mut VirtualDom, ev: DomEvent) {
+    // Skip the events whose capture phase was stopped
+    if ev.data.is_capture_stopped() {
+        return;
+    }
+
+    let data = ev.data.any();
+    vdom.handle_event(ev.name.into(), data, ev.element_id, ev.bubbles);
+    vdom.process_events();
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use dioxus_core::{prelude::*, NoOpMutations, VirtualDom};
+    use futures_task::noop_waker;
+    use tokio::sync::mpsc;
+
+    use super::poll_vdom_work;
+
+    /// Times the app was rendered by the VirtualDOM.
+    static RENDERS: AtomicUsize = AtomicUsize::new(0);
+
+    fn app() -> Element {
+        RENDERS.fetch_add(1, Ordering::Relaxed);
+        None
+    }
+
+    #[test]
+    fn queued_events_are_rendered_in_a_single_pass() {
+        let mut vdom = VirtualDom::new(app);
+        vdom.rebuild_in_place();
+        let renders = RENDERS.load(Ordering::Relaxed);
+
+        let waker = noop_waker();
+        let mut cx = std::task::Context::from_waker(&waker);
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+
+        // A burst of events that update the state, all queued before the VirtualDOM is polled
+        let events = 5;
+        for _ in 0..events {
+            sender.send(()).unwrap();
+        }
+
+        let mut passes = 0;
+        while poll_vdom_work(&mut cx, &mut vdom, &mut receiver, |vdom, _| {
+            vdom.mark_dirty(ScopeId::ROOT)
+        }) {
+            vdom.render_immediate(&mut NoOpMutations);
+            passes += 1;
+        }
+
+        // Handling only the event that woke it up would take a pass for every event
+        assert_eq!(passes, 1);
+        assert_eq!(RENDERS.load(Ordering::Relaxed), renders + 1);
+        assert!(!poll_vdom_work(
+            &mut cx,
+            &mut vdom,
+            &mut receiver,
+            |_, _| {}
+        ));
+    }
+}
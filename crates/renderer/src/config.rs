@@ -1,39 +1,213 @@
-use std::{io::Cursor, sync::Arc};
+use std::{io::Cursor, path::Path, sync::Arc, time::Duration};
 
+use freya_common::HitRegion;
 use freya_core::plugins::{FreyaPlugin, PluginsManager};
 use freya_engine::prelude::Color;
-use freya_node_state::Parse;
+use freya_hooks::WindowInsets;
+use freya_node_state::{CustomElement, CustomElements, Parse, RenderSettings};
 use image::io::Reader;
+use torin::geometry::Size2D;
+use winit::dpi::{PhysicalPosition, PhysicalSize};
 use winit::window::{Icon, Window, WindowBuilder};
 
 pub type WindowBuilderHook = Box<dyn Fn(WindowBuilder) -> WindowBuilder>;
 pub type EmbeddedFonts<'a> = Vec<(&'a str, &'a [u8])>;
 
+/// How the event loop waits for new events, see [`LaunchConfigBuilder::with_control_flow`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ControlFlowMode {
+    /// Sleep until there is a new event, e.g. an input or a redraw requested by the app.
+    ///
+    /// The app only uses CPU when something happens, while animations keep requesting new frames as they run.
+    #[default]
+    Wait,
+    /// Never sleep, and tick a new frame as soon as the previous one is done, like a game loop.
+    ///
+    /// Tickers and canvases are updated as often as possible, only limited by the [`PresentMode`] of the Window,
+    /// at the cost of keeping a CPU core busy even when the app is idle.
+    /// The event loop sleeps anyway while the Window is minimized or occluded.
+    Poll,
+    /// Sleep until there is a new event, but tick a new frame at least every given interval.
+    ///
+    /// Useful for apps that need to update periodically, e.g. a clock or a dashboard polling some data,
+    /// with a CPU usage that depends on the interval. Events are still processed as soon as they arrive.
+    WaitUntil(Duration),
+}
+
+/// When the rendered frames are presented in the Window, see [`LaunchConfigBuilder::with_present_mode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PresentMode {
+    /// Wait for the vertical blank of the display before presenting a frame, also known as vsync.
+    ///
+    /// Frames never tear and the frame rate is capped at the refresh rate of the display,
+    /// at the cost of up to a frame of latency.
+    #[default]
+    Fifo,
+    /// Present the newest frame at the vertical blank, replacing the frames that were queued but not presented yet.
+    ///
+    /// OpenGL can't replace queued frames, so this behaves like [`PresentMode::Fifo`].
+    Mailbox,
+    /// Present frames as soon as they are rendered, without waiting for the display.
+    ///
+    /// This has the lowest latency and an uncapped frame rate, but frames might tear.
+    Immediate,
+}
+
+impl PresentMode {
+    /// Whether presenting a frame waits for the vertical blank of the display.
+    pub fn is_vsync(&self) -> bool {
+        !matches!(self, Self::Immediate)
+    }
+}
+
+/// How the deltas of mouse wheels and trackpads are turned into scroll movement, see [`LaunchConfigBuilder::with_scroll_settings`].
+///
+/// Only the deltas of the Wheel events are affected, their raw delta keeps the values reported by the device.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollSettings {
+    /// Logical pixels scrolled for every line reported by mouse wheels.
+    pub line_multiplier: f32,
+    /// Multiplier of the logical pixels reported by trackpads.
+    pub pixel_multiplier: f64,
+    /// Invert the scroll direction of mouse wheels.
+    pub invert_lines: bool,
+    /// Invert the scroll direction of trackpads, e.g. when the system is not already using natural scrolling.
+    pub invert_pixels: bool,
+}
+
+impl Default for ScrollSettings {
+    fn default() -> Self {
+        Self {
+            // https://github.com/emilk/egui/issues/461
+            // https://github.com/rust-windowing/winit/issues/22
+            // https://github.com/flutter/flutter/issues/71385
+            line_multiplier: 53.0,
+            pixel_multiplier: 1.0,
+            invert_lines: false,
+            invert_pixels: false,
+        }
+    }
+}
+
+/// Number of buffers of the Window surface, see [`LaunchConfigBuilder::with_buffering`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Buffering {
+    /// Render directly into the buffer shown in the Window.
+    ///
+    /// Uses less memory, but partially rendered frames might be visible. Not every platform supports it,
+    /// in which case the surface gets double buffered anyway.
+    Single,
+    /// Render into a back buffer, which is swapped with the one shown in the Window once the frame is done.
+    #[default]
+    Double,
+}
+
+/// Monitor connected to the computer, as given to [`MonitorSelector::Custom`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorInfo {
+    /// Human-readable name, if the platform provides one.
+    pub name: Option<String>,
+    /// Position of the top-left corner in the desktop, in physical pixels.
+    pub position: PhysicalPosition<i32>,
+    /// Resolution in physical pixels.
+    pub size: PhysicalSize<u32>,
+    pub scale_factor: f64,
+    pub is_primary: bool,
+}
+
+/// Picks the monitor of the Window from the ones listed by the platform, by their index.
+pub type MonitorPicker = Arc<dyn Fn(&[MonitorInfo]) -> Option<usize> + Send + Sync>;
+
+/// Monitor the Window is opened on, see [`LaunchConfigBuilder::with_monitor`].
+#[derive(Clone, Default)]
+pub enum MonitorSelector {
+    /// Let the platform decide, usually the monitor with the cursor or the focused window.
+    /// The primary monitor is used if a [`WindowPosition`] is given.
+    #[default]
+    Platform,
+    /// The primary monitor of the system.
+    Primary,
+    /// Monitor at the given index of the list of available monitors.
+    Index(usize),
+    /// First monitor whose name contains the given text.
+    Name(String),
+    /// Pick one of the available monitors, returning its index.
+    Custom(MonitorPicker),
+}
+
+/// Position of the Window in its monitor, see [`LaunchConfigBuilder::with_position`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum WindowPosition {
+    /// Let the platform decide, unless a monitor was selected, in which case it's centered on it.
+    #[default]
+    Platform,
+    /// Center of the monitor.
+    Center,
+    /// Offset of the top-left corner of the Window from the top-left corner of the monitor, in logical pixels.
+    Offset(f64, f64),
+}
+
 /// Configuration for a Window.
 pub struct WindowConfig<T: Clone> {
     /// Width of the Window.
     pub width: f64,
     /// Height of the window.
     pub height: f64,
-    /// Minimum width of the Window.
+    /// Minimum width of the Window, in logical pixels. The layout never gets narrower than it.
     pub min_width: Option<f64>,
-    /// Minimum height of the window.
+    /// Minimum height of the window, in logical pixels. The layout never gets shorter than it.
     pub min_height: Option<f64>,
     /// Maximum width of the Window.
     pub max_width: Option<f64>,
     /// Maximum height of the window.
     pub max_height: Option<f64>,
+    /// Monitor where the Window is opened.
+    pub monitor: MonitorSelector,
+    /// Position of the Window in its monitor.
+    pub position: WindowPosition,
     /// Enable Window decorations.
     pub decorations: bool,
     /// Title for the Window.
     pub title: &'static str,
     /// Make the Window transparent or not.
+    ///
+    /// The Window surface will be created with an alpha channel, so the desktop shows through
+    /// wherever the [`background`](Self::background) and the app don't paint anything opaque.
     pub transparent: bool,
+    /// Keep the Window above all the other windows.
+    pub always_on_top: bool,
+    /// Parts of the Window that receive pointer events, the rest let them pass through to what's below it.
+    pub hit_region: HitRegion,
+    /// Hide the cursor while typing, until it gets moved again.
+    pub hide_cursor_while_typing: bool,
+    /// Height of the custom title bar painted by the app, included in the top inset of the Window.
+    pub title_bar_height: f32,
+    /// Space to keep clear at the edges of the Window while it's not maximized or in fullscreen,
+    /// e.g. because of its rounded corners.
+    pub window_insets: WindowInsets,
     /// A custom value to consume from your app.
     pub state: Option<T>,
-    /// Background color of the Window.
+    /// Background color of the Window, used to clear the canvas before every frame.
     pub background: Color,
-    /// The Icon of the Window.
+    /// Fixed logical resolution at which the app is laid out, scaled to fit the Window.
+    pub fixed_resolution: Option<Size2D>,
+    /// Outline the layout bounds of every node, it can be toggled with `Ctrl + Shift + L`.
+    pub layout_overlay: bool,
+    /// How the event loop waits for new events.
+    pub control_flow: ControlFlowMode,
+    /// Minimum time between the frames, `None` to follow the refresh rate of the monitor.
+    pub min_frame_time: Option<Duration>,
+    /// How the deltas of mouse wheels and trackpads are turned into scroll movement.
+    pub scroll_settings: ScrollSettings,
+    /// Whether the motion of the app is reduced, `None` to follow the setting of the platform.
+    pub reduced_motion: Option<bool>,
+    /// When the rendered frames are presented in the Window.
+    pub present_mode: PresentMode,
+    /// Number of buffers of the Window surface.
+    pub buffering: Buffering,
+    /// The Icon of the Window, also used as the taskbar icon on Windows.
+    ///
+    /// Ignored on platforms that don't support Window icons, like macOS or Wayland.
     pub icon: Option<Icon>,
     /// Setup callback.
     pub on_setup: Option<WindowCallback>,
@@ -55,6 +229,8 @@ pub struct LaunchConfig<'a, T: Clone> {
     pub embedded_fonts: EmbeddedFonts<'a>,
     pub plugins: PluginsManager,
     pub default_fonts: Vec<String>,
+    pub render_settings: RenderSettings,
+    pub custom_elements: CustomElements,
 }
 
 impl<'a, T: Clone> Default for LaunchConfig<'a, T> {
@@ -64,6 +240,8 @@ impl<'a, T: Clone> Default for LaunchConfig<'a, T> {
             embedded_fonts: Default::default(),
             plugins: Default::default(),
             default_fonts: vec!["Fira Sans".to_string()],
+            render_settings: RenderSettings::default(),
+            custom_elements: CustomElements::default(),
         }
     }
 }
@@ -75,6 +253,7 @@ impl<'a, T: Clone> LaunchConfig<'a, T> {
 }
 
 impl LaunchConfig<'_, ()> {
+    /// Load an [`Icon`] from the bytes of an encoded image (e.g. PNG).
     pub fn load_icon(icon: &[u8]) -> Icon {
         let reader = Reader::new(Cursor::new(icon))
             .with_guessed_format()
@@ -85,6 +264,25 @@ impl LaunchConfig<'_, ()> {
             .into_rgba8();
         let (width, height) = image.dimensions();
         let rgba = image.into_raw();
+        Self::load_icon_from_rgba(rgba, width, height)
+    }
+
+    /// Load an [`Icon`] from an image file in the given path.
+    pub fn load_icon_from_path(path: impl AsRef<Path>) -> Icon {
+        let image = Reader::open(path)
+            .expect("Failed to open icon path")
+            .with_guessed_format()
+            .expect("Failed to guess the icon format")
+            .decode()
+            .expect("Failed to decode icon")
+            .into_rgba8();
+        let (width, height) = image.dimensions();
+        let rgba = image.into_raw();
+        Self::load_icon_from_rgba(rgba, width, height)
+    }
+
+    /// Create an [`Icon`] from raw RGBA bytes, `rgba` must be `width * height * 4` bytes long.
+    pub fn load_icon_from_rgba(rgba: Vec<u8>, width: u32, height: u32) -> Icon {
         Icon::from_rgba(rgba, width, height).expect("Failed to open icon")
     }
 }
@@ -99,11 +297,26 @@ pub struct LaunchConfigBuilder<'a, T> {
     pub(crate) min_height: Option<f64>,
     pub(crate) max_width: Option<f64>,
     pub(crate) max_height: Option<f64>,
+    pub(crate) monitor: MonitorSelector,
+    pub(crate) position: WindowPosition,
     pub(crate) decorations: bool,
     pub(crate) title: &'static str,
     pub(crate) transparent: bool,
+    pub(crate) always_on_top: bool,
+    pub(crate) hit_region: HitRegion,
+    pub(crate) hide_cursor_while_typing: bool,
+    pub(crate) title_bar_height: f32,
+    pub(crate) window_insets: WindowInsets,
     pub(crate) state: Option<T>,
-    pub(crate) background: Color,
+    pub(crate) background: Option<Color>,
+    pub(crate) fixed_resolution: Option<Size2D>,
+    pub(crate) layout_overlay: bool,
+    pub(crate) control_flow: ControlFlowMode,
+    pub(crate) min_frame_time: Option<Duration>,
+    pub(crate) scroll_settings: ScrollSettings,
+    pub(crate) reduced_motion: Option<bool>,
+    pub(crate) present_mode: PresentMode,
+    pub(crate) buffering: Buffering,
     pub(crate) fonts: Vec<(&'a str, &'a [u8])>,
     pub(crate) icon: Option<Icon>,
     pub(crate) on_setup: Option<WindowCallback>,
@@ -111,6 +324,8 @@ pub struct LaunchConfigBuilder<'a, T> {
     pub(crate) plugins: PluginsManager,
     pub(crate) window_builder_hook: Option<WindowBuilderHook>,
     pub(crate) default_fonts: Vec<String>,
+    pub(crate) render_settings: RenderSettings,
+    pub(crate) custom_elements: CustomElements,
 }
 
 impl<T> Default for LaunchConfigBuilder<'_, T> {
@@ -122,11 +337,26 @@ impl<T> Default for LaunchConfigBuilder<'_, T> {
             min_height: None,
             max_height: None,
             max_width: None,
+            monitor: MonitorSelector::default(),
+            position: WindowPosition::default(),
             decorations: true,
             title: "Freya app",
             transparent: false,
+            always_on_top: false,
+            hit_region: HitRegion::default(),
+            hide_cursor_while_typing: false,
+            title_bar_height: 0.0,
+            window_insets: WindowInsets::default(),
             state: None,
-            background: Color::WHITE,
+            background: None,
+            fixed_resolution: None,
+            layout_overlay: false,
+            control_flow: ControlFlowMode::default(),
+            min_frame_time: None,
+            scroll_settings: ScrollSettings::default(),
+            reduced_motion: None,
+            present_mode: PresentMode::default(),
+            buffering: Buffering::default(),
             fonts: Vec::default(),
             icon: None,
             on_setup: None,
@@ -134,6 +364,8 @@ impl<T> Default for LaunchConfigBuilder<'_, T> {
             plugins: PluginsManager::default(),
             window_builder_hook: None,
             default_fonts: vec!["Fira Sans".to_string()],
+            render_settings: RenderSettings::default(),
+            custom_elements: CustomElements::default(),
         }
     }
 }
@@ -151,30 +383,45 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
         self
     }
 
-    /// Specify a minimum Window width.
+    /// Specify a minimum Window width, it can be changed later with `UsePlatform::set_window_min_size`.
     pub fn with_min_width(mut self, min_width: f64) -> Self {
         self.min_width = Some(min_width);
         self
     }
 
-    /// Specify a minimum Window height.
+    /// Specify a minimum Window height, it can be changed later with `UsePlatform::set_window_min_size`.
     pub fn with_min_height(mut self, min_height: f64) -> Self {
         self.min_height = Some(min_height);
         self
     }
 
-    /// Specify a maximum Window width.
+    /// Specify a maximum Window width, it can be changed later with `UsePlatform::set_window_max_size`.
     pub fn with_max_width(mut self, max_width: f64) -> Self {
         self.max_width = Some(max_width);
         self
     }
 
-    /// Specify a maximum Window height.
+    /// Specify a maximum Window height, it can be changed later with `UsePlatform::set_window_max_size`.
     pub fn with_max_height(mut self, max_height: f64) -> Self {
         self.max_height = Some(max_height);
         self
     }
 
+    /// Choose the monitor where the Window is opened, see [`MonitorSelector`].
+    ///
+    /// Falls back to the primary monitor if the selected one isn't connected. If the monitor of the Window
+    /// gets disconnected while the app runs, the Window is moved to the center of the primary monitor.
+    pub fn with_monitor(mut self, monitor: MonitorSelector) -> Self {
+        self.monitor = monitor;
+        self
+    }
+
+    /// Choose where the Window is placed in its monitor, e.g. [`WindowPosition::Center`].
+    pub fn with_position(mut self, position: WindowPosition) -> Self {
+        self.position = position;
+        self
+    }
+
     /// Whether the Window will have decorations or not.
     pub fn with_decorations(mut self, decorations: bool) -> Self {
         self.decorations = decorations;
@@ -188,20 +435,142 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
     }
 
     /// Make the Window transparent or not.
+    ///
+    /// Unless a background is specified with [`with_background`](Self::with_background),
+    /// transparent Windows will have a fully transparent background.
     pub fn with_transparency(mut self, transparency: bool) -> Self {
         self.transparent = transparency;
         self
     }
 
+    /// Keep the Window above all the other windows.
+    ///
+    /// Combined with [`with_transparency`](Self::with_transparency) and
+    /// [`with_decorations(false)`](Self::with_decorations) this is useful for overlays and HUDs.
+    ///
+    /// On Linux this is only a hint to the window manager or compositor, which might ignore it.
+    pub fn with_always_on_top(mut self, always_on_top: bool) -> Self {
+        self.always_on_top = always_on_top;
+        self
+    }
+
+    /// Choose the parts of the Window that receive pointer events, so the clicks elsewhere pass through
+    /// to the windows below, e.g. [`HitRegion::Painted`] for a transparent overlay.
+    ///
+    /// It can be changed later with `UsePlatform::set_hit_region`. See [`HitRegion`] for its platform support.
+    pub fn with_hit_region(mut self, hit_region: HitRegion) -> Self {
+        self.hit_region = hit_region;
+        self
+    }
+
+    /// Hide the cursor when a key gets pressed while it's over the Window, and show it again once it moves.
+    ///
+    /// Useful for text-heavy apps, so the cursor doesn't cover what is being typed.
+    pub fn with_hide_cursor_while_typing(mut self, hide_cursor_while_typing: bool) -> Self {
+        self.hide_cursor_while_typing = hide_cursor_while_typing;
+        self
+    }
+
+    /// Specify the height of the custom title bar painted by the app, usually along with
+    /// [`with_decorations(false)`](Self::with_decorations).
+    ///
+    /// It's added to the top inset of the Window, so the content wrapped in a `SafeArea` starts below it.
+    pub fn with_title_bar_height(mut self, title_bar_height: f32) -> Self {
+        self.title_bar_height = title_bar_height;
+        self
+    }
+
+    /// Specify the space to keep clear at the edges of the Window, e.g. so the content doesn't clash
+    /// with its rounded corners.
+    ///
+    /// These insets are dropped while the Window is maximized or in fullscreen.
+    pub fn with_window_insets(mut self, window_insets: WindowInsets) -> Self {
+        self.window_insets = window_insets;
+        self
+    }
+
     /// Pass a custom value that your app will consume.
     pub fn with_state(mut self, state: T) -> Self {
         self.state = Some(state);
         self
     }
 
-    /// Specify the Window background color.
+    /// Specify the Window background color, it can be changed later with `UsePlatform::set_window_background`.
+    ///
+    /// Unless the Window is [transparent](Self::with_transparency), the alpha channel of the color is ignored.
     pub fn with_background(mut self, background: &str) -> Self {
-        self.background = Color::parse(background).unwrap_or(Color::WHITE);
+        self.background = Some(Color::parse(background).unwrap_or(Color::WHITE));
+        self
+    }
+
+    /// Lay out the app at a fixed resolution in logical pixels, no matter the size of the Window.
+    ///
+    /// The app is scaled uniformly to fit the Window and centered in it, and the pointer events are
+    /// mapped back into the fixed resolution. The space left at the sides (the letterbox bars)
+    /// is painted with the [background](Self::with_background) of the Window.
+    pub fn with_fixed_resolution(mut self, width: f32, height: f32) -> Self {
+        self.fixed_resolution = Some(Size2D::new(width, height));
+        self
+    }
+
+    /// Outline the layout bounds of every node over the app, colored by their depth in the tree.
+    ///
+    /// Useful to debug the layout of the whole app at once, it can also be toggled at any time with `Ctrl + Shift + L`.
+    pub fn with_layout_overlay(mut self, layout_overlay: bool) -> Self {
+        self.layout_overlay = layout_overlay;
+        self
+    }
+
+    /// Choose how the event loop waits for new events, [`ControlFlowMode::Wait`] by default.
+    ///
+    /// Waiting is the best choice for most apps, as they only use CPU when something changes.
+    /// Polling keeps ticking frames continuously for the lowest latency, e.g. for games, but uses CPU all the time.
+    /// Waiting until an interval sits in between, ticking a frame periodically even if nothing happened.
+    pub fn with_control_flow(mut self, control_flow: ControlFlowMode) -> Self {
+        self.control_flow = control_flow;
+        self
+    }
+
+    /// Set the minimum time between two frames, by default it follows the refresh rate of the monitor.
+    ///
+    /// The app is laid out and rendered at most once per frame. During a flurry of events (e.g. fast typing
+    /// or rapid pointer movements) they are processed as they arrive, except the pointer movements which are coalesced
+    /// until the next frame, and all their state updates get batched and rendered together in the next frame.
+    /// Use [`Duration::ZERO`] to render as soon as something changes.
+    pub fn with_min_frame_time(mut self, min_frame_time: Duration) -> Self {
+        self.min_frame_time = Some(min_frame_time);
+        self
+    }
+
+    /// Change the scroll speed and direction of mouse wheels and trackpads, see [`ScrollSettings`].
+    ///
+    /// They are applied to the deltas of the Wheel events, so the `ScrollView`s and every other element follow them.
+    pub fn with_scroll_settings(mut self, scroll_settings: ScrollSettings) -> Self {
+        self.scroll_settings = scroll_settings;
+        self
+    }
+
+    /// Reduce the motion of the app or not, instead of following the setting of the platform.
+    ///
+    /// When it's reduced, the transitions and animations skip straight to their end and the infinite ones aren't played.
+    /// It can be changed later with `UseAnimationClock::set_reduced_motion`.
+    pub fn with_reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = Some(reduced_motion);
+        self
+    }
+
+    /// Choose when the rendered frames are presented in the Window, [`PresentMode::Fifo`] by default.
+    ///
+    /// [`PresentMode::Fifo`] waits for the vsync of the display so frames never tear, which is what most apps want.
+    /// [`PresentMode::Immediate`] presents frames right away for the lowest latency, e.g. for games, but they might tear.
+    pub fn with_present_mode(mut self, present_mode: PresentMode) -> Self {
+        self.present_mode = present_mode;
+        self
+    }
+
+    /// Choose the number of buffers of the Window surface, [`Buffering::Double`] by default.
+    pub fn with_buffering(mut self, buffering: Buffering) -> Self {
+        self.buffering = buffering;
         self
     }
 
@@ -223,6 +592,22 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
         self
     }
 
+    /// Specify the global antialiasing, pixel snapping and text rendering settings, which elements can override
+    /// with the `antialias`, `pixel_snapping`, `text_hinting` and `text_subpixel` attributes.
+    /// Defaults to the usual settings of the current platform.
+    ///
+    /// Raise [`RenderSettings::text_contrast`] if light text on a dark theme looks too thin.
+    pub fn with_render_settings(mut self, render_settings: RenderSettings) -> Self {
+        self.render_settings = render_settings;
+        self
+    }
+
+    /// Register a [`CustomElement`], used by the `rect` elements whose `custom_element` attribute is `name`.
+    pub fn with_custom_element(mut self, name: &str, element: impl CustomElement) -> Self {
+        self.custom_elements.register(name, element);
+        self
+    }
+
     /// Specify the Window icon.
     pub fn with_icon(mut self, icon: Icon) -> Self {
         self.icon = Some(icon);
@@ -267,10 +652,30 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
                 max_width: self.max_width,
                 max_height: self.max_height,
                 title: self.title,
+                monitor: self.monitor,
+                position: self.position,
                 decorations: self.decorations,
                 transparent: self.transparent,
+                always_on_top: self.always_on_top,
+                hit_region: self.hit_region,
+                hide_cursor_while_typing: self.hide_cursor_while_typing,
+                title_bar_height: self.title_bar_height,
+                window_insets: self.window_insets,
                 state: self.state,
-                background: self.background,
+                background: match self.background {
+                    Some(background) if self.transparent => background,
+                    Some(background) => background.with_a(255),
+                    None if self.transparent => Color::TRANSPARENT,
+                    None => Color::WHITE,
+                },
+                fixed_resolution: self.fixed_resolution,
+                layout_overlay: self.layout_overlay,
+                control_flow: self.control_flow,
+                min_frame_time: self.min_frame_time,
+                scroll_settings: self.scroll_settings,
+                reduced_motion: self.reduced_motion,
+                present_mode: self.present_mode,
+                buffering: self.buffering,
                 icon: self.icon,
                 on_setup: self.on_setup,
                 on_exit: self.on_exit,
@@ -279,6 +684,8 @@ impl<'a, T: Clone> LaunchConfigBuilder<'a, T> {
             embedded_fonts: self.fonts,
             plugins: self.plugins,
             default_fonts: self.default_fonts,
+            render_settings: self.render_settings,
+            custom_elements: self.custom_elements,
         }
     }
 }
@@ -1,25 +1,24 @@
+use std::time::Instant;
+
 use accesskit::Action;
 use accesskit_winit::ActionRequestEvent;
 use freya_common::EventMessage;
 use freya_core::prelude::*;
-use freya_elements::events::keyboard::{
-    map_winit_key, map_winit_modifiers, map_winit_physical_key, Code, Key,
+use freya_elements::events::{
+    keyboard::{map_winit_key, map_winit_modifiers, map_winit_physical_key, Code, Key, Modifiers},
+    WheelDelta,
 };
 use torin::geometry::CursorPoint;
+use tracing::info;
 use winit::event::{
-    ElementState, Event, Ime, KeyEvent, MouseButton, MouseScrollDelta, StartCause, Touch,
-    TouchPhase, WindowEvent,
+    DeviceEvent, ElementState, Event, Ime, KeyEvent, MouseButton, MouseScrollDelta, StartCause,
+    Touch, TouchPhase, WindowEvent,
 };
-use winit::event_loop::{EventLoop, EventLoopProxy};
-use winit::keyboard::ModifiersState;
+use winit::event_loop::{ControlFlow, DeviceEvents, EventLoop, EventLoopProxy};
+use winit::keyboard::{KeyCode, ModifiersState, PhysicalKey};
 
 use crate::app::App;
-use crate::HoveredNode;
-
-// https://github.com/emilk/egui/issues/461
-// https://github.com/rust-windowing/winit/issues/22
-// https://github.com/flutter/flutter/issues/71385
-const WHEEL_SPEED_MODIFIER: f32 = 53.0;
+use crate::{ControlFlowMode, HoveredNode, ScrollSettings};
 
 pub fn run_event_loop<State: Clone>(
     mut app: App<State>,
@@ -29,21 +28,68 @@ pub fn run_event_loop<State: Clone>(
 ) {
     let mut cursor_pos = CursorPoint::default();
     let mut modifiers_state = ModifiersState::empty();
-    let mut dropped_file_path = None;
+    let mut is_cursor_hidden = false;
+    let mut dropped_file_paths = Vec::new();
 
     app.window_env.run_on_setup();
 
+    let control_flow = app.window_env.window_config.control_flow;
+    // Next periodic tick of `ControlFlowMode::WaitUntil`
+    let mut next_tick = match control_flow {
+        ControlFlowMode::WaitUntil(interval) => Some(Instant::now() + interval),
+        _ => None,
+    };
+
+    // By default, sleep until the next event, the app only wakes up to render when something changed or an animation is running
+    event_loop.set_control_flow(match control_flow {
+        ControlFlowMode::Wait => ControlFlow::Wait,
+        ControlFlowMode::Poll => ControlFlow::Poll,
+        ControlFlowMode::WaitUntil(_) => ControlFlow::WaitUntil(next_tick.unwrap()),
+    });
+
     event_loop
         .run(move |event, event_loop| match event {
             Event::NewEvents(StartCause::Init) => {
+                event_loop.listen_device_events(device_events(&app));
                 _ = proxy.send_event(EventMessage::PollVDOM);
             }
+            Event::NewEvents(StartCause::ResumeTimeReached { .. }) => {
+                let now = Instant::now();
+                if let ControlFlowMode::WaitUntil(interval) = control_flow {
+                    if let Some(tick) = next_tick.filter(|tick| *tick <= now) {
+                        app.request_tick();
+                        // Keep a steady pace, without catching up the ticks that were missed
+                        next_tick = Some((tick + interval).max(now));
+                    }
+                }
+
+                // Render the frame that came too early
+                if app
+                    .frame_pacer
+                    .deferred_frame()
+                    .is_some_and(|deferred_frame| deferred_frame <= now)
+                {
+                    app.request_redraw();
+                }
+            }
             Event::UserEvent(EventMessage::FocusAccessibilityNode(id)) => {
                 app.accessibility
                     .set_accessibility_focus(id, &app.window_env.window);
             }
+            Event::UserEvent(EventMessage::Announce { message, live }) => {
+                app.accessibility.announce(message, live);
+            }
             Event::UserEvent(EventMessage::RequestRerender) => {
-                app.window_env.window.request_redraw();
+                app.request_repaint();
+            }
+            Event::UserEvent(EventMessage::Flush) => {
+                app.flush(&hovered_node, true);
+            }
+            Event::UserEvent(EventMessage::SetUnthrottledPointerMoves(unthrottled)) => {
+                app.set_unthrottled_pointer_moves(unthrottled);
+            }
+            Event::UserEvent(EventMessage::RequestTick) => {
+                app.request_tick();
             }
             Event::UserEvent(EventMessage::RemeasureTextGroup(text_id)) => {
                 app.measure_text_group(&text_id);
@@ -60,6 +106,68 @@ pub fn run_event_loop<State: Clone>(
             Event::UserEvent(EventMessage::SetCursorIcon(icon)) => {
                 app.window_env.window.set_cursor_icon(icon)
             }
+            Event::UserEvent(EventMessage::SetWindowLevel(level)) => {
+                app.window_env.window.set_window_level(level)
+            }
+            Event::UserEvent(EventMessage::SetCursorPosition(position)) => {
+                app.set_cursor_position(position);
+            }
+            Event::UserEvent(EventMessage::SetWindowMinSize(min_size)) => {
+                app.set_window_min_size(min_size);
+            }
+            Event::UserEvent(EventMessage::SetWindowMaxSize(max_size)) => {
+                app.set_window_max_size(max_size);
+            }
+            Event::UserEvent(EventMessage::SetWindowBackground(background)) => {
+                app.set_window_background(background);
+            }
+            Event::UserEvent(EventMessage::StartFrameCapture {
+                directory,
+                frames_per_second,
+                supersampling,
+            }) => {
+                app.start_frame_capture(directory, frames_per_second, supersampling);
+            }
+            Event::UserEvent(EventMessage::StopFrameCapture) => {
+                app.stop_frame_capture();
+            }
+            Event::UserEvent(EventMessage::SetHitRegion(hit_region)) => {
+                app.set_hit_region(hit_region);
+                event_loop.listen_device_events(device_events(&app));
+            }
+            Event::DeviceEvent {
+                event: DeviceEvent::MouseMotion { .. },
+                ..
+            } => {
+                // The pointer might be back over the hit region, so receive its events again and check it on the next movement
+                if app.needs_raw_pointer_movements() {
+                    app.set_cursor_hittest(true);
+                }
+            }
+            Event::UserEvent(EventMessage::NodesAtPoint { point, callback }) => {
+                callback(app.nodes_at_point(point))
+            }
+            Event::UserEvent(EventMessage::DispatchNodeEvent { node_id, event }) => {
+                app.dispatch_node_event(node_id, event);
+            }
+            Event::UserEvent(EventMessage::SetCursorGrab(mode)) => {
+                app.window_env.set_cursor_grab(mode);
+            }
+            Event::UserEvent(EventMessage::SetCursorVisible(visible)) => {
+                app.window_env.window.set_cursor_visible(visible);
+            }
+            Event::UserEvent(EventMessage::WithWindow(use_window)) => {
+                (use_window)(app.window_env.window())
+            }
+            Event::UserEvent(EventMessage::SnapshotNode {
+                node_id,
+                supersampling,
+                callback,
+            }) => callback(app.snapshot_node(node_id, supersampling)),
+            Event::UserEvent(EventMessage::ExitApp) => event_loop.exit(),
+            Event::UserEvent(EventMessage::DumpLayout) => {
+                info!("{}", app.dump_layout());
+            }
             Event::UserEvent(EventMessage::FocusPrevAccessibilityNode) => {
                 app.set_navigation_mode(NavigationMode::Keyboard);
                 app.focus_next_node(AccessibilityFocusDirection::Backward);
@@ -83,23 +191,53 @@ pub fn run_event_loop<State: Clone>(
                 app.accessibility
                     .process_accessibility_event(&event, &app.window_env.window);
                 match event {
-                    WindowEvent::CloseRequested => event_loop.exit(),
+                    WindowEvent::CloseRequested => {
+                        // Let the app decide whether to close if it's listening for it
+                        if !app.close_request_notifier.notify() {
+                            event_loop.exit()
+                        }
+                    }
                     WindowEvent::Ime(Ime::Commit(text)) => {
                         app.send_event(PlatformEvent::Keyboard {
                             name: EventName::KeyDown,
                             key: Key::Character(text),
                             code: Code::Unidentified,
                             modifiers: map_winit_modifiers(modifiers_state),
+                            repeat: false,
                         });
                     }
                     WindowEvent::RedrawRequested => {
+                        // The state keeps being updated, but nothing is rendered until the Window is visible again
+                        if app.is_occluded() {
+                            return;
+                        }
+
+                        // At most one layout and render per frame, the rest of changes wait for the next one
+                        if !app.frame_pacer.try_start_frame(Instant::now()) {
+                            return;
+                        }
+
+                        app.flush_frame_events();
+                        app.process_animations();
+
                         if app.measure_layout_on_next_render {
                             app.process_layout();
 
                             app.measure_layout_on_next_render = false;
                         }
-                        app.render(&hovered_node);
+                        app.notify_layout_effects();
+                        if app.must_repaint() {
+                            app.render(&hovered_node);
+                            // What's painted under the pointer might have changed
+                            app.update_cursor_hittest();
+                        }
+                        app.pending_repaint = false;
+                        app.tick_requested = false;
                         app.event_loop_tick();
+
+                        if app.has_running_animations() {
+                            app.request_tick();
+                        }
                     }
                     WindowEvent::MouseInput { state, button, .. } => {
                         app.set_navigation_mode(NavigationMode::NotKeyboard);
@@ -122,25 +260,56 @@ pub fn run_event_loop<State: Clone>(
                     }
                     WindowEvent::MouseWheel { delta, phase, .. } => {
                         if TouchPhase::Moved == phase {
-                            let scroll_data = {
+                            let ScrollSettings {
+                                line_multiplier,
+                                pixel_multiplier,
+                                invert_lines,
+                                invert_pixels,
+                            } = app.window_env.window_config.scroll_settings;
+
+                            let (scroll_data, delta) = {
                                 match delta {
-                                    MouseScrollDelta::LineDelta(x, y) => (
-                                        (x * WHEEL_SPEED_MODIFIER) as f64,
-                                        (y * WHEEL_SPEED_MODIFIER) as f64,
-                                    ),
-                                    MouseScrollDelta::PixelDelta(pos) => (pos.x, pos.y),
+                                    MouseScrollDelta::LineDelta(x, y) => {
+                                        let multiplier = if invert_lines {
+                                            -line_multiplier
+                                        } else {
+                                            line_multiplier
+                                        };
+                                        (
+                                            ((x * multiplier) as f64, (y * multiplier) as f64),
+                                            WheelDelta::Lines { x, y },
+                                        )
+                                    }
+                                    MouseScrollDelta::PixelDelta(pos) => {
+                                        let pos = pos.to_logical::<f64>(
+                                            app.window_env.window.scale_factor(),
+                                        );
+                                        let multiplier = if invert_pixels {
+                                            -pixel_multiplier
+                                        } else {
+                                            pixel_multiplier
+                                        };
+                                        (
+                                            (pos.x * multiplier, pos.y * multiplier),
+                                            WheelDelta::Pixels { x: pos.x, y: pos.y },
+                                        )
+                                    }
                                 }
                             };
 
                             app.send_event(PlatformEvent::Wheel {
                                 name: EventName::Wheel,
                                 scroll: CursorPoint::from(scroll_data),
+                                delta,
+                                modifiers: map_winit_modifiers(modifiers_state),
                                 cursor: cursor_pos,
                             });
                         }
                     }
                     WindowEvent::ModifiersChanged(modifiers) => {
                         modifiers_state = modifiers.state();
+                        app.keyboard_state_notifier
+                            .set_modifiers(map_winit_modifiers(modifiers_state));
                     }
                     WindowEvent::KeyboardInput {
                         event:
@@ -148,23 +317,56 @@ pub fn run_event_loop<State: Clone>(
                                 physical_key,
                                 logical_key,
                                 state,
+                                repeat,
                                 ..
                             },
                         ..
                     } => {
+                        // Ctrl + Shift + L toggles the layout overlay
+                        if physical_key == PhysicalKey::Code(KeyCode::KeyL)
+                            && modifiers_state.control_key()
+                            && modifiers_state.shift_key()
+                        {
+                            if state == ElementState::Pressed && !repeat {
+                                app.toggle_layout_overlay();
+                            }
+                            return;
+                        }
+
+                        if state == ElementState::Pressed
+                            && !is_cursor_hidden
+                            && app.window_env.window_config.hide_cursor_while_typing
+                        {
+                            // Only hidden while it's over the Window
+                            app.window_env.window.set_cursor_visible(false);
+                            is_cursor_hidden = true;
+                        }
+
+                        let key = map_winit_key(&logical_key);
+                        let code = map_winit_physical_key(&physical_key);
+                        let modifiers = map_winit_modifiers(modifiers_state);
                         let name = match state {
-                            ElementState::Pressed => EventName::KeyDown,
-                            ElementState::Released => EventName::KeyUp,
+                            ElementState::Pressed => {
+                                app.keyboard_state_notifier
+                                    .press(code, key.clone(), modifiers);
+                                EventName::KeyDown
+                            }
+                            ElementState::Released => {
+                                app.keyboard_state_notifier.release(code, modifiers);
+                                EventName::KeyUp
+                            }
                         };
                         app.send_event(PlatformEvent::Keyboard {
                             name,
-                            key: map_winit_key(&logical_key),
-                            code: map_winit_physical_key(&physical_key),
-                            modifiers: map_winit_modifiers(modifiers_state),
+                            key,
+                            code,
+                            modifiers,
+                            repeat,
                         })
                     }
                     WindowEvent::CursorLeft { .. } => {
                         cursor_pos = CursorPoint::new(-1.0, -1.0);
+                        app.update_cursor_position(None);
 
                         app.send_event(PlatformEvent::Mouse {
                             name: EventName::MouseOver,
@@ -175,13 +377,27 @@ pub fn run_event_loop<State: Clone>(
                     WindowEvent::CursorMoved { position, .. } => {
                         cursor_pos = CursorPoint::from((position.x, position.y));
 
-                        app.send_event(PlatformEvent::Mouse {
+                        if is_cursor_hidden {
+                            app.window_env.window.set_cursor_visible(true);
+                            is_cursor_hidden = false;
+                        }
+                        app.update_cursor_position(Some(cursor_pos));
+
+                        let event = PlatformEvent::Mouse {
                             name: EventName::MouseOver,
                             cursor: cursor_pos,
                             button: None,
-                        });
+                        };
+
+                        // Movements are coalesced until the next frame, or until another event is processed
+                        if app.are_pointer_moves_throttled() {
+                            app.queue_event(event);
+                            app.request_tick();
+                        } else {
+                            app.send_event(event);
+                        }
 
-                        if let Some(dropped_file_path) = dropped_file_path.take() {
+                        for dropped_file_path in dropped_file_paths.drain(..) {
                             app.send_event(PlatformEvent::File {
                                 name: EventName::FileDrop,
                                 file_path: Some(dropped_file_path),
@@ -197,6 +413,7 @@ pub fn run_event_loop<State: Clone>(
                         ..
                     }) => {
                         cursor_pos = CursorPoint::from((location.x, location.y));
+                        app.update_cursor_position(Some(cursor_pos));
 
                         let name = match phase {
                             TouchPhase::Cancelled => EventName::TouchCancel,
@@ -216,12 +433,43 @@ pub fn run_event_loop<State: Clone>(
                     WindowEvent::Resized(size) => {
                         app.resize(size);
                     }
+                    WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                        app.set_scale_factor(scale_factor);
+                        app.window_env.keep_on_monitor();
+                    }
+                    WindowEvent::Moved(_) => {
+                        app.window_env.keep_on_monitor();
+                    }
+                    WindowEvent::Focused(true) => {
+                        // Platforms without raw mouse movements from other windows rely on this to receive the pointer events again
+                        app.set_cursor_hittest(true);
+                        app.window_env.keep_on_monitor();
+                        app.window_env.update_cursor_grab(true);
+                    }
+                    WindowEvent::Focused(false) => {
+                        // Never keep the cursor trapped in an unfocused Window
+                        app.window_env.update_cursor_grab(false);
+                        // The keys released while the Window is unfocused are never reported, so release the held ones now
+                        modifiers_state = ModifiersState::empty();
+                        for (code, key) in app.keyboard_state_notifier.release_all() {
+                            app.send_event(PlatformEvent::Keyboard {
+                                name: EventName::KeyUp,
+                                key,
+                                code,
+                                modifiers: Modifiers::empty(),
+                                repeat: false,
+                            });
+                        }
+                    }
+                    WindowEvent::Occluded(occluded) => {
+                        app.set_occluded(occluded);
+                    }
                     WindowEvent::DroppedFile(file_path) => {
-                        dropped_file_path = Some(file_path);
+                        dropped_file_paths.push(file_path);
                     }
                     WindowEvent::HoveredFile(file_path) => {
                         app.send_event(PlatformEvent::File {
-                            name: EventName::GlobalFileHover,
+                            name: EventName::FileHover,
                             file_path: Some(file_path),
                             cursor: cursor_pos,
                         });
@@ -236,10 +484,48 @@ pub fn run_event_loop<State: Clone>(
                     _ => {}
                 }
             }
+            Event::AboutToWait => {
+                // Wake up for the deferred frame too
+                let deferred_frame = app.frame_pacer.deferred_frame();
+
+                match control_flow {
+                    // Don't spin while nothing can be rendered
+                    ControlFlowMode::Poll if app.is_occluded() => {
+                        event_loop.set_control_flow(ControlFlow::Wait);
+                    }
+                    ControlFlowMode::Poll => {
+                        if let Some(deferred_frame) = deferred_frame {
+                            event_loop.set_control_flow(ControlFlow::WaitUntil(deferred_frame));
+                        } else {
+                            event_loop.set_control_flow(ControlFlow::Poll);
+                            app.request_tick();
+                        }
+                    }
+                    ControlFlowMode::Wait => {
+                        event_loop.set_control_flow(
+                            deferred_frame.map_or(ControlFlow::Wait, ControlFlow::WaitUntil),
+                        );
+                    }
+                    ControlFlowMode::WaitUntil(_) => {
+                        let resume = next_tick.into_iter().chain(deferred_frame).min().unwrap();
+                        event_loop.set_control_flow(ControlFlow::WaitUntil(resume));
+                    }
+                }
+            }
             Event::LoopExiting => {
+                app.stop_frame_capture();
                 app.window_env.run_on_exit();
             }
             _ => (),
         })
         .expect("Failed to run Eventloop.");
 }
+
+/// The raw movements of the mouse are needed over other windows while the pointer events pass through.
+fn device_events<State: Clone>(app: &App<State>) -> DeviceEvents {
+    if app.needs_raw_pointer_movements() {
+        DeviceEvents::Always
+    } else {
+        DeviceEvents::WhenFocused
+    }
+}
@@ -0,0 +1,114 @@
+use std::path::PathBuf;
+use std::sync::mpsc::{channel, Sender};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use freya_engine::prelude::*;
+use tracing::warn;
+
+/// Frame to be written, along with the indices of the consecutive files it must be written to.
+struct CapturedFrame {
+    image: Image,
+    indices: std::ops::Range<u64>,
+}
+
+/// Writes the frames presented in the Window as a sequence of numbered PNG images
+/// (`frame_00000.png`, `frame_00001.png`, ...) at a fixed rate.
+///
+/// The sequence always has one image for every interval since the capture started. When no frame was presented
+/// during some intervals, because nothing changed or rendering was too slow, the next frame fills them all,
+/// so animations keep their real timing when the images are played back at the same rate.
+///
+/// With a `supersampling` factor above 1 the app is rendered again offscreen for every captured frame,
+/// instead of reading what was presented in the Window.
+pub struct FrameCapture {
+    started_at: Instant,
+    interval: Duration,
+    supersampling: u32,
+    next_index: u64,
+    last_image: Option<Image>,
+    sender: Sender<CapturedFrame>,
+    writer: JoinHandle<()>,
+}
+
+impl FrameCapture {
+    /// Start capturing frames at the given rate into `directory`, which is created if needed.
+    pub fn start(
+        directory: PathBuf,
+        frames_per_second: u32,
+        supersampling: u32,
+    ) -> std::io::Result<Self> {
+        std::fs::create_dir_all(&directory)?;
+
+        // Encoding is slow, so it doesn't block the rendering
+        let (sender, receiver) = channel::<CapturedFrame>();
+        let writer = std::thread::spawn(move || {
+            for frame in receiver {
+                let Some(data) = frame.image.encode(None, EncodedImageFormat::PNG, None) else {
+                    warn!("Failed to encode a captured frame.");
+                    continue;
+                };
+                for index in frame.indices {
+                    let path = directory.join(format!("frame_{index:05}.png"));
+                    if let Err(err) = std::fs::write(&path, data.as_bytes()) {
+                        warn!("Failed to write the captured frame {path:?}: {err}");
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            started_at: Instant::now(),
+            interval: Duration::from_secs(1) / frames_per_second.max(1),
+            supersampling,
+            next_index: 0,
+            last_image: None,
+            sender,
+            writer,
+        })
+    }
+
+    /// Get the indices of the images due until now that haven't been written yet.
+    fn due_indices(&self, now: Instant) -> std::ops::Range<u64> {
+        let elapsed = now.duration_since(self.started_at);
+        let last_index = (elapsed.as_nanos() / self.interval.as_nanos()) as u64;
+        self.next_index..last_index + 1
+    }
+
+    /// Supersampling factor the frames are captured with.
+    pub fn supersampling(&self) -> u32 {
+        self.supersampling
+    }
+
+    /// Capture the frame that was just rendered, `read_frame` must return it as a raster image
+    /// so it can be encoded in another thread. It's only called when a frame is due.
+    pub fn capture(&mut self, read_frame: impl FnOnce() -> Option<Image>) {
+        let indices = self.due_indices(Instant::now());
+        if indices.is_empty() {
+            return;
+        }
+
+        let Some(image) = read_frame() else {
+            warn!("Failed to read a captured frame.");
+            return;
+        };
+
+        self.next_index = indices.end;
+        self.last_image = Some(image.clone());
+        self.sender.send(CapturedFrame { image, indices }).ok();
+    }
+
+    /// Stop capturing, waiting for the pending images to be written.
+    pub fn stop(self) {
+        // Fill the time since the last presented frame
+        let indices = self.due_indices(Instant::now());
+        if let Some(image) = self.last_image {
+            if !indices.is_empty() {
+                self.sender.send(CapturedFrame { image, indices }).ok();
+            }
+        }
+
+        drop(self.sender);
+        self.writer.join().ok();
+    }
+}
@@ -0,0 +1,129 @@
+use std::time::{Duration, Instant};
+
+use winit::window::Window;
+
+/// Refresh rate assumed when the monitor of the Window doesn't report one.
+const FALLBACK_REFRESH_RATE_MILLIHERTZ: u32 = 60_000;
+
+/// Limits how often the app is laid out and rendered, so a flurry of events or state updates
+/// (e.g. fast typing or rapid pointer movements) can't make it render more than once per frame.
+///
+/// A redraw that comes too early is deferred until the frame time has passed. Meanwhile, the events keep being
+/// processed against the last layout and their state updates are batched, so they are all rendered at once.
+pub struct FramePacer {
+    /// Configured minimum time between frames, `None` to follow the refresh rate of the monitor.
+    configured_frame_time: Option<Duration>,
+    min_frame_time: Duration,
+    last_frame: Option<Instant>,
+    deferred_frame: Option<Instant>,
+}
+
+impl FramePacer {
+    pub fn new(configured_frame_time: Option<Duration>, window: &Window) -> Self {
+        let mut frame_pacer = Self {
+            configured_frame_time,
+            min_frame_time: Duration::ZERO,
+            last_frame: None,
+            deferred_frame: None,
+        };
+        frame_pacer.update_monitor(window);
+        frame_pacer
+    }
+
+    /// Follow the refresh rate of the monitor the Window is in at the moment.
+    pub fn update_monitor(&mut self, window: &Window) {
+        self.min_frame_time = self.configured_frame_time.unwrap_or_else(|| {
+            let refresh_rate = window
+                .current_monitor()
+                .and_then(|monitor| monitor.refresh_rate_millihertz())
+                .unwrap_or(FALLBACK_REFRESH_RATE_MILLIHERTZ)
+                .max(1);
+            // Leave some room for the jitter of the vsync, otherwise frames that are
+            // just a bit early would be deferred and miss the next vertical blank
+            Duration::from_secs(1000) / refresh_rate * 3 / 4
+        });
+    }
+
+    /// Check if a frame can be rendered now. Otherwise it gets deferred, see [`deferred_frame`](Self::deferred_frame).
+    pub fn try_start_frame(&mut self, now: Instant) -> bool {
+        let next_frame = self
+            .last_frame
+            .map(|last_frame| last_frame + self.min_frame_time);
+
+        match next_frame {
+            Some(next_frame) if next_frame > now => {
+                self.deferred_frame = Some(next_frame);
+                false
+            }
+            _ => {
+                self.start_frame(now);
+                true
+            }
+        }
+    }
+
+    /// Render a frame now no matter when the last one was, the next ones are paced from it.
+    pub fn start_frame(&mut self, now: Instant) {
+        self.last_frame = Some(now);
+        self.deferred_frame = None;
+    }
+
+    /// When the frame that was deferred can be rendered, if any.
+    pub fn deferred_frame(&self) -> Option<Instant> {
+        self.deferred_frame
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::FramePacer;
+
+    fn frame_pacer(min_frame_time: Duration) -> FramePacer {
+        FramePacer {
+            configured_frame_time: Some(min_frame_time),
+            min_frame_time,
+            last_frame: None,
+            deferred_frame: None,
+        }
+    }
+
+    #[test]
+    fn early_frames_are_deferred() {
+        let mut frame_pacer = frame_pacer(Duration::from_millis(10));
+        let start = Instant::now();
+
+        assert!(frame_pacer.try_start_frame(start));
+        assert_eq!(frame_pacer.deferred_frame(), None);
+
+        // Too early, it's deferred until the frame time has passed
+        assert!(!frame_pacer.try_start_frame(start + Duration::from_millis(4)));
+        assert_eq!(
+            frame_pacer.deferred_frame(),
+            Some(start + Duration::from_millis(10))
+        );
+
+        assert!(frame_pacer.try_start_frame(start + Duration::from_millis(10)));
+        assert_eq!(frame_pacer.deferred_frame(), None);
+    }
+
+    #[test]
+    fn forced_frames_pace_the_next_ones() {
+        let mut frame_pacer = frame_pacer(Duration::from_millis(10));
+        let start = Instant::now();
+
+        assert!(frame_pacer.try_start_frame(start));
+        assert!(!frame_pacer.try_start_frame(start + Duration::from_millis(4)));
+
+        // Rendering anyway clears the deferred frame
+        frame_pacer.start_frame(start + Duration::from_millis(5));
+        assert_eq!(frame_pacer.deferred_frame(), None);
+
+        assert!(!frame_pacer.try_start_frame(start + Duration::from_millis(10)));
+        assert_eq!(
+            frame_pacer.deferred_frame(),
+            Some(start + Duration::from_millis(15))
+        );
+    }
+}
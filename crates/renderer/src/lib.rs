@@ -9,17 +9,19 @@ use tokio::sync::Notify;
 use winit::event_loop::EventLoopBuilder;
 
 pub use config::WindowConfig;
+pub use freya_common::HitRegion;
+pub use freya_core::render::{render_layers, RenderPass, RenderTarget, SubtreeCache};
 pub use window::WindowEnv;
 
 mod accessibility;
 mod app;
 mod config;
-mod elements;
 mod event_loop;
-mod renderer;
+mod frame_capture;
+mod frame_pacer;
+mod reduced_motion;
 mod window;
 mod winit_waker;
-mod wireframe;
 
 pub type HoveredNode = Option<Arc<Mutex<Option<NodeId>>>>;
 
@@ -60,7 +62,7 @@ impl DesktopRenderer {
             });
         }
 
-        let window_env = WindowEnv::new(config.window, &event_loop);
+        let window_env = WindowEnv::new(config.window, &config.render_settings, &event_loop);
 
         let mut app = App::new(
             sdom,
@@ -0,0 +1,40 @@
+/// Check if the user asked the platform to reduce the motion of the interfaces.
+///
+/// Only GNOME on Linux and macOS are supported, `false` is assumed everywhere else or if the setting can't be read.
+pub fn platform_prefers_reduced_motion() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        read_setting(
+            "gsettings",
+            &["get", "org.gnome.desktop.interface", "enable-animations"],
+        )
+        .is_some_and(|enabled| enabled == "false")
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        read_setting(
+            "defaults",
+            &["read", "com.apple.universalaccess", "reduceMotion"],
+        )
+        .is_some_and(|reduced| reduced == "1")
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        false
+    }
+}
+
+/// Run the given command and get its trimmed output, if it succeeded.
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn read_setting(program: &str, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
@@ -1,5 +1,8 @@
 use freya_common::EventMessage;
+use freya_core::prelude::RenderTarget;
 use freya_engine::prelude::*;
+use freya_hooks::WindowInsets;
+use freya_node_state::RenderSettings;
 use gl::{types::*, *};
 use glutin::context::GlProfile;
 use glutin::context::NotCurrentGlContext;
@@ -9,20 +12,26 @@ use glutin::{
     context::{ContextApi, ContextAttributesBuilder, PossiblyCurrentContext},
     display::{GetGlDisplay, GlDisplay},
     prelude::GlSurface,
-    surface::{Surface as GlutinSurface, SurfaceAttributesBuilder, WindowSurface},
+    surface::{Surface as GlutinSurface, SurfaceAttributesBuilder, SwapInterval, WindowSurface},
 };
 use glutin_winit::DisplayBuilder;
-use raw_window_handle::HasRawWindowHandle;
+use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
 use std::ffi::CString;
 use std::num::NonZeroU32;
+use torin::geometry::Area;
+use tracing::warn;
 
-use winit::dpi::{LogicalSize, PhysicalSize};
+use winit::dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize};
+use winit::monitor::MonitorHandle;
 use winit::{
+    error::ExternalError,
     event_loop::EventLoop,
-    window::{Window, WindowBuilder},
+    window::{CursorGrabMode, Window, WindowBuilder, WindowLevel},
 };
 
-use crate::config::WindowConfig;
+use crate::config::{
+    Buffering, MonitorInfo, MonitorSelector, PresentMode, WindowConfig, WindowPosition,
+};
 
 /// Manager for a Window
 pub struct WindowEnv<State: Clone> {
@@ -34,7 +43,10 @@ pub struct WindowEnv<State: Clone> {
     pub(crate) fb_info: FramebufferInfo,
     pub(crate) num_samples: usize,
     pub(crate) stencil_size: usize,
+    pub(crate) surface_props: SurfaceProps,
     pub(crate) window_config: WindowConfig<State>,
+    /// Grab mode requested by the app, only applied while the Window is focused
+    pub(crate) cursor_grab: CursorGrabMode,
 }
 
 impl<T: Clone> Drop for WindowEnv<T> {
@@ -46,38 +58,69 @@ impl<T: Clone> Drop for WindowEnv<T> {
     }
 }
 
+impl<T: Clone> RenderTarget for WindowEnv<T> {
+    fn canvas(&mut self) -> &Canvas {
+        self.surface.canvas()
+    }
+}
+
 impl<T: Clone> WindowEnv<T> {
     /// Setup the Window and related features
-    pub fn new(mut window_config: WindowConfig<T>, event_loop: &EventLoop<EventMessage>) -> Self {
+    pub fn new(
+        window_config: WindowConfig<T>,
+        render_settings: &RenderSettings,
+        event_loop: &EventLoop<EventMessage>,
+    ) -> Self {
         let mut window_builder = WindowBuilder::new()
             .with_visible(false)
             .with_title(window_config.title)
             .with_decorations(window_config.decorations)
             .with_transparent(window_config.transparent)
-            .with_window_icon(window_config.icon.take())
+            .with_window_level(if window_config.always_on_top {
+                WindowLevel::AlwaysOnTop
+            } else {
+                WindowLevel::Normal
+            })
+            .with_window_icon(window_config.icon.clone())
             .with_inner_size(LogicalSize::<f64>::new(
                 window_config.width,
                 window_config.height,
             ));
 
+        #[cfg(target_os = "windows")]
+        {
+            use winit::platform::windows::WindowBuilderExtWindows;
+            window_builder = window_builder.with_taskbar_icon(window_config.icon.clone());
+        }
+
         set_resource_cache_total_bytes_limit(1000000); // 1MB
         set_resource_cache_single_allocation_byte_limit(Some(500000)); // 0.5MB
 
-        if let Some(min_size) = window_config.min_width.zip(window_config.min_height) {
-            window_builder = window_builder.with_min_inner_size(LogicalSize::<f64>::from(min_size))
+        if window_config.min_width.is_some() || window_config.min_height.is_some() {
+            window_builder = window_builder.with_min_inner_size(LogicalSize::<f64>::new(
+                window_config.min_width.unwrap_or_default(),
+                window_config.min_height.unwrap_or_default(),
+            ))
         }
 
         if let Some(max_size) = window_config.max_width.zip(window_config.max_height) {
             window_builder = window_builder.with_max_inner_size(LogicalSize::<f64>::from(max_size))
         }
 
+        if let Some(position) = initial_position(&window_config, event_loop) {
+            window_builder = window_builder.with_position(position);
+        }
+
         if let Some(with_window_builder) = &window_config.window_builder_hook {
             window_builder = (with_window_builder)(window_builder);
         }
 
+        let single_buffering = window_config.buffering == Buffering::Single;
+
         let template = ConfigTemplateBuilder::new()
             .with_alpha_size(8)
-            .with_transparency(window_config.transparent);
+            .with_transparency(window_config.transparent)
+            .with_single_buffering(single_buffering);
 
         let display_builder = DisplayBuilder::new().with_window_builder(Some(window_builder));
         let (window, gl_config) = display_builder
@@ -87,7 +130,14 @@ impl<T: Clone> WindowEnv<T> {
                         let transparency_check = config.supports_transparency().unwrap_or(false)
                             & !accum.supports_transparency().unwrap_or(false);
 
-                        if transparency_check || config.num_samples() < accum.num_samples() {
+                        // Pick the amount of samples closest to the requested one, none by default
+                        let samples_distance = |config: &glutin::config::Config| {
+                            config.num_samples().abs_diff(render_settings.multisampling)
+                        };
+
+                        if transparency_check
+                            || samples_distance(&config) < samples_distance(&accum)
+                        {
                             config
                         } else {
                             accum
@@ -99,6 +149,7 @@ impl<T: Clone> WindowEnv<T> {
 
         let mut window = window.expect("Could not create window with OpenGL context");
         window.set_ime_allowed(true);
+
         let raw_window_handle = window.raw_window_handle();
 
         let context_attributes = ContextAttributesBuilder::new()
@@ -124,11 +175,13 @@ impl<T: Clone> WindowEnv<T> {
 
         let (width, height): (u32, u32) = window.inner_size().into();
 
-        let attrs = SurfaceAttributesBuilder::<WindowSurface>::new().build(
-            raw_window_handle,
-            NonZeroU32::new(width).unwrap(),
-            NonZeroU32::new(height).unwrap(),
-        );
+        let attrs = SurfaceAttributesBuilder::<WindowSurface>::new()
+            .with_single_buffer(single_buffering)
+            .build(
+                raw_window_handle,
+                NonZeroU32::new(width).unwrap(),
+                NonZeroU32::new(height).unwrap(),
+            );
 
         let gl_surface = unsafe {
             gl_config
@@ -141,6 +194,18 @@ impl<T: Clone> WindowEnv<T> {
             .make_current(&gl_surface)
             .expect("Could not make GL context current when setting up skia renderer");
 
+        if window_config.present_mode == PresentMode::Mailbox {
+            warn!("The Mailbox present mode is not supported by OpenGL, falling back to Fifo.");
+        }
+        let swap_interval = if window_config.present_mode.is_vsync() {
+            SwapInterval::Wait(NonZeroU32::new(1).unwrap())
+        } else {
+            SwapInterval::DontWait
+        };
+        if let Err(err) = gl_surface.set_swap_interval(&gl_context, swap_interval) {
+            warn!("Could not set the swap interval of the Window surface: {err}");
+        }
+
         load_with(|s| {
             gl_config
                 .display()
@@ -173,12 +238,21 @@ impl<T: Clone> WindowEnv<T> {
         let num_samples = gl_config.num_samples() as usize;
         let stencil_size = gl_config.stencil_size() as usize;
 
+        // How the antialiased edges of the glyphs are blended, for everything rendered in the Window
+        let surface_props = SurfaceProps::new_with_text_properties(
+            SurfacePropsFlags::empty(),
+            PixelGeometry::Unknown,
+            render_settings.text_contrast,
+            render_settings.text_gamma,
+        );
+
         let mut surface = create_surface(
             &mut window,
             fb_info,
             &mut gr_context,
             num_samples,
             stencil_size,
+            &surface_props,
         );
 
         let sf = window.scale_factor() as f32;
@@ -192,16 +266,40 @@ impl<T: Clone> WindowEnv<T> {
             fb_info,
             num_samples,
             stencil_size,
+            surface_props,
             window,
             window_config,
+            cursor_grab: CursorGrabMode::None,
         }
     }
 
+    /// Get a reference to the underlying winit [`Window`].
+    ///
+    /// This is meant for integrating with platform APIs (e.g. taskbar progress, native menus).
+    /// Freya owns the rendering of this Window, so it should **not** be used to drive rendering
+    /// directly, nor to change its size or scale behind Freya's back.
+    pub fn window(&self) -> &Window {
+        &self.window
+    }
+
+    /// Get the [`RawWindowHandle`] of the underlying Window.
+    ///
+    /// The handle is only valid as long as this [`WindowEnv`] is alive, anything
+    /// built on top of it must not outlive the Window.
+    pub fn raw_window_handle(&self) -> RawWindowHandle {
+        self.window.raw_window_handle()
+    }
+
     /// Get a reference to the Canvas.
     pub fn canvas(&mut self) -> &Canvas {
         self.surface.canvas()
     }
 
+    /// Get the amount of frames since the back buffer was last presented, `0` if its contents are unknown.
+    pub fn buffer_age(&self) -> usize {
+        self.gl_surface.buffer_age() as usize
+    }
+
     /// Clear the canvas.
     pub fn clear(&mut self) {
         let canvas = self.surface.canvas();
@@ -209,13 +307,82 @@ impl<T: Clone> WindowEnv<T> {
     }
 
     /// Flush and submit the canvas.
-    pub fn finish_render(&mut self) {
+    ///
+    /// Only the `damage` region of the Window (in physical pixels) is presented if it's known,
+    /// as long as the platform supports it, so the compositor doesn't have to copy the rest.
+    pub fn finish_render(&mut self, damage: Option<Area>) {
         self.window.pre_present_notify();
         self.gr_context.flush_and_submit();
+
+        // Only EGL can swap the buffers with damage
+        #[cfg(all(
+            any(windows, unix),
+            not(any(target_os = "macos", target_os = "ios")),
+            not(target_family = "wasm")
+        ))]
+        if let (Some(damage), GlutinSurface::Egl(surface), PossiblyCurrentContext::Egl(context)) =
+            (damage, &self.gl_surface, &self.gl_context)
+        {
+            // The damage rects start from the bottom left corner of the Window
+            let height = self.window.inner_size().height as i32;
+            let (min_x, min_y) = (damage.min_x().floor() as i32, damage.min_y().floor() as i32);
+            let (max_x, max_y) = (damage.max_x().ceil() as i32, damage.max_y().ceil() as i32);
+            let rect =
+                glutin::surface::Rect::new(min_x, height - max_y, max_x - min_x, max_y - min_y);
+            surface.swap_buffers_with_damage(context, &[rect]).unwrap();
+            return;
+        }
+
         self.gl_surface.swap_buffers(&self.gl_context).unwrap();
     }
 
     /// Resize the Window
+    /// Change the minimum size of the Window, in logical pixels.
+    pub fn set_min_size(&mut self, min_size: Option<(f64, f64)>) {
+        self.window_config.min_width = min_size.map(|(width, _)| width);
+        self.window_config.min_height = min_size.map(|(_, height)| height);
+        self.window
+            .set_min_inner_size(min_size.map(LogicalSize::<f64>::from));
+    }
+
+    /// Change the maximum size of the Window, in logical pixels.
+    pub fn set_max_size(&mut self, max_size: Option<(f64, f64)>) {
+        self.window_config.max_width = max_size.map(|(width, _)| width);
+        self.window_config.max_height = max_size.map(|(_, height)| height);
+        self.window
+            .set_max_inner_size(max_size.map(LogicalSize::<f64>::from));
+    }
+
+    /// Change the background color of the Window.
+    /// Opaque Windows can't show through their background, so its alpha is ignored.
+    pub fn set_background(&mut self, background: Color) {
+        self.window_config.background = if self.window_config.transparent {
+            background
+        } else {
+            background.with_a(255)
+        };
+    }
+
+    /// Get the minimum size of the Window, in physical pixels.
+    pub fn min_size(&self) -> PhysicalSize<f64> {
+        LogicalSize::new(
+            self.window_config.min_width.unwrap_or_default(),
+            self.window_config.min_height.unwrap_or_default(),
+        )
+        .to_physical(self.window.scale_factor())
+    }
+
+    /// Get the insets of the Window, in logical pixels.
+    pub fn window_insets(&self) -> WindowInsets {
+        let mut insets = if self.window.is_maximized() || self.window.fullscreen().is_some() {
+            WindowInsets::default()
+        } else {
+            self.window_config.window_insets
+        };
+        insets.top += self.window_config.title_bar_height;
+        insets
+    }
+
     pub fn resize(&mut self, size: PhysicalSize<u32>) {
         self.surface = create_surface(
             &mut self.window,
@@ -223,6 +390,7 @@ impl<T: Clone> WindowEnv<T> {
             &mut self.gr_context,
             self.num_samples,
             self.stencil_size,
+            &self.surface_props,
         );
 
         let (width, height): (u32, u32) = size.into();
@@ -236,6 +404,81 @@ impl<T: Clone> WindowEnv<T> {
         self.window.request_redraw();
     }
 
+    /// Move the Window to the center of the primary monitor if it's not on any connected monitor anymore,
+    /// e.g. because its monitor got disconnected.
+    pub fn keep_on_monitor(&self) {
+        // Not every platform lets apps know or change the position of their windows
+        let Ok(position) = self.window.outer_position() else {
+            return;
+        };
+        let size = self.window.outer_size();
+        let is_visible = self.window.available_monitors().any(|monitor| {
+            let origin = monitor.position();
+            let monitor_size = monitor.size();
+            position.x < origin.x + monitor_size.width as i32
+                && position.x + size.width as i32 > origin.x
+                && position.y < origin.y + monitor_size.height as i32
+                && position.y + size.height as i32 > origin.y
+        });
+        if is_visible {
+            return;
+        }
+
+        let monitor = self
+            .window
+            .primary_monitor()
+            .or_else(|| self.window.available_monitors().next());
+        if let Some(monitor) = monitor {
+            let size = size.to_logical(self.window.scale_factor());
+            self.window.set_outer_position(position_in_monitor(
+                &monitor,
+                size,
+                WindowPosition::Center,
+            ));
+        }
+    }
+
+    /// Change how the cursor is grabbed, it's applied right away if the Window is focused.
+    pub fn set_cursor_grab(&mut self, mode: CursorGrabMode) {
+        self.cursor_grab = mode;
+        if self.window.has_focus() {
+            self.apply_cursor_grab(mode);
+        }
+    }
+
+    /// Release the grabbed cursor when the Window loses the focus, and grab it again when it gets it back.
+    pub fn update_cursor_grab(&self, focused: bool) {
+        if self.cursor_grab == CursorGrabMode::None {
+            return;
+        }
+        if focused {
+            self.apply_cursor_grab(self.cursor_grab);
+        } else {
+            self.apply_cursor_grab(CursorGrabMode::None);
+        }
+    }
+
+    fn apply_cursor_grab(&self, mode: CursorGrabMode) {
+        // Platforms only support one of the modes, so fall back to the other one
+        let fallback = match mode {
+            CursorGrabMode::Confined => Some(CursorGrabMode::Locked),
+            CursorGrabMode::Locked => Some(CursorGrabMode::Confined),
+            CursorGrabMode::None => None,
+        };
+        let result = self
+            .window
+            .set_cursor_grab(mode)
+            .or_else(|err| match (err, fallback) {
+                (ExternalError::NotSupported(_), Some(fallback)) => {
+                    self.window.set_cursor_grab(fallback)
+                }
+                (err, _) => Err(err),
+            });
+        if let Err(err) = result {
+            warn!("Could not change the cursor grab of the Window: {err}");
+        }
+    }
+
     /// Run the `on_setup` callback that was passed to the launch function
     pub fn run_on_setup(&mut self) {
         let on_setup = self.window_config.on_setup.clone();
@@ -253,6 +496,91 @@ impl<T: Clone> WindowEnv<T> {
     }
 }
 
+/// Get where the Window must be opened, according to its monitor and position settings.
+fn initial_position<T: Clone>(
+    window_config: &WindowConfig<T>,
+    event_loop: &EventLoop<EventMessage>,
+) -> Option<PhysicalPosition<i32>> {
+    if matches!(window_config.monitor, MonitorSelector::Platform)
+        && window_config.position == WindowPosition::Platform
+    {
+        return None;
+    }
+
+    let monitors = event_loop.available_monitors().collect::<Vec<_>>();
+    let primary = event_loop
+        .primary_monitor()
+        .or_else(|| monitors.first().cloned());
+    let monitor = match select_monitor(&window_config.monitor, &monitors, primary.as_ref()) {
+        Some(monitor) => monitor,
+        None => {
+            warn!(
+                "The selected monitor is not connected, opening the Window in the primary monitor."
+            );
+            primary?
+        }
+    };
+
+    let size = LogicalSize::new(window_config.width, window_config.height);
+    Some(position_in_monitor(&monitor, size, window_config.position))
+}
+
+fn select_monitor(
+    selector: &MonitorSelector,
+    monitors: &[MonitorHandle],
+    primary: Option<&MonitorHandle>,
+) -> Option<MonitorHandle> {
+    match selector {
+        MonitorSelector::Platform | MonitorSelector::Primary => primary.cloned(),
+        MonitorSelector::Index(index) => monitors.get(*index).cloned(),
+        MonitorSelector::Name(name) => monitors
+            .iter()
+            .find(|monitor| {
+                monitor
+                    .name()
+                    .is_some_and(|monitor_name| monitor_name.contains(name.as_str()))
+            })
+            .cloned(),
+        MonitorSelector::Custom(picker) => {
+            let infos = monitors
+                .iter()
+                .map(|monitor| MonitorInfo {
+                    name: monitor.name(),
+                    position: monitor.position(),
+                    size: monitor.size(),
+                    scale_factor: monitor.scale_factor(),
+                    is_primary: Some(monitor) == primary,
+                })
+                .collect::<Vec<_>>();
+            picker(&infos).and_then(|index| monitors.get(index).cloned())
+        }
+    }
+}
+
+/// Get the physical position of a Window with the given logical size placed in a monitor.
+fn position_in_monitor(
+    monitor: &MonitorHandle,
+    size: LogicalSize<f64>,
+    position: WindowPosition,
+) -> PhysicalPosition<i32> {
+    let origin = monitor.position();
+    let scale_factor = monitor.scale_factor();
+    match position {
+        WindowPosition::Offset(x, y) => {
+            let offset = LogicalPosition::new(x, y).to_physical::<i32>(scale_factor);
+            PhysicalPosition::new(origin.x + offset.x, origin.y + offset.y)
+        }
+        WindowPosition::Platform | WindowPosition::Center => {
+            let size = size.to_physical::<i32>(scale_factor);
+            let monitor_size = monitor.size();
+            PhysicalPosition::new(
+                origin.x + (monitor_size.width as i32 - size.width) / 2,
+                origin.y + (monitor_size.height as i32 - size.height) / 2,
+            )
+        }
+    }
+}
+
 /// Create the surface for Skia to render in
 fn create_surface(
     window: &mut Window,
@@ -260,6 +588,7 @@ fn create_surface(
     gr_context: &mut DirectContext,
     num_samples: usize,
     stencil_size: usize,
+    surface_props: &SurfaceProps,
 ) -> Surface {
     let size = window.inner_size();
     let size = (
@@ -274,7 +603,7 @@ fn create_surface(
         SurfaceOrigin::BottomLeft,
         ColorType::RGBA8888,
         None,
-        None,
+        Some(surface_props),
     )
     .expect("Could not create skia surface")
 }
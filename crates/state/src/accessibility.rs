@@ -1,4 +1,4 @@
-use accesskit::{NodeId as AccessibilityId, Role};
+use accesskit::{Checked, Live, NodeId as AccessibilityId, Role};
 use freya_native_core::node::OwnedAttributeValue;
 use freya_native_core::{attributes::AttributeName, exports::shipyard::Component};
 use freya_native_core::{
@@ -10,13 +10,57 @@ use freya_native_core_macro::partial_derive_state;
 
 use crate::CustomAttributeValues;
 
-#[derive(Clone, Debug, PartialEq, Eq, Default, Component)]
+#[derive(Clone, Debug, PartialEq, Eq, Component)]
 pub struct AccessibilityNodeState {
     pub accessibility_id: Option<AccessibilityId>,
     pub role: Option<Role>,
     pub alt: Option<String>,
     pub name: Option<String>,
+    /// Takes precedence over `name`.
+    pub label: Option<String>,
+    pub description: Option<String>,
+    pub checked: Option<Checked>,
+    pub expanded: Option<bool>,
+    pub selected: Option<bool>,
+    pub disabled: bool,
+    pub live: Option<Live>,
+    /// Whether the Node and its descendants are left out of the accessibility tree.
+    pub hidden: bool,
     pub focusable: bool,
+    /// Whether the Node can be focused by navigating with the `Tab` key.
+    pub tab_focusable: bool,
+    /// Position of the Node when navigating with the `Tab` key, see [`AccessibilityNodeState::tab_order`].
+    pub tab_index: Option<i32>,
+}
+
+impl AccessibilityNodeState {
+    /// Get the position of the Node when navigating with the `Tab` key if it has a positive `tab_index`.
+    /// These go first in ascending order, and then the rest in the order of the document.
+    pub fn tab_order(&self) -> Option<i32> {
+        self.tab_index.filter(|tab_index| *tab_index > 0)
+    }
+}
+
+impl Default for AccessibilityNodeState {
+    fn default() -> Self {
+        Self {
+            accessibility_id: None,
+            role: None,
+            alt: None,
+            name: None,
+            label: None,
+            description: None,
+            checked: None,
+            expanded: None,
+            selected: None,
+            disabled: false,
+            live: None,
+            hidden: false,
+            focusable: false,
+            tab_focusable: true,
+            tab_index: None,
+        }
+    }
 }
 
 #[partial_derive_state]
@@ -33,7 +77,17 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
             AttributeName::Role,
             AttributeName::Alt,
             AttributeName::Name,
+            AttributeName::AriaLabel,
+            AttributeName::AriaDescription,
+            AttributeName::AriaChecked,
+            AttributeName::AriaExpanded,
+            AttributeName::AriaSelected,
+            AttributeName::AriaDisabled,
+            AttributeName::AriaLive,
+            AttributeName::AriaHidden,
             AttributeName::Focusable,
+            AttributeName::TabFocusable,
+            AttributeName::TabIndex,
         ]));
 
     fn update<'a>(
@@ -45,6 +99,7 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
         _context: &SendAnyMap,
     ) -> bool {
         let mut accessibility = AccessibilityNodeState::default();
+        let mut skipped_by_tab = false;
 
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
@@ -76,15 +131,77 @@ impl State<CustomAttributeValues> for AccessibilityNodeState {
                             accessibility.name = Some(attr.to_owned())
                         }
                     }
+                    AttributeName::AriaLabel => {
+                        if let OwnedAttributeValue::Text(attr) = attr.value {
+                            accessibility.label = Some(attr.to_owned())
+                        }
+                    }
+                    AttributeName::AriaDescription => {
+                        if let OwnedAttributeValue::Text(attr) = attr.value {
+                            accessibility.description = Some(attr.to_owned())
+                        }
+                    }
+                    AttributeName::AriaChecked => {
+                        if let OwnedAttributeValue::Text(attr) = attr.value {
+                            accessibility.checked =
+                                serde_json::from_str::<Checked>(&format!("\"{attr}\"")).ok()
+                        }
+                    }
+                    AttributeName::AriaExpanded => {
+                        if let OwnedAttributeValue::Text(attr) = attr.value {
+                            accessibility.expanded = attr.parse().ok()
+                        }
+                    }
+                    AttributeName::AriaSelected => {
+                        if let OwnedAttributeValue::Text(attr) = attr.value {
+                            accessibility.selected = attr.parse().ok()
+                        }
+                    }
+                    AttributeName::AriaDisabled => {
+                        if let OwnedAttributeValue::Text(attr) = attr.value {
+                            accessibility.disabled = attr.parse().unwrap_or_default()
+                        }
+                    }
+                    AttributeName::AriaLive => {
+                        if let OwnedAttributeValue::Text(attr) = attr.value {
+                            accessibility.live =
+                                serde_json::from_str::<Live>(&format!("\"{attr}\"")).ok()
+                        }
+                    }
+                    AttributeName::AriaHidden => {
+                        if let OwnedAttributeValue::Text(attr) = attr.value {
+                            accessibility.hidden = attr.parse().unwrap_or_default()
+                        }
+                    }
                     AttributeName::Focusable => {
                         if let OwnedAttributeValue::Text(attr) = attr.value {
-                            accessibility.focusable = attr.parse().unwrap_or_default()
+                            accessibility.focusable = attr.parse().unwrap_or_default();
+                            skipped_by_tab = attr == "false";
+                        }
+                    }
+                    AttributeName::TabFocusable => {
+                        if let OwnedAttributeValue::Text(attr) = attr.value {
+                            accessibility.tab_focusable = attr.parse().unwrap_or(true)
+                        }
+                    }
+                    AttributeName::TabIndex => {
+                        if let OwnedAttributeValue::Text(attr) = attr.value {
+                            accessibility.tab_index = attr.parse().ok()
                         }
                     }
                     _ => {}
                 }
             }
         }
+        // Explicitly unfocusable Nodes and the negative `tab_index` can only be focused programmatically
+        if skipped_by_tab
+            || accessibility
+                .tab_index
+                .is_some_and(|tab_index| tab_index < 0)
+        {
+            accessibility.tab_focusable = false;
+        }
+
         let changed = &accessibility != self;
 
         *self = accessibility;
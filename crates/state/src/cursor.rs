@@ -11,7 +11,7 @@ use freya_native_core::{
 };
 use freya_native_core_macro::partial_derive_state;
 
-use crate::{CursorMode, CursorReference, CustomAttributeValues, Parse};
+use crate::{CursorMode, CursorReference, CustomAttributeValues, Parse, UserSelect};
 
 #[derive(Clone, Debug, PartialEq, Component)]
 pub struct CursorSettings {
@@ -22,6 +22,14 @@ pub struct CursorSettings {
     pub highlights: Option<Vec<(usize, usize)>>,
     pub highlight_color: Color,
     pub cursor_ref: Option<CursorReference>,
+    /// Ranges of the text to highlight, e.g. the matches of a search.
+    pub match_highlights: Option<Vec<(usize, usize)>>,
+    pub match_highlight_color: Color,
+    /// Index of the range in `match_highlights` that is highlighted with `current_match_color`.
+    pub current_match: Option<usize>,
+    pub current_match_color: Color,
+    /// Resolved from the `user_select` of the element or its closest ancestor that sets it.
+    pub user_select: UserSelect,
 }
 
 impl Default for CursorSettings {
@@ -34,6 +42,11 @@ impl Default for CursorSettings {
             highlights: None,
             highlight_color: Color::from_rgb(87, 108, 188),
             cursor_ref: None,
+            match_highlights: None,
+            match_highlight_color: Color::from_rgb(255, 235, 110),
+            current_match: None,
+            current_match_color: Color::from_rgb(255, 150, 50),
+            user_select: UserSelect::default(),
         }
     }
 }
@@ -55,6 +68,11 @@ impl State<CustomAttributeValues> for CursorSettings {
             AttributeName::Highlights,
             AttributeName::HighlightColor,
             AttributeName::CursorReference,
+            AttributeName::MatchHighlights,
+            AttributeName::MatchHighlightColor,
+            AttributeName::CurrentMatch,
+            AttributeName::CurrentMatchColor,
+            AttributeName::UserSelect,
         ]))
         .with_tag();
 
@@ -122,6 +140,39 @@ impl State<CustomAttributeValues> for CursorSettings {
                             cursor.cursor_ref = Some(reference.clone());
                         }
                     }
+                    AttributeName::MatchHighlights => {
+                        if let Some(CustomAttributeValues::TextHighlights(highlights)) =
+                            attr.value.as_custom()
+                        {
+                            cursor.match_highlights = Some(highlights.clone());
+                        }
+                    }
+                    AttributeName::MatchHighlightColor => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(match_highlight_color) = Color::parse(value) {
+                                cursor.match_highlight_color = match_highlight_color;
+                            }
+                        }
+                    }
+                    AttributeName::CurrentMatch => {
+                        if let Some(value) = attr.value.as_text() {
+                            cursor.current_match = value.parse().ok();
+                        }
+                    }
+                    AttributeName::CurrentMatchColor => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(current_match_color) = Color::parse(value) {
+                                cursor.current_match_color = current_match_color;
+                            }
+                        }
+                    }
+                    AttributeName::UserSelect => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(user_select) = UserSelect::parse(value) {
+                                cursor.user_select = user_select;
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -49,15 +49,44 @@ impl Display for NodeReference {
 
 pub type CanvasRunner = dyn Fn(&Canvas, &mut FontCollection, Area) + Sync + Send + 'static;
 
+/// Recorded output of a cached Canvas, replayed instead of running the Canvas again while its area stays the same.
+#[derive(Clone, Default)]
+pub struct CanvasCache(Arc<Mutex<Option<(Area, Picture)>>>);
+
+impl CanvasCache {
+    /// Get the recorded picture, if it was recorded for the given area.
+    pub fn get(&self, area: &Area) -> Option<Picture> {
+        self.0
+            .lock()
+            .unwrap()
+            .as_ref()
+            .filter(|(cached_area, _)| cached_area == area)
+            .map(|(_, picture)| picture.clone())
+    }
+
+    /// Replace the recorded picture.
+    pub fn set(&self, area: Area, picture: Picture) {
+        *self.0.lock().unwrap() = Some((area, picture));
+    }
+}
+
+impl PartialEq for CanvasCache {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
 /// Canvas Reference
 #[derive(Clone)]
 pub struct CanvasReference {
     pub runner: Arc<Box<CanvasRunner>>,
+    /// Only canvas with a cache have their output recorded and replayed.
+    pub cache: Option<CanvasCache>,
 }
 
 impl PartialEq for CanvasReference {
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.runner, &other.runner)
+        Arc::ptr_eq(&self.runner, &other.runner) && self.cache == other.cache
     }
 }
 
@@ -67,6 +96,54 @@ impl Debug for CanvasReference {
     }
 }
 
+/// Draws directly with the GPU, given the canvas of the Window, its GPU context and the area of the element.
+pub type GpuCanvasRunner = dyn Fn(&Canvas, &mut DirectContext, Area) + Sync + Send + 'static;
+
+/// GPU Canvas Reference
+#[derive(Clone)]
+pub struct GpuCanvasReference {
+    pub runner: Arc<Box<GpuCanvasRunner>>,
+}
+
+impl PartialEq for GpuCanvasReference {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.runner, &other.runner)
+    }
+}
+
+impl Debug for GpuCanvasReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GpuCanvasReference").finish_non_exhaustive()
+    }
+}
+
+/// Compiled SkSL effect.
+pub struct ShaderEffect(pub RuntimeEffect);
+
+// The effect is never mutated once compiled, it's only used to make the shaders while rendering
+unsafe impl Send for ShaderEffect {}
+unsafe impl Sync for ShaderEffect {}
+
+/// Shader Reference
+#[derive(Clone)]
+pub struct ShaderReference {
+    pub effect: Arc<ShaderEffect>,
+    /// Uniform values, laid out just like the effect expects them.
+    pub uniforms: Bytes,
+}
+
+impl PartialEq for ShaderReference {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.effect, &other.effect) && self.uniforms == other.uniforms
+    }
+}
+
+impl Debug for ShaderReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShaderReference").finish_non_exhaustive()
+    }
+}
+
 /// Cursor reference
 #[derive(Clone, Debug)]
 pub struct CursorReference {
@@ -129,6 +206,8 @@ pub enum CustomAttributeValues {
     AccessibilityId(AccessibilityId),
     TextHighlights(Vec<(usize, usize)>),
     Canvas(CanvasReference),
+    GpuCanvas(GpuCanvasReference),
+    Shader(ShaderReference),
 }
 
 impl Debug for CustomAttributeValues {
@@ -141,6 +220,8 @@ impl Debug for CustomAttributeValues {
             Self::AccessibilityId(_) => f.debug_tuple("AccessibilityId").finish(),
             Self::TextHighlights(_) => f.debug_tuple("TextHighlights").finish(),
             Self::Canvas(_) => f.debug_tuple("Canvas").finish(),
+            Self::GpuCanvas(_) => f.debug_tuple("GpuCanvas").finish(),
+            Self::Shader(_) => f.debug_tuple("Shader").finish(),
         }
     }
 }
@@ -158,6 +239,11 @@ pub fn dynamic_bytes(bytes: impl Into<Bytes>) -> AttributeValue {
     )))
 }
 
+/// Create the value of the `match_highlights` attribute of a `paragraph` from the ranges of text to highlight.
+pub fn text_highlights(ranges: impl Into<Vec<(usize, usize)>>) -> AttributeValue {
+    AttributeValue::any_value(CustomAttributeValues::TextHighlights(ranges.into()))
+}
+
 /// Transform some static bytes (e.g: statically linked images or SVGs) into an attribute
 pub fn static_bytes(bytes: &'static [u8]) -> AttributeValue {
     AttributeValue::any_value(CustomAttributeValues::Bytes(AttributesBytes::Static(bytes)))
@@ -0,0 +1,83 @@
+use std::{fmt::Debug, sync::Arc};
+
+use freya_engine::prelude::*;
+use rustc_hash::FxHashMap;
+use torin::geometry::{Area, Point2D, Size2D};
+
+/// Layout, painting and hit testing of a custom element, for the `rect` elements whose `custom_element`
+/// attribute has the name it was registered with in [`CustomElements`].
+///
+/// The sizes, areas and points are in physical pixels, just like the layout.
+///
+/// A custom element is shared by all the elements that use its name, and it doesn't get their attributes
+/// nor is notified when they change, everything else about them is handled like in any other `rect`.
+/// Elements that need different behaviors must use custom elements registered with different names.
+pub trait CustomElement: Send + Sync + 'static {
+    /// Measure the content of the element, given the size available for it.
+    /// The measured size is used for the `width` and `height` that are `auto`,
+    /// and the children of the element are not laid out then.
+    ///
+    /// Return `None` (default) to lay out the element and its children like any other `rect`.
+    fn measure(&self, _available_size: &Size2D) -> Option<Size2D> {
+        None
+    }
+
+    /// Paint the element in its area, over its background and borders.
+    fn paint(&self, canvas: &Canvas, font_collection: &mut FontCollection, area: Area);
+
+    /// Check if a point hits the element, for the pointer events.
+    /// It's only called for the points inside of its area, expanded by its `hit_padding`, so by default they all hit it.
+    fn hit_test(&self, _area: Area, _point: Point2D) -> bool {
+        true
+    }
+}
+
+/// Registry of the [`CustomElement`]s of an app, by the names the `custom_element` attribute refers to them with.
+///
+/// It's registered when launching the app, and elements with names that aren't registered behave like a normal `rect`.
+#[derive(Clone, Default)]
+pub struct CustomElements(Arc<FxHashMap<String, Arc<dyn CustomElement>>>);
+
+impl CustomElements {
+    /// Register a custom element, replacing any other registered with the same name.
+    pub fn register(&mut self, name: impl Into<String>, element: impl CustomElement) {
+        Arc::make_mut(&mut self.0).insert(name.into(), Arc::new(element));
+    }
+
+    /// Get the custom element registered with a name.
+    pub fn get(&self, name: &str) -> Option<Arc<dyn CustomElement>> {
+        self.0.get(name).cloned()
+    }
+
+    /// Check if there are no custom elements registered.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Debug for CustomElements {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+/// Custom element resolved for a node from the registered [`CustomElements`].
+#[derive(Clone)]
+pub struct CustomElementReference {
+    pub name: String,
+    pub element: Arc<dyn CustomElement>,
+}
+
+impl PartialEq for CustomElementReference {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && Arc::ptr_eq(&self.element, &other.element)
+    }
+}
+
+impl Debug for CustomElementReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomElementReference")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
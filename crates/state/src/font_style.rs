@@ -1,5 +1,6 @@
 use std::sync::{Arc, Mutex};
 
+use freya_common::RestyledParagraphs;
 use freya_engine::prelude::*;
 use freya_native_core::{
     attributes::AttributeName,
@@ -11,7 +12,10 @@ use freya_native_core::{
 use freya_native_core_macro::partial_derive_state;
 use torin::torin::Torin;
 
-use crate::{CustomAttributeValues, ExtSplit, Parse, TextOverflow};
+use crate::{
+    CustomAttributeValues, ExtSplit, Parse, RenderSettings, TextCentering, TextHinting,
+    TextOverflow, WhiteSpace,
+};
 
 #[derive(Debug, Clone, PartialEq, Component)]
 pub struct FontStyleState {
@@ -28,13 +32,22 @@ pub struct FontStyleState {
     pub letter_spacing: f32,
     pub text_align: TextAlign,
     pub max_lines: Option<usize>,
+    /// Amount of lines the text reserves space for, even if it has less.
+    pub min_lines: usize,
     pub text_overflow: TextOverflow,
+    pub white_space: WhiteSpace,
+    /// Only changes where the text is painted, so it's not part of the layout.
+    pub text_centering: TextCentering,
+    pub text_hinting: TextHinting,
+    pub text_subpixel: bool,
 }
 
 impl FontStyleState {
-    fn default_with_scale_factor(scale_factor: f32) -> Self {
+    fn default_with_settings(scale_factor: f32, render_settings: &RenderSettings) -> Self {
         Self {
             font_size: 16.0 * scale_factor,
+            text_hinting: render_settings.text_hinting,
+            text_subpixel: render_settings.text_subpixel,
             ..FontStyleState::default()
         }
     }
@@ -67,6 +80,25 @@ impl FontStyleState {
 
         text_style
     }
+
+    /// Check if texts are laid out the same with both styles,
+    /// in which case they only differ in how they are painted, e.g. their colors.
+    pub fn lays_out_like(&self, other: &Self) -> bool {
+        self.font_family == other.font_family
+            && self.font_size == other.font_size
+            && self.font_slant == other.font_slant
+            && self.font_weight == other.font_weight
+            && self.font_width == other.font_width
+            && self.line_height == other.line_height
+            && self.word_spacing == other.word_spacing
+            && self.letter_spacing == other.letter_spacing
+            && self.text_align == other.text_align
+            && self.max_lines == other.max_lines
+            && self.min_lines == other.min_lines
+            && self.text_overflow == other.text_overflow
+            && self.white_space == other.white_space
+            && self.text_hinting == other.text_hinting
+    }
 }
 
 impl Default for FontStyleState {
@@ -88,7 +120,12 @@ impl Default for FontStyleState {
             },
             text_align: TextAlign::default(),
             max_lines: None,
+            min_lines: 0,
             text_overflow: TextOverflow::default(),
+            white_space: WhiteSpace::default(),
+            text_centering: TextCentering::default(),
+            text_hinting: TextHinting::default(),
+            text_subpixel: true,
         }
     }
 }
@@ -110,6 +147,7 @@ impl State<CustomAttributeValues> for FontStyleState {
             AttributeName::FontFamily,
             AttributeName::LineHeight,
             AttributeName::MaxLines,
+            AttributeName::MinLines,
             AttributeName::FontStyle,
             AttributeName::FontWeight,
             AttributeName::FontWidth,
@@ -119,6 +157,10 @@ impl State<CustomAttributeValues> for FontStyleState {
             AttributeName::DecorationColor,
             AttributeName::DecorationStyle,
             AttributeName::TextOverflow,
+            AttributeName::WhiteSpace,
+            AttributeName::TextCentering,
+            AttributeName::TextHinting,
+            AttributeName::TextSubpixel,
         ]));
 
     fn update<'a>(
@@ -130,11 +172,13 @@ impl State<CustomAttributeValues> for FontStyleState {
         context: &SendAnyMap,
     ) -> bool {
         let torin_layout = context.get::<Arc<Mutex<Torin<NodeId>>>>().unwrap();
+        let restyled_paragraphs = context.get::<RestyledParagraphs>().unwrap();
         let scale_factor = context.get::<f32>().unwrap();
+        let render_settings = context.get::<RenderSettings>().copied().unwrap_or_default();
 
-        let mut font_style = parent
-            .map(|(v,)| v.clone())
-            .unwrap_or_else(|| FontStyleState::default_with_scale_factor(*scale_factor));
+        let mut font_style = parent.map(|(v,)| v.clone()).unwrap_or_else(|| {
+            FontStyleState::default_with_settings(*scale_factor, &render_settings)
+        });
 
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
@@ -197,6 +241,13 @@ impl State<CustomAttributeValues> for FontStyleState {
                             }
                         }
                     }
+                    AttributeName::MinLines => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(min_lines) = value.parse() {
+                                font_style.min_lines = min_lines;
+                            }
+                        }
+                    }
                     AttributeName::TextOverflow => {
                         let value = attr.value.as_text();
                         if let Some(value) = value {
@@ -205,6 +256,32 @@ impl State<CustomAttributeValues> for FontStyleState {
                             }
                         }
                     }
+                    AttributeName::WhiteSpace => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(white_space) = WhiteSpace::parse(value) {
+                                font_style.white_space = white_space;
+                            }
+                        }
+                    }
+                    AttributeName::TextCentering => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(text_centering) = TextCentering::parse(value) {
+                                font_style.text_centering = text_centering;
+                            }
+                        }
+                    }
+                    AttributeName::TextHinting => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(text_hinting) = TextHinting::parse(value) {
+                                font_style.text_hinting = text_hinting;
+                            }
+                        }
+                    }
+                    AttributeName::TextSubpixel => {
+                        if let Some(value) = attr.value.as_text() {
+                            font_style.text_subpixel = value != "false";
+                        }
+                    }
                     AttributeName::FontStyle => {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(font_slant) = Slant::parse(value) {
@@ -270,13 +347,17 @@ impl State<CustomAttributeValues> for FontStyleState {
             }
         }
 
-        let changed_size = font_style != *self;
+        let changed = &font_style != self;
 
-        if changed_size {
-            torin_layout.lock().unwrap().invalidate(node_view.node_id());
+        if changed {
+            if font_style.lays_out_like(self) {
+                // Only the paint changed, so the paragraph can be rebuilt without measuring the layout again
+                restyled_paragraphs.insert_node(node_view.node_id());
+            } else {
+                torin_layout.lock().unwrap().invalidate(node_view.node_id());
+            }
         }
 
-        let changed = &font_style != self;
         *self = font_style;
         changed
     }
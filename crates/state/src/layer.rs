@@ -1,4 +1,4 @@
-use freya_common::Layers;
+use freya_common::{Layer, Layers};
 use freya_native_core::{
     attributes::AttributeName,
     exports::shipyard::Component,
@@ -10,10 +10,17 @@ use freya_native_core_macro::partial_derive_state;
 
 use crate::CustomAttributeValues;
 
+/// Relative layer of the elements with `layer: "overlay"`, so they are painted above the rest of the tree.
+const OVERLAY_LAYER: i16 = -4096;
+
 #[derive(Default, PartialEq, Clone, Debug, Component)]
 pub struct LayerState {
-    pub layer: i16,
+    pub layer: Layer,
     pub layer_for_children: i16,
+    /// Layer of the root of the stacking context the children are in, see `isolation`.
+    pub context_for_children: Layer,
+    /// Height of the root of the stacking context the children are in.
+    pub context_height: u16,
 }
 
 #[partial_derive_state]
@@ -25,7 +32,10 @@ impl State<CustomAttributeValues> for LayerState {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
-        .with_attrs(AttributeMaskBuilder::Some(&[AttributeName::Layer]))
+        .with_attrs(AttributeMaskBuilder::Some(&[
+            AttributeName::Layer,
+            AttributeName::Isolation,
+        ]))
         .with_tag();
 
     fn update<'a>(
@@ -41,35 +51,67 @@ impl State<CustomAttributeValues> for LayerState {
         }
 
         let layers = context.get::<Layers>().unwrap();
-        let inherited_layer = parent.map(|(p,)| p.layer_for_children).unwrap_or(0i16);
+        let inherited_layer = parent
+            .as_ref()
+            .map(|(p,)| p.layer_for_children)
+            .unwrap_or(0i16);
+        let (stacking_context, context_height) = parent
+            .map(|(p,)| (p.context_for_children.clone(), p.context_height))
+            .unwrap_or_default();
 
         let mut provided_layer = 0;
+        let mut isolate = false;
 
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                #[allow(clippy::single_match)]
                 match attr.attribute {
                     AttributeName::Layer => {
                         if let Some(value) = attr.value.as_text() {
-                            if let Ok(relative_layer) = value.parse::<i16>() {
+                            if value == "overlay" {
+                                provided_layer = OVERLAY_LAYER;
+                            } else if let Ok(relative_layer) = value.parse::<i16>() {
                                 provided_layer = relative_layer;
                             }
                         }
                     }
+                    AttributeName::Isolation => {
+                        if let Some(value) = attr.value.as_text() {
+                            isolate = value == "isolate";
+                        }
+                    }
                     _ => {}
                 }
             }
         }
 
-        let layer_state = LayerState {
-            layer: -provided_layer + node_view.height() as i16 - inherited_layer,
-            layer_for_children: provided_layer + inherited_layer,
+        let height = node_view.height();
+        let layer = stacking_context
+            .nested(-provided_layer + (height - context_height) as i16 - inherited_layer);
+
+        let layer_state = if isolate {
+            // The children start a new stacking context on top of this element
+            LayerState {
+                context_for_children: layer.clone(),
+                layer,
+                layer_for_children: 0,
+                context_height: height,
+            }
+        } else {
+            LayerState {
+                layer,
+                layer_for_children: provided_layer + inherited_layer,
+                context_for_children: stacking_context,
+                context_height,
+            }
         };
 
         let changed = &layer_state != self;
 
-        if changed {
-            layers.insert_node_in_layer(node_view.node_id(), layer_state.layer);
+        if changed && layer_state.layer != self.layer {
+            if !self.layer.0.is_empty() {
+                layers.remove_node_from_layer(node_view.node_id(), &self.layer);
+            }
+            layers.insert_node_in_layer(node_view.node_id(), layer_state.layer.clone());
         }
 
         *self = layer_state;
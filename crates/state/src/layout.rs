@@ -11,7 +11,7 @@ use freya_native_core::{
 use freya_native_core_macro::partial_derive_state;
 use torin::prelude::*;
 
-use crate::{CustomAttributeValues, NodeReference, Parse};
+use crate::{Border, BorderAlignment, CustomAttributeValues, NodeReference, Padding, Parse};
 
 #[derive(Default, Clone, Debug, Component, PartialEq)]
 pub struct LayoutState {
@@ -22,16 +22,26 @@ pub struct LayoutState {
     pub maximum_height: Size,
     pub maximum_width: Size,
     pub padding: Gaps,
+    pub padding_percentage: Gaps,
+    pub border: Gaps,
+    pub box_sizing: BoxSizing,
     pub margin: Gaps,
     pub direction: DirectionMode,
     pub offset_y: Length,
     pub offset_x: Length,
+    pub scrollable: bool,
     pub main_alignment: Alignment,
     pub cross_alignment: Alignment,
     pub position: Position,
     pub content: Content,
+    pub content_visibility: ContentVisibility,
+    pub contain_intrinsic_size: Size2D,
+    pub order: i32,
+    pub flex: Flex,
     pub node_ref: Option<NodeReference>,
     pub node_id: NodeId,
+    /// Skip the element and its descendants in the layout and rendering, while keeping them mounted.
+    pub hidden: bool,
 }
 
 #[partial_derive_state]
@@ -51,6 +61,9 @@ impl State<CustomAttributeValues> for LayoutState {
             AttributeName::MaxWidth,
             AttributeName::MaxHeight,
             AttributeName::Padding,
+            AttributeName::Border,
+            AttributeName::BorderAlign,
+            AttributeName::BoxSizing,
             AttributeName::Direction,
             AttributeName::OffsetX,
             AttributeName::OffsetY,
@@ -64,6 +77,13 @@ impl State<CustomAttributeValues> for LayoutState {
             AttributeName::PositionBottom,
             AttributeName::PositionLeft,
             AttributeName::Content,
+            AttributeName::ContentVisibility,
+            AttributeName::ContainIntrinsicSize,
+            AttributeName::Hidden,
+            AttributeName::Order,
+            AttributeName::FlexBasis,
+            AttributeName::FlexGrow,
+            AttributeName::FlexShrink,
         ]));
 
     fn update<'a>(
@@ -82,6 +102,9 @@ impl State<CustomAttributeValues> for LayoutState {
             ..Default::default()
         };
 
+        let mut border_width = 0.0;
+        let mut border_alignment = BorderAlignment::default();
+
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
                 match attr.attribute {
@@ -135,9 +158,35 @@ impl State<CustomAttributeValues> for LayoutState {
                     }
                     AttributeName::Padding => {
                         if let Some(value) = attr.value.as_text() {
-                            if let Ok(mut padding) = Gaps::parse(value) {
-                                padding.scale(*scale_factor);
-                                layout.padding = padding;
+                            if let Ok(Padding {
+                                mut fixed,
+                                percentage,
+                            }) = Padding::parse(value)
+                            {
+                                fixed.scale(*scale_factor);
+                                layout.padding = fixed;
+                                layout.padding_percentage = percentage;
+                            }
+                        }
+                    }
+                    AttributeName::Border => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(border) = Border::parse(value) {
+                                border_width = border.width * scale_factor;
+                            }
+                        }
+                    }
+                    AttributeName::BorderAlign => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(alignment) = BorderAlignment::parse(value) {
+                                border_alignment = alignment;
+                            }
+                        }
+                    }
+                    AttributeName::BoxSizing => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(box_sizing) = BoxSizing::parse(value) {
+                                layout.box_sizing = box_sizing;
                             }
                         }
                     }
@@ -161,6 +210,7 @@ impl State<CustomAttributeValues> for LayoutState {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(scroll) = value.parse::<f32>() {
                                 layout.offset_y = Length::new(scroll * scale_factor);
+                                layout.scrollable = true;
                             }
                         }
                     }
@@ -168,6 +218,7 @@ impl State<CustomAttributeValues> for LayoutState {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(scroll) = value.parse::<f32>() {
                                 layout.offset_x = Length::new(scroll * scale_factor);
+                                layout.scrollable = true;
                             }
                         }
                     }
@@ -188,7 +239,11 @@ impl State<CustomAttributeValues> for LayoutState {
                     AttributeName::Position => {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(position) = Position::parse(value) {
-                                if layout.position.is_empty() {
+                                if position.is_sticky() {
+                                    layout.position.set_sticky();
+                                } else if position.is_global() {
+                                    layout.position.set_global();
+                                } else if layout.position.is_empty() {
                                     layout.position = position;
                                 }
                             }
@@ -229,6 +284,66 @@ impl State<CustomAttributeValues> for LayoutState {
                             }
                         }
                     }
+                    AttributeName::ContentVisibility => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(content_visibility) = ContentVisibility::parse(value) {
+                                layout.content_visibility = content_visibility;
+                            }
+                        }
+                    }
+                    AttributeName::ContainIntrinsicSize => {
+                        if let Some(value) = attr.value.as_text() {
+                            let sizes = value
+                                .split_ascii_whitespace()
+                                .map(|size| size.parse::<f32>())
+                                .collect::<Result<Vec<_>, _>>();
+                            // A single size is used for both the width and height
+                            let size = match sizes.as_deref() {
+                                Ok([size]) => Some(Size2D::new(*size, *size)),
+                                Ok([width, height]) => Some(Size2D::new(*width, *height)),
+                                _ => None,
+                            };
+                            if let Some(size) = size {
+                                layout.contain_intrinsic_size = size * *scale_factor;
+                            }
+                        }
+                    }
+                    AttributeName::Hidden => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(hidden) = value.parse::<bool>() {
+                                layout.hidden = hidden;
+                            }
+                        }
+                    }
+                    AttributeName::Order => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(order) = value.parse::<i32>() {
+                                layout.order = order;
+                            }
+                        }
+                    }
+                    AttributeName::FlexBasis => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(mut basis) = Size::parse(value) {
+                                basis.scale(*scale_factor);
+                                layout.flex.basis = basis;
+                            }
+                        }
+                    }
+                    AttributeName::FlexGrow => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(grow) = value.parse::<f32>() {
+                                layout.flex.grow = grow.max(0.0);
+                            }
+                        }
+                    }
+                    AttributeName::FlexShrink => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(shrink) = value.parse::<f32>() {
+                                layout.flex.shrink = shrink.max(0.0);
+                            }
+                        }
+                    }
                     AttributeName::Reference => {
                         if let OwnedAttributeValue::Custom(CustomAttributeValues::Reference(
                             reference,
@@ -242,6 +357,14 @@ impl State<CustomAttributeValues> for LayoutState {
             }
         }
 
+        // Only the part of the border drawn inside the element takes space
+        let border_inset = match border_alignment {
+            BorderAlignment::Inner => border_width,
+            BorderAlignment::Center => border_width / 2.0,
+            BorderAlignment::Outer => 0.0,
+        };
+        layout.border.fill_all(border_inset);
+
         let changed = layout != *self;
 
         if changed {
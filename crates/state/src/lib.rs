@@ -1,6 +1,7 @@
 mod accessibility;
 mod cursor;
 mod custom_attributes;
+mod custom_elements;
 mod font_style;
 mod layer;
 mod layout;
@@ -14,6 +15,7 @@ mod viewport;
 pub use accessibility::*;
 pub use cursor::*;
 pub use custom_attributes::*;
+pub use custom_elements::*;
 pub use font_style::*;
 pub use layer::*;
 pub use layout::*;
@@ -4,16 +4,23 @@ use freya_native_core::{
     node::OwnedAttributeValue,
     node_ref::NodeView,
     prelude::{AttributeMaskBuilder, Dependancy, NodeMaskBuilder, State},
+    tags::TagName,
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
 
-use crate::{CanvasReference, CustomAttributeValues, ImageReference};
+use crate::{
+    CanvasReference, CustomAttributeValues, CustomElementReference, CustomElements,
+    GpuCanvasReference, ImageReference,
+};
 
 #[derive(Default, PartialEq, Clone, Debug, Component)]
 pub struct References {
     pub image_ref: Option<ImageReference>,
     pub canvas_ref: Option<CanvasReference>,
+    pub gpu_canvas_ref: Option<GpuCanvasReference>,
+    /// Registered custom element of a `rect`, from its `custom_element` attribute.
+    pub custom_element: Option<CustomElementReference>,
 }
 
 #[partial_derive_state]
@@ -28,6 +35,8 @@ impl State<CustomAttributeValues> for References {
         .with_attrs(AttributeMaskBuilder::Some(&[
             AttributeName::ImageReference,
             AttributeName::CanvasReference,
+            AttributeName::GpuCanvasReference,
+            AttributeName::CustomElement,
         ]))
         .with_tag();
 
@@ -37,7 +46,7 @@ impl State<CustomAttributeValues> for References {
         _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
-        _context: &SendAnyMap,
+        context: &SendAnyMap,
     ) -> bool {
         let mut references = References::default();
 
@@ -60,6 +69,31 @@ impl State<CustomAttributeValues> for References {
                             references.canvas_ref = Some(new_canvas.clone());
                         }
                     }
+                    AttributeName::GpuCanvasReference => {
+                        if let OwnedAttributeValue::Custom(CustomAttributeValues::GpuCanvas(
+                            new_canvas,
+                        )) = attr.value
+                        {
+                            references.gpu_canvas_ref = Some(new_canvas.clone());
+                        }
+                    }
+                    AttributeName::CustomElement => {
+                        let (Some(name), Some(custom_elements)) =
+                            (attr.value.as_text(), context.get::<CustomElements>())
+                        else {
+                            continue;
+                        };
+                        if node_view.tag() != Some(&TagName::Rect) {
+                            continue;
+                        }
+                        references.custom_element =
+                            custom_elements
+                                .get(name)
+                                .map(|element| CustomElementReference {
+                                    name: name.to_string(),
+                                    element,
+                                });
+                    }
                     _ => {}
                 }
             }
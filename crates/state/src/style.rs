@@ -7,23 +7,50 @@ use freya_native_core::{
     SendAnyMap,
 };
 use freya_native_core_macro::partial_derive_state;
-use torin::scaled::Scaled;
+use torin::{gaps::Gaps, scaled::Scaled};
 
 use crate::{
-    parsing::ExtSplit, AttributesBytes, Border, BorderAlignment, CornerRadius,
-    CustomAttributeValues, Fill, OverflowMode, Parse, Shadow,
+    parsing::ExtSplit, AttributesBytes, BackgroundClip, Border, BorderAlignment, CornerRadius,
+    CustomAttributeValues, Fill, Filters, Mask, OverflowMode, Parse, RenderSettings,
+    ShaderPosition, ShaderReference, Shadow, TextFill, WillChange,
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Component)]
 pub struct Style {
     pub background: Fill,
+    pub background_clip: BackgroundClip,
     pub border: Border,
+    /// Drawn outside of the element, following its corner radius. Its alignment is ignored.
+    pub outline: Border,
+    /// Gap between the element and its outline.
+    pub outline_offset: f32,
     pub shadows: Vec<Shadow>,
     pub corner_radius: CornerRadius,
     pub image_data: Option<AttributesBytes>,
     pub svg_data: Option<AttributesBytes>,
     pub overflow: OverflowMode,
     pub opacity: Option<f32>,
+    /// Blur radius applied to whatever is painted behind the element.
+    pub backdrop_blur: f32,
+    pub cache: bool,
+    /// Effects that are applied when painting the cached subtree of the element, instead of being part of it.
+    pub will_change: WillChange,
+    pub shader: Option<ShaderReference>,
+    pub shader_position: ShaderPosition,
+    pub mask: Option<Mask>,
+    /// Length of the fade applied to the edges where the scrolled content is clipped.
+    pub scroll_fade: f32,
+    /// Applied to the rendered output of the element and its descendants.
+    pub filters: Filters,
+    /// Paints the glyphs of text elements instead of their color.
+    pub text_fill: Option<TextFill>,
+    /// Inherited from the parent, or the global [`RenderSettings`] in the root.
+    pub antialias: bool,
+    /// Round the area to the physical pixel grid before rendering.
+    /// Inherited from the parent, or the global [`RenderSettings`] in the root.
+    pub pixel_snapping: bool,
+    /// Space around the element that also receives the pointer events, without changing its layout.
+    pub hit_padding: Gaps,
 }
 
 #[partial_derive_state]
@@ -37,9 +64,12 @@ impl State<CustomAttributeValues> for Style {
     const NODE_MASK: NodeMaskBuilder<'static> =
         NodeMaskBuilder::new().with_attrs(AttributeMaskBuilder::Some(&[
             AttributeName::Background,
+            AttributeName::BackgroundClip,
             AttributeName::Layer,
             AttributeName::Border,
             AttributeName::BorderAlign,
+            AttributeName::Outline,
+            AttributeName::OutlineOffset,
             AttributeName::Shadow,
             AttributeName::CornerRadius,
             AttributeName::CornerSmoothing,
@@ -48,18 +78,42 @@ impl State<CustomAttributeValues> for Style {
             AttributeName::SvgContent,
             AttributeName::Overflow,
             AttributeName::Opacity,
+            AttributeName::BackdropBlur,
+            AttributeName::Cache,
+            AttributeName::WillChange,
+            AttributeName::Shader,
+            AttributeName::ShaderPosition,
+            AttributeName::Mask,
+            AttributeName::MaskData,
+            AttributeName::ScrollFade,
+            AttributeName::Filter,
+            AttributeName::TextFill,
+            AttributeName::TextFillData,
+            AttributeName::Antialias,
+            AttributeName::PixelSnapping,
+            AttributeName::HitPadding,
         ]));
 
     fn update<'a>(
         &mut self,
         node_view: NodeView<CustomAttributeValues>,
         _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
-        _parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
+        parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
         context: &SendAnyMap,
     ) -> bool {
-        let mut style = Style::default();
         let scale_factor = context.get::<f32>().unwrap();
+        let render_settings = context.get::<RenderSettings>().copied().unwrap_or_default();
+        let mut style = Style {
+            antialias: parent
+                .as_ref()
+                .map(|(parent,)| parent.antialias)
+                .unwrap_or(render_settings.antialias),
+            pixel_snapping: parent
+                .map(|(parent,)| parent.pixel_snapping)
+                .unwrap_or(render_settings.pixel_snapping),
+            ..Style::default()
+        };
 
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
@@ -71,6 +125,13 @@ impl State<CustomAttributeValues> for Style {
                             }
                         }
                     }
+                    AttributeName::BackgroundClip => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(background_clip) = BackgroundClip::parse(value) {
+                                style.background_clip = background_clip;
+                            }
+                        }
+                    }
                     AttributeName::Border => {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(mut border) = Border::parse(value) {
@@ -81,6 +142,21 @@ impl State<CustomAttributeValues> for Style {
                             }
                         }
                     }
+                    AttributeName::Outline => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(mut outline) = Border::parse(value) {
+                                outline.scale(*scale_factor);
+                                style.outline = outline;
+                            }
+                        }
+                    }
+                    AttributeName::OutlineOffset => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(offset) = value.parse::<f32>() {
+                                style.outline_offset = offset * scale_factor;
+                            }
+                        }
+                    }
                     AttributeName::BorderAlign => {
                         if let Some(value) = attr.value.as_text() {
                             if let Ok(alignment) = BorderAlignment::parse(value) {
@@ -152,6 +228,100 @@ impl State<CustomAttributeValues> for Style {
                             }
                         }
                     }
+                    AttributeName::BackdropBlur => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(blur) = value.parse::<f32>() {
+                                style.backdrop_blur = blur.max(0.0) * scale_factor;
+                            }
+                        }
+                    }
+                    AttributeName::Shader => {
+                        if let OwnedAttributeValue::Custom(CustomAttributeValues::Shader(shader)) =
+                            attr.value
+                        {
+                            style.shader = Some(shader.clone());
+                        }
+                    }
+                    AttributeName::ShaderPosition => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(position) = ShaderPosition::parse(value) {
+                                style.shader_position = position;
+                            }
+                        }
+                    }
+                    AttributeName::Mask => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(fill) = Fill::parse(value) {
+                                style.mask = Some(Mask::Fill(fill));
+                            }
+                        }
+                    }
+                    AttributeName::MaskData => {
+                        if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) =
+                            attr.value
+                        {
+                            style.mask = Some(Mask::Image(bytes.clone()));
+                        }
+                    }
+                    AttributeName::ScrollFade => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(fade) = value.parse::<f32>() {
+                                style.scroll_fade = fade.max(0.0) * scale_factor;
+                            }
+                        }
+                    }
+                    AttributeName::TextFill => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(fill) = Fill::parse(value) {
+                                style.text_fill = Some(TextFill::Fill(fill));
+                            }
+                        }
+                    }
+                    AttributeName::TextFillData => {
+                        if let OwnedAttributeValue::Custom(CustomAttributeValues::Bytes(bytes)) =
+                            attr.value
+                        {
+                            style.text_fill = Some(TextFill::Image(bytes.clone()));
+                        }
+                    }
+                    AttributeName::Filter => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(mut filters) = Filters::parse(value) {
+                                filters.scale(*scale_factor);
+                                style.filters = filters;
+                            }
+                        }
+                    }
+                    AttributeName::Cache => {
+                        if let Some(value) = attr.value.as_text() {
+                            style.cache = value == "true";
+                        }
+                    }
+                    AttributeName::WillChange => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(will_change) = WillChange::parse(value) {
+                                style.will_change = will_change;
+                            }
+                        }
+                    }
+                    AttributeName::Antialias => {
+                        if let Some(value) = attr.value.as_text() {
+                            style.antialias = value != "false";
+                        }
+                    }
+                    AttributeName::PixelSnapping => {
+                        if let Some(value) = attr.value.as_text() {
+                            style.pixel_snapping = value == "true";
+                        }
+                    }
+                    AttributeName::HitPadding => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(mut hit_padding) = Gaps::parse(value) {
+                                hit_padding.scale(*scale_factor);
+                                style.hit_padding = hit_padding;
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -8,6 +8,16 @@ use freya_native_core_macro::partial_derive_state;
 
 use crate::CustomAttributeValues;
 
+/// Parse the degrees of a `rotate` attribute, e.g. `45deg`.
+pub(crate) fn parse_rotate_degs(value: &str) -> Option<f32> {
+    if !value.ends_with("deg") {
+        return None;
+    }
+    let degs = value.replacen("deg", "", 1).parse::<f32>().ok()?;
+    // Full turns make no difference, but big angles lose precision
+    Some(degs.rem_euclid(360.0))
+}
+
 #[derive(Default, Clone, Debug, Component, PartialEq)]
 pub struct Transform {
     pub rotate_degs: Option<f32>,
@@ -40,11 +50,7 @@ impl State<CustomAttributeValues> for Transform {
                 match attr.attribute {
                     AttributeName::Rotate => {
                         if let Some(value) = attr.value.as_text() {
-                            if value.ends_with("deg") {
-                                if let Ok(degs) = value.replacen("deg", "", 1).parse::<f32>() {
-                                    rotate_degs = Some(degs)
-                                }
-                            }
+                            rotate_degs = parse_rotate_degs(value);
                         }
                     }
                     _ => {}
@@ -11,6 +11,7 @@ impl Parse for Alignment {
         Ok(match value {
             "center" => Alignment::Center,
             "end" => Alignment::End,
+            "baseline" => Alignment::Baseline,
             _ => Alignment::Start,
         })
     }
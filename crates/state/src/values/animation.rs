@@ -0,0 +1,319 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use freya_native_core::prelude::AttributeName;
+
+use crate::{interpolate_attribute, is_interpolatable, parse_time, Parse, TransitionEasing};
+
+/// Values of some attributes at a point of a [`Keyframes`] animation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keyframe {
+    pub offset: f32,
+    pub attributes: Vec<(AttributeName, String)>,
+}
+
+/// Named sequence of [`Keyframe`]s, referenced from the `animation` attribute.
+///
+/// ```rust
+/// # use freya_node_state::Keyframes;
+/// let pulse = Keyframes::new()
+///     .keyframe(0.0, [("opacity", "1"), ("width", "100")])
+///     .keyframe(0.5, [("opacity", "0.5")])
+///     .keyframe(1.0, [("opacity", "1"), ("width", "150")]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Keyframes {
+    frames: Vec<Keyframe>,
+}
+
+impl Keyframes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a keyframe with the values of the given attributes at `offset`, from `0.0` (start) to `1.0` (end).
+    /// Attributes that can't be interpolated change in steps, and unknown attributes are ignored.
+    pub fn keyframe<'a>(
+        mut self,
+        offset: f32,
+        attributes: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        let attributes = attributes
+            .into_iter()
+            .filter_map(|(attribute, value)| {
+                AttributeName::from_str(attribute)
+                    .ok()
+                    .map(|attribute| (attribute, value.to_string()))
+            })
+            .collect();
+        let offset = offset.clamp(0.0, 1.0);
+        let index = self
+            .frames
+            .partition_point(|keyframe| keyframe.offset <= offset);
+        self.frames.insert(index, Keyframe { offset, attributes });
+        self
+    }
+
+    /// Get the keyframes, sorted by their offset.
+    pub fn frames(&self) -> &[Keyframe] {
+        &self.frames
+    }
+
+    /// Get the attributes animated by any of the keyframes.
+    pub fn attributes(&self) -> Vec<AttributeName> {
+        let mut attributes = Vec::new();
+        for (attribute, _) in self.frames.iter().flat_map(|frame| &frame.attributes) {
+            if !attributes.contains(attribute) {
+                attributes.push(*attribute);
+            }
+        }
+        attributes
+    }
+
+    /// Get the value of `attribute` at the given `progress` of the animation, from `0.0` to `1.0`.
+    pub fn sample(&self, attribute: &AttributeName, progress: f32) -> Option<String> {
+        let mut frames = self.frames.iter().filter_map(|frame| {
+            frame
+                .attributes
+                .iter()
+                .find(|(frame_attribute, _)| frame_attribute == attribute)
+                .map(|(_, value)| (frame.offset, value))
+        });
+
+        let (mut from_offset, mut from) = frames.next()?;
+        if progress <= from_offset {
+            return Some(from.clone());
+        }
+
+        for (to_offset, to) in frames {
+            if progress <= to_offset {
+                let local_progress = if to_offset > from_offset {
+                    (progress - from_offset) / (to_offset - from_offset)
+                } else {
+                    1.0
+                };
+                let value = if is_interpolatable(attribute) {
+                    interpolate_attribute(attribute, from, to, local_progress)
+                } else {
+                    None
+                };
+                return Some(value.unwrap_or_else(|| {
+                    if local_progress >= 1.0 {
+                        to.clone()
+                    } else {
+                        from.clone()
+                    }
+                }));
+            }
+            from_offset = to_offset;
+            from = to;
+        }
+
+        Some(from.clone())
+    }
+}
+
+/// How many times an [`Animation`] is played.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AnimationIterations {
+    Finite(f32),
+    Infinite,
+}
+
+impl Default for AnimationIterations {
+    fn default() -> Self {
+        Self::Finite(1.0)
+    }
+}
+
+/// Direction in which each iteration of an [`Animation`] is played.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AnimationDirection {
+    #[default]
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+/// Which values of an [`Animation`] are applied while it is not running.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum AnimationFillMode {
+    /// The attributes keep their own values.
+    #[default]
+    None,
+    /// The values of the last keyframe are kept once it ends.
+    Forwards,
+    /// The values of the first keyframe are applied during the delay.
+    Backwards,
+    /// Both [`AnimationFillMode::Forwards`] and [`AnimationFillMode::Backwards`].
+    Both,
+}
+
+impl AnimationFillMode {
+    pub fn forwards(&self) -> bool {
+        matches!(self, Self::Forwards | Self::Both)
+    }
+
+    pub fn backwards(&self) -> bool {
+        matches!(self, Self::Backwards | Self::Both)
+    }
+}
+
+/// State of an [`Animation`] at some point of time.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AnimationProgress {
+    /// Waiting for the delay to pass.
+    Delayed,
+    /// Running at the given progress of the keyframes.
+    Running(f32),
+    /// Ended at the given progress of the keyframes.
+    Finished(f32),
+}
+
+/// Value of the `animation` attribute, e.g. `pulse 1s infinite alternate ease-in-out`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Animation {
+    pub name: String,
+    pub duration: Duration,
+    pub delay: Duration,
+    pub iterations: AnimationIterations,
+    pub direction: AnimationDirection,
+    pub fill_mode: AnimationFillMode,
+    pub easing: TransitionEasing,
+    pub paused: bool,
+}
+
+impl Animation {
+    /// Get the progress of the keyframes after the animation has been playing for `elapsed`, including its delay.
+    pub fn progress(&self, elapsed: Duration) -> AnimationProgress {
+        if elapsed < self.delay {
+            return AnimationProgress::Delayed;
+        }
+        let elapsed = elapsed - self.delay;
+        let duration = self.duration.as_secs_f32();
+
+        let (is_odd_iteration, iteration_progress, finished) = match self.iterations {
+            AnimationIterations::Finite(iterations)
+                if duration == 0.0 || elapsed.as_secs_f32() >= duration * iterations =>
+            {
+                // Ends at the progress of its last iteration
+                let iteration = iterations.ceil().max(1.0) - 1.0;
+                let last_progress = iterations - iteration;
+                (iteration % 2.0 == 1.0, last_progress, true)
+            }
+            _ if duration == 0.0 => (false, 1.0, false),
+            _ => {
+                // Integer arithmetic so long running animations don't drift
+                let elapsed = elapsed.as_nanos();
+                let duration = self.duration.as_nanos();
+                let iteration = elapsed / duration;
+                let iteration_progress = (elapsed % duration) as f64 / duration as f64;
+                (iteration % 2 == 1, iteration_progress as f32, false)
+            }
+        };
+
+        let is_reversed = match self.direction {
+            AnimationDirection::Normal => false,
+            AnimationDirection::Reverse => true,
+            AnimationDirection::Alternate => is_odd_iteration,
+            AnimationDirection::AlternateReverse => !is_odd_iteration,
+        };
+        let progress = self.easing.apply(iteration_progress);
+        let progress = if is_reversed {
+            1.0 - progress
+        } else {
+            progress
+        };
+
+        if finished {
+            AnimationProgress::Finished(progress)
+        } else {
+            AnimationProgress::Running(progress)
+        }
+    }
+
+    /// Get the progress of the keyframes at the very start of the animation.
+    pub fn start_progress(&self) -> f32 {
+        match self.direction {
+            AnimationDirection::Normal | AnimationDirection::Alternate => 0.0,
+            AnimationDirection::Reverse | AnimationDirection::AlternateReverse => 1.0,
+        }
+    }
+
+    /// Check if both animations are the same, ignoring whether they are paused.
+    pub fn same_as(&self, other: &Self) -> bool {
+        Self {
+            paused: other.paused,
+            ..self.clone()
+        } == *other
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseAnimationError;
+
+impl Parse for Animation {
+    type Err = ParseAnimationError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.split_ascii_whitespace();
+        let name = parts.next().ok_or(ParseAnimationError)?.to_string();
+
+        let mut duration = None;
+        let mut delay = None;
+        let mut iterations = AnimationIterations::default();
+        let mut direction = AnimationDirection::default();
+        let mut fill_mode = AnimationFillMode::default();
+        let mut easing = TransitionEasing::default();
+        let mut paused = false;
+
+        for part in parts {
+            match part {
+                "infinite" => iterations = AnimationIterations::Infinite,
+                "normal" => direction = AnimationDirection::Normal,
+                "reverse" => direction = AnimationDirection::Reverse,
+                "alternate" => direction = AnimationDirection::Alternate,
+                "alternate-reverse" => direction = AnimationDirection::AlternateReverse,
+                "none" => fill_mode = AnimationFillMode::None,
+                "forwards" => fill_mode = AnimationFillMode::Forwards,
+                "backwards" => fill_mode = AnimationFillMode::Backwards,
+                "both" => fill_mode = AnimationFillMode::Both,
+                "paused" => paused = true,
+                "running" => paused = false,
+                _ => {
+                    if let Ok(part_easing) = TransitionEasing::parse(part) {
+                        easing = part_easing;
+                    } else if let Some(time) = parse_time(part) {
+                        // The first time is the duration and the second one the delay
+                        if duration.is_none() {
+                            duration = Some(time);
+                        } else if delay.is_none() {
+                            delay = Some(time);
+                        } else {
+                            return Err(ParseAnimationError);
+                        }
+                    } else if let Ok(count) = part.parse::<f32>() {
+                        if count < 0.0 {
+                            return Err(ParseAnimationError);
+                        }
+                        iterations = AnimationIterations::Finite(count);
+                    } else {
+                        return Err(ParseAnimationError);
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            name,
+            duration: duration.ok_or(ParseAnimationError)?,
+            delay: delay.unwrap_or_default(),
+            iterations,
+            direction,
+            fill_mode,
+            easing,
+            paused,
+        })
+    }
+}
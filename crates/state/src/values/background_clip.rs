@@ -0,0 +1,36 @@
+use crate::Parse;
+use std::fmt;
+
+/// Area of an element that is painted with its background.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum BackgroundClip {
+    /// The whole area of the element, including the border.
+    #[default]
+    BorderBox,
+    /// The area inside of the border.
+    PaddingBox,
+    /// The area inside of the padding.
+    ContentBox,
+}
+
+impl Parse for BackgroundClip {
+    type Err = ();
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "padding-box" => BackgroundClip::PaddingBox,
+            "content-box" => BackgroundClip::ContentBox,
+            _ => BackgroundClip::BorderBox,
+        })
+    }
+}
+
+impl fmt::Display for BackgroundClip {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            BackgroundClip::BorderBox => "border-box",
+            BackgroundClip::PaddingBox => "padding-box",
+            BackgroundClip::ContentBox => "content-box",
+        })
+    }
+}
@@ -0,0 +1,17 @@
+use torin::box_sizing::BoxSizing;
+
+use crate::Parse;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseBoxSizingError;
+
+impl Parse for BoxSizing {
+    type Err = ParseBoxSizingError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "content-box" => BoxSizing::ContentBox,
+            _ => BoxSizing::BorderBox,
+        })
+    }
+}
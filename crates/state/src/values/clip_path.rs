@@ -0,0 +1,164 @@
+use freya_engine::prelude::*;
+use torin::{geometry::Area, scaled::Scaled};
+
+use crate::Parse;
+
+/// Length used by the shapes of a [`ClipPath`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ClipLength {
+    Pixels(f32),
+    /// Percentage of the reference size, from `0.0` to `100.0`.
+    Percentage(f32),
+}
+
+impl ClipLength {
+    /// Get the length in pixels, with percentages related to `reference`.
+    pub fn resolve(&self, reference: f32) -> f32 {
+        match self {
+            Self::Pixels(pixels) => *pixels,
+            Self::Percentage(percentage) => reference * percentage / 100.0,
+        }
+    }
+}
+
+impl Parse for ClipLength {
+    type Err = ParseClipPathError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        if let Some(percentage) = value.strip_suffix('%') {
+            percentage
+                .parse::<f32>()
+                .map(Self::Percentage)
+                .map_err(|_| ParseClipPathError)
+        } else {
+            value
+                .parse::<f32>()
+                .map(Self::Pixels)
+                .map_err(|_| ParseClipPathError)
+        }
+    }
+}
+
+impl Scaled for ClipLength {
+    fn scale(&mut self, scale_factor: f32) {
+        if let Self::Pixels(pixels) = self {
+            *pixels *= scale_factor;
+        }
+    }
+}
+
+/// Shape the content of an element is clipped to, positioned from the top-left corner of its area.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClipPath {
+    /// Circle centered in the area, percentages are relative to its smallest side.
+    Circle(ClipLength),
+    /// Ellipse filling the whole area.
+    Ellipse,
+    /// Polygon through the given points, percentages are relative to the width and height of the area.
+    Polygon(Vec<(ClipLength, ClipLength)>),
+    /// SVG path data, in pixels, scaled by `scale`.
+    Path { data: String, scale: f32 },
+}
+
+impl ClipPath {
+    /// Create the path of this shape in the given area.
+    /// Returns `None` if the SVG path data is not valid.
+    pub fn to_path(&self, area: Area) -> Option<Path> {
+        let mut path = Path::new();
+        match self {
+            Self::Circle(radius) => {
+                let center = area.center();
+                let radius = radius.resolve(area.width().min(area.height()));
+                path.add_circle((center.x, center.y), radius, None);
+            }
+            Self::Ellipse => {
+                path.add_oval(
+                    Rect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                    None,
+                );
+            }
+            Self::Polygon(points) => {
+                let points = points
+                    .iter()
+                    .map(|(x, y)| {
+                        Point::new(
+                            area.min_x() + x.resolve(area.width()),
+                            area.min_y() + y.resolve(area.height()),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                path.add_poly(&points, true);
+            }
+            Self::Path { data, scale } => {
+                path = Path::from_svg(data)?;
+                path.transform(&Matrix::scale((*scale, *scale)));
+                path.offset((area.min_x(), area.min_y()));
+            }
+        }
+        Some(path)
+    }
+
+    /// Check if a point is inside of this shape in the given area.
+    pub fn contains(&self, area: Area, point: (f32, f32)) -> bool {
+        self.to_path(area)
+            .map(|path| path.contains(point))
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseClipPathError;
+
+impl Parse for ClipPath {
+    type Err = ParseClipPathError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let (name, arguments) = value
+            .trim()
+            .strip_suffix(')')
+            .and_then(|value| value.split_once('('))
+            .ok_or(ParseClipPathError)?;
+        let arguments = arguments.trim();
+
+        Ok(match name.trim() {
+            "circle" if arguments.is_empty() => Self::Circle(ClipLength::Percentage(50.0)),
+            "circle" => Self::Circle(ClipLength::parse(arguments)?),
+            "ellipse" if arguments.is_empty() => Self::Ellipse,
+            "polygon" => Self::Polygon(
+                arguments
+                    .split(',')
+                    .map(|point| {
+                        let mut lengths = point.split_ascii_whitespace();
+                        match (lengths.next(), lengths.next(), lengths.next()) {
+                            (Some(x), Some(y), None) => {
+                                Ok((ClipLength::parse(x)?, ClipLength::parse(y)?))
+                            }
+                            _ => Err(ParseClipPathError),
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            "path" if !arguments.is_empty() => Self::Path {
+                data: arguments.to_string(),
+                scale: 1.0,
+            },
+            _ => return Err(ParseClipPathError),
+        })
+    }
+}
+
+impl Scaled for ClipPath {
+    fn scale(&mut self, scale_factor: f32) {
+        match self {
+            Self::Circle(radius) => radius.scale(scale_factor),
+            Self::Ellipse => {}
+            Self::Polygon(points) => {
+                for (x, y) in points {
+                    x.scale(scale_factor);
+                    y.scale(scale_factor);
+                }
+            }
+            Self::Path { scale, .. } => *scale *= scale_factor,
+        }
+    }
+}
@@ -11,6 +11,7 @@ impl Parse for Content {
     fn parse(value: &str) -> Result<Self, Self::Err> {
         Ok(match value {
             "fit" => Content::Fit,
+            "flex" => Content::Flex,
             _ => Content::Normal,
         })
     }
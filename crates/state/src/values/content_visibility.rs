@@ -0,0 +1,17 @@
+use torin::content_visibility::ContentVisibility;
+
+use crate::Parse;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseContentVisibilityError;
+
+impl Parse for ContentVisibility {
+    type Err = ParseContentVisibilityError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "auto" => ContentVisibility::Auto,
+            _ => ContentVisibility::Visible,
+        })
+    }
+}
@@ -0,0 +1,199 @@
+use freya_engine::prelude::Color;
+use torin::scaled::Scaled;
+
+use crate::{ExtSplit, Parse};
+
+/// Effect applied to the rendered output of an element and its descendants, e.g. `blur(4)`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Filter {
+    /// Blur radius.
+    Blur(f32),
+    /// Shadow following the shape of the rendered content, not just the box of the element.
+    DropShadow {
+        x: f32,
+        y: f32,
+        blur: f32,
+        color: Color,
+    },
+    /// `1.0` keeps the original colors, `0.0` makes them black.
+    Brightness(f32),
+    /// `1.0` keeps the original colors, `0.0` makes them gray.
+    Contrast(f32),
+    /// From `0.0` to `1.0`.
+    Grayscale(f32),
+    /// `1.0` keeps the original colors, `0.0` makes them gray.
+    Saturate(f32),
+    /// From `0.0` to `1.0`.
+    Invert(f32),
+    /// From `0.0` to `1.0`.
+    Sepia(f32),
+}
+
+impl Filter {
+    /// Get the row-major 4x5 color matrix of the filters that only transform colors.
+    pub fn color_matrix(&self) -> Option<[f32; 20]> {
+        let matrix = match *self {
+            Self::Blur(_) | Self::DropShadow { .. } => return None,
+            Self::Brightness(amount) => scale_matrix(amount, 0.0),
+            Self::Contrast(amount) => scale_matrix(amount, (1.0 - amount) / 2.0),
+            Self::Invert(amount) => scale_matrix(1.0 - 2.0 * amount, amount),
+            Self::Grayscale(amount) => {
+                let a = 1.0 - amount;
+                rgb_matrix([
+                    [
+                        0.2126 + 0.7874 * a,
+                        0.7152 - 0.7152 * a,
+                        0.0722 - 0.0722 * a,
+                    ],
+                    [
+                        0.2126 - 0.2126 * a,
+                        0.7152 + 0.2848 * a,
+                        0.0722 - 0.0722 * a,
+                    ],
+                    [
+                        0.2126 - 0.2126 * a,
+                        0.7152 - 0.7152 * a,
+                        0.0722 + 0.9278 * a,
+                    ],
+                ])
+            }
+            Self::Sepia(amount) => {
+                let a = 1.0 - amount;
+                rgb_matrix([
+                    [0.393 + 0.607 * a, 0.769 - 0.769 * a, 0.189 - 0.189 * a],
+                    [0.349 - 0.349 * a, 0.686 + 0.314 * a, 0.168 - 0.168 * a],
+                    [0.272 - 0.272 * a, 0.534 - 0.534 * a, 0.131 + 0.869 * a],
+                ])
+            }
+            Self::Saturate(s) => rgb_matrix([
+                [0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s],
+                [0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s],
+                [0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s],
+            ]),
+        };
+        Some(matrix)
+    }
+}
+
+/// Scale the RGB channels and add an offset to them, keeping the alpha.
+fn scale_matrix(scale: f32, offset: f32) -> [f32; 20] {
+    [
+        scale, 0.0, 0.0, 0.0, offset, //
+        0.0, scale, 0.0, 0.0, offset, //
+        0.0, 0.0, scale, 0.0, offset, //
+        0.0, 0.0, 0.0, 1.0, 0.0,
+    ]
+}
+
+/// Mix the RGB channels, keeping the alpha.
+fn rgb_matrix([r, g, b]: [[f32; 3]; 3]) -> [f32; 20] {
+    [
+        r[0], r[1], r[2], 0.0, 0.0, //
+        g[0], g[1], g[2], 0.0, 0.0, //
+        b[0], b[1], b[2], 0.0, 0.0, //
+        0.0, 0.0, 0.0, 1.0, 0.0,
+    ]
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseFilterError;
+
+/// Parse a number (`1.2`) or a percentage (`120%`).
+fn parse_amount(value: &str) -> Result<f32, ParseFilterError> {
+    let amount = if let Some(percentage) = value.strip_suffix('%') {
+        percentage.parse::<f32>().map(|amount| amount / 100.0)
+    } else {
+        value.parse::<f32>()
+    };
+    amount
+        .ok()
+        .filter(|amount| *amount >= 0.0)
+        .ok_or(ParseFilterError)
+}
+
+impl Parse for Filter {
+    type Err = ParseFilterError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let (name, arguments) = value
+            .strip_suffix(')')
+            .and_then(|value| value.split_once('('))
+            .ok_or(ParseFilterError)?;
+        let arguments = arguments.trim();
+
+        Ok(match name {
+            "blur" => Self::Blur(
+                arguments
+                    .parse::<f32>()
+                    .map_err(|_| ParseFilterError)?
+                    .max(0.0),
+            ),
+            "drop-shadow" => {
+                let mut arguments = arguments.split_ascii_whitespace_excluding_group('(', ')');
+                let mut length = || {
+                    arguments
+                        .next()
+                        .and_then(|length| length.parse::<f32>().ok())
+                        .ok_or(ParseFilterError)
+                };
+                let x = length()?;
+                let y = length()?;
+                let blur = length()?;
+                let color = match arguments.next() {
+                    Some(color) => Color::parse(color).map_err(|_| ParseFilterError)?,
+                    None => Color::BLACK,
+                };
+                if arguments.next().is_some() {
+                    return Err(ParseFilterError);
+                }
+                Self::DropShadow { x, y, blur, color }
+            }
+            "brightness" => Self::Brightness(parse_amount(arguments)?),
+            "contrast" => Self::Contrast(parse_amount(arguments)?),
+            "grayscale" => Self::Grayscale(parse_amount(arguments)?.min(1.0)),
+            "saturate" => Self::Saturate(parse_amount(arguments)?),
+            "invert" => Self::Invert(parse_amount(arguments)?.min(1.0)),
+            "sepia" => Self::Sepia(parse_amount(arguments)?.min(1.0)),
+            _ => return Err(ParseFilterError),
+        })
+    }
+}
+
+impl Scaled for Filter {
+    fn scale(&mut self, scale_factor: f32) {
+        match self {
+            Self::Blur(blur) => *blur *= scale_factor,
+            Self::DropShadow { x, y, blur, .. } => {
+                *x *= scale_factor;
+                *y *= scale_factor;
+                *blur *= scale_factor;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Space-separated list of [`Filter`]s, the value of the `filter` attribute.
+/// They are applied in order, each one to the output of the previous one.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Filters(pub Vec<Filter>);
+
+impl Parse for Filters {
+    type Err = ParseFilterError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        value
+            .split_ascii_whitespace_excluding_group('(', ')')
+            .map(Filter::parse)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl Scaled for Filters {
+    fn scale(&mut self, scale_factor: f32) {
+        for filter in &mut self.0 {
+            filter.scale(scale_factor);
+        }
+    }
+}
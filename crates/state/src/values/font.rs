@@ -1,3 +1,5 @@
+use std::{borrow::Cow, fmt};
+
 use crate::Parse;
 use freya_engine::prelude::*;
 
@@ -98,7 +100,7 @@ impl Parse for Weight {
     }
 }
 
-#[derive(Default, Clone, Debug, PartialEq)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum TextOverflow {
     #[default]
     Clip,
@@ -138,3 +140,112 @@ impl Parse for TextOverflow {
         })
     }
 }
+
+/// How the white space and line breaks of a text are laid out.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WhiteSpace {
+    /// Collapse the white space and line breaks into single spaces, and wrap the lines.
+    Normal,
+    /// Collapse the white space and line breaks into single spaces, without wrapping the line.
+    NoWrap,
+    /// Keep the white space and line breaks as they are, without wrapping the lines.
+    Pre,
+    /// Keep the white space and line breaks as they are, and wrap the lines.
+    #[default]
+    PreWrap,
+}
+
+impl WhiteSpace {
+    /// Check if the lines wrap when they don't fit in the width of the text.
+    pub fn wraps(&self) -> bool {
+        matches!(self, Self::Normal | Self::PreWrap)
+    }
+
+    /// Check if the sequences of white spaces and line breaks are collapsed into single spaces.
+    pub fn collapses(&self) -> bool {
+        matches!(self, Self::Normal | Self::NoWrap)
+    }
+
+    /// Collapse the white space of the given text if this mode does.
+    /// `after_space` tells if the previous text ended with a space, so a text split in
+    /// multiple spans is collapsed as if it was a single one.
+    pub fn collapse<'a>(&self, text: &'a str, after_space: &mut bool) -> Cow<'a, str> {
+        if !self.collapses() {
+            return Cow::Borrowed(text);
+        }
+
+        let mut collapsed = String::with_capacity(text.len());
+        for character in text.chars() {
+            if character.is_whitespace() {
+                if !*after_space {
+                    collapsed.push(' ');
+                }
+                *after_space = true;
+            } else {
+                collapsed.push(character);
+                *after_space = false;
+            }
+        }
+        Cow::Owned(collapsed)
+    }
+}
+
+impl Parse for WhiteSpace {
+    type Err = ();
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "normal" => Ok(WhiteSpace::Normal),
+            "nowrap" => Ok(WhiteSpace::NoWrap),
+            "pre" => Ok(WhiteSpace::Pre),
+            "pre-wrap" => Ok(WhiteSpace::PreWrap),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for WhiteSpace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WhiteSpace::Normal => "normal",
+            WhiteSpace::NoWrap => "nowrap",
+            WhiteSpace::Pre => "pre",
+            WhiteSpace::PreWrap => "pre-wrap",
+        })
+    }
+}
+
+/// What part of a single line text is vertically centered in its box when it's painted.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextCentering {
+    /// The whole line box, including the space for the ascenders and descenders.
+    #[default]
+    Box,
+    /// The height of the capital letters, above the baseline.
+    CapHeight,
+    /// The height of the lowercase letters, above the baseline.
+    XHeight,
+}
+
+impl Parse for TextCentering {
+    type Err = ();
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "box" => Ok(TextCentering::Box),
+            "cap-height" => Ok(TextCentering::CapHeight),
+            "x-height" => Ok(TextCentering::XHeight),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for TextCentering {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TextCentering::Box => "box",
+            TextCentering::CapHeight => "cap-height",
+            TextCentering::XHeight => "x-height",
+        })
+    }
+}
@@ -4,6 +4,45 @@ use torin::gaps::Gaps;
 #[derive(Debug, PartialEq, Eq)]
 pub struct ParseGapError;
 
+/// Sides of a `padding`, each of them in pixels or in a percentage of the width of the parent, e.g. `"10 5%"`.
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct Padding {
+    pub fixed: Gaps,
+    pub percentage: Gaps,
+}
+
+impl Parse for Padding {
+    type Err = ParseGapError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let values = value.split_ascii_whitespace().collect::<Vec<&str>>();
+
+        // Same shorthands as the fixed gaps, the missing sides take the value of their opposite side
+        let [top, right, bottom, left] = match values[..] {
+            [all] => [all, all, all, all],
+            [vertical, horizontal] => [vertical, horizontal, vertical, horizontal],
+            [top, horizontal, bottom] => [top, horizontal, bottom, horizontal],
+            [top, right, bottom, left] => [top, right, bottom, left],
+            _ => return Err(ParseGapError),
+        };
+
+        let mut fixed = [0.0; 4];
+        let mut percentage = [0.0; 4];
+        for (i, side) in [top, right, bottom, left].into_iter().enumerate() {
+            if let Some(side) = side.strip_suffix('%') {
+                percentage[i] = side.parse::<f32>().map_err(|_| ParseGapError)?;
+            } else {
+                fixed[i] = side.parse::<f32>().map_err(|_| ParseGapError)?;
+            }
+        }
+
+        Ok(Self {
+            fixed: Gaps::new(fixed[0], fixed[1], fixed[2], fixed[3]),
+            percentage: Gaps::new(percentage[0], percentage[1], percentage[2], percentage[3]),
+        })
+    }
+}
+
 impl Parse for Gaps {
     type Err = ParseGapError;
 
@@ -0,0 +1,10 @@
+use crate::{AttributesBytes, Fill};
+
+/// Source of the alpha an element gets masked with.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Mask {
+    /// Alpha of a color or a gradient, spanning the element area.
+    Fill(Fill),
+    /// Alpha of an encoded image, stretched to the element area.
+    Image(AttributesBytes),
+}
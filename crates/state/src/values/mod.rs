@@ -1,33 +1,61 @@
 mod alignment;
+mod animation;
+mod background_clip;
 mod border;
+mod box_sizing;
+mod clip_path;
 mod color;
 mod content;
+mod content_visibility;
 mod corner_radius;
 mod cursor;
 mod decoration;
 mod fill;
+mod filter;
 mod font;
 mod gaps;
 mod gradient;
+mod mask;
 mod overflow;
+mod pointer_events;
 mod position;
+mod render_settings;
+mod shader;
 mod shadow;
 mod size;
+mod text_fill;
 mod text_shadow;
+mod transition;
+mod user_select;
+mod will_change;
 
 pub use alignment::*;
+pub use animation::*;
+pub use background_clip::*;
 pub use border::*;
+pub use box_sizing::*;
+pub use clip_path::*;
 pub use color::*;
 pub use content::*;
+pub use content_visibility::*;
 pub use corner_radius::*;
 pub use cursor::*;
 pub use decoration::*;
 pub use fill::*;
+pub use filter::*;
 pub use font::*;
 pub use gaps::*;
 pub use gradient::*;
+pub use mask::*;
 pub use overflow::*;
+pub use pointer_events::*;
 pub use position::*;
+pub use render_settings::*;
+pub use shader::*;
 pub use shadow::*;
 pub use size::*;
+pub use text_fill::*;
 pub use text_shadow::*;
+pub use transition::*;
+pub use user_select::*;
+pub use will_change::*;
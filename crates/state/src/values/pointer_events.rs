@@ -0,0 +1,41 @@
+use crate::Parse;
+use std::fmt;
+
+/// Whether an element can be the target of pointer events, inherited by its descendants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum PointerEvents {
+    #[default]
+    Auto,
+    /// The element is skipped when hit-testing, so the events reach what is behind it.
+    None,
+}
+
+impl PointerEvents {
+    pub fn is_none(&self) -> bool {
+        *self == Self::None
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParsePointerEventsError;
+
+impl Parse for PointerEvents {
+    type Err = ParsePointerEventsError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "auto" => Ok(PointerEvents::Auto),
+            "none" => Ok(PointerEvents::None),
+            _ => Err(ParsePointerEventsError),
+        }
+    }
+}
+
+impl fmt::Display for PointerEvents {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            PointerEvents::Auto => "auto",
+            PointerEvents::None => "none",
+        })
+    }
+}
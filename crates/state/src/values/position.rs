@@ -10,6 +10,8 @@ impl Parse for Position {
     fn parse(value: &str) -> Result<Self, Self::Err> {
         Ok(match value {
             "absolute" => Position::new_absolute(),
+            "sticky" => Position::new_sticky(),
+            "global" => Position::new_global(),
             _ => Position::Stacked,
         })
     }
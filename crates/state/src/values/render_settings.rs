@@ -0,0 +1,99 @@
+use crate::Parse;
+use std::fmt;
+
+/// How much the outlines of the glyphs are adjusted to fit the pixel grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TextHinting {
+    /// Keep the original outlines, like macOS does.
+    None,
+    /// Slightly adjust the outlines vertically, sharper at low resolutions.
+    Slight,
+}
+
+impl Default for TextHinting {
+    fn default() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::None
+        } else {
+            Self::Slight
+        }
+    }
+}
+
+impl Parse for TextHinting {
+    type Err = ();
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(TextHinting::None),
+            "slight" => Ok(TextHinting::Slight),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for TextHinting {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            TextHinting::None => "none",
+            TextHinting::Slight => "slight",
+        })
+    }
+}
+
+/// macOS already blends the glyphs in a way its users expect, so no extra contrast is added there.
+fn default_text_contrast() -> f32 {
+    if cfg!(target_os = "macos") {
+        0.0
+    } else {
+        0.2
+    }
+}
+
+fn default_text_gamma() -> f32 {
+    if cfg!(target_os = "macos") {
+        0.0
+    } else {
+        1.2
+    }
+}
+
+/// Global rendering settings, used by the elements that don't override them
+/// with the `antialias`, `pixel_snapping`, `text_hinting` and `text_subpixel` attributes.
+/// The text contrast, gamma and multisampling apply to the whole Window, so they can't be overridden.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderSettings {
+    /// Smooth the edges of shapes, borders, images and SVGs.
+    pub antialias: bool,
+    /// Hinting of the text glyphs.
+    pub text_hinting: TextHinting,
+    /// Paint texts at fractional pixel positions instead of snapping them to the pixel grid.
+    /// Disabling it can make texts sharper at fractional scale factors.
+    pub text_subpixel: bool,
+    /// Round the areas of the elements to the physical pixel grid before rendering them,
+    /// so thin borders stay crisp at fractional scale factors. Disabled by default.
+    pub pixel_snapping: bool,
+    /// Extra contrast given to the antialiased edges of the glyphs, from `0.0` to `1.0`.
+    /// Raising it makes light text on dark backgrounds look less thin.
+    pub text_contrast: f32,
+    /// Gamma applied to the antialiased edges of the glyphs, `0.0` to blend them in sRGB.
+    pub text_gamma: f32,
+    /// Samples per pixel used to render the Window, e.g. `4`, smoothing the edges of curved shapes and clips
+    /// even more than `antialias` does. It's only a request, the closest amount supported by the GPU is used.
+    /// Disabled by default, as it uses more GPU memory.
+    pub multisampling: u8,
+}
+
+impl Default for RenderSettings {
+    fn default() -> Self {
+        Self {
+            antialias: true,
+            text_hinting: TextHinting::default(),
+            text_subpixel: true,
+            pixel_snapping: false,
+            text_contrast: default_text_contrast(),
+            text_gamma: default_text_gamma(),
+            multisampling: 0,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::Parse;
+use std::fmt;
+
+/// Where the shader of an element is painted.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub enum ShaderPosition {
+    /// Painted as the background of the element.
+    #[default]
+    Background,
+    /// Painted over the element and all its descendants.
+    Foreground,
+}
+
+impl Parse for ShaderPosition {
+    type Err = ();
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "foreground" => ShaderPosition::Foreground,
+            _ => ShaderPosition::Background,
+        })
+    }
+}
+
+impl fmt::Display for ShaderPosition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ShaderPosition::Background => "background",
+            ShaderPosition::Foreground => "foreground",
+        })
+    }
+}
@@ -0,0 +1,10 @@
+use crate::{AttributesBytes, Fill};
+
+/// What the glyphs of a text element are painted with, instead of their color.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextFill {
+    /// Color or gradient, spanning the element area.
+    Fill(Fill),
+    /// Encoded image, stretched to the element area.
+    Image(AttributesBytes),
+}
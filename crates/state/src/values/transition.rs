@@ -0,0 +1,201 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use freya_engine::prelude::Color;
+use freya_native_core::prelude::AttributeName;
+
+use crate::Parse;
+
+/// Curve used to ease a [`Transition`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum TransitionEasing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+}
+
+impl TransitionEasing {
+    /// Map the linear `progress` (from `0.0` to `1.0`) of a transition through this curve.
+    pub fn apply(&self, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => progress,
+            Self::EaseIn => progress * progress,
+            Self::EaseOut => 1.0 - (1.0 - progress) * (1.0 - progress),
+            Self::EaseInOut => {
+                if progress < 0.5 {
+                    2.0 * progress * progress
+                } else {
+                    1.0 - (-2.0 * progress + 2.0).powi(2) / 2.0
+                }
+            }
+        }
+    }
+}
+
+impl Parse for TransitionEasing {
+    type Err = ParseTransitionError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value {
+            "linear" => Self::Linear,
+            "ease-in" => Self::EaseIn,
+            "ease-out" => Self::EaseOut,
+            "ease-in-out" => Self::EaseInOut,
+            _ => return Err(ParseTransitionError),
+        })
+    }
+}
+
+/// Transition of a single attribute, e.g. `background 200ms ease-out`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transition {
+    pub attribute: AttributeName,
+    pub duration: Duration,
+    pub easing: TransitionEasing,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseTransitionError;
+
+impl Parse for Transition {
+    type Err = ParseTransitionError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.split_ascii_whitespace();
+
+        let attribute = parts
+            .next()
+            .and_then(|attribute| AttributeName::from_str(attribute).ok())
+            .filter(is_interpolatable)
+            .ok_or(ParseTransitionError)?;
+
+        let duration = parts
+            .next()
+            .and_then(parse_time)
+            .ok_or(ParseTransitionError)?;
+
+        let easing = parts
+            .next()
+            .map(TransitionEasing::parse)
+            .transpose()?
+            .unwrap_or_default();
+
+        // There should not be more than 3 components.
+        if parts.next().is_some() {
+            return Err(ParseTransitionError);
+        }
+
+        Ok(Self {
+            attribute,
+            duration,
+            easing,
+        })
+    }
+}
+
+/// Parse a time in milliseconds (`200ms`) or seconds (`1.5s`).
+pub(crate) fn parse_time(value: &str) -> Option<Duration> {
+    if let Some(millis) = value.strip_suffix("ms") {
+        millis.parse::<u64>().ok().map(Duration::from_millis)
+    } else if let Some(secs) = value.strip_suffix('s') {
+        secs.parse::<f32>()
+            .ok()
+            .filter(|secs| *secs >= 0.0)
+            .map(Duration::from_secs_f32)
+    } else {
+        None
+    }
+}
+
+/// Comma-separated list of [`Transition`]s, the value of the `transition` attribute.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Transitions(pub Vec<Transition>);
+
+impl Transitions {
+    /// Get the transition of the given attribute, if any.
+    pub fn get(&self, attribute: &AttributeName) -> Option<&Transition> {
+        self.0
+            .iter()
+            .find(|transition| transition.attribute == *attribute)
+    }
+}
+
+impl Parse for Transitions {
+    type Err = ParseTransitionError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        value
+            .split(',')
+            .map(|transition| Transition::parse(transition.trim()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+/// Check if the values of the given attribute can be interpolated by a [`Transition`].
+pub fn is_interpolatable(attribute: &AttributeName) -> bool {
+    matches!(
+        attribute,
+        AttributeName::Background
+            | AttributeName::Color
+            | AttributeName::Opacity
+            | AttributeName::CornerRadius
+            | AttributeName::FontSize
+            | AttributeName::Rotate
+            | AttributeName::Width
+            | AttributeName::Height
+            | AttributeName::MinWidth
+            | AttributeName::MinHeight
+            | AttributeName::MaxWidth
+            | AttributeName::MaxHeight
+    )
+}
+
+/// Get the value of `attribute` at the given eased `progress` between `from` and `to`.
+/// Returns `None` if any of the values can't be interpolated, e.g. a gradient background or a `fill` width,
+/// in which case the attribute should jump to its new value.
+pub fn interpolate_attribute(
+    attribute: &AttributeName,
+    from: &str,
+    to: &str,
+    progress: f32,
+) -> Option<String> {
+    let lerp = |from: f32, to: f32| from + (to - from) * progress;
+
+    match attribute {
+        AttributeName::Background | AttributeName::Color => {
+            let from = Color::parse(from).ok()?;
+            let to = Color::parse(to).ok()?;
+            let channel = |from: u8, to: u8| lerp(from as f32, to as f32).round() as u8;
+            Some(format!(
+                "rgb({}, {}, {}, {})",
+                channel(from.r(), to.r()),
+                channel(from.g(), to.g()),
+                channel(from.b(), to.b()),
+                channel(from.a(), to.a())
+            ))
+        }
+        AttributeName::Rotate => {
+            let from = from.strip_suffix("deg")?.parse::<f32>().ok()?;
+            let to = to.strip_suffix("deg")?.parse::<f32>().ok()?;
+            Some(format!("{}deg", lerp(from, to)))
+        }
+        AttributeName::Opacity
+        | AttributeName::CornerRadius
+        | AttributeName::FontSize
+        | AttributeName::Width
+        | AttributeName::Height
+        | AttributeName::MinWidth
+        | AttributeName::MinHeight
+        | AttributeName::MaxWidth
+        | AttributeName::MaxHeight => {
+            let from = from.parse::<f32>().ok()?;
+            let to = to.parse::<f32>().ok()?;
+            Some(lerp(from, to).to_string())
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,40 @@
+use crate::Parse;
+use std::fmt;
+
+/// Whether the text of an element can be selected with the mouse, inherited by its descendants.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum UserSelect {
+    /// The text can be selected character by character.
+    #[default]
+    Text,
+    /// The text can't be selected, dragging over it neither starts nor extends a selection.
+    None,
+    /// The whole text is selected at once.
+    All,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseUserSelectError;
+
+impl Parse for UserSelect {
+    type Err = ParseUserSelectError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "text" => Ok(UserSelect::Text),
+            "none" => Ok(UserSelect::None),
+            "all" => Ok(UserSelect::All),
+            _ => Err(ParseUserSelectError),
+        }
+    }
+}
+
+impl fmt::Display for UserSelect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            UserSelect::Text => "text",
+            UserSelect::None => "none",
+            UserSelect::All => "all",
+        })
+    }
+}
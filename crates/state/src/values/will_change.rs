@@ -0,0 +1,52 @@
+use crate::Parse;
+use std::fmt;
+
+/// Effects of an element that are expected to change often, so its subtree is cached in an image
+/// and they are only applied when painting it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct WillChange {
+    /// The rotation of the element.
+    pub transform: bool,
+    /// The opacity of the element.
+    pub opacity: bool,
+}
+
+impl WillChange {
+    /// Check if any effect is expected to change.
+    pub fn is_any(&self) -> bool {
+        self.transform || self.opacity
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseWillChangeError;
+
+impl Parse for WillChange {
+    type Err = ParseWillChangeError;
+
+    fn parse(value: &str) -> Result<Self, Self::Err> {
+        let mut will_change = WillChange::default();
+
+        for effect in value.split(',').map(str::trim) {
+            match effect {
+                "transform" => will_change.transform = true,
+                "opacity" => will_change.opacity = true,
+                "auto" => {}
+                _ => return Err(ParseWillChangeError),
+            }
+        }
+
+        Ok(will_change)
+    }
+}
+
+impl fmt::Display for WillChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.transform, self.opacity) {
+            (true, true) => f.write_str("transform, opacity"),
+            (true, false) => f.write_str("transform"),
+            (false, true) => f.write_str("opacity"),
+            (false, false) => f.write_str("auto"),
+        }
+    }
+}
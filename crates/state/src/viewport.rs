@@ -7,13 +7,25 @@ use freya_native_core::{
 };
 use freya_native_core_macro::partial_derive_state;
 
-use crate::{CustomAttributeValues, OverflowMode, Parse};
+use torin::{position::Position, scaled::Scaled};
+
+use crate::{
+    transform::parse_rotate_degs, ClipPath, CustomAttributeValues, OverflowMode, Parse,
+    PointerEvents,
+};
 
 #[derive(Default, PartialEq, Clone, Debug, Component)]
 pub struct ViewportState {
     pub viewports: Vec<NodeId>,
     pub node_id: NodeId,
     pub overflow: OverflowMode,
+    /// Resolved from the `pointer_events` of the element or its closest ancestor that sets it.
+    pub pointer_events: PointerEvents,
+    /// Shapes from the `clip_path` of the element and its ancestors, along with the elements that define them.
+    pub clip_paths: Vec<(NodeId, ClipPath)>,
+    /// Degrees of the `rotate` of the element and its ancestors, along with the elements that define them,
+    /// from the outermost one. Unlike the clipping, global elements are rotated with their ancestors too.
+    pub rotations: Vec<(NodeId, f32)>,
 }
 
 #[partial_derive_state]
@@ -25,7 +37,13 @@ impl State<CustomAttributeValues> for ViewportState {
     type NodeDependencies = ();
 
     const NODE_MASK: NodeMaskBuilder<'static> = NodeMaskBuilder::new()
-        .with_attrs(AttributeMaskBuilder::Some(&[AttributeName::Overflow]))
+        .with_attrs(AttributeMaskBuilder::Some(&[
+            AttributeName::Overflow,
+            AttributeName::Position,
+            AttributeName::PointerEvents,
+            AttributeName::ClipPath,
+            AttributeName::Rotate,
+        ]))
         .with_tag();
 
     fn update<'a>(
@@ -34,20 +52,25 @@ impl State<CustomAttributeValues> for ViewportState {
         _node: <Self::NodeDependencies as Dependancy>::ElementBorrowed<'a>,
         parent: Option<<Self::ParentDependencies as Dependancy>::ElementBorrowed<'a>>,
         _children: Vec<<Self::ChildDependencies as Dependancy>::ElementBorrowed<'a>>,
-        _context: &SendAnyMap,
+        context: &SendAnyMap,
     ) -> bool {
         if !node_view.node_type().is_visible_element() {
             return false;
         }
 
+        let scale_factor = context.get::<f32>().unwrap();
+
         let mut viewports_state = ViewportState {
             node_id: node_view.node_id(),
             ..Default::default()
         };
+        let mut is_global = false;
+        let mut pointer_events = None;
+        let mut clip_path = None;
+        let mut rotate_degs = None;
 
         if let Some(attributes) = node_view.attributes() {
             for attr in attributes {
-                #[allow(clippy::single_match)]
                 match attr.attribute {
                     AttributeName::Overflow => {
                         if let Some(value) = attr.value.as_text() {
@@ -56,16 +79,63 @@ impl State<CustomAttributeValues> for ViewportState {
                             }
                         }
                     }
+                    AttributeName::Position => {
+                        if let Some(value) = attr.value.as_text() {
+                            is_global = Position::parse(value)
+                                .map(|position| position.is_global())
+                                .unwrap_or_default();
+                        }
+                    }
+                    AttributeName::PointerEvents => {
+                        if let Some(value) = attr.value.as_text() {
+                            pointer_events = PointerEvents::parse(value).ok();
+                        }
+                    }
+                    AttributeName::ClipPath => {
+                        if let Some(value) = attr.value.as_text() {
+                            if let Ok(mut value) = ClipPath::parse(value) {
+                                value.scale(*scale_factor);
+                                clip_path = Some(value);
+                            }
+                        }
+                    }
+                    AttributeName::Rotate => {
+                        if let Some(value) = attr.value.as_text() {
+                            rotate_degs = parse_rotate_degs(value);
+                        }
+                    }
                     _ => {}
                 }
             }
         }
 
-        if let Some((parent,)) = parent {
+        viewports_state.pointer_events = pointer_events
+            .or_else(|| parent.as_ref().map(|(parent,)| parent.pointer_events))
+            .unwrap_or_default();
+
+        if let Some((parent,)) = &parent {
+            viewports_state.rotations.extend(parent.rotations.clone());
+        }
+
+        if let Some(rotate_degs) = rotate_degs {
+            viewports_state
+                .rotations
+                .push((viewports_state.node_id, rotate_degs));
+        }
+
+        // Global Nodes are not clipped by their ancestors
+        if let Some((parent,)) = parent.filter(|_| !is_global) {
             viewports_state.viewports.extend(parent.viewports.clone());
             if parent.overflow == OverflowMode::Clip {
                 viewports_state.viewports.push(parent.node_id);
             }
+            viewports_state.clip_paths.extend(parent.clip_paths.clone());
+        }
+
+        if let Some(clip_path) = clip_path {
+            viewports_state
+                .clip_paths
+                .push((viewports_state.node_id, clip_path));
         }
 
         let changed = &viewports_state != self;
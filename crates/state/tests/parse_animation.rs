@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use freya_native_core::prelude::AttributeName;
+use freya_node_state::{
+    Animation, AnimationDirection, AnimationFillMode, AnimationIterations, AnimationProgress,
+    Keyframes, Parse, TransitionEasing,
+};
+
+#[test]
+fn parse_animation() {
+    let animation = Animation::parse("pulse 1s 200ms infinite alternate both ease-in paused");
+    assert_eq!(
+        animation,
+        Ok(Animation {
+            name: "pulse".to_string(),
+            duration: Duration::from_secs(1),
+            delay: Duration::from_millis(200),
+            iterations: AnimationIterations::Infinite,
+            direction: AnimationDirection::Alternate,
+            fill_mode: AnimationFillMode::Both,
+            easing: TransitionEasing::EaseIn,
+            paused: true,
+        })
+    );
+}
+
+#[test]
+fn parse_invalid_animation() {
+    // Missing duration
+    assert!(Animation::parse("pulse").is_err());
+    // Unknown keyword
+    assert!(Animation::parse("pulse 1s sideways").is_err());
+}
+
+#[test]
+fn animation_progress() {
+    let animation = Animation::parse("pulse 100ms 2 alternate").unwrap();
+    assert_eq!(
+        animation.progress(Duration::from_millis(25)),
+        AnimationProgress::Running(0.25)
+    );
+    // The second iteration is reversed
+    assert_eq!(
+        animation.progress(Duration::from_millis(125)),
+        AnimationProgress::Running(0.75)
+    );
+    assert_eq!(
+        animation.progress(Duration::from_millis(300)),
+        AnimationProgress::Finished(0.0)
+    );
+
+    let animation = Animation::parse("pulse 100ms 50ms").unwrap();
+    assert_eq!(
+        animation.progress(Duration::from_millis(20)),
+        AnimationProgress::Delayed
+    );
+}
+
+#[test]
+fn sample_keyframes() {
+    let keyframes = Keyframes::new()
+        .keyframe(1.0, [("width", "200"), ("direction", "horizontal")])
+        .keyframe(0.0, [("width", "100"), ("direction", "vertical")])
+        .keyframe(0.5, [("width", "300")]);
+
+    assert_eq!(
+        keyframes.attributes(),
+        vec![AttributeName::Width, AttributeName::Direction]
+    );
+    assert_eq!(
+        keyframes.sample(&AttributeName::Width, 0.25),
+        Some("200".to_string())
+    );
+    assert_eq!(
+        keyframes.sample(&AttributeName::Width, 0.75),
+        Some("250".to_string())
+    );
+    // Not interpolatable attributes change in steps
+    assert_eq!(
+        keyframes.sample(&AttributeName::Direction, 0.75),
+        Some("vertical".to_string())
+    );
+    assert_eq!(
+        keyframes.sample(&AttributeName::Direction, 1.0),
+        Some("horizontal".to_string())
+    );
+}
+
+#[test]
+fn long_running_animation_does_not_drift() {
+    let animation = Animation::parse("spin 1s linear infinite").unwrap();
+    let ten_minutes = Duration::from_secs(10 * 60);
+
+    for offset in [0, 250, 500, 999] {
+        let offset = Duration::from_millis(offset);
+        assert_eq!(
+            animation.progress(ten_minutes + offset),
+            animation.progress(offset)
+        );
+    }
+
+    let keyframes = Keyframes::new()
+        .keyframe(0.0, [("rotate", "0deg")])
+        .keyframe(1.0, [("rotate", "360deg")]);
+    let AnimationProgress::Running(progress) =
+        animation.progress(ten_minutes + Duration::from_millis(250))
+    else {
+        panic!("The animation should still be running");
+    };
+    assert_eq!(
+        keyframes.sample(&AttributeName::Rotate, progress),
+        Some("90deg".to_string())
+    );
+}
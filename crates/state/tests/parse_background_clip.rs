@@ -0,0 +1,25 @@
+use freya_node_state::{BackgroundClip, Parse};
+
+#[test]
+fn parse_border_box_background_clip() {
+    let clip = BackgroundClip::parse("border-box");
+    assert_eq!(clip, Ok(BackgroundClip::BorderBox));
+}
+
+#[test]
+fn parse_padding_box_background_clip() {
+    let clip = BackgroundClip::parse("padding-box");
+    assert_eq!(clip, Ok(BackgroundClip::PaddingBox));
+}
+
+#[test]
+fn parse_content_box_background_clip() {
+    let clip = BackgroundClip::parse("content-box");
+    assert_eq!(clip, Ok(BackgroundClip::ContentBox));
+}
+
+#[test]
+fn parse_fallback_background_clip() {
+    let clip = BackgroundClip::parse("margin-box");
+    assert_eq!(clip, Ok(BackgroundClip::BorderBox));
+}
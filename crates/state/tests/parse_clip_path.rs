@@ -0,0 +1,55 @@
+use freya_node_state::{ClipLength, ClipPath, Parse};
+
+#[test]
+fn parse_circle_clip_path() {
+    assert_eq!(
+        ClipPath::parse("circle()"),
+        Ok(ClipPath::Circle(ClipLength::Percentage(50.0)))
+    );
+    assert_eq!(
+        ClipPath::parse("circle(25%)"),
+        Ok(ClipPath::Circle(ClipLength::Percentage(25.0)))
+    );
+    assert_eq!(
+        ClipPath::parse("circle(40)"),
+        Ok(ClipPath::Circle(ClipLength::Pixels(40.0)))
+    );
+}
+
+#[test]
+fn parse_ellipse_clip_path() {
+    assert_eq!(ClipPath::parse("ellipse()"), Ok(ClipPath::Ellipse));
+}
+
+#[test]
+fn parse_polygon_clip_path() {
+    assert_eq!(
+        ClipPath::parse("polygon(50% 0%, 100 100, 0% 100%)"),
+        Ok(ClipPath::Polygon(vec![
+            (ClipLength::Percentage(50.0), ClipLength::Percentage(0.0)),
+            (ClipLength::Pixels(100.0), ClipLength::Pixels(100.0)),
+            (ClipLength::Percentage(0.0), ClipLength::Percentage(100.0)),
+        ]))
+    );
+}
+
+#[test]
+fn parse_path_clip_path() {
+    assert_eq!(
+        ClipPath::parse("path(M 0 0 L 100 0 L 50 100 Z)"),
+        Ok(ClipPath::Path {
+            data: "M 0 0 L 100 0 L 50 100 Z".to_string(),
+            scale: 1.0
+        })
+    );
+}
+
+#[test]
+fn parse_invalid_clip_paths() {
+    assert!(ClipPath::parse("circle").is_err());
+    assert!(ClipPath::parse("circle(big)").is_err());
+    assert!(ClipPath::parse("ellipse(10)").is_err());
+    assert!(ClipPath::parse("polygon(10 10 10, 20 20)").is_err());
+    assert!(ClipPath::parse("path()").is_err());
+    assert!(ClipPath::parse("star(5)").is_err());
+}
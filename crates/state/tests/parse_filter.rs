@@ -0,0 +1,69 @@
+use freya_engine::prelude::*;
+use freya_node_state::{Filter, Filters, Parse};
+
+#[test]
+fn parse_filters() {
+    let filters = Filters::parse("blur(4) drop-shadow(0 2 4 red) brightness(120%)");
+    assert_eq!(
+        filters,
+        Ok(Filters(vec![
+            Filter::Blur(4.0),
+            Filter::DropShadow {
+                x: 0.0,
+                y: 2.0,
+                blur: 4.0,
+                color: Color::RED
+            },
+            Filter::Brightness(1.2),
+        ]))
+    );
+}
+
+#[test]
+fn parse_drop_shadow_without_color() {
+    let filter = Filter::parse("drop-shadow(1 2 3)");
+    assert_eq!(
+        filter,
+        Ok(Filter::DropShadow {
+            x: 1.0,
+            y: 2.0,
+            blur: 3.0,
+            color: Color::BLACK
+        })
+    );
+}
+
+#[test]
+fn parse_clamped_amounts() {
+    assert_eq!(Filter::parse("grayscale(2)"), Ok(Filter::Grayscale(1.0)));
+    assert_eq!(Filter::parse("invert(50%)"), Ok(Filter::Invert(0.5)));
+    assert_eq!(Filter::parse("saturate(3)"), Ok(Filter::Saturate(3.0)));
+}
+
+#[test]
+fn invalid_filters() {
+    assert!(Filters::parse("blur(4) glow(2)").is_err());
+    assert!(Filter::parse("brightness(-1)").is_err());
+    assert!(Filter::parse("drop-shadow(1 2)").is_err());
+    assert!(Filter::parse("blur 4").is_err());
+}
+
+#[test]
+fn color_matrices() {
+    assert_eq!(Filter::Blur(2.0).color_matrix(), None);
+
+    // Untouched colors
+    let identity = [
+        1.0, 0.0, 0.0, 0.0, 0.0, //
+        0.0, 1.0, 0.0, 0.0, 0.0, //
+        0.0, 0.0, 1.0, 0.0, 0.0, //
+        0.0, 0.0, 0.0, 1.0, 0.0,
+    ];
+    assert_eq!(Filter::Brightness(1.0).color_matrix(), Some(identity));
+    assert_eq!(Filter::Contrast(1.0).color_matrix(), Some(identity));
+    assert_eq!(Filter::Invert(0.0).color_matrix(), Some(identity));
+
+    let invert = Filter::Invert(1.0).color_matrix().unwrap();
+    assert_eq!(invert[0], -1.0);
+    assert_eq!(invert[4], 1.0);
+}
@@ -1,4 +1,4 @@
-use freya_node_state::Parse;
+use freya_node_state::{Padding, Parse};
 use torin::gaps::Gaps;
 
 #[test]
@@ -24,3 +24,27 @@ fn parse_horizontal_axis_and_vertical_sides() {
     let gaps = Gaps::parse("5 50 30");
     assert_eq!(gaps, Ok(Gaps::new(5.0, 50.0, 30.0, 50.0)));
 }
+
+#[test]
+fn parse_padding() {
+    let padding = Padding::parse("10 5%");
+    assert_eq!(
+        padding,
+        Ok(Padding {
+            fixed: Gaps::new(10.0, 0.0, 10.0, 0.0),
+            percentage: Gaps::new(0.0, 5.0, 0.0, 5.0),
+        })
+    );
+
+    let padding = Padding::parse("1 2% 3 4.5%");
+    assert_eq!(
+        padding,
+        Ok(Padding {
+            fixed: Gaps::new(1.0, 0.0, 3.0, 0.0),
+            percentage: Gaps::new(0.0, 2.0, 0.0, 4.5),
+        })
+    );
+
+    assert!(Padding::parse("1 2 3 4 5").is_err());
+    assert!(Padding::parse("10px").is_err());
+}
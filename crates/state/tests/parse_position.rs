@@ -0,0 +1,26 @@
+use freya_node_state::Parse;
+use torin::position::Position;
+
+#[test]
+fn parse_absolute_position() {
+    let position = Position::parse("absolute");
+    assert_eq!(position, Ok(Position::new_absolute()));
+}
+
+#[test]
+fn parse_sticky_position() {
+    let position = Position::parse("sticky");
+    assert_eq!(position, Ok(Position::new_sticky()));
+}
+
+#[test]
+fn parse_global_position() {
+    let position = Position::parse("global");
+    assert_eq!(position, Ok(Position::new_global()));
+}
+
+#[test]
+fn parse_fallback_position() {
+    let position = Position::parse("Hello, World!");
+    assert_eq!(position, Ok(Position::Stacked));
+}
@@ -0,0 +1,19 @@
+use freya_node_state::{Parse, ShaderPosition};
+
+#[test]
+fn parse_background_shader_position() {
+    let position = ShaderPosition::parse("background");
+    assert_eq!(position, Ok(ShaderPosition::Background));
+}
+
+#[test]
+fn parse_foreground_shader_position() {
+    let position = ShaderPosition::parse("foreground");
+    assert_eq!(position, Ok(ShaderPosition::Foreground));
+}
+
+#[test]
+fn parse_fallback_shader_position() {
+    let position = ShaderPosition::parse("sideground");
+    assert_eq!(position, Ok(ShaderPosition::Background));
+}
@@ -0,0 +1,17 @@
+use freya_node_state::{Parse, TextCentering};
+
+#[test]
+fn parse_text_centering() {
+    assert_eq!(TextCentering::parse("box"), Ok(TextCentering::Box));
+    assert_eq!(
+        TextCentering::parse("cap-height"),
+        Ok(TextCentering::CapHeight)
+    );
+    assert_eq!(TextCentering::parse("x-height"), Ok(TextCentering::XHeight));
+}
+
+#[test]
+fn parse_invalid_text_centering() {
+    let text_centering = TextCentering::parse("baseline");
+    assert_eq!(text_centering, Err(()));
+}
@@ -0,0 +1,19 @@
+use freya_node_state::{Parse, TextHinting};
+
+#[test]
+fn parse_none_text_hinting() {
+    let hinting = TextHinting::parse("none");
+    assert_eq!(hinting, Ok(TextHinting::None));
+}
+
+#[test]
+fn parse_slight_text_hinting() {
+    let hinting = TextHinting::parse("slight");
+    assert_eq!(hinting, Ok(TextHinting::Slight));
+}
+
+#[test]
+fn parse_invalid_text_hinting() {
+    let hinting = TextHinting::parse("full");
+    assert_eq!(hinting, Err(()));
+}
@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+use freya_native_core::prelude::AttributeName;
+use freya_node_state::{interpolate_attribute, Parse, Transition, TransitionEasing, Transitions};
+
+#[test]
+fn parse_transitions() {
+    let transitions = Transitions::parse("background 200ms ease-out, opacity 1.5s");
+    assert_eq!(
+        transitions,
+        Ok(Transitions(vec![
+            Transition {
+                attribute: AttributeName::Background,
+                duration: Duration::from_millis(200),
+                easing: TransitionEasing::EaseOut,
+            },
+            Transition {
+                attribute: AttributeName::Opacity,
+                duration: Duration::from_millis(1500),
+                easing: TransitionEasing::Linear,
+            }
+        ]))
+    );
+}
+
+#[test]
+fn parse_invalid_transitions() {
+    // Not interpolatable
+    assert!(Transitions::parse("padding 200ms").is_err());
+    // Missing unit
+    assert!(Transitions::parse("width 200").is_err());
+    // Unknown easing
+    assert!(Transitions::parse("width 200ms bounce").is_err());
+}
+
+#[test]
+fn interpolate_numeric_attributes() {
+    assert_eq!(
+        interpolate_attribute(&AttributeName::Width, "100", "200", 0.25),
+        Some("125".to_string())
+    );
+    assert_eq!(
+        interpolate_attribute(&AttributeName::Rotate, "0deg", "90deg", 0.5),
+        Some("45deg".to_string())
+    );
+    assert_eq!(
+        interpolate_attribute(&AttributeName::Width, "100", "fill", 0.5),
+        None
+    );
+}
+
+#[test]
+fn ease_transitions() {
+    assert_eq!(TransitionEasing::Linear.apply(0.5), 0.5);
+    assert_eq!(TransitionEasing::EaseIn.apply(0.5), 0.25);
+    assert_eq!(TransitionEasing::EaseOut.apply(0.5), 0.75);
+    assert_eq!(TransitionEasing::EaseInOut.apply(0.5), 0.5);
+    assert_eq!(TransitionEasing::EaseInOut.apply(1.0), 1.0);
+}
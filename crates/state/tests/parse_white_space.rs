@@ -0,0 +1,47 @@
+use freya_node_state::{Parse, WhiteSpace};
+
+#[test]
+fn parse_white_space() {
+    assert_eq!(WhiteSpace::parse("normal"), Ok(WhiteSpace::Normal));
+    assert_eq!(WhiteSpace::parse("nowrap"), Ok(WhiteSpace::NoWrap));
+    assert_eq!(WhiteSpace::parse("pre"), Ok(WhiteSpace::Pre));
+    assert_eq!(WhiteSpace::parse("pre-wrap"), Ok(WhiteSpace::PreWrap));
+}
+
+#[test]
+fn parse_invalid_white_space() {
+    let white_space = WhiteSpace::parse("break-spaces");
+    assert_eq!(white_space, Err(()));
+}
+
+#[test]
+fn white_space_wrapping() {
+    assert!(WhiteSpace::Normal.wraps());
+    assert!(!WhiteSpace::NoWrap.wraps());
+    assert!(!WhiteSpace::Pre.wraps());
+    assert!(WhiteSpace::PreWrap.wraps());
+}
+
+#[test]
+fn collapse_white_space() {
+    for white_space in [WhiteSpace::Normal, WhiteSpace::NoWrap] {
+        let mut after_space = true;
+        assert_eq!(
+            white_space.collapse("  Hello \n\t  world ", &mut after_space),
+            "Hello world "
+        );
+        // The spaces at the start of the next span are collapsed with the ones of the previous span
+        assert_eq!(white_space.collapse("  !", &mut after_space), "!");
+    }
+}
+
+#[test]
+fn preserve_white_space() {
+    for white_space in [WhiteSpace::Pre, WhiteSpace::PreWrap] {
+        let mut after_space = true;
+        assert_eq!(
+            white_space.collapse("  Hello \n\t  world ", &mut after_space),
+            "  Hello \n\t  world "
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use freya_node_state::{Parse, WillChange};
+
+#[test]
+fn parse_transform_will_change() {
+    let will_change = WillChange::parse("transform");
+    assert_eq!(
+        will_change,
+        Ok(WillChange {
+            transform: true,
+            opacity: false
+        })
+    );
+}
+
+#[test]
+fn parse_multiple_will_change() {
+    let will_change = WillChange::parse("opacity, transform");
+    assert_eq!(
+        will_change,
+        Ok(WillChange {
+            transform: true,
+            opacity: true
+        })
+    );
+}
+
+#[test]
+fn parse_auto_will_change() {
+    let will_change = WillChange::parse("auto");
+    assert_eq!(will_change, Ok(WillChange::default()));
+}
+
+#[test]
+fn parse_invalid_will_change() {
+    let will_change = WillChange::parse("transform, width");
+    assert!(will_change.is_err());
+}
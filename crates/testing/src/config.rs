@@ -1,13 +1,28 @@
 use std::time::Duration;
 
+use freya_hooks::WindowInsets;
+use freya_node_state::{CustomElements, RenderSettings};
 use torin::geometry::Size2D;
 
 /// Configuration for [`crate::test_handler::TestingHandler`].
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct TestingConfig {
     pub vdom_timeout: Duration,
+    /// Size of the simulated canvas in logical pixels.
     pub size: Size2D,
     pub event_loop_ticker: bool,
+    /// Scale factor of the simulated Window.
+    pub scale_factor: f64,
+    /// Insets of the simulated Window.
+    pub window_insets: WindowInsets,
+    /// Global antialiasing and text rendering settings.
+    pub render_settings: RenderSettings,
+    /// Fixed logical resolution at which the app is laid out, scaled to fit the simulated canvas.
+    pub fixed_resolution: Option<Size2D>,
+    /// Reduce the motion of the transitions and animations, like the platform setting does.
+    pub reduced_motion: bool,
+    /// Custom elements available to the `custom_element` attribute.
+    pub custom_elements: CustomElements,
 }
 
 impl Default for TestingConfig {
@@ -16,6 +31,12 @@ impl Default for TestingConfig {
             vdom_timeout: Duration::from_millis(16),
             size: Size2D::from((500.0, 500.0)),
             event_loop_ticker: true,
+            scale_factor: 1.0,
+            window_insets: WindowInsets::default(),
+            render_settings: RenderSettings::default(),
+            fixed_resolution: None,
+            reduced_motion: false,
+            custom_elements: CustomElements::default(),
         }
     }
 }
@@ -30,6 +51,11 @@ impl TestingConfig {
         self.size
     }
 
+    /// Get the scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
     /// Get the VirtualDOM polling timeout.
     pub fn vdom_timeout(&self) -> Duration {
         self.vdom_timeout
@@ -6,7 +6,10 @@ use freya_common::EventMessage;
 use freya_components::KeyboardNavigator;
 use freya_core::prelude::*;
 use freya_engine::prelude::*;
-use freya_hooks::PlatformInformation;
+use freya_hooks::{
+    CloseRequestNotifier, KeyboardStateNotifier, LayoutNotifier, PlatformInformation,
+    PointerNodesNotifier, WindowInsetsNotifier, WindowSizeNotifier,
+};
 use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc::unbounded_channel;
 use tokio::sync::{broadcast, watch};
@@ -26,33 +29,54 @@ pub fn launch_test(root: AppComponent) -> TestingHandler {
 /// Run a Component in a headless testing environment
 pub fn launch_test_with_config(root: AppComponent, config: TestingConfig) -> TestingHandler {
     let vdom = with_accessibility(root);
-    let fdom = FreyaDOM::default();
+    let mut fdom = FreyaDOM::default();
+    fdom.set_render_settings(config.render_settings);
+    fdom.set_custom_elements(config.custom_elements.clone());
+    fdom.animation_clock()
+        .set_reduced_motion(config.reduced_motion);
     let sdom = SafeDOM::new(fdom);
 
     let (event_emitter, event_receiver) = unbounded_channel::<DomEvent>();
     let (platform_event_emitter, platform_event_receiver) = unbounded_channel::<EventMessage>();
     let (focus_sender, focus_receiver) = watch::channel(ACCESSIBILITY_ROOT_ID);
+    let font_mgr = FontMgr::default();
     let mut font_collection = FontCollection::new();
-    font_collection.set_dynamic_font_manager(FontMgr::default());
+    font_collection.set_dynamic_font_manager(font_mgr.clone());
+    let text_measurer = TextMeasurer::new(font_collection.clone(), vec!["Fira Sans".to_string()]);
 
     let mut handler = TestingHandler {
         vdom,
         events_queue: EventsQueue::new(),
         nodes_state: NodesState::default(),
         font_collection,
+        font_mgr,
+        text_measurer,
         event_emitter,
         event_receiver,
         utils: TestUtils { sdom },
-        config,
         platform_event_emitter,
         platform_event_receiver,
         accessibility_manager: AccessibilityManager::new(ACCESSIBILITY_ROOT_ID).wrap(),
         ticker_sender: broadcast::channel(5).0,
+        layout_notifier: LayoutNotifier::default(),
+        pointer_nodes_notifier: PointerNodesNotifier::default(),
+        window_insets_notifier: WindowInsetsNotifier::new(config.window_insets),
+        window_size_notifier: WindowSizeNotifier::new(
+            config.fixed_resolution.unwrap_or(config.size),
+        ),
+        keyboard_state_notifier: KeyboardStateNotifier::default(),
+        close_request_notifier: CloseRequestNotifier::default(),
+        closed: false,
         navigation_state: NavigatorState::new(NavigationMode::NotKeyboard),
-        platform_information: Arc::new(Mutex::new(PlatformInformation::new(config.size))),
+        platform_information: Arc::new(Mutex::new(PlatformInformation::new(
+            config.fixed_resolution.unwrap_or(config.size),
+            config.scale_factor,
+        ))),
         cursor_icon: CursorIcon::default(),
+        events_recorder: None,
         focus_sender,
         focus_receiver,
+        config,
     };
 
     handler.init_dom();
@@ -152,18 +152,52 @@
 //!     assert_eq!(label_text.text(), Some("Hello World!"));
 //! }
 //! ````
+//!
+//! ## Visual regression example
+//!
+//! The canvas can be rendered with `render` and compared against a golden PNG with `assert_snapshot`.
+//! The golden image is created the first time, and it can be updated by running the tests with the `FREYA_UPDATE_SNAPSHOTS` environment variable.
+//! When they don't match, the rendered frame and an image with the different pixels highlighted in red are written next to the golden image.
+//!
+//! Text is rasterized slightly different on each platform, so the tolerance lets some pixels differ.
+//!
+//! ```rust, no_run
+//! #[tokio::test]
+//! async fn visual_test() {
+//!     fn our_component() -> Element {
+//!         rsx!(
+//!             rect {
+//!                 width: "100",
+//!                 height: "100",
+//!                 background: "red",
+//!                 label {
+//!                     "Hello World!"
+//!                 }
+//!             }
+//!         )
+//!     }
+//!
+//!     let mut utils = launch_test(our_component);
+//!     utils.wait_for_update().await;
+//!
+//!     utils.assert_snapshot(
+//!         "tests/snapshots/our_component.png",
+//!         SnapshotTolerance::cross_platform(),
+//!     );
+//! }
+//! ```
 
 pub mod config;
 pub mod launch;
+pub mod snapshot;
 pub mod test_handler;
 pub mod test_node;
 pub mod test_utils;
 
-const SCALE_FACTOR: f64 = 1.0;
-
 pub mod prelude {
     pub use crate::config::*;
     pub use crate::launch::*;
+    pub use crate::snapshot::*;
     pub use crate::test_handler::*;
     pub use crate::test_node::*;
     pub use crate::test_utils::*;
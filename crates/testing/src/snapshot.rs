@@ -0,0 +1,305 @@
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use freya_engine::prelude::*;
+
+/// When this environment variable is set, [`assert_snapshot`] overwrites the golden images with the rendered frames
+/// instead of comparing them.
+pub const UPDATE_SNAPSHOTS_VAR: &str = "FREYA_UPDATE_SNAPSHOTS";
+
+/// How much a rendered frame can differ from its golden image and still match it.
+///
+/// Text is rasterized slightly different across platforms because of their font backends and hinting,
+/// so snapshots with text usually need some tolerance to pass everywhere.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapshotTolerance {
+    /// Maximum difference, from `0` to `255`, of any channel of a pixel for it to be considered equal.
+    pub channel: u8,
+    /// Maximum fraction of the pixels, from `0.0` to `1.0`, that can be different.
+    pub pixels: f32,
+}
+
+impl Default for SnapshotTolerance {
+    fn default() -> Self {
+        Self::exact()
+    }
+}
+
+impl SnapshotTolerance {
+    /// Every pixel must be exactly the same.
+    pub fn exact() -> Self {
+        Self {
+            channel: 0,
+            pixels: 0.0,
+        }
+    }
+
+    /// Tolerate the antialiasing differences of the text and the shapes across platforms.
+    pub fn cross_platform() -> Self {
+        Self {
+            channel: 48,
+            pixels: 0.005,
+        }
+    }
+}
+
+/// Frame rendered by [`TestingHandler::render`](crate::test_handler::TestingHandler::render), in physical pixels.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderedFrame {
+    pub width: u32,
+    pub height: u32,
+    /// Unpremultiplied RGBA pixels, row by row.
+    pub pixels: Vec<u8>,
+}
+
+impl RenderedFrame {
+    /// Create a frame of the given size filled with a color.
+    pub fn new(width: u32, height: u32, rgba: [u8; 4]) -> Self {
+        Self {
+            width,
+            height,
+            pixels: rgba.repeat((width * height) as usize),
+        }
+    }
+
+    /// Read the pixels of a raster image.
+    pub fn from_image(image: &Image) -> Option<Self> {
+        let dimensions = image.dimensions();
+        let image_info = ImageInfo::new(dimensions, ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let row_bytes = dimensions.width as usize * 4;
+        let mut pixels = vec![0; row_bytes * dimensions.height as usize];
+        image
+            .read_pixels(
+                &image_info,
+                &mut pixels,
+                row_bytes,
+                (0, 0),
+                CachingHint::Disallow,
+            )
+            .then_some(Self {
+                width: dimensions.width as u32,
+                height: dimensions.height as u32,
+                pixels,
+            })
+    }
+
+    /// Decode a PNG image.
+    pub fn from_png(bytes: &[u8]) -> Option<Self> {
+        Self::from_image(&Image::from_encoded(Data::new_copy(bytes))?)
+    }
+
+    /// Encode the frame as a PNG image.
+    pub fn to_png(&self) -> Option<Vec<u8>> {
+        let image_info = ImageInfo::new(
+            (self.width as i32, self.height as i32),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+        let image = images::raster_from_data(
+            &image_info,
+            Data::new_copy(&self.pixels),
+            self.width as usize * 4,
+        )?;
+        let data = image.encode(None, EncodedImageFormat::PNG, None)?;
+        Some(data.as_bytes().to_vec())
+    }
+
+    /// Get the RGBA color of a pixel.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let start = ((y * self.width + x) * 4) as usize;
+        self.pixels[start..start + 4].try_into().unwrap()
+    }
+
+    /// Compare the frame against the expected one, pixel by pixel.
+    ///
+    /// Returns `None` if they don't have the same size.
+    pub fn compare(&self, expected: &Self, tolerance: SnapshotTolerance) -> Option<SnapshotDiff> {
+        if (self.width, self.height) != (expected.width, expected.height) {
+            return None;
+        }
+
+        let mut different_pixels = 0;
+        let mut diff = Vec::with_capacity(self.pixels.len());
+        for (actual, expected) in self
+            .pixels
+            .chunks_exact(4)
+            .zip(expected.pixels.chunks_exact(4))
+        {
+            let different = actual
+                .iter()
+                .zip(expected)
+                .any(|(actual, expected)| actual.abs_diff(*expected) > tolerance.channel);
+
+            // The different pixels are painted red over a faded grayscale version of the frame
+            if different {
+                different_pixels += 1;
+                diff.extend_from_slice(&[255, 0, 0, 255]);
+            } else {
+                let luma = (actual[0] as u32 * 3 + actual[1] as u32 * 6 + actual[2] as u32) / 10;
+                let luma = luma * actual[3] as u32 / 255;
+                let faded = 255 - (255 - luma as u8) / 4;
+                diff.extend_from_slice(&[faded, faded, faded, 255]);
+            }
+        }
+
+        Some(SnapshotDiff {
+            different_pixels,
+            total_pixels: (self.width * self.height) as usize,
+            tolerance,
+            image: RenderedFrame {
+                width: self.width,
+                height: self.height,
+                pixels: diff,
+            },
+        })
+    }
+}
+
+/// Result of comparing a [`RenderedFrame`] against the expected one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnapshotDiff {
+    /// How many pixels are different beyond the channel tolerance.
+    pub different_pixels: usize,
+    pub total_pixels: usize,
+    pub tolerance: SnapshotTolerance,
+    /// The different pixels highlighted in red.
+    pub image: RenderedFrame,
+}
+
+impl SnapshotDiff {
+    /// Check if the frames match within the tolerance.
+    pub fn matches(&self) -> bool {
+        self.different_pixels as f32 <= self.total_pixels as f32 * self.tolerance.pixels
+    }
+}
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// The golden image couldn't be read or written.
+    Io(PathBuf, io::Error),
+    /// The golden image isn't a valid PNG.
+    InvalidGolden(PathBuf),
+    /// The rendered frame couldn't be encoded.
+    Encode,
+    /// The rendered frame doesn't have the size of the golden image.
+    SizeMismatch {
+        golden: PathBuf,
+        expected: (u32, u32),
+        actual: (u32, u32),
+    },
+    /// Too many pixels are different, the rendered frame and the diff image were written next to the golden image.
+    Mismatch {
+        golden: PathBuf,
+        different_pixels: usize,
+        total_pixels: usize,
+    },
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, err) => write!(f, "Failed to access `{}`: {err}", path.display()),
+            Self::InvalidGolden(path) => write!(f, "`{}` is not a valid PNG", path.display()),
+            Self::Encode => write!(f, "Failed to encode the rendered frame"),
+            Self::SizeMismatch {
+                golden,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "The rendered frame is {}x{} but `{}` is {}x{}",
+                actual.0,
+                actual.1,
+                golden.display(),
+                expected.0,
+                expected.1
+            ),
+            Self::Mismatch {
+                golden,
+                different_pixels,
+                total_pixels,
+            } => write!(
+                f,
+                "{different_pixels} of {total_pixels} pixels are different from `{}`, see `{}`",
+                golden.display(),
+                sibling_path(golden, "diff").display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// Get the path of a file next to the golden image, e.g. `button.diff.png` for `button.png`.
+fn sibling_path(golden: &Path, suffix: &str) -> PathBuf {
+    let stem = golden.file_stem().unwrap_or_default().to_string_lossy();
+    golden.with_file_name(format!("{stem}.{suffix}.png"))
+}
+
+fn write_png(path: &Path, frame: &RenderedFrame) -> Result<(), SnapshotError> {
+    let png = frame.to_png().ok_or(SnapshotError::Encode)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| SnapshotError::Io(parent.to_path_buf(), err))?;
+    }
+    fs::write(path, png).map_err(|err| SnapshotError::Io(path.to_path_buf(), err))
+}
+
+/// Compare a rendered frame against the golden PNG at the given path.
+///
+/// The golden image is created if it doesn't exist yet, or overwritten if [`UPDATE_SNAPSHOTS_VAR`] is set.
+/// If they don't match, the frame is written as `<name>.actual.png` and the different pixels as `<name>.diff.png`
+/// next to the golden image. Both are removed once it matches again.
+pub fn check_snapshot(
+    frame: &RenderedFrame,
+    golden: impl AsRef<Path>,
+    tolerance: SnapshotTolerance,
+) -> Result<(), SnapshotError> {
+    let golden = golden.as_ref();
+    let (actual_path, diff_path) = (sibling_path(golden, "actual"), sibling_path(golden, "diff"));
+
+    if env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() || !golden.exists() {
+        return write_png(golden, frame);
+    }
+
+    let bytes = fs::read(golden).map_err(|err| SnapshotError::Io(golden.to_path_buf(), err))?;
+    let expected = RenderedFrame::from_png(&bytes)
+        .ok_or_else(|| SnapshotError::InvalidGolden(golden.to_path_buf()))?;
+
+    let Some(diff) = frame.compare(&expected, tolerance) else {
+        write_png(&actual_path, frame)?;
+        return Err(SnapshotError::SizeMismatch {
+            golden: golden.to_path_buf(),
+            expected: (expected.width, expected.height),
+            actual: (frame.width, frame.height),
+        });
+    };
+
+    if diff.matches() {
+        fs::remove_file(actual_path).ok();
+        fs::remove_file(diff_path).ok();
+        Ok(())
+    } else {
+        write_png(&actual_path, frame)?;
+        write_png(&diff_path, &diff.image)?;
+        Err(SnapshotError::Mismatch {
+            golden: golden.to_path_buf(),
+            different_pixels: diff.different_pixels,
+            total_pixels: diff.total_pixels,
+        })
+    }
+}
+
+/// Like [`check_snapshot`] but panics if the frame doesn't match the golden image.
+#[track_caller]
+pub fn assert_snapshot(
+    frame: &RenderedFrame,
+    golden: impl AsRef<Path>,
+    tolerance: SnapshotTolerance,
+) {
+    if let Err(err) = check_snapshot(frame, golden, tolerance) {
+        panic!("{err}");
+    }
+}
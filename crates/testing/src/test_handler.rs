@@ -1,21 +1,26 @@
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use dioxus_core::VirtualDom;
 use freya_common::EventMessage;
 use freya_core::prelude::*;
-use freya_engine::prelude::FontCollection;
-use freya_hooks::PlatformInformation;
+use freya_engine::prelude::*;
+use freya_hooks::{
+    CloseRequestNotifier, KeyboardStateNotifier, LayoutNotifier, PlatformInformation,
+    PointerNodesNotifier, WindowInsets, WindowInsetsNotifier, WindowSizeNotifier,
+};
 use tokio::sync::broadcast;
 use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
-use tokio::time::{interval, timeout};
-use torin::geometry::{Area, Size2D};
+use tokio::time::{interval, sleep_until, timeout, Instant};
+use torin::geometry::{Area, CursorPoint, Size2D};
+use winit::event::MouseButton;
 use winit::window::CursorIcon;
 
 use crate::config::TestingConfig;
+use crate::snapshot::{assert_snapshot, RenderedFrame, SnapshotTolerance};
 use crate::test_node::TestNode;
-use crate::test_utils::TestUtils;
-use crate::SCALE_FACTOR;
+use crate::test_utils::{TestIdError, TestUtils};
 
 /// Manages the lifecycle of your tests.
 pub struct TestingHandler {
@@ -30,12 +35,22 @@ pub struct TestingHandler {
     pub(crate) focus_sender: FocusSender,
     pub(crate) focus_receiver: FocusReceiver,
     pub(crate) font_collection: FontCollection,
+    pub(crate) font_mgr: FontMgr,
+    pub(crate) text_measurer: TextMeasurer,
     pub(crate) accessibility_manager: SharedAccessibilityManager,
     pub(crate) config: TestingConfig,
     pub(crate) ticker_sender: broadcast::Sender<()>,
+    pub(crate) layout_notifier: LayoutNotifier,
+    pub(crate) pointer_nodes_notifier: PointerNodesNotifier,
+    pub(crate) window_insets_notifier: WindowInsetsNotifier,
+    pub(crate) window_size_notifier: WindowSizeNotifier,
+    pub(crate) keyboard_state_notifier: KeyboardStateNotifier,
+    pub(crate) close_request_notifier: CloseRequestNotifier,
+    pub(crate) closed: bool,
     pub(crate) navigation_state: NavigatorState,
     pub(crate) platform_information: Arc<Mutex<PlatformInformation>>,
     pub(crate) cursor_icon: CursorIcon,
+    pub(crate) events_recorder: Option<EventsRecorder>,
 }
 
 impl TestingHandler {
@@ -44,7 +59,7 @@ impl TestingHandler {
         self.provide_vdom_contexts();
         let sdom = self.utils.sdom();
         let mut fdom = sdom.get();
-        fdom.init_dom(&mut self.vdom, SCALE_FACTOR as f32);
+        fdom.init_dom(&mut self.vdom, self.config.scale_factor() as f32);
     }
 
     /// Get a mutable reference to the current [`TestingConfig`].
@@ -60,10 +75,28 @@ impl TestingHandler {
             .insert_any_root_context(Box::new(self.focus_receiver.clone()));
         self.vdom
             .insert_any_root_context(Box::new(Arc::new(self.ticker_sender.subscribe())));
+        self.vdom
+            .insert_any_root_context(Box::new(self.layout_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.pointer_nodes_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.window_insets_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.window_size_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.keyboard_state_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.close_request_notifier.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.text_measurer.clone()));
         self.vdom
             .insert_any_root_context(Box::new(self.navigation_state.clone()));
         self.vdom
             .insert_any_root_context(Box::new(self.platform_information.clone()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.utils.sdom().get().keyframes()));
+        self.vdom
+            .insert_any_root_context(Box::new(self.utils.sdom().get().animation_clock()));
     }
 
     /// Wait and apply new changes
@@ -87,7 +120,7 @@ impl TestingHandler {
 
             if let Ok(ev) = platform_ev {
                 match ev {
-                    EventMessage::RequestRerender => {
+                    EventMessage::RequestRerender | EventMessage::RequestTick => {
                         if let Some(ticker) = ticker.as_mut() {
                             ticker.tick().await;
                             self.ticker_sender.send(()).unwrap();
@@ -129,17 +162,63 @@ impl TestingHandler {
                             .send(tree.focus)
                             .expect("Failed to focus the Node.");
                     }
+                    EventMessage::Announce { message, live } => {
+                        self.accessibility_manager
+                            .lock()
+                            .unwrap()
+                            .announce(message, live);
+                    }
+                    EventMessage::SetCursorPosition((x, y)) => {
+                        self.platform_information.lock().unwrap().cursor_position =
+                            Some(CursorPoint::new(x, y));
+                    }
                     EventMessage::SetCursorIcon(icon) => {
                         self.cursor_icon = icon;
                     }
+                    EventMessage::ExitApp => {
+                        self.closed = true;
+                    }
+                    EventMessage::UpdateTemplate(template) => {
+                        self.vdom.replace_template(template);
+                    }
+                    EventMessage::DispatchNodeEvent { node_id, event } => {
+                        process_node_event(
+                            &self.utils.sdom().get(),
+                            node_id,
+                            event,
+                            &self.event_emitter,
+                            self.config.scale_factor(),
+                        );
+                    }
+                    EventMessage::NodesAtPoint {
+                        point: (x, y),
+                        callback,
+                    } => {
+                        let scale_factor = self.config.scale_factor();
+                        callback(nodes_at_point(
+                            &self.utils.sdom().get(),
+                            CursorPoint::new(x * scale_factor, y * scale_factor),
+                        ));
+                    }
+                    // Nothing is rendered in the testing renderer
+                    EventMessage::SnapshotNode { callback, .. } => {
+                        callback(None);
+                    }
                     _ => {}
                 }
             }
 
             if let Ok(ev) = vdom_ev {
-                self.vdom
-                    .handle_event(ev.name.into(), ev.data.any(), ev.element_id, ev.bubbles);
-                self.vdom.process_events();
+                // Skip the events whose capture phase was stopped
+                if !ev.data.is_capture_stopped() {
+                    self.vdom.handle_event(
+                        ev.name.into(),
+                        ev.data.any(),
+                        ev.element_id,
+                        ev.bubbles,
+                    );
+                    self.vdom.process_events();
+                }
             }
         }
 
@@ -151,7 +230,16 @@ impl TestingHandler {
             .utils
             .sdom()
             .get_mut()
-            .render_mutations(&mut self.vdom, SCALE_FACTOR as f32);
+            .render_mutations(&mut self.vdom, self.config.scale_factor() as f32);
+
+        // Advance the attribute transitions and animations to the current time
+        let (animations_repaint, animations_relayout) = self
+            .utils
+            .sdom()
+            .get_mut()
+            .process_animations(self.config.scale_factor() as f32);
+        let must_repaint = must_repaint || animations_repaint;
+        let must_relayout = must_relayout || animations_relayout;
 
         self.wait_for_work(self.config.size());
 
@@ -165,20 +253,47 @@ impl TestingHandler {
         // Clear cached results
         self.utils.sdom().get_mut().layout().reset();
 
+        let scale_factor = self.config.scale_factor();
+
+        let letterbox = self.config.fixed_resolution.map(|fixed_resolution| {
+            Letterbox::new(
+                fixed_resolution,
+                scale_factor as f32,
+                size * scale_factor as f32,
+            )
+        });
+        self.utils.sdom().get_mut().set_letterbox(letterbox);
+
+        // Everything is measured again, so the restyled paragraphs get rebuilt too
+        self.utils
+            .sdom()
+            .get()
+            .restyle_paragraphs(&self.font_collection, &["Fira Sans".to_string()]);
+
         // Measure layout
         process_layout(
             &self.utils.sdom().get(),
-            Area {
-                origin: (0.0, 0.0).into(),
-                size,
-            },
+            letterbox
+                .map(|letterbox| letterbox.layout_area())
+                .unwrap_or(Area {
+                    origin: (0.0, 0.0).into(),
+                    size: size * scale_factor as f32,
+                }),
             &mut self.font_collection,
-            SCALE_FACTOR as f32,
+            scale_factor as f32,
             &["Fira Sans".to_string()],
         );
 
+        self.layout_notifier.notify();
+
+        let mounted_nodes = self.utils.sdom().get_mut().take_mounted_nodes();
+        let scrolled_nodes = self.utils.sdom().get_mut().take_scrolled_nodes();
+
         let dom = &self.utils.sdom().get_mut();
 
+        process_mounted_events(dom, mounted_nodes, &self.event_emitter, scale_factor);
+        process_scroll_events(dom, scrolled_nodes, &self.event_emitter, scale_factor);
+
         process_accessibility(
             &dom.layout(),
             dom.rdom(),
@@ -190,15 +305,82 @@ impl TestingHandler {
             &mut self.events_queue,
             &self.event_emitter,
             &mut self.nodes_state,
-            SCALE_FACTOR,
+            scale_factor,
         );
+
+        self.pointer_nodes_notifier.notify(&self.nodes_state);
+    }
+
+    /// Push a left click at the given cursor position to the events queue.
+    pub fn click(&mut self, cursor: impl Into<CursorPoint>) {
+        self.push_event(PlatformEvent::Mouse {
+            name: EventName::Click,
+            cursor: cursor.into(),
+            button: Some(MouseButton::Left),
+        });
     }
 
     /// Push an event to the events queue
     pub fn push_event(&mut self, event: PlatformEvent) {
+        if let Some(recorder) = &mut self.events_recorder {
+            recorder.record(&event);
+        }
+        let cursor_position = match &event {
+            PlatformEvent::Mouse { cursor, .. } => Some(*cursor),
+            PlatformEvent::Touch { location, .. } => Some(*location),
+            _ => None,
+        };
+        if let Some(cursor_position) = cursor_position {
+            self.platform_information.lock().unwrap().cursor_position =
+                Some(cursor_position / self.config.scale_factor());
+        }
+        if let PlatformEvent::Keyboard {
+            name,
+            key,
+            code,
+            modifiers,
+            ..
+        } = &event
+        {
+            match name {
+                EventName::KeyDown => {
+                    self.keyboard_state_notifier
+                        .press(*code, key.clone(), *modifiers)
+                }
+                EventName::KeyUp => self.keyboard_state_notifier.release(*code, *modifiers),
+                _ => {}
+            }
+        }
         self.events_queue.push(event);
     }
 
+    /// Start recording the events pushed with [`push_event`](Self::push_event), discarding any previous recording.
+    pub fn start_recording(&mut self) {
+        self.events_recorder = Some(EventsRecorder::new());
+    }
+
+    /// Stop recording the events, see [`start_recording`](Self::start_recording).
+    pub fn stop_recording(&mut self) -> Option<EventsRecording> {
+        self.events_recorder.take().map(EventsRecorder::finish)
+    }
+
+    /// Push the events of a recording and wait for the app to handle each of them.
+    ///
+    /// The events are pushed at their original times divided by `speed`, so `1.0` keeps the original timing
+    /// and [`f64::INFINITY`] pushes them as fast as possible.
+    ///
+    /// The events are always handled in the same order, but anything relying on real time
+    /// (timers, animations or tasks) might not progress the same way it did while recording,
+    /// especially when replaying at a different speed.
+    pub async fn replay(&mut self, recording: &EventsRecording, speed: f64) {
+        let started_at = Instant::now();
+        for recorded in &recording.events {
+            sleep_until(started_at + recorded.time.div_f64(speed)).await;
+            self.push_event(recorded.event.clone());
+            self.wait_for_update().await;
+        }
+    }
+
     /// Get the root node
     pub fn root(&mut self) -> TestNode {
         let root_id = {
@@ -214,15 +396,76 @@ impl TestingHandler {
             .get(0)
     }
 
+    /// Get all the Nodes with the given `test_id` attribute, see [`TestUtils::find_all_by_test_id`].
+    pub fn find_all_by_test_id(&self, test_id: &str) -> Vec<TestNode> {
+        self.utils.find_all_by_test_id(test_id)
+    }
+
+    /// Get the only Node with the given `test_id` attribute, see [`TestUtils::find_by_test_id`].
+    pub fn find_by_test_id(&self, test_id: &str) -> Result<TestNode, TestIdError> {
+        self.utils.find_by_test_id(test_id)
+    }
+
     /// Get the current [AccessibilityId].
     pub fn focus_id(&self) -> AccessibilityId {
         self.accessibility_manager.lock().unwrap().focused_id
     }
 
-    /// Resize the simulated canvas.
+    /// Get the Accessibility Node with the given [AccessibilityId], as it was last sent to the Accessibility provider.
+    pub fn accessibility_node(&self, id: AccessibilityId) -> Option<AccessibilityNode> {
+        self.accessibility_manager
+            .lock()
+            .unwrap()
+            .nodes
+            .iter()
+            .find(|(node_id, _)| *node_id == id)
+            .map(|(_, node)| node.clone())
+    }
+
+    /// Get the last message announced with the given [AccessibilityLive] mode.
+    pub fn announcement(&self, live: AccessibilityLive) -> Option<String> {
+        let accessibility_manager = self.accessibility_manager.lock().unwrap();
+        match live {
+            AccessibilityLive::Polite => accessibility_manager.polite_announcement.clone(),
+            AccessibilityLive::Assertive => accessibility_manager.assertive_announcement.clone(),
+            AccessibilityLive::Off => None,
+        }
+    }
+
+    /// Resize the simulated canvas, `size` is in logical pixels.
     pub fn resize(&mut self, size: Size2D) {
         self.config.size = size;
-        self.platform_information.lock().unwrap().window_size = size;
+        let window_size = self.config.fixed_resolution.unwrap_or(size);
+        self.platform_information.lock().unwrap().window_size = window_size;
+        self.window_size_notifier.set(window_size);
+    }
+
+    /// Change the scale factor of the simulated Window, like when moving it to a monitor with a different DPI.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.config.scale_factor = scale_factor;
+        self.platform_information.lock().unwrap().scale_factor = scale_factor;
+        self.utils
+            .sdom()
+            .get_mut()
+            .update_scale_factor(scale_factor as f32);
+    }
+
+    /// Change the insets of the simulated Window.
+    pub fn set_window_insets(&mut self, insets: WindowInsets) {
+        self.config.window_insets = insets;
+        self.window_insets_notifier.set(insets);
+    }
+
+    /// Simulate the user asking to close the Window, see [`is_closed`](Self::is_closed).
+    pub fn request_close(&mut self) {
+        if !self.close_request_notifier.notify() {
+            self.closed = true;
+        }
+    }
+
+    /// Check if the simulated Window was closed.
+    pub fn is_closed(&self) -> bool {
+        self.closed
     }
 
     /// Get the current [CursorIcon].
@@ -234,4 +477,50 @@ impl TestingHandler {
     pub fn sdom(&self) -> &SafeDOM {
         self.utils.sdom()
     }
+
+    /// Render the whole canvas like the Window would paint it, in physical pixels and over a white background.
+    pub fn render(&mut self) -> RenderedFrame {
+        let scale_factor = self.config.scale_factor() as f32;
+        let size = self.config.size() * scale_factor;
+        let mut surface = surfaces::raster_n32_premul((size.width as i32, size.height as i32))
+            .expect("Failed to create the surface.");
+        surface.canvas().clear(Color::WHITE);
+
+        let fdom = self.utils.sdom().get();
+
+        // Scale and center the app in the canvas, just like the Window
+        if let Some(letterbox) = fdom.letterbox() {
+            let layout_area = letterbox.layout_area();
+            let canvas = surface.canvas();
+            canvas.translate((letterbox.offset.x, letterbox.offset.y));
+            canvas.scale((letterbox.scale, letterbox.scale));
+            canvas.clip_rect(
+                Rect::new(0.0, 0.0, layout_area.width(), layout_area.height()),
+                ClipOp::Intersect,
+                false,
+            );
+        }
+
+        render_layers(
+            &mut surface,
+            &fdom,
+            &mut self.font_collection,
+            &self.font_mgr,
+            &["Fira Sans".to_string()],
+            RenderPass::default(),
+        );
+
+        RenderedFrame::from_image(&surface.image_snapshot()).expect("Failed to read the pixels.")
+    }
+
+    /// Render the canvas and compare it against the golden PNG at the given path, see [`assert_snapshot`].
+    #[track_caller]
+    pub fn assert_snapshot(&mut self, golden: impl AsRef<Path>, tolerance: SnapshotTolerance) {
+        assert_snapshot(&self.render(), golden, tolerance);
+    }
+
+    /// Get the computed layout of the whole tree as pretty-printed JSON, useful for snapshot tests.
+    pub fn dump_layout(&self) -> String {
+        format!("{:#}", self.utils.sdom().get().dump_layout())
+    }
 }
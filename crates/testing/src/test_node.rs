@@ -32,6 +32,11 @@ impl TestNode {
         Some(child)
     }
 
+    /// Get the ID of the Node
+    pub fn id(&self) -> NodeId {
+        self.node_id
+    }
+
     /// Get the Node text
     pub fn text(&self) -> Option<&str> {
         self.node_type.text()
@@ -134,6 +139,29 @@ impl TestNode {
         self.node_type.is_placeholder()
     }
 
+    /// Get a `data-*` attribute of the Node, e.g. `data("test-id")` for `data-test-id`.
+    pub fn data(&self, key: &str) -> Option<&str> {
+        if let NodeType::Element(element) = &self.node_type {
+            element.dataset.get(key).map(String::as_str)
+        } else {
+            None
+        }
+    }
+
+    /// Get a Node by a matching `data-*` attribute, e.g. `get_by_data("test-id", "submit")` for `data-test-id: "submit"`.
+    pub fn get_by_data(&self, key: &str, value: &str) -> Option<Self> {
+        self.utils()
+            .get_node_matching_inside_id(self.node_id, |node| {
+                if let NodeType::Element(element) = &*node.node_type() {
+                    element.dataset.get(key).map(String::as_str) == Some(value)
+                } else {
+                    false
+                }
+            })
+            .first()
+            .cloned()
+    }
+
     /// Get a Node by a matching text.
     pub fn get_by_text(&self, matching_text: &str) -> Option<Self> {
         self.utils()
@@ -1,10 +1,36 @@
+use std::fmt;
+
 use freya_core::prelude::*;
+use freya_native_core::attributes::AttributeName;
+use freya_native_core::node::{NodeType, OwnedAttributeValue};
 use freya_native_core::real_dom::NodeImmutable;
 use freya_native_core::tree::TreeRef;
 use freya_native_core::NodeId;
 
 use crate::test_node::TestNode;
 
+/// Error returned by [`TestUtils::find_by_test_id`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TestIdError {
+    /// No Node has the given `test_id`.
+    NotFound(String),
+    /// More than one Node has the given `test_id`, use [`TestUtils::find_all_by_test_id`] instead.
+    Ambiguous { test_id: String, matches: usize },
+}
+
+impl fmt::Display for TestIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(test_id) => write!(f, "No Node has the test id `{test_id}`"),
+            Self::Ambiguous { test_id, matches } => {
+                write!(f, "{matches} Nodes have the test id `{test_id}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TestIdError {}
+
 #[derive(Clone)]
 pub struct TestUtils {
     pub(crate) sdom: SafeDOM,
@@ -74,7 +100,7 @@ impl TestUtils {
                 let node_type = node.node_type().clone();
 
                 nodes.push(TestNode {
-                    node_id,
+                    node_id: node.id(),
                     utils,
                     children_ids,
                     height,
@@ -86,4 +112,52 @@ impl TestUtils {
 
         nodes
     }
+    /// Get all the Nodes with the given `test_id` attribute, sorted by their layer.
+    pub fn find_all_by_test_id(&self, test_id: &str) -> Vec<TestNode> {
+        let node_ids = {
+            let dom = self.sdom.get();
+            let rdom = dom.rdom();
+            let layers = dom.layers().layers();
+            let mut layers = layers.iter().collect::<Vec<_>>();
+            layers.sort_by_key(|(layer, _)| *layer);
+
+            layers
+                .into_iter()
+                .flat_map(|(_, layer_nodes)| layer_nodes)
+                .copied()
+                .filter(|node_id| {
+                    let Some(node) = rdom.get(*node_id) else {
+                        return false;
+                    };
+                    let node_type = node.node_type();
+                    let NodeType::Element(element) = &*node_type else {
+                        return false;
+                    };
+                    matches!(
+                        element.attributes.get(&AttributeName::TestId),
+                        Some(OwnedAttributeValue::Text(id)) if id == test_id
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        node_ids
+            .into_iter()
+            .map(|node_id| self.get_node_by_id(node_id))
+            .collect()
+    }
+
+    /// Get the only Node with the given `test_id` attribute.
+    /// Fails if there is no Node with it, or if there are many.
+    pub fn find_by_test_id(&self, test_id: &str) -> Result<TestNode, TestIdError> {
+        let mut nodes = self.find_all_by_test_id(test_id);
+        match nodes.len() {
+            0 => Err(TestIdError::NotFound(test_id.to_string())),
+            1 => Ok(nodes.remove(0)),
+            matches => Err(TestIdError::Ambiguous {
+                test_id: test_id.to_string(),
+                matches,
+            }),
+        }
+    }
 }
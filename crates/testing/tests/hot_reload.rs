@@ -0,0 +1,126 @@
+use std::sync::OnceLock;
+
+use dioxus_core::{Template, TemplateNode};
+use freya::prelude::*;
+use freya_common::EventMessage;
+use freya_testing::prelude::*;
+
+/// Template rendered by the app before hot reloading.
+static TEMPLATE: OnceLock<Template> = OnceLock::new();
+
+/// Same template with a new label after the counter, like if it was edited while the app runs.
+fn hot_reloaded_template() -> Template {
+    let template = *TEMPLATE.get().unwrap();
+    let TemplateNode::Element {
+        tag,
+        namespace,
+        attrs,
+        children,
+    } = template.roots[0]
+    else {
+        unreachable!()
+    };
+
+    let mut new_children = children.to_vec();
+    new_children.push(TemplateNode::Element {
+        tag: "label",
+        namespace: None,
+        attrs: &[],
+        children: &[TemplateNode::Text {
+            text: "Hot reloaded",
+        }],
+    });
+
+    Template {
+        roots: Box::leak(Box::new([TemplateNode::Element {
+            tag,
+            namespace,
+            attrs,
+            children: Box::leak(new_children.into_boxed_slice()),
+        }])),
+        ..template
+    }
+}
+
+#[tokio::test]
+async fn update_template() {
+    fn hot_reload_app() -> Element {
+        let mut count = use_signal(|| 0);
+        let platform = use_platform();
+
+        let element = rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                onclick: move |_| count += 1,
+                onkeydown: move |_| {
+                    platform
+                        .send(EventMessage::UpdateTemplate(hot_reloaded_template()))
+                        .ok();
+                },
+                label {
+                    "{count}"
+                }
+            }
+        );
+        TEMPLATE.get_or_init(|| element.as_ref().unwrap().template.get());
+        element
+    }
+
+    let mut utils = launch_test(hot_reload_app);
+    utils.wait_for_update().await;
+
+    utils.click((5., 5.));
+    utils.wait_for_update().await;
+    utils.click((5., 5.));
+    utils.wait_for_update().await;
+
+    let root = utils.root().get(0);
+    assert_eq!(root.children_ids().len(), 1);
+    assert_eq!(root.get(0).get(0).text(), Some("2"));
+
+    let template_name = TEMPLATE.get().unwrap().name;
+    let old_roots = utils
+        .sdom()
+        .get_mut()
+        .state_mut()
+        .template_roots(template_name)
+        .unwrap()
+        .to_vec();
+
+    // Send the edited template
+    utils.push_event(PlatformEvent::Keyboard {
+        name: EventName::KeyDown,
+        key: Key::Enter,
+        code: Code::Enter,
+        modifiers: Modifiers::empty(),
+        repeat: false,
+    });
+    utils.wait_for_update().await;
+    utils.wait_for_update().await;
+
+    // The new template is rendered and the counter keeps its state
+    let root = utils.root().get(0);
+    assert_eq!(root.children_ids().len(), 2);
+    assert_eq!(root.get(0).get(0).text(), Some("2"));
+    assert_eq!(root.get(1).get(0).text(), Some("Hot reloaded"));
+
+    utils.click((5., 5.));
+    utils.wait_for_update().await;
+    assert_eq!(utils.root().get(0).get(0).get(0).text(), Some("3"));
+
+    // The roots of the outdated template are freed
+    let mut fdom = utils.sdom().get_mut();
+    let new_roots = fdom
+        .state_mut()
+        .template_roots(template_name)
+        .unwrap()
+        .to_vec();
+    assert_ne!(old_roots, new_roots);
+    for old_root in old_roots {
+        assert!(!fdom.rdom().contains(old_root));
+    }
+    for new_root in new_roots {
+        assert!(fdom.rdom().contains(new_root));
+    }
+}
@@ -0,0 +1,86 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[test]
+fn equal_frames_match() {
+    let frame = RenderedFrame::new(10, 10, [255, 0, 0, 255]);
+    let diff = frame
+        .compare(&frame.clone(), SnapshotTolerance::exact())
+        .unwrap();
+    assert_eq!(diff.different_pixels, 0);
+    assert!(diff.matches());
+}
+
+#[test]
+fn frames_of_different_sizes_never_match() {
+    let frame = RenderedFrame::new(10, 10, [255, 0, 0, 255]);
+    let expected = RenderedFrame::new(10, 20, [255, 0, 0, 255]);
+    assert!(frame
+        .compare(&expected, SnapshotTolerance::cross_platform())
+        .is_none());
+}
+
+#[test]
+fn tolerated_differences() {
+    let expected = RenderedFrame::new(10, 10, [255, 255, 255, 255]);
+    let mut frame = expected.clone();
+
+    // A single pixel that is slightly darker
+    frame.pixels[..4].copy_from_slice(&[235, 235, 235, 255]);
+
+    let diff = frame
+        .compare(&expected, SnapshotTolerance::exact())
+        .unwrap();
+    assert_eq!(diff.different_pixels, 1);
+    assert!(!diff.matches());
+    assert_eq!(diff.image.pixel(0, 0), [255, 0, 0, 255]);
+    assert_eq!(diff.image.pixel(1, 0), [255, 255, 255, 255]);
+
+    // Similar enough colors are equal
+    let tolerance = SnapshotTolerance {
+        channel: 20,
+        pixels: 0.0,
+    };
+    assert!(frame.compare(&expected, tolerance).unwrap().matches());
+
+    // Or a few pixels can be different
+    let tolerance = SnapshotTolerance {
+        channel: 0,
+        pixels: 0.01,
+    };
+    assert!(frame.compare(&expected, tolerance).unwrap().matches());
+}
+
+#[tokio::test]
+async fn rendered_rects_match_their_golden_image() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                width: "100%",
+                height: "100%",
+                direction: "horizontal",
+                rect {
+                    width: "50",
+                    height: "50",
+                    background: "rgb(255, 0, 0)",
+                }
+                rect {
+                    width: "50",
+                    height: "100",
+                    background: "rgb(0, 0, 255)",
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test_with_config(
+        app,
+        TestingConfig {
+            size: (100.0, 100.0).into(),
+            ..TestingConfig::default()
+        },
+    );
+    utils.wait_for_update().await;
+
+    utils.assert_snapshot("tests/snapshots/rects.png", SnapshotTolerance::exact());
+}
@@ -137,3 +137,84 @@ async fn match_by_text() {
         Some("Hello, Rust!")
     );
 }
+
+#[tokio::test]
+async fn match_by_data() {
+    fn app() -> Element {
+        let mut clicked = use_signal(|| None);
+        let status = format!("{:?}", clicked.read());
+
+        rsx!(
+            rect {
+                "data-test-id": "submit",
+                width: "100%",
+                height: "50%",
+                onclick: move |e: MouseEvent| {
+                    clicked.set(e.get_dataset().get("test-id").map(str::to_string))
+                },
+            }
+            label {
+                "data-test-id": "status",
+                "{status}"
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let submit = utils.root().get_by_data("test-id", "submit").unwrap();
+    assert_eq!(submit.data("test-id"), Some("submit"));
+    assert!(utils.root().get_by_data("test-id", "blabla").is_none());
+
+    // The handler can read the data attributes of the element that got the event
+    let area = submit.area().unwrap();
+    utils.push_event(PlatformEvent::Mouse {
+        name: EventName::Click,
+        cursor: (area.center().x as f64, area.center().y as f64).into(),
+        button: Some(MouseButton::Left),
+    });
+    utils.wait_for_update().await;
+
+    let status = utils.root().get_by_data("test-id", "status").unwrap();
+    assert_eq!(status.get(0).text(), Some("Some(\"submit\")"));
+}
+
+#[tokio::test]
+async fn find_by_test_id() {
+    fn app() -> Element {
+        rsx!(
+            rect {
+                test_id: "submit-button",
+                width: "100",
+                height: "50",
+            }
+            for _ in 0..2 {
+                label {
+                    test_id: "item",
+                    "Item"
+                }
+            }
+        )
+    }
+
+    let mut utils = launch_test(app);
+    utils.wait_for_update().await;
+
+    let button = utils.find_by_test_id("submit-button").unwrap();
+    assert_eq!(button.area().unwrap().width(), 100.0);
+    assert_eq!(button.id(), utils.root().get(0).id());
+
+    assert_eq!(utils.find_all_by_test_id("item").len(), 2);
+    assert_eq!(
+        utils.find_by_test_id("item").err(),
+        Some(TestIdError::Ambiguous {
+            test_id: "item".to_string(),
+            matches: 2
+        })
+    );
+    assert_eq!(
+        utils.find_by_test_id("blabla").err(),
+        Some(TestIdError::NotFound("blabla".to_string()))
+    );
+}
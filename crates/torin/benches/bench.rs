@@ -16,7 +16,11 @@ impl LayoutMeasurer<usize> for TestingMeasurer {
         None
     }
 
-    fn should_measure_inner_children(&mut self, _node_id: usize) -> bool {
+    fn should_measure_inner_children(
+        &mut self,
+        _node_id: usize,
+        _layout_node: &LayoutNode,
+    ) -> bool {
         true
     }
 }
@@ -362,5 +366,90 @@ fn criterion_benchmark(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, criterion_benchmark);
+/// Measure the layout while the root is continuously resized in the horizontal axis, like when dragging
+/// the edge of a Window, with some cards that depend on the intrinsic sizes of their nested content.
+fn resize_benchmark(c: &mut Criterion) {
+    let mut g = c.benchmark_group("resize");
+
+    fn centered(width: Size, height: Size) -> Node {
+        Node::from_size_and_alignments_and_direction(
+            width,
+            height,
+            Alignment::Center,
+            Alignment::Center,
+            DirectionMode::Vertical,
+        )
+    }
+
+    const CARDS: usize = 50;
+    const DEPTH: usize = 8;
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        (1..=CARDS).map(|card| card * 100).collect(),
+        centered(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+        ),
+    );
+    for card in 1..=CARDS {
+        let card_id = card * 100;
+        mocked_dom.add(
+            card_id,
+            Some(0),
+            vec![card_id + 1],
+            centered(Size::Pixels(Length::new(200.0)), Size::Inner),
+        );
+        for level in 1..DEPTH {
+            mocked_dom.add(
+                card_id + level,
+                Some(card_id + level - 1),
+                vec![card_id + level + 1],
+                centered(Size::Inner, Size::Inner),
+            );
+        }
+        mocked_dom.add(
+            card_id + DEPTH,
+            Some(card_id + DEPTH - 1),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(50.0)),
+                Size::Pixels(Length::new(20.0)),
+                DirectionMode::Vertical,
+            ),
+        );
+    }
+
+    for keep_intrinsic_sizes in [false, true] {
+        let name = format!(
+            "cards={CARDS} depth={DEPTH} intrinsic sizes={}",
+            if keep_intrinsic_sizes {
+                "kept"
+            } else {
+                "reset"
+            }
+        );
+        g.significance_level(0.05).sample_size(100);
+        g.bench_function(name, |b| {
+            let mut measurer = Some(TestingMeasurer);
+            let mut layout = Torin::<usize>::new();
+            b.iter(|| {
+                for width in (1000..1100).step_by(5) {
+                    if keep_intrinsic_sizes {
+                        layout.invalidate_all();
+                    } else {
+                        layout.reset();
+                    }
+                    let root_area =
+                        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(width as f32, 1000.0));
+                    layout.measure(0, root_area, &mut measurer, &mut mocked_dom);
+                }
+            })
+        });
+    }
+}
+
+criterion_group!(benches, criterion_benchmark, resize_benchmark);
 criterion_main!(benches);
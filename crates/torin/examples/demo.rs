@@ -16,7 +16,11 @@ impl LayoutMeasurer<usize> for CustomMeasurer {
         None
     }
 
-    fn should_measure_inner_children(&mut self, _node_id: usize) -> bool {
+    fn should_measure_inner_children(
+        &mut self,
+        _node_id: usize,
+        _layout_node: &LayoutNode,
+    ) -> bool {
         true
     }
 }
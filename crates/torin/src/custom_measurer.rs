@@ -11,7 +11,14 @@ pub trait LayoutMeasurer<Key: NodeKey> {
         size: &Size2D,
     ) -> Option<(Size2D, Arc<SendAnyMap>)>;
 
-    fn should_measure_inner_children(&mut self, node_id: Key) -> bool;
+    /// Check if the children of a Node must be measured when its own cached layout is reused,
+    /// e.g. they must not if they were skipped when the Node was measured with [`LayoutMeasurer::measure`].
+    fn should_measure_inner_children(&mut self, node_id: Key, layout_node: &LayoutNode) -> bool;
+
+    /// Get the distance from the top of the visible area of a measured Node to its first text baseline.
+    fn baseline(&self, _node_id: Key, _layout_node: &LayoutNode) -> Option<f32> {
+        None
+    }
 
     fn notify_layout_references(&self, _node_id: Key, _layout_node: &LayoutNode) {}
 }
@@ -24,6 +24,9 @@ pub struct LayoutNode {
     /// Outer margin
     pub margin: Gaps,
 
+    /// Inner padding, with its percentages resolved
+    pub padding: Gaps,
+
     /// Associated data
     pub data: Option<Arc<SendAnyMap>>,
 }
@@ -34,6 +37,7 @@ impl PartialEq for LayoutNode {
             && self.inner_area == other.inner_area
             && self.inner_sizes == other.inner_sizes
             && self.margin == other.margin
+            && self.padding == other.padding
     }
 }
 
@@ -66,6 +70,17 @@ pub trait DOMAdapter<Key: NodeKey> {
     /// Get the children of a Node
     fn children_of(&mut self, node_id: &Key) -> Vec<Key>;
 
+    /// Get the children of a Node in the order they are laid out, sorted by their [`Node::order`]
+    fn ordered_children_of(&mut self, node_id: &Key) -> Vec<Key> {
+        let mut children = self.children_of(node_id);
+        children.sort_by_cached_key(|child_id| {
+            self.get_node(child_id)
+                .map(|child| child.order)
+                .unwrap_or_default()
+        });
+        children
+    }
+
     /// Check whether the given Node is valid (isn't a placeholder, unconnected node..)
     fn is_node_valid(&mut self, node_id: &Key) -> bool;
 
@@ -6,6 +6,7 @@ pub struct Measure;
 pub type Area = euclid::Rect<f32, Measure>;
 pub type Size2D = euclid::Size2D<f32, Measure>;
 pub type Point2D = euclid::Point2D<f32, Measure>;
+pub type Vector2D = euclid::Vector2D<f32, Measure>;
 pub type CursorPoint = euclid::Point2D<f64, Measure>;
 pub type Length = euclid::Length<f32, Measure>;
 
@@ -13,6 +14,9 @@ pub trait AreaModel {
     // The area without any outer gap (e.g margin)
     fn after_gaps(&self, margin: &Gaps) -> Area;
 
+    // The area extended with an outer gap
+    fn before_gaps(&self, margin: &Gaps) -> Area;
+
     fn move_with_offsets(&mut self, offset_x: &Length, offset_y: &Length);
 
     fn align_content(
@@ -39,6 +43,19 @@ impl AreaModel for Area {
         )
     }
 
+    /// Get the area outside after including the gaps
+    fn before_gaps(&self, margin: &Gaps) -> Area {
+        let origin = self.origin;
+        let size = self.size;
+        Area::new(
+            Point2D::new(origin.x - margin.left(), origin.y - margin.top()),
+            Size2D::new(
+                size.width + margin.horizontal(),
+                size.height + margin.vertical(),
+            ),
+        )
+    }
+
     /// Get the area inside after including the gaps (margins or paddings)
     fn move_with_offsets(&mut self, offset_x: &Length, offset_y: &Length) {
         self.origin.x += offset_x.get();
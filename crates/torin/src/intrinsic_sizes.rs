@@ -0,0 +1,132 @@
+use rustc_hash::FxHashMap;
+
+use crate::{
+    dom_adapter::{DOMAdapter, LayoutNode, NodeKey},
+    geometry::{Area, Point2D, Size2D, Vector2D},
+    measure::Phase,
+    node::Node,
+};
+
+/// How many measurements are kept per Node, e.g. the ones of both phases while a parent is resized back and forth.
+const MAX_ENTRIES_PER_NODE: usize = 4;
+
+/// Everything the layout of a Node depends on when measuring it, besides its inner Nodes.
+#[derive(PartialEq)]
+pub struct IntrinsicInputs {
+    /// The Node as it was measured, its sizes might be overriden by its flex parent
+    pub node: Node,
+    pub phase: Phase,
+    pub parent_size: Size2D,
+    pub available_size: Size2D,
+    /// Position of the available area inside of the parent area, for the absolute Nodes
+    pub available_offset: Vector2D,
+}
+
+impl IntrinsicInputs {
+    pub fn new(
+        node: &Node,
+        phase: Phase,
+        parent_area: &Area,
+        available_parent_area: &Area,
+    ) -> Self {
+        Self {
+            node: node.clone(),
+            phase,
+            parent_size: parent_area.size,
+            available_size: available_parent_area.size,
+            available_offset: available_parent_area.origin - parent_area.origin,
+        }
+    }
+}
+
+struct IntrinsicSize {
+    inputs: IntrinsicInputs,
+    /// Origin of the available area the layout was measured in
+    origin: Point2D,
+    layout_node: LayoutNode,
+}
+
+/// Layouts of the Nodes measured without caching the layouts of their inner Nodes, which give the
+/// size of their content to the siblings and parents that depend on it, e.g. to resolve the flex basis,
+/// the alignments or the fit content.
+///
+/// They are measured again and again in every layout pass of their ancestors, so they are reused
+/// as long as the same inputs are measured and neither the Node nor its inner Nodes changed.
+pub struct IntrinsicSizes<Key: NodeKey> {
+    entries: FxHashMap<Key, Vec<IntrinsicSize>>,
+    /// Whether the measurement in progress depends on the root area, either on where the Nodes are
+    /// or on its size, so it can't be reused somewhere else or after resizing the root area.
+    pub root_dependent: bool,
+}
+
+impl<Key: NodeKey> Default for IntrinsicSizes<Key> {
+    fn default() -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            root_dependent: false,
+        }
+    }
+}
+
+impl<Key: NodeKey> IntrinsicSizes<Key> {
+    /// Get the layout of a Node measured with the same inputs, moved to the given origin of the available area.
+    pub fn get(
+        &self,
+        node_id: &Key,
+        inputs: &IntrinsicInputs,
+        origin: Point2D,
+    ) -> Option<LayoutNode> {
+        let entry = self
+            .entries
+            .get(node_id)?
+            .iter()
+            .find(|entry| &entry.inputs == inputs)?;
+        let offset = origin - entry.origin;
+        let mut layout_node = entry.layout_node.clone();
+        layout_node.area = layout_node.area.translate(offset);
+        layout_node.inner_area = layout_node.inner_area.translate(offset);
+        Some(layout_node)
+    }
+
+    /// Keep the layout of a Node, replacing its oldest measurement if there are too many.
+    pub fn insert(
+        &mut self,
+        node_id: Key,
+        inputs: IntrinsicInputs,
+        origin: Point2D,
+        layout_node: LayoutNode,
+    ) {
+        let entries = self.entries.entry(node_id).or_default();
+        if entries.len() == MAX_ENTRIES_PER_NODE {
+            entries.remove(0);
+        }
+        entries.push(IntrinsicSize {
+            inputs,
+            origin,
+            layout_node,
+        });
+    }
+
+    /// Remove the layouts of a Node.
+    pub fn remove(&mut self, node_id: &Key) {
+        self.entries.remove(node_id);
+    }
+
+    /// Remove the layouts of a Node that changed and of its ancestors, as their content changed with it.
+    pub fn invalidate(&mut self, node_id: &Key, dom_adapter: &impl DOMAdapter<Key>) {
+        let mut node_id = Some(*node_id);
+        while let Some(id) = node_id {
+            self.entries.remove(&id);
+            node_id = dom_adapter.parent_of(&id);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Get how many Nodes have cached layouts.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
@@ -1,6 +1,7 @@
 pub mod custom_measurer;
 pub mod dom_adapter;
 pub mod geometry;
+mod intrinsic_sizes;
 mod measure;
 mod measure_mode;
 pub mod node;
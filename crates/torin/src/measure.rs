@@ -1,18 +1,24 @@
+use std::mem;
+
 pub use euclid::Rect;
 use rustc_hash::FxHashMap;
 
 use crate::{
     custom_measurer::LayoutMeasurer,
     dom_adapter::{DOMAdapter, LayoutNode, NodeKey},
-    geometry::{Area, Size2D},
+    flex::{resolve_flexible_lengths, FlexItem},
+    geometry::Length,
+    geometry::{Area, Size2D, Vector2D},
+    intrinsic_sizes::IntrinsicInputs,
     measure_mode::MeasureMode,
     node::Node,
-    prelude::{AlignmentDirection, AreaModel, LayoutMetadata, Torin},
+    prelude::{Alignment, AlignmentDirection, AreaModel, DirectionMode, LayoutMetadata, Torin},
+    size::Size,
 };
 
 /// Some layout strategies require two-phase measurements
 /// Example: Alignments or content-fit.
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum Phase {
     Initial,
     Final,
@@ -41,12 +47,49 @@ pub fn measure_node<Key: NodeKey>(
 
     phase: Phase,
 ) -> (bool, LayoutNode) {
+    // Percentage paddings are relative to the width of the parent, just like the percentage widths
+    let padding_basis = if node.position.is_global() {
+        layout_metadata.root_area.width()
+    } else {
+        parent_area.width()
+    };
+    let node = &*node.resolve_padding(padding_basis);
+
     let must_revalidate = invalidated_tree
         || layout.dirty.contains(&node_id)
         || !layout.results.contains_key(&node_id);
+
+    // Without caching the inner Nodes the measurement only gives the intrinsic size of this Node,
+    // which is the same as long as it's measured with the same inputs and nothing inside changed
+    let intrinsic_inputs =
+        (must_revalidate && !must_cache_inner_nodes && !node.position.is_global())
+            .then(|| IntrinsicInputs::new(node, phase, parent_area, available_parent_area));
+    if let Some(inputs) = &intrinsic_inputs {
+        if let Some(layout_node) =
+            layout
+                .intrinsic_sizes
+                .get(&node_id, inputs, available_parent_area.origin)
+        {
+            return (false, layout_node);
+        }
+    }
+    let available_origin = available_parent_area.origin;
+
     if must_revalidate {
+        let was_root_dependent = mem::take(&mut layout.intrinsic_sizes.root_dependent);
+        layout.intrinsic_sizes.root_dependent = node.depends_on_root_size();
+
+        // Global Nodes are laid out inside the root area, no matter where their parent is
+        let (parent_area, available_parent_area) = if node.position.is_global() {
+            (&layout_metadata.root_area, &layout_metadata.root_area)
+        } else {
+            (parent_area, available_parent_area)
+        };
+
+        let inner_gaps = node.inner_gaps();
+
         // Create the initial Node area size
-        let mut area_size = Size2D::new(node.padding.horizontal(), node.padding.vertical());
+        let mut area_size = Size2D::new(inner_gaps.horizontal(), inner_gaps.vertical());
 
         // Compute the width and height given the size, the minimum size, the maximum size and margins
         area_size.width = node.width.min_max(
@@ -72,6 +115,15 @@ pub fn measure_node<Key: NodeKey>(
             phase,
         );
 
+        // Content-box sizes don't include the padding and border
+        let content_box_gaps = node.content_box_gaps();
+        if node.width.is_explicit() {
+            area_size.width += content_box_gaps.horizontal();
+        }
+        if node.height.is_explicit() {
+            area_size.height += content_box_gaps.vertical();
+        }
+
         // If available, run a custom layout measure function
         // This is useful when you use third-party libraries (e.g. rust-skia, cosmic-text) to measure text layouts
         // When a Node is measured by a custom measurer function the inner children will be skipped
@@ -172,18 +224,57 @@ pub fn measure_node<Key: NodeKey>(
             .get_origin(available_parent_area, parent_area, &area_size);
         let mut area = Rect::new(area_origin, area_size);
         let mut inner_area = Rect::new(area_origin, inner_size)
-            .after_gaps(&node.padding)
+            .after_gaps(&inner_gaps)
             .after_gaps(&node.margin);
 
         let mut inner_sizes = Size2D::default();
 
-        if measure_inner_children && phase_measure_inner_children {
+        // Skip the inner Nodes while this Node is outside of the root area,
+        // estimating the sizes they decide with its last size or the size of its intrinsic content
+        let skipped_area = if measure_inner_children && node.content_visibility.is_auto() {
+            layout.intrinsic_sizes.root_dependent = true;
+            let last_size = layout
+                .results
+                .get(&node_id)
+                .map(|layout_node| layout_node.area.size);
+            let mut estimated_area = area;
+            if node.width.inner_sized() {
+                estimated_area.size.width = last_size
+                    .map_or(area.width() + node.contain_intrinsic_size.width, |size| {
+                        size.width
+                    });
+            }
+            if node.height.inner_sized() {
+                estimated_area.size.height = last_size
+                    .map_or(area.height() + node.contain_intrinsic_size.height, |size| {
+                        size.height
+                    });
+            }
+            Some(estimated_area)
+                .filter(|estimated_area| is_outside(estimated_area, &layout_metadata.root_area))
+        } else {
+            None
+        };
+
+        if let Some(skipped_area) = skipped_area {
+            area = skipped_area;
+            inner_area = area.after_gaps(&inner_gaps).after_gaps(&node.margin);
+            inner_sizes = inner_area.size;
+
+            // The previous layouts of the inner Nodes are outdated now
+            if must_cache_inner_nodes {
+                remove_inner_layouts(&node_id, layout, dom_adapter);
+            }
+        } else if measure_inner_children && phase_measure_inner_children {
             // Create an area containing the available space inside the inner area
             let mut available_area = inner_area;
 
             // Adjust the available area with the node offsets (mainly used by scrollviews)
             available_area.move_with_offsets(&node.offset_x, &node.offset_y);
 
+            let inner_layout_metadata =
+                get_inner_layout_metadata(node, &inner_area, layout_metadata);
+
             let mut measurement_mode = MeasureMode::ParentIsNotCached {
                 area: &mut area,
                 inner_area: &mut inner_area,
@@ -200,21 +291,33 @@ pub fn measure_node<Key: NodeKey>(
                 must_cache_inner_nodes,
                 &mut measurement_mode,
                 dom_adapter,
-                layout_metadata,
+                &inner_layout_metadata,
                 true,
             );
         }
 
-        (
-            must_cache_inner_nodes,
-            LayoutNode {
-                area,
-                margin: node.margin,
-                inner_area,
-                inner_sizes,
-                data: node_data,
-            },
-        )
+        let layout_node = LayoutNode {
+            area,
+            margin: node.margin,
+            padding: node.padding,
+            inner_area,
+            inner_sizes,
+            data: node_data,
+        };
+
+        if let Some(inputs) = intrinsic_inputs {
+            if !layout.intrinsic_sizes.root_dependent {
+                layout.intrinsic_sizes.insert(
+                    node_id,
+                    inputs,
+                    available_origin,
+                    layout_node.clone(),
+                );
+            }
+        }
+        layout.intrinsic_sizes.root_dependent |= was_root_dependent;
+
+        (must_cache_inner_nodes, layout_node)
     } else {
         let layout_node = layout.get(node_id).unwrap().clone();
 
@@ -223,16 +326,24 @@ pub fn measure_node<Key: NodeKey>(
 
         available_area.move_with_offsets(&node.offset_x, &node.offset_y);
 
+        let inner_layout_metadata =
+            get_inner_layout_metadata(node, &layout_node.inner_area, layout_metadata);
+
         let mut measurement_mode = MeasureMode::ParentIsCached {
             inner_area: &layout_node.inner_area,
         };
 
         let measure_inner_children = if let Some(measurer) = measurer {
-            measurer.should_measure_inner_children(node_id)
+            measurer.should_measure_inner_children(node_id, &layout_node)
         } else {
             true
         };
 
+        // The inner Nodes are still skipped if this Node was outside of the root area
+        let measure_inner_children = measure_inner_children
+            && !(node.content_visibility.is_auto()
+                && is_outside(&layout_node.area, &layout_metadata.root_area));
+
         if measure_inner_children {
             measure_inner_nodes(
                 &node_id,
@@ -244,7 +355,7 @@ pub fn measure_node<Key: NodeKey>(
                 must_cache_inner_nodes,
                 &mut measurement_mode,
                 dom_adapter,
-                layout_metadata,
+                &inner_layout_metadata,
                 false,
             );
         }
@@ -253,6 +364,26 @@ pub fn measure_node<Key: NodeKey>(
     }
 }
 
+/// Check if an area is completely outside of the root area, touching its edges doesn't count.
+fn is_outside(area: &Area, root_area: &Area) -> bool {
+    area.max_x() < root_area.min_x()
+        || area.min_x() > root_area.max_x()
+        || area.max_y() < root_area.min_y()
+        || area.min_y() > root_area.max_y()
+}
+
+/// Remove the layouts of the descendants of a Node.
+fn remove_inner_layouts<Key: NodeKey>(
+    node_id: &Key,
+    layout: &mut Torin<Key>,
+    dom_adapter: &mut impl DOMAdapter<Key>,
+) {
+    for child_id in dom_adapter.children_of(node_id) {
+        layout.raw_remove(child_id);
+        remove_inner_layouts(&child_id, layout, dom_adapter);
+    }
+}
+
 /// Measure the children layouts of a Node
 #[allow(clippy::too_many_arguments)]
 #[inline(always)]
@@ -275,10 +406,33 @@ pub fn measure_inner_nodes<Key: NodeKey>(
 
     invalidated_tree: bool,
 ) {
-    let children = dom_adapter.children_of(parent_node_id);
+    let children = dom_adapter.ordered_children_of(parent_node_id);
+
+    // Sizes of the children in the main axis when the parent is a flex container
+    let flex_sizes = if parent_node.content.is_flex() {
+        measure_flex_sizes(
+            parent_node,
+            &children,
+            layout,
+            mode.inner_area(),
+            available_area,
+            measurer,
+            dom_adapter,
+            layout_metadata,
+            invalidated_tree,
+        )
+    } else {
+        FxHashMap::default()
+    };
 
     let mut initial_phase_sizes = FxHashMap::default();
 
+    // Baselines of the children, only when aligning them by their baseline
+    let aligns_baselines = parent_node.cross_alignment == Alignment::Baseline
+        && parent_node.direction == DirectionMode::Horizontal;
+    let mut initial_phase_baselines = FxHashMap::default();
+    let mut max_baseline = 0f32;
+
     // Initial phase: Measure the size and position of the children if the parent has a
     // non-start cross alignment, non-start main aligment of a fit-content.
     if parent_node.cross_alignment.is_not_start()
@@ -292,11 +446,14 @@ pub fn measure_inner_nodes<Key: NodeKey>(
 
         // 1. Measure the children
         for child_id in &children {
-            let Some(child_data) = dom_adapter.get_node(child_id) else {
+            let Some(mut child_data) = dom_adapter.get_node(child_id) else {
                 continue;
             };
+            if let Some(flex_size) = flex_sizes.get(child_id) {
+                apply_flex_size(&mut child_data, *flex_size, &parent_node.direction);
+            }
 
-            if child_data.position.is_absolute() {
+            if child_data.position.is_out_of_flow() {
                 continue;
             }
 
@@ -327,6 +484,17 @@ pub fn measure_inner_nodes<Key: NodeKey>(
             if parent_node.cross_alignment.is_not_start() {
                 initial_phase_sizes.insert(*child_id, child_areas.area.size);
             }
+
+            if aligns_baselines {
+                // Fallback to the bottom edge for Nodes without text
+                let baseline = measurer
+                    .as_ref()
+                    .and_then(|measurer| measurer.baseline(*child_id, &child_areas))
+                    .map(|baseline| child_areas.margin.top() + baseline)
+                    .unwrap_or_else(|| child_areas.area.height());
+                max_baseline = max_baseline.max(baseline);
+                initial_phase_baselines.insert(*child_id, baseline);
+            }
         }
 
         if parent_node.main_alignment.is_not_start() {
@@ -357,14 +525,27 @@ pub fn measure_inner_nodes<Key: NodeKey>(
         }
     }
 
+    // Sticky children, they are moved once all their siblings are measured
+    let mut sticky_children = Vec::new();
+
     // Final phase: measure the children with all the axis and sizes adjusted
     for child_id in children {
-        let Some(child_data) = dom_adapter.get_node(&child_id) else {
+        let Some(mut child_data) = dom_adapter.get_node(&child_id) else {
             continue;
         };
+        if let Some(flex_size) = flex_sizes.get(&child_id) {
+            apply_flex_size(&mut child_data, *flex_size, &parent_node.direction);
+        }
 
         let mut adapted_available_area = *available_area;
-        if parent_node.cross_alignment.is_not_start() {
+        let mut baseline_offset = 0.;
+        if aligns_baselines {
+            if let Some(baseline) = initial_phase_baselines.get(&child_id) {
+                // 1. Move the child down so its baseline matches the lowest one
+                baseline_offset = max_baseline - baseline;
+                adapted_available_area.origin.y += baseline_offset;
+            }
+        } else if parent_node.cross_alignment.is_not_start() {
             let initial_phase_size = initial_phase_sizes.get(&child_id);
 
             if let Some(initial_phase_size) = initial_phase_size {
@@ -396,15 +577,23 @@ pub fn measure_inner_nodes<Key: NodeKey>(
             Phase::Final,
         );
 
-        // Stack the child into its parent
+        // Stack the child into its parent, including the space above it left by the baseline alignment
+        let mut stacked_area = child_areas.area;
+        stacked_area.origin.y -= baseline_offset;
+        stacked_area.size.height += baseline_offset;
         mode.stack_into_node(
             parent_node,
             available_area,
-            &child_areas.area,
+            &stacked_area,
             inner_sizes,
             &child_data,
         );
 
+        if child_data.position.is_sticky() {
+            sticky_children.push((child_id, child_data, child_revalidated, child_areas));
+            continue;
+        }
+
         // Cache the child layout if it was mutated and inner nodes must be cache
         if child_revalidated && must_cache_inner_nodes {
             if let Some(measurer) = measurer {
@@ -415,4 +604,222 @@ pub fn measure_inner_nodes<Key: NodeKey>(
             layout.cache_node(child_id, child_areas);
         }
     }
+
+    if sticky_children.is_empty() {
+        return;
+    }
+
+    let parent_area = match mode {
+        MeasureMode::ParentIsCached { inner_area } => **inner_area,
+        MeasureMode::ParentIsNotCached { area, .. } => area
+            .after_gaps(&parent_node.inner_gaps())
+            .after_gaps(&parent_node.margin),
+    };
+
+    // From the last to the first, so every sticky Node knows where the next one ended up
+    let mut next_sticky_area = None;
+    for (child_id, child_data, child_revalidated, mut child_areas) in
+        sticky_children.into_iter().rev()
+    {
+        if child_revalidated && must_cache_inner_nodes {
+            if let Some(viewport) = &layout_metadata.scroll_viewport {
+                let origin = child_data.position.get_sticky_origin(
+                    &child_areas.area,
+                    &parent_area,
+                    viewport,
+                    next_sticky_area.as_ref(),
+                    &parent_node.direction,
+                );
+                let offset = origin - child_areas.area.origin;
+                if offset != Vector2D::zero() {
+                    child_areas.area = child_areas.area.translate(offset);
+                    child_areas.inner_area = child_areas.inner_area.translate(offset);
+                    for inner_child_id in dom_adapter.children_of(&child_id) {
+                        translate_node(inner_child_id, offset, layout, measurer, dom_adapter);
+                    }
+                }
+            }
+
+            if let Some(measurer) = measurer {
+                if child_data.has_layout_references {
+                    measurer.notify_layout_references(child_id, &child_areas);
+                }
+            }
+            next_sticky_area = Some(child_areas.area);
+            layout.cache_node(child_id, child_areas);
+        } else {
+            next_sticky_area = Some(child_areas.area);
+        }
+    }
+}
+
+/// Resolve the sizes in the main axis of the children of a flex container, in the units of their widths or heights.
+/// Nothing flexes when the container is sized by its children in the main axis, as there is no space to fill.
+#[allow(clippy::too_many_arguments)]
+fn measure_flex_sizes<Key: NodeKey>(
+    parent_node: &Node,
+    children: &[Key],
+    layout: &mut Torin<Key>,
+    inner_area: &Area,
+    available_area: &Area,
+    measurer: &mut Option<impl LayoutMeasurer<Key>>,
+    dom_adapter: &mut impl DOMAdapter<Key>,
+    layout_metadata: &LayoutMetadata,
+    invalidated_tree: bool,
+) -> FxHashMap<Key, f32> {
+    let is_horizontal = parent_node.direction == DirectionMode::Horizontal;
+    let parent_main_size = if is_horizontal {
+        &parent_node.width
+    } else {
+        &parent_node.height
+    };
+    if parent_main_size.inner_sized() {
+        return FxHashMap::default();
+    }
+
+    let main = |size: Size2D| {
+        if is_horizontal {
+            size.width
+        } else {
+            size.height
+        }
+    };
+    let container_size = main(inner_area.size);
+    let available_size = main(available_area.size);
+    let root_size = main(layout_metadata.root_area.size);
+
+    let mut flex_children = Vec::new();
+    let mut items = Vec::new();
+    for child_id in children {
+        let Some(child_data) = dom_adapter.get_node(child_id) else {
+            continue;
+        };
+        if child_data.position.is_out_of_flow() {
+            continue;
+        }
+        layout.intrinsic_sizes.root_dependent |= child_data.depends_on_root_size();
+
+        let (size, minimum, maximum, margin, content_box_gaps) = if is_horizontal {
+            (
+                &child_data.width,
+                &child_data.minimum_width,
+                &child_data.maximum_width,
+                child_data.margin.horizontal(),
+                child_data.content_box_gaps().horizontal(),
+            )
+        } else {
+            (
+                &child_data.height,
+                &child_data.minimum_height,
+                &child_data.maximum_height,
+                child_data.margin.vertical(),
+                child_data.content_box_gaps().vertical(),
+            )
+        };
+        let eval =
+            |size: &Size| size.eval(container_size, available_size, 0.0, root_size, Phase::Final);
+
+        // An automatic basis is the width or height, or the size of the content when that's automatic too
+        let basis = if child_data.flex.basis == Size::Inner {
+            size
+        } else {
+            &child_data.flex.basis
+        };
+        let base = if basis.is_explicit() {
+            eval(basis).unwrap_or_default()
+        } else {
+            let (_, child_areas) = measure_node(
+                *child_id,
+                &child_data,
+                layout,
+                inner_area,
+                available_area,
+                measurer,
+                false,
+                dom_adapter,
+                layout_metadata,
+                invalidated_tree,
+                Phase::Initial,
+            );
+            main(child_areas.area.size) - margin - content_box_gaps
+        };
+
+        items.push(FlexItem {
+            base,
+            minimum: eval(minimum).unwrap_or_default(),
+            maximum: eval(maximum).unwrap_or(f32::INFINITY),
+            grow: child_data.flex.grow,
+            shrink: child_data.flex.shrink,
+            outer: margin + content_box_gaps,
+        });
+        flex_children.push(*child_id);
+    }
+
+    flex_children
+        .into_iter()
+        .zip(resolve_flexible_lengths(&items, container_size))
+        .collect()
+}
+
+/// Give a fixed size in the main axis of its parent to a Node, which is already clamped by its minimum and maximum.
+fn apply_flex_size(node: &mut Node, size: f32, direction: &DirectionMode) {
+    let size = Size::Pixels(Length::new(size));
+    match direction {
+        DirectionMode::Horizontal => {
+            node.width = size;
+            node.minimum_width = Size::Inner;
+            node.maximum_width = Size::Inner;
+        }
+        DirectionMode::Vertical => {
+            node.height = size;
+            node.minimum_height = Size::Inner;
+            node.maximum_height = Size::Inner;
+        }
+    }
+}
+
+/// Get the layout metadata for the children of a Node,
+/// scrollable Nodes are the viewport of their sticky descendants.
+fn get_inner_layout_metadata(
+    node: &Node,
+    inner_area: &Area,
+    layout_metadata: &LayoutMetadata,
+) -> LayoutMetadata {
+    LayoutMetadata {
+        root_area: layout_metadata.root_area,
+        scroll_viewport: if node.scrollable {
+            Some(*inner_area)
+        } else {
+            layout_metadata.scroll_viewport
+        },
+    }
+}
+
+/// Move the cached layout of a Node and all its descendants.
+fn translate_node<Key: NodeKey>(
+    node_id: Key,
+    offset: Vector2D,
+    layout: &mut Torin<Key>,
+    measurer: &mut Option<impl LayoutMeasurer<Key>>,
+    dom_adapter: &mut impl DOMAdapter<Key>,
+) {
+    let Some(layout_node) = layout.results.get_mut(&node_id) else {
+        return;
+    };
+    layout_node.area = layout_node.area.translate(offset);
+    layout_node.inner_area = layout_node.inner_area.translate(offset);
+
+    if let Some(measurer) = measurer {
+        let has_layout_references = dom_adapter
+            .get_node(&node_id)
+            .map(|node| node.has_layout_references)
+            .unwrap_or_default();
+        if has_layout_references {
+            measurer.notify_layout_references(node_id, layout_node);
+        }
+    }
+
+    for child_id in dom_adapter.children_of(&node_id) {
+        translate_node(child_id, offset, layout, measurer, dom_adapter);
+    }
 }
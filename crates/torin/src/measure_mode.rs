@@ -80,8 +80,8 @@ impl<'a> MeasureMode<'a> {
                         inner_size: &mut inner_area.size.height,
                         area_origin: &mut area.origin.y,
                         area_size: &mut area.size.height,
-                        one_side_padding: parent_node.padding.top(),
-                        two_sides_padding: parent_node.padding.vertical(),
+                        one_side_padding: parent_node.inner_gaps().top(),
+                        two_sides_padding: parent_node.inner_gaps().vertical(),
                         one_side_margin: parent_node.margin.top(),
                         two_sides_margin: parent_node.margin.vertical(),
                         available_size: &mut available_area.size.height,
@@ -93,8 +93,8 @@ impl<'a> MeasureMode<'a> {
                         inner_size: &mut inner_area.size.width,
                         area_origin: &mut area.origin.x,
                         area_size: &mut area.size.width,
-                        one_side_padding: parent_node.padding.left(),
-                        two_sides_padding: parent_node.padding.horizontal(),
+                        one_side_padding: parent_node.inner_gaps().left(),
+                        two_sides_padding: parent_node.inner_gaps().horizontal(),
                         one_side_margin: parent_node.margin.left(),
                         two_sides_margin: parent_node.margin.horizontal(),
                         available_size: &mut available_area.size.width,
@@ -136,7 +136,7 @@ impl<'a> MeasureMode<'a> {
         inner_sizes: &mut Size2D,
         node_data: &Node,
     ) {
-        if node_data.position.is_absolute() {
+        if node_data.position.is_out_of_flow() {
             return;
         }
 
@@ -154,12 +154,12 @@ impl<'a> MeasureMode<'a> {
                     if parent_node.height.inner_sized() {
                         area.size.height = area.size.height.max(
                             content_area.size.height
-                                + parent_node.padding.vertical()
+                                + parent_node.inner_gaps().vertical()
                                 + parent_node.margin.vertical(),
                         );
                         // Keep the inner area in sync
                         inner_area.size.height = area.size.height
-                            - parent_node.padding.vertical()
+                            - parent_node.inner_gaps().vertical()
                             - parent_node.margin.vertical();
                     }
 
@@ -182,12 +182,12 @@ impl<'a> MeasureMode<'a> {
                     if parent_node.width.inner_sized() {
                         area.size.width = area.size.width.max(
                             content_area.size.width
-                                + parent_node.padding.horizontal()
+                                + parent_node.inner_gaps().horizontal()
                                 + parent_node.margin.horizontal(),
                         );
                         // Keep the inner area in sync
                         inner_area.size.width = area.size.width
-                            - parent_node.padding.horizontal()
+                            - parent_node.inner_gaps().horizontal()
                             - parent_node.margin.horizontal();
                     }
 
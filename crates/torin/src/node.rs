@@ -1,11 +1,14 @@
+use std::borrow::Cow;
+
 pub use euclid::Rect;
 
 use crate::{
     alignment::Alignment,
+    box_sizing::BoxSizing,
     direction::DirectionMode,
     gaps::Gaps,
-    geometry::Length,
-    prelude::{Content, Position},
+    geometry::{Length, Size2D},
+    prelude::{Content, ContentVisibility, Flex, Position},
     size::Size,
 };
 
@@ -31,6 +34,15 @@ pub struct Node {
     /// Inner padding
     pub padding: Gaps,
 
+    /// Inner padding relative to the width of the parent, in percentages, added to the [`padding`](Self::padding)
+    pub padding_percentage: Gaps,
+
+    /// Space taken by the border inside the Node, only part of the layout with [`BoxSizing::ContentBox`]
+    pub border: Gaps,
+
+    /// Whether the width and height include the padding and border
+    pub box_sizing: BoxSizing,
+
     /// Inner margin
     pub margin: Gaps,
 
@@ -38,6 +50,10 @@ pub struct Node {
     pub offset_x: Length,
     pub offset_y: Length,
 
+    /// Whether the inner Nodes are scrolled with the offsets,
+    /// which makes this Node the viewport of its sticky descendants.
+    pub scrollable: bool,
+
     /// Direction in which it's inner Nodes will be stacked
     pub direction: DirectionMode,
 
@@ -46,6 +62,19 @@ pub struct Node {
 
     pub content: Content,
 
+    /// How it grows and shrinks when its parent is a flex container
+    pub flex: Flex,
+
+    /// Whether the inner Nodes are skipped while this Node is outside of the root area.
+    pub content_visibility: ContentVisibility,
+
+    /// Estimated size of the inner Nodes while they are skipped, used if they were never laid out.
+    pub contain_intrinsic_size: Size2D,
+
+    /// Position of the Node among its siblings in the layout, they are laid out from the lowest to the highest.
+    /// Siblings with the same order keep their order in the DOM.
+    pub order: i32,
+
     /// A Node might depend on inner sizes but have a fixed position, like scroll views.
     pub has_layout_references: bool,
 
@@ -80,6 +109,7 @@ impl Node {
             height,
             offset_x,
             offset_y,
+            scrollable: true,
             ..Default::default()
         }
     }
@@ -178,6 +208,91 @@ impl Node {
         }
     }
 
+    /// Construct a new Node given a size, a content visibility and the estimated size of its content
+    pub fn from_size_and_content_visibility(
+        width: Size,
+        height: Size,
+        content_visibility: ContentVisibility,
+        contain_intrinsic_size: Size2D,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            content_visibility,
+            contain_intrinsic_size,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size and how it flexes
+    pub fn from_size_and_flex(width: Size, height: Size, flex: Flex) -> Self {
+        Self {
+            width,
+            height,
+            flex,
+            ..Default::default()
+        }
+    }
+
+    /// Construct a new Node given a size, padding, border and box sizing
+    pub fn from_size_and_padding_and_box_sizing(
+        width: Size,
+        height: Size,
+        padding: Gaps,
+        border: Gaps,
+        box_sizing: BoxSizing,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            padding,
+            border,
+            box_sizing,
+            ..Default::default()
+        }
+    }
+
+    /// Get this Node with its [`padding_percentage`](Self::padding_percentage) resolved against the width of its parent.
+    pub fn resolve_padding(&self, parent_width: f32) -> Cow<'_, Node> {
+        if self.padding_percentage == Gaps::default() {
+            return Cow::Borrowed(self);
+        }
+
+        let resolve = |fixed: f32, percentage: f32| fixed + parent_width / 100.0 * percentage;
+        let mut node = self.clone();
+        node.padding = Gaps::new(
+            resolve(self.padding.top(), self.padding_percentage.top()),
+            resolve(self.padding.right(), self.padding_percentage.right()),
+            resolve(self.padding.bottom(), self.padding_percentage.bottom()),
+            resolve(self.padding.left(), self.padding_percentage.left()),
+        );
+        node.padding_percentage = Gaps::default();
+        Cow::Owned(node)
+    }
+
+    /// Space between the edges of the Node and its content that isn't part of its explicit sizes.
+    pub fn content_box_gaps(&self) -> Gaps {
+        if self.box_sizing.is_content_box() {
+            Gaps::new(
+                self.padding.top() + self.border.top(),
+                self.padding.right() + self.border.right(),
+                self.padding.bottom() + self.border.bottom(),
+                self.padding.left() + self.border.left(),
+            )
+        } else {
+            Gaps::default()
+        }
+    }
+
+    /// Space between the edges of the Node and the area of its inner Nodes.
+    pub fn inner_gaps(&self) -> Gaps {
+        if self.box_sizing.is_content_box() {
+            self.content_box_gaps()
+        } else {
+            self.padding
+        }
+    }
+
     /// Has properties that depend on the inner Nodes?
     pub fn does_depend_on_inner(&self) -> bool {
         self.width.inner_sized()
@@ -186,5 +301,21 @@ impl Node {
             || self.cross_alignment.is_not_start()
             || self.main_alignment.is_not_start()
             || self.contains_text
+            || self.content.is_flex()
+    }
+
+    /// Has sizes relative to the root area?
+    pub fn depends_on_root_size(&self) -> bool {
+        [
+            &self.width,
+            &self.height,
+            &self.minimum_width,
+            &self.minimum_height,
+            &self.maximum_width,
+            &self.maximum_height,
+            &self.flex.basis,
+        ]
+        .into_iter()
+        .any(|size| matches!(size, Size::RootPercentage(_)))
     }
 }
@@ -15,7 +15,11 @@ impl LayoutMeasurer<usize> for TestingMeasurer {
         None
     }
 
-    fn should_measure_inner_children(&mut self, _node_id: usize) -> bool {
+    fn should_measure_inner_children(
+        &mut self,
+        _node_id: usize,
+        _layout_node: &LayoutNode,
+    ) -> bool {
         true
     }
 }
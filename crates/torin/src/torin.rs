@@ -8,12 +8,15 @@ use crate::{
     custom_measurer::LayoutMeasurer,
     dom_adapter::{DOMAdapter, LayoutNode, NodeKey},
     geometry::{Area, Size2D},
+    intrinsic_sizes::IntrinsicSizes,
     measure::{measure_node, Phase},
     prelude::{AreaModel, Gaps},
 };
 
 pub struct LayoutMetadata {
     pub root_area: Area,
+    /// Inner area of the closest scrollable ancestor, sticky Nodes are pinned to it.
+    pub scroll_viewport: Option<Area>,
 }
 
 /// Contains the best Root node candidate from where to start measuring
@@ -61,6 +64,9 @@ pub struct Torin<Key: NodeKey> {
 
     /// Best Root node candidate from where to start measuring
     pub root_node_candidate: RootNodeCandidate<Key>,
+
+    /// Intrinsic sizes of the Nodes, kept across measurements
+    pub(crate) intrinsic_sizes: IntrinsicSizes<Key>,
 }
 
 impl<Key: NodeKey> Default for Torin<Key> {
@@ -76,6 +82,7 @@ impl<Key: NodeKey> Torin<Key> {
             results: HashMap::default(),
             dirty: FxHashSet::default(),
             root_node_candidate: RootNodeCandidate::None,
+            intrinsic_sizes: IntrinsicSizes::default(),
         }
     }
 
@@ -85,11 +92,23 @@ impl<Key: NodeKey> Torin<Key> {
 
     /// Reset the layout
     pub fn reset(&mut self) {
+        self.invalidate_all();
+        self.intrinsic_sizes.clear();
+    }
+
+    /// Measure all the Nodes again in the next measurement, e.g. after the root area was resized.
+    /// Unlike [`Torin::reset`] the intrinsic sizes of the Nodes are kept, as their content didn't change.
+    pub fn invalidate_all(&mut self) {
         self.root_node_candidate = RootNodeCandidate::None;
         self.results.clear();
         self.dirty.clear();
     }
 
+    /// Get how many Nodes have their intrinsic sizes cached.
+    pub fn intrinsic_sizes_len(&self) -> usize {
+        self.intrinsic_sizes.len()
+    }
+
     /// Read the HashSet of dirty nodes
     pub fn get_dirty_nodes(&self) -> &FxHashSet<Key> {
         &self.dirty
@@ -98,6 +117,7 @@ impl<Key: NodeKey> Torin<Key> {
     /// Remove a Node's result and data
     pub fn raw_remove(&mut self, node_id: Key) {
         self.results.remove(&node_id);
+        self.intrinsic_sizes.remove(&node_id);
         self.dirty.remove(&node_id);
         if let RootNodeCandidate::Valid(id) = self.root_node_candidate {
             if id == node_id {
@@ -167,11 +187,12 @@ impl<Key: NodeKey> Torin<Key> {
                     // Mark parent if it depends on it's inner children
                     self.check_dirty_dependants(parent_id, dom_adapter, true);
                 } else {
-                    let parent_children = dom_adapter.children_of(&parent_id);
+                    let parent_children = dom_adapter.ordered_children_of(&parent_id);
                     let multiple_children = parent_children.len() > 1;
 
+                    // The siblings laid out after this Node depend on it
                     let mut found_node = false;
-                    for child_id in dom_adapter.children_of(&parent_id) {
+                    for child_id in parent_children {
                         if found_node {
                             self.safe_invalidate(child_id, dom_adapter);
                         }
@@ -200,6 +221,10 @@ impl<Key: NodeKey> Torin<Key> {
         if self.results.is_empty() {
             return;
         }
+        // Nodes that can't be measured, e.g inside hidden subtrees, don't need to be measured either
+        // They get measured from scratch once they become valid, as they don't have cached results
+        self.dirty
+            .retain(|node_id| dom_adapter.is_node_valid(node_id));
         for dirty in self.dirty.clone() {
             self.check_dirty_dependants(dirty, dom_adapter, false);
         }
@@ -220,12 +245,28 @@ impl<Key: NodeKey> Torin<Key> {
             return;
         }
 
+        for node_id in &self.dirty {
+            self.intrinsic_sizes.invalidate(node_id, &*dom_adapter);
+        }
+
         // Try the Root candidate otherwise use the provided Root
-        let root_id = if let RootNodeCandidate::Valid(id) = self.root_node_candidate.take() {
+        let mut root_id = if let RootNodeCandidate::Valid(id) = self.root_node_candidate.take() {
             id
         } else {
             suggested_root_id
         };
+
+        // Sticky Nodes are moved by their parents once all their siblings are measured
+        while let Some(parent_id) = dom_adapter.parent_of(&root_id) {
+            let is_sticky = dom_adapter
+                .get_node(&root_id)
+                .map(|node| node.position.is_sticky())
+                .unwrap_or_default();
+            if !is_sticky {
+                break;
+            }
+            root_id = parent_id;
+        }
         let root_parent_id = dom_adapter.parent_of(&root_id);
         let layout_node = root_parent_id
             .and_then(|root_parent_id| self.get(root_parent_id).cloned())
@@ -234,6 +275,7 @@ impl<Key: NodeKey> Torin<Key> {
                 inner_area: root_area,
                 inner_sizes: Size2D::default(),
                 margin: Gaps::default(),
+                padding: Gaps::default(),
                 data: None,
             });
         let root = dom_adapter.get_node(&root_id).unwrap();
@@ -246,7 +288,25 @@ impl<Key: NodeKey> Torin<Key> {
             root_height
         );
 
-        let metadata = LayoutMetadata { root_area };
+        // Find the viewport of the sticky Nodes when measuring from the middle of the tree
+        let mut scroll_viewport = None;
+        let mut ancestor_id = root_parent_id;
+        while let Some(id) = ancestor_id {
+            if dom_adapter
+                .get_node(&id)
+                .map(|node| node.scrollable)
+                .unwrap_or_default()
+            {
+                scroll_viewport = self.get(id).map(|layout_node| layout_node.inner_area);
+                break;
+            }
+            ancestor_id = dom_adapter.parent_of(&id);
+        }
+
+        let metadata = LayoutMetadata {
+            root_area,
+            scroll_viewport,
+        };
 
         let mut available_area = layout_node.inner_area;
         if let Some(root_parent_id) = root_parent_id {
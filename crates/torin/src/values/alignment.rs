@@ -4,6 +4,9 @@ pub enum Alignment {
     Start,
     Center,
     End,
+    /// Align the first text baseline of the children, only for the cross axis of horizontal Nodes.
+    /// Children without text are aligned by their bottom edge.
+    Baseline,
 }
 
 impl Alignment {
@@ -16,6 +19,7 @@ impl Alignment {
             Alignment::Start => "start".to_string(),
             Alignment::Center => "center".to_string(),
             Alignment::End => "end".to_string(),
+            Alignment::Baseline => "baseline".to_string(),
         }
     }
 }
@@ -0,0 +1,22 @@
+/// Whether the explicit sizes of a Node include its padding and border.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum BoxSizing {
+    /// The width and height include the padding and border.
+    #[default]
+    BorderBox,
+    /// The width and height are the size of the content, the padding and border are added on top.
+    ContentBox,
+}
+
+impl BoxSizing {
+    pub fn is_content_box(&self) -> bool {
+        self == &Self::ContentBox
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::BorderBox => "border-box".to_string(),
+            Self::ContentBox => "content-box".to_string(),
+        }
+    }
+}
@@ -3,10 +3,16 @@ pub enum Content {
     #[default]
     Normal,
     Fit,
+    /// Grow and shrink the inner Nodes in the main axis to fill the Node, see [`Flex`](crate::flex::Flex).
+    Flex,
 }
 
 impl Content {
     pub fn is_fit(&self) -> bool {
         self == &Self::Fit
     }
+
+    pub fn is_flex(&self) -> bool {
+        self == &Self::Flex
+    }
 }
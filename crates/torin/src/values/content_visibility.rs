@@ -0,0 +1,23 @@
+/// Whether the inner Nodes of a Node are laid out while it's outside of the root area.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum ContentVisibility {
+    /// The inner Nodes are always laid out.
+    #[default]
+    Visible,
+    /// The inner Nodes are skipped while the Node is outside of the root area,
+    /// and the sizes that depend on them are estimated.
+    Auto,
+}
+
+impl ContentVisibility {
+    pub fn is_auto(&self) -> bool {
+        self == &Self::Auto
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Visible => "visible".to_string(),
+            Self::Auto => "auto".to_string(),
+        }
+    }
+}
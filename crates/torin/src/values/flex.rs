@@ -0,0 +1,148 @@
+use crate::size::Size;
+
+/// How a Node grows and shrinks in the main axis of a flex container, see [`Content::Flex`](crate::content::Content::Flex).
+#[derive(PartialEq, Clone, Debug)]
+pub struct Flex {
+    /// Size of the Node in the main axis before growing or shrinking, [`Size::Inner`] to use its width or height.
+    pub basis: Size,
+    /// Share of the free space of the container that is given to this Node, `0` to not grow.
+    pub grow: f32,
+    /// Share of the overflowing space that is taken from this Node, proportional to its basis. `0` to not shrink.
+    pub shrink: f32,
+}
+
+impl Default for Flex {
+    fn default() -> Self {
+        Self {
+            basis: Size::Inner,
+            grow: 0.0,
+            shrink: 1.0,
+        }
+    }
+}
+
+impl Flex {
+    pub fn new(basis: Size, grow: f32, shrink: f32) -> Self {
+        Self {
+            basis,
+            grow,
+            shrink,
+        }
+    }
+}
+
+/// Flex item with its sizes in the main axis, in the same units as its width or height.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct FlexItem {
+    /// Size before growing or shrinking.
+    pub base: f32,
+    pub minimum: f32,
+    pub maximum: f32,
+    pub grow: f32,
+    pub shrink: f32,
+    /// Space taken in the container besides its size, e.g. its margins.
+    pub outer: f32,
+}
+
+impl FlexItem {
+    fn clamp(&self, size: f32) -> f32 {
+        // The minimum wins over the maximum, like in CSS
+        size.min(self.maximum).max(self.minimum).max(0.0)
+    }
+}
+
+/// Resolve the sizes of the items of a flex container with the given size in the main axis,
+/// following the algorithm of the CSS flexible lengths: the free space is distributed by the grow factors,
+/// or the overflow is taken by the shrink factors scaled by the bases, and the items that get clamped
+/// by their minimum or maximum sizes are frozen while the rest of the space is distributed again.
+pub(crate) fn resolve_flexible_lengths(items: &[FlexItem], container_size: f32) -> Vec<f32> {
+    let outer: f32 = items.iter().map(|item| item.outer).sum();
+    let hypothetical_sizes = items
+        .iter()
+        .map(|item| item.clamp(item.base))
+        .collect::<Vec<f32>>();
+    let growing = hypothetical_sizes.iter().sum::<f32>() + outer < container_size;
+
+    let factor = |item: &FlexItem| if growing { item.grow } else { item.shrink };
+
+    // Items that can't flex keep their hypothetical size
+    let mut sizes = hypothetical_sizes.clone();
+    let mut frozen = items
+        .iter()
+        .zip(&hypothetical_sizes)
+        .map(|(item, hypothetical_size)| {
+            factor(item) == 0.0
+                || (growing && item.base > *hypothetical_size)
+                || (!growing && item.base < *hypothetical_size)
+        })
+        .collect::<Vec<bool>>();
+
+    let free_space = |sizes: &[f32], frozen: &[bool]| {
+        let used: f32 = items
+            .iter()
+            .zip(sizes.iter().zip(frozen))
+            .map(|(item, (size, frozen))| if *frozen { *size } else { item.base })
+            .sum();
+        container_size - used - outer
+    };
+    let initial_free_space = free_space(&sizes, &frozen);
+
+    while frozen.iter().any(|frozen| !frozen) {
+        let mut remaining_free_space = free_space(&sizes, &frozen);
+
+        // Factors that add up to less than 1 only take that fraction of the free space
+        let factors: f32 = items
+            .iter()
+            .zip(&frozen)
+            .filter(|(_, frozen)| !**frozen)
+            .map(|(item, _)| factor(item))
+            .sum();
+        if factors < 1.0 && (initial_free_space * factors).abs() < remaining_free_space.abs() {
+            remaining_free_space = initial_free_space * factors;
+        }
+
+        let scaled_shrink_factors: f32 = items
+            .iter()
+            .zip(&frozen)
+            .filter(|(_, frozen)| !**frozen)
+            .map(|(item, _)| item.shrink * item.base)
+            .sum();
+
+        for (i, item) in items.iter().enumerate() {
+            if frozen[i] {
+                continue;
+            }
+            sizes[i] = if growing {
+                item.base + remaining_free_space * item.grow / factors
+            } else if scaled_shrink_factors > 0.0 {
+                item.base
+                    - remaining_free_space.abs() * item.shrink * item.base / scaled_shrink_factors
+            } else {
+                item.base
+            };
+        }
+
+        // Clamp the sizes and freeze the items that were clamped in the direction of the total violation
+        let mut violations = vec![0.0; items.len()];
+        for (i, item) in items.iter().enumerate() {
+            if frozen[i] {
+                continue;
+            }
+            let clamped_size = item.clamp(sizes[i]);
+            violations[i] = clamped_size - sizes[i];
+            sizes[i] = clamped_size;
+        }
+        let total_violation: f32 = violations.iter().sum();
+
+        for (i, violation) in violations.iter().enumerate() {
+            if frozen[i] {
+                continue;
+            }
+            frozen[i] = total_violation == 0.0
+                || (total_violation > 0.0 && *violation > 0.0)
+                || (total_violation < 0.0 && *violation < 0.0);
+        }
+    }
+
+    sizes
+}
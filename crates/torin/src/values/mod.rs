@@ -1,14 +1,20 @@
 pub mod alignment;
+pub mod box_sizing;
 pub mod content;
+pub mod content_visibility;
 pub mod direction;
+pub mod flex;
 pub mod gaps;
 pub mod position;
 pub mod size;
 
 pub mod prelude {
     pub use crate::alignment::*;
+    pub use crate::box_sizing::*;
     pub use crate::content::*;
+    pub use crate::content_visibility::*;
     pub use crate::direction::*;
+    pub use crate::flex::*;
     pub use crate::gaps::*;
     pub use crate::position::*;
     pub use crate::size::*;
@@ -1,6 +1,6 @@
 use std::ops::Deref;
 
-use crate::prelude::{Area, Point2D, Size2D};
+use crate::prelude::{Area, DirectionMode, Point2D, Size2D};
 
 #[derive(Default, PartialEq, Clone, Debug)]
 pub struct AbsolutePosition {
@@ -16,12 +16,22 @@ pub enum Position {
     Stacked,
 
     Absolute(Box<AbsolutePosition>),
+
+    /// Stacked like any other Node, but pinned to the viewport of its closest scrollable ancestor
+    /// once it has been scrolled past the given offsets, without ever leaving its parent.
+    Sticky(Box<AbsolutePosition>),
+
+    /// Like absolute, but relative to the root area instead of its parent.
+    /// Its size is relative to the root area too and it ignores the scroll of its ancestors.
+    Global(Box<AbsolutePosition>),
 }
 
 impl Position {
     pub fn is_empty(&self) -> bool {
         match self {
-            Self::Absolute(absolute_position) => {
+            Self::Absolute(absolute_position)
+            | Self::Sticky(absolute_position)
+            | Self::Global(absolute_position) => {
                 let AbsolutePosition {
                     top,
                     right,
@@ -47,38 +57,91 @@ impl Position {
         matches!(self, Self::Absolute { .. })
     }
 
+    pub fn new_sticky() -> Self {
+        Self::Sticky(Box::default())
+    }
+
+    pub fn is_sticky(&self) -> bool {
+        matches!(self, Self::Sticky { .. })
+    }
+
+    /// Make this position sticky, keeping the offsets that were already set.
+    pub fn set_sticky(&mut self) {
+        *self = match std::mem::take(self) {
+            Self::Absolute(absolute_position)
+            | Self::Sticky(absolute_position)
+            | Self::Global(absolute_position) => Self::Sticky(absolute_position),
+            Self::Stacked => Self::new_sticky(),
+        }
+    }
+
+    pub fn new_global() -> Self {
+        Self::Global(Box::default())
+    }
+
+    pub fn is_global(&self) -> bool {
+        matches!(self, Self::Global { .. })
+    }
+
+    /// Make this position global, keeping the offsets that were already set.
+    pub fn set_global(&mut self) {
+        *self = match std::mem::take(self) {
+            Self::Absolute(absolute_position)
+            | Self::Sticky(absolute_position)
+            | Self::Global(absolute_position) => Self::Global(absolute_position),
+            Self::Stacked => Self::new_global(),
+        }
+    }
+
+    /// Check if the Node is taken out of the flow of its siblings, so it doesn't affect the layout of its parent.
+    pub fn is_out_of_flow(&self) -> bool {
+        self.is_absolute() || self.is_global()
+    }
+
     pub fn set_top(&mut self, value: f32) {
-        if !self.is_absolute() {
+        if let Self::Stacked = self {
             *self = Self::new_absolute();
         }
-        if let Self::Absolute(absolute_position) = self {
+        if let Self::Absolute(absolute_position)
+        | Self::Sticky(absolute_position)
+        | Self::Global(absolute_position) = self
+        {
             absolute_position.top = Some(value)
         }
     }
 
     pub fn set_right(&mut self, value: f32) {
-        if !self.is_absolute() {
+        if let Self::Stacked = self {
             *self = Self::new_absolute();
         }
-        if let Self::Absolute(absolute_position) = self {
+        if let Self::Absolute(absolute_position)
+        | Self::Sticky(absolute_position)
+        | Self::Global(absolute_position) = self
+        {
             absolute_position.right = Some(value)
         }
     }
 
     pub fn set_bottom(&mut self, value: f32) {
-        if !self.is_absolute() {
+        if let Self::Stacked = self {
             *self = Self::new_absolute();
         }
-        if let Self::Absolute(absolute_position) = self {
+        if let Self::Absolute(absolute_position)
+        | Self::Sticky(absolute_position)
+        | Self::Global(absolute_position) = self
+        {
             absolute_position.bottom = Some(value)
         }
     }
 
     pub fn set_left(&mut self, value: f32) {
-        if !self.is_absolute() {
+        if let Self::Stacked = self {
             *self = Self::new_absolute();
         }
-        if let Self::Absolute(absolute_position) = self {
+        if let Self::Absolute(absolute_position)
+        | Self::Sticky(absolute_position)
+        | Self::Global(absolute_position) = self
+        {
             absolute_position.left = Some(value)
         }
     }
@@ -90,8 +153,9 @@ impl Position {
         area_size: &Size2D,
     ) -> Point2D {
         match self {
-            Position::Stacked => available_parent_area.origin,
-            Position::Absolute(absolute_position) => {
+            Position::Stacked | Position::Sticky(_) => available_parent_area.origin,
+            // Global Nodes are measured with the root area as their parent area
+            Position::Absolute(absolute_position) | Position::Global(absolute_position) => {
                 let AbsolutePosition {
                     top,
                     right,
@@ -120,4 +184,54 @@ impl Position {
             }
         }
     }
+
+    /// Get the origin of a sticky Node, given the area it was stacked into (`area`),
+    /// the inner area of its parent (`parent_area`), the viewport where it must stay visible (`viewport`)
+    /// and the area of the next sticky sibling (`next_sticky_area`), which pushes the Node away when they meet.
+    pub fn get_sticky_origin(
+        &self,
+        area: &Area,
+        parent_area: &Area,
+        viewport: &Area,
+        next_sticky_area: Option<&Area>,
+        direction: &DirectionMode,
+    ) -> Point2D {
+        let Position::Sticky(sticky_position) = self else {
+            return area.origin;
+        };
+        let AbsolutePosition {
+            top,
+            right,
+            bottom,
+            left,
+        } = sticky_position.deref();
+
+        let (max_x, max_y) = match (next_sticky_area, direction) {
+            (Some(next), DirectionMode::Horizontal) => {
+                (parent_area.max_x().min(next.min_x()), parent_area.max_y())
+            }
+            (Some(next), DirectionMode::Vertical) => {
+                (parent_area.max_x(), parent_area.max_y().min(next.min_y()))
+            }
+            _ => (parent_area.max_x(), parent_area.max_y()),
+        };
+
+        let mut y = area.min_y();
+        if let Some(top) = top {
+            y = y.max((viewport.min_y() + top).min(max_y - area.height()));
+        }
+        if let Some(bottom) = bottom {
+            y = y.min((viewport.max_y() - bottom - area.height()).max(parent_area.min_y()));
+        }
+
+        let mut x = area.min_x();
+        if let Some(left) = left {
+            x = x.max((viewport.min_x() + left).min(max_x - area.width()));
+        }
+        if let Some(right) = right {
+            x = x.min((viewport.max_x() - right - area.width()).max(parent_area.min_x()));
+        }
+
+        Point2D::new(x, y)
+    }
 }
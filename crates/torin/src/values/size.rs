@@ -27,6 +27,17 @@ impl Size {
         matches!(self, Self::Inner | Self::FillMinimum)
     }
 
+    /// Check if it's given by the user, rather than by the available space or the inner Nodes.
+    pub fn is_explicit(&self) -> bool {
+        matches!(
+            self,
+            Self::Pixels(_)
+                | Self::Percentage(_)
+                | Self::RootPercentage(_)
+                | Self::DynamicCalculations(_)
+        )
+    }
+
     pub fn pretty(&self) -> String {
         match self {
             Size::Inner => "auto".to_string(),
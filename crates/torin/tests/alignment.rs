@@ -1,4 +1,7 @@
+use std::{collections::HashMap, sync::Arc};
+
 use euclid::Length;
+use freya_native_core::SendAnyMap;
 use torin::{prelude::*, test_utils::*};
 
 #[test]
@@ -330,3 +333,107 @@ pub fn nested_unsized_alignment() {
         Rect::new(Point2D::new(50.0, 50.0), Size2D::new(100.0, 100.0)),
     );
 }
+
+/// Measures some Nodes as if they were texts, with a given size and baseline.
+struct TextMeasurer(HashMap<usize, (Size2D, f32)>);
+
+impl LayoutMeasurer<usize> for TextMeasurer {
+    fn measure(
+        &mut self,
+        node_id: usize,
+        _node: &Node,
+        _area_size: &Size2D,
+    ) -> Option<(Size2D, Arc<SendAnyMap>)> {
+        self.0
+            .get(&node_id)
+            .map(|(size, _)| (*size, Arc::new(SendAnyMap::new())))
+    }
+
+    fn should_measure_inner_children(
+        &mut self,
+        _node_id: usize,
+        _layout_node: &LayoutNode,
+    ) -> bool {
+        true
+    }
+
+    fn baseline(&self, node_id: usize, _layout_node: &LayoutNode) -> Option<f32> {
+        self.0.get(&node_id).map(|(_, baseline)| *baseline)
+    }
+}
+
+#[test]
+pub fn baseline_alignment_with_mixed_font_sizes() {
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = Some(TextMeasurer(HashMap::from([
+        // Small text
+        (1, (Size2D::new(50.0, 12.0), 10.0)),
+        // Big text
+        (2, (Size2D::new(100.0, 36.0), 30.0)),
+    ])));
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_alignments_and_direction(
+            Size::Inner,
+            Size::Inner,
+            Alignment::Start,
+            Alignment::Baseline,
+            DirectionMode::Horizontal,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(Size::Inner, Size::Inner, DirectionMode::Vertical),
+    );
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(Size::Inner, Size::Inner, DirectionMode::Vertical),
+    );
+    // Not a text, aligned by its bottom edge
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(20.0)),
+            Size::Pixels(Length::new(20.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(1).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 20.0), Size2D::new(50.0, 12.0)),
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().visible_area(),
+        Rect::new(Point2D::new(50.0, 0.0), Size2D::new(100.0, 36.0)),
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().visible_area(),
+        Rect::new(Point2D::new(150.0, 10.0), Size2D::new(20.0, 20.0)),
+    );
+
+    // Tall enough for the descent of the big text
+    assert_eq!(
+        layout.get(0).unwrap().visible_area(),
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(170.0, 36.0)),
+    );
+}
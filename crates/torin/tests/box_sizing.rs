@@ -0,0 +1,99 @@
+use torin::{prelude::*, test_utils::*};
+
+fn box_sizing_layout(box_sizing: BoxSizing, width: Size, height: Size) -> Torin<usize> {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_padding_and_box_sizing(
+            width,
+            height,
+            Gaps::new(5.0, 10.0, 15.0, 20.0),
+            Gaps::new(2.0, 2.0, 2.0, 2.0),
+            box_sizing,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    layout
+}
+
+#[test]
+pub fn border_box() {
+    let layout = box_sizing_layout(
+        BoxSizing::BorderBox,
+        Size::Pixels(Length::new(200.0)),
+        Size::Pixels(Length::new(200.0)),
+    );
+
+    // The padding is included in the size and the border doesn't take space
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 200.0)),
+    );
+    assert_eq!(
+        layout.get(0).unwrap().inner_area,
+        Rect::new(Point2D::new(20.0, 5.0), Size2D::new(170.0, 180.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(20.0, 5.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn content_box() {
+    let layout = box_sizing_layout(
+        BoxSizing::ContentBox,
+        Size::Pixels(Length::new(200.0)),
+        Size::Pixels(Length::new(200.0)),
+    );
+
+    // The padding and border are added to the size
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(234.0, 224.0)),
+    );
+    assert_eq!(
+        layout.get(0).unwrap().inner_area,
+        Rect::new(Point2D::new(22.0, 7.0), Size2D::new(200.0, 200.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(22.0, 7.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn content_box_inner_sized() {
+    let layout = box_sizing_layout(BoxSizing::ContentBox, Size::Inner, Size::Inner);
+
+    // Wraps the content, the padding and the border
+    assert_eq!(
+        layout.get(0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(84.0, 74.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(22.0, 7.0), Size2D::new(50.0, 50.0)),
+    );
+}
@@ -0,0 +1,90 @@
+use torin::{prelude::*, test_utils::*};
+
+fn filler(height: f32) -> Node {
+    Node::from_size_and_direction(
+        Size::Percentage(Length::new(100.0)),
+        Size::Pixels(Length::new(height)),
+        DirectionMode::Vertical,
+    )
+}
+
+#[test]
+pub fn content_visibility_auto() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(1, Some(0), vec![], filler(1200.0));
+    mocked_dom.add(
+        2,
+        Some(0),
+        vec![3],
+        Node::from_size_and_content_visibility(
+            Size::Percentage(Length::new(100.0)),
+            Size::Inner,
+            ContentVisibility::Auto,
+            Size2D::new(0.0, 100.0),
+        ),
+    );
+    mocked_dom.add(3, Some(2), vec![], filler(300.0));
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Outside of the root area, so its content is skipped and its height estimated
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 1200.0), Size2D::new(1000.0, 100.0)),
+    );
+    assert!(layout.get(3).is_none());
+
+    mocked_dom.set_node(1, filler(500.0));
+    layout.invalidate(0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Inside of the root area, so its content is laid out
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 500.0), Size2D::new(1000.0, 300.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 500.0), Size2D::new(1000.0, 300.0)),
+    );
+
+    mocked_dom.set_node(1, filler(1200.0));
+    layout.invalidate(0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Outside again, it keeps the last height it had
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 1200.0), Size2D::new(1000.0, 300.0)),
+    );
+    assert!(layout.get(3).is_none());
+}
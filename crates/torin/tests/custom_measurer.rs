@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use freya_native_core::SendAnyMap;
+use torin::{prelude::*, test_utils::*};
+
+/// Measures a Node itself like a custom element, whose children are never laid out.
+struct SelfMeasurer {
+    measured_id: usize,
+}
+
+impl LayoutMeasurer<usize> for SelfMeasurer {
+    fn measure(
+        &mut self,
+        node_id: usize,
+        _node: &Node,
+        _area_size: &Size2D,
+    ) -> Option<(Size2D, Arc<SendAnyMap>)> {
+        (node_id == self.measured_id)
+            .then(|| (Size2D::new(50.0, 50.0), Arc::new(SendAnyMap::new())))
+    }
+
+    fn should_measure_inner_children(&mut self, _node_id: usize, layout_node: &LayoutNode) -> bool {
+        layout_node.data.is_none()
+    }
+}
+
+#[test]
+pub fn measured_node_children_are_skipped_when_the_parent_is_relayouted() {
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = Some(SelfMeasurer { measured_id: 2 });
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 4],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(500.0)),
+            Size::Pixels(Length::new(500.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![3],
+        Node::from_size_and_direction(Size::Inner, Size::Inner, DirectionMode::Vertical),
+    );
+    mocked_dom.add(
+        3,
+        Some(2),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(300.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        4,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+    assert!(layout.get(3).is_none());
+
+    // Only the sibling changes, so the measured Node reuses its cached layout
+    mocked_dom.set_node(
+        4,
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(200.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    layout.invalidate(4);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 50.0), Size2D::new(200.0, 200.0)),
+    );
+    assert!(layout.get(3).is_none());
+}
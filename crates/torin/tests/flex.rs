@@ -0,0 +1,186 @@
+use torin::{prelude::*, test_utils::*};
+
+fn flex_container(width: f32) -> Node {
+    Node {
+        content: Content::Flex,
+        ..Node::from_size_and_direction(
+            Size::Pixels(Length::new(width)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Horizontal,
+        )
+    }
+}
+
+fn flex_item(basis: f32, grow: f32, shrink: f32) -> Node {
+    Node::from_size_and_flex(
+        Size::Inner,
+        Size::Pixels(Length::new(100.0)),
+        Flex::new(Size::Pixels(Length::new(basis)), grow, shrink),
+    )
+}
+
+fn measure(
+    layout: &mut Torin<usize>,
+    measurer: &mut Option<TestingMeasurer>,
+    dom: &mut TestingDOM,
+) {
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        measurer,
+        dom,
+    );
+}
+
+fn width(layout: &Torin<usize>, node_id: usize) -> f32 {
+    layout.get(node_id).unwrap().area.width()
+}
+
+#[test]
+pub fn flex_grow() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1, 2], flex_container(1000.0));
+    mocked_dom.add(1, Some(0), vec![], flex_item(100.0, 1.0, 1.0));
+    mocked_dom.add(2, Some(0), vec![], flex_item(200.0, 3.0, 1.0));
+
+    measure(&mut layout, &mut measurer, &mut mocked_dom);
+
+    // The 700 pixels left are split 1:3
+    assert_eq!(width(&layout, 1), 275.0);
+    assert_eq!(width(&layout, 2), 725.0);
+    assert_eq!(layout.get(2).unwrap().area.min_x(), 275.0);
+}
+
+#[test]
+pub fn flex_shrink() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1, 2, 3], flex_container(500.0));
+    mocked_dom.add(1, Some(0), vec![], flex_item(300.0, 0.0, 1.0));
+    mocked_dom.add(2, Some(0), vec![], flex_item(300.0, 0.0, 1.0));
+    mocked_dom.add(3, Some(0), vec![], flex_item(200.0, 0.0, 2.0));
+
+    measure(&mut layout, &mut measurer, &mut mocked_dom);
+
+    // The 300 overflowing pixels are taken proportionally to the shrink factors scaled by the bases, 300:300:400
+    assert_eq!(width(&layout, 1), 210.0);
+    assert_eq!(width(&layout, 2), 210.0);
+    assert_eq!(width(&layout, 3), 80.0);
+}
+
+#[test]
+pub fn flex_max_size() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1, 2, 3], flex_container(1000.0));
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            maximum_width: Size::Pixels(Length::new(100.0)),
+            ..flex_item(0.0, 1.0, 1.0)
+        },
+    );
+    mocked_dom.add(2, Some(0), vec![], flex_item(0.0, 1.0, 1.0));
+    mocked_dom.add(3, Some(0), vec![], flex_item(0.0, 1.0, 1.0));
+
+    measure(&mut layout, &mut measurer, &mut mocked_dom);
+
+    // The first item is frozen at its maximum and the rest of the space goes to the others
+    assert_eq!(width(&layout, 1), 100.0);
+    assert_eq!(width(&layout, 2), 450.0);
+    assert_eq!(width(&layout, 3), 450.0);
+}
+
+#[test]
+pub fn flex_min_size() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1, 2], flex_container(400.0));
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![],
+        Node {
+            minimum_width: Size::Pixels(Length::new(250.0)),
+            ..flex_item(300.0, 0.0, 1.0)
+        },
+    );
+    mocked_dom.add(2, Some(0), vec![], flex_item(300.0, 0.0, 1.0));
+
+    measure(&mut layout, &mut measurer, &mut mocked_dom);
+
+    // The first item stops shrinking at its minimum, so the second one takes the rest of the overflow
+    assert_eq!(width(&layout, 1), 250.0);
+    assert_eq!(width(&layout, 2), 150.0);
+}
+
+#[test]
+pub fn flex_fractional_grow() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1], flex_container(1000.0));
+    mocked_dom.add(1, Some(0), vec![], flex_item(200.0, 0.5, 1.0));
+
+    measure(&mut layout, &mut measurer, &mut mocked_dom);
+
+    // Grow factors that add up to less than 1 only take that fraction of the free space
+    assert_eq!(width(&layout, 1), 600.0);
+}
+
+#[test]
+pub fn flex_auto_basis() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(0, None, vec![1, 3], flex_container(1000.0));
+    // The basis is the size of the content
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2],
+        Node::from_size_and_flex(
+            Size::Inner,
+            Size::Pixels(Length::new(100.0)),
+            Flex::new(Size::Inner, 1.0, 1.0),
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(150.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    // The basis is the width, with its margins taking space too
+    mocked_dom.add(
+        3,
+        Some(0),
+        vec![],
+        Node {
+            margin: Gaps::new(0.0, 25.0, 0.0, 25.0),
+            ..Node::from_size_and_flex(
+                Size::Pixels(Length::new(100.0)),
+                Size::Pixels(Length::new(100.0)),
+                Flex::new(Size::Inner, 1.0, 1.0),
+            )
+        },
+    );
+
+    measure(&mut layout, &mut measurer, &mut mocked_dom);
+
+    // The 700 pixels left are split equally
+    assert_eq!(width(&layout, 1), 500.0);
+    assert_eq!(layout.get(3).unwrap().visible_area().width(), 450.0);
+    assert_eq!(layout.get(3).unwrap().visible_area().min_x(), 525.0);
+}
@@ -0,0 +1,203 @@
+use std::sync::Arc;
+
+use freya_native_core::SendAnyMap;
+use torin::{prelude::*, test_utils::*};
+
+/// Measures the last Node as a text of the given size, counting how many times it was measured.
+struct CountingMeasurer {
+    text_id: usize,
+    text_size: Size2D,
+    measurements: usize,
+}
+
+impl LayoutMeasurer<usize> for CountingMeasurer {
+    fn measure(
+        &mut self,
+        node_id: usize,
+        _node: &Node,
+        _area_size: &Size2D,
+    ) -> Option<(Size2D, Arc<SendAnyMap>)> {
+        if node_id != self.text_id {
+            return None;
+        }
+        self.measurements += 1;
+        Some((self.text_size, Arc::new(SendAnyMap::new())))
+    }
+
+    fn should_measure_inner_children(
+        &mut self,
+        _node_id: usize,
+        _layout_node: &LayoutNode,
+    ) -> bool {
+        true
+    }
+}
+
+fn centered(width: Size, height: Size) -> Node {
+    Node::from_size_and_alignments_and_direction(
+        width,
+        height,
+        Alignment::Center,
+        Alignment::Center,
+        DirectionMode::Vertical,
+    )
+}
+
+/// A fixed panel centered in the root, with a text centered in some nested auto-sized Nodes.
+fn nested_dom(depth: usize) -> TestingDOM {
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        centered(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2],
+        centered(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(200.0)),
+        ),
+    );
+    for node_id in 2..depth {
+        mocked_dom.add(
+            node_id,
+            Some(node_id - 1),
+            vec![node_id + 1],
+            centered(Size::Inner, Size::Inner),
+        );
+    }
+    mocked_dom.add(
+        depth,
+        Some(depth - 1),
+        vec![],
+        Node::from_size_and_direction(Size::Inner, Size::Inner, DirectionMode::Vertical),
+    );
+    mocked_dom
+}
+
+fn measure(
+    layout: &mut Torin<usize>,
+    measurer: &mut Option<CountingMeasurer>,
+    mocked_dom: &mut TestingDOM,
+    root_size: f32,
+) {
+    layout.find_best_root(mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(root_size, root_size)),
+        measurer,
+        mocked_dom,
+    );
+}
+
+fn counting_measurer(text_id: usize) -> Option<CountingMeasurer> {
+    Some(CountingMeasurer {
+        text_id,
+        text_size: Size2D::new(50.0, 20.0),
+        measurements: 0,
+    })
+}
+
+#[test]
+pub fn intrinsic_sizes_are_reused_while_resizing() {
+    const DEPTH: usize = 8;
+
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = counting_measurer(DEPTH);
+    let mut mocked_dom = nested_dom(DEPTH);
+
+    measure(&mut layout, &mut measurer, &mut mocked_dom, 1000.0);
+    let first_measurements = measurer.as_ref().unwrap().measurements;
+
+    // The content of the panel keeps its size while the root is resized
+    for root_size in [900.0, 800.0, 700.0] {
+        layout.invalidate_all();
+        measurer.as_mut().unwrap().measurements = 0;
+        measure(&mut layout, &mut measurer, &mut mocked_dom, root_size);
+        assert!(measurer.as_ref().unwrap().measurements < first_measurements);
+
+        let offset = (root_size - 200.0) / 2.0;
+        assert_eq!(
+            layout.get(1).unwrap().area,
+            Rect::new(Point2D::new(offset, offset), Size2D::new(200.0, 200.0)),
+        );
+        assert_eq!(
+            layout.get(DEPTH).unwrap().area,
+            Rect::new(
+                Point2D::new(offset + 75.0, offset + 90.0),
+                Size2D::new(50.0, 20.0)
+            ),
+        );
+    }
+
+    // Same layout as measuring from scratch
+    let mut fresh_layout = Torin::<usize>::new();
+    measure(&mut fresh_layout, &mut measurer, &mut mocked_dom, 700.0);
+    for node_id in 0..=DEPTH {
+        assert_eq!(layout.get(node_id), fresh_layout.get(node_id));
+    }
+}
+
+#[test]
+pub fn intrinsic_sizes_are_invalidated_with_their_content() {
+    const DEPTH: usize = 4;
+
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = counting_measurer(DEPTH);
+    let mut mocked_dom = nested_dom(DEPTH);
+
+    measure(&mut layout, &mut measurer, &mut mocked_dom, 1000.0);
+    assert!(layout.intrinsic_sizes_len() > 0);
+
+    // The text got bigger, so the Nodes around it did too
+    measurer.as_mut().unwrap().text_size = Size2D::new(100.0, 40.0);
+    layout.invalidate(DEPTH);
+    measure(&mut layout, &mut measurer, &mut mocked_dom, 1000.0);
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(450.0, 480.0), Size2D::new(100.0, 40.0)),
+    );
+    assert_eq!(
+        layout.get(DEPTH).unwrap().area,
+        Rect::new(Point2D::new(450.0, 480.0), Size2D::new(100.0, 40.0)),
+    );
+
+    // Resetting the layout forgets them
+    layout.reset();
+    assert_eq!(layout.intrinsic_sizes_len(), 0);
+}
+
+#[test]
+pub fn intrinsic_sizes_relative_to_the_root_are_not_reused() {
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = counting_measurer(3);
+    let mut mocked_dom = nested_dom(3);
+    mocked_dom.set_node(
+        3,
+        Node::from_size_and_direction(
+            Size::Inner,
+            Size::RootPercentage(Length::new(10.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    measure(&mut layout, &mut measurer, &mut mocked_dom, 1000.0);
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(475.0, 450.0), Size2D::new(50.0, 100.0)),
+    );
+
+    layout.invalidate_all();
+    measure(&mut layout, &mut measurer, &mut mocked_dom, 500.0);
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(225.0, 225.0), Size2D::new(50.0, 50.0)),
+    );
+}
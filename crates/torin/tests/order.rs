@@ -0,0 +1,77 @@
+use torin::{prelude::*, test_utils::*};
+
+fn item(width: f32, order: i32) -> Node {
+    Node {
+        order,
+        ..Node::from_size_and_direction(
+            Size::Pixels(Length::new(width)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Vertical,
+        )
+    }
+}
+
+#[test]
+pub fn order() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            DirectionMode::Horizontal,
+        ),
+    );
+    mocked_dom.add(1, Some(0), vec![], item(100.0, 2));
+    mocked_dom.add(2, Some(0), vec![], item(200.0, 0));
+    mocked_dom.add(3, Some(0), vec![], item(300.0, 1));
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(200.0, 0.0), Size2D::new(300.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(500.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+
+    // Nodes with the same order keep their order in the DOM
+    mocked_dom.set_node(1, item(100.0, 0));
+    mocked_dom.set_node(3, item(300.0, -1));
+    layout.invalidate(0);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(300.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(300.0, 0.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(400.0, 0.0), Size2D::new(200.0, 100.0)),
+    );
+}
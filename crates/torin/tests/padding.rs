@@ -38,3 +38,87 @@ pub fn padding() {
         Rect::new(Point2D::new(20.0, 5.0), Size2D::new(170.0, 180.0)),
     );
 }
+
+fn percentage_padding_layout(box_sizing: BoxSizing) -> Torin<usize> {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(500.0)),
+            Size::Pixels(Length::new(500.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2],
+        Node {
+            padding_percentage: Gaps::new(10.0, 0.0, 0.0, 5.0),
+            ..Node::from_size_and_padding_and_box_sizing(
+                Size::Pixels(Length::new(300.0)),
+                Size::Pixels(Length::new(200.0)),
+                Gaps::new(0.0, 0.0, 0.0, 10.0),
+                Gaps::default(),
+                box_sizing,
+            )
+        },
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    layout
+}
+
+#[test]
+pub fn percentage_padding() {
+    let layout = percentage_padding_layout(BoxSizing::BorderBox);
+
+    // The percentages are relative to the width of the parent, even the vertical ones
+    assert_eq!(
+        layout.get(1).unwrap().padding,
+        Gaps::new(50.0, 0.0, 0.0, 35.0)
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(300.0, 200.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(35.0, 50.0), Size2D::new(265.0, 150.0)),
+    );
+}
+
+#[test]
+pub fn percentage_padding_with_content_box() {
+    let layout = percentage_padding_layout(BoxSizing::ContentBox);
+
+    // The resolved padding is added to the size
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(335.0, 250.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(35.0, 50.0), Size2D::new(300.0, 200.0)),
+    );
+}
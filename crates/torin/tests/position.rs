@@ -111,3 +111,282 @@ pub fn position() {
         Rect::new(Point2D::new(100.0, 650.0), Size2D::new(200.0, 200.0)),
     );
 }
+
+#[test]
+pub fn sticky_position() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let sticky_header = || {
+        Node::from_size_and_position(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            Position::Sticky(Box::new(AbsolutePosition {
+                top: Some(0.0),
+                right: None,
+                bottom: None,
+                left: None,
+            })),
+        )
+    };
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 4],
+        Node::from_size_and_scroll(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(300.0)),
+            Length::new(0.0),
+            Length::new(-250.0),
+        ),
+    );
+    // Sections with a sticky header each
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 3],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Inner,
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(2, Some(1), vec![], sticky_header());
+    mocked_dom.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(150.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(
+        4,
+        Some(0),
+        vec![5, 6],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Inner,
+            DirectionMode::Vertical,
+        ),
+    );
+    mocked_dom.add(5, Some(4), vec![], sticky_header());
+    mocked_dom.add(
+        6,
+        Some(4),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(150.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The first section got scrolled out and pushed its header away
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, -250.0), Size2D::new(1000.0, 200.0)),
+    );
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, -100.0), Size2D::new(1000.0, 50.0)),
+    );
+    // The second section is still visible, so its header is pinned to the top of the viewport
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 50.0)),
+    );
+    // Content is stacked as if the headers had not moved
+    assert_eq!(
+        layout.get(6).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 150.0)),
+    );
+
+    // Scroll back to the top
+    mocked_dom.set_node(
+        0,
+        Node::from_size_and_scroll(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(300.0)),
+            Length::new(0.0),
+            Length::new(0.0),
+        ),
+    );
+    layout.invalidate(0);
+    layout.find_best_root(&mut mocked_dom);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Both headers are back in the normal flow
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(5).unwrap().area,
+        Rect::new(Point2D::new(0.0, 200.0), Size2D::new(1000.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn sticky_siblings() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let sticky_header = || {
+        Node::from_size_and_position(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(50.0)),
+            Position::Sticky(Box::new(AbsolutePosition {
+                top: Some(0.0),
+                right: None,
+                bottom: None,
+                left: None,
+            })),
+        )
+    };
+    let content = || {
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(150.0)),
+            DirectionMode::Vertical,
+        )
+    };
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1, 2, 3, 4],
+        Node::from_size_and_scroll(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(300.0)),
+            Length::new(0.0),
+            Length::new(-170.0),
+        ),
+    );
+    mocked_dom.add(1, Some(0), vec![], sticky_header());
+    mocked_dom.add(2, Some(0), vec![], content());
+    mocked_dom.add(3, Some(0), vec![], sticky_header());
+    mocked_dom.add(4, Some(0), vec![], content());
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // The second header is about to replace the first one
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 30.0), Size2D::new(1000.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(1).unwrap().area,
+        Rect::new(Point2D::new(0.0, -20.0), Size2D::new(1000.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn global_position() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_dom = TestingDOM::default();
+    mocked_dom.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_padding(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Gaps::new(20.0, 20.0, 20.0, 20.0),
+        ),
+    );
+    mocked_dom.add(
+        1,
+        Some(0),
+        vec![2, 3, 4],
+        Node::from_size_and_scroll(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(300.0)),
+            Length::new(0.0),
+            Length::new(-100.0),
+        ),
+    );
+    mocked_dom.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_position(
+            Size::Percentage(Length::new(50.0)),
+            Size::Pixels(Length::new(100.0)),
+            Position::Global(Box::new(AbsolutePosition {
+                top: Some(10.0),
+                right: None,
+                bottom: None,
+                left: Some(10.0),
+            })),
+        ),
+    );
+    mocked_dom.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_position(
+            Size::Pixels(Length::new(200.0)),
+            Size::Pixels(Length::new(200.0)),
+            Position::Global(Box::new(AbsolutePosition {
+                top: None,
+                right: Some(50.0),
+                bottom: Some(100.0),
+                left: None,
+            })),
+        ),
+    );
+    mocked_dom.add(
+        4,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            DirectionMode::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_dom,
+    );
+
+    // Relative to the root area, sized by it and not scrolled
+    assert_eq!(
+        layout.get(2).unwrap().area,
+        Rect::new(Point2D::new(10.0, 10.0), Size2D::new(500.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(3).unwrap().area,
+        Rect::new(Point2D::new(750.0, 700.0), Size2D::new(200.0, 200.0)),
+    );
+    // Siblings are stacked as if the global Nodes weren't there
+    assert_eq!(
+        layout.get(4).unwrap().area,
+        Rect::new(Point2D::new(20.0, -80.0), Size2D::new(100.0, 100.0)),
+    );
+}
@@ -0,0 +1,80 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+// Small curves show the difference the most, zoom into a screenshot to compare the edges
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::builder()
+            .with_width(400.0)
+            .with_height(300.0)
+            .with_title("Antialias")
+            .with_render_settings(RenderSettings {
+                multisampling: 4,
+                ..RenderSettings::default()
+            })
+            .build(),
+    );
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            direction: "horizontal",
+            main_align: "space-around",
+            padding: "20",
+            Column {
+                title: "Smooth",
+                antialias: true,
+            }
+            Column {
+                title: "Pixel art",
+                antialias: false,
+            }
+        }
+    )
+}
+
+#[component]
+fn Column(title: &'static str, antialias: bool) -> Element {
+    rsx!(
+        rect {
+            antialias: "{antialias}",
+            label {
+                "{title}"
+            }
+            rect {
+                margin: "10 0 0 0",
+                width: "24",
+                height: "24",
+                corner_radius: "8",
+                background: "rgb(0, 119, 182)",
+            }
+            rect {
+                margin: "10 0 0 0",
+                width: "24",
+                height: "24",
+                corner_radius: "6",
+                border: "1 solid black",
+            }
+            // Clipped by a curved clip rather than painted with a curved shape
+            rect {
+                margin: "10 0 0 0",
+                width: "32",
+                height: "32",
+                clip_path: "circle()",
+                rect {
+                    width: "100%",
+                    height: "100%",
+                    background: "rgb(230, 57, 70)",
+                }
+            }
+        }
+    )
+}
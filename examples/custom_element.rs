@@ -0,0 +1,66 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+use skia_safe::{textlayout::FontCollection, Canvas, Color, Paint};
+
+/// Round element that measures itself and is only clicked inside of its circle.
+struct Dot {
+    diameter: f32,
+    color: Color,
+}
+
+impl CustomElement for Dot {
+    fn measure(&self, _available_size: &Size2D) -> Option<Size2D> {
+        Some(Size2D::new(self.diameter, self.diameter))
+    }
+
+    fn paint(&self, canvas: &Canvas, _font_collection: &mut FontCollection, area: Area) {
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_color(self.color);
+        let center = area.center();
+        canvas.draw_circle((center.x, center.y), area.width() / 2.0, &paint);
+    }
+
+    fn hit_test(&self, area: Area, point: Point2D) -> bool {
+        (point - area.center()).length() <= area.width() / 2.0
+    }
+}
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::builder()
+            .with_custom_element(
+                "dot",
+                Dot {
+                    diameter: 150.0,
+                    color: Color::from_rgb(230, 80, 90),
+                },
+            )
+            .build(),
+    )
+}
+
+fn app() -> Element {
+    let mut clicks = use_signal(|| 0);
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            main_align: "center",
+            cross_align: "center",
+            rect {
+                custom_element: "dot",
+                onclick: move |_| clicks += 1,
+            }
+            label {
+                "Clicked the dot {clicks} times"
+            }
+        }
+    )
+}
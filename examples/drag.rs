@@ -35,20 +35,23 @@ fn app() -> Element {
             height: "100%",
             offset_x: "{positions.read().0}",
             offset_y: "{positions.read().1}",
-            rect {
-                background: "rgb(255, 166, 0)",
-                width: "120",
-                height: "120",
-                corner_radius: "16",
-                shadow: "0 0 35 10 rgb(255, 255, 255, 0.4)",
-                onglobalclick,
-                onglobalmouseover,
-                onmousedown,
-                main_align: "center",
-                cross_align: "center",
-                label {
-                    color: "white",
-                    "Drag me"
+            CursorArea {
+                icon: CursorIcon::Grab,
+                rect {
+                    background: "rgb(255, 166, 0)",
+                    width: "120",
+                    height: "120",
+                    corner_radius: "16",
+                    shadow: "0 0 35 10 rgb(255, 255, 255, 0.4)",
+                    onglobalclick,
+                    onglobalmouseover,
+                    onmousedown,
+                    main_align: "center",
+                    cross_align: "center",
+                    label {
+                        color: "white",
+                        "Drag me"
+                    }
                 }
             }
         }
@@ -0,0 +1,53 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Event delegation", (400.0, 600.0));
+}
+
+fn app() -> Element {
+    let mut selected = use_signal(|| None::<String>);
+
+    // A single handler for all the items, instead of one per item
+    let onclick = move |e: MouseEvent| {
+        if let Some(item) = e.get_target().dataset().get("item") {
+            selected.set(Some(item.to_string()));
+        }
+    };
+
+    let selected_text = selected
+        .read()
+        .as_ref()
+        .map(|item| format!("Selected item {item}"))
+        .unwrap_or_else(|| "Click an item".to_string());
+
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            label {
+                margin: "8",
+                "{selected_text}"
+            }
+            ScrollView {
+                rect {
+                    width: "100%",
+                    onclick,
+                    for item in 0..1000 {
+                        rect {
+                            key: "{item}",
+                            "data-item": "{item}",
+                            width: "100%",
+                            padding: "6 8",
+                            label { "Item {item}" }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,70 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+#[cfg(feature = "use_file_dialog")]
+use freya::prelude::*;
+
+#[cfg(not(feature = "use_file_dialog"))]
+fn main() {
+    panic!("Run with the 'use_file_dialog' feature");
+}
+
+#[cfg(feature = "use_file_dialog")]
+fn main() {
+    launch_with_props(app, "File Dialog", (400.0, 350.0));
+}
+
+#[cfg(feature = "use_file_dialog")]
+fn app() -> Element {
+    let mut file_dialog = use_file_dialog();
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "20",
+            rect {
+                direction: "horizontal",
+                Button {
+                    onclick: move |_| {
+                        file_dialog.pick_files(
+                            FileDialogOptions::default()
+                                .with_title("Open some files")
+                                .with_filter("Rust", &["rs"])
+                                .with_filter("Text", &["txt", "md"]),
+                        )
+                    },
+                    label {
+                        "Open files"
+                    }
+                }
+                Button {
+                    onclick: move |_| file_dialog.pick_folder(FileDialogOptions::default()),
+                    label {
+                        "Open folder"
+                    }
+                }
+                Button {
+                    onclick: move |_| {
+                        file_dialog.save_file(FileDialogOptions::default().with_file_name("notes.txt"))
+                    },
+                    label {
+                        "Save"
+                    }
+                }
+            }
+            if file_dialog.is_open() {
+                label {
+                    "Waiting for the dialog..."
+                }
+            }
+            for path in file_dialog.paths().read().iter() {
+                label {
+                    "{path.display()}"
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,39 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::builder()
+            .with_width(900.0)
+            .with_height(500.0)
+            .with_title("Fixed resolution")
+            .with_background("black")
+            .with_fixed_resolution(1280.0, 720.0)
+            .build(),
+    );
+}
+
+fn app() -> Element {
+    let mut clicks = use_signal(|| 0);
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "fill",
+            background: "rgb(40, 90, 160)",
+            main_align: "center",
+            cross_align: "center",
+            onclick: move |_| clicks += 1,
+            label {
+                font_size: "60",
+                color: "white",
+                "Designed at 1280x720, clicked {clicks} times"
+            }
+        }
+    )
+}
@@ -3,12 +3,43 @@
     windows_subsystem = "windows"
 )]
 
+use std::time::Instant;
+
 use freya::{hotreload::FreyaCtx, prelude::*};
+use freya_core::plugins::{FreyaPlugin, PluginEvent};
+
+/// Logs the hot reloaded templates and how long they took to get rendered.
+#[derive(Default)]
+struct HotReloadLogger {
+    reloaded_at: Option<Instant>,
+}
+
+impl FreyaPlugin for HotReloadLogger {
+    fn on_event(&mut self, event: &PluginEvent) {
+        match event {
+            PluginEvent::TemplateReplaced(template) => {
+                println!("Reloaded {}", template.name);
+                self.reloaded_at = Some(Instant::now());
+            }
+            PluginEvent::AfterRender { .. } => {
+                if let Some(reloaded_at) = self.reloaded_at.take() {
+                    println!("Rendered in {:?}", reloaded_at.elapsed());
+                }
+            }
+            _ => {}
+        }
+    }
+}
 
 fn main() {
     dioxus_hot_reload::hot_reload_init!(Config::<FreyaCtx>::default());
 
-    launch(app);
+    launch_cfg(
+        app,
+        LaunchConfig::<()>::builder()
+            .with_plugin(HotReloadLogger::default())
+            .build(),
+    );
 }
 
 fn app() -> Element {
@@ -0,0 +1,76 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Mounted animation", (400.0, 350.0));
+}
+
+fn app() -> Element {
+    let mut items = use_signal(Vec::<usize>::new);
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "10",
+            Button {
+                onclick: move |_| {
+                    let id = items.read().len();
+                    items.write().push(id);
+                },
+                label {
+                    "Add item"
+                }
+            }
+            ScrollView {
+                {items.read().iter().map(|id| rsx!(
+                    ListItem {
+                        key: "{id}",
+                        id: *id
+                    }
+                ))}
+            }
+        }
+    )
+}
+
+#[component]
+fn ListItem(id: usize) -> Element {
+    let mut width = use_signal(|| 0.);
+    let animation = use_animation(|ctx| {
+        ctx.with(
+            AnimNum::new(0., 1.)
+                .time(400)
+                .ease(Ease::Out)
+                .function(Function::Expo),
+        )
+    });
+
+    let progress = animation.get().read().as_f32();
+    // Slide in from the left using the width measured when the item appeared
+    let offset_x = -width() * (1. - progress);
+
+    rsx!(
+        rect {
+            width: "fill",
+            height: "40",
+            margin: "8 0 0 0",
+            offset_x: "{offset_x}",
+            corner_radius: "8",
+            padding: "0 12",
+            main_align: "center",
+            background: "rgb(150, 200, 225)",
+            onmounted: move |e| {
+                width.set(e.get_area().width());
+                animation.start();
+            },
+            label {
+                "Item {id}"
+            }
+        }
+    )
+}
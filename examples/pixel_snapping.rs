@@ -0,0 +1,54 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+// Compare both columns at a fractional scale factor, e.g. on X11:
+// WINIT_X11_SCALE_FACTOR=1.25 cargo run --example pixel_snapping
+fn main() {
+    launch_with_props(app, "Pixel snapping", (400.0, 350.0));
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            direction: "horizontal",
+            main_align: "space-around",
+            padding: "20",
+            Column {
+                title: "Before",
+                pixel_snapping: false,
+            }
+            Column {
+                title: "After",
+                pixel_snapping: true,
+            }
+        }
+    )
+}
+
+#[component]
+fn Column(title: &'static str, pixel_snapping: bool) -> Element {
+    rsx!(
+        rect {
+            pixel_snapping: "{pixel_snapping}",
+            label {
+                "{title}"
+            }
+            for i in 0..6 {
+                rect {
+                    key: "{i}",
+                    margin: "7.3 0 0 0",
+                    // Fractional sizes put the borders between two physical pixels
+                    width: "{120.0 + i as f32 * 0.3}",
+                    height: "{30.0 + i as f32 * 0.3}",
+                    border: "1 solid black",
+                }
+            }
+        }
+    )
+}
@@ -0,0 +1,47 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Scrim", (500.0, 400.0));
+}
+
+fn app() -> Element {
+    let mut show_modal = use_signal(|| false);
+
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            main_align: "center",
+            cross_align: "center",
+            background: "linear-gradient(45deg, rgb(255, 95, 0) 0%, rgb(103, 80, 164) 100%)",
+            Button {
+                onclick: move |_| show_modal.set(true),
+                label {
+                    "Open modal"
+                }
+            }
+        }
+        Scrim {
+            visible: show_modal(),
+            ondismiss: move |_| show_modal.set(false),
+            theme: theme_with!(ScrimTheme {
+                background: "rgb(0, 0, 0, 0.3)".into(),
+                backdrop_blur: "12".into(),
+            }),
+            rect {
+                padding: "20",
+                corner_radius: "8",
+                background: "white",
+                shadow: "0 4 10 0 rgb(0, 0, 0, 0.3)",
+                label {
+                    "Click outside to close me"
+                }
+            }
+        }
+    )
+}
@@ -47,6 +47,22 @@ fn app() -> Element {
                     corner_radius: "8",
                     background: "rgb(224, 224, 224)",
                 }
+                rect { width: "40" }
+                // The shadow follows the circle of the avatar
+                rect {
+                    clip_path: "circle()",
+                    shadow: "0 6 20 2 rgb(0, 0, 0, 90)",
+                    height: "80",
+                    width: "80",
+                    background: "rgb(103, 80, 164)",
+                    main_align: "center",
+                    cross_align: "center",
+                    label {
+                        color: "white",
+                        font_size: "32",
+                        "MF"
+                    }
+                }
             }
             rect { height: "40" }
             label {
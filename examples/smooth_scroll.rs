@@ -0,0 +1,56 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Smooth scroll", (600.0, 400.0));
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            direction: "horizontal",
+            List {
+                title: "Instant",
+                smooth_scroll: false,
+            }
+            List {
+                title: "Smooth",
+                smooth_scroll: true,
+            }
+        }
+    )
+}
+
+#[component]
+fn List(title: &'static str, smooth_scroll: bool) -> Element {
+    rsx!(
+        rect {
+            width: "50%",
+            height: "100%",
+            padding: "10",
+            label {
+                font_size: "18",
+                "{title}"
+            }
+            ScrollView {
+                smooth_scroll,
+                for i in 0..200 {
+                    rect {
+                        key: "{i}",
+                        width: "100%",
+                        height: "40",
+                        main_align: "center",
+                        background: if i % 2 == 0 { "rgb(235, 235, 235)" } else { "white" },
+                        label { "Item {i}" }
+                    }
+                }
+            }
+        }
+    )
+}
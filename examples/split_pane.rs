@@ -0,0 +1,54 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Split Pane", (700.0, 500.0));
+}
+
+fn app() -> Element {
+    let mut ratio = use_signal(|| 0.3);
+
+    rsx!(SplitPane {
+        initial_ratio: 0.3,
+        first_min_size: 120.0,
+        second_min_size: 200.0,
+        onresize: move |new_ratio| ratio.set(new_ratio),
+        first: rsx!(
+            rect {
+                width: "fill",
+                height: "fill",
+                padding: "10",
+                label {
+                    "Sidebar, {(ratio() * 100.0).round()}%"
+                }
+            }
+        ),
+        second: rsx!(SplitPane {
+            direction: "vertical",
+            first: rsx!(
+                rect {
+                    width: "fill",
+                    height: "fill",
+                    padding: "10",
+                    label {
+                        "Editor"
+                    }
+                }
+            ),
+            second: rsx!(
+                rect {
+                    width: "fill",
+                    height: "fill",
+                    padding: "10",
+                    label {
+                        "Terminal"
+                    }
+                }
+            )
+        })
+    })
+}
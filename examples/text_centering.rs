@@ -0,0 +1,57 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch_with_props(app, "Text centering", (500.0, 350.0));
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            width: "100%",
+            height: "100%",
+            padding: "16",
+            direction: "horizontal",
+            for text_centering in ["box", "cap-height", "x-height"] {
+                rect {
+                    key: "{text_centering}",
+                    margin: "8",
+                    label {
+                        margin: "0 0 8 0",
+                        "{text_centering}"
+                    }
+                    for text in ["Continue", "CANCEL", "ok", "Agg"] {
+                        rect {
+                            key: "{text}",
+                            width: "130",
+                            height: "36",
+                            margin: "0 0 12 0",
+                            main_align: "center",
+                            cross_align: "center",
+                            corner_radius: "8",
+                            background: "rgb(0, 119, 182)",
+                            text_centering,
+                            // The midline of the button, to compare where the text is painted
+                            rect {
+                                position: "absolute",
+                                position_top: "18",
+                                width: "130",
+                                height: "1",
+                                background: "rgb(255, 255, 255, 0.4)",
+                            }
+                            label {
+                                color: "white",
+                                font_size: "18",
+                                "{text}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
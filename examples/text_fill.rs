@@ -0,0 +1,42 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+static RUST_LOGO: &[u8] = include_bytes!("./rust_logo.png");
+
+fn main() {
+    launch(app);
+}
+
+fn app() -> Element {
+    rsx!(
+        rect {
+            height: "100%",
+            width: "100%",
+            main_align: "center",
+            cross_align: "center",
+            label {
+                font_size: "72",
+                font_weight: "bold",
+                text_fill: "linear-gradient(90deg, rgb(255, 0, 128) 0%, rgb(255, 140, 0) 100%)",
+                "Freya"
+            }
+            label {
+                font_size: "72",
+                font_weight: "bold",
+                text_shadow: "0 4 8 rgb(0, 0, 0, 80)",
+                text_fill: "linear-gradient(0deg, rgb(0, 200, 255) 0%, rgb(120, 0, 255) 100%)",
+                "With a shadow"
+            }
+            paragraph {
+                font_size: "96",
+                font_weight: "bold",
+                text_fill_data: static_bytes(RUST_LOGO),
+                text { "Image" }
+            }
+        }
+    )
+}